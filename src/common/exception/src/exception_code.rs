@@ -160,6 +160,8 @@ build_exceptions! {
     // virtual column
     VirtualColumnTooMany(1128),
     VirtualColumnIdOutBound(1129),
+    // pivot
+    ExceedPivotLimit(1130),
 
     // Data Related Errors
 
@@ -167,6 +169,10 @@ build_exceptions! {
     ParquetFileInvalid(1201),
     /// InvalidUtf8String is used when given string is not a valid utf8 string.
     InvalidUtf8String(1202),
+    /// EmptyResult is used when a query asserted to return rows returns none.
+    ///
+    /// For example: `ASSERT EXISTS (SELECT ...)` on an empty result.
+    EmptyResult(1203),
 
     // Table related errors starts here.
 
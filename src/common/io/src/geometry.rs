@@ -17,7 +17,9 @@ use std::str::FromStr;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use geo::BoundingRect;
 use geo::Geometry;
+use geo::Rect;
 use geozero::geo_types::GeoWriter;
 use geozero::geojson::GeoJson;
 use geozero::wkb::Ewkb;
@@ -276,6 +278,12 @@ pub fn ewkb_to_geo<B: AsRef<[u8]>>(ewkb: &mut Ewkb<B>) -> Result<(Geometry<f64>,
     Ok((geo, srid))
 }
 
+/// Returns the bounding rectangle of an EWKB geometry, or `None` if the geometry is empty.
+pub fn ewkb_bounding_rect(ewkb: &[u8]) -> Result<Option<Rect<f64>>> {
+    let (geo, _) = ewkb_to_geo(&mut Ewkb(ewkb))?;
+    Ok(geo.bounding_rect())
+}
+
 struct SridProcessor {
     srid: Option<i32>,
 }
@@ -52,6 +52,7 @@ pub use decimal::display_decimal_128;
 pub use decimal::display_decimal_256;
 pub use escape::escape_string;
 pub use escape::escape_string_with_quote;
+pub use geometry::ewkb_bounding_rect;
 pub use geometry::ewkb_to_geo;
 pub use geometry::geo_to_ewkb;
 pub use geometry::geo_to_ewkt;
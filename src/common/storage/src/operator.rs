@@ -237,10 +237,15 @@ fn init_ipfs_operator(cfg: &StorageIpfsConfig) -> Result<impl Builder> {
 }
 
 fn init_http_operator(cfg: &StorageHttpConfig) -> Result<(impl Builder, ImmutableIndexLayer)> {
-    let builder = services::Http::default()
+    let mut builder = services::Http::default()
         // Endpoint.
         .endpoint(&cfg.endpoint_url);
 
+    // Credential.
+    if !cfg.token.is_empty() {
+        builder = builder.token(&cfg.token);
+    }
+
     // HTTP Service is read-only and doesn't support list operation.
     // ImmutableIndexLayer will build an in-memory immutable index for it.
     let mut immutable_layer = ImmutableIndexLayer::default();
@@ -78,6 +78,8 @@ pub enum UserPrivilegeType {
     CreateDatabase = 1 << 20,
     // Privilege to Create warehouse
     CreateWarehouse = 1 << 21,
+    // Privilege to read the raw values of masked columns
+    Unmasked = 1 << 22,
     // Discard Privilege Type
     Set = 1 << 4,
 }
@@ -133,6 +135,7 @@ impl Display for UserPrivilegeType {
             UserPrivilegeType::Write => "Write",
             UserPrivilegeType::CreateDatabase => "CREATE DATABASE",
             UserPrivilegeType::CreateWarehouse => "CREATE WAREHOUSE",
+            UserPrivilegeType::Unmasked => "UNMASKED",
         })
     }
 }
@@ -173,6 +176,7 @@ impl From<databend_common_ast::ast::UserPrivilegeType> for UserPrivilegeType {
             databend_common_ast::ast::UserPrivilegeType::CreateWarehouse => {
                 UserPrivilegeType::CreateWarehouse
             }
+            databend_common_ast::ast::UserPrivilegeType::Unmasked => UserPrivilegeType::Unmasked,
             databend_common_ast::ast::UserPrivilegeType::Set => UserPrivilegeType::Set,
         }
     }
@@ -223,11 +227,12 @@ impl UserPrivilegeSet {
         UserPrivilegeSet::available_privileges_on_table(available_ownership)
     }
 
-    /// The all privileges global which available to the table object
+    /// The all privileges global which available to the table object.
+    /// `Unmasked` can only be granted explicitly, so it is not a part of `ALL`.
     pub fn available_privileges_on_table(available_ownership: bool) -> Self {
         let tab_privs = make_bitflags!(UserPrivilegeType::{ Create | Update | Select | Insert | Delete | Drop | Alter | Grant });
         if available_ownership {
-            (tab_privs | make_bitflags!(UserPrivilegeType::{  Ownership })).into()
+            (tab_privs | make_bitflags!(UserPrivilegeType::{  Ownership | Unmasked })).into()
         } else {
             tab_privs.into()
         }
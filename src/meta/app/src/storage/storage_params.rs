@@ -417,10 +417,24 @@ impl Debug for StorageS3Config {
 }
 
 /// Config for storage backend http.
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StorageHttpConfig {
     pub endpoint_url: String,
     pub paths: Vec<String>,
+    /// Bearer token sent in the `Authorization` header.
+    ///
+    /// Only needed for private endpoints.
+    pub token: String,
+}
+
+impl Debug for StorageHttpConfig {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_struct("StorageHttpConfig")
+            .field("endpoint_url", &self.endpoint_url)
+            .field("paths", &self.paths)
+            .field("token", &mask_string(&self.token, 3))
+            .finish()
+    }
 }
 
 pub const STORAGE_IPFS_DEFAULT_ENDPOINT: &str = "https://ipfs.io";
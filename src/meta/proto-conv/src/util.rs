@@ -152,6 +152,7 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (120, "2025-02-11: Add: Add new UserPrivilege CreateWarehouse and new OwnershipObject::Warehouse"),
     (121, "2025-03-03: Add: Add new FileFormat AvroFileFormatParams"),
     (122, "2025-03-11: Add: table_meta and virtual_data_schema"),
+    (123, "2025-03-20: Add: Add new UserPrivilege Unmasked"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
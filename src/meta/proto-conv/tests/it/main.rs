@@ -117,3 +117,4 @@ mod v119_virtual_column;
 mod v120_warehouse_ownershipobject;
 mod v121_avro_format_params;
 mod v122_virtual_schema;
+mod v123_unmasked_privilege;
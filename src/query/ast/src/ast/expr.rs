@@ -1837,6 +1837,9 @@ impl ExprReplacer {
                 self.replace_table_table_reference(&mut join.right);
             }
             TableReference::Location { .. } => (),
+            TableReference::JsonTable { source, .. } => {
+                self.replace_expr(source);
+            }
         }
     }
 
@@ -1860,6 +1863,17 @@ impl ExprReplacer {
                     self.replace_group_by(group_by);
                 }
             }
+            GroupBy::TumblingWindow {
+                time_column,
+                size: window,
+            }
+            | GroupBy::SessionWindow {
+                time_column,
+                gap: window,
+            } => {
+                self.replace_expr(time_column);
+                self.replace_expr(window);
+            }
             _ => (),
         }
     }
@@ -30,6 +30,7 @@ use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Lambda;
 use crate::ast::SelectStageOptions;
+use crate::ast::TypeName;
 use crate::ast::WindowDefinition;
 use crate::ParseError;
 use crate::Result;
@@ -234,6 +235,21 @@ pub enum GroupBy {
     /// GROUP BY ROLLUP ( expr [, expr]* )
     Rollup(Vec<Expr>),
     Combined(Vec<GroupBy>),
+    /// GROUP BY TUMBLING WINDOW ( time_column, size )
+    ///
+    /// Groups the rows into fixed-size, non-overlapping windows of `time_column`.
+    TumblingWindow {
+        time_column: Box<Expr>,
+        size: Box<Expr>,
+    },
+    /// GROUP BY SESSION WINDOW ( time_column, GAP gap )
+    ///
+    /// Groups the rows into sessions of `time_column`, a session ends when there are no rows
+    /// within `gap` after its last row.
+    SessionWindow {
+        time_column: Box<Expr>,
+        gap: Box<Expr>,
+    },
 }
 
 impl GroupBy {
@@ -284,6 +300,12 @@ impl Display for GroupBy {
                     write!(f, "{}", group_by)?;
                 }
             }
+            GroupBy::TumblingWindow { time_column, size } => {
+                write!(f, "TUMBLING WINDOW({time_column}, {size})")?;
+            }
+            GroupBy::SessionWindow { time_column, gap } => {
+                write!(f, "SESSION WINDOW({time_column}, GAP {gap})")?;
+            }
         }
         Ok(())
     }
@@ -667,6 +689,32 @@ impl Display for Unpivot {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum IndexHintKind {
+    /// Only consider the listed indexes.
+    Use,
+    /// Only consider the listed indexes, even if the index scans are disabled.
+    Force,
+}
+
+/// `USE INDEX (index, ...)` or `FORCE INDEX (index, ...)` after a table name.
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct IndexHint {
+    pub kind: IndexHintKind,
+    pub indexes: Vec<Identifier>,
+}
+
+impl Display for IndexHint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self.kind {
+            IndexHintKind::Use => write!(f, "USE INDEX (")?,
+            IndexHintKind::Force => write!(f, "FORCE INDEX (")?,
+        }
+        write_comma_separated_list(f, &self.indexes)?;
+        write!(f, ")")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default, Drive, DriveMut)]
 pub struct WithOptions {
     pub options: BTreeMap<String, String>,
@@ -782,12 +830,23 @@ impl SampleRowLevel {
 
 impl Eq for SampleRowLevel {}
 
+/// `TABLESAMPLE SYSTEM (probability) [REPEATABLE (seed)]`, which keeps or skips whole blocks
+/// by the seed, the same seed samples the same blocks.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct SampleSystem {
+    pub probability: f64,
+    pub seed: Option<u64>,
+}
+
+impl Eq for SampleSystem {}
+
 #[derive(
     serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Drive, DriveMut, Default,
 )]
 pub struct SampleConfig {
     pub row_level: Option<SampleRowLevel>,
     pub block_level: Option<f64>,
+    pub system: Option<SampleSystem>,
 }
 
 impl SampleConfig {
@@ -802,12 +861,26 @@ impl SampleConfig {
     pub fn set_block_level_sample(&mut self, probability: f64) {
         self.block_level = Some(probability);
     }
+
+    pub fn set_system_sample(&mut self, probability: f64, seed: Option<u64>) {
+        self.system = Some(SampleSystem { probability, seed });
+    }
 }
 
 impl Eq for SampleConfig {}
 
 impl Display for SampleConfig {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(system) = &self.system {
+            write!(f, "TABLESAMPLE SYSTEM ({})", system.probability)?;
+            if let Some(seed) = system.seed {
+                write!(f, " REPEATABLE ({})", seed)?;
+            }
+            if self.block_level.is_none() && self.row_level.is_none() {
+                return Ok(());
+            }
+            write!(f, " ")?;
+        }
         write!(f, "SAMPLE ")?;
         if let Some(block_level) = self.block_level {
             write!(f, "BLOCK ({}) ", block_level)?;
@@ -838,6 +911,7 @@ pub enum TableReference {
         alias: Option<TableAlias>,
         temporal: Option<TemporalClause>,
         with_options: Option<WithOptions>,
+        index_hint: Option<IndexHint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
         sample: Option<SampleConfig>,
@@ -850,6 +924,8 @@ pub enum TableReference {
         name: Identifier,
         params: Vec<Expr>,
         named_params: Vec<(Identifier, Expr)>,
+        /// Whether to append a 1-based index column, `WITH ORDINALITY`
+        with_ordinality: bool,
         alias: Option<TableAlias>,
         sample: Option<SampleConfig>,
     },
@@ -873,6 +949,15 @@ pub enum TableReference {
         options: SelectStageOptions,
         alias: Option<TableAlias>,
     },
+    // `JSON_TABLE(expr, 'row_path' COLUMNS (name type PATH 'path', ...))[ AS alias ]`
+    JsonTable {
+        span: Span,
+        source: Box<Expr>,
+        /// The path of the JSON values to output a row for each
+        row_path: String,
+        columns: Vec<JsonTableColumn>,
+        alias: Option<TableAlias>,
+    },
 }
 
 impl TableReference {
@@ -919,6 +1004,7 @@ impl Display for TableReference {
                 alias,
                 temporal,
                 with_options,
+                index_hint,
                 pivot,
                 unpivot,
                 sample,
@@ -939,6 +1025,9 @@ impl Display for TableReference {
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
+                if let Some(index_hint) = index_hint {
+                    write!(f, " {index_hint}")?;
+                }
                 if let Some(pivot) = pivot {
                     write!(f, " {pivot}")?;
                 }
@@ -957,6 +1046,7 @@ impl Display for TableReference {
                 name,
                 params,
                 named_params,
+                with_ordinality,
                 alias,
                 sample,
             } => {
@@ -975,6 +1065,9 @@ impl Display for TableReference {
                     write!(f, "{k}=>{v}")?;
                 }
                 write!(f, ")")?;
+                if *with_ordinality {
+                    write!(f, " WITH ORDINALITY")?;
+                }
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
@@ -1067,11 +1160,49 @@ impl Display for TableReference {
                     write!(f, " AS {alias}")?;
                 }
             }
+            TableReference::JsonTable {
+                span: _,
+                source,
+                row_path,
+                columns,
+                alias,
+            } => {
+                write!(
+                    f,
+                    "JSON_TABLE({source}, {} COLUMNS (",
+                    QuotedString(row_path, '\'')
+                )?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, "))")?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {alias}")?;
+                }
+            }
         }
         Ok(())
     }
 }
 
+/// A column of `JSON_TABLE`, the value at `path` of each row is cast to `data_type`.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct JsonTableColumn {
+    pub name: Identifier,
+    pub data_type: TypeName,
+    pub path: String,
+}
+
+impl Display for JsonTableColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} PATH {}",
+            self.name,
+            self.data_type,
+            QuotedString(&self.path, '\'')
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct TableAlias {
     pub name: Identifier,
@@ -26,6 +26,7 @@ use percent_encoding::percent_decode_str;
 use url::Url;
 
 use crate::ast::quote::QuotedString;
+use crate::ast::write_comma_separated_list;
 use crate::ast::write_comma_separated_map;
 use crate::ast::write_comma_separated_string_list;
 use crate::ast::write_comma_separated_string_map;
@@ -233,6 +234,8 @@ pub struct CopyIntoLocationOptions {
     pub use_raw_path: bool,
     pub include_query_id: bool,
     pub overwrite: bool,
+    /// Columns whose values name the sub-directory of each output file, e.g. `year=2023/month=01/`.
+    pub partition_by: Vec<String>,
 }
 
 impl Default for CopyIntoLocationOptions {
@@ -244,6 +247,7 @@ impl Default for CopyIntoLocationOptions {
             use_raw_path: false,
             include_query_id: true,
             overwrite: false,
+            partition_by: vec![],
         }
     }
 }
@@ -270,6 +274,11 @@ impl Display for CopyIntoLocationStmt {
         }
         write!(f, " INTO {}", self.dst)?;
         write!(f, " FROM {}", self.src)?;
+        if !self.options.partition_by.is_empty() {
+            write!(f, " PARTITION BY (")?;
+            write_comma_separated_list(f, &self.options.partition_by)?;
+            write!(f, ")")?;
+        }
 
         if !self.file_format.is_empty() {
             write!(f, " FILE_FORMAT = ({})", self.file_format)?;
@@ -295,6 +304,9 @@ impl CopyIntoLocationStmt {
             CopyIntoLocationOption::IncludeQueryID(v) => self.options.include_query_id = v,
             CopyIntoLocationOption::UseRawPath(v) => self.options.use_raw_path = v,
             CopyIntoLocationOption::OverWrite(v) => self.options.overwrite = v,
+            CopyIntoLocationOption::PartitionBy(v) => {
+                self.options.partition_by = v.into_iter().map(|ident| ident.name).collect()
+            }
         }
     }
 }
@@ -585,6 +597,7 @@ pub enum CopyIntoLocationOption {
     UseRawPath(bool),
     DetailedOutput(bool),
     OverWrite(bool),
+    PartitionBy(Vec<Identifier>),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Drive, DriveMut)]
@@ -24,6 +24,7 @@ use crate::ast::Expr;
 use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Query;
+use crate::ast::SelectTarget;
 use crate::ast::With;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -37,6 +38,8 @@ pub struct InsertStmt {
     pub columns: Vec<Identifier>,
     pub source: InsertSource,
     pub overwrite: bool,
+    // The expressions evaluated on the inserted rows and returned to the client.
+    pub returning: Vec<SelectTarget>,
 }
 
 impl Display for InsertStmt {
@@ -65,7 +68,12 @@ impl Display for InsertStmt {
             write_comma_separated_list(f, &self.columns)?;
             write!(f, ")")?;
         }
-        write!(f, " {}", self.source)
+        write!(f, " {}", self.source)?;
+        if !self.returning.is_empty() {
+            write!(f, " RETURNING ")?;
+            write_comma_separated_list(f, &self.returning)?;
+        }
+        Ok(())
     }
 }
 
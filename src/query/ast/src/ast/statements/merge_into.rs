@@ -221,6 +221,7 @@ impl MutationSource {
                 alias: alias.clone(),
                 temporal: None,
                 with_options: with_options.clone(),
+                index_hint: None,
                 pivot: None,
                 unpivot: None,
                 sample: None,
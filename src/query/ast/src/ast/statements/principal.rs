@@ -152,6 +152,8 @@ pub enum UserPrivilegeType {
     CreateDatabase,
     // Privilege to Create warehouse
     CreateWarehouse,
+    // Privilege to read the raw values of masked columns
+    Unmasked,
     // Discard Privilege Type
     Set,
 }
@@ -181,6 +183,7 @@ impl Display for UserPrivilegeType {
             UserPrivilegeType::Write => "Write",
             UserPrivilegeType::CreateDatabase => "CREATE DATABASE",
             UserPrivilegeType::CreateWarehouse => "CREATE WAREHOUSE",
+            UserPrivilegeType::Unmasked => "UNMASKED",
         })
     }
 }
@@ -56,6 +56,11 @@ pub enum Statement {
         graphical: bool,
         query: Box<Statement>,
     },
+    AssertExists {
+        query: Box<Query>,
+        // The error message if the query returns no rows.
+        message: Option<String>,
+    },
 
     CopyIntoTable(CopyIntoTableStmt),
     CopyIntoLocation(CopyIntoLocationStmt),
@@ -407,6 +412,7 @@ impl Statement {
     pub fn allowed_in_multi_statement(&self) -> bool {
         match self {
             Statement::Query(..)
+            | Statement::AssertExists { .. }
             | Statement::Explain { .. }
             | Statement::ExplainAnalyze { .. }
             | Statement::CopyIntoTable(..)
@@ -662,6 +668,12 @@ impl Display for Statement {
                     write!(f, "EXPLAIN ANALYZE {query}")?;
                 }
             }
+            Statement::AssertExists { query, message } => {
+                write!(f, "ASSERT EXISTS ({query})")?;
+                if let Some(message) = message {
+                    write!(f, " ELSE {}", QuotedString(message, '\''))?;
+                }
+            }
             Statement::Query(stmt) => write!(f, "{stmt}")?,
             Statement::Insert(stmt) => write!(f, "{stmt}")?,
             Statement::InsertMultiTable(insert_multi_table) => write!(f, "{insert_multi_table}")?,
@@ -120,6 +120,7 @@ pub fn copy_into(i: Input) -> IResult<Statement> {
          #copy_into_location:"`COPY
                 INTO { internalStage | externalStage | externalLocation }
                 FROM { [<database_name>.]<table_name> | ( <query> ) }
+                [ PARTITION BY ( <column_name> [ , ... ] ) ]
                 [ FILE_FORMAT = ( { TYPE = { CSV | NDJSON | PARQUET | TSV | AVRO } [ formatTypeOptions ] } ) ]
                 [ copyOptions ]`"
          | #copy_into_table: "`COPY
@@ -216,6 +217,10 @@ fn copy_into_location_option(i: Input) -> IResult<CopyIntoLocationOption> {
             rule! {  OVERWRITE ~ "=" ~ #literal_bool },
             |(_, _, include_query_id)| CopyIntoLocationOption::OverWrite(include_query_id),
         ),
+        map(
+            rule! { PARTITION ~ BY ~ "(" ~ #comma_separated_list1(ident) ~ ")" },
+            |(_, _, _, columns, _)| CopyIntoLocationOption::PartitionBy(columns),
+        ),
         map(rule! { #file_format_clause }, |options| {
             CopyIntoLocationOption::FileFormat(options)
         }),
@@ -657,6 +657,22 @@ pub fn table_alias(i: Input) -> IResult<TableAlias> {
     )(i)
 }
 
+pub fn index_hint(i: Input) -> IResult<IndexHint> {
+    map(
+        rule! {
+            #index_hint_kind ~ INDEX ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(kind, _, _, indexes, _)| IndexHint { kind, indexes },
+    )(i)
+}
+
+fn index_hint_kind(i: Input) -> IResult<IndexHintKind> {
+    alt((
+        value(IndexHintKind::Use, rule! { USE }),
+        value(IndexHintKind::Force, rule! { FORCE }),
+    ))(i)
+}
+
 pub fn table_alias_without_as(i: Input) -> IResult<TableAlias> {
     map(
         rule! { #ident ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )? },
@@ -735,6 +751,7 @@ pub enum TableReferenceElement {
         alias: Option<TableAlias>,
         temporal: Option<TemporalClause>,
         with_options: Option<WithOptions>,
+        index_hint: Option<IndexHint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
         sample: Option<SampleConfig>,
@@ -745,6 +762,7 @@ pub enum TableReferenceElement {
         lateral: bool,
         name: Identifier,
         params: Vec<TableFunctionParam>,
+        with_ordinality: bool,
         alias: Option<TableAlias>,
         sample: Option<SampleConfig>,
     },
@@ -770,25 +788,35 @@ pub enum TableReferenceElement {
         options: Vec<SelectStageOption>,
         alias: Option<TableAlias>,
     },
+    JsonTable {
+        source: Box<Expr>,
+        row_path: String,
+        columns: Vec<JsonTableColumn>,
+        alias: Option<TableAlias>,
+    },
 }
 
 pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceElement>> {
     let aliased_table = map(
         rule! {
-            #dot_separated_idents_1_to_3 ~ #temporal_clause? ~ #with_options? ~ #table_alias? ~ #pivot? ~ #unpivot? ~ SAMPLE? ~ (BLOCK ~ "(" ~ #expr ~ ")")? ~ (ROW ~ "(" ~ #expr ~ ROWS? ~ ")")?
+            #dot_separated_idents_1_to_3 ~ #temporal_clause? ~ #with_options? ~ #index_hint? ~ #table_alias? ~ #index_hint? ~ #pivot? ~ #unpivot? ~ SAMPLE? ~ (BLOCK ~ "(" ~ #expr ~ ")")? ~ (ROW ~ "(" ~ #expr ~ ROWS? ~ ")")? ~ #sample_system?
         },
         |(
             (catalog, database, table),
             temporal,
             with_options,
+            index_hint_before_alias,
             alias,
+            index_hint_after_alias,
             pivot,
             unpivot,
             sample,
             sample_block_level,
             sample_row_level,
+            sample_system,
         )| {
-            let table_sample = get_table_sample(sample, sample_block_level, sample_row_level);
+            let table_sample =
+                get_table_sample(sample, sample_block_level, sample_row_level, sample_system);
             TableReferenceElement::Table {
                 catalog,
                 database,
@@ -796,6 +824,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
                 alias,
                 temporal,
                 with_options,
+                index_hint: index_hint_before_alias.or(index_hint_after_alias),
                 pivot: pivot.map(Box::new),
                 unpivot: unpivot.map(Box::new),
                 sample: table_sample,
@@ -825,14 +854,27 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let table_function = map(
         rule! {
-            LATERAL? ~ #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias? ~ SAMPLE? ~ (BLOCK ~ "(" ~ #expr ~ ")")? ~ (ROW ~ "(" ~ #expr ~ ROWS? ~ ")")?
+            LATERAL? ~ #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ (WITH ~ ORDINALITY)? ~ #table_alias? ~ SAMPLE? ~ (BLOCK ~ "(" ~ #expr ~ ")")? ~ (ROW ~ "(" ~ #expr ~ ROWS? ~ ")")? ~ #sample_system?
         },
-        |(lateral, name, _, params, _, alias, sample, level, sample_conf)| {
-            let table_sample = get_table_sample(sample, level, sample_conf);
+        |(
+            lateral,
+            name,
+            _,
+            params,
+            _,
+            with_ordinality,
+            alias,
+            sample,
+            level,
+            sample_conf,
+            sample_system,
+        )| {
+            let table_sample = get_table_sample(sample, level, sample_conf, sample_system);
             TableReferenceElement::TableFunction {
                 lateral: lateral.is_some(),
                 name,
                 params,
+                with_ordinality: with_ordinality.is_some(),
                 alias,
                 sample: table_sample,
             }
@@ -872,9 +914,35 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
             }
         },
     );
+    let json_table_column = map(
+        rule! {
+            #ident ~ #type_name ~ ^PATH ~ ^#literal_string
+        },
+        |(name, data_type, _, path)| JsonTableColumn {
+            name,
+            data_type,
+            path,
+        },
+    );
+    let json_table = map(
+        rule! {
+            JSON_TABLE ~ "(" ~ ^#expr ~ ^"," ~ ^#literal_string
+            ~ ^COLUMNS ~ ^"(" ~ ^#comma_separated_list1(json_table_column) ~ ^")" ~ ^")"
+            ~ #table_alias?
+        },
+        |(_, _, source, _, row_path, _, _, columns, _, _, alias)| {
+            TableReferenceElement::JsonTable {
+                source: Box::new(source),
+                row_path,
+                columns,
+                alias,
+            }
+        },
+    );
 
     let (rest, (span, elem)) = consumed(rule! {
         #aliased_stage
+        | #json_table
         | #table_function
         | #aliased_table
         | #subquery
@@ -945,12 +1013,34 @@ fn pivot_values(i: Input) -> IResult<PivotValues> {
     ))(i)
 }
 
+// `TABLESAMPLE SYSTEM (probability) [REPEATABLE (seed)]`
+fn sample_system(i: Input) -> IResult<(f64, Option<u64>)> {
+    map(
+        rule! {
+            TABLESAMPLE ~ ^SYSTEM ~ ^"(" ~ #literal_number ~ ^")" ~ (REPEATABLE ~ ^"(" ~ #literal_u64 ~ ^")")?
+        },
+        |(_, _, _, probability, _, seed)| {
+            (
+                probability.as_double().unwrap_or_default(),
+                seed.map(|(_, _, seed, _)| seed),
+            )
+        },
+    )(i)
+}
+
 fn get_table_sample(
     sample: Option<&Token>,
     block_level_sample: Option<(&Token, &Token, Expr, &Token)>,
     row_level_sample: Option<(&Token, &Token, Expr, Option<&Token>, &Token)>,
+    system_sample: Option<(f64, Option<u64>)>,
 ) -> Option<SampleConfig> {
     let mut default_sample_conf = SampleConfig::default();
+    if let Some((probability, seed)) = system_sample {
+        default_sample_conf.set_system_sample(probability, seed);
+        if sample.is_none() {
+            return Some(default_sample_conf);
+        }
+    }
     if sample.is_some() {
         if let Some((_, _, Expr::Literal { value, .. }, _)) = block_level_sample {
             default_sample_conf.set_block_level_sample(value.as_double().unwrap_or_default());
@@ -992,6 +1082,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 alias,
                 temporal,
                 with_options,
+                index_hint,
                 pivot,
                 unpivot,
                 sample,
@@ -1003,6 +1094,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 alias,
                 temporal,
                 with_options,
+                index_hint,
                 pivot,
                 unpivot,
                 sample,
@@ -1011,6 +1103,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 lateral,
                 name,
                 params,
+                with_ordinality,
                 alias,
                 sample,
             } => {
@@ -1034,6 +1127,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     name,
                     params: normal_params,
                     named_params,
+                    with_ordinality,
                     alias,
                     sample,
                 }
@@ -1065,6 +1159,18 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     alias,
                 }
             }
+            TableReferenceElement::JsonTable {
+                source,
+                row_path,
+                columns,
+                alias,
+            } => TableReference::JsonTable {
+                span: transform_span(input.span.tokens),
+                source,
+                row_path,
+                columns,
+                alias,
+            },
             _ => unreachable!(),
         };
         Ok(table_ref)
@@ -1155,9 +1261,32 @@ pub fn group_by_items(i: Input) -> IResult<GroupBy> {
         |(_, _, _, sets, _)| GroupBy::GroupingSets(sets),
     );
 
+    let tumbling_window = map(
+        rule! { TUMBLING ~ WINDOW ~ "(" ~ ^#expr ~ "," ~ ^#expr ~ ")" },
+        |(_, _, _, time_column, _, size, _)| GroupBy::TumblingWindow {
+            time_column: Box::new(time_column),
+            size: Box::new(size),
+        },
+    );
+    let session_window = map(
+        rule! { SESSION ~ WINDOW ~ "(" ~ ^#expr ~ "," ~ ^GAP ~ ^#expr ~ ")" },
+        |(_, _, _, time_column, _, _, gap, _)| GroupBy::SessionWindow {
+            time_column: Box::new(time_column),
+            gap: Box::new(gap),
+        },
+    );
+
     // New rule to handle multiple GroupBy items
     let single_normal = map(rule! { #expr }, |group| GroupBy::Normal(vec![group]));
-    let group_by_item = alt((all, group_sets, cube, rollup, single_normal));
+    let group_by_item = alt((
+        all,
+        group_sets,
+        cube,
+        rollup,
+        tumbling_window,
+        session_window,
+        single_normal,
+    ));
     map(rule! { ^#comma_separated_list1(group_by_item) }, |items| {
         if items.len() > 1 {
             if items.iter().all(|item| matches!(item, GroupBy::Normal(_))) {
@@ -122,6 +122,16 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    let assert_exists = map(
+        rule! {
+            ASSERT ~ EXISTS ~ "(" ~ #query ~ ")" ~ ( ELSE ~ ^#literal_string )?
+        },
+        |(_, _, _, query, _, opt_message)| Statement::AssertExists {
+            query: Box::new(query),
+            message: opt_message.map(|(_, message)| message),
+        },
+    );
+
     let create_task = map(
         rule! {
             CREATE ~ TASK ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -2431,6 +2441,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             #map(query, |query| Statement::Query(Box::new(query)))
             | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
             | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
+            | #assert_exists : "`ASSERT EXISTS (<query>) [ELSE '<message>']`"
             | #show_settings : "`SHOW SETTINGS [<show_limit>]`"
             | #show_variables : "`SHOW VARIABLES [<show_limit>]`"
             | #show_stages : "`SHOW STAGES`"
@@ -2702,6 +2713,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                 ~ #dot_separated_idents_1_to_3
                 ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
                 ~ #insert_source_parser
+                ~ ( RETURNING ~ ^#comma_separated_list1(select_target) )?
             },
             |(
                 with,
@@ -2712,6 +2724,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                 (catalog, database, table),
                 opt_columns,
                 source,
+                opt_returning,
             )| {
                 Statement::Insert(InsertStmt {
                     hints: opt_hints,
@@ -2724,6 +2737,9 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                         .unwrap_or_default(),
                     source,
                     overwrite: overwrite.kind == OVERWRITE,
+                    returning: opt_returning
+                        .map(|(_, returning)| returning)
+                        .unwrap_or_default(),
                 })
             },
         )(i)
@@ -3322,6 +3338,7 @@ pub fn priv_type(i: Input) -> IResult<UserPrivilegeType> {
         value(UserPrivilegeType::Delete, rule! { DELETE }),
         value(UserPrivilegeType::Alter, rule! { ALTER }),
         value(UserPrivilegeType::Super, rule! { SUPER }),
+        value(UserPrivilegeType::Unmasked, rule! { UNMASKED }),
         value(UserPrivilegeType::CreateUser, rule! { CREATE ~ USER }),
         value(
             UserPrivilegeType::CreateDatabase,
@@ -4534,6 +4551,7 @@ pub fn table_reference_with_alias(i: Input) -> IResult<TableReference> {
             }),
             temporal: None,
             with_options: None,
+            index_hint: None,
             pivot: None,
             unpivot: None,
             sample: None,
@@ -4554,6 +4572,7 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
             alias: None,
             temporal: None,
             with_options: None,
+            index_hint: None,
             pivot: None,
             unpivot: None,
             sample: None,
@@ -375,6 +375,8 @@ pub enum TokenKind {
     AT,
     #[token("ASC", ignore(ascii_case))]
     ASC,
+    #[token("ASSERT", ignore(ascii_case))]
+    ASSERT,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
     #[token("ASYNC", ignore(ascii_case))]
@@ -661,6 +663,8 @@ pub enum TokenKind {
     SET_VAR,
     #[token("FUSE", ignore(ascii_case))]
     FUSE,
+    #[token("GAP", ignore(ascii_case))]
+    GAP,
     #[token("GET", ignore(ascii_case))]
     GET,
     #[token("GENERATED", ignore(ascii_case))]
@@ -753,6 +757,8 @@ pub enum TokenKind {
     JOIN,
     #[token("JSON", ignore(ascii_case))]
     JSON,
+    #[token("JSON_TABLE", ignore(ascii_case))]
+    JSON_TABLE,
     #[token("JULIAN", ignore(ascii_case))]
     JULIAN,
     #[token("JWT", ignore(ascii_case))]
@@ -878,6 +884,8 @@ pub enum TokenKind {
     ORC,
     #[token("ORDER", ignore(ascii_case))]
     ORDER,
+    #[token("ORDINALITY", ignore(ascii_case))]
+    ORDINALITY,
     #[token("OUTPUT_HEADER", ignore(ascii_case))]
     OUTPUT_HEADER,
     #[token("OUTER", ignore(ascii_case))]
@@ -894,6 +902,8 @@ pub enum TokenKind {
     PARQUET,
     #[token("PASSWORD", ignore(ascii_case))]
     PASSWORD,
+    #[token("PATH", ignore(ascii_case))]
+    PATH,
     #[token("PASSWORD_MIN_LENGTH", ignore(ascii_case))]
     PASSWORD_MIN_LENGTH,
     #[token("PASSWORD_MAX_LENGTH", ignore(ascii_case))]
@@ -992,6 +1002,8 @@ pub enum TokenKind {
     GRANT,
     #[token("REPEAT", ignore(ascii_case))]
     REPEAT,
+    #[token("REPEATABLE", ignore(ascii_case))]
+    REPEATABLE,
     #[token("ROLE", ignore(ascii_case))]
     ROLE,
     #[token("PRECEDING", ignore(ascii_case))]
@@ -1016,6 +1028,8 @@ pub enum TokenKind {
     RETURN,
     #[token("RETURNS", ignore(ascii_case))]
     RETURNS,
+    #[token("RETURNING", ignore(ascii_case))]
+    RETURNING,
     #[token("RESULTSET", ignore(ascii_case))]
     RESULTSET,
     #[token("RUN", ignore(ascii_case))]
@@ -1142,6 +1156,8 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TABLESAMPLE", ignore(ascii_case))]
+    TABLESAMPLE,
     #[token("TARGET_LAG", ignore(ascii_case))]
     TARGET_LAG,
     #[token("TEXT", ignore(ascii_case))]
@@ -1192,6 +1208,8 @@ pub enum TokenKind {
     TSV,
     #[token("TUESDAY", ignore(ascii_case))]
     TUESDAY,
+    #[token("TUMBLING", ignore(ascii_case))]
+    TUMBLING,
     #[token("TUPLE", ignore(ascii_case))]
     TUPLE,
     #[token("TYPE", ignore(ascii_case))]
@@ -1210,6 +1228,8 @@ pub enum TokenKind {
     UINT8,
     #[token("UNDROP", ignore(ascii_case))]
     UNDROP,
+    #[token("UNMASKED", ignore(ascii_case))]
+    UNMASKED,
     #[token("UNSIGNED", ignore(ascii_case))]
     UNSIGNED,
     #[token("URL", ignore(ascii_case))]
@@ -1720,7 +1740,7 @@ impl TokenKind {
             | TokenKind::SET
             | TokenKind::SAMPLE
             // | TokenKind::SYMMETRIC
-            // | TokenKind::TABLESAMPLE
+            | TokenKind::TABLESAMPLE
             | TokenKind::THEN
             | TokenKind::TRAILING
             | TokenKind::TRUE
@@ -1757,7 +1777,7 @@ impl TokenKind {
             | TokenKind::ROWS
             | TokenKind::RANGE
             // | TokenKind::OVERLAPS
-            // | TokenKind::RETURNING
+            | TokenKind::RETURNING
             | TokenKind::STAGE
             | TokenKind::UDF
             | TokenKind::SHARE
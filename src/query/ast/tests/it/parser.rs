@@ -193,6 +193,7 @@ fn test_statement() {
         r#"DROP database if exists db1;"#,
         r#"select distinct a, count(*) from t where a = 1 and b - 1 < a group by a having a = 1;"#,
         r#"select * from t4;"#,
+        r#"assert exists (select * from t4) else 'no rows';"#,
         r#"select top 2 * from t4;"#,
         r#"select * from aa.bb;"#,
         r#"from aa.bb select *;"#,
@@ -315,6 +316,7 @@ fn test_statement() {
         r#"GRANT SELECT ON db01.tb1 TO 'test-grant';"#,
         r#"GRANT SELECT ON db01.tb1 TO USER 'test-grant';"#,
         r#"GRANT SELECT ON db01.tb1 TO ROLE role1;"#,
+        r#"GRANT UNMASKED ON db01.tb1 TO ROLE role1;"#,
         r#"GRANT SELECT ON tb1 TO ROLE role1;"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
         r#"SHOW GRANTS;"#,
@@ -445,6 +447,11 @@ fn test_statement() {
                     skip_header = 1
                 );
         "#,
+        r#"
+            COPY INTO @my_stage/unload/
+                FROM mytable
+                PARTITION BY (year, month);
+        "#,
         r#"
             COPY INTO mytable
                 FROM 's3://mybucket/data.csv'
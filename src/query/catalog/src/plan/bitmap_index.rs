@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::Bitmap;
+use databend_common_expression::Scalar;
+
+/// A bitmap index of a low-cardinality column of a table, e.g. `status` or `category`,
+/// which keeps a bitmap of the rows of each block for every distinct value of the column.
+pub trait BitmapIndex: Send + Sync {
+    /// Returns the bitmaps of the rows holding `value`, keyed by the locations of the blocks.
+    /// The blocks without any row holding the value may be absent.
+    fn lookup(&self, value: &Scalar) -> Result<HashMap<String, Bitmap>>;
+}
+
+/// Returns the rows of each block holding any of `values`, the bitmaps of the values are
+/// ORed. The blocks without any matching row are absent.
+pub fn bitmap_index_lookup(
+    bitmap_index: &dyn BitmapIndex,
+    values: &[Scalar],
+) -> Result<HashMap<String, Bitmap>> {
+    let mut rows: HashMap<String, Bitmap> = HashMap::new();
+    for value in values {
+        for (location, bitmap) in bitmap_index.lookup(value)? {
+            match rows.get_mut(&location) {
+                Some(matched) => *matched = &*matched | &bitmap,
+                None => {
+                    rows.insert(location, bitmap);
+                }
+            }
+        }
+    }
+    rows.retain(|_, bitmap| bitmap.true_count() > 0);
+    Ok(rows)
+}
@@ -48,6 +48,8 @@ pub struct DataSourcePlan {
 
     pub table_index: usize,
     pub scan_id: usize,
+    // The number of partitions read ahead of the one being processed, 0 disables read-ahead.
+    pub prefetch_depth: usize,
 }
 
 impl DataSourcePlan {
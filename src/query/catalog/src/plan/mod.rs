@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod agg_index;
+mod bitmap_index;
 mod datasource;
 mod internal_column;
 mod partition;
@@ -20,9 +21,11 @@ mod partition_statistics;
 mod projection;
 mod pruning_statistics;
 mod pushdown;
+mod spatial_index;
 mod stream_column;
 
 pub use agg_index::*;
+pub use bitmap_index::*;
 pub use datasource::*;
 pub use internal_column::*;
 pub use partition::*;
@@ -30,4 +33,5 @@ pub use partition_statistics::PartStatistics;
 pub use projection::Projection;
 pub use pruning_statistics::PruningStatistics;
 pub use pushdown::*;
+pub use spatial_index::*;
 pub use stream_column::*;
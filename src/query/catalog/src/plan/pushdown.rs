@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::DefaultHasher;
 use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::fmt::Debug;
+use std::hash::Hash;
+use std::hash::Hasher;
 
 use databend_common_ast::ast::SampleConfig;
 use databend_common_expression::types::DataType;
@@ -74,7 +77,7 @@ pub struct VirtualColumnField {
 /// Prewhere steps:
 ///
 /// 1. Read columns by `prewhere_columns`.
-/// 2. Filter data by `filter`.
+/// 2. Filter data by `filter`, or select rows by `row_selector_column` if it's set.
 /// 3. Read columns by `remain_columns`.
 /// 4. If virtual columns are required, generate them from the source columns.
 /// 5. Combine columns from step 1 and step 4, and prune columns to be `output_columns`.
@@ -95,6 +98,10 @@ pub struct PrewhereInfo {
     pub filter: RemoteExpr<String>,
     /// Optional prewhere virtual column ids
     pub virtual_column_ids: Option<Vec<u32>>,
+    /// Optional index in the [`TableSchema`] of a boolean column which already holds
+    /// the result of `filter`, such as a mask computed by an accelerator.
+    /// If it's set, the rows are selected by the column and `filter` is not evaluated.
+    pub row_selector_column: Option<usize>,
 }
 
 /// Inverted index option for additional search functions configuration.
@@ -276,4 +283,23 @@ impl PushDownInfo {
             None
         }
     }
+
+    /// The probability in `[0, 1]` and the seed of `TABLESAMPLE SYSTEM`.
+    /// The seed is fixed when the table is bound.
+    pub fn system_sample_of_push_downs(push_downs: &Option<PushDownInfo>) -> Option<(f64, u64)> {
+        let system = push_downs.as_ref()?.sample.as_ref()?.system.as_ref()?;
+        Some((system.probability / 100.0, system.seed.unwrap_or_default()))
+    }
+}
+
+/// Whether `TABLESAMPLE SYSTEM` keeps the block `block_id`. The decision only depends on
+/// the block and the seed, so the same seed keeps the same blocks wherever they're pruned.
+pub fn sample_block<T: Hash + ?Sized>(block_id: &T, probability: f64, seed: u64) -> bool {
+    if probability >= 1.0 {
+        return true;
+    }
+    let mut hasher = DefaultHasher::new();
+    block_id.hash(&mut hasher);
+    seed.hash(&mut hasher);
+    (hasher.finish() as f64) < probability * u64::MAX as f64
 }
@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_exception::Result;
+use databend_common_io::ewkb_bounding_rect;
+
+/// The bounding box of a geometry, in the coordinates of the geometry.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct BoundingBox {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl BoundingBox {
+    /// Returns the bounding box of an EWKB geometry, or `None` if the geometry is empty.
+    pub fn from_ewkb(ewkb: &[u8]) -> Result<Option<BoundingBox>> {
+        Ok(ewkb_bounding_rect(ewkb)?.map(|rect| BoundingBox {
+            min_x: rect.min().x,
+            min_y: rect.min().y,
+            max_x: rect.max().x,
+            max_y: rect.max().y,
+        }))
+    }
+
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+}
+
+impl Display for BoundingBox {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[({}, {}), ({}, {})]",
+            self.min_x, self.min_y, self.max_x, self.max_y
+        )
+    }
+}
+
+/// The relation between the geometries of a column and a constant geometry.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpatialRelation {
+    /// `ST_WITHIN(column, geometry)`
+    Within,
+    /// `ST_WITHIN(geometry, column)`
+    Contains,
+    /// `ST_INTERSECTS(column, geometry)`
+    Intersects,
+}
+
+impl Display for SpatialRelation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpatialRelation::Within => write!(f, "within"),
+            SpatialRelation::Contains => write!(f, "contains"),
+            SpatialRelation::Intersects => write!(f, "intersects"),
+        }
+    }
+}
+
+/// A lookup of the R-tree of a geometry column by the bounding box of a constant geometry.
+///
+/// The geometries matching any of the relations intersect the bounding box, so the lookup
+/// returns the blocks whose bounding boxes intersect it, the rows of the blocks still have
+/// to be evaluated by the exact predicate.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SpatialPredicate {
+    pub relation: SpatialRelation,
+    pub bbox: BoundingBox,
+}
+
+impl Display for SpatialPredicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.relation, self.bbox)
+    }
+}
+
+/// A spatial index of a geometry column of a table, e.g. an R-tree of the bounding boxes
+/// of the geometries in each block.
+pub trait SpatialIndex: Send + Sync {
+    /// Returns the locations of the blocks which may hold a geometry matching `predicate`.
+    fn candidate_blocks(&self, predicate: &SpatialPredicate) -> Result<HashSet<String>>;
+}
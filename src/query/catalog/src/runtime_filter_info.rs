@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_base::base::tokio::sync::watch;
 use databend_common_base::base::tokio::sync::watch::Receiver;
 use databend_common_base::base::tokio::sync::watch::Sender;
 use databend_common_expression::Expr;
+use parking_lot::Mutex;
 use xorf::BinaryFuse16;
 
 #[derive(Clone, Debug, Default)]
@@ -23,6 +26,18 @@ pub struct RuntimeFilterInfo {
     inlist: Vec<Expr<String>>,
     min_max: Vec<Expr<String>>,
     bloom: Vec<(String, BinaryFuse16)>,
+    bloom_stats: Vec<(String, Arc<Mutex<RuntimeFilterStats>>)>,
+}
+
+/// The statistics of a bloom runtime filter, shows how effective the filter is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RuntimeFilterStats {
+    /// The number of build rows the filter is built from.
+    pub build_rows: u64,
+    /// The number of probe rows filtered out by the filter.
+    pub filtered_rows: u64,
+    /// The number of probe rows passed the filter but not matched by the join.
+    pub false_positive_rows: u64,
 }
 
 impl RuntimeFilterInfo {
@@ -34,6 +49,10 @@ impl RuntimeFilterInfo {
         self.bloom.push(bloom);
     }
 
+    pub fn add_bloom_stats(&mut self, stats: (String, Arc<Mutex<RuntimeFilterStats>>)) {
+        self.bloom_stats.push(stats);
+    }
+
     pub fn add_min_max(&mut self, expr: Expr<String>) {
         self.min_max.push(expr);
     }
@@ -46,6 +65,10 @@ impl RuntimeFilterInfo {
         &self.bloom
     }
 
+    pub fn get_bloom_stats(&self) -> &Vec<(String, Arc<Mutex<RuntimeFilterStats>>)> {
+        &self.bloom_stats
+    }
+
     pub fn get_min_max(&self) -> &Vec<Expr<String>> {
         &self.min_max
     }
@@ -49,12 +49,14 @@ use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use databend_storages_common_table_meta::table_id_ranges::is_temp_table_id;
 
+use crate::plan::BitmapIndex;
 use crate::plan::DataSourceInfo;
 use crate::plan::DataSourcePlan;
 use crate::plan::PartStatistics;
 use crate::plan::Partitions;
 use crate::plan::PushDownInfo;
 use crate::plan::ReclusterParts;
+use crate::plan::SpatialIndex;
 use crate::plan::StreamColumn;
 use crate::statistics::BasicColumnStatistics;
 use crate::table_args::TableArgs;
@@ -197,6 +199,16 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// The spatial index of the geometry column `column_id`, if the table has one.
+    fn spatial_index(&self, _column_id: ColumnId) -> Option<Arc<dyn SpatialIndex>> {
+        None
+    }
+
+    /// The bitmap index of the column `column_id`, if the table has one.
+    fn bitmap_index(&self, _column_id: ColumnId) -> Option<Arc<dyn BitmapIndex>> {
+        None
+    }
+
     /// Gather partitions to be scanned according to the push_downs
     #[async_backtrace::framed]
     async fn read_partitions(
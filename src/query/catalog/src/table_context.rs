@@ -75,6 +75,7 @@ use crate::plan::Partitions;
 use crate::query_kind::QueryKind;
 use crate::runtime_filter_info::RuntimeFilterInfo;
 use crate::runtime_filter_info::RuntimeFilterReady;
+use crate::runtime_filter_info::RuntimeFilterStats;
 use crate::statistics::data_cache_statistics::DataCacheMetrics;
 use crate::table::Table;
 
@@ -181,6 +182,13 @@ pub trait TableContext: Send + Sync {
     fn get_compaction_num_block_hint(&self, _table_name: &str) -> u64 {
         unimplemented!()
     }
+    /// Append a block of the `RETURNING` columns evaluated on the inserted rows.
+    fn append_returning_block(&self, _block: DataBlock) {
+        unimplemented!()
+    }
+    fn get_returning_blocks(&self) -> Vec<DataBlock> {
+        unimplemented!()
+    }
 
     fn attach_query_str(&self, kind: QueryKind, query: String);
     fn attach_query_hash(&self, text_hash: String, parameterized_hash: String);
@@ -228,6 +236,9 @@ pub trait TableContext: Send + Sync {
         &self,
         ignore_ownership: bool,
     ) -> Result<GrantObjectVisibilityChecker>;
+    /// Get the row access policy of the table, a predicate the rows must satisfy
+    /// to be visible to the current query.
+    fn get_row_access_policy(&self, table: &dyn Table) -> Result<Option<String>>;
     fn get_fuse_version(&self) -> String;
     fn get_format_settings(&self) -> Result<FormatSettings>;
     fn get_tenant(&self) -> Tenant;
@@ -341,6 +352,14 @@ pub trait TableContext: Send + Sync {
 
     fn get_bloom_runtime_filter_with_id(&self, id: usize) -> Vec<(String, BinaryFuse16)>;
 
+    fn get_bloom_runtime_filter_stats_with_id(
+        &self,
+        id: usize,
+    ) -> Vec<(String, Arc<Mutex<RuntimeFilterStats>>)>;
+
+    /// Get the statistics of all the bloom runtime filters, keyed by the probe column name.
+    fn get_runtime_filter_stats(&self) -> Vec<(String, RuntimeFilterStats)>;
+
     fn get_inlist_runtime_filter_with_id(&self, id: usize) -> Vec<Expr<String>>;
 
     fn get_min_max_runtime_filter_with_id(&self, id: usize) -> Vec<Expr<String>>;
@@ -1451,6 +1451,20 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "8")]
     pub max_running_queries: u64,
 
+    /// The max number of the running queries of a user, 0 for no limit.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub max_running_queries_per_user: u64,
+
+    /// Persist the queries waiting in the queue to the meta store,
+    /// so that they keep their enqueue time across restarts.
+    #[clap(
+        long,
+        value_name = "VALUE",
+        value_parser = clap::value_parser!(bool),
+        default_value = "false"
+    )]
+    pub persist_queries_queue: bool,
+
     /// The max total memory in bytes that can be used by this process.
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub max_server_memory_usage: u64,
@@ -1781,6 +1795,8 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_tls_server_key: self.mysql_tls_server_key,
             max_active_sessions: self.max_active_sessions,
             max_running_queries: self.max_running_queries,
+            max_running_queries_per_user: self.max_running_queries_per_user,
+            persist_queries_queue: self.persist_queries_queue,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
             clickhouse_http_handler_host: self.clickhouse_http_handler_host,
@@ -1873,6 +1889,8 @@ impl From<InnerQueryConfig> for QueryConfig {
             mysql_tls_server_key: inner.mysql_tls_server_key,
             max_active_sessions: inner.max_active_sessions,
             max_running_queries: inner.max_running_queries,
+            max_running_queries_per_user: inner.max_running_queries_per_user,
+            persist_queries_queue: inner.persist_queries_queue,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
 
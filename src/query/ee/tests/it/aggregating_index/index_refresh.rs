@@ -239,6 +239,57 @@ async fn test_refresh_agg_index_with_limit() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_refresh_agg_index_partial() -> Result<()> {
+    let fixture = TestFixture::setup_with_custom(EESetup::new()).await?;
+
+    fixture
+        .execute_command("CREATE TABLE t2 (a int, b int, c int) storage_format = 'parquet'")
+        .await?;
+
+    // Each insertion generates a new block.
+    fixture
+        .execute_command("INSERT INTO t2 VALUES (1,1,4), (1,2,4)")
+        .await?;
+    fixture
+        .execute_command("INSERT INTO t2 VALUES (2,2,5), (2,3,5), (2,4,5)")
+        .await?;
+
+    let index_name = "index2";
+    let original_query = "SELECT b, SUM(a) from t2 WHERE c > 1 GROUP BY b";
+    let ctx = fixture.new_query_ctx().await?;
+    let query = rewrite_original_query(ctx, original_query)?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let index_id = create_index(ctx, index_name, original_query, query.as_str(), false).await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    refresh_index(ctx.clone(), index_name, None).await?;
+    assert_eq!(ctx.get_scan_progress_value().rows, 5);
+
+    // Nothing changed, nothing to read.
+    let ctx = fixture.new_query_ctx().await?;
+    refresh_index(ctx.clone(), index_name, None).await?;
+    assert_eq!(ctx.get_scan_progress_value().rows, 0);
+
+    // Only the second block is rewritten.
+    fixture
+        .execute_command("UPDATE t2 SET c = 6 WHERE a = 2")
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    refresh_index(ctx.clone(), index_name, None).await?;
+    assert_eq!(ctx.get_scan_progress_value().rows, 3);
+
+    let root = fixture.storage_root();
+    let blocks = collect_file_names(find_block_path(root)?.unwrap())?;
+    let indexes = collect_file_names(find_agg_index_path(root, index_id)?.unwrap())?;
+    assert_eq!(blocks.len(), 3);
+    assert_eq!(indexes.len(), 3);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_sync_agg_index() -> Result<()> {
     test_sync_agg_index_after_update().await?;
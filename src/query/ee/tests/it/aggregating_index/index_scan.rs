@@ -63,6 +63,11 @@ async fn test_index_scan_agg_args_are_expression() -> Result<()> {
     test_index_scan_agg_args_are_expression_impl("native").await
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_index_hint() -> Result<()> {
+    test_index_hint_impl("parquet").await
+}
+
 #[test]
 fn test_fuzz() -> Result<()> {
     let runtime = Runtime::with_worker_threads(2, None)?;
@@ -587,6 +592,129 @@ async fn test_index_scan_agg_args_are_expression_impl(format: &str) -> Result<()
     Ok(())
 }
 
+async fn explain_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<String> {
+    let blocks: Vec<DataBlock> = execute_sql(ctx, &format!("EXPLAIN {sql}"))
+        .await?
+        .try_collect()
+        .await?;
+    Ok(pretty_format_blocks(&blocks)?)
+}
+
+async fn test_index_hint_impl(format: &str) -> Result<()> {
+    let fixture = TestFixture::setup_with_custom(EESetup::new()).await?;
+
+    fixture
+        .execute_command(&format!(
+            "CREATE TABLE t (a int, b int, c int) storage_format = '{format}'"
+        ))
+        .await?;
+    fixture
+        .execute_command("INSERT INTO t VALUES (1,1,4), (1,2,1), (1,2,4), (2,2,5)")
+        .await?;
+
+    // Both indexes can answer the query.
+    let index_sqls = [
+        ("index1", "SELECT b, SUM(a) from t WHERE c > 1 GROUP BY b"),
+        (
+            "index2",
+            "SELECT b, MAX(a), SUM(a) from t WHERE c > 1 GROUP BY b",
+        ),
+    ];
+    for (index_name, index_sql) in index_sqls {
+        fixture
+            .execute_command(&format!(
+                "CREATE ASYNC AGGREGATING INDEX {index_name} AS {index_sql}"
+            ))
+            .await?;
+        fixture
+            .execute_command(&format!("REFRESH AGGREGATING INDEX {index_name}"))
+            .await?;
+    }
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1        | 1        |",
+        "| 2        | 3        |",
+        "+----------+----------+",
+    ];
+
+    // The optimizer only considers the hinted index.
+    for (index_name, index_sql) in index_sqls {
+        let sql =
+            format!("SELECT b, SUM(a) from t USE INDEX ({index_name}) WHERE c > 1 GROUP BY b");
+        let ctx = fixture.new_query_ctx().await?;
+        let explain = explain_sql(ctx.clone(), &sql).await?;
+        assert!(explain.contains(&format!("force index: {index_name}")));
+        assert!(explain.contains(&format!("aggregating index: [{index_sql}]")));
+        assert!(ctx.pop_warnings().is_empty());
+
+        let ctx = fixture.new_query_ctx().await?;
+        let plan = plan_sql(ctx.clone(), &sql).await?;
+        assert!(is_index_scan_plan(&plan));
+        expects_ok(
+            "Index hint",
+            execute_plan(ctx, &plan).await,
+            expected.clone(),
+        )
+        .await?;
+    }
+
+    // `FORCE INDEX` applies the index even if the index scans are disabled.
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings()
+        .set_setting("enable_aggregating_index_scan".to_string(), "0".to_string())?;
+    let sql = "SELECT b, SUM(a) from t WHERE c > 1 GROUP BY b";
+    assert!(!is_index_scan_plan(&plan_sql(ctx.clone(), sql).await?));
+    let plan = plan_sql(
+        ctx.clone(),
+        "SELECT b, SUM(a) from t FORCE INDEX (index2) WHERE c > 1 GROUP BY b",
+    )
+    .await?;
+    assert!(is_index_scan_plan(&plan));
+    expects_ok(
+        "Force index",
+        execute_plan(ctx.clone(), &plan).await,
+        expected,
+    )
+    .await?;
+    ctx.get_settings()
+        .set_setting("enable_aggregating_index_scan".to_string(), "1".to_string())?;
+
+    // The hinted index can't answer the query, the table is scanned with a warning.
+    let ctx = fixture.new_query_ctx().await?;
+    let explain = explain_sql(
+        ctx.clone(),
+        "SELECT b, MIN(c) from t USE INDEX (index1) GROUP BY b",
+    )
+    .await?;
+    assert!(!explain.contains("force index"));
+    assert!(!explain.contains("aggregating index"));
+    let warnings = ctx.pop_warnings();
+    assert!(warnings.iter().any(
+        |warning| warning.contains("USE INDEX (index1)") && warning.contains("not applicable")
+    ));
+
+    // The unknown indexes of the hint are ignored with a warning.
+    let ctx = fixture.new_query_ctx().await?;
+    let explain = explain_sql(
+        ctx.clone(),
+        "SELECT b, SUM(a) from t USE INDEX (index3, index2) WHERE c > 1 GROUP BY b",
+    )
+    .await?;
+    assert!(explain.contains("force index: index2"));
+    let warnings = ctx.pop_warnings();
+    assert!(warnings
+        .iter()
+        .any(|warning| warning.contains("unknown aggregating index 'index3'")));
+
+    for (index_name, _) in index_sqls {
+        drop_index(fixture.new_query_ctx().await?, index_name).await?;
+    }
+
+    Ok(())
+}
+
 fn is_index_scan_plan(plan: &Plan) -> bool {
     if let Plan::Query { s_expr, .. } = plan {
         is_index_scan_sexpr(s_expr.as_ref())
@@ -1041,10 +1169,7 @@ async fn test_fuzz_impl(format: &str, spill: bool) -> Result<()> {
                 "query_out_of_memory_behavior".to_string(),
                 "spilling".to_string(),
             ),
-            (
-                "max_query_memory_usage".to_string(),
-                "1".to_string(),
-            ),
+            ("max_query_memory_usage".to_string(), "1".to_string()),
         ]))
     } else {
         None
@@ -0,0 +1,147 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_common_expression::block_debug::pretty_format_blocks;
+use databend_common_expression::DataBlock;
+use databend_common_meta_app::principal::AuthInfo;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_sql::Planner;
+use databend_enterprise_query::test_kits::context::EESetup;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::SessionType;
+use databend_query::test_kits::*;
+use futures_util::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_mask_apply() -> Result<()> {
+    let fixture = TestFixture::setup_with_custom(EESetup::new()).await?;
+
+    fixture
+        .execute_command("CREATE TABLE t (id int, email string)")
+        .await?;
+    fixture
+        .execute_command("INSERT INTO t VALUES (1, 'a@databend.com'), (2, 'b@databend.com')")
+        .await?;
+    fixture
+        .execute_command(
+            "CREATE MASKING POLICY email_mask AS (val string) RETURNS string -> '*****'",
+        )
+        .await?;
+    fixture
+        .execute_command("ALTER TABLE t MODIFY COLUMN email SET MASKING POLICY email_mask")
+        .await?;
+
+    let sql = "SELECT id, email FROM t ORDER BY id";
+
+    // A user without the `UNMASKED` privilege sees the masked values.
+    let ctx = new_query_ctx(&fixture, None).await?;
+    let explain = execute_sql(ctx.clone(), &format!("EXPLAIN {sql}")).await?;
+    assert!(pretty_format_blocks(&explain)?.contains("MaskApply"));
+    let expect = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1        | '*****'  |",
+        "| 2        | '*****'  |",
+        "+----------+----------+",
+    ];
+    assert_blocks_eq(expect, &execute_sql(ctx, sql).await?);
+
+    // A user granted `UNMASKED` on the table sees the raw values.
+    let table = GrantObject::Table(
+        "default".to_string(),
+        "default".to_string(),
+        "t".to_string(),
+    );
+    let ctx = new_query_ctx(&fixture, Some(table)).await?;
+    let explain = execute_sql(ctx.clone(), &format!("EXPLAIN {sql}")).await?;
+    assert!(!pretty_format_blocks(&explain)?.contains("MaskApply"));
+    let expect = vec![
+        "+----------+------------------+",
+        "| Column 0 | Column 1         |",
+        "+----------+------------------+",
+        "| 1        | 'a@databend.com' |",
+        "| 2        | 'b@databend.com' |",
+        "+----------+------------------+",
+    ];
+    assert_blocks_eq(expect, &execute_sql(ctx, sql).await?);
+
+    // `UNMASKED` on the database covers its tables.
+    let database = GrantObject::Database("default".to_string(), "default".to_string());
+    let ctx = new_query_ctx(&fixture, Some(database)).await?;
+    let explain = execute_sql(ctx, &format!("EXPLAIN {sql}")).await?;
+    assert!(!pretty_format_blocks(&explain)?.contains("MaskApply"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_grant_unmasked() -> Result<()> {
+    let fixture = TestFixture::setup_with_custom(EESetup::new()).await?;
+
+    fixture.execute_command("CREATE TABLE t (id int)").await?;
+    fixture.execute_command("CREATE ROLE r1").await?;
+    fixture
+        .execute_command("GRANT UNMASKED ON default.t TO ROLE r1")
+        .await?;
+    fixture
+        .execute_command("GRANT UNMASKED ON default.* TO ROLE r1")
+        .await?;
+
+    // `UNMASKED` can not be granted globally, and is not a part of `ALL`.
+    assert!(fixture
+        .execute_command("GRANT UNMASKED ON *.* TO ROLE r1")
+        .await
+        .is_err());
+    assert!(!UserPrivilegeSet::available_privileges_on_global()
+        .has_privilege(UserPrivilegeType::Unmasked));
+
+    Ok(())
+}
+
+/// Create a query context for the root user, who is also granted `UNMASKED` on `unmasked`.
+async fn new_query_ctx(
+    fixture: &TestFixture,
+    unmasked: Option<GrantObject>,
+) -> Result<Arc<QueryContext>> {
+    let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    let mut user_info = UserInfo::new("root", "%", AuthInfo::None);
+    user_info.grants.grant_privileges(
+        &GrantObject::Global,
+        UserPrivilegeSet::available_privileges_on_global(),
+    );
+    if let Some(object) = unmasked {
+        user_info
+            .grants
+            .grant_privileges(&object, UserPrivilegeType::Unmasked.into());
+    }
+    session.set_authed_user(user_info, None).await?;
+    session.create_query_context().await
+}
+
+async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<Vec<DataBlock>> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    interpreter.execute(ctx).await?.try_collect().await
+}
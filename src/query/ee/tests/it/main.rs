@@ -14,6 +14,7 @@
 
 #![feature(unwrap_infallible)]
 mod aggregating_index;
+mod data_mask;
 mod inverted_index;
 mod license;
 mod storages;
@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow_flight::decode::FlightRecordBatchStream;
+use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::Ticket;
+use databend_common_base::version::DATABEND_SEMVER;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use tonic::transport::channel::Channel;
+use tonic::transport::Endpoint;
+
+use crate::DataSchema;
+
+/// The user name and password sent in the handshake with a Flight SQL service.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct AuthCredentials {
+    pub username: String,
+    pub password: String,
+}
+
+/// A client to run queries on a remote Arrow Flight SQL service.
+#[derive(Debug, Clone)]
+pub struct FlightSqlClient {
+    endpoint: String,
+    inner: FlightSqlServiceClient<Channel>,
+}
+
+impl FlightSqlClient {
+    #[async_backtrace::framed]
+    pub async fn connect(endpoint: &str, auth: Option<&AuthCredentials>) -> Result<Self> {
+        let channel = Endpoint::from_shared(endpoint.to_string())
+            .map_err(|err| {
+                ErrorCode::BadArguments(format!("Invalid Flight SQL endpoint {endpoint}: {err}"))
+            })?
+            .user_agent(format!("databend-query/{}", *DATABEND_SEMVER))
+            .map_err(|err| {
+                ErrorCode::BadArguments(format!("Invalid Flight SQL client user agent: {err}"))
+            })?
+            .connect()
+            .await
+            .map_err(|err| {
+                ErrorCode::CannotConnectNode(format!(
+                    "Cannot connect to Flight SQL service {endpoint}: {err}"
+                ))
+            })?;
+
+        let mut inner = FlightSqlServiceClient::new(channel);
+        if let Some(auth) = auth {
+            inner
+                .handshake(&auth.username, &auth.password)
+                .await
+                .map_err(|err| {
+                    ErrorCode::AuthenticateFailure(format!(
+                        "Flight SQL handshake with {endpoint} failed: {err}"
+                    ))
+                })?;
+        }
+        Ok(FlightSqlClient {
+            endpoint: endpoint.to_string(),
+            inner,
+        })
+    }
+
+    /// Returns the schema of the result of `query` without running it.
+    #[async_backtrace::framed]
+    pub async fn query_schema(&mut self, query: &str) -> Result<DataSchema> {
+        let statement = self
+            .inner
+            .prepare(query.to_string(), None)
+            .await
+            .map_err(|err| self.query_error(query, err))?;
+        let schema = statement
+            .dataset_schema()
+            .map_err(|err| self.query_error(query, err))?;
+        DataSchema::try_from(schema)
+    }
+
+    /// Run `query` and return the tickets to fetch the result from.
+    #[async_backtrace::framed]
+    pub async fn execute(&mut self, query: &str) -> Result<Vec<Ticket>> {
+        let flight_info = self
+            .inner
+            .execute(query.to_string(), None)
+            .await
+            .map_err(|err| self.query_error(query, err))?;
+        Ok(flight_info
+            .endpoint
+            .into_iter()
+            .filter_map(|endpoint| endpoint.ticket)
+            .collect())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn do_get(&mut self, ticket: Ticket) -> Result<FlightRecordBatchStream> {
+        self.inner.do_get(ticket).await.map_err(|err| {
+            ErrorCode::Internal(format!(
+                "Fetch result from Flight SQL service {} failed: {err}",
+                self.endpoint
+            ))
+        })
+    }
+
+    fn query_error(&self, query: &str, err: arrow_schema::ArrowError) -> ErrorCode {
+        ErrorCode::Internal(format!(
+            "Query {query:?} on Flight SQL service {} failed: {err}",
+            self.endpoint
+        ))
+    }
+}
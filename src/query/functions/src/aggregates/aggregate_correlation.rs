@@ -0,0 +1,272 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::Number;
+use databend_common_expression::types::number::F64;
+use databend_common_expression::types::Bitmap;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::AggrStateRegistry;
+use databend_common_expression::AggrStateType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::InputColumns;
+use databend_common_expression::Scalar;
+use num_traits::AsPrimitive;
+
+use super::borsh_deserialize_state;
+use super::borsh_serialize_state;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionSortDesc;
+use crate::aggregates::aggregator_common::assert_binary_arguments;
+use crate::aggregates::AggrState;
+use crate::aggregates::AggrStateLoc;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// Running moments of a pair of columns, updated with Welford's online algorithm.
+///
+/// The state is shared by the `corr` aggregate function and the `Correlation`
+/// physical operator, so both produce the same result for the same input.
+#[derive(BorshSerialize, BorshDeserialize, Default, Clone, Debug)]
+pub struct AggregateCorrelationState {
+    pub count: u64,
+    pub left_mean: f64,
+    pub right_mean: f64,
+    pub left_m2: f64,
+    pub right_m2: f64,
+    pub co_moments: f64,
+}
+
+impl AggregateCorrelationState {
+    #[inline(always)]
+    pub fn add(&mut self, s: f64, t: f64) {
+        let left_delta = s - self.left_mean;
+        let right_delta = t - self.right_mean;
+
+        self.count += 1;
+        self.left_mean += left_delta / self.count as f64;
+        self.right_mean += right_delta / self.count as f64;
+
+        self.left_m2 += left_delta * (s - self.left_mean);
+        self.right_m2 += right_delta * (t - self.right_mean);
+        self.co_moments += left_delta * (t - self.right_mean);
+    }
+
+    // Same pairwise update as `AggregateCovarianceState::merge`, applied to
+    // the second moments of both sides as well.
+    #[inline(always)]
+    pub fn merge(&mut self, other: &Self) {
+        let total = self.count + other.count;
+        if total == 0 {
+            return;
+        }
+
+        let factor = self.count as f64 * other.count as f64 / total as f64;
+        let left_delta = other.left_mean - self.left_mean;
+        let right_delta = other.right_mean - self.right_mean;
+
+        self.left_m2 += other.left_m2 + left_delta * left_delta * factor;
+        self.right_m2 += other.right_m2 + right_delta * right_delta * factor;
+        self.co_moments += other.co_moments + left_delta * right_delta * factor;
+
+        self.left_mean += left_delta * other.count as f64 / total as f64;
+        self.right_mean += right_delta * other.count as f64 / total as f64;
+        self.count = total;
+    }
+
+    /// Pearson correlation coefficient, `None` if it is undefined for the
+    /// rows seen so far (fewer than two rows or a constant column).
+    #[inline(always)]
+    pub fn result(&self) -> Option<f64> {
+        if self.count < 2 || self.left_m2 == 0.0 || self.right_m2 == 0.0 {
+            return None;
+        }
+        Some(self.co_moments / (self.left_m2 * self.right_m2).sqrt())
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateCorrelationFunction<T0, T1> {
+    display_name: String,
+    _t0: PhantomData<T0>,
+    _t1: PhantomData<T1>,
+}
+
+impl<T0, T1> AggregateFunction for AggregateCorrelationFunction<T0, T1>
+where
+    T0: Number + AsPrimitive<f64>,
+    T1: Number + AsPrimitive<f64>,
+{
+    fn name(&self) -> &str {
+        "AggregateCorrelationFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Number(NumberDataType::Float64))
+    }
+
+    fn init_state(&self, place: AggrState) {
+        place.write(AggregateCorrelationState::default);
+    }
+
+    fn register_state(&self, registry: &mut AggrStateRegistry) {
+        registry.register(AggrStateType::Custom(Layout::new::<
+            AggregateCorrelationState,
+        >()));
+    }
+
+    fn accumulate(
+        &self,
+        place: AggrState,
+        columns: InputColumns,
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateCorrelationState>();
+        let left = NumberType::<T0>::try_downcast_column(&columns[0]).unwrap();
+        let right = NumberType::<T1>::try_downcast_column(&columns[1]).unwrap();
+
+        match validity {
+            Some(bitmap) => {
+                left.iter().zip(right.iter()).zip(bitmap.iter()).for_each(
+                    |((left_val, right_val), valid)| {
+                        if valid {
+                            state.add(left_val.as_(), right_val.as_());
+                        }
+                    },
+                );
+            }
+            None => {
+                left.iter()
+                    .zip(right.iter())
+                    .for_each(|(left_val, right_val)| {
+                        state.add(left_val.as_(), right_val.as_());
+                    });
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        loc: &[AggrStateLoc],
+        columns: InputColumns,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let left = NumberType::<T0>::try_downcast_column(&columns[0]).unwrap();
+        let right = NumberType::<T1>::try_downcast_column(&columns[1]).unwrap();
+
+        left.iter().zip(right.iter()).zip(places.iter()).for_each(
+            |((left_val, right_val), place)| {
+                let state = AggrState::new(*place, loc).get::<AggregateCorrelationState>();
+                state.add(left_val.as_(), right_val.as_());
+            },
+        );
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: AggrState, columns: InputColumns, row: usize) -> Result<()> {
+        let left = NumberType::<T0>::try_downcast_column(&columns[0]).unwrap();
+        let right = NumberType::<T1>::try_downcast_column(&columns[1]).unwrap();
+
+        let left_val = unsafe { left.get_unchecked(row) };
+        let right_val = unsafe { right.get_unchecked(row) };
+
+        let state = place.get::<AggregateCorrelationState>();
+        state.add(left_val.as_(), right_val.as_());
+        Ok(())
+    }
+
+    fn serialize(&self, place: AggrState, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateCorrelationState>();
+        borsh_serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: AggrState, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateCorrelationState>();
+        let rhs: AggregateCorrelationState = borsh_deserialize_state(reader)?;
+        state.merge(&rhs);
+        Ok(())
+    }
+
+    fn merge_states(&self, place: AggrState, rhs: AggrState) -> Result<()> {
+        let state = place.get::<AggregateCorrelationState>();
+        let other = rhs.get::<AggregateCorrelationState>();
+        state.merge(other);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: AggrState, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateCorrelationState>();
+        let builder = NumberType::<F64>::try_downcast_builder(builder).unwrap();
+        builder.push(state.result().unwrap_or(f64::NAN).into());
+        Ok(())
+    }
+}
+
+impl<T0, T1> fmt::Display for AggregateCorrelationFunction<T0, T1> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_correlation(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+    _sort_descs: Vec<AggregateFunctionSortDesc>,
+) -> Result<AggregateFunctionRef> {
+    assert_binary_arguments(display_name, arguments.len())?;
+
+    with_number_mapped_type!(|NUM_TYPE0| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE0) =>
+            with_number_mapped_type!(|NUM_TYPE1| match &arguments[1] {
+                DataType::Number(NumberDataType::NUM_TYPE1) => {
+                    return Ok(Arc::new(AggregateCorrelationFunction::<
+                        NUM_TYPE0,
+                        NUM_TYPE1,
+                    > {
+                        display_name: display_name.to_string(),
+                        _t0: PhantomData,
+                        _t1: PhantomData,
+                    }));
+                }
+                _ => (),
+            }),
+        _ => (),
+    });
+
+    Err(ErrorCode::BadDataValueType(format!(
+        "Expected number data type, but got {:?}",
+        arguments
+    )))
+}
+
+pub fn aggregate_correlation_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_correlation))
+}
@@ -55,8 +55,12 @@ use crate::BUILTIN_FUNCTIONS;
 pub(crate) const MEDIAN: u8 = 0;
 pub(crate) const QUANTILE: u8 = 1;
 
+/// A t-digest sketch of the values added to it.
+///
+/// The state is shared by the `quantile_tdigest` aggregate function and the
+/// `TDigestAgg` physical operator.
 #[derive(BorshSerialize, BorshDeserialize)]
-pub(crate) struct QuantileTDigestState {
+pub struct QuantileTDigestState {
     epsilon: u32,
     max_centroids: usize,
 
@@ -73,8 +77,13 @@ pub(crate) struct QuantileTDigestState {
 
 impl QuantileTDigestState {
     pub(crate) fn new() -> Self {
+        Self::with_compression(100f64)
+    }
+
+    /// A larger compression keeps more centroids, which makes the quantiles more accurate.
+    pub fn with_compression(compression: f64) -> Self {
         Self {
-            epsilon: 100u32,
+            epsilon: compression.max(1f64) as u32,
             max_centroids: 2048,
             total_weight: 0f64,
             weights: vec![],
@@ -87,7 +96,7 @@ impl QuantileTDigestState {
         }
     }
 
-    pub(crate) fn add(&mut self, other: f64, weight: Option<u64>) {
+    pub fn add(&mut self, other: f64, weight: Option<u64>) {
         if self.unmerged_weights.len() + self.weights.len() >= self.max_centroids - 1 {
             self.compress();
         }
@@ -135,7 +144,7 @@ impl QuantileTDigestState {
         Ok(())
     }
 
-    pub(crate) fn quantile(&mut self, level: f64) -> f64 {
+    pub fn quantile(&mut self, level: f64) -> f64 {
         self.compress();
         if self.weights.is_empty() {
             return 0f64;
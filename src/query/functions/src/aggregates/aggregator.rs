@@ -26,6 +26,7 @@ use super::aggregate_bitmap::aggregate_bitmap_xor_count_function_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_distinct_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_uniq_desc;
 use super::aggregate_combinator_state::AggregateStateCombinator;
+use super::aggregate_correlation::aggregate_correlation_desc;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
 use super::aggregate_covariance::aggregate_covariance_sample_desc;
 use super::aggregate_markov_tarin::aggregate_markov_train_function_desc;
@@ -76,6 +77,7 @@ impl Aggregators {
         factory.register("arg_min", aggregate_arg_min_function_desc());
         factory.register("arg_max", aggregate_arg_max_function_desc());
 
+        factory.register("corr", aggregate_correlation_desc());
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
         factory.register("stddev_samp", aggregate_stddev_samp_function_desc());
@@ -89,6 +91,10 @@ impl Aggregators {
             "quantile_tdigest",
             aggregate_quantile_tdigest_function_desc(),
         );
+        factory.register(
+            "approx_percentile_cont",
+            aggregate_quantile_tdigest_function_desc(),
+        );
         factory.register(
             "quantile_tdigest_weighted",
             aggregate_quantile_tdigest_weighted_function_desc(),
@@ -26,6 +26,7 @@ mod aggregate_bitmap;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
 mod aggregate_combinator_state;
+mod aggregate_correlation;
 mod aggregate_covariance;
 mod aggregate_distinct_state;
 mod aggregate_histogram;
@@ -60,6 +61,7 @@ pub use aggregate_array_agg::*;
 pub use aggregate_array_moving::*;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
+pub use aggregate_correlation::AggregateCorrelationState;
 pub use aggregate_count::AggregateCountFunction;
 pub use aggregate_covariance::AggregateCovarianceFunction;
 pub use aggregate_function::*;
@@ -70,6 +70,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     registry.register_aliases("try_inet_ntoa", &["try_ipv4_num_to_string"]);
     registry.register_aliases("assume_not_null", &["remove_nullable"]);
     registry.register_aliases("gen_random_uuid", &["uuid"]);
+    registry.register_aliases("grouping", &["grouping_id"]);
 
     register_inet_aton(registry);
     register_inet_ntoa(registry);
@@ -16,6 +16,7 @@ mod array;
 mod variant;
 
 use databend_common_expression::FunctionRegistry;
+pub use variant::json_each_entries;
 
 pub fn register(registry: &mut FunctionRegistry) {
     array::register(registry);
@@ -643,29 +643,46 @@ fn unnest_variant_obj(
     row: usize,
     max_nums_per_row: &mut [usize],
 ) -> (Value<AnyType>, usize) {
-    match RawJsonb::new(val).object_each() {
-        Ok(Some(key_vals)) if !key_vals.is_empty() => {
-            let len = key_vals.len();
-            let mut val_builder = BinaryColumnBuilder::with_capacity(0, 0);
-            let mut key_builder = StringColumnBuilder::with_capacity(0);
+    let entries = json_each_entries(val);
+    if entries.is_empty() {
+        return (
+            Value::Scalar(Scalar::Tuple(vec![Scalar::Null, Scalar::Null])),
+            0,
+        );
+    }
 
-            max_nums_per_row[row] = std::cmp::max(max_nums_per_row[row], len);
+    let len = entries.len();
+    let mut val_builder = BinaryColumnBuilder::with_capacity(0, 0);
+    let mut key_builder = StringColumnBuilder::with_capacity(0);
 
-            for (key, val) in key_vals {
-                key_builder.put_and_commit(&key);
-                val_builder.put_slice(val.as_ref());
-                val_builder.commit_row();
-            }
+    max_nums_per_row[row] = std::cmp::max(max_nums_per_row[row], len);
+
+    for (key, val) in entries {
+        key_builder.put_and_commit(&key);
+        val_builder.put_slice(val.as_ref());
+        val_builder.commit_row();
+    }
 
-            let key_col = Column::String(key_builder.build()).wrap_nullable(None);
-            let val_col = Column::Variant(val_builder.build()).wrap_nullable(None);
+    let key_col = Column::String(key_builder.build()).wrap_nullable(None);
+    let val_col = Column::Variant(val_builder.build()).wrap_nullable(None);
 
-            (Value::Column(Column::Tuple(vec![key_col, val_col])), len)
-        }
-        _ => (
-            Value::Scalar(Scalar::Tuple(vec![Scalar::Null, Scalar::Null])),
-            0,
-        ),
+    (Value::Column(Column::Tuple(vec![key_col, val_col])), len)
+}
+
+/// The `(key, value)` pairs of `json_each`. The keys of an array are the indexes
+/// of the elements, a scalar value has no pairs.
+pub fn json_each_entries(val: &[u8]) -> Vec<(String, OwnedJsonb)> {
+    let value = RawJsonb::new(val);
+    if let Ok(Some(key_vals)) = value.object_each() {
+        return key_vals;
+    }
+    match value.array_values() {
+        Ok(Some(vals)) => vals
+            .into_iter()
+            .enumerate()
+            .map(|(index, val)| (index.to_string(), val))
+            .collect(),
+        _ => vec![],
     }
 }
 
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use std::sync::Arc;
+
+use criterion::Criterion;
+use databend_common_base::base::tokio;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::FromData;
+use databend_common_expression::SortColumnDescription;
+use databend_common_pipeline_transforms::processors::parallel_sort;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+// Compare the partial sort of a large block in the current thread and with 8 tasks,
+// the parallel sort is expected to be several times faster on an 8-core machine.
+fn bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallel_sort");
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let num_rows = 1_000_000;
+    let mut rng = StdRng::seed_from_u64(0);
+    let data = (0..num_rows)
+        .map(|_| rng.gen_range(i64::MIN..i64::MAX))
+        .collect::<Vec<_>>();
+    let block = DataBlock::new_from_columns(vec![Int64Type::from_data(data)]);
+    let schema = DataSchemaRefExt::create(vec![DataField::new(
+        "a",
+        DataType::Number(NumberDataType::Int64),
+    )]);
+    let sort_desc = Arc::new(vec![SortColumnDescription {
+        offset: 0,
+        asc: true,
+        nulls_first: false,
+    }]);
+
+    for parallelism in [1, 8] {
+        group.bench_function(format!("sort_{num_rows}/{parallelism}"), |b| {
+            b.iter(|| {
+                runtime
+                    .block_on(parallel_sort(
+                        schema.clone(),
+                        block.clone(),
+                        sort_desc.clone(),
+                        None,
+                        parallelism,
+                    ))
+                    .unwrap()
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
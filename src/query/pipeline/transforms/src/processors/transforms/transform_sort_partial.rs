@@ -14,17 +14,30 @@
 
 use std::sync::Arc;
 
+use databend_common_base::runtime::spawn_blocking;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
 use databend_common_expression::LimitType;
+use databend_common_expression::RowConverter as CommonRowConverter;
 use databend_common_expression::SortColumnDescription;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 
+use crate::processors::sort::RowConverter;
+use crate::processors::sort::Rows;
+use crate::processors::transforms::sort_merge;
+use crate::processors::transforms::AsyncTransform;
+use crate::processors::transforms::AsyncTransformer;
 use crate::processors::transforms::Transform;
 use crate::processors::transforms::Transformer;
 
+/// Each parallel sort task sorts at least this number of rows,
+/// smaller blocks are sorted in the current thread.
+const PARALLEL_SORT_MIN_ROWS: usize = 8192;
+
 pub struct TransformSortPartial {
     limit: LimitType,
     sort_columns_descriptions: Arc<Vec<SortColumnDescription>>,
@@ -62,3 +75,168 @@ impl Transform for TransformSortPartial {
         DataBlock::sort_with_type(&block, &self.sort_columns_descriptions, self.limit)
     }
 }
+
+/// Partial sort for the blocks which are expected to be sorted already, e.g. the blocks
+/// of a table clustered by the sort keys.
+///
+/// A block is only sorted if it's actually not sorted, such as a block written before
+/// the cluster key is defined.
+pub struct TransformSortedPartial {
+    limit: LimitType,
+    sort_columns_descriptions: Arc<Vec<SortColumnDescription>>,
+    converter: CommonRowConverter,
+}
+
+impl TransformSortedPartial {
+    pub fn try_new(
+        schema: DataSchemaRef,
+        limit: Option<usize>,
+        sort_columns_descriptions: Arc<Vec<SortColumnDescription>>,
+    ) -> Result<Self> {
+        let converter = CommonRowConverter::create(&sort_columns_descriptions, schema)?;
+        Ok(Self {
+            limit: LimitType::from_limit_rows(limit),
+            sort_columns_descriptions,
+            converter,
+        })
+    }
+
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        limit: Option<usize>,
+        sort_columns_descriptions: Arc<Vec<SortColumnDescription>>,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(Transformer::create(
+            input,
+            output,
+            Self::try_new(schema, limit, sort_columns_descriptions)?,
+        ))
+    }
+
+    fn is_sorted(&mut self, block: &DataBlock) -> Result<bool> {
+        let columns = self
+            .sort_columns_descriptions
+            .iter()
+            .map(|desc| block.get_by_offset(desc.offset).clone())
+            .collect::<Vec<_>>();
+        let rows = self.converter.convert(&columns, block.num_rows())?;
+        Ok((1..rows.len()).all(|i| rows.row(i - 1) <= rows.row(i)))
+    }
+}
+
+impl Transform for TransformSortedPartial {
+    const NAME: &'static str = "SortedPartialTransform";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        if !self.is_sorted(&block)? {
+            return DataBlock::sort_with_type(&block, &self.sort_columns_descriptions, self.limit);
+        }
+        match self.limit {
+            LimitType::LimitRows(limit) if limit < block.num_rows() => Ok(block.slice(0..limit)),
+            _ => Ok(block),
+        }
+    }
+}
+
+/// Partial sort which sorts a large block with `parallelism` blocking tasks.
+///
+/// The block is split into parts which are sorted in parallel, and the sorted parts
+/// are merged by a loser tree (tournament tree).
+pub struct TransformParallelSortPartial {
+    schema: DataSchemaRef,
+    limit: Option<usize>,
+    sort_columns_descriptions: Arc<Vec<SortColumnDescription>>,
+    parallelism: usize,
+}
+
+impl TransformParallelSortPartial {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        limit: Option<usize>,
+        sort_columns_descriptions: Arc<Vec<SortColumnDescription>>,
+        parallelism: usize,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(AsyncTransformer::create(
+            input,
+            output,
+            TransformParallelSortPartial {
+                schema,
+                limit,
+                sort_columns_descriptions,
+                parallelism,
+            },
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformParallelSortPartial {
+    const NAME: &'static str = "ParallelSortPartialTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        parallel_sort(
+            self.schema.clone(),
+            block,
+            self.sort_columns_descriptions.clone(),
+            self.limit,
+            self.parallelism,
+        )
+        .await
+    }
+}
+
+/// Sort `block` with at most `parallelism` blocking tasks, the result is the same as
+/// `DataBlock::sort_with_type` with `LimitType::from_limit_rows(limit)`.
+#[async_backtrace::framed]
+pub async fn parallel_sort(
+    schema: DataSchemaRef,
+    block: DataBlock,
+    sort_desc: Arc<Vec<SortColumnDescription>>,
+    limit: Option<usize>,
+    parallelism: usize,
+) -> Result<DataBlock> {
+    let num_rows = block.num_rows();
+    let num_tasks = parallelism.min(num_rows / PARALLEL_SORT_MIN_ROWS);
+    if num_tasks <= 1 {
+        return DataBlock::sort_with_type(&block, &sort_desc, LimitType::from_limit_rows(limit));
+    }
+
+    let rows_per_task = num_rows.div_ceil(num_tasks);
+    let mut handles = Vec::with_capacity(num_tasks);
+    for start in (0..num_rows).step_by(rows_per_task) {
+        let part = block.slice(start..num_rows.min(start + rows_per_task));
+        let sort_desc = sort_desc.clone();
+        handles.push(spawn_blocking(move || {
+            DataBlock::sort_with_type(&part, &sort_desc, LimitType::from_limit_rows(limit))
+        }));
+    }
+
+    let mut parts = Vec::with_capacity(handles.len());
+    for handle in handles {
+        let part = handle
+            .await
+            .map_err(|e| ErrorCode::Internal(format!("Parallel sort task failed: {}", e)))??;
+        parts.push(part);
+    }
+
+    // The spilling is disabled in `sort_merge`, so the spilling batch bytes is not used.
+    let merged = sort_merge(
+        schema,
+        num_rows,
+        sort_desc.to_vec(),
+        parts,
+        usize::MAX,
+        true,
+        false,
+    )?;
+    let block = DataBlock::concat(&merged)?;
+    match limit {
+        Some(limit) if limit < block.num_rows() => Ok(block.slice(0..limit)),
+        _ => Ok(block),
+    }
+}
@@ -13,3 +13,5 @@
 // limitations under the License.
 
 mod merger;
+mod parallel_sort;
+mod sorted_partial;
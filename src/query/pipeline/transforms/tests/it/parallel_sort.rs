@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::FromData;
+use databend_common_expression::LimitType;
+use databend_common_expression::SortColumnDescription;
+use databend_common_pipeline_transforms::processors::parallel_sort;
+use rand::Rng;
+
+fn random_block(num_rows: usize) -> DataBlock {
+    let mut rng = rand::thread_rng();
+    let a = (0..num_rows)
+        .map(|_| rng.gen_range(0..1000))
+        .collect::<Vec<i32>>();
+    let b = (0..num_rows)
+        .map(|_| format!("{}", rng.gen_range(0..100)))
+        .collect::<Vec<_>>();
+    DataBlock::new_from_columns(vec![Int32Type::from_data(a), StringType::from_data(b)])
+}
+
+async fn check_parallel_sort(
+    num_rows: usize,
+    limit: Option<usize>,
+    parallelism: usize,
+) -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Number(NumberDataType::Int32)),
+        DataField::new("b", DataType::String),
+    ]);
+    let sort_desc = Arc::new(vec![
+        SortColumnDescription {
+            offset: 0,
+            asc: true,
+            nulls_first: false,
+        },
+        SortColumnDescription {
+            offset: 1,
+            asc: false,
+            nulls_first: false,
+        },
+    ]);
+
+    let block = random_block(num_rows);
+    let expected =
+        DataBlock::sort_with_type(&block, &sort_desc, LimitType::from_limit_rows(limit))?;
+    let actual = parallel_sort(schema, block, sort_desc, limit, parallelism).await?;
+
+    assert_eq!(expected.num_rows(), actual.num_rows());
+    for (expected, actual) in expected.columns().iter().zip(actual.columns()) {
+        assert_eq!(expected.value, actual.value);
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_parallel_sort() -> Result<()> {
+    // Small blocks are sorted in the current thread.
+    check_parallel_sort(100, None, 8).await?;
+    check_parallel_sort(100_000, None, 1).await?;
+
+    check_parallel_sort(100_000, None, 8).await?;
+    check_parallel_sort(100_001, None, 3).await?;
+    check_parallel_sort(100_000, Some(10), 8).await?;
+    check_parallel_sort(100_000, Some(50_000), 4).await?;
+    Ok(())
+}
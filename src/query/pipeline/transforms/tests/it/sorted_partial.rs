@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::FromData;
+use databend_common_expression::LimitType;
+use databend_common_expression::SortColumnDescription;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_pipeline_transforms::processors::TransformSortedPartial;
+
+fn check_sorted_partial(a: Vec<Option<i32>>, b: Vec<i32>, limit: Option<usize>) -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Number(NumberDataType::Int32).wrap_nullable()),
+        DataField::new("b", DataType::Number(NumberDataType::Int32)),
+    ]);
+    let sort_desc = Arc::new(vec![
+        SortColumnDescription {
+            offset: 0,
+            asc: true,
+            nulls_first: false,
+        },
+        SortColumnDescription {
+            offset: 1,
+            asc: true,
+            nulls_first: false,
+        },
+    ]);
+
+    let block =
+        DataBlock::new_from_columns(vec![Int32Type::from_opt_data(a), Int32Type::from_data(b)]);
+    let expected =
+        DataBlock::sort_with_type(&block, &sort_desc, LimitType::from_limit_rows(limit))?;
+    let mut transform = TransformSortedPartial::try_new(schema, limit, sort_desc)?;
+    let actual = transform.transform(block)?;
+
+    assert_eq!(expected.num_rows(), actual.num_rows());
+    for (expected, actual) in expected.columns().iter().zip(actual.columns()) {
+        assert_eq!(expected.value, actual.value);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_sorted_partial() -> Result<()> {
+    // Sorted blocks are passed through.
+    check_sorted_partial(
+        vec![Some(1), Some(1), Some(2), Some(3), None],
+        vec![1, 2, 0, 5, 1],
+        None,
+    )?;
+    check_sorted_partial(
+        vec![Some(1), Some(1), Some(2), Some(3), None],
+        vec![1, 2, 0, 5, 1],
+        Some(2),
+    )?;
+    check_sorted_partial(vec![], vec![], None)?;
+
+    // Unsorted blocks are sorted.
+    check_sorted_partial(
+        vec![Some(2), None, Some(1), Some(1)],
+        vec![0, 1, 2, 1],
+        None,
+    )?;
+    check_sorted_partial(
+        vec![Some(2), None, Some(1), Some(1)],
+        vec![0, 1, 2, 1],
+        Some(3),
+    )?;
+    Ok(())
+}
@@ -380,6 +380,7 @@ impl Compiler {
                         },
                     }],
                     named_params: vec![],
+                    with_ordinality: false,
                     alias: None,
                     sample: None,
                 }],
@@ -50,6 +50,7 @@ use crate::pipelines::executor::GlobalQueriesExecutor;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
 use crate::servers::http::v1::ClientSessionManager;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::sessions::PersistentQueriesQueueManager;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionManager;
 
@@ -110,7 +111,10 @@ impl GlobalServices {
             CatalogManager::init(config, Arc::new(default_catalog), catalog_creator).await?;
         }
 
-        QueriesQueueManager::init(config.query.max_running_queries as usize)?;
+        QueriesQueueManager::init(
+            config.query.max_running_queries as usize,
+            config.query.max_running_queries_per_user as usize,
+        )?;
         HttpQueryManager::init(config).await?;
         ClientSessionManager::init(config).await?;
         DataExchangeManager::init()?;
@@ -138,6 +142,22 @@ impl GlobalServices {
             .await?;
         }
 
+        if config.query.persist_queries_queue {
+            // The queue is local to the node, which keeps its flight address across restarts.
+            let prefix = format!(
+                "__fd_queries_queue/{}/{}/{}",
+                config.query.tenant_id.tenant_name(),
+                config.query.cluster_id,
+                config.query.flight_api_address
+            );
+            PersistentQueriesQueueManager::init(
+                QueriesQueueManager::instance(),
+                UserApiProvider::instance().get_meta_store_client(),
+                &prefix,
+            )
+            .await?;
+        }
+
         RoleCacheManager::init()?;
 
         DataOperator::init(&config.storage, config.spill.storage_params.clone()).await?;
@@ -20,6 +20,7 @@ mod stream;
 mod table;
 mod task;
 mod util;
+mod wal;
 
 pub mod table_option_validation;
 
@@ -33,5 +34,7 @@ pub use task::get_task_client_config;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
 pub use util::check_deduplicate_label;
+pub use wal::recover_from_wal;
+pub use wal::wal_location;
 
 pub use self::metrics::*;
@@ -18,6 +18,9 @@ use std::sync::LazyLock;
 
 use chrono::Duration;
 use databend_common_ast::ast::Engine;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
 use databend_common_exception::ErrorCode;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_ROW_COUNT;
@@ -44,6 +47,9 @@ use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_MAX_ARRAY_LEN;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_MAX_STRING_LEN;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_MIN_STRING_LEN;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_SEED;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_COUNT;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_KEYS;
+use databend_storages_common_table_meta::table::OPT_KEY_ROW_ACCESS_POLICY;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
@@ -67,6 +73,9 @@ pub static CREATE_FUSE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
     r.insert(OPT_KEY_CLUSTER_TYPE);
+    r.insert(OPT_KEY_ROLLUP_KEYS);
+    r.insert(OPT_KEY_ROLLUP_COUNT);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -115,6 +124,7 @@ pub static UNSET_TABLE_OPTIONS_WHITE_LIST: LazyLock<HashSet<&'static str>> = Laz
     r.insert(FUSE_OPT_KEY_FILE_SIZE);
     r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
     r.insert(OPT_KEY_ENABLE_COPY_DEDUP_FULL_PATH);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY);
     r
 });
 
@@ -193,6 +203,47 @@ pub fn is_valid_bloom_index_columns(
     Ok(())
 }
 
+pub fn is_valid_rollup_options(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> databend_common_exception::Result<()> {
+    if let Some(value) = options.get(OPT_KEY_ROLLUP_KEYS) {
+        for key in value.split(',').map(|key| key.trim()) {
+            if key.is_empty() || schema.field_with_name(key).is_err() {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "invalid rollup key '{}', it must be a column of the table",
+                    key
+                )));
+            }
+        }
+    }
+    if let Some(count) = options.get(OPT_KEY_ROLLUP_COUNT) {
+        if schema.field_with_name(count.trim()).is_err() {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid rollup count column '{}', it must be a column of the table",
+                count
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_row_access_policy(
+    options: &BTreeMap<String, String>,
+    dialect: Dialect,
+) -> databend_common_exception::Result<()> {
+    if let Some(value) = options.get(OPT_KEY_ROW_ACCESS_POLICY) {
+        let tokens = tokenize_sql(value)?;
+        parse_expr(&tokens, dialect).map_err(|e| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid row access policy '{}', it must be a predicate: {}",
+                value, e.1
+            ))
+        })?;
+    }
+    Ok(())
+}
+
 pub fn is_valid_change_tracking(
     options: &BTreeMap<String, String>,
 ) -> databend_common_exception::Result<()> {
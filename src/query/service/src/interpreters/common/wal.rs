@@ -0,0 +1,205 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::TableSchema;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::BlocksSource;
+use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use databend_common_storages_fuse::FuseTable;
+use futures::TryStreamExt;
+use log::info;
+use opendal::Operator;
+use parking_lot::Mutex;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReader;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::processors::transforms::WAL_COMPLETE_MARKER;
+use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+
+// The write-ahead logs of the inserts into `table` coordinated by the node `node_id`, each
+// node only replays its own logs, since it can not tell the queries running on the others.
+fn node_wal_prefix(table: &FuseTable, node_id: &str) -> String {
+    format!(
+        "{}{}/",
+        table.meta_location_generator().wal_location_prefix(),
+        node_id
+    )
+}
+
+/// The write-ahead log location of the insert of `ctx` into `table`.
+pub fn wal_location(table: &FuseTable, ctx: &QueryContext) -> String {
+    let node_id = ctx.get_cluster().local_id();
+    format!("{}{}/", node_wal_prefix(table, &node_id), ctx.get_id())
+}
+
+/// Replays the write-ahead logs left by the inserts into `table` that are no longer running
+/// on this node, and removes them. Only the logs holding all the rows of an insert are
+/// replayed, the others are removed as is.
+///
+/// Returns the number of replayed logs.
+pub async fn recover_from_wal(ctx: Arc<QueryContext>, table: Arc<dyn Table>) -> Result<usize> {
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let operator = fuse_table.get_operator();
+    let prefix = node_wal_prefix(fuse_table, &ctx.get_cluster().local_id());
+
+    let mut logs: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut lister = operator.lister_with(&prefix).recursive(true).await?;
+    while let Some(entry) = lister.try_next().await? {
+        if entry.metadata().is_dir() {
+            continue;
+        }
+        let path = entry.path();
+        let Some((query_id, _)) = path
+            .strip_prefix(prefix.as_str())
+            .and_then(|s| s.split_once('/'))
+        else {
+            continue;
+        };
+        logs.entry(query_id.to_string())
+            .or_default()
+            .push(path.to_string());
+    }
+    if logs.is_empty() {
+        return Ok(0);
+    }
+
+    let running = SessionManager::instance()
+        .processes_info()
+        .into_iter()
+        .filter_map(|process| process.current_query_id)
+        .collect::<HashSet<_>>();
+
+    let mut replayed = 0;
+    for (query_id, mut files) in logs {
+        if running.contains(&query_id) {
+            continue;
+        }
+
+        let location = format!("{}{}/", prefix, query_id);
+        let marker = format!("{}{}", location, WAL_COMPLETE_MARKER);
+        if files.contains(&marker) {
+            files.retain(|file| file != &marker);
+            // The files are named by uuid v7, so they are replayed in the order of writing.
+            files.sort();
+            info!(
+                "replay write-ahead log {} of table {}",
+                location,
+                table.get_table_info().desc
+            );
+            replay(ctx.clone(), table.clone(), &operator, &files).await?;
+            replayed += 1;
+        } else {
+            info!("remove incomplete write-ahead log {}", location);
+        }
+        operator.remove_all(&location).await?;
+    }
+
+    Ok(replayed)
+}
+
+// Insert the rows of `files` into `table` in a new query context, so that they are committed
+// with their own table meta timestamps and not counted as inserted by the current query.
+async fn replay(
+    ctx: Arc<QueryContext>,
+    table: Arc<dyn Table>,
+    operator: &Operator,
+    files: &[String],
+) -> Result<()> {
+    let ctx = ctx.get_current_session().create_query_context().await?;
+    let batch_size = ctx.get_settings().get_max_block_size()? as usize;
+    let mut wal_schema = None;
+    let mut blocks = VecDeque::new();
+    for file in files {
+        let data = operator.read(file).await?.to_bytes();
+        let reader = ParquetRecordBatchReader::try_new(data, batch_size)?;
+        let schema = wal_schema.get_or_insert(DataSchema::from(&TableSchema::try_from(
+            reader.schema().as_ref(),
+        )?));
+        for batch in reader {
+            let (block, _) = DataBlock::from_record_batch(schema, &batch?)?;
+            blocks.push_back(block);
+        }
+    }
+    let Some(wal_schema) = wal_schema else {
+        return Ok(());
+    };
+    let wal_schema = DataSchemaRef::new(wal_schema);
+
+    let table_schema = table.schema();
+    let insert_schema = DataSchemaRef::new(DataSchema::new(
+        wal_schema
+            .fields()
+            .iter()
+            .map(|field| Ok(DataField::from(table_schema.field_with_name(field.name())?)))
+            .collect::<Result<Vec<_>>>()?,
+    ));
+
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?;
+    let table_meta_timestamps = ctx.get_table_meta_timestamps(table.as_ref(), snapshot)?;
+
+    let mut pipeline = Pipeline::create();
+    let blocks = Arc::new(Mutex::new(blocks));
+    pipeline.add_source(
+        |output| BlocksSource::create(ctx.clone(), output, blocks.clone()),
+        1,
+    )?;
+    if wal_schema != insert_schema {
+        let func_ctx = ctx.get_function_context()?;
+        pipeline.try_add_transformer(|| {
+            TransformCastSchema::try_new(
+                wal_schema.clone(),
+                insert_schema.clone(),
+                func_ctx.clone(),
+            )
+        })?;
+    }
+    PipelineBuilder::fill_and_reorder_columns(
+        ctx.clone(),
+        &mut pipeline,
+        table.clone(),
+        insert_schema,
+    )?;
+    table.append_data(ctx.clone(), &mut pipeline, table_meta_timestamps)?;
+    table.commit_insertion(
+        ctx.clone(),
+        &mut pipeline,
+        None,
+        vec![],
+        false,
+        None,
+        None,
+        table_meta_timestamps,
+    )?;
+
+    let executor_settings = ExecutorSettings::try_create(ctx.clone())?;
+    let executor = PipelineCompleteExecutor::from_pipelines(vec![pipeline], executor_settings)?;
+    executor.execute()
+}
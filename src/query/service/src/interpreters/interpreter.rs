@@ -29,6 +29,7 @@ use databend_common_base::runtime::profile::ProfileDesc;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ResultExt;
@@ -60,6 +61,7 @@ use crate::pipelines::executor::PipelinePullingExecutor;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::ServiceQueryExecutor;
 use crate::sessions::AcquireQueueGuard;
+use crate::sessions::PersistentQueriesQueueManager;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryEntry;
@@ -260,18 +262,28 @@ async fn plan_sql(
         // planning the statement, to avoid potential deadlocks.
         // See PR https://github.com/databendlabs/databend/pull/16632
         let query_entry = QueryEntry::create_entry(&ctx, &extras, true)?;
-        let guard = QueriesQueueManager::instance().acquire(query_entry).await?;
+        let guard = acquire_queries_queue(query_entry).await?;
         let plan = planner.plan_stmt(&extras.statement).await?;
         Ok((plan, extras, guard))
     } else {
         // No lock is needed, plan the statement first, then acquire the queue guard.
         let plan = planner.plan_stmt(&extras.statement).await?;
         let query_entry = QueryEntry::create(&ctx, &plan, &extras)?;
-        let guard = QueriesQueueManager::instance().acquire(query_entry).await?;
+        let guard = acquire_queries_queue(query_entry).await?;
         Ok((plan, extras, guard))
     }
 }
 
+async fn acquire_queries_queue(query_entry: QueryEntry) -> Result<AcquireQueueGuard> {
+    // The waiting queries are persisted to keep their enqueue time across restarts.
+    if GlobalConfig::instance().query.persist_queries_queue {
+        return PersistentQueriesQueueManager::instance()
+            .acquire(query_entry)
+            .await;
+    }
+    QueriesQueueManager::instance().acquire(query_entry).await
+}
+
 fn attach_query_hash(ctx: &Arc<QueryContext>, stmt: &mut Option<Statement>, sql: &str) {
     let (query_hash, query_parameterized_hash) = if let Some(stmt) = stmt {
         let query_hash = format!("{:x}", Md5::digest(stmt.to_string()));
@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_sql::MetadataRef;
+use databend_common_storages_result_cache::gen_result_cache_key;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline_without_render_result_set;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::executor::PhysicalPlanBuilder;
+use crate::sql::optimizer::SExpr;
+use crate::sql::BindContext;
+
+/// Run an aggregation query in the background, returns the query id of the background query,
+/// whose result is read by `RESULT_SCAN(query_id)` when it finishes.
+pub struct AsyncAggregateInterpreter {
+    ctx: Arc<QueryContext>,
+    s_expr: SExpr,
+    bind_context: BindContext,
+    metadata: MetadataRef,
+    formatted_ast: Option<String>,
+}
+
+impl AsyncAggregateInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        bind_context: BindContext,
+        s_expr: SExpr,
+        metadata: MetadataRef,
+        formatted_ast: Option<String>,
+    ) -> Result<Self> {
+        Ok(AsyncAggregateInterpreter {
+            ctx,
+            s_expr,
+            bind_context,
+            metadata,
+            formatted_ast,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AsyncAggregateInterpreter {
+    fn name(&self) -> &str {
+        "AsyncAggregateInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let query = match &self.formatted_ast {
+            Some(formatted_ast) => formatted_ast.clone(),
+            None => self.ctx.get_query_str(),
+        };
+        let result_cache_key = gen_result_cache_key(&query);
+
+        let mut builder = PhysicalPlanBuilder::new(self.metadata.clone(), self.ctx.clone(), false);
+        let physical_plan = builder
+            .build_async(
+                &self.s_expr,
+                self.bind_context.column_set(),
+                self.bind_context.columns.clone(),
+                result_cache_key,
+            )
+            .await?;
+        info!(
+            "Query physical plan: \n{}",
+            physical_plan
+                .format(self.metadata.clone(), Default::default())?
+                .format_pretty()?
+        );
+
+        build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await
+    }
+}
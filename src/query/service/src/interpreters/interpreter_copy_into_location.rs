@@ -16,15 +16,11 @@ use std::sync::Arc;
 
 use databend_common_ast::ast::CopyIntoLocationOptions;
 use databend_common_base::runtime::GlobalIORuntime;
-use databend_common_catalog::plan::StageTableInfo;
 use databend_common_exception::Result;
-use databend_common_expression::infer_table_schema;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_common_pipeline_core::ExecutionInfo;
-use databend_common_sql::executor::physical_plans::CopyIntoLocation;
-use databend_common_sql::executor::PhysicalPlan;
-use databend_common_storage::StageFilesInfo;
+use databend_common_sql::executor::PhysicalPlanBuilder;
 use log::debug;
 use log::info;
 
@@ -91,31 +87,18 @@ impl CopyIntoLocationInterpreter {
     ) -> Result<(PipelineBuildResult, Vec<UpdateStreamMetaReq>)> {
         let (query_interpreter, update_stream_meta_req) = self.build_query(query).await?;
         let query_physical_plan = query_interpreter.build_physical_plan().await?;
-        let query_result_schema = query_interpreter.get_result_schema();
-        let table_schema = infer_table_schema(&query_result_schema)?;
-
-        let mut physical_plan = PhysicalPlan::CopyIntoLocation(Box::new(CopyIntoLocation {
-            plan_id: 0,
-            input: Box::new(query_physical_plan),
-            project_columns: query_interpreter.get_result_columns(),
-            input_schema: query_result_schema,
-            to_stage_info: StageTableInfo {
-                schema: table_schema,
-                stage_info: stage.clone(),
-                files_info: StageFilesInfo {
-                    path: path.to_string(),
-                    files: None,
-                    pattern: None,
-                },
-                files_to_copy: None,
-                duplicated_files_detected: vec![],
-                is_select: false,
-                default_values: None,
-                copy_into_location_options: options.clone(),
-                copy_into_table_options: Default::default(),
-                stage_root: "".to_string(),
-            },
-        }));
+        let Plan::Query { metadata, .. } = query else {
+            unreachable!("Input plan must be Query, but it's {}", query)
+        };
+        let builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), false);
+        let mut physical_plan = builder.build_copy_into_stage(
+            query_physical_plan,
+            query_interpreter.get_result_columns(),
+            query_interpreter.get_result_schema(),
+            stage,
+            path,
+            options,
+        )?;
 
         let mut next_plan_id = 0;
         physical_plan.adjust_plan_id(&mut next_plan_id);
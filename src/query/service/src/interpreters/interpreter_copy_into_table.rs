@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use databend_common_ast::ast::OnErrorMode;
 use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::Result;
@@ -26,8 +27,10 @@ use databend_common_expression::SendableDataBlockStream;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_sql::executor::physical_plans::CastMode;
 use databend_common_sql::executor::physical_plans::CopyIntoTable;
 use databend_common_sql::executor::physical_plans::CopyIntoTableSource;
+use databend_common_sql::executor::physical_plans::EnforceSchema;
 use databend_common_sql::executor::physical_plans::Exchange;
 use databend_common_sql::executor::physical_plans::FragmentKind;
 use databend_common_sql::executor::physical_plans::MutationKind;
@@ -144,18 +147,31 @@ impl CopyIntoTableInterpreter {
                 name_mapping.insert(field.name.clone(), idx);
             }
 
-            (
-                CopyIntoTableSource::Stage(Box::new(PhysicalPlan::TableScan(TableScan {
-                    plan_id: 0,
-                    scan_id: 0,
-                    name_mapping,
-                    stat_info: None,
-                    table_index: None,
-                    internal_column: None,
-                    source: Box::new(data_source_plan),
-                }))),
-                None,
-            )
+            let stage_read = PhysicalPlan::TableScan(TableScan {
+                plan_id: 0,
+                scan_id: 0,
+                name_mapping,
+                stat_info: None,
+                table_index: None,
+                internal_column: None,
+                source: Box::new(data_source_plan),
+                speculative_prefetch: false,
+                prefetch_depth: 0,
+                force_index: None,
+            });
+            // The schema inferred from the files may not exactly match the table.
+            let cast_mode = match plan.stage_table_info.copy_into_table_options.on_error {
+                OnErrorMode::Continue => CastMode::Permissive,
+                _ => CastMode::Strict,
+            };
+            let stage_read = PhysicalPlan::EnforceSchema(Box::new(EnforceSchema {
+                plan_id: 0,
+                input: Box::new(stage_read),
+                target_schema: plan.required_source_schema.clone(),
+                cast_mode,
+                stat_info: None,
+            }));
+            (CopyIntoTableSource::Stage(Box::new(stage_read)), None)
         };
 
         let mut root = PhysicalPlan::CopyIntoTable(Box::new(CopyIntoTable {
@@ -180,6 +196,7 @@ impl CopyIntoTableInterpreter {
                 kind: FragmentKind::Merge,
                 keys: Vec::new(),
                 allow_adjust_parallelism: true,
+                consistent_hash: false,
                 ignore_exchange: false,
             });
         }
@@ -21,6 +21,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::binder::ExplainConfig;
 use databend_common_sql::plans::Mutation;
+use databend_common_sql::plans::RewriteKind;
 use log::error;
 
 use super::interpreter_catalog_create::CreateCatalogInterpreter;
@@ -192,6 +193,37 @@ impl InterpreterFactory {
 
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
         match plan {
+            Plan::Query {
+                s_expr,
+                bind_context,
+                metadata,
+                formatted_ast,
+                rewrite_kind: Some(RewriteKind::AsyncAggregate),
+                ..
+            } => Ok(Arc::new(AsyncAggregateInterpreter::try_create(
+                ctx,
+                *bind_context.clone(),
+                *s_expr.clone(),
+                metadata.clone(),
+                formatted_ast.clone(),
+            )?)),
+            Plan::Query {
+                s_expr,
+                bind_context,
+                metadata,
+                rewrite_kind: Some(RewriteKind::AssertExists(error_message)),
+                ..
+            } => Ok(Arc::new(
+                SelectInterpreter::try_create(
+                    ctx,
+                    *bind_context.clone(),
+                    *s_expr.clone(),
+                    metadata.clone(),
+                    None,
+                    false,
+                )?
+                .with_abort_if_empty(error_message.clone()),
+            )),
             Plan::Query {
                 s_expr,
                 bind_context,
@@ -34,6 +34,7 @@ use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
+use databend_common_sql::executor::physical_plans::MvRefreshPartial;
 use databend_common_sql::executor::physical_plans::TableScan;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::executor::PhysicalPlanBuilder;
@@ -42,6 +43,7 @@ use databend_common_sql::plans::Plan;
 use databend_common_sql::plans::RefreshIndexPlan;
 use databend_common_sql::plans::RelOperator;
 use databend_common_storages_fuse::operations::AggIndexSink;
+use databend_common_storages_fuse::operations::PartitionChangeDetector;
 use databend_common_storages_fuse::pruning::create_segment_location_vector;
 use databend_common_storages_fuse::FuseBlockPartInfo;
 use databend_common_storages_fuse::FuseLazyPartInfo;
@@ -149,10 +151,14 @@ impl RefreshIndexInterpreter {
             let partitions = match segments {
                 Some(segment_locs) if !segment_locs.is_empty() => {
                     let segment_locations = create_segment_location_vector(segment_locs, None);
-                    self.get_partitions_with_given_segments(&source, fuse_table, segment_locations)
-                        .await?
+                    self.get_partitions_with_given_segments(
+                        &source,
+                        fuse_table.clone(),
+                        segment_locations,
+                    )
+                    .await?
                 }
-                Some(_) | None => self.get_partitions(&source, fuse_table).await?,
+                Some(_) | None => self.get_partitions(&source, fuse_table.clone()).await?,
             };
             if let Some(parts) = partitions {
                 source.parts = parts;
@@ -165,26 +171,18 @@ impl RefreshIndexInterpreter {
                 p1.create_on.partial_cmp(&p2.create_on).unwrap()
             });
 
-            // then, find the last refresh position.
-            let last = source
-                .parts
-                .partitions
-                .binary_search_by(|p| {
-                    let fp = FuseBlockPartInfo::from_part(p).unwrap();
-                    fp.create_on
-                        .partial_cmp(&self.plan.index_meta.updated_on)
-                        .unwrap()
-                })
-                .map_or_else(|i| i, |i| i + 1);
-
-            // finally, skip the refreshed partitions.
-            source.parts.partitions = match self.plan.limit {
-                Some(limit) => {
-                    let end = std::cmp::min(source.parts.len(), last + limit as usize);
-                    source.parts.partitions[last..end].to_vec()
-                }
-                None => source.parts.partitions.into_iter().skip(last).collect(),
-            };
+            // then, skip the partitions not changed since the last refresh.
+            let detector = PartitionChangeDetector::try_create(
+                fuse_table.as_ref(),
+                self.plan.index_id,
+                self.plan.index_meta.updated_on,
+            )
+            .await?;
+            let mut changed = detector.changed_partitions(source.parts.partitions)?;
+            if let Some(limit) = self.plan.limit {
+                changed.truncate(limit);
+            }
+            source.parts.partitions = changed;
 
             if !source.parts.is_empty() {
                 Ok(Some(source))
@@ -197,7 +195,7 @@ impl RefreshIndexInterpreter {
     fn update_index_meta(&self, read_source: &DataSourcePlan) -> Result<IndexMeta> {
         let fuse_part = FuseBlockPartInfo::from_part(read_source.parts.partitions.last().unwrap())?;
         let mut index_meta = self.plan.index_meta.clone();
-        index_meta.updated_on = fuse_part.create_on;
+        index_meta.updated_on = std::cmp::max(index_meta.updated_on, fuse_part.create_on);
         Ok(index_meta)
     }
 }
@@ -278,10 +276,21 @@ impl Interpreter for RefreshIndexInterpreter {
 
         let new_index_meta = self.update_index_meta(&new_read_source)?;
 
+        let changed_partitions = new_read_source
+            .parts
+            .partitions
+            .iter()
+            .map(|part| Ok(FuseBlockPartInfo::from_part(part)?.location.clone()))
+            .collect::<Result<Vec<_>>>()?;
         let mut replace_read_source = ReadSourceReplacer {
             source: new_read_source,
         };
         query_plan = replace_read_source.replace(&query_plan)?;
+        query_plan = PhysicalPlan::MvRefreshPartial(Box::new(MvRefreshPartial::try_create(
+            self.plan.index_id,
+            query_plan,
+            changed_partitions,
+        )?));
 
         let mut build_res =
             build_query_pipeline_without_render_result_set(&self.ctx, &query_plan).await?;
@@ -14,7 +14,9 @@
 
 use std::sync::Arc;
 
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -23,6 +25,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
 use databend_common_expression::FromData;
 use databend_common_expression::SendableDataBlockStream;
+use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::executor::physical_plans::DistributedInsertSelect;
 use databend_common_sql::executor::physical_plans::MutationKind;
@@ -32,11 +35,18 @@ use databend_common_sql::plans::Insert;
 use databend_common_sql::plans::InsertInputSource;
 use databend_common_sql::plans::InsertValue;
 use databend_common_sql::plans::Plan;
+use databend_common_sql::ColumnBinding;
+use databend_common_sql::MetadataRef;
 use databend_common_sql::NameResolutionContext;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_index::BloomIndex;
+use databend_storages_common_table_meta::meta::TableMetaTimestamps;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
 use crate::interpreters::common::dml_build_update_stream_req;
+use crate::interpreters::common::recover_from_wal;
+use crate::interpreters::common::wal_location;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -76,6 +86,62 @@ impl InsertInterpreter {
         let cast_needed = select_schema.as_ref() != &DataSchema::from(output_schema.as_ref());
         Ok(cast_needed)
     }
+
+    /// Put a `BloomBuild` on the select plan if the table has bloom-index columns,
+    /// the bloom filters of the inserted rows are written next to the new segment.
+    fn build_bloom_build(
+        &self,
+        table: &Arc<dyn Table>,
+        select_plan: PhysicalPlan,
+        select_column_bindings: &[ColumnBinding],
+        table_meta_timestamps: TableMetaTimestamps,
+    ) -> Result<PhysicalPlan> {
+        if table.engine() != "FUSE" {
+            return Ok(select_plan);
+        }
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let bloom_fields = fuse_table
+            .bloom_index_cols()
+            .bloom_index_fields(table.schema(), BloomIndex::supported_type)?
+            .into_values()
+            .collect::<Vec<_>>();
+        if bloom_fields.is_empty() {
+            return Ok(select_plan);
+        }
+
+        let segment_location = fuse_table
+            .meta_location_generator()
+            .gen_segment_info_location(table_meta_timestamps);
+        let builder = PhysicalPlanBuilder::new(MetadataRef::default(), self.ctx.clone(), false);
+        builder.build_bloom_build(
+            select_plan,
+            table.get_table_info().clone(),
+            &self.plan.dest_schema(),
+            select_column_bindings,
+            bloom_fields,
+            segment_location,
+        )
+    }
+
+    /// Put a `WriteAheadLog` on the select plan, the inserted rows are logged under the
+    /// wal location of the query until the insert is committed.
+    fn build_insert_with_wal(
+        &self,
+        table: &Arc<dyn Table>,
+        select_plan: PhysicalPlan,
+        select_column_bindings: &[ColumnBinding],
+    ) -> Result<PhysicalPlan> {
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let builder = PhysicalPlanBuilder::new(MetadataRef::default(), self.ctx.clone(), false);
+        builder.build_insert_with_wal(
+            select_plan,
+            table.get_table_info().clone(),
+            &self.plan.dest_schema(),
+            select_column_bindings,
+            wal_location(fuse_table, &self.ctx),
+        )
+    }
 }
 
 #[async_trait::async_trait]
@@ -107,6 +173,18 @@ impl Interpreter for InsertInterpreter {
 
         // check mutability
         table.check_mutable()?;
+
+        let enable_wal = matches!(self.plan.source, InsertInputSource::SelectPlan(_))
+            && self.plan.returning.is_empty()
+            && table.engine() == "FUSE"
+            && self.ctx.get_settings().get_enable_wal()?;
+        // replay the write-ahead logs of the failed inserts before this one
+        let table = if enable_wal && recover_from_wal(self.ctx.clone(), table.clone()).await? > 0 {
+            table.refresh(self.ctx.as_ref()).await?
+        } else {
+            table
+        };
+
         let table_meta_timestamps = if table.engine() == "FUSE" {
             let fuse_table =
                 databend_common_storages_fuse::FuseTable::try_from_table(table.as_ref())?;
@@ -117,6 +195,46 @@ impl Interpreter for InsertInterpreter {
             Default::default()
         };
 
+        if !self.plan.returning.is_empty() {
+            let mut builder =
+                PhysicalPlanBuilder::new(MetadataRef::default(), self.ctx.clone(), false);
+            let emit_plan =
+                builder.build_insert_returning(&self.plan, table.get_table_info().clone())?;
+            let mut build_res =
+                build_query_pipeline_without_render_result_set(&self.ctx, &emit_plan).await?;
+
+            table.append_data(
+                self.ctx.clone(),
+                &mut build_res.main_pipeline,
+                table_meta_timestamps,
+            )?;
+            table.commit_insertion(
+                self.ctx.clone(),
+                &mut build_res.main_pipeline,
+                None,
+                vec![],
+                self.plan.overwrite,
+                None,
+                unsafe { self.ctx.get_settings().get_deduplicate_label()? },
+                table_meta_timestamps,
+            )?;
+
+            //  Execute the hook operator.
+            {
+                let hook_operator = HookOperator::create(
+                    self.ctx.clone(),
+                    self.plan.catalog.clone(),
+                    self.plan.database.clone(),
+                    self.plan.table.clone(),
+                    MutationKind::Insert,
+                    LockTableOption::LockNoRetry,
+                );
+                hook_operator.execute(&mut build_res.main_pipeline).await;
+            }
+
+            return Ok(build_res);
+        }
+
         let mut build_res = PipelineBuildResult::create();
 
         match &self.plan.source {
@@ -201,6 +319,17 @@ impl Interpreter for InsertInterpreter {
                         PhysicalPlan::Exchange(exchange.clone())
                     }
                     (other_plan, _) => {
+                        let other_plan = self.build_bloom_build(
+                            &table,
+                            other_plan,
+                            &select_column_bindings,
+                            table_meta_timestamps,
+                        )?;
+                        let other_plan = if enable_wal {
+                            self.build_insert_with_wal(&table, other_plan, &select_column_bindings)?
+                        } else {
+                            other_plan
+                        };
                         // insert should wait until all nodes finished
                         PhysicalPlan::DistributedInsertSelect(Box::new(DistributedInsertSelect {
                             // TODO: we reuse the id of other plan here,
@@ -232,6 +361,22 @@ impl Interpreter for InsertInterpreter {
                     table_meta_timestamps,
                 )?;
 
+                if enable_wal {
+                    // the write-ahead log is not needed once the insert is committed
+                    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                    let operator = fuse_table.get_operator();
+                    let location = wal_location(fuse_table, &self.ctx);
+                    build_res.main_pipeline.set_on_finished(
+                        move |info: &ExecutionInfo| match &info.res {
+                            Ok(_) => GlobalIORuntime::instance().block_on(async move {
+                                operator.remove_all(&location).await?;
+                                Ok(())
+                            }),
+                            Err(error_code) => Err(error_code.clone()),
+                        },
+                    );
+                }
+
                 //  Execute the hook operator.
                 {
                     let hook_operator = HookOperator::create(
@@ -278,6 +423,11 @@ impl Interpreter for InsertInterpreter {
     }
 
     fn inject_result(&self) -> Result<SendableDataBlockStream> {
+        if !self.plan.returning.is_empty() {
+            let blocks = self.ctx.get_returning_blocks();
+            return Ok(Box::pin(DataBlockStream::create(None, blocks)));
+        }
+
         let binding = self.ctx.get_mutation_status();
         let status = binding.read();
         let blocks = vec![DataBlock::new_from_columns(vec![UInt64Type::from_data(
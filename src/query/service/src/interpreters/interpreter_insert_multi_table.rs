@@ -25,6 +25,7 @@ use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::FromData;
 use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_sql::executor::physical_plans::CastSchema;
@@ -37,10 +38,12 @@ use databend_common_sql::executor::physical_plans::ChunkMerge;
 use databend_common_sql::executor::physical_plans::FillAndReorder;
 use databend_common_sql::executor::physical_plans::MultiInsertEvalScalar;
 use databend_common_sql::executor::physical_plans::MutationKind;
+use databend_common_sql::executor::physical_plans::Scatter;
 use databend_common_sql::executor::physical_plans::SerializableTable;
 use databend_common_sql::executor::physical_plans::ShuffleStrategy;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::plans::ConstantExpr;
 use databend_common_sql::plans::Else;
 use databend_common_sql::plans::FunctionCall;
 use databend_common_sql::plans::InsertMultiTable;
@@ -174,11 +177,23 @@ impl InsertMultiTableInterpreter {
         let fill_and_reorders = branches.build_fill_and_reorder(self.ctx.clone()).await?;
         let group_ids = branches.build_group_ids();
 
-        root = PhysicalPlan::Duplicate(Box::new(Duplicate {
-            plan_id: 0,
-            input: Box::new(root),
-            n: branches.len(),
-        }));
+        let use_scatter = self.use_scatter();
+        if use_scatter {
+            let (conditions, targets) = branches.build_scatter(source_schema.as_ref())?;
+            root = PhysicalPlan::Scatter(Box::new(Scatter {
+                plan_id: 0,
+                input: Box::new(root),
+                conditions,
+                targets,
+                stat_info: None,
+            }));
+        } else {
+            root = PhysicalPlan::Duplicate(Box::new(Duplicate {
+                plan_id: 0,
+                input: Box::new(root),
+                n: branches.len(),
+            }));
+        }
 
         let shuffle_strategy = ShuffleStrategy::Transpose(branches.len());
         root = PhysicalPlan::Shuffle(Box::new(Shuffle {
@@ -187,11 +202,13 @@ impl InsertMultiTableInterpreter {
             strategy: shuffle_strategy,
         }));
 
-        root = PhysicalPlan::ChunkFilter(Box::new(ChunkFilter {
-            plan_id: 0,
-            input: Box::new(root),
-            predicates,
-        }));
+        if !use_scatter {
+            root = PhysicalPlan::ChunkFilter(Box::new(ChunkFilter {
+                plan_id: 0,
+                input: Box::new(root),
+                predicates,
+            }));
+        }
 
         root = PhysicalPlan::ChunkEvalScalar(Box::new(ChunkEvalScalar {
             plan_id: 0,
@@ -253,6 +270,22 @@ impl InsertMultiTableInterpreter {
         }
     }
 
+    /// `INSERT FIRST` whose branches each insert into a single table writes
+    /// every row at most once, the rows can be routed by `Scatter` in one pass.
+    fn use_scatter(&self) -> bool {
+        let InsertMultiTable {
+            is_first,
+            whens,
+            opt_else,
+            intos,
+            ..
+        } = &self.plan;
+        *is_first
+            && intos.is_empty()
+            && whens.iter().all(|when| when.intos.len() == 1)
+            && opt_else.as_ref().is_none_or(|e| e.intos.len() == 1)
+    }
+
     async fn build_insert_into_branches(&self) -> Result<InsertIntoBranches> {
         let InsertMultiTable {
             input_source: _,
@@ -264,12 +297,14 @@ impl InsertMultiTableInterpreter {
             target_tables: _,
             meta_data: _,
         } = &self.plan;
+        let use_scatter = self.use_scatter();
         let mut branches = InsertIntoBranches::default();
         let mut condition_intos = vec![];
         let mut previous_not: Option<ScalarExpr> = None;
         for when in whens {
             let mut condition = when.condition.clone();
-            if *is_first {
+            // `Scatter` evaluates the conditions in order, no need to merge the previous ones.
+            if *is_first && !use_scatter {
                 if let Some(prev_not) = &previous_not {
                     let merged_condition = and(prev_not.clone(), condition.clone());
                     previous_not = Some(and(prev_not.clone(), not(condition.clone())));
@@ -283,7 +318,11 @@ impl InsertMultiTableInterpreter {
             }
         }
         if let Some(Else { intos }) = opt_else {
-            let condition = if *is_first { previous_not.take() } else { None };
+            let condition = if *is_first && !use_scatter {
+                previous_not.take()
+            } else {
+                None
+            };
             for into in intos {
                 condition_intos.push((condition.clone(), into));
             }
@@ -292,14 +331,16 @@ impl InsertMultiTableInterpreter {
             condition_intos.push((None, into));
         }
 
-        condition_intos.sort_by(|a, b| {
+        // Remember the position of each branch in the statement before sorting by table.
+        let mut condition_intos = condition_intos.into_iter().enumerate().collect::<Vec<_>>();
+        condition_intos.sort_by(|(_, a), (_, b)| {
             a.1.catalog
                 .cmp(&b.1.catalog)
                 .then(a.1.database.cmp(&b.1.database))
                 .then(a.1.table.cmp(&b.1.table))
         });
 
-        for (condition, into) in condition_intos {
+        for (position, (condition, into)) in condition_intos {
             let Into {
                 catalog,
                 database,
@@ -313,6 +354,7 @@ impl InsertMultiTableInterpreter {
                 condition,
                 source_scalar_exprs.clone(),
                 casted_schema.clone(),
+                position,
             );
         }
 
@@ -354,6 +396,7 @@ struct InsertIntoBranches {
     conditions: Vec<Option<ScalarExpr>>,
     source_exprs: Vec<Option<Vec<ScalarExpr>>>,
     casted_schemas: Vec<DataSchemaRef>,
+    positions: Vec<usize>,
     len: usize,
 }
 
@@ -364,11 +407,13 @@ impl InsertIntoBranches {
         condition: Option<ScalarExpr>,
         source_exprs: Option<Vec<ScalarExpr>>,
         casted_schema: DataSchemaRef,
+        position: usize,
     ) {
         self.tables.push(table);
         self.conditions.push(condition);
         self.source_exprs.push(source_exprs);
         self.casted_schemas.push(casted_schema);
+        self.positions.push(position);
         self.len += 1;
     }
 
@@ -440,6 +485,29 @@ impl InsertIntoBranches {
         Ok(predicates)
     }
 
+    /// Build the conditions of `Scatter` in the statement order, paired with their branches.
+    /// The branch without condition is the `ELSE` branch, which matches all remaining rows.
+    fn build_scatter(&self, source_schema: &DataSchema) -> Result<(Vec<RemoteExpr>, Vec<usize>)> {
+        let mut targets = (0..self.len).collect::<Vec<_>>();
+        targets.sort_by_key(|target| self.positions[*target]);
+
+        let mut conditions = Vec::with_capacity(self.len);
+        for target in targets.iter() {
+            let condition = self.conditions[*target].clone().unwrap_or_else(|| {
+                ScalarExpr::ConstantExpr(ConstantExpr {
+                    span: None,
+                    value: Scalar::Boolean(true),
+                })
+            });
+            let expr =
+                cast_expr_to_non_null_boolean(condition.as_expr()?.project_column_ref(|col| {
+                    source_schema.index_of(&col.index.to_string()).unwrap()
+                }))?;
+            conditions.push(expr.as_remote_expr());
+        }
+        Ok((conditions, targets))
+    }
+
     fn build_eval_scalars(
         &self,
         source_schema: &DataSchema,
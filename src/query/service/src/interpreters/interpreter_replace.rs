@@ -281,6 +281,7 @@ impl ReplaceInterpreter {
                 kind: FragmentKind::Expansive,
                 keys: vec![],
                 allow_adjust_parallelism: true,
+                consistent_hash: false,
                 ignore_exchange: false,
             }));
         } else if is_exchange && !is_stage_source {
@@ -290,6 +291,7 @@ impl ReplaceInterpreter {
                 kind: FragmentKind::Merge,
                 keys: vec![],
                 allow_adjust_parallelism: true,
+                consistent_hash: false,
                 ignore_exchange: false,
             }));
         }
@@ -354,6 +356,7 @@ impl ReplaceInterpreter {
                 kind: FragmentKind::Merge,
                 keys: vec![],
                 allow_adjust_parallelism: true,
+                consistent_hash: false,
                 ignore_exchange: false,
             }));
         }
@@ -368,6 +371,8 @@ impl ReplaceInterpreter {
             },
             update_stream_meta: update_stream_meta.clone(),
             deduplicated_label: unsafe { self.ctx.get_settings().get_deduplicate_label()? },
+            write_conflict_retry_count: self.ctx.get_settings().get_write_conflict_retry_count()?,
+            write_conflict_backoff_ms: self.ctx.get_settings().get_write_conflict_backoff_ms()?,
             plan_id: u32::MAX,
             table_meta_timestamps,
             recluster_info: None,
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::UNIX_EPOCH;
 
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::table::Table;
@@ -27,15 +29,21 @@ use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
 use databend_common_meta_store::MetaStore;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_pipeline_core::Pipe;
 use databend_common_pipeline_core::PipeItem;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::EmptySink;
 use databend_common_pipeline_transforms::processors::TransformDummy;
 use databend_common_sql::executor::physical_plans::FragmentKind;
+use databend_common_sql::executor::physical_plans::PrewarmCache;
+use databend_common_sql::executor::physical_plans::ResultCacheScan;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::parse_result_scan_args;
+use databend_common_sql::BaseTableColumn;
 use databend_common_sql::ColumnBinding;
+use databend_common_sql::ColumnEntry;
 use databend_common_sql::MetadataRef;
 use databend_common_storages_result_cache::gen_result_cache_key;
 use databend_common_storages_result_cache::ResultCacheReader;
@@ -47,6 +55,7 @@ use log::info;
 use crate::interpreters::common::query_build_update_stream_req;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_local_pipeline;
 use crate::schedulers::build_query_pipeline;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -62,6 +71,8 @@ pub struct SelectInterpreter {
     metadata: MetadataRef,
     formatted_ast: Option<String>,
     ignore_result: bool,
+    // Fail the query with the message if it returns no rows.
+    abort_if_empty: Option<String>,
 }
 
 impl SelectInterpreter {
@@ -80,9 +91,15 @@ impl SelectInterpreter {
             metadata,
             formatted_ast,
             ignore_result,
+            abort_if_empty: None,
         })
     }
 
+    pub fn with_abort_if_empty(mut self, error_message: String) -> Self {
+        self.abort_if_empty = Some(error_message);
+        self
+    }
+
     pub fn get_ignore_result(&self) -> bool {
         self.ignore_result
     }
@@ -112,10 +129,33 @@ impl SelectInterpreter {
     #[async_backtrace::framed]
     pub async fn build_physical_plan(&self) -> Result<PhysicalPlan> {
         let mut builder = PhysicalPlanBuilder::new(self.metadata.clone(), self.ctx.clone(), false);
+        let settings = self.ctx.get_settings();
+        if settings.get_enable_convert_timezone()? {
+            let tz = settings.get_timezone()?;
+            if tz != "UTC" {
+                builder.set_convert_timezone(Some(tz));
+            }
+        }
         self.ctx.set_status_info("building physical plan");
-        builder
-            .build(&self.s_expr, self.bind_context.column_set())
-            .await
+        let plan = if settings.get_enable_streaming_watermark()? {
+            let late_output_path = settings.get_streaming_late_output_path()?;
+            builder
+                .build_streaming(
+                    &self.s_expr,
+                    self.bind_context.column_set(),
+                    settings.get_streaming_watermark_delay_ms()?,
+                    (!late_output_path.is_empty()).then_some(late_output_path),
+                )
+                .await?
+        } else {
+            builder
+                .build(&self.s_expr, self.bind_context.column_set())
+                .await?
+        };
+        match &self.abort_if_empty {
+            Some(error_message) => builder.build_abort_if_empty(plan, error_message.clone()),
+            None => Ok(plan),
+        }
     }
 
     #[async_backtrace::framed]
@@ -162,6 +202,21 @@ impl SelectInterpreter {
         Ok(build_res)
     }
 
+    /// Build the pipeline of a query missing the result cache, the result is written to the
+    /// cache by the pipeline.
+    async fn build_pipeline_with_result_cache(
+        &self,
+        physical_plan: PhysicalPlan,
+        key: &str,
+        kv_store: Arc<MetaStore>,
+    ) -> Result<PipelineBuildResult> {
+        let mut build_res = self.build_pipeline(physical_plan).await?;
+        let schema = infer_table_schema(&self.bind_context.output_schema())?;
+        self.add_result_cache(key, schema, &mut build_res.main_pipeline, kv_store)?;
+        self.add_prewarm_cache(&mut build_res).await?;
+        Ok(build_res)
+    }
+
     /// Add pipelines for writing query result cache.
     fn add_result_cache(
         &self,
@@ -222,6 +277,7 @@ impl SelectInterpreter {
                 schema,
                 sink_inputs.clone(),
                 kv_store,
+                self.result_cache_columns_used()?,
             )?,
             sink_inputs,
             vec![],
@@ -232,6 +288,65 @@ impl SelectInterpreter {
         Ok(())
     }
 
+    /// The `(table id, column id)` of the table columns read by the query, the result cache
+    /// is invalidated when one of them is dropped.
+    fn result_cache_columns_used(&self) -> Result<Vec<(u64, u32)>> {
+        let used_columns = self.s_expr.derive_relational_prop()?.used_columns.clone();
+        let metadata = self.metadata.read();
+        let mut columns_used = used_columns
+            .iter()
+            .filter_map(|index| match metadata.column(*index) {
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    table_index,
+                    column_id: Some(column_id),
+                    ..
+                }) => Some((metadata.table(*table_index).table().get_id(), *column_id)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        columns_used.sort();
+        columns_used.dedup();
+        Ok(columns_used)
+    }
+
+    /// Prewarm the result cache of the hot queries in the session that read the same tables
+    /// as this query, their cached results are likely stale as well. The queries are submitted
+    /// in background by `PrewarmCache`, which runs in a source pipeline of this query.
+    async fn add_prewarm_cache(&self, build_res: &mut PipelineBuildResult) -> Result<()> {
+        let max_bytes = self
+            .ctx
+            .get_settings()
+            .get_query_result_cache_prewarm_max_bytes()?;
+        if max_bytes == 0 {
+            return Ok(());
+        }
+
+        let tables = self
+            .metadata
+            .read()
+            .tables()
+            .iter()
+            .map(|table| table.table().get_id())
+            .collect();
+        let queries = self
+            .ctx
+            .record_result_cache_miss(self.formatted_ast.as_ref().unwrap(), tables);
+        if queries.is_empty() {
+            return Ok(());
+        }
+
+        let plan = PhysicalPlan::PrewarmCache(Box::new(PrewarmCache::create(queries, max_bytes)));
+        let mut prewarm_res = build_local_pipeline(&self.ctx, &plan).await?;
+        prewarm_res
+            .main_pipeline
+            .add_sink(|input| Ok(ProcessorPtr::create(EmptySink::create(input))))?;
+        build_res.sources_pipelines.push(prewarm_res.main_pipeline);
+        build_res
+            .sources_pipelines
+            .extend(prewarm_res.sources_pipelines);
+        Ok(())
+    }
+
     fn result_scan_table(&self) -> Result<Option<Arc<dyn Table>>> {
         let r_lock = self.metadata.read();
         let tables = r_lock.tables();
@@ -327,23 +442,53 @@ impl Interpreter for SelectInterpreter {
             );
 
             // 2. Check the cache.
-            match cache_reader.try_read_cached_result().await {
-                Ok(Some(blocks)) => {
-                    // 2.0 update query_id -> result_cache_meta_key in session.
-                    self.ctx
-                        .set_query_id_result_cache(self.ctx.get_id(), cache_reader.get_meta_key());
-                    // 2.1 If found, return the result directly.
-                    return PipelineBuildResult::from_blocks(blocks);
+            match cache_reader.check_cache().await {
+                Ok(Some(value)) => {
+                    // 2.1 If found, read the result from the cache, the plan of the query is
+                    // executed instead if the cache expires before the execution starts.
+                    let result_schema = DataSchemaRefExt::create(
+                        self.bind_context
+                            .columns
+                            .iter()
+                            .map(|column| {
+                                DataField::new(&column.index.to_string(), *column.data_type.clone())
+                            })
+                            .collect(),
+                    );
+                    let expiry = Duration::from_secs(value.query_time + value.ttl);
+                    let scan = ResultCacheScan {
+                        plan_id: 0,
+                        input: Box::new(physical_plan),
+                        location: value.location,
+                        num_rows: value.num_rows,
+                        result_schema,
+                        cache_expiry: Some(UNIX_EPOCH + expiry),
+                    };
+                    if !scan.is_expired() {
+                        // 2.2 update query_id -> result_cache_meta_key in session.
+                        self.ctx.set_query_id_result_cache(
+                            self.ctx.get_id(),
+                            cache_reader.get_meta_key(),
+                        );
+                        let plan = PhysicalPlan::ResultCacheScan(Box::new(scan));
+                        return self.build_pipeline(plan).await;
+                    }
+                    info!(
+                        "Result cache {} has expired, execute the query and refresh the cache",
+                        scan.location
+                    );
+                    return self
+                        .build_pipeline_with_result_cache(*scan.input, &key, kv_store)
+                        .await;
                 }
                 Ok(None) => {
-                    let mut build_res = self.build_pipeline(physical_plan).await?;
-                    // 2.2 If not found result in cache, add pipelines to write the result to cache.
-                    let schema = infer_table_schema(&self.bind_context.output_schema())?;
-                    self.add_result_cache(&key, schema, &mut build_res.main_pipeline, kv_store)?;
-                    return Ok(build_res);
+                    // 2.3 If not found result in cache, add pipelines to write the result to cache.
+                    return self
+                        .build_pipeline_with_result_cache(physical_plan, &key, kv_store)
+                        .await;
                 }
                 Err(e) => {
-                    // 2.3 If an error occurs, turn back to the normal pipeline.
+                    // 2.4 If an error occurs, turn back to the normal pipeline.
                     error!("Failed to read query result cache. {}", e);
                 }
             }
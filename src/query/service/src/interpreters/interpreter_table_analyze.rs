@@ -24,8 +24,7 @@ use databend_common_sql::executor::physical_plans::AggregateFinal;
 use databend_common_sql::executor::physical_plans::AggregatePartial;
 use databend_common_sql::executor::physical_plans::EvalScalar;
 use databend_common_sql::executor::physical_plans::Filter;
-use databend_common_sql::executor::physical_plans::Sort;
-use databend_common_sql::executor::physical_plans::Window;
+use databend_common_sql::executor::physical_plans::Histogram;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::executor::PhysicalPlanBuilder;
 use databend_common_sql::plans::AnalyzeTablePlan;
@@ -44,7 +43,6 @@ use log::info;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
-use crate::schedulers::build_query_pipeline;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -59,7 +57,11 @@ impl AnalyzeTableInterpreter {
         Ok(AnalyzeTableInterpreter { ctx, plan })
     }
 
-    async fn plan_sql(&self, sql: String) -> Result<(PhysicalPlan, BindContext)> {
+    async fn plan_sql(
+        &self,
+        sql: String,
+        streaming_histogram: bool,
+    ) -> Result<(PhysicalPlan, BindContext)> {
         let mut planner = Planner::new(self.ctx.clone());
         let (plan, _) = planner.plan_sql(&sql).await?;
         let (select_plan, bind_context) = match &plan {
@@ -71,6 +73,7 @@ impl AnalyzeTableInterpreter {
             } => {
                 let mut builder =
                     PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), false);
+                builder.set_streaming_histogram(streaming_histogram);
                 (
                     builder.build(s_expr, bind_context.column_set()).await?,
                     (**bind_context).clone(),
@@ -189,11 +192,11 @@ impl Interpreter for AnalyzeTableInterpreter {
 
             info!("Analyze via sql: {sql}");
 
-            let (physical_plan, bind_context) = self.plan_sql(sql).await?;
+            let (physical_plan, bind_context) = self.plan_sql(sql, false).await?;
             let mut build_res =
                 build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
-            // After profiling, computing histogram is heavy and the bottleneck is window function(90%).
-            // It's possible to OOM if the table is too large and spilling isn't enabled.
+            // The histogram is built from a sample of the column in a single pass, but it still
+            // scans the whole table for each column.
             // We add a setting `enable_analyze_histogram` to control whether to compute histogram(default is closed).
             let mut histogram_info_receivers = HashMap::new();
             if self.ctx.get_settings().get_enable_analyze_histogram()? {
@@ -206,16 +209,8 @@ impl Interpreter for AnalyzeTableInterpreter {
                         let col_name = format!("{quote}{}{quote}", f.name);
                         (
                             format!(
-                                "SELECT quantile, \
-                                    COUNT(DISTINCT {col_name}) AS ndv, \
-                                    MAX({col_name}) AS max_value, \
-                                    MIN({col_name}) AS min_value, \
-                                    COUNT() as count \
-                                FROM ( \
-                                    SELECT {col_name}, NTILE({}) OVER (ORDER BY {col_name}) AS quantile \
-                                    FROM {}.{} WHERE {col_name} IS DISTINCT FROM NULL \
-                                ) \
-                                GROUP BY quantile ORDER BY quantile",
+                                "SELECT HISTOGRAM({col_name}, {}) \
+                                FROM {}.{} WHERE {col_name} IS DISTINCT FROM NULL",
                                 DEFAULT_HISTOGRAM_BUCKETS, plan.database, plan.table,
                             ),
                             f.column_id(),
@@ -224,15 +219,14 @@ impl Interpreter for AnalyzeTableInterpreter {
                     .collect::<Vec<_>>();
                 for (sql, col_id) in histogram_sqls.into_iter() {
                     info!("Analyze histogram via sql: {sql}");
-                    let (mut histogram_plan, bind_context) = self.plan_sql(sql).await?;
+                    // The `Histogram` outputs the buckets instead of the result of `HISTOGRAM`.
+                    let (mut histogram_plan, _) = self.plan_sql(sql, true).await?;
                     if !self.ctx.get_cluster().is_empty() {
                         histogram_plan = remove_exchange(histogram_plan);
                     }
-                    let mut histogram_build_res = build_query_pipeline(
+                    let mut histogram_build_res = build_query_pipeline_without_render_result_set(
                         &QueryContext::create_from(self.ctx.as_ref()),
-                        &bind_context.columns,
                         &histogram_plan,
-                        false,
                     )
                     .await?;
                     let (tx, rx) = async_channel::unbounded();
@@ -301,6 +295,7 @@ fn remove_exchange(plan: PhysicalPlan) -> PhysicalPlan {
                     group_by: plan.group_by,
                     agg_funcs: plan.agg_funcs,
                     rank_limit: plan.rank_limit,
+                    early_termination_threshold: plan.early_termination_threshold,
                     enable_experimental_aggregate_hashtable: plan
                         .enable_experimental_aggregate_hashtable,
                     group_by_display: plan.group_by_display,
@@ -316,23 +311,11 @@ fn remove_exchange(plan: PhysicalPlan) -> PhysicalPlan {
                 group_by_display: plan.group_by_display,
                 stat_info: plan.stat_info,
             }),
-            PhysicalPlan::Window(plan) => PhysicalPlan::Window(Window {
+            PhysicalPlan::Histogram(plan) => PhysicalPlan::Histogram(Histogram {
                 plan_id: plan.plan_id,
-                index: plan.index,
                 input: Box::new(traverse(*plan.input)),
-                func: plan.func,
-                partition_by: plan.partition_by,
-                order_by: plan.order_by,
-                window_frame: plan.window_frame,
-                limit: plan.limit,
-            }),
-            PhysicalPlan::Sort(plan) => PhysicalPlan::Sort(Sort {
-                plan_id: plan.plan_id,
-                input: Box::new(traverse(*plan.input)),
-                order_by: plan.order_by,
-                limit: plan.limit,
-                after_exchange: plan.after_exchange,
-                pre_projection: plan.pre_projection,
+                column: plan.column,
+                num_buckets: plan.num_buckets,
                 stat_info: plan.stat_info,
             }),
             PhysicalPlan::Exchange(plan) => traverse(*plan.input),
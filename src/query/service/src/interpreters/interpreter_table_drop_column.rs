@@ -21,8 +21,10 @@ use databend_common_expression::DataSchema;
 use databend_common_meta_app::schema::DatabaseType;
 use databend_common_sql::plans::DropTableColumnPlan;
 use databend_common_sql::BloomIndexColumns;
+use databend_common_storages_result_cache::ResultCacheInvalidator;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::UserApiProvider;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
@@ -107,6 +109,7 @@ impl Interpreter for DropTableColumnInterpreter {
             }
         }
 
+        let column_id = field.column_id;
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let mut new_table_meta = table.get_table_info().meta.clone();
         new_table_meta.drop_column(&self.plan.column)?;
@@ -133,6 +136,16 @@ impl Interpreter for DropTableColumnInterpreter {
         )
         .await?;
 
+        // The cached results which read the dropped column are stale.
+        let meta_store = UserApiProvider::instance().get_meta_store_client();
+        ResultCacheInvalidator::create(meta_store)
+            .invalidate_for_column_drop(
+                self.ctx.get_tenant().tenant_name(),
+                table.get_id(),
+                column_id,
+            )
+            .await?;
+
         Ok(PipelineBuildResult::create())
     }
 }
@@ -456,6 +456,7 @@ impl ReclusterTableInterpreter {
                 kind: FragmentKind::Normal,
                 keys: vec![expr],
                 allow_adjust_parallelism: true,
+                consistent_hash: false,
                 ignore_exchange: false,
             }));
         }
@@ -476,7 +477,7 @@ impl ReclusterTableInterpreter {
         }));
 
         // Finally, commit the newly clustered table
-        Ok(Some(Self::add_commit_sink(
+        Ok(Some(self.add_commit_sink(
             plan,
             is_distributed,
             table_info,
@@ -484,7 +485,7 @@ impl ReclusterTableInterpreter {
             false,
             Some(recluster_info),
             table_meta_timestamps,
-        )))
+        )?))
     }
 
     async fn build_linear_plan(
@@ -522,7 +523,7 @@ impl ReclusterTableInterpreter {
                     table_meta_timestamps,
                 }));
 
-                Self::add_commit_sink(
+                self.add_commit_sink(
                     root,
                     is_distributed,
                     table_info,
@@ -534,7 +535,7 @@ impl ReclusterTableInterpreter {
                         removed_statistics: removed_segment_summary,
                     }),
                     table_meta_timestamps,
-                )
+                )?
             }
             ReclusterParts::Compact(parts) => {
                 let merge_meta = parts.partitions_type() == PartInfoType::LazyLevel;
@@ -546,7 +547,7 @@ impl ReclusterTableInterpreter {
                     table_meta_timestamps,
                 }));
 
-                Self::add_commit_sink(
+                self.add_commit_sink(
                     root,
                     is_distributed,
                     table_info,
@@ -554,7 +555,7 @@ impl ReclusterTableInterpreter {
                     merge_meta,
                     None,
                     table_meta_timestamps,
-                )
+                )?
             }
         };
         Ok(Some(plan))
@@ -702,7 +703,9 @@ impl ReclusterTableInterpreter {
         DataBlock::concat(&data_blocks)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn add_commit_sink(
+        &self,
         input: PhysicalPlan,
         is_distributed: bool,
         table_info: TableInfo,
@@ -710,7 +713,7 @@ impl ReclusterTableInterpreter {
         merge_meta: bool,
         recluster_info: Option<ReclusterInfoSideCar>,
         table_meta_timestamps: TableMetaTimestamps,
-    ) -> PhysicalPlan {
+    ) -> Result<PhysicalPlan> {
         let plan = if is_distributed {
             PhysicalPlan::Exchange(Exchange {
                 plan_id: 0,
@@ -718,6 +721,7 @@ impl ReclusterTableInterpreter {
                 kind: FragmentKind::Merge,
                 keys: vec![],
                 allow_adjust_parallelism: true,
+                consistent_hash: false,
                 ignore_exchange: false,
             })
         } else {
@@ -729,7 +733,8 @@ impl ReclusterTableInterpreter {
         } else {
             MutationKind::Compact
         };
-        PhysicalPlan::CommitSink(Box::new(CommitSink {
+        let settings = self.ctx.get_settings();
+        Ok(PhysicalPlan::CommitSink(Box::new(CommitSink {
             input: Box::new(plan),
             table_info,
             snapshot: Some(snapshot),
@@ -737,9 +742,11 @@ impl ReclusterTableInterpreter {
             update_stream_meta: vec![],
             deduplicated_label: None,
             table_meta_timestamps,
+            write_conflict_retry_count: settings.get_write_conflict_retry_count()?,
+            write_conflict_backoff_ms: settings.get_write_conflict_backoff_ms()?,
             plan_id: u32::MAX,
             recluster_info,
-        }))
+        })))
     }
 }
 
@@ -35,6 +35,8 @@ use crate::interpreters::common::table_option_validation::is_valid_block_per_seg
 use crate::interpreters::common::table_option_validation::is_valid_bloom_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
+use crate::interpreters::common::table_option_validation::is_valid_rollup_options;
+use crate::interpreters::common::table_option_validation::is_valid_row_access_policy;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -139,6 +141,11 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        is_valid_rollup_options(&self.plan.set_options, table.schema())?;
+        is_valid_row_access_policy(
+            &self.plan.set_options,
+            self.ctx.get_settings().get_sql_dialect()?,
+        )?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
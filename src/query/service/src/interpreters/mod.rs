@@ -18,6 +18,7 @@ mod hook;
 mod interpreter;
 mod interpreter_add_warehouse_cluster;
 mod interpreter_assign_warehouse_nodes;
+mod interpreter_async_aggregate;
 mod interpreter_catalog_create;
 mod interpreter_catalog_drop;
 mod interpreter_catalog_show_create;
@@ -168,6 +169,7 @@ pub use hook::HookOperator;
 pub use interpreter::interpreter_plan_sql;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
+pub use interpreter_async_aggregate::AsyncAggregateInterpreter;
 pub use interpreter_catalog_use::UseCatalogInterpreter;
 pub use interpreter_cluster_key_alter::AlterTableClusterKeyInterpreter;
 pub use interpreter_cluster_key_drop::DropTableClusterKeyInterpreter;
@@ -16,21 +16,33 @@ use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
 use databend_common_expression::AggregateFunctionRef;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Expr;
 use databend_common_expression::HashTableConfig;
 use databend_common_expression::LimitType;
 use databend_common_expression::SortColumnDescription;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
 use databend_common_functions::aggregates::AggregateFunctionSortDesc;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_pipeline_transforms::processors::TransformSortPartial;
+use databend_common_sql::evaluator::BlockOperator;
+use databend_common_sql::evaluator::CompoundBlockOperator;
 use databend_common_sql::executor::physical_plans::AggregateExpand;
 use databend_common_sql::executor::physical_plans::AggregateFinal;
 use databend_common_sql::executor::physical_plans::AggregateFunctionDesc;
 use databend_common_sql::executor::physical_plans::AggregatePartial;
+use databend_common_sql::executor::physical_plans::Correlation;
+use databend_common_sql::executor::physical_plans::GroupingId;
+use databend_common_sql::executor::physical_plans::Histogram;
+use databend_common_sql::executor::physical_plans::MaterializeAgg;
+use databend_common_sql::executor::physical_plans::SortMergeAggregate;
+use databend_common_sql::executor::physical_plans::TDigestAgg;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::plans::UDFType;
 use databend_common_sql::IndexType;
@@ -46,9 +58,27 @@ use crate::pipelines::processors::transforms::aggregator::PartialSingleStateAggr
 use crate::pipelines::processors::transforms::aggregator::TransformAggregateSpillWriter;
 use crate::pipelines::processors::transforms::aggregator::TransformExpandGroupingSets;
 use crate::pipelines::processors::transforms::aggregator::TransformPartialAggregate;
+use crate::pipelines::processors::transforms::aggregator::TransformSortMergeAggregate;
+use crate::pipelines::processors::transforms::TransformCorrelation;
+use crate::pipelines::processors::transforms::TransformGroupingId;
+use crate::pipelines::processors::transforms::TransformHistogram;
+use crate::pipelines::processors::transforms::TransformTDigestAgg;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
+    pub(crate) fn build_grouping_id(&mut self, grouping_id: &GroupingId) -> Result<()> {
+        self.build_pipeline(&grouping_id.input)?;
+
+        let input_schema = grouping_id.input.output_schema()?;
+        let grouping_id_offset =
+            input_schema.index_of(&grouping_id.grouping_id_index.to_string())?;
+        self.main_pipeline.add_transformer(|| {
+            TransformGroupingId::new(grouping_id.grouping_columns.clone(), grouping_id_offset)
+        });
+
+        Ok(())
+    }
+
     pub(crate) fn build_aggregate_expand(&mut self, expand: &AggregateExpand) -> Result<()> {
         self.build_pipeline(&expand.input)?;
         let input_schema = expand.input.output_schema()?;
@@ -132,6 +162,12 @@ impl PipelineBuilder {
                 .cluster_with_partial(true, self.ctx.get_cluster().nodes.len())
         };
 
+        // In cluster mode the partial payloads are scattered by bucket, passthrough is not supported.
+        let early_termination_threshold = match self.is_exchange_neighbor {
+            true => 0.0,
+            false => aggregate.early_termination_threshold,
+        };
+
         // For rank limit, we can filter data using sort with rank before partial
         if let Some(rank_limit) = &aggregate.rank_limit {
             let sort_desc = rank_limit
@@ -160,6 +196,7 @@ impl PipelineBuilder {
                 output,
                 params.clone(),
                 partial_agg_config.clone(),
+                early_termination_threshold,
             )?))
         })?;
 
@@ -226,7 +263,139 @@ impl PipelineBuilder {
         build_partition_bucket(&mut self.main_pipeline, params.clone())
     }
 
-    fn build_aggregator_params(
+    pub(crate) fn build_sort_merge_aggregate(
+        &mut self,
+        aggregate: &SortMergeAggregate,
+    ) -> Result<()> {
+        self.build_pipeline(&aggregate.input)?;
+
+        let max_block_size = self.settings.get_max_block_size()?;
+        let max_spill_io_requests = self.settings.get_max_spill_io_requests()?;
+        let params = Self::build_aggregator_params(
+            aggregate.input.output_schema()?,
+            &aggregate.group_by,
+            &aggregate.agg_funcs,
+            false,
+            false,
+            max_block_size as usize,
+            max_spill_io_requests as usize,
+        )?;
+
+        // The sorted input is merged into one stream, the rows of a group are adjacent.
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline
+            .try_add_accumulating_transformer(|| TransformSortMergeAggregate::try_create(&params))
+    }
+
+    pub(crate) fn build_histogram(&mut self, histogram: &Histogram) -> Result<()> {
+        self.build_pipeline(&histogram.input)?;
+
+        let input_schema = histogram.input.output_schema()?;
+        let offset = input_schema.index_of(&histogram.column.to_string())?;
+        let bound_type = input_schema.field(offset).data_type().remove_nullable();
+
+        // The sample must see all the rows to build one histogram.
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                input,
+                output,
+                TransformHistogram::new(offset, bound_type.clone(), histogram.num_buckets),
+            )))
+        })
+    }
+
+    pub(crate) fn build_correlation(&mut self, correlation: &Correlation) -> Result<()> {
+        self.build_pipeline(&correlation.input)?;
+
+        let input_schema = correlation.input.output_schema()?;
+        let x_offset = input_schema.index_of(&correlation.x_col.to_string())?;
+        let y_offset = input_schema.index_of(&correlation.y_col.to_string())?;
+        let output_schema = correlation.output_schema()?;
+        let output_type = output_schema.field(0).data_type().clone();
+
+        // The moments must see all the rows to output one coefficient.
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                input,
+                output,
+                TransformCorrelation::new(x_offset, y_offset, output_type.clone()),
+            )))
+        })
+    }
+
+    pub(crate) fn build_tdigest_agg(&mut self, tdigest_agg: &TDigestAgg) -> Result<()> {
+        self.build_pipeline(&tdigest_agg.input)?;
+
+        let input_schema = tdigest_agg.input.output_schema()?;
+        let offset = input_schema.index_of(&tdigest_agg.column.to_string())?;
+        let output_schema = tdigest_agg.output_schema()?;
+        let output_type = output_schema.field(0).data_type().clone();
+
+        // The sketch must see all the rows to output one percentile.
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                input,
+                output,
+                TransformTDigestAgg::new(
+                    offset,
+                    tdigest_agg.percentile,
+                    tdigest_agg.compression,
+                    output_type.clone(),
+                ),
+            )))
+        })
+    }
+
+    pub(crate) fn build_materialize_agg(&mut self, materialize_agg: &MaterializeAgg) -> Result<()> {
+        self.build_pipeline(&materialize_agg.input)?;
+
+        // Each group is a single row of the rollup table, cast the column read by each
+        // function to the result type of the function.
+        let input_schema = materialize_agg.input.output_schema()?;
+        let num_input_columns = input_schema.num_fields();
+        let mut exprs = Vec::with_capacity(materialize_agg.original_agg_funcs.len());
+        let mut projection = Vec::with_capacity(
+            materialize_agg.original_agg_funcs.len() + materialize_agg.group_by.len(),
+        );
+        for agg in materialize_agg.original_agg_funcs.iter() {
+            let offset = input_schema.index_of(&agg.arg_indices[0].to_string())?;
+            let field = input_schema.field(offset);
+            let expr = Expr::ColumnRef {
+                span: None,
+                id: offset,
+                data_type: field.data_type().clone(),
+                display_name: field.name().clone(),
+            };
+            exprs.push(check_cast(
+                None,
+                false,
+                expr,
+                &agg.sig.return_type,
+                &BUILTIN_FUNCTIONS,
+            )?);
+            projection.push(num_input_columns + projection.len());
+        }
+        for index in materialize_agg.group_by.iter() {
+            projection.push(input_schema.index_of(&index.to_string())?);
+        }
+
+        let operators = vec![
+            BlockOperator::Map {
+                exprs,
+                projections: None,
+            },
+            BlockOperator::Project { projection },
+        ];
+        self.main_pipeline.add_transformer(|| {
+            CompoundBlockOperator::new(operators.clone(), self.func_ctx.clone(), num_input_columns)
+        });
+        Ok(())
+    }
+
+    pub(crate) fn build_aggregator_params(
         input_schema: DataSchemaRef,
         group_by: &[IndexType],
         agg_funcs: &[AggregateFunctionDesc],
@@ -313,7 +482,9 @@ impl PipelineBuilder {
                             .collect(),
                         agg_func.sig.return_type.clone(),
                     ),
-                    Some((UDFType::Server(_), _state_fields)) => unimplemented!(),
+                    Some((UDFType::Server(_) | UDFType::Model(_), _state_fields)) => {
+                        unimplemented!()
+                    }
                 }
             })
             .collect::<Result<_>>()?;
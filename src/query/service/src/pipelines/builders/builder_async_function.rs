@@ -25,11 +25,7 @@ impl PipelineBuilder {
 
         let operators = TransformAsyncFunction::init_operators(&async_function.async_func_descs)?;
         self.main_pipeline.add_async_transformer(|| {
-            TransformAsyncFunction::new(
-                self.ctx.clone(),
-                async_function.async_func_descs.clone(),
-                operators.clone(),
-            )
+            TransformAsyncFunction::new(async_function.async_func_descs.clone(), operators.clone())
         });
 
         Ok(())
@@ -25,6 +25,7 @@ use databend_common_storages_fuse::operations::MutationGenerator;
 use databend_common_storages_fuse::operations::TableMutationAggregator;
 use databend_common_storages_fuse::operations::TransformMergeCommitMeta;
 use databend_common_storages_fuse::operations::TruncateGenerator;
+use databend_common_storages_fuse::operations::WriteConflictRetry;
 use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_table_meta::readers::snapshot_reader::TableSnapshotAccessor;
 
@@ -37,6 +38,10 @@ impl PipelineBuilder {
         let table = FuseTable::try_from_table(table.as_ref())?;
 
         self.main_pipeline.try_resize(1)?;
+        let write_conflict_retry = WriteConflictRetry {
+            retry_count: plan.write_conflict_retry_count,
+            backoff_ms: plan.write_conflict_backoff_ms,
+        };
         match &plan.commit_type {
             CommitType::Truncate { mode } => {
                 let prev_snapshot_id = match mode {
@@ -68,6 +73,7 @@ impl PipelineBuilder {
                         snapshot_gen.clone(),
                         input,
                         None,
+                        write_conflict_retry,
                         prev_snapshot_id,
                         plan.deduplicated_label.clone(),
                         plan.table_meta_timestamps,
@@ -117,6 +123,7 @@ impl PipelineBuilder {
                         snapshot_gen.clone(),
                         input,
                         None,
+                        write_conflict_retry,
                         None,
                         plan.deduplicated_label.clone(),
                         plan.table_meta_timestamps,
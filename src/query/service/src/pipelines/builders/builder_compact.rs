@@ -19,12 +19,16 @@ use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::BlockThresholds;
 use databend_common_pipeline_sources::EmptySource;
 use databend_common_pipeline_sources::PrefetchAsyncSourcer;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use databend_common_sql::executor::physical_plans::Compact;
 use databend_common_sql::executor::physical_plans::CompactSource as PhysicalCompactSource;
 use databend_common_sql::executor::physical_plans::MutationKind;
+use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::StreamContext;
 use databend_common_storages_fuse::operations::BlockCompactMutator;
 use databend_common_storages_fuse::operations::CompactLazyPartInfo;
@@ -37,6 +41,30 @@ use databend_common_storages_fuse::FuseTable;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
+    pub(crate) fn build_compact(&mut self, compact: &Compact) -> Result<()> {
+        let PhysicalPlan::CompactSource(compact_block) = compact.input.as_ref() else {
+            return Err(ErrorCode::Internal(
+                "The input of Compact must be a CompactSource",
+            ));
+        };
+        let table = self
+            .ctx
+            .build_table_by_table_info(&compact_block.table_info, None)?;
+        let table = FuseTable::try_from_table(table.as_ref())?;
+
+        let thresholds = table.get_block_thresholds();
+        let block_per_segment = (compact.target_segment_size_mb as usize * 1024 * 1024)
+            .div_ceil(thresholds.max_compressed_per_block)
+            .max(1);
+        let thresholds = BlockThresholds::new(
+            compact.target_block_size_rows as usize,
+            thresholds.max_bytes_per_block,
+            thresholds.max_compressed_per_block,
+            block_per_segment,
+        );
+        self.build_compact_blocks(compact_block, thresholds, compact.max_tasks.max(1))
+    }
+
     pub(crate) fn build_compact_source(
         &mut self,
         compact_block: &PhysicalCompactSource,
@@ -46,14 +74,30 @@ impl PipelineBuilder {
             .build_table_by_table_info(&compact_block.table_info, None)?;
         let table = FuseTable::try_from_table(table.as_ref())?;
 
+        let thresholds = table.get_block_thresholds();
+        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
+        self.build_compact_blocks(compact_block, thresholds, max_threads)
+    }
+
+    /// Read the blocks of `compact_block.parts` with at most `max_threads` tasks,
+    /// and rewrite them according to `thresholds`.
+    fn build_compact_blocks(
+        &mut self,
+        compact_block: &PhysicalCompactSource,
+        thresholds: BlockThresholds,
+        mut max_threads: usize,
+    ) -> Result<()> {
+        let table = self
+            .ctx
+            .build_table_by_table_info(&compact_block.table_info, None)?;
+        let table = FuseTable::try_from_table(table.as_ref())?;
+
         if compact_block.parts.is_empty() {
             return self.main_pipeline.add_source(EmptySource::create, 1);
         }
 
         let is_lazy = compact_block.parts.partitions_type() == PartInfoType::LazyLevel;
-        let thresholds = table.get_block_thresholds();
         let cluster_key_id = table.cluster_key_id();
-        let mut max_threads = self.ctx.get_settings().get_max_threads()? as usize;
 
         if is_lazy {
             let query_ctx = self.ctx.clone();
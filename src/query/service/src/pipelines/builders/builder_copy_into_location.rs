@@ -12,9 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::CopyIntoLocationOptions;
+use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::infer_table_schema;
+use databend_common_meta_app::principal::StageInfo;
 use databend_common_sql::executor::physical_plans::CopyIntoLocation;
+use databend_common_sql::executor::physical_plans::StreamOutput;
+use databend_common_storage::StageFilesInfo;
 use databend_common_storages_stage::StageTable;
 
 use crate::pipelines::PipelineBuilder;
@@ -45,4 +51,43 @@ impl PipelineBuilder {
             Default::default(),
         )
     }
+    pub(crate) fn build_stream_output(&mut self, output: &StreamOutput) -> Result<()> {
+        self.build_pipeline(&output.input)?;
+
+        let input_schema = output.input.output_schema()?;
+        let user = self.ctx.get_current_user()?;
+        let mut stage_info = StageInfo::new_user_stage(&user.name);
+        stage_info.file_format_params = output.file_format_params()?;
+
+        let to_table = StageTable::try_create(StageTableInfo {
+            schema: infer_table_schema(&input_schema)?,
+            stage_info,
+            files_info: StageFilesInfo {
+                path: output.path.clone(),
+                files: None,
+                pattern: None,
+            },
+            files_to_copy: None,
+            duplicated_files_detected: vec![],
+            is_select: false,
+            default_values: None,
+            copy_into_location_options: CopyIntoLocationOptions {
+                max_file_size: output.max_file_size.unwrap_or_default() as usize,
+                ..Default::default()
+            },
+            copy_into_table_options: Default::default(),
+            stage_root: "".to_string(),
+        })?;
+        PipelineBuilder::build_append2table_with_commit_pipeline(
+            self.ctx.clone(),
+            &mut self.main_pipeline,
+            to_table,
+            input_schema,
+            None,
+            vec![],
+            false,
+            unsafe { self.ctx.get_settings().get_deduplicate_label()? },
+            Default::default(),
+        )
+    }
 }
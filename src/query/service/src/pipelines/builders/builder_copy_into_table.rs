@@ -33,12 +33,14 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_sql::executor::physical_plans::CopyIntoTable;
 use databend_common_sql::executor::physical_plans::CopyIntoTableSource;
+use databend_common_sql::executor::physical_plans::EnforceSchema;
 use databend_common_sql::plans::CopyIntoTableMode;
 use databend_common_storage::StageFileInfo;
 use log::debug;
 use log::info;
 
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
+use crate::pipelines::processors::transforms::TransformEnforceSchema;
 use crate::pipelines::processors::TransformCastSchema;
 use crate::pipelines::processors::TransformNullIf;
 use crate::pipelines::PipelineBuilder;
@@ -91,6 +93,20 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    pub(crate) fn build_enforce_schema(&mut self, enforce: &EnforceSchema) -> Result<()> {
+        self.build_pipeline(&enforce.input)?;
+
+        let input_schema = enforce.input.output_schema()?;
+        self.main_pipeline.try_add_transformer(|| {
+            TransformEnforceSchema::try_new(
+                input_schema.clone(),
+                enforce.target_schema.clone(),
+                enforce.cast_mode,
+                self.func_ctx.clone(),
+            )
+        })
+    }
+
     fn need_null_if_processor<'a>(
         plan: &'a CopyIntoTable,
         source_schema: &Arc<DataSchema>,
@@ -13,9 +13,22 @@
 // limitations under the License.
 
 use databend_common_exception::Result;
+use databend_common_expression::infer_table_schema;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use databend_common_sql::executor::physical_plans::BloomBuild;
 use databend_common_sql::executor::physical_plans::DistributedInsertSelect;
+use databend_common_sql::executor::physical_plans::Emit;
+use databend_common_sql::executor::physical_plans::WriteAheadLog;
+use databend_common_storages_fuse::io::TableMetaLocationGenerator;
+use databend_common_storages_fuse::FuseTable;
 
+use crate::pipelines::processors::transforms::BloomBuildState;
+use crate::pipelines::processors::transforms::TransformBloomBuild;
+use crate::pipelines::processors::transforms::TransformEmit;
+use crate::pipelines::processors::transforms::TransformWriteAheadLog;
+use crate::pipelines::processors::transforms::WriteAheadLogState;
 use crate::pipelines::processors::TransformCastSchema;
 use crate::pipelines::PipelineBuilder;
 
@@ -68,4 +81,78 @@ impl PipelineBuilder {
 
         Ok(())
     }
+
+    pub(crate) fn build_emit(&mut self, emit: &Emit) -> Result<()> {
+        self.build_pipeline(&emit.input)?;
+
+        let table = self.ctx.build_table_by_table_info(&emit.table_info, None)?;
+        Self::fill_and_reorder_columns(
+            self.ctx.clone(),
+            &mut self.main_pipeline,
+            table,
+            emit.insert_schema.clone(),
+        )?;
+
+        let returning_exprs = emit
+            .returning_exprs
+            .iter()
+            .map(|(expr, _)| expr.clone())
+            .collect::<Vec<_>>();
+        self.main_pipeline
+            .try_add_transformer(|| TransformEmit::try_new(self.ctx.clone(), &returning_exprs))
+    }
+
+    pub(crate) fn build_bloom_build(&mut self, bloom_build: &BloomBuild) -> Result<()> {
+        self.build_pipeline(&bloom_build.input)?;
+
+        let table = self
+            .ctx
+            .build_table_by_table_info(&bloom_build.table_info, None)?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let operator = fuse_table.get_operator();
+        let location = TableMetaLocationGenerator::gen_bloom_index_location_from_block_location(
+            &bloom_build.segment_location,
+        );
+        let input_schema = bloom_build.input.output_schema()?;
+        let state = BloomBuildState::new(self.main_pipeline.output_len());
+
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformBloomBuild::try_new(
+                self.func_ctx.clone(),
+                input_schema.clone(),
+                bloom_build.bloom_columns.clone(),
+                bloom_build.bloom_fields.clone(),
+                location.clone(),
+                operator.clone(),
+                state.clone(),
+            )?;
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input, output, transform,
+            )))
+        })
+    }
+
+    pub(crate) fn build_write_ahead_log(&mut self, wal: &WriteAheadLog) -> Result<()> {
+        self.build_pipeline(&wal.input)?;
+
+        let table = self.ctx.build_table_by_table_info(&wal.table_info, None)?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let operator = fuse_table.get_operator();
+        let wal_schema = infer_table_schema(&wal.wal_schema)?;
+        let state = WriteAheadLogState::new(self.main_pipeline.output_len());
+
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformWriteAheadLog::new(
+                wal.wal_columns.clone(),
+                wal_schema.clone(),
+                wal.flush_interval_ms,
+                wal.wal_location.clone(),
+                operator.clone(),
+                state.clone(),
+            );
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input, output, transform,
+            )))
+        })
+    }
 }
@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_sql::executor::physical_plans::ExchangeSink;
 use databend_common_sql::executor::physical_plans::ExchangeSource;
+use databend_common_sql::executor::physical_plans::SkewDetection;
 
+use crate::pipelines::processors::transforms::SkewDetectionState;
+use crate::pipelines::processors::transforms::TransformSkewDetection;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -47,4 +52,26 @@ impl PipelineBuilder {
         self.is_exchange_neighbor = is_exchange_neighbor;
         Ok(())
     }
+
+    pub(crate) fn build_skew_detection(&mut self, skew_detection: &SkewDetection) -> Result<()> {
+        self.build_pipeline(&skew_detection.input)?;
+
+        // The rows received by this node are split into the same number of hash
+        // partitions as the threads processing them.
+        let num_partitions = self.ctx.get_settings().get_max_threads()? as usize;
+        let state = SkewDetectionState::new(
+            num_partitions,
+            skew_detection.skew_threshold,
+            self.main_pipeline.output_len(),
+        );
+        self.main_pipeline.add_transformer(|| {
+            TransformSkewDetection::new(
+                skew_detection.plan_id,
+                self.func_ctx.clone(),
+                &skew_detection.partition_key,
+                state.clone(),
+            )
+        });
+        Ok(())
+    }
 }
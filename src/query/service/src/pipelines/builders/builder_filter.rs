@@ -14,6 +14,8 @@
 
 use databend_common_exception::Result;
 use databend_common_sql::executor::physical_plans::Filter;
+use databend_common_sql::executor::physical_plans::Qualify;
+use databend_common_sql::executor::physical_plans::RowAccessPolicy;
 
 use crate::pipelines::PipelineBuilder;
 impl PipelineBuilder {
@@ -25,4 +27,26 @@ impl PipelineBuilder {
 
         Ok(())
     }
+
+    pub(crate) fn build_qualify(&mut self, qualify: &Qualify) -> Result<()> {
+        self.build_pipeline(&qualify.input)?;
+        self.main_pipeline.add_transform(
+            self.filter_transform_builder(&qualify.predicates, qualify.projections.clone())?,
+        )?;
+
+        Ok(())
+    }
+
+    pub(crate) fn build_row_access_policy(
+        &mut self,
+        row_access_policy: &RowAccessPolicy,
+    ) -> Result<()> {
+        self.build_pipeline(&row_access_policy.input)?;
+        let predicates = [row_access_policy.policy_filter.clone()];
+        let projections = (0..row_access_policy.input.output_schema()?.num_fields()).collect();
+        self.main_pipeline
+            .add_transform(self.filter_transform_builder(&predicates, projections)?)?;
+
+        Ok(())
+    }
 }
@@ -21,8 +21,14 @@ use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
 use databend_common_expression::LimitType;
 use databend_common_expression::SortColumnDescription;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::PartitionProcessor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::DynTransformBuilder;
+use databend_common_pipeline_core::Pipe;
+use databend_common_pipeline_core::PipeItem;
 use databend_common_pipeline_sinks::AsyncSinker;
 use databend_common_pipeline_transforms::processors::TransformSortPartial;
 use databend_common_sql::executor::physical_plans::ChunkAppendData;
@@ -33,11 +39,13 @@ use databend_common_sql::executor::physical_plans::ChunkFillAndReorder;
 use databend_common_sql::executor::physical_plans::ChunkFilter;
 use databend_common_sql::executor::physical_plans::ChunkMerge;
 use databend_common_sql::executor::physical_plans::Duplicate;
+use databend_common_sql::executor::physical_plans::Scatter;
 use databend_common_sql::executor::physical_plans::Shuffle;
 use databend_common_storages_fuse::operations::CommitMultiTableInsert;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
 
+use crate::pipelines::processors::transforms::ScatterExchange;
 use crate::pipelines::PipelineBuilder;
 use crate::sql::evaluator::CompoundBlockOperator;
 impl PipelineBuilder {
@@ -47,6 +55,34 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    pub(crate) fn build_scatter(&mut self, plan: &Scatter) -> Result<()> {
+        self.build_pipeline(&plan.input)?;
+        let conditions = plan
+            .conditions
+            .iter()
+            .map(|condition| condition.as_expr(&BUILTIN_FUNCTIONS))
+            .collect();
+        let exchange =
+            ScatterExchange::create(self.func_ctx.clone(), conditions, plan.targets.clone());
+
+        // Same layout as `Duplicate`, each input port has one output port per branch.
+        let n = plan.targets.len();
+        let input_len = self.main_pipeline.output_len();
+        let mut items = Vec::with_capacity(input_len);
+        for _ in 0..input_len {
+            let input = InputPort::create();
+            let outputs = (0..n).map(|_| OutputPort::create()).collect::<Vec<_>>();
+            items.push(PipeItem::create(
+                PartitionProcessor::create(input.clone(), outputs.clone(), exchange.clone()),
+                vec![input],
+                outputs,
+            ));
+        }
+        self.main_pipeline
+            .add_pipe(Pipe::create(input_len, input_len * n, items));
+        Ok(())
+    }
+
     pub(crate) fn build_shuffle(&mut self, plan: &Shuffle) -> Result<()> {
         self.build_pipeline(&plan.input)?;
         self.main_pipeline
@@ -20,11 +20,19 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_sinks::Sinker;
 use databend_common_sql::executor::physical_plans::HashJoin;
 use databend_common_sql::executor::physical_plans::RangeJoin;
+use databend_common_sql::executor::physical_plans::SemiHashJoin;
+use databend_common_sql::executor::physical_plans::SortMergeJoin;
 use databend_common_sql::executor::PhysicalPlan;
 
 use crate::pipelines::processors::transforms::range_join::RangeJoinState;
 use crate::pipelines::processors::transforms::range_join::TransformRangeJoinLeft;
 use crate::pipelines::processors::transforms::range_join::TransformRangeJoinRight;
+use crate::pipelines::processors::transforms::semi_hash_join::SemiHashJoinState;
+use crate::pipelines::processors::transforms::semi_hash_join::TransformSemiHashJoinBuild;
+use crate::pipelines::processors::transforms::semi_hash_join::TransformSemiHashJoinProbe;
+use crate::pipelines::processors::transforms::sort_merge_join::SortMergeJoinState;
+use crate::pipelines::processors::transforms::sort_merge_join::TransformSortMergeJoinLeft;
+use crate::pipelines::processors::transforms::sort_merge_join::TransformSortMergeJoinRight;
 use crate::pipelines::processors::transforms::HashJoinBuildState;
 use crate::pipelines::processors::transforms::HashJoinProbeState;
 use crate::pipelines::processors::transforms::TransformHashJoinBuild;
@@ -88,6 +96,79 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    pub(crate) fn build_semi_hash_join(&mut self, join: &SemiHashJoin) -> Result<()> {
+        let state = SemiHashJoinState::create(self.func_ctx.clone(), join);
+
+        // The build side only collects the distinct keys into the shared state.
+        let build_side_context = QueryContext::create_from(self.ctx.as_ref());
+        let mut build_side_builder = PipelineBuilder::create(
+            self.func_ctx.clone(),
+            self.settings.clone(),
+            build_side_context,
+            self.main_pipeline.get_scopes(),
+        );
+        build_side_builder.hash_join_states = self.hash_join_states.clone();
+
+        let mut build_res = build_side_builder.finalize(&join.build)?;
+        build_res.main_pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(
+                Sinker::<TransformSemiHashJoinBuild>::create(
+                    input,
+                    TransformSemiHashJoinBuild::create(state.clone()),
+                ),
+            ))
+        })?;
+        self.pipelines.push(build_res.main_pipeline.finalize());
+        self.pipelines.extend(build_res.sources_pipelines);
+
+        self.build_pipeline(&join.probe)?;
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(TransformSemiHashJoinProbe::create(
+                input,
+                output,
+                state.clone(),
+            )))
+        })
+    }
+
+    pub(crate) fn build_sort_merge_join(&mut self, join: &SortMergeJoin) -> Result<()> {
+        let state = SortMergeJoinState::create(self.func_ctx.clone(), join)?;
+
+        // The right side is collected in order into the shared state.
+        let right_side_context = QueryContext::create_from(self.ctx.as_ref());
+        let mut right_side_builder = PipelineBuilder::create(
+            self.func_ctx.clone(),
+            self.settings.clone(),
+            right_side_context,
+            self.main_pipeline.get_scopes(),
+        );
+        right_side_builder.hash_join_states = self.hash_join_states.clone();
+
+        let mut right_res = right_side_builder.finalize(&join.right)?;
+        // Both the sides are sorted into a single stream, which must not be split.
+        right_res.main_pipeline.try_resize(1)?;
+        right_res.main_pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(
+                Sinker::<TransformSortMergeJoinRight>::create(
+                    input,
+                    TransformSortMergeJoinRight::create(state.clone()),
+                ),
+            ))
+        })?;
+        self.pipelines.push(right_res.main_pipeline.finalize());
+        self.pipelines.extend(right_res.sources_pipelines);
+
+        self.build_pipeline(&join.left)?;
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(TransformSortMergeJoinLeft::create(
+                input,
+                output,
+                state.clone(),
+            )))
+        })
+    }
+
     pub(crate) fn build_join(&mut self, join: &HashJoin) -> Result<()> {
         // for merge into target table as build side.
         let (enable_merge_into_optimization, merge_into_is_distributed) =
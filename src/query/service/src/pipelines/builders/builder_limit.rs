@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use databend_common_exception::Result;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_sql::executor::physical_plans::ConditionalLimit;
 use databend_common_sql::executor::physical_plans::Limit;
 
+use crate::pipelines::processors::transforms::TransformConditionalLimit;
 use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::PipelineBuilder;
 
@@ -24,6 +27,21 @@ impl PipelineBuilder {
         self.build_pipeline(&limit.input)?;
 
         if limit.limit.is_some() || limit.offset != 0 {
+            // Stop each parallel branch once it has produced enough rows, instead of
+            // waiting for the merged stream to reach the limit.
+            if let Some(pre_limit) = limit.pre_limit {
+                if self.main_pipeline.output_len() > 1 {
+                    self.main_pipeline.add_transform(|input, output| {
+                        Ok(ProcessorPtr::create(TransformLimit::try_create(
+                            Some(pre_limit),
+                            0,
+                            input,
+                            output,
+                        )?))
+                    })?;
+                }
+            }
+
             self.main_pipeline.try_resize(1)?;
             return self.main_pipeline.add_transform(|input, output| {
                 Ok(ProcessorPtr::create(TransformLimit::try_create(
@@ -36,4 +54,22 @@ impl PipelineBuilder {
         }
         Ok(())
     }
+
+    pub(crate) fn build_conditional_limit(&mut self, limit: &ConditionalLimit) -> Result<()> {
+        self.build_pipeline(&limit.input)?;
+
+        let condition = limit.condition.as_expr(&BUILTIN_FUNCTIONS);
+        let max_block_size = self.settings.get_max_block_size()? as usize;
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(TransformConditionalLimit::create(
+                input,
+                output,
+                condition.clone(),
+                limit.limit,
+                self.func_ctx.clone(),
+                max_block_size,
+            )))
+        })
+    }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::FunctionContext;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -22,10 +23,14 @@ use databend_common_pipeline_sinks::EmptySink;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
+use databend_common_sql::executor::physical_plans::FlatMap;
 use databend_common_sql::executor::physical_plans::ProjectSet;
+use databend_common_sql::executor::physical_plans::Transpose;
+use databend_common_sql::executor::physical_plans::Zip;
 use databend_common_sql::ColumnBinding;
 
 use crate::pipelines::processors::transforms::TransformSRF;
+use crate::pipelines::processors::transforms::TransformTranspose;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -89,7 +94,74 @@ impl PipelineBuilder {
                 project_set.projections.clone(),
                 srf_exprs.clone(),
                 max_block_size,
+                false,
             )))
         })
     }
+
+    pub(crate) fn build_zip(&mut self, zip: &Zip) -> Result<()> {
+        self.build_pipeline(&zip.input)?;
+
+        let array_exprs = zip
+            .arrays
+            .iter()
+            .map(|(expr, _)| expr.as_expr(&BUILTIN_FUNCTIONS))
+            .collect::<Vec<_>>();
+        let max_block_size = self.settings.get_max_block_size()? as usize;
+
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(TransformSRF::try_create(
+                input,
+                output,
+                self.func_ctx.clone(),
+                zip.projections.clone(),
+                array_exprs.clone(),
+                max_block_size,
+                zip.with_ordinality.is_some(),
+            )))
+        })
+    }
+
+    pub(crate) fn build_flat_map(&mut self, flat_map: &FlatMap) -> Result<()> {
+        self.build_pipeline(&flat_map.input)?;
+
+        // Call the table function for each row and unnest the returned array.
+        let args = flat_map
+            .args
+            .iter()
+            .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+            .collect::<Vec<_>>();
+        let func = check_function(None, &flat_map.func_name, &[], &args, &BUILTIN_FUNCTIONS)?;
+        let srf = check_function(None, "unnest", &[], &[func], &BUILTIN_FUNCTIONS)?;
+        let max_block_size = self.settings.get_max_block_size()? as usize;
+
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(TransformSRF::try_create(
+                input,
+                output,
+                self.func_ctx.clone(),
+                flat_map.projections.clone(),
+                vec![srf.clone()],
+                max_block_size,
+                false,
+            )))
+        })
+    }
+
+    pub(crate) fn build_transpose(&mut self, transpose: &Transpose) -> Result<()> {
+        self.build_pipeline(&transpose.input)?;
+
+        let input_schema = transpose.input.output_schema()?;
+        let unpivot_offsets = transpose
+            .columns_to_unpivot
+            .iter()
+            .map(|index| input_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.main_pipeline.add_transformer(|| {
+            TransformTranspose::new(unpivot_offsets.clone(), transpose.names.clone())
+        });
+
+        Ok(())
+    }
 }
@@ -89,6 +89,7 @@ impl PipelineBuilder {
                     data_mask_policy: None,
                     table_index: usize::MAX,
                     scan_id: usize::MAX,
+                    prefetch_depth: 0,
                 };
 
                 {
@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use databend_common_exception::Result;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::Sinker;
+use databend_common_sql::executor::physical_plans::CacheScan;
+use databend_common_sql::executor::physical_plans::CteMaterialization;
+use databend_common_sql::executor::physical_plans::Replicate;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::plans::CacheSource;
+
+use crate::pipelines::processors::transforms::TransformReplicateSink;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+
+impl PipelineBuilder {
+    pub(crate) fn build_replicate(&mut self, replicate: &Replicate) -> Result<()> {
+        self.build_shared_blocks(replicate.cache_key, &replicate.input)?;
+
+        let output_schema = replicate.output_schema()?;
+        let column_indexes = (0..output_schema.num_fields()).collect();
+        self.build_cache_scan(&CacheScan {
+            plan_id: replicate.plan_id,
+            cache_source: CacheSource::Replicate((replicate.cache_key, column_indexes)),
+            output_schema,
+        })
+    }
+
+    pub(crate) fn build_cte_materialization(&mut self, cte: &CteMaterialization) -> Result<()> {
+        // The blocks of the cte body are shared among the consumers like `Replicate`.
+        let mut hasher = DefaultHasher::new();
+        ("cte", cte.cte_id).hash(&mut hasher);
+        let cache_key = hasher.finish();
+        self.build_shared_blocks(cache_key, &cte.body)?;
+
+        self.build_cache_scan(&CacheScan {
+            plan_id: cte.plan_id,
+            cache_source: CacheSource::Replicate((cache_key, cte.column_indexes.clone())),
+            output_schema: cte.output_schema.clone(),
+        })
+    }
+
+    // The input is only executed by the first plan with the cache key, which stores
+    // the result blocks in the query context, the others just read the stored blocks.
+    fn build_shared_blocks(&mut self, cache_key: u64, input: &PhysicalPlan) -> Result<()> {
+        let (state, created) = self.ctx.get_or_create_replicate_state(cache_key);
+        if !created {
+            return Ok(());
+        }
+
+        let replicate_context = QueryContext::create_from(self.ctx.as_ref());
+        let mut replicate_builder = PipelineBuilder::create(
+            self.func_ctx.clone(),
+            self.settings.clone(),
+            replicate_context,
+            self.main_pipeline.get_scopes(),
+        );
+        replicate_builder.hash_join_states = self.hash_join_states.clone();
+
+        let mut replicate_res = replicate_builder.finalize(input)?;
+        replicate_res.main_pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(
+                Sinker::<TransformReplicateSink>::create(
+                    input,
+                    TransformReplicateSink::create(state.clone()),
+                ),
+            ))
+        })?;
+        self.pipelines.push(replicate_res.main_pipeline.finalize());
+        self.pipelines.extend(replicate_res.sources_pipelines);
+        Ok(())
+    }
+}
@@ -13,12 +13,23 @@
 // limitations under the License.
 
 use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
+use databend_common_sql::executor::physical_plans::ConvertTimezone;
 use databend_common_sql::executor::physical_plans::EvalScalar;
+use databend_common_sql::executor::physical_plans::JsonEach;
+use databend_common_sql::executor::physical_plans::JsonExtract;
+use databend_common_sql::executor::physical_plans::JsonTable;
+use databend_common_sql::executor::physical_plans::MaskApply;
 
+use crate::pipelines::processors::transforms::JsonPathElement;
+use crate::pipelines::processors::transforms::TransformConvertTimezone;
+use crate::pipelines::processors::transforms::TransformJsonEach;
+use crate::pipelines::processors::transforms::TransformJsonExtract;
+use crate::pipelines::processors::transforms::TransformJsonTable;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -49,4 +60,115 @@ impl PipelineBuilder {
 
         Ok(())
     }
+
+    pub(crate) fn build_convert_timezone(
+        &mut self,
+        convert_timezone: &ConvertTimezone,
+    ) -> Result<()> {
+        self.build_pipeline(&convert_timezone.input)?;
+
+        let input_schema = convert_timezone.input.output_schema()?;
+        let offsets = convert_timezone
+            .columns
+            .iter()
+            .map(|index| input_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.main_pipeline.try_add_transformer(|| {
+            TransformConvertTimezone::try_new(
+                offsets.clone(),
+                &convert_timezone.tz,
+                convert_timezone.direction,
+            )
+        })
+    }
+
+    pub(crate) fn build_mask_apply(&mut self, mask_apply: &MaskApply) -> Result<()> {
+        self.build_pipeline(&mask_apply.input)?;
+
+        let input_schema = mask_apply.input.output_schema()?;
+        let num_input_columns = input_schema.num_fields();
+        let mut exprs = Vec::with_capacity(mask_apply.masking_rules.len());
+        let mut projection = (0..num_input_columns).collect::<Vec<_>>();
+        for (index, expr) in mask_apply.masking_rules.iter() {
+            let offset = input_schema.index_of(&index.to_string())?;
+            let expr = expr
+                .as_expr(&BUILTIN_FUNCTIONS)
+                .project_column_ref(|_| offset);
+            // The policy may return a non-nullable value for a nullable column.
+            let data_type = input_schema.field(offset).data_type();
+            let expr = if expr.data_type() != data_type {
+                check_cast(None, false, expr, data_type, &BUILTIN_FUNCTIONS)?
+            } else {
+                expr
+            };
+            exprs.push(expr);
+            // The masked value is appended after the input columns, take its place.
+            projection[offset] = num_input_columns + exprs.len() - 1;
+        }
+
+        let ops = vec![
+            BlockOperator::Map {
+                exprs,
+                projections: None,
+            },
+            BlockOperator::Project { projection },
+        ];
+
+        self.main_pipeline.add_transformer(|| {
+            CompoundBlockOperator::new(ops.clone(), self.func_ctx.clone(), num_input_columns)
+        });
+
+        Ok(())
+    }
+
+    pub(crate) fn build_json_extract(&mut self, json_extract: &JsonExtract) -> Result<()> {
+        self.build_pipeline(&json_extract.input)?;
+
+        let input_schema = json_extract.input.output_schema()?;
+        let source_offset = input_schema.index_of(&json_extract.source_col.to_string())?;
+        let paths = json_extract
+            .paths
+            .iter()
+            .map(|(_, elements)| JsonPathElement::parse_path(elements))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.main_pipeline
+            .add_transformer(|| TransformJsonExtract::new(source_offset, paths.clone()));
+
+        Ok(())
+    }
+
+    pub(crate) fn build_json_each(&mut self, json_each: &JsonEach) -> Result<()> {
+        self.build_pipeline(&json_each.input)?;
+
+        let input_schema = json_each.input.output_schema()?;
+        let source_offset = input_schema.index_of(&json_each.source_col.to_string())?;
+
+        self.main_pipeline
+            .add_transformer(|| TransformJsonEach::new(source_offset));
+
+        Ok(())
+    }
+
+    pub(crate) fn build_json_table(&mut self, json_table: &JsonTable) -> Result<()> {
+        self.build_pipeline(&json_table.input)?;
+
+        let input_schema = json_table.input.output_schema()?;
+        let source_offset = input_schema.index_of(&json_table.source_col.to_string())?;
+        let columns = json_table
+            .paths
+            .iter()
+            .map(|(_, path, data_type)| (path.clone(), data_type.clone()))
+            .collect::<Vec<_>>();
+
+        self.main_pipeline.try_add_transformer(|| {
+            TransformJsonTable::try_create(
+                self.func_ctx.clone(),
+                source_offset,
+                json_table.row_path.clone(),
+                columns.clone(),
+            )
+        })
+    }
 }
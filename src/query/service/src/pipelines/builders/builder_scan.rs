@@ -12,27 +12,53 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_base::runtime::Runtime;
+use databend_common_catalog::plan::bitmap_index_lookup;
+use databend_common_catalog::plan::BitmapIndex;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::SpatialIndex;
+use databend_common_catalog::plan::SpatialPredicate;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_sources::OneBlockSource;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
+use databend_common_sql::executor::physical_plans::BitmapIndexScan;
+use databend_common_sql::executor::physical_plans::BlockSample;
+use databend_common_sql::executor::physical_plans::BloomLookup;
 use databend_common_sql::executor::physical_plans::CacheScan;
 use databend_common_sql::executor::physical_plans::ConstantTableScan;
 use databend_common_sql::executor::physical_plans::ExpressionScan;
+use databend_common_sql::executor::physical_plans::FunctionImport;
+use databend_common_sql::executor::physical_plans::GeoScan;
+use databend_common_sql::executor::physical_plans::ResultCacheScan;
+use databend_common_sql::executor::physical_plans::SchemaEvolve;
 use databend_common_sql::executor::physical_plans::TableScan;
+use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::plans::CacheSource;
+use databend_common_storages_fuse::FuseBlockPartInfo;
+use databend_common_storages_fuse::FuseLazyPartInfo;
+use databend_common_storages_fuse::FuseTable;
 
 use crate::pipelines::processors::transforms::CacheSourceState;
 use crate::pipelines::processors::transforms::HashJoinCacheState;
+use crate::pipelines::processors::transforms::ReplicateCacheState;
 use crate::pipelines::processors::transforms::TransformAddInternalColumns;
 use crate::pipelines::processors::transforms::TransformCacheScan;
 use crate::pipelines::processors::transforms::TransformExpressionScan;
+use crate::pipelines::processors::transforms::TransformFunctionImport;
+use crate::pipelines::processors::transforms::TransformResultCacheScan;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -50,12 +76,20 @@ impl PipelineBuilder {
                 self.pipelines.push(prune_pipeline);
             }
         }
-        table.read_data(
-            self.ctx.clone(),
-            &scan.source,
-            &mut self.main_pipeline,
-            true,
-        )?;
+        if scan.speculative_prefetch {
+            let source = DataSourcePlan {
+                prefetch_depth: scan.prefetch_depth,
+                ..scan.source.as_ref().clone()
+            };
+            table.read_data(self.ctx.clone(), &source, &mut self.main_pipeline, true)?;
+        } else {
+            table.read_data(
+                self.ctx.clone(),
+                &scan.source,
+                &mut self.main_pipeline,
+                true,
+            )?;
+        }
 
         // Fill internal columns if needed.
         if let Some(internal_columns) = &scan.internal_column {
@@ -83,6 +117,152 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    pub(crate) fn build_geo_scan(&mut self, geo_scan: &GeoScan) -> Result<()> {
+        let PhysicalPlan::TableScan(scan) = geo_scan.input.as_ref() else {
+            return Err(ErrorCode::Internal(format!(
+                "The input of GeoScan must be a TableScan, but got {}",
+                geo_scan.input.name()
+            )));
+        };
+
+        let table = self.ctx.build_table_from_source_plan(&scan.source)?;
+        let spatial_index = scan
+            .name_mapping
+            .iter()
+            .find(|(_, index)| **index == geo_scan.geo_column)
+            .map(|(name, _)| table.schema().field_with_name(name).map(|f| f.column_id()))
+            .transpose()?
+            .and_then(|column_id| table.spatial_index(column_id));
+        match spatial_index {
+            Some(spatial_index) => {
+                let mut scan = scan.clone();
+                scan.source.parts = prune_partitions_by_spatial_index(
+                    &scan.source.parts,
+                    spatial_index.as_ref(),
+                    &geo_scan.spatial_predicate,
+                )?;
+                self.build_pipeline(&PhysicalPlan::TableScan(scan))?;
+            }
+            None => self.build_pipeline(&geo_scan.input)?,
+        }
+
+        if let Some(filter) = &geo_scan.fallback_filter {
+            let projections = (0..geo_scan.input.output_schema()?.num_fields()).collect();
+            self.main_pipeline
+                .add_transform(self.filter_transform_builder(&[filter.clone()], projections)?)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn build_bloom_lookup(&mut self, lookup: &BloomLookup) -> Result<()> {
+        let PhysicalPlan::TableScan(scan) = lookup.inner_scan.as_ref() else {
+            return Err(ErrorCode::Internal(format!(
+                "The inner scan of BloomLookup must be a TableScan, but got {}",
+                lookup.inner_scan.name()
+            )));
+        };
+        let Some(column) = scan
+            .name_mapping
+            .iter()
+            .find(|(_, index)| **index == lookup.lookup_column)
+            .map(|(name, _)| name.clone())
+        else {
+            return self.build_pipeline(&lookup.inner_scan);
+        };
+
+        let table = self.ctx.build_table_from_source_plan(&scan.source)?;
+        let ctx = self.ctx.clone();
+        let parts = scan.source.parts.clone();
+        let value = lookup.lookup_value.clone();
+        let parts = Runtime::with_worker_threads(2, Some("bloom_lookup".to_string()))?.block_on(
+            async move {
+                let table = FuseTable::try_from_table(table.as_ref())?;
+                prune_partitions_by_bloom_lookup(ctx, table, &column, &value, &parts).await
+            },
+        )?;
+
+        let mut scan = scan.clone();
+        scan.source.parts = parts;
+        self.build_pipeline(&PhysicalPlan::TableScan(scan))
+    }
+
+    pub(crate) fn build_bitmap_index_scan(&mut self, bitmap_scan: &BitmapIndexScan) -> Result<()> {
+        let PhysicalPlan::TableScan(scan) = bitmap_scan.input.as_ref() else {
+            return Err(ErrorCode::Internal(format!(
+                "The input of BitmapIndexScan must be a TableScan, but got {}",
+                bitmap_scan.input.name()
+            )));
+        };
+
+        let table = self.ctx.build_table_from_source_plan(&scan.source)?;
+        let bitmap_index = scan
+            .name_mapping
+            .iter()
+            .find(|(_, index)| **index == bitmap_scan.indexed_column)
+            .map(|(name, _)| table.schema().field_with_name(name).map(|f| f.column_id()))
+            .transpose()?
+            .and_then(|column_id| table.bitmap_index(column_id));
+        let Some(bitmap_index) = bitmap_index else {
+            return self.build_pipeline(&bitmap_scan.input);
+        };
+
+        let mut scan = scan.clone();
+        scan.source.parts = prune_partitions_by_bitmap_index(
+            &scan.source.parts,
+            bitmap_index.as_ref(),
+            &bitmap_scan.predicate_values,
+        )?;
+        self.build_pipeline(&PhysicalPlan::TableScan(scan))
+    }
+
+    pub(crate) fn build_block_sample(&mut self, block_sample: &BlockSample) -> Result<()> {
+        // The blocks are kept or skipped by the seed when the table scan prunes them
+        // with the push downs, the skipped blocks are never read.
+        self.build_pipeline(&block_sample.input)
+    }
+
+    pub(crate) fn build_schema_evolve(&mut self, schema_evolve: &SchemaEvolve) -> Result<()> {
+        self.build_pipeline(&schema_evolve.input)?;
+
+        let input_schema = schema_evolve.input.output_schema()?;
+        let fields = schema_evolve.target_schema.fields();
+        let mut exprs = Vec::with_capacity(fields.len());
+        for (offset, field) in fields.iter().enumerate() {
+            let expr = match schema_evolve
+                .fill_defaults
+                .iter()
+                .find(|(index, _)| *index == offset)
+            {
+                Some((_, default)) => default.as_expr(&BUILTIN_FUNCTIONS),
+                None => {
+                    let id = input_schema.index_of(field.name())?;
+                    Expr::ColumnRef {
+                        span: None,
+                        id,
+                        data_type: field.data_type().clone(),
+                        display_name: field.name().clone(),
+                    }
+                }
+            };
+            exprs.push(expr);
+        }
+
+        let num_input_columns = input_schema.num_fields();
+        let projection = (num_input_columns..num_input_columns + exprs.len()).collect();
+        let ops = vec![
+            BlockOperator::Map {
+                exprs,
+                projections: None,
+            },
+            BlockOperator::Project { projection },
+        ];
+        self.main_pipeline.add_transformer(|| {
+            CompoundBlockOperator::new(ops.clone(), self.func_ctx.clone(), num_input_columns)
+        });
+        Ok(())
+    }
+
     pub(crate) fn build_constant_table_scan(&mut self, scan: &ConstantTableScan) -> Result<()> {
         self.main_pipeline.add_source(
             |output| {
@@ -116,6 +296,24 @@ impl PipelineBuilder {
                     max_block_size,
                 ))
             }
+            CacheSource::Replicate((cache_key, column_indexes)) => {
+                let (replicate_state, _) = self.ctx.get_or_create_replicate_state(*cache_key);
+                CacheSourceState::ReplicateCacheState(ReplicateCacheState::new(
+                    column_indexes.clone(),
+                    replicate_state,
+                    max_block_size,
+                ))
+            }
+            CacheSource::Cte(_) => {
+                return Err(ErrorCode::Internal(
+                    "The consumer of a shared cte should be built as CteMaterialization",
+                ));
+            }
+            CacheSource::FlightSql(_) => {
+                return Err(ErrorCode::Internal(
+                    "The flight_sql table function should be built as FunctionImport",
+                ));
+            }
         };
 
         self.main_pipeline.add_source(
@@ -126,6 +324,37 @@ impl PipelineBuilder {
         )
     }
 
+    pub(crate) fn build_result_cache_scan(&mut self, scan: &ResultCacheScan) -> Result<()> {
+        self.main_pipeline.add_source(
+            |output| {
+                TransformResultCacheScan::create(
+                    self.ctx.clone(),
+                    output,
+                    scan.location.clone(),
+                    scan.num_rows,
+                )
+            },
+            1,
+        )
+    }
+
+    pub(crate) fn build_function_import(&mut self, import: &FunctionImport) -> Result<()> {
+        self.main_pipeline.add_source(
+            |output| {
+                TransformFunctionImport::create(
+                    self.ctx.clone(),
+                    output,
+                    import.endpoint.clone(),
+                    import.query.clone(),
+                    import.auth.clone(),
+                    import.schema.clone(),
+                    import.projection.clone(),
+                )
+            },
+            1,
+        )
+    }
+
     pub(crate) fn build_expression_scan(&mut self, scan: &ExpressionScan) -> Result<()> {
         self.build_pipeline(&scan.input)?;
 
@@ -153,3 +382,75 @@ impl PipelineBuilder {
         Ok(())
     }
 }
+
+/// Keep the partitions of the blocks which may hold a geometry matching `predicate`
+/// by the spatial index. The partitions not of a single block are kept.
+pub fn prune_partitions_by_spatial_index(
+    parts: &Partitions,
+    spatial_index: &dyn SpatialIndex,
+    predicate: &SpatialPredicate,
+) -> Result<Partitions> {
+    let candidates = spatial_index.candidate_blocks(predicate)?;
+    let partitions = parts
+        .partitions
+        .iter()
+        .filter(|part| match FuseBlockPartInfo::from_part(part) {
+            Ok(block) => candidates.contains(&block.location),
+            Err(_) => true,
+        })
+        .cloned()
+        .collect();
+    Ok(Partitions::create(parts.kind.clone(), partitions))
+}
+
+/// Keep the partitions of the blocks holding a row equal to any of `values` by the bitmap
+/// index. The partitions not of a single block are kept.
+pub fn prune_partitions_by_bitmap_index(
+    parts: &Partitions,
+    bitmap_index: &dyn BitmapIndex,
+    values: &[Scalar],
+) -> Result<Partitions> {
+    let matched = bitmap_index_lookup(bitmap_index, values)?;
+    let partitions = parts
+        .partitions
+        .iter()
+        .filter(|part| match FuseBlockPartInfo::from_part(part) {
+            Ok(block) => matched.contains_key(&block.location),
+            Err(_) => true,
+        })
+        .cloned()
+        .collect();
+    Ok(Partitions::create(parts.kind.clone(), partitions))
+}
+
+/// Keep the partitions of the segments which may contain rows with `column = value`, see
+/// [`FuseTable::bloom_lookup_segments`].
+pub async fn prune_partitions_by_bloom_lookup(
+    ctx: Arc<dyn TableContext>,
+    table: &FuseTable,
+    column: &str,
+    value: &Scalar,
+    parts: &Partitions,
+) -> Result<Partitions> {
+    let segments = table
+        .bloom_lookup_segments(ctx, column, value)
+        .await?
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect::<HashSet<_>>();
+    let partitions = parts
+        .partitions
+        .iter()
+        .filter(|part| {
+            if let Ok(lazy) = FuseLazyPartInfo::from_part(part) {
+                return segments.contains(&lazy.segment_location.0);
+            }
+            match FuseBlockPartInfo::from_part(part).map(|block| &block.block_meta_index) {
+                Ok(Some(index)) => segments.contains(&index.segment_location),
+                _ => true,
+            }
+        })
+        .cloned()
+        .collect();
+    Ok(Partitions::create(parts.kind.clone(), partitions))
+}
@@ -0,0 +1,37 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use databend_common_sql::executor::physical_plans::SequenceNext;
+
+use crate::pipelines::processors::transforms::SequenceCounter;
+use crate::pipelines::processors::transforms::TransformSequenceNext;
+use crate::pipelines::PipelineBuilder;
+
+impl PipelineBuilder {
+    pub(crate) fn build_sequence_next(&mut self, plan: &SequenceNext) -> Result<()> {
+        self.build_pipeline(&plan.input)?;
+
+        let counter = SequenceCounter::new(
+            self.ctx.clone(),
+            plan.sequence_name.clone(),
+            plan.batch_size,
+        );
+        self.main_pipeline
+            .add_async_transformer(|| TransformSequenceNext::new(counter.clone()));
+
+        Ok(())
+    }
+}
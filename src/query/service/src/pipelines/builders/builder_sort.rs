@@ -12,29 +12,51 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use async_channel::Receiver;
 use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::LimitType;
 use databend_common_expression::SortColumnDescription;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipe;
+use databend_common_pipeline_core::PipeItem;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::UnionReceiveSink;
 use databend_common_pipeline_transforms::processors::add_k_way_merge_sort;
+use databend_common_pipeline_transforms::processors::create_dummy_items;
 use databend_common_pipeline_transforms::processors::sort::utils::add_order_field;
+use databend_common_pipeline_transforms::processors::sort::utils::has_order_field;
 use databend_common_pipeline_transforms::processors::try_add_multi_sort_merge;
+use databend_common_pipeline_transforms::processors::TransformParallelSortPartial;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_pipeline_transforms::processors::TransformSortMergeBuilder;
 use databend_common_pipeline_transforms::processors::TransformSortPartial;
+use databend_common_pipeline_transforms::processors::TransformSortedPartial;
 use databend_common_pipeline_transforms::MemorySettings;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
+use databend_common_sql::executor::physical_plans::ClusterSort;
+use databend_common_sql::executor::physical_plans::FuzzyMatch;
 use databend_common_sql::executor::physical_plans::Sort;
+use databend_common_sql::executor::physical_plans::SortedMerge;
+use databend_common_sql::executor::physical_plans::SpillSort;
+use databend_common_sql::executor::PhysicalPlan;
 use databend_common_storage::DataOperator;
 use databend_common_storages_fuse::TableContext;
 
 use crate::pipelines::memory_settings::MemorySettingsExt;
 use crate::pipelines::processors::transforms::create_transform_stream_sort_spill;
+use crate::pipelines::processors::transforms::SortedStreamSource;
+use crate::pipelines::processors::transforms::TransformSortedMerge;
+use crate::pipelines::processors::transforms::TransformSpillSort;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
 use crate::spillers::Spiller;
@@ -88,7 +110,213 @@ impl PipelineBuilder {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        self.build_sort_pipeline(plan_schema, sort_desc, sort.limit, sort.after_exchange)
+        self.build_sort_pipeline(
+            plan_schema,
+            sort_desc,
+            sort.limit,
+            sort.after_exchange,
+            sort.parallelism,
+        )
+    }
+
+    // If the blocks are already sorted by the cluster keys,
+    // the partial sort is skipped and the blocks are merged directly.
+    pub(crate) fn build_cluster_sort(&mut self, sort: &ClusterSort) -> Result<()> {
+        self.build_pipeline(&sort.input)?;
+
+        let plan_schema = sort.output_schema()?;
+        let sort_desc = sort
+            .order_by
+            .iter()
+            .map(|desc| {
+                let offset = plan_schema.index_of(&desc.order_by.to_string())?;
+                Ok(SortColumnDescription {
+                    offset,
+                    asc: desc.asc,
+                    nulls_first: desc.nulls_first,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let max_threads = self.settings.get_max_threads()? as usize;
+        // TODO(Winter): the query will hang in MultiSortMergeProcessor when max_threads == 1 and output_len != 1
+        if self.main_pipeline.output_len() == 1 || max_threads == 1 {
+            self.main_pipeline.try_resize(max_threads)?;
+        }
+
+        let builder =
+            SortPipelineBuilder::create(self.ctx.clone(), plan_schema, Arc::new(sort_desc))?
+                .with_limit(sort.limit)
+                .remove_order_col_at_last();
+        match sort.is_already_sorted {
+            true => builder.build_sorted_merge_pipeline(&mut self.main_pipeline),
+            false => builder.build_full_sort_pipeline(&mut self.main_pipeline),
+        }
+    }
+
+    pub(crate) fn build_fuzzy_match(&mut self, fuzzy_match: &FuzzyMatch) -> Result<()> {
+        // Every row is a candidate, the nearest ones are kept by the sort with limit above.
+        self.build_pipeline(&fuzzy_match.input)
+    }
+
+    // Every output port of the inputs is a sorted stream, the streams are merged
+    // by a `TransformSortedMerge` without sorting again.
+    pub(crate) fn build_sorted_merge(&mut self, plan: &SortedMerge) -> Result<()> {
+        self.build_pipeline(&plan.inputs[0])?;
+        let mut receivers = Vec::new();
+        for input in plan.inputs.iter().skip(1) {
+            receivers.extend(self.expand_sorted_streams(input)?);
+        }
+
+        if !receivers.is_empty() {
+            let output_len = self.main_pipeline.output_len();
+            let mut items = create_dummy_items(output_len, output_len + receivers.len());
+            for receiver in receivers {
+                let output = OutputPort::create();
+                items.push(PipeItem::create(
+                    SortedStreamSource::create(self.ctx.clone(), output.clone(), receiver)?,
+                    vec![],
+                    vec![output],
+                ));
+            }
+            self.main_pipeline
+                .add_pipe(Pipe::create(output_len, items.len(), items));
+        }
+
+        // The order column of the inputs is at last, so the offsets of the sort keys
+        // are the same in the input schema and the output schema.
+        let input_schema = plan.inputs[0].output_schema()?;
+        let output_schema = plan.output_schema()?;
+        let sort_desc = plan
+            .order_by
+            .iter()
+            .map(|desc| {
+                let offset = output_schema.index_of(&desc.order_by.to_string())?;
+                Ok(SortColumnDescription {
+                    offset,
+                    asc: desc.asc,
+                    nulls_first: desc.nulls_first,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let block_size = self.settings.get_max_block_size()? as usize;
+        let inputs = (0..self.main_pipeline.output_len())
+            .map(|_| InputPort::create())
+            .collect::<Vec<_>>();
+        let output = OutputPort::create();
+        let processor = TransformSortedMerge::try_create(
+            inputs.clone(),
+            output.clone(),
+            input_schema,
+            Arc::new(sort_desc),
+            output_schema.num_fields(),
+            block_size,
+        )?;
+        self.main_pipeline
+            .add_pipe(Pipe::create(inputs.len(), 1, vec![PipeItem::create(
+                processor,
+                inputs,
+                vec![output],
+            )]));
+        Ok(())
+    }
+
+    // Every output port of the input is sorted by a `TransformSpillSort` which shares the
+    // spill threshold of the plan, then the sorted streams are merged by a `TransformSortedMerge`.
+    pub(crate) fn build_spill_sort(&mut self, plan: &SpillSort) -> Result<()> {
+        self.build_pipeline(&plan.input)?;
+
+        let schema = plan.output_schema()?;
+        let sort_desc = plan
+            .order_by
+            .iter()
+            .map(|desc| {
+                let offset = schema.index_of(&desc.order_by.to_string())?;
+                Ok(SortColumnDescription {
+                    offset,
+                    asc: desc.asc,
+                    nulls_first: desc.nulls_first,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let sort_desc = Arc::new(sort_desc);
+
+        let block_size = self.settings.get_max_block_size()? as usize;
+        let threshold_bytes =
+            plan.spill_threshold_bytes as usize / self.main_pipeline.output_len().max(1);
+        let config = SpillerConfig {
+            spiller_type: SpillerType::OrderBy,
+            location_prefix: plan.spill_location.clone(),
+            disk_spill: None,
+            use_parquet: self.settings.get_spilling_file_format()?.is_parquet(),
+        };
+        self.main_pipeline.add_transform(|input, output| {
+            let op = DataOperator::instance().spill_operator();
+            let spiller = Spiller::create(self.ctx.clone(), op, config.clone())?;
+            TransformSpillSort::try_create(
+                input,
+                output,
+                schema.clone(),
+                sort_desc.clone(),
+                spiller,
+                threshold_bytes,
+                block_size,
+            )
+        })?;
+
+        if self.main_pipeline.output_len() == 1 {
+            return Ok(());
+        }
+        let inputs = (0..self.main_pipeline.output_len())
+            .map(|_| InputPort::create())
+            .collect::<Vec<_>>();
+        let output = OutputPort::create();
+        let processor = TransformSortedMerge::try_create(
+            inputs.clone(),
+            output.clone(),
+            schema.clone(),
+            sort_desc,
+            schema.num_fields(),
+            block_size,
+        )?;
+        self.main_pipeline
+            .add_pipe(Pipe::create(inputs.len(), 1, vec![PipeItem::create(
+                processor,
+                inputs,
+                vec![output],
+            )]));
+        Ok(())
+    }
+
+    // Build `input` in a new pipeline, each output port of it is sent to its own channel
+    // to keep the order of the stream.
+    fn expand_sorted_streams(&mut self, input: &PhysicalPlan) -> Result<Vec<Receiver<DataBlock>>> {
+        let ctx = QueryContext::create_from(self.ctx.as_ref());
+        let mut pipeline_builder = PipelineBuilder::create(
+            self.func_ctx.clone(),
+            self.settings.clone(),
+            ctx,
+            self.main_pipeline.get_scopes(),
+        );
+        pipeline_builder.hash_join_states = self.hash_join_states.clone();
+
+        let mut build_res = pipeline_builder.finalize(input)?;
+        let channels = (0..build_res.main_pipeline.output_len())
+            .map(|_| async_channel::bounded(2))
+            .collect::<Vec<_>>();
+        let next = AtomicUsize::new(0);
+        build_res.main_pipeline.add_sink(|input_port| {
+            let (tx, _) = &channels[next.fetch_add(1, Ordering::Relaxed)];
+            Ok(ProcessorPtr::create(UnionReceiveSink::create(
+                Some(tx.clone()),
+                input_port,
+            )))
+        })?;
+
+        self.pipelines.push(build_res.main_pipeline.finalize());
+        self.pipelines.extend(build_res.sources_pipelines);
+        Ok(channels.into_iter().map(|(_, rx)| rx).collect())
     }
 
     pub(crate) fn build_sort_pipeline(
@@ -97,6 +325,7 @@ impl PipelineBuilder {
         sort_desc: Vec<SortColumnDescription>,
         limit: Option<usize>,
         after_exchange: Option<bool>,
+        parallelism: usize,
     ) -> Result<()> {
         let max_threads = self.settings.get_max_threads()? as usize;
         let sort_desc = Arc::new(sort_desc);
@@ -107,7 +336,8 @@ impl PipelineBuilder {
         }
 
         let builder = SortPipelineBuilder::create(self.ctx.clone(), plan_schema, sort_desc)?
-            .with_limit(limit);
+            .with_limit(limit)
+            .with_parallelism(parallelism);
 
         match after_exchange {
             Some(true) => {
@@ -148,6 +378,7 @@ pub struct SortPipelineBuilder {
     sort_desc: Arc<Vec<SortColumnDescription>>,
     limit: Option<usize>,
     block_size: usize,
+    parallelism: usize,
     remove_order_col_at_last: bool,
 }
 
@@ -164,6 +395,7 @@ impl SortPipelineBuilder {
             sort_desc,
             limit: None,
             block_size,
+            parallelism: 1,
             remove_order_col_at_last: false,
         })
     }
@@ -173,6 +405,12 @@ impl SortPipelineBuilder {
         self
     }
 
+    // The number of tasks to sort a large block in the partial sort.
+    pub fn with_parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism;
+        self
+    }
+
     // The expected output block size, the actual output block size will be equal to or less than the given value.
     pub fn with_block_size_hit(mut self, block_size: usize) -> Self {
         self.block_size = self.block_size.min(block_size);
@@ -186,12 +424,52 @@ impl SortPipelineBuilder {
 
     pub fn build_full_sort_pipeline(self, pipeline: &mut Pipeline) -> Result<()> {
         // Partial sort
-        pipeline.add_transformer(|| {
-            TransformSortPartial::new(
-                LimitType::from_limit_rows(self.limit),
+        if self.parallelism > 1 {
+            // The order column is not generated before the partial sort.
+            let schema = match has_order_field(&self.schema) {
+                true => {
+                    let mut fields = self.schema.fields().clone();
+                    fields.pop();
+                    DataSchemaRefExt::create(fields)
+                }
+                false => self.schema.clone(),
+            };
+            pipeline.add_transform(|input, output| {
+                Ok(ProcessorPtr::create(
+                    TransformParallelSortPartial::try_create(
+                        input,
+                        output,
+                        schema.clone(),
+                        self.limit,
+                        self.sort_desc.clone(),
+                        self.parallelism,
+                    )?,
+                ))
+            })?;
+        } else {
+            pipeline.add_transformer(|| {
+                TransformSortPartial::new(
+                    LimitType::from_limit_rows(self.limit),
+                    self.sort_desc.clone(),
+                )
+            });
+        }
+
+        self.build_merge_sort_pipeline(pipeline, false)
+    }
+
+    // Build the sort pipeline for the blocks which are already sorted, only the blocks
+    // which are actually not sorted are sorted in the partial sort.
+    pub fn build_sorted_merge_pipeline(self, pipeline: &mut Pipeline) -> Result<()> {
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(TransformSortedPartial::try_create(
+                input,
+                output,
+                self.schema.clone(),
+                self.limit,
                 self.sort_desc.clone(),
-            )
-        });
+            )?))
+        })?;
 
         self.build_merge_sort_pipeline(pipeline, false)
     }
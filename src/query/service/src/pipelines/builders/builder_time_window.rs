@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::SortColumnDescription;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::EmptySink;
+use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use databend_common_sql::executor::physical_plans::AggregateFunctionDesc;
+use databend_common_sql::executor::physical_plans::SessionWindow;
+use databend_common_sql::executor::physical_plans::TumblingWindow;
+use databend_common_sql::executor::physical_plans::Watermark;
+use databend_common_sql::executor::PhysicalPlan;
+
+use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+use crate::pipelines::processors::transforms::TransformSessionWindow;
+use crate::pipelines::processors::transforms::TransformTumblingWindow;
+use crate::pipelines::processors::transforms::TransformWatermark;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+
+impl PipelineBuilder {
+    pub(crate) fn build_tumbling_window(&mut self, plan: &TumblingWindow) -> Result<()> {
+        self.build_pipeline(&plan.input)?;
+
+        let input_schema = plan.input.output_schema()?;
+        let time_offset = input_schema.index_of(&plan.time_col.to_string())?;
+        let params = self.build_time_window_params(input_schema, &plan.agg_funcs)?;
+
+        // The rows of a window may be in any stream.
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline.try_add_accumulating_transformer(|| {
+            TransformTumblingWindow::try_create(&params, time_offset, plan.window_size)
+        })
+    }
+
+    pub(crate) fn build_session_window(&mut self, plan: &SessionWindow) -> Result<()> {
+        self.build_pipeline(&plan.input)?;
+
+        let input_schema = plan.input.output_schema()?;
+        let time_offset = input_schema.index_of(&plan.time_col.to_string())?;
+        let params = self.build_time_window_params(input_schema.clone(), &plan.agg_funcs)?;
+
+        // The sessions are split on the rows sorted by time, the full sort merges all the
+        // streams into one sorted stream.
+        let sort_desc = vec![SortColumnDescription {
+            offset: time_offset,
+            asc: true,
+            nulls_first: false,
+        }];
+        let max_threads = self.settings.get_max_threads()? as usize;
+        self.build_sort_pipeline(input_schema, sort_desc, None, None, max_threads)?;
+
+        self.main_pipeline.try_add_accumulating_transformer(|| {
+            TransformSessionWindow::try_create(&params, time_offset, plan.window_size)
+        })
+    }
+
+    pub(crate) fn build_watermark(&mut self, plan: &Watermark) -> Result<()> {
+        self.build_pipeline(&plan.input)?;
+
+        let input_schema = plan.input.output_schema()?;
+        let time_offset = input_schema.index_of(&plan.event_time_col.to_string())?;
+        let late_rows = match &plan.late_output {
+            Some(late_output) => {
+                let (late_rows, _) = self.ctx.get_or_create_replicate_state(plan.late_cache_key);
+                self.build_late_output(late_output)?;
+                Some(late_rows)
+            }
+            None => None,
+        };
+        let delay = i64::try_from(plan.watermark_delay_ms)
+            .unwrap_or(i64::MAX)
+            .saturating_mul(1000);
+
+        // The watermark follows the order the rows arrive in, so it's tracked on one stream.
+        self.main_pipeline.try_resize(1)?;
+        self.main_pipeline
+            .add_transformer(|| TransformWatermark::create(time_offset, delay, late_rows.clone()));
+        Ok(())
+    }
+
+    // The late output reads the late rows in its own pipeline, and its result is discarded.
+    fn build_late_output(&mut self, late_output: &PhysicalPlan) -> Result<()> {
+        let late_context = QueryContext::create_from(self.ctx.as_ref());
+        let mut late_builder = PipelineBuilder::create(
+            self.func_ctx.clone(),
+            self.settings.clone(),
+            late_context,
+            self.main_pipeline.get_scopes(),
+        );
+        late_builder.hash_join_states = self.hash_join_states.clone();
+
+        let mut late_res = late_builder.finalize(late_output)?;
+        if late_res.main_pipeline.is_pulling_pipeline()? {
+            late_res
+                .main_pipeline
+                .add_sink(|input| Ok(ProcessorPtr::create(EmptySink::create(input))))?;
+        }
+        self.pipelines.push(late_res.main_pipeline.finalize());
+        self.pipelines.extend(late_res.sources_pipelines);
+        Ok(())
+    }
+
+    fn build_time_window_params(
+        &self,
+        input_schema: DataSchemaRef,
+        agg_funcs: &[AggregateFunctionDesc],
+    ) -> Result<Arc<AggregatorParams>> {
+        let max_block_size = self.settings.get_max_block_size()?;
+        let max_spill_io_requests = self.settings.get_max_spill_io_requests()?;
+        Self::build_aggregator_params(
+            input_schema,
+            &[],
+            agg_funcs,
+            false,
+            false,
+            max_block_size as usize,
+            max_spill_io_requests as usize,
+        )
+    }
+}
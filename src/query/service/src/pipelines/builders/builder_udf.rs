@@ -14,8 +14,11 @@
 
 use databend_common_exception::Result;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use databend_common_sql::executor::physical_plans::Classify;
 use databend_common_sql::executor::physical_plans::Udf;
 
+use crate::pipelines::processors::transforms::ClassifyModel;
+use crate::pipelines::processors::transforms::TransformClassify;
 use crate::pipelines::processors::transforms::TransformUdfScript;
 use crate::pipelines::processors::transforms::TransformUdfServer;
 use crate::pipelines::PipelineBuilder;
@@ -46,4 +49,31 @@ impl PipelineBuilder {
             })
         }
     }
+
+    pub(crate) fn build_classify(&mut self, plan: &Classify) -> Result<()> {
+        self.build_pipeline(&plan.input)?;
+
+        let input_schema = plan.input.output_schema()?;
+        let input_offsets = plan
+            .input_cols
+            .iter()
+            .map(|index| input_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        let output_types = plan
+            .output_cols
+            .iter()
+            .map(|(_, data_type)| data_type.clone())
+            .collect::<Vec<_>>();
+        let model = ClassifyModel::new(self.ctx.clone(), plan.model_location.clone());
+        self.main_pipeline.add_async_transformer(|| {
+            TransformClassify::new(
+                model.clone(),
+                input_offsets.clone(),
+                output_types.clone(),
+                plan.batch_size,
+            )
+        });
+
+        Ok(())
+    }
 }
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod builder_abort_if_empty;
 mod builder_add_stream_column;
 mod builder_aggregate;
 mod builder_append_table;
+mod builder_async_aggregate;
 mod builder_async_function;
 mod builder_column_mutation;
 mod builder_commit;
@@ -34,15 +36,20 @@ mod builder_mutation_manipulate;
 mod builder_mutation_organize;
 mod builder_mutation_source;
 mod builder_mutation_split;
+mod builder_mv_refresh_partial;
 mod builder_on_finished;
+mod builder_prewarm_cache;
 mod builder_project;
 mod builder_recluster;
 mod builder_recursive_cte;
 mod builder_replace_into;
+mod builder_replicate;
 mod builder_row_fetch;
 mod builder_scalar;
 mod builder_scan;
+mod builder_sequence_next;
 mod builder_sort;
+mod builder_time_window;
 mod builder_udf;
 mod builder_union_all;
 mod builder_window;
@@ -51,4 +58,7 @@ mod transform_builder;
 
 pub use builder_replace_into::RawValueSource;
 pub use builder_replace_into::ValueSource;
+pub use builder_scan::prune_partitions_by_bitmap_index;
+pub use builder_scan::prune_partitions_by_bloom_lookup;
+pub use builder_scan::prune_partitions_by_spatial_index;
 pub use builder_sort::SortPipelineBuilder;
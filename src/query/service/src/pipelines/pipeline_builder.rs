@@ -170,12 +170,24 @@ impl PipelineBuilder {
             PhysicalPlan::AggregateExpand(aggregate) => self.build_aggregate_expand(aggregate),
             PhysicalPlan::AggregatePartial(aggregate) => self.build_aggregate_partial(aggregate),
             PhysicalPlan::AggregateFinal(aggregate) => self.build_aggregate_final(aggregate),
+            PhysicalPlan::SortMergeAggregate(aggregate) => {
+                self.build_sort_merge_aggregate(aggregate)
+            }
+            PhysicalPlan::MaterializeAgg(materialize_agg) => {
+                self.build_materialize_agg(materialize_agg)
+            }
             PhysicalPlan::Window(window) => self.build_window(window),
             PhysicalPlan::WindowPartition(window_partition) => {
                 self.build_window_partition(window_partition)
             }
+            PhysicalPlan::TumblingWindow(window) => self.build_tumbling_window(window),
+            PhysicalPlan::SessionWindow(window) => self.build_session_window(window),
+            PhysicalPlan::Watermark(watermark) => self.build_watermark(watermark),
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
             PhysicalPlan::Limit(limit) => self.build_limit(limit),
+            PhysicalPlan::ConditionalLimit(conditional_limit) => {
+                self.build_conditional_limit(conditional_limit)
+            }
             PhysicalPlan::RowFetch(row_fetch) => self.build_row_fetch(row_fetch),
             PhysicalPlan::HashJoin(join) => self.build_join(join),
             PhysicalPlan::ExchangeSink(sink) => self.build_exchange_sink(sink),
@@ -184,19 +196,32 @@ impl PipelineBuilder {
             PhysicalPlan::DistributedInsertSelect(insert_select) => {
                 self.build_distributed_insert_select(insert_select)
             }
+            PhysicalPlan::BloomBuild(bloom_build) => self.build_bloom_build(bloom_build),
+            PhysicalPlan::WriteAheadLog(wal) => self.build_write_ahead_log(wal),
             PhysicalPlan::ProjectSet(project_set) => self.build_project_set(project_set),
             PhysicalPlan::Udf(udf) => self.build_udf(udf),
             PhysicalPlan::Exchange(_) => Err(ErrorCode::Internal(
                 "Invalid physical plan with PhysicalPlan::Exchange",
             )),
             PhysicalPlan::RangeJoin(range_join) => self.build_range_join(range_join),
+            PhysicalPlan::SortMergeJoin(join) => self.build_sort_merge_join(join),
+            PhysicalPlan::SemiHashJoin(join) | PhysicalPlan::AntiHashJoin(join) => {
+                self.build_semi_hash_join(join)
+            }
             PhysicalPlan::CacheScan(cache_scan) => self.build_cache_scan(cache_scan),
+            PhysicalPlan::ResultCacheScan(scan) => self.build_result_cache_scan(scan),
+            PhysicalPlan::FunctionImport(function_import) => {
+                self.build_function_import(function_import)
+            }
             PhysicalPlan::ExpressionScan(expression_scan) => {
                 self.build_expression_scan(expression_scan)
             }
 
             // Copy into.
             PhysicalPlan::CopyIntoTable(copy) => self.build_copy_into_table(copy),
+            PhysicalPlan::EnforceSchema(enforce_schema) => {
+                self.build_enforce_schema(enforce_schema)
+            }
             PhysicalPlan::CopyIntoLocation(copy) => self.build_copy_into_location(copy),
 
             // Replace.
@@ -251,6 +276,8 @@ impl PipelineBuilder {
                 self.build_chunk_commit_insert(chunk_commit_insert)
             }
             PhysicalPlan::AsyncFunction(async_func) => self.build_async_function(async_func),
+            PhysicalPlan::SequenceNext(plan) => self.build_sequence_next(plan),
+            PhysicalPlan::AbortIfEmpty(plan) => self.build_abort_if_empty(plan),
             PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
             PhysicalPlan::MutationSource(mutation_source) => {
                 self.build_mutation_source(mutation_source)
@@ -258,6 +285,50 @@ impl PipelineBuilder {
             PhysicalPlan::ColumnMutation(column_mutation) => {
                 self.build_column_mutation(column_mutation)
             }
+            PhysicalPlan::SkewDetection(skew_detection) => {
+                self.build_skew_detection(skew_detection)
+            }
+            PhysicalPlan::ConvertTimezone(convert_timezone) => {
+                self.build_convert_timezone(convert_timezone)
+            }
+            PhysicalPlan::MaskApply(mask_apply) => self.build_mask_apply(mask_apply),
+            PhysicalPlan::RowAccessPolicy(row_access_policy) => {
+                self.build_row_access_policy(row_access_policy)
+            }
+            PhysicalPlan::Correlation(correlation) => self.build_correlation(correlation),
+            PhysicalPlan::TDigestAgg(tdigest_agg) => self.build_tdigest_agg(tdigest_agg),
+            PhysicalPlan::CteMaterialization(cte_materialization) => {
+                self.build_cte_materialization(cte_materialization)
+            }
+            PhysicalPlan::FlatMap(flat_map) => self.build_flat_map(flat_map),
+            PhysicalPlan::FuzzyMatch(fuzzy_match) => self.build_fuzzy_match(fuzzy_match),
+            PhysicalPlan::GeoScan(geo_scan) => self.build_geo_scan(geo_scan),
+            PhysicalPlan::BloomLookup(lookup) => self.build_bloom_lookup(lookup),
+            PhysicalPlan::BitmapIndexScan(scan) => self.build_bitmap_index_scan(scan),
+            PhysicalPlan::Compact(compact) => self.build_compact(compact),
+            PhysicalPlan::BlockSample(block_sample) => self.build_block_sample(block_sample),
+            PhysicalPlan::MvRefreshPartial(mv_refresh_partial) => {
+                self.build_mv_refresh_partial(mv_refresh_partial)
+            }
+            PhysicalPlan::SchemaEvolve(schema_evolve) => self.build_schema_evolve(schema_evolve),
+            PhysicalPlan::SortedMerge(sorted_merge) => self.build_sorted_merge(sorted_merge),
+            PhysicalPlan::Scatter(scatter) => self.build_scatter(scatter),
+            PhysicalPlan::JsonEach(json_each) => self.build_json_each(json_each),
+            PhysicalPlan::JsonTable(json_table) => self.build_json_table(json_table),
+            PhysicalPlan::Classify(classify) => self.build_classify(classify),
+            PhysicalPlan::StreamOutput(stream_output) => self.build_stream_output(stream_output),
+            PhysicalPlan::Replicate(replicate) => self.build_replicate(replicate),
+            PhysicalPlan::GroupingId(grouping_id) => self.build_grouping_id(grouping_id),
+            PhysicalPlan::Emit(emit) => self.build_emit(emit),
+            PhysicalPlan::Histogram(histogram) => self.build_histogram(histogram),
+            PhysicalPlan::Qualify(qualify) => self.build_qualify(qualify),
+            PhysicalPlan::Transpose(transpose) => self.build_transpose(transpose),
+            PhysicalPlan::JsonExtract(json_extract) => self.build_json_extract(json_extract),
+            PhysicalPlan::ClusterSort(sort) => self.build_cluster_sort(sort),
+            PhysicalPlan::SpillSort(sort) => self.build_spill_sort(sort),
+            PhysicalPlan::Zip(plan) => self.build_zip(plan),
+            PhysicalPlan::PrewarmCache(plan) => self.build_prewarm_cache(plan),
+            PhysicalPlan::AsyncAggregate(plan) => self.build_async_aggregate(plan),
         }?;
 
         self.is_exchange_neighbor = is_exchange_neighbor;
@@ -37,6 +37,7 @@ use databend_common_pipeline_transforms::MemorySettings;
 
 use crate::pipelines::memory_settings::MemorySettingsExt;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
+use crate::pipelines::processors::transforms::aggregator::new_transform_partition_bucket::SINGLE_LEVEL_BUCKET_NUM;
 use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
 use crate::sessions::QueryContext;
 #[allow(clippy::enum_variant_names)]
@@ -61,6 +62,8 @@ pub struct TransformPartialAggregate {
     processed_bytes: usize,
     processed_rows: usize,
     settings: MemorySettings,
+    early_termination_threshold: f64,
+    passthrough: bool,
 }
 
 impl TransformPartialAggregate {
@@ -70,6 +73,7 @@ impl TransformPartialAggregate {
         output: Arc<OutputPort>,
         params: Arc<AggregatorParams>,
         config: HashTableConfig,
+        early_termination_threshold: f64,
     ) -> Result<Box<dyn Processor>> {
         let hash_table = {
             let arena = Arc::new(Bump::new());
@@ -104,6 +108,8 @@ impl TransformPartialAggregate {
                 first_block_start: None,
                 processed_bytes: 0,
                 processed_rows: 0,
+                early_termination_threshold,
+                passthrough: false,
             },
         ))
     }
@@ -122,6 +128,30 @@ impl TransformPartialAggregate {
 
     #[inline(always)]
     fn execute_one_block(&mut self, block: DataBlock) -> Result<()> {
+        match &mut self.hash_table {
+            HashTable::MovedOut => unreachable!(),
+            HashTable::AggregateHashTable(hashtable) => Self::add_block(
+                &self.params,
+                &mut self.probe_state,
+                hashtable,
+                block,
+                &mut self.processed_bytes,
+                &mut self.processed_rows,
+                &mut self.first_block_start,
+            ),
+        }
+    }
+
+    #[inline(always)]
+    fn add_block(
+        params: &AggregatorParams,
+        probe_state: &mut ProbeState,
+        hashtable: &mut AggregateHashTable,
+        block: DataBlock,
+        processed_bytes: &mut usize,
+        processed_rows: &mut usize,
+        first_block_start: &mut Option<Instant>,
+    ) -> Result<()> {
         let is_agg_index_block = block
             .get_meta()
             .and_then(AggIndexMeta::downcast_ref_from)
@@ -129,67 +159,127 @@ impl TransformPartialAggregate {
             .unwrap_or_default();
 
         let block = block.consume_convert_to_full();
-        let group_columns = InputColumns::new_block_proxy(&self.params.group_columns, &block);
+        let group_columns = InputColumns::new_block_proxy(&params.group_columns, &block);
         let rows_num = block.num_rows();
 
-        self.processed_bytes += block.memory_size();
-        self.processed_rows += rows_num;
-        if self.first_block_start.is_none() {
-            self.first_block_start = Some(Instant::now());
+        *processed_bytes += block.memory_size();
+        *processed_rows += rows_num;
+        if first_block_start.is_none() {
+            *first_block_start = Some(Instant::now());
         }
 
+        let (params_columns, states_index) = if is_agg_index_block {
+            let num_columns = block.num_columns();
+            let states_count = params
+                .states_layout
+                .as_ref()
+                .map(|layout| layout.states_loc.len())
+                .unwrap_or(0);
+            (
+                vec![],
+                (num_columns - states_count..num_columns).collect::<Vec<_>>(),
+            )
+        } else {
+            (
+                Self::aggregate_arguments(&block, &params.aggregate_functions_arguments),
+                vec![],
+            )
+        };
+
+        let agg_states = if !states_index.is_empty() {
+            InputColumns::new_block_proxy(&states_index, &block)
+        } else {
+            (&[]).into()
+        };
+
+        let _ = hashtable.add_groups(
+            probe_state,
+            group_columns,
+            &params_columns,
+            agg_states,
+            rows_num,
+        )?;
+        Ok(())
+    }
+
+    // Every input row almost forms a distinct group, accumulating them only costs memory.
+    fn reach_early_termination(&self) -> bool {
+        if self.early_termination_threshold <= 0.0
+            || self.params.has_distinct_combinator()
+            || self.processed_rows < self.params.max_block_size
         {
-            match &mut self.hash_table {
-                HashTable::MovedOut => unreachable!(),
-                HashTable::AggregateHashTable(hashtable) => {
-                    let (params_columns, states_index) = if is_agg_index_block {
-                        let num_columns = block.num_columns();
-                        let states_count = self
-                            .params
-                            .states_layout
-                            .as_ref()
-                            .map(|layout| layout.states_loc.len())
-                            .unwrap_or(0);
-                        (
-                            vec![],
-                            (num_columns - states_count..num_columns).collect::<Vec<_>>(),
-                        )
-                    } else {
-                        (
-                            Self::aggregate_arguments(
-                                &block,
-                                &self.params.aggregate_functions_arguments,
-                            ),
-                            vec![],
-                        )
-                    };
+            return false;
+        }
 
-                    let agg_states = if !states_index.is_empty() {
-                        InputColumns::new_block_proxy(&states_index, &block)
-                    } else {
-                        (&[]).into()
-                    };
-
-                    let _ = hashtable.add_groups(
-                        &mut self.probe_state,
-                        group_columns,
-                        &params_columns,
-                        agg_states,
-                        rows_num,
-                    )?;
-                    Ok(())
-                }
+        match &self.hash_table {
+            HashTable::MovedOut => false,
+            HashTable::AggregateHashTable(hashtable) => {
+                hashtable.len() as f64 / self.processed_rows as f64
+                    >= self.early_termination_threshold
             }
         }
     }
+
+    // Send the rows of the block to the final aggregator without grouping them. The final
+    // aggregator merges aggregate states, so each row is still turned into a state, but the
+    // rows are appended to the payload directly instead of probing a hashtable. The payload is
+    // marked as unpartitioned and will be repartitioned there.
+    fn passthrough_block(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
+        let mut hashtable = AggregateHashTable::new_directly(
+            self.params.group_data_types.clone(),
+            self.params.aggregate_functions.clone(),
+            HashTableConfig::default().with_initial_radix_bits(0),
+            0,
+            Arc::new(Bump::new()),
+            false,
+        );
+        Self::add_block(
+            &self.params,
+            &mut self.probe_state,
+            &mut hashtable,
+            block,
+            &mut self.processed_bytes,
+            &mut self.processed_rows,
+            &mut self.first_block_start,
+        )?;
+
+        Ok(hashtable
+            .payload
+            .payloads
+            .into_iter()
+            .filter(|payload| payload.len() != 0)
+            .map(|payload| {
+                DataBlock::empty_with_meta(AggregateMeta::create_agg_payload(
+                    SINGLE_LEVEL_BUCKET_NUM,
+                    payload,
+                    1,
+                ))
+            })
+            .collect())
+    }
 }
 
 impl AccumulatingTransform for TransformPartialAggregate {
     const NAME: &'static str = "TransformPartialAggregate";
 
     fn transform(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
+        if self.passthrough {
+            return self.passthrough_block(block);
+        }
+
         self.execute_one_block(block)?;
 
+        if self.reach_early_termination() {
+            log::info!(
+                "Partial aggregate switches to passthrough after {} rows",
+                self.processed_rows
+            );
+            // The groups aggregated so far stay in the hashtable until finish, the partition
+            // bucket transform expects the partitioned payloads after the unpartitioned ones.
+            self.passthrough = true;
+            return Ok(vec![]);
+        }
+
         if self.settings.check_spill() {
             if let HashTable::AggregateHashTable(v) = std::mem::take(&mut self.hash_table) {
                 let group_types = v.payload.group_types.clone();
@@ -0,0 +1,230 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bumpalo::Bump;
+use databend_common_exception::Result;
+use databend_common_expression::get_states_layout;
+use databend_common_expression::AggrState;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::InputColumns;
+use databend_common_expression::Scalar;
+use databend_common_expression::StatesLayout;
+use databend_common_functions::aggregates::AggregateFunctionRef;
+use databend_common_functions::aggregates::StateAddr;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+
+use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+
+/// Aggregate the rows sorted by the group by columns. Only the states of the current group
+/// are kept, they are merged into the output and reset when the group key changes, so the
+/// memory doesn't grow with the number of groups.
+pub struct TransformSortMergeAggregate {
+    arena: Bump,
+    // The states of the current group, always initialized.
+    addr: StateAddr,
+    states_layout: StatesLayout,
+    funcs: Vec<AggregateFunctionRef>,
+    arg_indices: Vec<Vec<usize>>,
+    group_columns: Vec<usize>,
+    max_block_size: usize,
+
+    // The key of the last row accumulated, `None` before the first row.
+    current_key: Option<Vec<Scalar>>,
+    // The results of the finished groups, the key of the current group is pushed to
+    // `key_builders` when the group starts.
+    agg_builders: Vec<ColumnBuilder>,
+    key_builders: Vec<ColumnBuilder>,
+}
+
+impl TransformSortMergeAggregate {
+    pub fn try_create(params: &AggregatorParams) -> Result<Self> {
+        let arena = Bump::new();
+        let states_layout = get_states_layout(&params.aggregate_functions)?;
+        let addr: StateAddr = arena.alloc_layout(states_layout.layout).into();
+        for (func, loc) in params
+            .aggregate_functions
+            .iter()
+            .zip(states_layout.states_loc.iter())
+        {
+            func.init_state(AggrState::new(addr, loc));
+        }
+
+        let mut transform = TransformSortMergeAggregate {
+            arena,
+            addr,
+            states_layout,
+            funcs: params.aggregate_functions.clone(),
+            arg_indices: params.aggregate_functions_arguments.clone(),
+            group_columns: params.group_columns.clone(),
+            max_block_size: params.max_block_size,
+            current_key: None,
+            agg_builders: vec![],
+            key_builders: vec![],
+        };
+        transform.agg_builders = transform.new_agg_builders()?;
+        transform.key_builders = params
+            .group_data_types
+            .iter()
+            .map(|data_type| ColumnBuilder::with_capacity(data_type, 0))
+            .collect();
+        Ok(transform)
+    }
+
+    /// The bytes allocated for the aggregate states and the unsent results.
+    pub fn allocated_bytes(&self) -> usize {
+        self.arena.allocated_bytes()
+            + self
+                .agg_builders
+                .iter()
+                .chain(self.key_builders.iter())
+                .map(|builder| builder.memory_size())
+                .sum::<usize>()
+    }
+
+    fn new_agg_builders(&self) -> Result<Vec<ColumnBuilder>> {
+        self.funcs
+            .iter()
+            .map(|func| Ok(ColumnBuilder::with_capacity(&func.return_type()?, 0)))
+            .collect()
+    }
+
+    // Accumulate the rows in `start..end` of `block` into the current group.
+    fn accumulate(&self, block: &DataBlock, start: usize, end: usize) -> Result<()> {
+        if start == end {
+            return Ok(());
+        }
+        let block = block.slice(start..end);
+        for ((func, loc), indices) in self
+            .funcs
+            .iter()
+            .zip(self.states_layout.states_loc.iter())
+            .zip(self.arg_indices.iter())
+        {
+            let columns = InputColumns::new_block_proxy(indices.as_slice(), &block);
+            func.accumulate(AggrState::new(self.addr, loc), columns, None, end - start)?;
+        }
+        Ok(())
+    }
+
+    // Output the result of the current group and reset the states for the next group.
+    fn finish_group(&mut self) -> Result<()> {
+        for ((func, loc), builder) in self
+            .funcs
+            .iter()
+            .zip(self.states_layout.states_loc.iter())
+            .zip(self.agg_builders.iter_mut())
+        {
+            let place = AggrState::new(self.addr, loc);
+            func.merge_result(place, builder)?;
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(place) }
+            }
+            func.init_state(place);
+        }
+        Ok(())
+    }
+
+    fn take_output(&mut self) -> Result<DataBlock> {
+        let agg_builders = std::mem::replace(&mut self.agg_builders, self.new_agg_builders()?);
+        let key_builders = self
+            .key_builders
+            .iter_mut()
+            .map(|builder| {
+                let data_type = builder.data_type();
+                std::mem::replace(builder, ColumnBuilder::with_capacity(&data_type, 0))
+            })
+            .collect::<Vec<_>>();
+        let columns = agg_builders
+            .into_iter()
+            .chain(key_builders)
+            .map(|builder| builder.build())
+            .collect::<Vec<_>>();
+        Ok(DataBlock::new_from_columns(columns))
+    }
+}
+
+impl AccumulatingTransform for TransformSortMergeAggregate {
+    const NAME: &'static str = "SortMergeAggregateTransform";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let num_rows = data.num_rows();
+        if num_rows == 0 {
+            return Ok(vec![]);
+        }
+        let data = data.consume_convert_to_full();
+        let keys = self
+            .group_columns
+            .iter()
+            .map(|offset| data.get_by_offset(*offset).to_column(num_rows))
+            .collect::<Vec<Column>>();
+
+        let mut output = vec![];
+        let mut start = 0;
+        for row in 0..num_rows {
+            let new_group = match (row, &self.current_key) {
+                (0, None) => true,
+                (0, Some(key)) => key
+                    .iter()
+                    .zip(keys.iter())
+                    .any(|(scalar, column)| column.index(0) != Some(scalar.as_ref())),
+                _ => keys
+                    .iter()
+                    .any(|column| column.index(row) != column.index(row - 1)),
+            };
+            if !new_group {
+                continue;
+            }
+
+            self.accumulate(&data, start, row)?;
+            if row > 0 || self.current_key.is_some() {
+                self.finish_group()?;
+                // The keys are of the finished groups before the next key is pushed.
+                if self.key_builders[0].len() >= self.max_block_size {
+                    output.push(self.take_output()?);
+                }
+            }
+            for (builder, column) in self.key_builders.iter_mut().zip(keys.iter()) {
+                builder.push(column.index(row).unwrap());
+            }
+            start = row;
+        }
+        self.accumulate(&data, start, num_rows)?;
+        self.current_key = Some(
+            keys.iter()
+                .map(|column| column.index(num_rows - 1).unwrap().to_owned())
+                .collect(),
+        );
+        Ok(output)
+    }
+
+    fn on_finish(&mut self, generate_data: bool) -> Result<Vec<DataBlock>> {
+        if !generate_data || self.current_key.take().is_none() {
+            return Ok(vec![]);
+        }
+        self.finish_group()?;
+        Ok(vec![self.take_output()?])
+    }
+}
+
+impl Drop for TransformSortMergeAggregate {
+    fn drop(&mut self) {
+        for (func, loc) in self.funcs.iter().zip(self.states_layout.states_loc.iter()) {
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(AggrState::new(self.addr, loc)) }
+            }
+        }
+    }
+}
@@ -46,6 +46,8 @@ pub struct HashJoinDesc {
     pub(crate) marker_join_desc: MarkJoinDesc,
     /// Whether the Join are derived from correlated subquery.
     pub(crate) from_correlated_subquery: bool,
+    /// Whether to remove the duplicate build keys when building the hash table.
+    pub(crate) build_dedup: bool,
     pub(crate) probe_keys_rt: Vec<Option<(Expr<String>, IndexType)>>,
     // Under cluster, mark if the join is broadcast join.
     pub broadcast: bool,
@@ -89,6 +91,7 @@ impl HashJoinDesc {
                 // marker_index: join.marker_index,
             },
             from_correlated_subquery: join.from_correlated_subquery,
+            build_dedup: join.build_dedup,
             probe_keys_rt,
             broadcast: join.broadcast,
             single_to_inner: join.single_to_inner.clone(),
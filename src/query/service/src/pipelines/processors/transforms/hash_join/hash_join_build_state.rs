@@ -23,6 +23,7 @@ use std::sync::Arc;
 use databend_common_base::base::tokio::sync::Barrier;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterReady;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterStats;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_column::bitmap::Bitmap;
 use databend_common_exception::ErrorCode;
@@ -112,6 +113,9 @@ pub struct HashJoinBuildState {
     /// Tasks for building hash table.
     pub(crate) build_hash_table_tasks: RwLock<VecDeque<usize>>,
     pub(crate) mutex: Mutex<()>,
+    /// The build keys that have been added, used to remove the duplicate build rows
+    /// if `build_dedup` of the hash join is true.
+    pub(crate) dedup_build_keys: Option<Mutex<HashSet<Scalar>>>,
 
     /// Spill related states.
     pub(crate) memory_settings: MemorySettings,
@@ -164,6 +168,10 @@ impl HashJoinBuildState {
         let settings = ctx.get_settings();
         let chunk_size_limit = settings.get_max_block_size()? as usize * 16;
         let memory_settings = MemorySettings::from_join_settings(&ctx)?;
+        let dedup_build_keys = hash_join_state
+            .hash_join_desc
+            .build_dedup
+            .then(|| Mutex::new(HashSet::new()));
 
         Ok(Arc::new(Self {
             ctx: ctx.clone(),
@@ -181,6 +189,7 @@ impl HashJoinBuildState {
             build_worker_num: Default::default(),
             build_hash_table_tasks: Default::default(),
             mutex: Default::default(),
+            dedup_build_keys,
             memory_settings,
             enable_bloom_runtime_filter,
             enable_inlist_runtime_filter,
@@ -220,6 +229,14 @@ impl HashJoinBuildState {
 
     // Add `data_block` for build table to `row_space`
     pub(crate) fn add_build_block(&self, data_block: DataBlock) -> Result<()> {
+        let data_block = match &self.dedup_build_keys {
+            Some(dedup_build_keys) => self.dedup_build_block(data_block, dedup_build_keys)?,
+            None => data_block,
+        };
+        if data_block.is_empty() {
+            return Ok(());
+        }
+
         let block_outer_scan_map = if self.hash_join_state.need_outer_scan()
             || matches!(
                 self.hash_join_state.hash_join_desc.single_to_inner,
@@ -260,6 +277,31 @@ impl HashJoinBuildState {
         Ok(())
     }
 
+    // Remove the rows whose build key has been added, only the first row of each key is kept.
+    fn dedup_build_block(
+        &self,
+        data_block: DataBlock,
+        dedup_build_keys: &Mutex<HashSet<Scalar>>,
+    ) -> Result<DataBlock> {
+        let build_key = &self.hash_join_state.hash_join_desc.build_keys[0];
+        let evaluator = Evaluator::new(&data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let key_column = evaluator
+            .run(build_key)?
+            .convert_to_full_column(build_key.data_type(), data_block.num_rows());
+
+        let bitmap = {
+            let mut dedup_build_keys = dedup_build_keys.lock();
+            key_column
+                .iter()
+                .map(|key| dedup_build_keys.insert(key.to_owned()))
+                .collect::<Bitmap>()
+        };
+        if bitmap.null_count() == 0 {
+            return Ok(data_block);
+        }
+        data_block.filter_with_bitmap(&bitmap)
+    }
+
     /// Attach to state: `collect_counter` and `finalize_counter`.
     pub fn build_attach(&self) {
         self.build_worker_num.fetch_add(1, Ordering::AcqRel);
@@ -951,6 +993,11 @@ impl HashJoinBuildState {
             });
             let filter = BinaryFuse16::try_from(&hashes_vec)?;
             runtime_filter.add_bloom((id.to_string(), filter));
+            let stats = RuntimeFilterStats {
+                build_rows: num_rows as u64,
+                ..Default::default()
+            };
+            runtime_filter.add_bloom_stats((id.to_string(), Arc::new(Mutex::new(stats))));
         }
         Ok(())
     }
@@ -19,6 +19,8 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use databend_common_base::base::tokio::sync::Barrier;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterStats;
+use databend_common_catalog::table_context::TableContext;
 use databend_common_column::bitmap::Bitmap;
 use databend_common_column::bitmap::MutableBitmap;
 use databend_common_exception::ErrorCode;
@@ -31,6 +33,7 @@ use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::HashMethod;
 use databend_common_expression::HashMethodKind;
@@ -287,11 +290,12 @@ impl HashJoinProbeState {
         // Adaptive early filtering.
         // Thanks to the **adaptive** execution strategy of early filtering, we don't experience a performance decrease
         // when all keys have matches. This allows us to achieve the same performance as before.
-        probe_state.num_keys += if let Some(valids) = &valids {
+        let num_keys = if let Some(valids) = &valids {
             (valids.len() - valids.null_count()) as u64
         } else {
             input_num_rows as u64
         };
+        probe_state.num_keys += num_keys;
         // We use the information from the probed data to predict the matching state of this probe.
         let prefer_early_filtering =
             (probe_state.num_keys_hash_matched as f64) / (probe_state.num_keys as f64) < 0.8;
@@ -359,6 +363,10 @@ impl HashJoinProbeState {
                     }
                 };
                 probe_state.num_keys_hash_matched += probe_state.selection_count as u64;
+                self.update_runtime_filter_stats(
+                    probe_state,
+                    num_keys - probe_state.selection_count as u64,
+                );
 
                 // Continue to probe hash table and process data blocks.
                 self.result_blocks(probe_state, keys, &table.hash_table)
@@ -369,6 +377,37 @@ impl HashJoinProbeState {
         })
     }
 
+    /// The probe keys that passed the bloom runtime filters but have no match in the
+    /// hash table are the false positives of the filters.
+    fn update_runtime_filter_stats(&self, probe_state: &mut ProbeState, unmatched_keys: u64) {
+        let runtime_filter_stats = probe_state
+            .runtime_filter_stats
+            .get_or_insert_with(|| self.bloom_runtime_filter_stats());
+        for stats in runtime_filter_stats.iter() {
+            stats.lock().false_positive_rows += unmatched_keys;
+        }
+    }
+
+    fn bloom_runtime_filter_stats(&self) -> Vec<Arc<Mutex<RuntimeFilterStats>>> {
+        self.hash_join_state
+            .hash_join_desc
+            .probe_keys_rt
+            .iter()
+            .flatten()
+            .filter_map(|(probe_key, table_index)| match probe_key {
+                Expr::ColumnRef { id, .. } => Some((id, *table_index)),
+                _ => None,
+            })
+            .flat_map(|(id, table_index)| {
+                self.ctx
+                    .get_bloom_runtime_filter_stats_with_id(table_index)
+                    .into_iter()
+                    .filter(move |(name, _)| name == id)
+                    .map(|(_, stats)| stats)
+            })
+            .collect()
+    }
+
     /// Checks if a join type can eliminate valids.
     pub fn check_for_eliminate_valids(
         from_correlated_subquery: bool,
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use databend_common_catalog::runtime_filter_info::RuntimeFilterStats;
 use databend_common_column::bitmap::Bitmap;
 use databend_common_expression::filter::FilterExecutor;
 use databend_common_expression::DataBlock;
@@ -20,6 +23,7 @@ use databend_common_expression::FunctionContext;
 use databend_common_expression::KeysState;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_hashtable::RowPtr;
+use parking_lot::Mutex;
 
 use super::desc::MARKER_KIND_FALSE;
 use crate::sql::plans::JoinType;
@@ -76,6 +80,9 @@ pub struct ProbeState {
     pub(crate) probe_unmatched_indexes_count: usize,
 
     pub(crate) filter_executor: Option<FilterExecutor>,
+    // The statistics of the bloom runtime filters applied on the probe keys,
+    // they are fetched from the query context at the first probe.
+    pub(crate) runtime_filter_stats: Option<Vec<Arc<Mutex<RuntimeFilterStats>>>>,
 }
 
 impl ProbeState {
@@ -151,6 +158,7 @@ impl ProbeState {
             probe_unmatched_indexes_count: 0,
             with_conjunction,
             filter_executor,
+            runtime_filter_stats: None,
         }
     }
 
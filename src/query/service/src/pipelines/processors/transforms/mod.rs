@@ -16,52 +16,123 @@ pub mod aggregator;
 mod hash_join;
 pub(crate) mod range_join;
 mod runtime_pool;
+pub(crate) mod semi_hash_join;
+pub(crate) mod sort_merge_join;
+mod transform_abort_if_empty;
 mod transform_add_computed_columns;
 mod transform_add_const_columns;
 mod transform_add_internal_columns;
 mod transform_add_stream_columns;
+mod transform_async_aggregate;
 mod transform_async_function;
+mod transform_bloom_build;
 mod transform_cache_scan;
 mod transform_cast_schema;
+mod transform_classify;
+mod transform_conditional_limit;
+mod transform_convert_timezone;
+mod transform_correlation;
 mod transform_create_sets;
 mod transform_dictionary;
+mod transform_emit;
+mod transform_enforce_schema;
 mod transform_expression_scan;
 mod transform_filter;
+mod transform_function_import;
+mod transform_grouping_id;
+mod transform_histogram;
+mod transform_json_each;
+mod transform_json_extract;
+mod transform_json_table;
 mod transform_limit;
 mod transform_merge_block;
 mod transform_null_if;
+mod transform_prewarm_cache;
 mod transform_recursive_cte_scan;
 mod transform_recursive_cte_source;
+mod transform_replicate;
 mod transform_resort_addon;
 mod transform_resort_addon_without_source_schema;
+mod transform_result_cache_scan;
+mod transform_scatter;
+mod transform_sequence_next;
+mod transform_skew_detection;
+mod transform_sorted_merge;
+mod transform_spill_sort;
 mod transform_srf;
 mod transform_stream_sort_spill;
+mod transform_tdigest_agg;
+mod transform_time_window;
+mod transform_transpose;
 mod transform_udf_script;
 mod transform_udf_server;
+mod transform_write_ahead_log;
 mod window;
 
 pub use hash_join::*;
+pub use semi_hash_join::SemiHashJoinState;
+pub use semi_hash_join::TransformSemiHashJoinBuild;
+pub use transform_abort_if_empty::AbortIfEmptyState;
+pub use transform_abort_if_empty::TransformAbortIfEmpty;
 pub use transform_add_computed_columns::TransformAddComputedColumns;
 pub use transform_add_const_columns::TransformAddConstColumns;
 pub use transform_add_internal_columns::TransformAddInternalColumns;
 pub use transform_add_stream_columns::TransformAddStreamColumns;
+pub use transform_async_aggregate::TransformAsyncAggregate;
 pub use transform_async_function::TransformAsyncFunction;
+pub use transform_bloom_build::BloomBuildState;
+pub use transform_bloom_build::TransformBloomBuild;
 pub use transform_cache_scan::CacheSourceState;
 pub use transform_cache_scan::HashJoinCacheState;
+pub use transform_cache_scan::ReplicateCacheState;
 pub use transform_cache_scan::TransformCacheScan;
 pub use transform_cast_schema::TransformCastSchema;
+pub use transform_classify::ClassifyModel;
+pub use transform_classify::TransformClassify;
+pub use transform_conditional_limit::TransformConditionalLimit;
+pub use transform_convert_timezone::TransformConvertTimezone;
+pub use transform_correlation::TransformCorrelation;
 pub use transform_create_sets::TransformCreateSets;
+pub use transform_emit::TransformEmit;
+pub use transform_enforce_schema::TransformEnforceSchema;
 pub use transform_expression_scan::TransformExpressionScan;
 pub use transform_filter::TransformFilter;
+pub use transform_function_import::TransformFunctionImport;
+pub use transform_grouping_id::TransformGroupingId;
+pub use transform_histogram::TransformHistogram;
+pub use transform_json_each::TransformJsonEach;
+pub use transform_json_extract::JsonPathElement;
+pub use transform_json_extract::TransformJsonExtract;
+pub use transform_json_table::TransformJsonTable;
 pub use transform_limit::TransformLimit;
 pub use transform_merge_block::TransformMergeBlock;
 pub use transform_null_if::TransformNullIf;
+pub use transform_prewarm_cache::TransformPrewarmCache;
 pub use transform_recursive_cte_scan::TransformRecursiveCteScan;
 pub use transform_recursive_cte_source::TransformRecursiveCteSource;
+pub use transform_replicate::ReplicateState;
+pub use transform_replicate::TransformReplicateSink;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
+pub use transform_result_cache_scan::TransformResultCacheScan;
+pub use transform_scatter::ScatterExchange;
+pub use transform_sequence_next::SequenceCounter;
+pub use transform_sequence_next::TransformSequenceNext;
+pub use transform_skew_detection::SkewDetectionState;
+pub use transform_skew_detection::TransformSkewDetection;
+pub use transform_sorted_merge::SortedStreamSource;
+pub use transform_sorted_merge::TransformSortedMerge;
+pub use transform_spill_sort::TransformSpillSort;
 pub use transform_srf::TransformSRF;
 pub use transform_stream_sort_spill::*;
+pub use transform_tdigest_agg::TransformTDigestAgg;
+pub use transform_time_window::TransformSessionWindow;
+pub use transform_time_window::TransformTumblingWindow;
+pub use transform_time_window::TransformWatermark;
+pub use transform_transpose::TransformTranspose;
 pub use transform_udf_script::TransformUdfScript;
 pub use transform_udf_server::TransformUdfServer;
+pub use transform_write_ahead_log::TransformWriteAheadLog;
+pub use transform_write_ahead_log::WriteAheadLogState;
+pub use transform_write_ahead_log::WAL_COMPLETE_MARKER;
 pub use window::*;
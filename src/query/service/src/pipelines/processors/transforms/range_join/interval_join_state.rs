@@ -0,0 +1,190 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_sql::executor::physical_plans::RangeJoin;
+use parking_lot::RwLock;
+
+use crate::pipelines::processors::transforms::range_join::filter_block;
+use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+
+/// The intervals of the rows of a block, sorted by the lower bounds.
+///
+/// It's an interval tree in the form of a segment tree over the sorted intervals: each node
+/// keeps the max upper bound of its intervals. A probe only visits the intervals whose lower
+/// bounds are below the key, and skips the nodes whose upper bounds are all below the key.
+struct IntervalTree {
+    // (lower, upper, row) of the rows without NULL bounds.
+    intervals: Vec<(Scalar, Scalar, u32)>,
+    max_upper: Vec<Scalar>,
+}
+
+impl IntervalTree {
+    fn new(block: &DataBlock) -> Self {
+        let lower = block.get_by_offset(0);
+        let upper = block.get_by_offset(1);
+        let mut intervals = Vec::with_capacity(block.num_rows());
+        for row in 0..block.num_rows() {
+            if let (Some(lower), Some(upper)) = (lower.value.index(row), upper.value.index(row)) {
+                if !lower.is_null() && !upper.is_null() {
+                    intervals.push((lower.to_owned(), upper.to_owned(), row as u32));
+                }
+            }
+        }
+        intervals.sort_by(|a, b| a.0.as_ref().cmp(&b.0.as_ref()));
+
+        let mut tree = IntervalTree {
+            max_upper: vec![Scalar::Null; intervals.len() * 4],
+            intervals,
+        };
+        if !tree.intervals.is_empty() {
+            tree.build(1, 0, tree.intervals.len());
+        }
+        tree
+    }
+
+    fn build(&mut self, node: usize, start: usize, end: usize) {
+        if end - start == 1 {
+            self.max_upper[node] = self.intervals[start].1.clone();
+            return;
+        }
+        let mid = (start + end) / 2;
+        self.build(node * 2, start, mid);
+        self.build(node * 2 + 1, mid, end);
+        let (left, right) = (&self.max_upper[node * 2], &self.max_upper[node * 2 + 1]);
+        self.max_upper[node] = match left.as_ref().cmp(&right.as_ref()) {
+            Ordering::Less => right.clone(),
+            _ => left.clone(),
+        };
+    }
+
+    // Push the rows of the intervals which contain `key`.
+    fn probe(&self, key: &ScalarRef, strict_lower: bool, strict_upper: bool, rows: &mut Vec<u32>) {
+        let limit = self
+            .intervals
+            .partition_point(|(lower, _, _)| match lower.as_ref().cmp(key) {
+                Ordering::Less => true,
+                Ordering::Equal => !strict_lower,
+                Ordering::Greater => false,
+            });
+        let mut stack = vec![(1, 0, self.intervals.len())];
+        while let Some((node, start, end)) = stack.pop() {
+            if start >= limit {
+                continue;
+            }
+            match self.max_upper[node].as_ref().cmp(key) {
+                Ordering::Less => continue,
+                Ordering::Equal if strict_upper => continue,
+                _ => {}
+            }
+            if end - start == 1 {
+                rows.push(self.intervals[start].2);
+                continue;
+            }
+            let mid = (start + end) / 2;
+            stack.push((node * 2 + 1, mid, end));
+            stack.push((node * 2, start, mid));
+        }
+    }
+}
+
+pub struct IntervalJoinState {
+    // The key is on the left side, the bounds are on the right side.
+    key_on_left: bool,
+    strict_lower: bool,
+    strict_upper: bool,
+    // The interval tree of each block of the bounds side.
+    trees: RwLock<Vec<IntervalTree>>,
+}
+
+impl IntervalJoinState {
+    pub(crate) fn new(range_join: &RangeJoin) -> Self {
+        let lower = &range_join.conditions[0];
+        let upper = &range_join.conditions[1];
+        IntervalJoinState {
+            key_on_left: lower.left_expr == upper.left_expr,
+            strict_lower: matches!(lower.operator.as_str(), "gt" | "lt"),
+            strict_upper: matches!(upper.operator.as_str(), "gt" | "lt"),
+            trees: RwLock::new(vec![]),
+        }
+    }
+}
+
+impl RangeJoinState {
+    // Build the interval trees from the key blocks of the bounds side, whose first two
+    // columns are the lower and upper bounds.
+    pub(crate) fn build_interval_trees(
+        &self,
+        left_sorted_blocks: &[DataBlock],
+        right_sorted_blocks: &[DataBlock],
+    ) {
+        let Some(state) = &self.interval_join_state else {
+            return;
+        };
+        let blocks = match state.key_on_left {
+            true => right_sorted_blocks,
+            false => left_sorted_blocks,
+        };
+        *state.trees.write() = blocks.iter().map(IntervalTree::new).collect();
+    }
+
+    pub fn interval_join(&self, task_id: usize) -> Result<Vec<DataBlock>> {
+        let state = self.interval_join_state.as_ref().unwrap();
+        let (left_idx, right_idx) = self.tasks.read()[task_id];
+        let (key_block, tree_idx) = match state.key_on_left {
+            true => (self.left_sorted_blocks.read()[left_idx].clone(), right_idx),
+            false => (self.right_sorted_blocks.read()[right_idx].clone(), left_idx),
+        };
+        let trees = state.trees.read();
+        let tree = &trees[tree_idx];
+
+        let mut key_rows = vec![];
+        let mut bound_rows = vec![];
+        let mut rows = vec![];
+        let key = key_block.get_by_offset(0);
+        for row in 0..key_block.num_rows() {
+            let Some(key) = key.value.index(row) else {
+                continue;
+            };
+            if key.is_null() {
+                continue;
+            }
+            tree.probe(&key, state.strict_lower, state.strict_upper, &mut rows);
+            key_rows.extend(std::iter::repeat_n(row as u32, rows.len()));
+            bound_rows.append(&mut rows);
+        }
+        if key_rows.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let (left_rows, right_rows) = match state.key_on_left {
+            true => (key_rows, bound_rows),
+            false => (bound_rows, key_rows),
+        };
+        let mut block = self.left_table.read()[left_idx].take(&left_rows)?;
+        let right_block = self.right_table.read()[right_idx].take(&right_rows)?;
+        for col in right_block.columns() {
+            block.add_column(col.clone());
+        }
+        for filter in self.other_conditions.iter() {
+            block = filter_block(block, filter)?;
+        }
+        Ok(vec![block])
+    }
+}
@@ -14,12 +14,14 @@
 
 mod ie_join_state;
 mod ie_join_util;
+mod interval_join_state;
 mod merge_join_state;
 mod range_join_state;
 mod transform_range_join;
 
 pub(crate) use ie_join_state::IEJoinState;
 pub(crate) use ie_join_util::*;
+pub(crate) use interval_join_state::IntervalJoinState;
 pub use range_join_state::RangeJoinState;
 pub use transform_range_join::TransformRangeJoinLeft;
 pub use transform_range_join::TransformRangeJoinRight;
@@ -36,6 +36,7 @@ use parking_lot::RwLock;
 
 use crate::pipelines::executor::WatchNotify;
 use crate::pipelines::processors::transforms::range_join::IEJoinState;
+use crate::pipelines::processors::transforms::range_join::IntervalJoinState;
 use crate::sessions::QueryContext;
 
 pub struct RangeJoinState {
@@ -61,6 +62,8 @@ pub struct RangeJoinState {
     pub(crate) finished_tasks: AtomicU64,
     // IEJoin state
     pub(crate) ie_join_state: Option<IEJoinState>,
+    // Interval join state
+    pub(crate) interval_join_state: Option<IntervalJoinState>,
 }
 
 impl RangeJoinState {
@@ -70,6 +73,11 @@ impl RangeJoinState {
         } else {
             None
         };
+        let interval_join_state = if matches!(range_join.range_join_type, RangeJoinType::Interval) {
+            Some(IntervalJoinState::new(range_join))
+        } else {
+            None
+        };
 
         Self {
             ctx,
@@ -88,6 +96,7 @@ impl RangeJoinState {
             row_offset: RwLock::new(vec![]),
             finished_tasks: AtomicU64::new(0),
             ie_join_state,
+            interval_join_state,
         }
     }
 
@@ -258,6 +267,7 @@ impl RangeJoinState {
             right_sorted_blocks.push(keys_block);
             current_rows += right_block.num_rows();
         }
+        self.build_interval_trees(&left_sorted_blocks, &right_sorted_blocks);
         // Add tasks
         let mut row_offset = self.row_offset.write();
         let mut left_offset = 0;
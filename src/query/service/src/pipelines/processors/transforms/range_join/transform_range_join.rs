@@ -67,6 +67,8 @@ impl Processor for TransformRangeJoinLeft {
     fn name(&self) -> String {
         if self.state.ie_join_state.is_some() {
             "TransformIEJoinLeft".to_string()
+        } else if self.state.interval_join_state.is_some() {
+            "TransformIntervalJoinLeft".to_string()
         } else {
             "TransformMergeJoinLeft".to_string()
         }
@@ -134,9 +136,12 @@ impl Processor for TransformRangeJoinLeft {
             RangeJoinStep::Execute => {
                 let task_id = self.state.task_id();
                 if let Some(task_id) = task_id {
-                    let res = match self.state.ie_join_state {
-                        Some(ref _ie_join_state) => self.state.ie_join(task_id)?,
-                        None => self.state.range_join(task_id)?,
+                    let res = if self.state.ie_join_state.is_some() {
+                        self.state.ie_join(task_id)?
+                    } else if self.state.interval_join_state.is_some() {
+                        self.state.interval_join(task_id)?
+                    } else {
+                        self.state.range_join(task_id)?
                     };
                     for block in res {
                         if !block.is_empty() {
@@ -0,0 +1,249 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_column::bitmap::Bitmap;
+use databend_common_column::bitmap::MutableBitmap;
+use databend_common_exception::Result;
+use databend_common_expression::arrow::and_validities;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::HashMethod;
+use databend_common_expression::HashMethodSerializer;
+use databend_common_expression::KeysState;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::executor::physical_plans::SemiHashJoin;
+use databend_common_sql::ColumnSet;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+use crate::pipelines::executor::WatchNotify;
+
+/// The shared state of `SemiHashJoin` and `AntiHashJoin`.
+///
+/// The build side only records the serialized keys and how many times they occur, rows with NULL
+/// keys are skipped because they can never be matched, unless NULL keys are equal (`EXCEPT`).
+pub struct SemiHashJoinState {
+    func_ctx: FunctionContext,
+    build_keys: Vec<Expr>,
+    probe_keys: Vec<Expr>,
+    projections: ColumnSet,
+    is_anti: bool,
+    is_null_equal: bool,
+    counting: bool,
+
+    keys: RwLock<HashMap<Vec<u8>, usize>>,
+
+    // Pipeline event related
+    build_sinker_count: Mutex<usize>,
+    build_finished: Mutex<bool>,
+    finished_notify: Arc<WatchNotify>,
+}
+
+impl SemiHashJoinState {
+    pub fn create(func_ctx: FunctionContext, join: &SemiHashJoin) -> Arc<Self> {
+        Arc::new(SemiHashJoinState {
+            func_ctx,
+            build_keys: join
+                .build_keys
+                .iter()
+                .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+                .collect(),
+            probe_keys: join
+                .probe_keys
+                .iter()
+                .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+                .collect(),
+            projections: join.projections.clone(),
+            is_anti: join.is_anti(),
+            is_null_equal: join.is_null_equal,
+            counting: join.counting,
+            keys: RwLock::new(HashMap::new()),
+            build_sinker_count: Mutex::new(0),
+            build_finished: Mutex::new(false),
+            finished_notify: Arc::new(WatchNotify::new()),
+        })
+    }
+
+    pub(crate) fn build_attach(&self) {
+        let mut build_sinker_count = self.build_sinker_count.lock();
+        *build_sinker_count += 1;
+    }
+
+    pub(crate) fn build_detach(&self) {
+        let mut build_sinker_count = self.build_sinker_count.lock();
+        *build_sinker_count -= 1;
+        if *build_sinker_count == 0 {
+            let mut build_finished = self.build_finished.lock();
+            *build_finished = true;
+            self.finished_notify.notify_waiters();
+        }
+    }
+
+    pub(crate) async fn wait_build_finish(&self) -> Result<()> {
+        let notified = {
+            let build_finished = self.build_finished.lock();
+
+            match *build_finished {
+                true => None,
+                false => Some(self.finished_notify.notified()),
+            }
+        };
+
+        if let Some(notified) = notified {
+            notified.await;
+        }
+        Ok(())
+    }
+
+    /// The number of the distinct keys of the build side.
+    pub fn num_keys(&self) -> usize {
+        self.keys.read().len()
+    }
+
+    pub(crate) fn build(&self, data_block: DataBlock) -> Result<()> {
+        if data_block.is_empty() {
+            return Ok(());
+        }
+
+        let (keys_state, validity) = self.serialize_keys(&data_block, &self.build_keys)?;
+        let method = HashMethodSerializer::default();
+        let keys_iter = method.build_keys_iter(&keys_state)?;
+
+        let mut keys = self.keys.write();
+        match validity {
+            Some(validity) => {
+                for (key, valid) in keys_iter.zip(validity.iter()) {
+                    if valid {
+                        self.insert_key(&mut keys, key);
+                    }
+                }
+            }
+            None => {
+                for key in keys_iter {
+                    self.insert_key(&mut keys, key);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn insert_key(&self, keys: &mut HashMap<Vec<u8>, usize>, key: &[u8]) {
+        match keys.get_mut(key) {
+            // The count is only needed by `EXCEPT ALL` and `INTERSECT ALL`.
+            Some(count) if self.counting => *count += 1,
+            Some(_) => {}
+            None => {
+                keys.insert(key.to_vec(), 1);
+            }
+        }
+    }
+
+    pub(crate) fn probe(&self, data_block: DataBlock) -> Result<DataBlock> {
+        if data_block.is_empty() {
+            return Ok(data_block.project(&self.projections));
+        }
+
+        let (keys_state, validity) = self.serialize_keys(&data_block, &self.probe_keys)?;
+        let method = HashMethodSerializer::default();
+        let keys_iter = method.build_keys_iter(&keys_state)?;
+
+        let mut selection = MutableBitmap::with_capacity(data_block.num_rows());
+        if self.counting {
+            // Each matched probe row consumes one build row, so the probe is serialized.
+            let mut keys = self.keys.write();
+            for (row, key) in keys_iter.enumerate() {
+                let valid = validity
+                    .as_ref()
+                    .is_none_or(|validity| validity.get_bit(row));
+                let matched = match keys.get_mut(key) {
+                    Some(count) if valid && *count > 0 => {
+                        *count -= 1;
+                        true
+                    }
+                    _ => false,
+                };
+                selection.push(matched != self.is_anti);
+            }
+        } else {
+            // A probe row with NULL key never matches, it's kept by anti join and dropped by semi join.
+            let keys = self.keys.read();
+            match validity {
+                Some(validity) => {
+                    for (key, valid) in keys_iter.zip(validity.iter()) {
+                        selection.push((valid && keys.contains_key(key)) != self.is_anti);
+                    }
+                }
+                None => {
+                    for key in keys_iter {
+                        selection.push(keys.contains_key(key) != self.is_anti);
+                    }
+                }
+            }
+        }
+
+        let selection: Bitmap = selection.into();
+        data_block
+            .filter_with_bitmap(&selection)
+            .map(|block| block.project(&self.projections))
+    }
+
+    fn serialize_keys(
+        &self,
+        data_block: &DataBlock,
+        keys: &[Expr],
+    ) -> Result<(KeysState, Option<Bitmap>)> {
+        let num_rows = data_block.num_rows();
+        let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+
+        if self.is_null_equal {
+            // NULL keys are serialized with the null flags, so that they are equal to each other.
+            let columns = keys
+                .iter()
+                .map(|expr| {
+                    Ok(evaluator
+                        .run(expr)?
+                        .convert_to_full_column(expr.data_type(), num_rows))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let method = HashMethodSerializer::default();
+            let keys_state = method.build_keys_state((&columns).into(), num_rows)?;
+            return Ok((keys_state, None));
+        }
+
+        let mut validity = None;
+        let mut columns = Vec::with_capacity(keys.len());
+        for expr in keys.iter() {
+            let column = evaluator
+                .run(expr)?
+                .convert_to_full_column(expr.data_type(), num_rows);
+            match column.validity() {
+                (true, _) => validity = Some(Bitmap::new_constant(false, num_rows)),
+                (false, column_validity) => {
+                    validity = and_validities(validity, column_validity.cloned());
+                }
+            }
+            // Both sides have been cast to the same type, serialize the keys without null flags.
+            columns.push(column.remove_nullable());
+        }
+
+        let method = HashMethodSerializer::default();
+        let keys_state = method.build_keys_state((&columns).into(), num_rows)?;
+        Ok((keys_state, validity))
+    }
+}
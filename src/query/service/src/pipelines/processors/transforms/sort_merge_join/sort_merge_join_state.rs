@@ -0,0 +1,280 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_column::bitmap::Bitmap;
+use databend_common_column::bitmap::MutableBitmap;
+use databend_common_exception::Result;
+use databend_common_expression::arrow::and_validities;
+use databend_common_expression::types::BinaryColumn;
+use databend_common_expression::types::BinaryColumnBuilder;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::RowConverter;
+use databend_common_expression::Scalar;
+use databend_common_expression::SortField;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::executor::physical_plans::SortMergeJoin;
+use databend_common_sql::plans::JoinType;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+use crate::pipelines::executor::WatchNotify;
+
+/// The shared state of `SortMergeJoin`.
+///
+/// The right side is collected in order, and the keys of both the sides are converted into
+/// the comparable row format, so the left side is merged with it by a cursor which only
+/// moves forward. The rows with NULL keys never match, the ones of the right side are dropped.
+pub struct SortMergeJoinState {
+    func_ctx: FunctionContext,
+    left_keys: Vec<Expr>,
+    right_keys: Vec<Expr>,
+    left_converter: RowConverter,
+    right_converter: RowConverter,
+    right_types: Vec<DataType>,
+    join_type: JoinType,
+
+    right_blocks: Mutex<Vec<DataBlock>>,
+    right: RwLock<Option<SortedRows>>,
+
+    // Pipeline event related
+    right_sinker_count: Mutex<usize>,
+    right_finished: Mutex<bool>,
+    finished_notify: Arc<WatchNotify>,
+}
+
+struct SortedRows {
+    block: DataBlock,
+    keys: BinaryColumn,
+}
+
+impl SortMergeJoinState {
+    pub fn create(func_ctx: FunctionContext, join: &SortMergeJoin) -> Result<Arc<Self>> {
+        let exprs = |keys: &[RemoteExpr]| {
+            keys.iter()
+                .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+                .collect::<Vec<_>>()
+        };
+        let left_keys = exprs(&join.left_keys);
+        let right_keys = exprs(&join.right_keys);
+        let converter = |keys: &[Expr]| {
+            RowConverter::new(
+                keys.iter()
+                    .zip(join.key_orders.iter())
+                    .map(|(expr, (asc, nulls_first))| {
+                        SortField::new_with_options(expr.data_type().clone(), *asc, *nulls_first)
+                    })
+                    .collect(),
+            )
+        };
+
+        Ok(Arc::new(SortMergeJoinState {
+            func_ctx,
+            left_converter: converter(&left_keys)?,
+            right_converter: converter(&right_keys)?,
+            left_keys,
+            right_keys,
+            right_types: join
+                .right
+                .output_schema()?
+                .fields()
+                .iter()
+                .map(|field| field.data_type().clone())
+                .collect(),
+            join_type: join.join_type.clone(),
+            right_blocks: Mutex::new(vec![]),
+            right: RwLock::new(None),
+            right_sinker_count: Mutex::new(0),
+            right_finished: Mutex::new(false),
+            finished_notify: Arc::new(WatchNotify::new()),
+        }))
+    }
+
+    pub(crate) fn right_attach(&self) {
+        let mut right_sinker_count = self.right_sinker_count.lock();
+        *right_sinker_count += 1;
+    }
+
+    pub(crate) fn right_detach(&self) -> Result<()> {
+        let mut right_sinker_count = self.right_sinker_count.lock();
+        *right_sinker_count -= 1;
+        if *right_sinker_count == 0 {
+            let blocks = std::mem::take(&mut *self.right_blocks.lock());
+            *self.right.write() = Some(self.sorted_rows(blocks)?);
+
+            let mut right_finished = self.right_finished.lock();
+            *right_finished = true;
+            self.finished_notify.notify_waiters();
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn wait_right_finish(&self) -> Result<()> {
+        let notified = {
+            let right_finished = self.right_finished.lock();
+
+            match *right_finished {
+                true => None,
+                false => Some(self.finished_notify.notified()),
+            }
+        };
+
+        if let Some(notified) = notified {
+            notified.await;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn sink_right(&self, data_block: DataBlock) -> Result<()> {
+        if !data_block.is_empty() {
+            self.right_blocks.lock().push(data_block);
+        }
+        Ok(())
+    }
+
+    // Concat the sorted blocks of the right side, and drop the rows with NULL keys.
+    fn sorted_rows(&self, blocks: Vec<DataBlock>) -> Result<SortedRows> {
+        if blocks.is_empty() {
+            return Ok(SortedRows {
+                block: DataBlock::new(vec![], 0),
+                keys: BinaryColumnBuilder::with_capacity(0, 0).build(),
+            });
+        }
+
+        let block = DataBlock::concat(&blocks)?;
+        let (columns, validity) = self.eval_keys(&block, &self.right_keys)?;
+        let (block, columns) = match validity {
+            Some(validity) => (
+                block.filter_with_bitmap(&validity)?,
+                columns
+                    .iter()
+                    .map(|column| column.filter(&validity))
+                    .collect(),
+            ),
+            None => (block, columns),
+        };
+        let keys = self
+            .right_converter
+            .convert_columns(&columns, block.num_rows());
+        Ok(SortedRows { block, keys })
+    }
+
+    fn eval_keys(
+        &self,
+        data_block: &DataBlock,
+        keys: &[Expr],
+    ) -> Result<(Vec<Column>, Option<Bitmap>)> {
+        let num_rows = data_block.num_rows();
+        let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+
+        let mut validity = None;
+        let mut columns = Vec::with_capacity(keys.len());
+        for expr in keys.iter() {
+            let column = evaluator
+                .run(expr)?
+                .convert_to_full_column(expr.data_type(), num_rows);
+            match column.validity() {
+                (true, _) => validity = Some(Bitmap::new_constant(false, num_rows)),
+                (false, column_validity) => {
+                    validity = and_validities(validity, column_validity.cloned());
+                }
+            }
+            columns.push(column);
+        }
+        Ok((columns, validity))
+    }
+
+    /// Merge a block of the left side with the right side from `cursor`, the first right row
+    /// which may match the left rows. The blocks of the left side must be merged in order.
+    pub(crate) fn merge(&self, cursor: &mut usize, data_block: DataBlock) -> Result<DataBlock> {
+        let right = self.right.read();
+        let right = right.as_ref().unwrap();
+        let is_left_join = self.join_type == JoinType::Left;
+        if data_block.is_empty() || (right.keys.len() == 0 && !is_left_join) {
+            return Ok(DataBlock::empty());
+        }
+
+        let (columns, validity) = self.eval_keys(&data_block, &self.left_keys)?;
+        let keys = self
+            .left_converter
+            .convert_columns(&columns, data_block.num_rows());
+
+        let mut left_indices = Vec::with_capacity(data_block.num_rows());
+        let mut right_indices = Vec::with_capacity(data_block.num_rows());
+        // Whether the left rows of the indices are matched, only needed by left join.
+        let mut matched = MutableBitmap::with_capacity(data_block.num_rows());
+        for row in 0..data_block.num_rows() {
+            let valid = validity
+                .as_ref()
+                .is_none_or(|validity| validity.get_bit(row));
+            let mut found = false;
+            if valid {
+                let key = keys.index(row).unwrap();
+                while *cursor < right.keys.len() && right.keys.index(*cursor).unwrap() < key {
+                    *cursor += 1;
+                }
+                // The cursor stays at the first tie, the next left row may have the same key.
+                let mut right_row = *cursor;
+                while right_row < right.keys.len() && right.keys.index(right_row).unwrap() == key {
+                    left_indices.push(row as u32);
+                    right_indices.push(right_row as u32);
+                    matched.push(true);
+                    right_row += 1;
+                    found = true;
+                }
+            }
+            if !found && is_left_join {
+                left_indices.push(row as u32);
+                right_indices.push(0);
+                matched.push(false);
+            }
+        }
+
+        let num_rows = left_indices.len();
+        if num_rows == 0 {
+            return Ok(DataBlock::empty());
+        }
+        let mut entries = data_block.take(&left_indices)?.columns().to_vec();
+        if right.block.num_rows() == 0 {
+            // All the rows are unmatched rows of left join.
+            entries.extend(self.right_types.iter().map(|data_type| {
+                BlockEntry::new(data_type.wrap_nullable(), Value::Scalar(Scalar::Null))
+            }));
+        } else {
+            let right_block = right.block.take(&right_indices)?;
+            match is_left_join {
+                true => {
+                    let matched: Bitmap = matched.into();
+                    entries.extend(right_block.columns().iter().map(|entry| {
+                        let column = entry
+                            .to_column(num_rows)
+                            .wrap_nullable(Some(matched.clone()));
+                        BlockEntry::new(entry.data_type.wrap_nullable(), Value::Column(column))
+                    }));
+                }
+                false => entries.extend(right_block.columns().iter().cloned()),
+            }
+        }
+        Ok(DataBlock::new(entries, num_rows))
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_transforms::processors::Transform;
+
+/// The rows passed through the parallel `TransformAbortIfEmpty`s of one `AbortIfEmpty` plan.
+/// The last finished transform fails if there is no row.
+pub struct AbortIfEmptyState {
+    rows: AtomicU64,
+    running: AtomicUsize,
+}
+
+impl AbortIfEmptyState {
+    pub fn new(num_transforms: usize) -> Arc<Self> {
+        Arc::new(AbortIfEmptyState {
+            rows: AtomicU64::new(0),
+            running: AtomicUsize::new(num_transforms),
+        })
+    }
+}
+
+/// Count the rows of each block, the block is passed through unchanged.
+pub struct TransformAbortIfEmpty {
+    error_message: String,
+    state: Arc<AbortIfEmptyState>,
+}
+
+impl TransformAbortIfEmpty {
+    pub fn new(error_message: String, state: Arc<AbortIfEmptyState>) -> Self {
+        TransformAbortIfEmpty {
+            error_message,
+            state,
+        }
+    }
+}
+
+impl Transform for TransformAbortIfEmpty {
+    const NAME: &'static str = "TransformAbortIfEmpty";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        self.state
+            .rows
+            .fetch_add(data.num_rows() as u64, Ordering::Relaxed);
+        Ok(data)
+    }
+
+    fn on_finish(&mut self) -> Result<()> {
+        if self.state.running.fetch_sub(1, Ordering::AcqRel) != 1 {
+            return Ok(());
+        }
+        if self.state.rows.load(Ordering::Relaxed) == 0 {
+            return Err(ErrorCode::EmptyResult(self.error_message.clone()));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::infer_table_schema;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::FromData;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipe;
+use databend_common_pipeline_core::PipeItem;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_sql::executor::physical_plans::AsyncAggregate;
+use databend_common_storages_result_cache::WriteResultCacheSink;
+use databend_common_users::UserApiProvider;
+use log::info;
+use log::warn;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::schedulers::build_query_pipeline;
+use crate::sessions::QueryContext;
+
+/// Run the inner plan of an `AsyncAggregate` in a background task of a new query context,
+/// and write its result into the query result cache. The source outputs the query id of
+/// the background task at once.
+pub struct TransformAsyncAggregate {
+    ctx: Arc<QueryContext>,
+    plan: Option<AsyncAggregate>,
+}
+
+impl TransformAsyncAggregate {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        plan: AsyncAggregate,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output_port, TransformAsyncAggregate {
+            ctx,
+            plan: Some(plan),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for TransformAsyncAggregate {
+    const NAME: &'static str = "AsyncAggregate";
+
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let Some(plan) = self.plan.take() else {
+            return Ok(None);
+        };
+
+        let ctx = self
+            .ctx
+            .get_current_session()
+            .create_query_context()
+            .await?;
+        // The result is always cached, or it could not be read by `RESULT_SCAN`.
+        let settings = HashMap::from([(
+            "query_result_cache_min_execute_secs".to_string(),
+            "0".to_string(),
+        )]);
+        ctx.get_shared_settings()
+            .set_batch_settings(&settings, true)?;
+        ctx.attach_query_str(self.ctx.get_query_kind(), self.ctx.get_query_str());
+
+        let query_id = ctx.get_id();
+        info!("Run async aggregate in background query {}", query_id);
+        GlobalIORuntime::instance().spawn(async move {
+            let query_id = ctx.get_id();
+            let res = execute_in_background(&ctx, &plan).await;
+            if let Err(cause) = &res {
+                warn!("Async aggregate query {} failed: {:?}", query_id, cause);
+            }
+            if let Some(webhook) = &plan.notify_webhook {
+                notify(webhook, &query_id, res).await;
+            }
+        });
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![query_id]),
+        ])))
+    }
+}
+
+async fn execute_in_background(ctx: &Arc<QueryContext>, plan: &AsyncAggregate) -> Result<()> {
+    let mut build_res = build_query_pipeline(ctx, &plan.result_columns, &plan.inner, false).await?;
+
+    let schema = DataSchemaRefExt::create(
+        plan.result_columns
+            .iter()
+            .map(|column_binding| {
+                DataField::new(
+                    &column_binding.column_name,
+                    *column_binding.data_type.clone(),
+                )
+            })
+            .collect(),
+    );
+    let output_len = build_res.main_pipeline.output_len();
+    let inputs = (0..output_len)
+        .map(|_| InputPort::create())
+        .collect::<Vec<_>>();
+    let sink = WriteResultCacheSink::try_create(
+        ctx.clone(),
+        &plan.result_cache_key,
+        infer_table_schema(&schema)?,
+        inputs.clone(),
+        UserApiProvider::instance().get_meta_store_client(),
+        plan.result_cache_columns_used.clone(),
+    )?;
+    build_res
+        .main_pipeline
+        .add_pipe(Pipe::create(output_len, 0, vec![PipeItem::create(
+            sink,
+            inputs,
+            vec![],
+        )]));
+
+    build_res.set_max_threads(ctx.get_settings().get_max_threads()? as usize);
+    let executor_settings = ExecutorSettings::try_create(ctx.clone())?;
+    let mut pipelines = build_res.sources_pipelines;
+    pipelines.push(build_res.main_pipeline);
+    let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+    ctx.set_executor(executor.get_inner())?;
+    GlobalIORuntime::instance()
+        .spawn_blocking(move || executor.execute())
+        .await?;
+
+    // The sink skips the results larger than `query_result_cache_max_bytes`.
+    match ctx.get_result_cache_key(&ctx.get_id()) {
+        Some(_) => Ok(()),
+        None => Err(ErrorCode::Internal(
+            "The result exceeds query_result_cache_max_bytes and is not cached",
+        )),
+    }
+}
+
+/// POST the state of the background query to `webhook`.
+async fn notify(webhook: &str, query_id: &str, res: Result<()>) {
+    let body = match res {
+        Ok(_) => serde_json::json!({
+            "query_id": query_id,
+            "state": "Succeeded",
+        }),
+        Err(cause) => serde_json::json!({
+            "query_id": query_id,
+            "state": "Failed",
+            "error": cause.message(),
+        }),
+    };
+
+    let client = reqwest::Client::new();
+    if let Err(cause) = client
+        .post(webhook)
+        .json(&body)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+    {
+        warn!(
+            "Notify webhook {} of async aggregate query {} failed: {:?}",
+            webhook, query_id, cause
+        );
+    }
+}
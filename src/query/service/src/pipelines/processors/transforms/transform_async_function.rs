@@ -15,25 +15,16 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
-use databend_common_expression::types::DataType;
-use databend_common_expression::types::UInt64Type;
-use databend_common_expression::BlockEntry;
 use databend_common_expression::DataBlock;
-use databend_common_expression::FromData;
-use databend_common_expression::Value;
-use databend_common_meta_app::schema::GetSequenceNextValueReq;
-use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_pipeline_transforms::processors::AsyncTransform;
-use databend_common_storages_fuse::TableContext;
 
 use crate::pipelines::processors::transforms::transform_dictionary::DictionaryOperator;
-use crate::sessions::QueryContext;
 use crate::sql::executor::physical_plans::AsyncFunctionDesc;
 use crate::sql::plans::AsyncFunctionArgument;
 
 pub struct TransformAsyncFunction {
-    ctx: Arc<QueryContext>,
     // key is the index of async_func_desc
     pub(crate) operators: BTreeMap<usize, Arc<DictionaryOperator>>,
     async_func_descs: Vec<AsyncFunctionDesc>,
@@ -41,46 +32,14 @@ pub struct TransformAsyncFunction {
 
 impl TransformAsyncFunction {
     pub(crate) fn new(
-        ctx: Arc<QueryContext>,
         async_func_descs: Vec<AsyncFunctionDesc>,
         operators: BTreeMap<usize, Arc<DictionaryOperator>>,
     ) -> Self {
         Self {
-            ctx,
             async_func_descs,
             operators,
         }
     }
-
-    // transform add sequence nextval column.
-    async fn transform_sequence(
-        &self,
-        data_block: &mut DataBlock,
-        sequence_name: &String,
-        data_type: &DataType,
-    ) -> Result<()> {
-        let count = data_block.num_rows() as u64;
-        let value = if count == 0 {
-            UInt64Type::from_data(vec![])
-        } else {
-            let tenant = self.ctx.get_tenant();
-            let catalog = self.ctx.get_default_catalog()?;
-            let req = GetSequenceNextValueReq {
-                ident: SequenceIdent::new(&tenant, sequence_name),
-                count,
-            };
-            let resp = catalog.get_sequence_next_value(req).await?;
-            let range = resp.start..resp.start + count;
-            UInt64Type::from_data(range.collect::<Vec<u64>>())
-        };
-        let entry = BlockEntry {
-            data_type: data_type.clone(),
-            value: Value::Column(value),
-        };
-        data_block.add_column(entry);
-
-        Ok(())
-    }
 }
 
 #[async_trait::async_trait]
@@ -91,13 +50,10 @@ impl AsyncTransform for TransformAsyncFunction {
     async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
         for (i, async_func_desc) in self.async_func_descs.iter().enumerate() {
             match &async_func_desc.func_arg {
-                AsyncFunctionArgument::SequenceFunction(sequence_name) => {
-                    self.transform_sequence(
-                        &mut data_block,
-                        sequence_name,
-                        &async_func_desc.data_type,
-                    )
-                    .await?;
+                AsyncFunctionArgument::SequenceFunction(_) => {
+                    return Err(ErrorCode::Internal(
+                        "nextval should be evaluated by SequenceNext",
+                    ));
                 }
                 AsyncFunctionArgument::DictGetFunction(dict_arg) => {
                     self.transform_dict_get(
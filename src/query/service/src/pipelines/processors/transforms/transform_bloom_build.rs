@@ -0,0 +1,187 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::TableField;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use databend_storages_common_blocks::blocks_to_parquet;
+use databend_storages_common_index::filters::BlockFilter;
+use databend_storages_common_index::BloomIndex;
+use databend_storages_common_table_meta::meta::Versioned;
+use databend_storages_common_table_meta::table::TableCompression;
+use opendal::Operator;
+use parking_lot::Mutex;
+
+/// The bloom-index columns of the rows seen by the parallel `TransformBloomBuild`s of one
+/// `BloomBuild` plan. The last finished transform builds and writes the bloom filters.
+pub struct BloomBuildState {
+    blocks: Mutex<Vec<DataBlock>>,
+    running: AtomicUsize,
+}
+
+impl BloomBuildState {
+    pub fn new(num_transforms: usize) -> Arc<Self> {
+        Arc::new(BloomBuildState {
+            blocks: Mutex::new(vec![]),
+            running: AtomicUsize::new(num_transforms),
+        })
+    }
+}
+
+/// Collect the bloom-index columns of each block, the block is passed through unchanged.
+pub struct TransformBloomBuild {
+    func_ctx: FunctionContext,
+    bloom_columns: Vec<usize>,
+    bloom_fields: Vec<TableField>,
+    // The cast of each bloom column to the type of its table field, `None` if the column
+    // already has the type.
+    casts: Vec<Option<Expr>>,
+    location: String,
+    operator: Operator,
+    state: Arc<BloomBuildState>,
+}
+
+impl TransformBloomBuild {
+    pub fn try_new(
+        func_ctx: FunctionContext,
+        input_schema: DataSchemaRef,
+        bloom_columns: Vec<usize>,
+        bloom_fields: Vec<TableField>,
+        location: String,
+        operator: Operator,
+        state: Arc<BloomBuildState>,
+    ) -> Result<Self> {
+        let casts = bloom_columns
+            .iter()
+            .zip(bloom_fields.iter())
+            .map(|(offset, field)| {
+                let from = input_schema.field(*offset);
+                let to = DataType::from(field.data_type());
+                if from.data_type() == &to {
+                    return Ok(None);
+                }
+                let expr = Expr::ColumnRef {
+                    span: None,
+                    id: *offset,
+                    data_type: from.data_type().clone(),
+                    display_name: from.name().clone(),
+                };
+                Ok(Some(check_cast(
+                    None,
+                    false,
+                    expr,
+                    &to,
+                    &BUILTIN_FUNCTIONS,
+                )?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(TransformBloomBuild {
+            func_ctx,
+            bloom_columns,
+            bloom_fields,
+            casts,
+            location,
+            operator,
+            state,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncAccumulatingTransform for TransformBloomBuild {
+    const NAME: &'static str = "TransformBloomBuild";
+
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(Some(data));
+        }
+
+        let evaluator = Evaluator::new(&data, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let mut columns = Vec::with_capacity(self.bloom_columns.len());
+        for ((offset, field), cast) in self
+            .bloom_columns
+            .iter()
+            .zip(self.bloom_fields.iter())
+            .zip(self.casts.iter())
+        {
+            let column = match cast {
+                None => data.get_by_offset(*offset).clone(),
+                Some(expr) => {
+                    BlockEntry::new(DataType::from(field.data_type()), evaluator.run(expr)?)
+                }
+            };
+            columns.push(column);
+        }
+        self.state
+            .blocks
+            .lock()
+            .push(DataBlock::new(columns, data.num_rows()));
+        Ok(Some(data))
+    }
+
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        if self.state.running.fetch_sub(1, Ordering::AcqRel) != 1 {
+            return Ok(None);
+        }
+
+        let blocks = std::mem::take(&mut *self.state.blocks.lock());
+        if blocks.is_empty() {
+            return Ok(None);
+        }
+        let block = DataBlock::concat(&blocks)?;
+
+        let bloom_columns_map = self
+            .bloom_fields
+            .iter()
+            .cloned()
+            .enumerate()
+            .collect::<BTreeMap<_, _>>();
+        let Some(bloom_index) = BloomIndex::try_create(
+            self.func_ctx.clone(),
+            BlockFilter::VERSION,
+            &block,
+            bloom_columns_map,
+        )?
+        else {
+            return Ok(None);
+        };
+
+        let index_block = bloom_index.serialize_to_data_block()?;
+        let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+        blocks_to_parquet(
+            &bloom_index.filter_schema,
+            vec![index_block],
+            &mut data,
+            TableCompression::None,
+        )?;
+        self.operator.write(&self.location, data).await?;
+        Ok(None)
+    }
+}
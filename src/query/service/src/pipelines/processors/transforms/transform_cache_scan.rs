@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::collections::VecDeque;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use databend_common_exception::Result;
@@ -23,18 +25,30 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
 
+use crate::pipelines::processors::transforms::ReplicateState;
 use crate::pipelines::processors::HashJoinState;
 use crate::sessions::QueryContext;
 
 #[derive(Clone)]
 pub enum CacheSourceState {
     HashJoinCacheState(HashJoinCacheState),
+    ReplicateCacheState(ReplicateCacheState),
 }
 
 impl CacheSourceState {
+    async fn wait_ready(&self) -> Result<()> {
+        match self {
+            CacheSourceState::HashJoinCacheState(_) => Ok(()),
+            CacheSourceState::ReplicateCacheState(state) => {
+                state.replicate_state.wait_finish().await
+            }
+        }
+    }
+
     fn next_data_block(&mut self) -> Option<DataBlock> {
         match self {
             CacheSourceState::HashJoinCacheState(state) => state.next_data_block(),
+            CacheSourceState::ReplicateCacheState(state) => state.next_data_block(),
         }
     }
 }
@@ -104,6 +118,64 @@ impl HashJoinCacheState {
     }
 }
 
+/// Read the blocks stored by `PhysicalPlan::Replicate`, the sources of a `CacheScan` share
+/// the `next_block_index`, so that each block is only output once.
+#[derive(Clone)]
+pub struct ReplicateCacheState {
+    column_indexes: Vec<usize>,
+    replicate_state: Arc<ReplicateState>,
+    blocks: Option<Vec<DataBlock>>,
+    next_block_index: Arc<AtomicUsize>,
+    output_buffer: VecDeque<DataBlock>,
+    max_block_size: usize,
+}
+
+impl ReplicateCacheState {
+    pub fn new(
+        column_indexes: Vec<usize>,
+        replicate_state: Arc<ReplicateState>,
+        max_block_size: usize,
+    ) -> Self {
+        Self {
+            column_indexes,
+            replicate_state,
+            blocks: None,
+            next_block_index: Arc::new(AtomicUsize::new(0)),
+            output_buffer: VecDeque::new(),
+            max_block_size,
+        }
+    }
+
+    fn next_data_block(&mut self) -> Option<DataBlock> {
+        if let Some(data_block) = self.output_buffer.pop_front() {
+            return Some(data_block);
+        }
+
+        let blocks = self
+            .blocks
+            .get_or_insert_with(|| self.replicate_state.blocks());
+        let next_block_index = self.next_block_index.fetch_add(1, Ordering::SeqCst);
+        if next_block_index >= blocks.len() {
+            // Release memory.
+            blocks.clear();
+            return None;
+        }
+
+        let data_block = &blocks[next_block_index];
+        let block_entries = self
+            .column_indexes
+            .iter()
+            .map(|idx| data_block.get_by_offset(*idx).clone())
+            .collect::<Vec<BlockEntry>>();
+        let data_block = DataBlock::new(block_entries, data_block.num_rows());
+        for data_block in data_block.split_by_rows_no_tail(self.max_block_size) {
+            self.output_buffer.push_back(data_block);
+        }
+
+        self.output_buffer.pop_front()
+    }
+}
+
 pub struct TransformCacheScan {
     cache_source_state: CacheSourceState,
 }
@@ -126,6 +198,7 @@ impl AsyncSource for TransformCacheScan {
 
     #[async_backtrace::framed]
     async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        self.cache_source_state.wait_ready().await?;
         let data_block = self.cache_source_state.next_data_block();
         Ok(data_block)
     }
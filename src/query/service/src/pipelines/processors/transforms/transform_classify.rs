@@ -0,0 +1,206 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio::sync::Mutex;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Float32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::types::F32;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Value;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_sql::binder::resolve_stage_location;
+use databend_common_storage::init_stage_operator;
+use log::info;
+use tract_onnx::prelude::tvec;
+use tract_onnx::prelude::Framework;
+use tract_onnx::prelude::InferenceModelExt;
+use tract_onnx::prelude::TValue;
+use tract_onnx::prelude::Tensor;
+use tract_onnx::prelude::TypedModel;
+use tract_onnx::prelude::TypedRunnableModel;
+
+use crate::sessions::QueryContext;
+
+type OnnxModel = TypedRunnableModel<TypedModel>;
+
+/// The ONNX model of a `Classify` plan, loaded from the stage by the first transform that
+/// runs, and shared by the parallel transforms of the plan.
+pub struct ClassifyModel {
+    ctx: Arc<QueryContext>,
+    location: String,
+    model: Mutex<Option<Arc<OnnxModel>>>,
+}
+
+impl ClassifyModel {
+    pub fn new(ctx: Arc<QueryContext>, location: String) -> Arc<Self> {
+        Arc::new(ClassifyModel {
+            ctx,
+            location,
+            model: Mutex::new(None),
+        })
+    }
+
+    async fn get(&self) -> Result<Arc<OnnxModel>> {
+        // The lock is held while loading, so the model is read only once.
+        let mut model = self.model.lock().await;
+        if let Some(model) = model.as_ref() {
+            return Ok(model.clone());
+        }
+
+        let location = self.location.trim_start_matches('@');
+        let (stage_info, path) = resolve_stage_location(self.ctx.as_ref(), location).await?;
+        let operator = init_stage_operator(&stage_info)?;
+        let data = operator.read(&path).await?.to_vec();
+        let loaded = tract_onnx::onnx()
+            .model_for_read(&mut data.as_slice())
+            .and_then(|model| model.into_optimized())
+            .and_then(|model| model.into_runnable())
+            .map_err(|e| {
+                ErrorCode::BadBytes(format!("Invalid ONNX model {}: {}", self.location, e))
+            })?;
+        info!(
+            "Loaded ONNX model {} of {} bytes",
+            self.location,
+            data.len()
+        );
+
+        let loaded = Arc::new(loaded);
+        *model = Some(loaded.clone());
+        Ok(loaded)
+    }
+}
+
+/// Run the model on the input columns of each block, and append the columns of the
+/// predictions. The prediction of a row with a NULL input is NULL.
+pub struct TransformClassify {
+    model: Arc<ClassifyModel>,
+    input_offsets: Vec<usize>,
+    output_types: Vec<DataType>,
+    batch_size: usize,
+}
+
+impl TransformClassify {
+    pub fn new(
+        model: Arc<ClassifyModel>,
+        input_offsets: Vec<usize>,
+        output_types: Vec<DataType>,
+        batch_size: usize,
+    ) -> Self {
+        TransformClassify {
+            model,
+            input_offsets,
+            output_types,
+            batch_size: batch_size.max(1),
+        }
+    }
+
+    // Run the model on `rows` rows of the row-major `inputs`, returns the row-major outputs.
+    fn predict(&self, model: &OnnxModel, inputs: &[f32], rows: usize) -> Result<Vec<f32>> {
+        let num_inputs = self.input_offsets.len();
+        let outputs = Tensor::from_shape(&[rows, num_inputs], inputs)
+            .and_then(|input| model.run(tvec!(TValue::from(input))))
+            .map_err(|e| {
+                ErrorCode::UDFRuntimeError(format!(
+                    "Failed to run ONNX model {}: {}",
+                    self.model.location, e
+                ))
+            })?;
+        let Some(output) = outputs.first() else {
+            return Err(ErrorCode::UDFRuntimeError(format!(
+                "ONNX model {} has no output",
+                self.model.location
+            )));
+        };
+        let output = output.cast_to::<f32>().map_err(|e| {
+            ErrorCode::UDFRuntimeError(format!(
+                "Output of ONNX model {} is not a float tensor: {}",
+                self.model.location, e
+            ))
+        })?;
+        let values = output.as_slice::<f32>().map_err(|e| {
+            ErrorCode::UDFRuntimeError(format!("Invalid output of ONNX model: {}", e))
+        })?;
+        if output.shape().first() != Some(&rows) || values.len() < rows * self.output_types.len() {
+            return Err(ErrorCode::UDFRuntimeError(format!(
+                "Output of ONNX model {} of shape {:?} doesn't match {} rows of {} columns",
+                self.model.location,
+                output.shape(),
+                rows,
+                self.output_types.len()
+            )));
+        }
+        Ok(values.to_vec())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformClassify {
+    const NAME: &'static str = "Classify";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        let num_inputs = self.input_offsets.len();
+        let mut validity = vec![true; num_rows];
+        // The inputs of the rows in row-major order, the NULLs are fed to the model as zeros.
+        let mut inputs = vec![0f32; num_rows * num_inputs];
+        for (i, offset) in self.input_offsets.iter().enumerate() {
+            let column = data_block.get_by_offset(*offset).to_column(num_rows);
+            if let Column::Nullable(column) = &column {
+                for (row, valid) in column.validity.iter().enumerate() {
+                    validity[row] &= valid;
+                }
+            }
+            let column = Float32Type::try_downcast_column(&column.remove_nullable()).unwrap();
+            for (row, value) in column.iter().enumerate() {
+                inputs[row * num_inputs + i] = value.0;
+            }
+        }
+
+        let mut outputs = vec![vec![F32::from(0.0); num_rows]; self.output_types.len()];
+        if num_rows > 0 {
+            let model = self.model.get().await?;
+            for start in (0..num_rows).step_by(self.batch_size) {
+                let rows = self.batch_size.min(num_rows - start);
+                let batch = &inputs[start * num_inputs..(start + rows) * num_inputs];
+                let values = self.predict(&model, batch, rows)?;
+                let width = values.len() / rows;
+                for row in 0..rows {
+                    for (i, output) in outputs.iter_mut().enumerate() {
+                        output[start + row] = F32::from(values[row * width + i]);
+                    }
+                }
+            }
+        }
+
+        for (output, data_type) in outputs.into_iter().zip(self.output_types.iter()) {
+            data_block.add_column(BlockEntry {
+                data_type: data_type.clone(),
+                value: Value::Column(Float32Type::from_data_with_validity(
+                    output,
+                    validity.clone(),
+                )),
+            });
+        }
+        Ok(data_block)
+    }
+}
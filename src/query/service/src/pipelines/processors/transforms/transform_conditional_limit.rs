@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::filter::FilterExecutor;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::pipelines::processors::Event;
+use crate::pipelines::processors::InputPort;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::Processor;
+
+/// Output the rows satisfying the condition until `limit` of them are collected, then
+/// finish the input to stop the upstream processors.
+pub struct TransformConditionalLimit {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    filter: FilterExecutor,
+    max_block_size: usize,
+    take_remaining: usize,
+
+    input_data_block: Option<DataBlock>,
+    output_data_blocks: VecDeque<DataBlock>,
+}
+
+impl TransformConditionalLimit {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        condition: Expr,
+        limit: usize,
+        func_ctx: FunctionContext,
+        max_block_size: usize,
+    ) -> Box<dyn Processor> {
+        let filter = FilterExecutor::new(
+            condition,
+            func_ctx,
+            max_block_size,
+            None,
+            &BUILTIN_FUNCTIONS,
+            true,
+        );
+        Box::new(TransformConditionalLimit {
+            input,
+            output,
+            filter,
+            max_block_size,
+            take_remaining: limit,
+            input_data_block: None,
+            output_data_blocks: VecDeque::new(),
+        })
+    }
+}
+
+impl Processor for TransformConditionalLimit {
+    fn name(&self) -> String {
+        "ConditionalLimitTransform".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data_block) = self.output_data_blocks.pop_front() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.take_remaining == 0 {
+            self.input.finish();
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        if self.input_data_block.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.input.is_finished() {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.input.has_data() {
+            self.input.set_need_data();
+            return Ok(Event::NeedData);
+        }
+
+        self.input_data_block = Some(self.input.pull_data().unwrap()?);
+        Ok(Event::Sync)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data_block.take() {
+            for block in data_block.split_by_rows_no_tail(self.max_block_size) {
+                if self.take_remaining == 0 {
+                    break;
+                }
+                let mut block = self.filter.filter(block)?;
+                if block.num_rows() > self.take_remaining {
+                    block = block.slice(0..self.take_remaining);
+                }
+                self.take_remaining -= block.num_rows();
+                if !block.is_empty() {
+                    self.output_data_blocks.push_back(block);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_column::buffer::Buffer;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::date_helper::DateConverter;
+use databend_common_expression::types::timestamp::clamp_timestamp;
+use databend_common_expression::types::NullableColumn;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Value;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_sql::executor::physical_plans::TzDirection;
+use jiff::tz::TimeZone;
+
+/// Convert the `TIMESTAMP` columns at `offsets` between UTC and the wall-clock time of `tz`.
+///
+/// A wall-clock time that is skipped by a DST transition is moved forward by the length
+/// of the gap, and a wall-clock time that is repeated takes the offset before the transition.
+pub struct TransformConvertTimezone {
+    offsets: Vec<usize>,
+    tz: TimeZone,
+    direction: TzDirection,
+}
+
+impl TransformConvertTimezone {
+    pub fn try_new(offsets: Vec<usize>, tz: &str, direction: TzDirection) -> Result<Self> {
+        let tz = TimeZone::get(tz)
+            .map_err(|e| ErrorCode::InvalidTimezone(format!("Invalid timezone '{}': {}", tz, e)))?;
+        Ok(Self {
+            offsets,
+            tz,
+            direction,
+        })
+    }
+
+    pub fn convert(&self, micros: i64) -> Result<i64> {
+        let (from, to) = match self.direction {
+            TzDirection::ToLocal => (&self.tz, &TimeZone::UTC),
+            TzDirection::ToUtc => (&TimeZone::UTC, &self.tz),
+        };
+        let datetime = micros.to_timestamp(from.clone()).datetime();
+        let mut micros = datetime
+            .to_zoned(to.clone())
+            .map_err(|e| ErrorCode::BadArguments(format!("Cannot convert timestamp: {}", e)))?
+            .timestamp()
+            .as_microsecond();
+        clamp_timestamp(&mut micros);
+        Ok(micros)
+    }
+
+    fn convert_column(&self, column: Column) -> Result<Column> {
+        Ok(match column {
+            Column::Timestamp(values) => Column::Timestamp(
+                values
+                    .iter()
+                    .map(|micros| self.convert(*micros))
+                    .collect::<Result<Buffer<_>>>()?,
+            ),
+            Column::Nullable(box NullableColumn { column, validity }) => Column::Nullable(
+                Box::new(NullableColumn::new(self.convert_column(column)?, validity)),
+            ),
+            column => {
+                return Err(ErrorCode::Internal(format!(
+                    "ConvertTimezone expects a timestamp column, but got {}",
+                    column.data_type()
+                )));
+            }
+        })
+    }
+}
+
+impl Transform for TransformConvertTimezone {
+    const NAME: &'static str = "ConvertTimezoneTransform";
+
+    fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        for offset in self.offsets.iter() {
+            let entry = data_block.get_by_offset(*offset);
+            let data_type = entry.data_type.clone();
+            let column = self.convert_column(entry.to_column(num_rows))?;
+            data_block.columns_mut()[*offset] = BlockEntry::new(data_type, Value::Column(column));
+        }
+        Ok(data_block)
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::F64;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_functions::aggregates::AggregateCorrelationState;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+
+/// Compute `CORR(x, y)` of the columns at `x_offset` and `y_offset` in a single pass.
+///
+/// Rows where either side is NULL are skipped. The result is NULL for a nullable
+/// output without any rows, and NaN if the correlation is otherwise undefined,
+/// the same as the `corr` aggregate function.
+pub struct TransformCorrelation {
+    x_offset: usize,
+    y_offset: usize,
+    output_type: DataType,
+    state: AggregateCorrelationState,
+}
+
+impl TransformCorrelation {
+    pub fn new(x_offset: usize, y_offset: usize, output_type: DataType) -> Self {
+        TransformCorrelation {
+            x_offset,
+            y_offset,
+            output_type,
+            state: AggregateCorrelationState::default(),
+        }
+    }
+}
+
+impl AccumulatingTransform for TransformCorrelation {
+    const NAME: &'static str = "TransformCorrelation";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let x = data.get_by_offset(self.x_offset);
+        let y = data.get_by_offset(self.y_offset);
+        for row in 0..data.num_rows() {
+            if let (Some(ScalarRef::Number(x)), Some(ScalarRef::Number(y))) =
+                (x.value.index(row), y.value.index(row))
+            {
+                self.state
+                    .add(x.to_f64().into_inner(), y.to_f64().into_inner());
+            }
+        }
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output {
+            return Ok(vec![]);
+        }
+        let value = if self.state.count == 0 && self.output_type.is_nullable() {
+            Scalar::Null
+        } else {
+            let corr = self.state.result().unwrap_or(f64::NAN);
+            Scalar::Number(NumberScalar::Float64(F64::from(corr)))
+        };
+        let mut builder = ColumnBuilder::with_capacity(&self.output_type, 1);
+        builder.push(value.as_ref());
+        Ok(vec![DataBlock::new_from_columns(vec![builder.build()])])
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_transforms::processors::Transform;
+
+use crate::sessions::QueryContext;
+
+/// Evaluate the `RETURNING` expressions on each inserted block and collect the results
+/// into the query context, the block itself is forwarded to the table writer unchanged.
+pub struct TransformEmit {
+    ctx: Arc<QueryContext>,
+    func_ctx: FunctionContext,
+    exprs: Vec<Expr>,
+}
+
+impl TransformEmit {
+    pub fn try_new(ctx: Arc<QueryContext>, returning_exprs: &[RemoteExpr]) -> Result<Self> {
+        let func_ctx = ctx.get_function_context()?;
+        let exprs = returning_exprs
+            .iter()
+            .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+            .collect();
+        Ok(Self {
+            ctx,
+            func_ctx,
+            exprs,
+        })
+    }
+}
+
+impl Transform for TransformEmit {
+    const NAME: &'static str = "TransformEmit";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let evaluator = Evaluator::new(&data, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let mut columns = Vec::with_capacity(self.exprs.len());
+        for expr in &self.exprs {
+            let value = evaluator.run(expr)?;
+            columns.push(BlockEntry::new(expr.data_type().clone(), value));
+        }
+        self.ctx
+            .append_returning_block(DataBlock::new(columns, data.num_rows()));
+        Ok(data)
+    }
+}
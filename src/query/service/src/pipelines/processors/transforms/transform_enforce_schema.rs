@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_sql::executor::physical_plans::CastMode;
+
+/// Cast the columns of each block to the types of `target_schema`. With `CastMode::Permissive`,
+/// a value that can't be cast becomes NULL, which is still an error for a NOT NULL column.
+pub struct TransformEnforceSchema {
+    func_ctx: FunctionContext,
+    input_schema: DataSchemaRef,
+    target_schema: DataSchemaRef,
+    // The cast of each column, `None` if the column already has the target type.
+    exprs: Vec<Option<Expr>>,
+}
+
+impl TransformEnforceSchema {
+    pub fn try_new(
+        input_schema: DataSchemaRef,
+        target_schema: DataSchemaRef,
+        cast_mode: CastMode,
+        func_ctx: FunctionContext,
+    ) -> Result<Self> {
+        if input_schema.num_fields() != target_schema.num_fields() {
+            return Err(ErrorCode::Internal(format!(
+                "EnforceSchema expects {} input columns, but got {}",
+                target_schema.num_fields(),
+                input_schema.num_fields()
+            )));
+        }
+
+        let exprs = input_schema
+            .fields()
+            .iter()
+            .zip(target_schema.fields().iter())
+            .enumerate()
+            .map(|(index, (from, to))| {
+                if from.data_type() == to.data_type() {
+                    return Ok(None);
+                }
+                let expr = Expr::ColumnRef {
+                    span: None,
+                    id: index,
+                    data_type: from.data_type().clone(),
+                    display_name: from.name().clone(),
+                };
+                let expr = match cast_mode {
+                    CastMode::Strict => {
+                        check_cast(None, false, expr, to.data_type(), &BUILTIN_FUNCTIONS)?
+                    }
+                    CastMode::Permissive => {
+                        let expr =
+                            check_cast(None, true, expr, to.data_type(), &BUILTIN_FUNCTIONS)?;
+                        check_cast(None, false, expr, to.data_type(), &BUILTIN_FUNCTIONS)?
+                    }
+                };
+                Ok(Some(expr))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            func_ctx,
+            input_schema,
+            target_schema,
+            exprs,
+        })
+    }
+}
+
+impl Transform for TransformEnforceSchema {
+    const NAME: &'static str = "EnforceSchemaTransform";
+
+    fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
+        let evaluator = Evaluator::new(&data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let mut columns = Vec::with_capacity(self.exprs.len());
+        for (i, (field, expr)) in self
+            .target_schema
+            .fields()
+            .iter()
+            .zip(self.exprs.iter())
+            .enumerate()
+        {
+            let column = match expr {
+                None => data_block.get_by_offset(i).clone(),
+                Some(expr) => {
+                    let value = evaluator.run(expr).map_err(|err| {
+                        err.add_message(format!(
+                            "fail to cast column {} ({}) to column {} ({})",
+                            self.input_schema.field(i).name(),
+                            self.input_schema.field(i).data_type(),
+                            field.name(),
+                            field.data_type(),
+                        ))
+                    })?;
+                    BlockEntry::new(field.data_type().clone(), value)
+                }
+            };
+            columns.push(column);
+        }
+        Ok(DataBlock::new(columns, data_block.num_rows()))
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use arrow_flight::decode::FlightRecordBatchStream;
+use arrow_flight::Ticket;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::flight_sql_client::AuthCredentials;
+use databend_common_expression::flight_sql_client::FlightSqlClient;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Value;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use futures_util::StreamExt;
+
+use crate::sessions::QueryContext;
+
+/// Send a query to a remote Flight SQL service, and convert the returned record batches
+/// into data blocks. The result of each ticket is read one by one.
+pub struct TransformFunctionImport {
+    endpoint: String,
+    query: String,
+    auth: Option<AuthCredentials>,
+    schema: DataSchemaRef,
+    projection: Vec<usize>,
+
+    client: Option<FlightSqlClient>,
+    tickets: VecDeque<Ticket>,
+    stream: Option<FlightRecordBatchStream>,
+}
+
+impl TransformFunctionImport {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        endpoint: String,
+        query: String,
+        auth: Option<AuthCredentials>,
+        schema: DataSchemaRef,
+        projection: Vec<usize>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx, output_port, TransformFunctionImport {
+            endpoint,
+            query,
+            auth,
+            schema,
+            projection,
+            client: None,
+            tickets: VecDeque::new(),
+            stream: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for TransformFunctionImport {
+    const NAME: &'static str = "FunctionImport";
+
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.client.is_none() {
+            let mut client = FlightSqlClient::connect(&self.endpoint, self.auth.as_ref()).await?;
+            self.tickets = client.execute(&self.query).await?.into();
+            self.client = Some(client);
+        }
+
+        loop {
+            if let Some(stream) = self.stream.as_mut() {
+                match stream.next().await {
+                    Some(batch) => {
+                        let batch = batch.map_err(|err| {
+                            ErrorCode::Internal(format!(
+                                "Read result from Flight SQL service {} failed: {err}",
+                                self.endpoint
+                            ))
+                        })?;
+                        let columns = self
+                            .projection
+                            .iter()
+                            .zip(self.schema.fields())
+                            .map(|(offset, field)| {
+                                let column = Column::from_arrow_rs(
+                                    batch.column(*offset).clone(),
+                                    field.data_type(),
+                                )?;
+                                Ok(BlockEntry::new(
+                                    field.data_type().clone(),
+                                    Value::Column(column),
+                                ))
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        return Ok(Some(DataBlock::new(columns, batch.num_rows())));
+                    }
+                    None => self.stream = None,
+                }
+            }
+
+            match self.tickets.pop_front() {
+                Some(ticket) => {
+                    let client = self.client.as_mut().unwrap();
+                    self.stream = Some(client.do_get(ticket).await?);
+                }
+                None => return Ok(None),
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::UInt32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_functions::scalars::compute_grouping;
+use databend_common_pipeline_transforms::processors::Transform;
+
+/// Compute a `GROUPING_ID` column from the virtual column `_grouping_id` at `grouping_id_offset`,
+/// the bits of `_grouping_id` at `grouping_columns` are collected into the new column.
+pub struct TransformGroupingId {
+    grouping_columns: Vec<usize>,
+    grouping_id_offset: usize,
+}
+
+impl TransformGroupingId {
+    pub fn new(grouping_columns: Vec<usize>, grouping_id_offset: usize) -> Self {
+        TransformGroupingId {
+            grouping_columns,
+            grouping_id_offset,
+        }
+    }
+}
+
+impl Transform for TransformGroupingId {
+    const NAME: &'static str = "TransformGroupingId";
+
+    fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
+        let entry = block.get_by_offset(self.grouping_id_offset);
+        let value = match &entry.value {
+            Value::Scalar(scalar) => {
+                let grouping_id = UInt32Type::try_downcast_scalar(&scalar.as_ref()).unwrap();
+                let grouping = compute_grouping(&self.grouping_columns, grouping_id);
+                Value::Scalar(Scalar::Number(NumberScalar::UInt32(grouping)))
+            }
+            Value::Column(column) => {
+                let grouping_ids = UInt32Type::try_downcast_column(column).unwrap();
+                let grouping = grouping_ids
+                    .iter()
+                    .map(|grouping_id| compute_grouping(&self.grouping_columns, *grouping_id))
+                    .collect::<Vec<_>>();
+                Value::Column(UInt32Type::from_data(grouping))
+            }
+        };
+
+        block.add_column(BlockEntry::new(
+            DataType::Number(NumberDataType::UInt32),
+            value,
+        ));
+        Ok(block)
+    }
+}
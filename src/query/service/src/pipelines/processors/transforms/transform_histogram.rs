@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+/// The number of sampled values kept for each bucket.
+const SAMPLES_PER_BUCKET: usize = 1024;
+
+/// Build an equi-depth histogram of the column at `offset` in a single pass.
+///
+/// The non-null values are sampled into a reservoir, the buckets are split from the
+/// sorted sample and the counts of the buckets are scaled to the number of input rows,
+/// so they are exact if the input fits in the reservoir.
+pub struct TransformHistogram {
+    offset: usize,
+    bound_type: DataType,
+    num_buckets: usize,
+    capacity: usize,
+    reservoir: Vec<Scalar>,
+    num_values: u64,
+    rng: SmallRng,
+}
+
+impl TransformHistogram {
+    pub fn new(offset: usize, bound_type: DataType, num_buckets: usize) -> Self {
+        let capacity = num_buckets.saturating_mul(SAMPLES_PER_BUCKET);
+        TransformHistogram {
+            offset,
+            bound_type,
+            num_buckets,
+            capacity,
+            reservoir: Vec::new(),
+            num_values: 0,
+            rng: SmallRng::from_entropy(),
+        }
+    }
+
+    fn sample(&mut self, value: ScalarRef) {
+        self.num_values += 1;
+        if self.reservoir.len() < self.capacity {
+            self.reservoir.push(value.to_owned());
+        } else {
+            let index = self.rng.gen_range(0..self.num_values);
+            if index < self.capacity as u64 {
+                self.reservoir[index as usize] = value.to_owned();
+            }
+        }
+    }
+
+    fn build_histogram(&mut self) -> DataBlock {
+        let mut sample = std::mem::take(&mut self.reservoir);
+        sample.sort();
+
+        let num_samples = sample.len();
+        let num_buckets = self.num_buckets.min(num_samples);
+        let mut lower = ColumnBuilder::with_capacity(&self.bound_type, num_buckets);
+        let mut upper = ColumnBuilder::with_capacity(&self.bound_type, num_buckets);
+        let mut counts = Vec::with_capacity(num_buckets);
+        let mut ndvs = Vec::with_capacity(num_buckets);
+
+        // The number of input values up to the end of the bucket, the last one is `num_values`.
+        let scaled = |end: usize| {
+            (self.num_values as u128 * end as u128 / num_samples.max(1) as u128) as u64
+        };
+        for bucket in 0..num_buckets {
+            let start = bucket * num_samples / num_buckets;
+            let end = (bucket + 1) * num_samples / num_buckets;
+            let values = &sample[start..end];
+            lower.push(values[0].as_ref());
+            upper.push(values[values.len() - 1].as_ref());
+            counts.push(scaled(end) - scaled(start));
+            ndvs.push(1 + values.windows(2).filter(|w| w[0] != w[1]).count() as u64);
+        }
+
+        DataBlock::new_from_columns(vec![
+            lower.build(),
+            upper.build(),
+            UInt64Type::from_data(counts),
+            UInt64Type::from_data(ndvs),
+        ])
+    }
+}
+
+impl AccumulatingTransform for TransformHistogram {
+    const NAME: &'static str = "TransformHistogram";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let entry = data.get_by_offset(self.offset);
+        for row in 0..data.num_rows() {
+            match entry.value.index(row) {
+                Some(ScalarRef::Null) | None => {}
+                Some(value) => self.sample(value),
+            }
+        }
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output || self.num_values == 0 {
+            return Ok(vec![]);
+        }
+        Ok(vec![self.build_histogram()])
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::types::VariantType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Value;
+use databend_common_functions::srfs::json_each_entries;
+use databend_common_pipeline_transforms::processors::Transform;
+
+/// Expand each variant value of the source column into `(key, value)` rows. The input
+/// columns are repeated for each row, and the key and value columns are appended.
+pub struct TransformJsonEach {
+    source_offset: usize,
+}
+
+impl TransformJsonEach {
+    pub fn new(source_offset: usize) -> Self {
+        TransformJsonEach { source_offset }
+    }
+}
+
+impl Transform for TransformJsonEach {
+    const NAME: &'static str = "TransformJsonEach";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let entry = block.get_by_offset(self.source_offset);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+        let validity = column.validity().1.cloned();
+        let Column::Variant(values) = column.remove_nullable() else {
+            return Err(ErrorCode::Internal(format!(
+                "JsonEach expects a variant column, but got {}",
+                entry.data_type
+            )));
+        };
+
+        let mut indices = Vec::with_capacity(num_rows);
+        let mut key_builder = StringColumnBuilder::with_capacity(num_rows);
+        let mut value_builder = VariantType::create_builder(num_rows, &[]);
+        for (row, value) in values.iter().enumerate() {
+            if matches!(&validity, Some(validity) if !validity.get_bit(row)) {
+                continue;
+            }
+            for (key, value) in json_each_entries(value) {
+                indices.push(row as u32);
+                key_builder.put_and_commit(&key);
+                value_builder.put_slice(value.as_ref());
+                value_builder.commit_row();
+            }
+        }
+
+        let mut block = block.take(&indices)?;
+        block.add_column(BlockEntry::new(
+            DataType::Nullable(Box::new(DataType::String)),
+            Value::Column(Column::String(key_builder.build()).wrap_nullable(None)),
+        ));
+        block.add_column(BlockEntry::new(
+            DataType::Nullable(Box::new(DataType::Variant)),
+            Value::Column(Column::Variant(value_builder.build()).wrap_nullable(None)),
+        ));
+        Ok(block)
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::MutableBitmap;
+use databend_common_expression::types::NullableColumn;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::types::VariantType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Value;
+use databend_common_pipeline_transforms::processors::Transform;
+use jsonb::keypath::parse_key_paths;
+use jsonb::keypath::KeyPath;
+
+/// An element of a key path.
+#[derive(Clone, Debug)]
+pub enum JsonPathElement {
+    Index(i32),
+    Name(String),
+}
+
+impl JsonPathElement {
+    /// Parse the elements of a key path, such as `["\"a\"", "0"]` for `{"a",0}`.
+    pub fn parse_path(elements: &[String]) -> Result<Vec<JsonPathElement>> {
+        let key_paths = format!("{{{}}}", elements.join(","));
+        let key_paths = parse_key_paths(key_paths.as_bytes())
+            .map_err(|e| ErrorCode::BadArguments(format!("Invalid key path {key_paths}: {e}")))?;
+        Ok(key_paths
+            .paths
+            .iter()
+            .map(|path| match path {
+                KeyPath::Index(index) => JsonPathElement::Index(*index),
+                KeyPath::QuotedName(name) | KeyPath::Name(name) => {
+                    JsonPathElement::Name(name.to_string())
+                }
+            })
+            .collect())
+    }
+}
+
+/// Extract the values of several key paths from a variant column, each value
+/// is decoded once for all the key paths. The extracted columns are appended
+/// to the block in the order of `paths`.
+pub struct TransformJsonExtract {
+    source_offset: usize,
+    paths: Vec<Vec<JsonPathElement>>,
+}
+
+impl TransformJsonExtract {
+    pub fn new(source_offset: usize, paths: Vec<Vec<JsonPathElement>>) -> Self {
+        TransformJsonExtract {
+            source_offset,
+            paths,
+        }
+    }
+}
+
+impl Transform for TransformJsonExtract {
+    const NAME: &'static str = "TransformJsonExtract";
+
+    fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let entry = block.get_by_offset(self.source_offset);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+        let validity = column.validity().1.cloned();
+        let Column::Variant(values) = column.remove_nullable() else {
+            return Err(ErrorCode::Internal(format!(
+                "JsonExtract expects a variant column, but got {}",
+                entry.data_type
+            )));
+        };
+
+        let mut builders = self
+            .paths
+            .iter()
+            .map(|_| {
+                (
+                    VariantType::create_builder(num_rows, &[]),
+                    MutableBitmap::with_capacity(num_rows),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        for (row, value) in values.iter().enumerate() {
+            let root = match &validity {
+                Some(validity) if !validity.get_bit(row) => None,
+                // Decode the value once for all the key paths.
+                _ => jsonb::from_slice(value).ok(),
+            };
+            for (path, (builder, validity)) in self.paths.iter().zip(builders.iter_mut()) {
+                match root.as_ref().and_then(|root| get_by_path(root, path)) {
+                    Some(value) => {
+                        value.write_to_vec(&mut builder.data);
+                        validity.push(true);
+                    }
+                    None => validity.push(false),
+                }
+                builder.commit_row();
+            }
+        }
+
+        for (builder, validity) in builders {
+            let column =
+                NullableColumn::new_column(Column::Variant(builder.build()), validity.into());
+            block.add_column(BlockEntry::new(
+                DataType::Nullable(Box::new(DataType::Variant)),
+                Value::Column(column),
+            ));
+        }
+        Ok(block)
+    }
+}
+
+/// The same as `get_by_keypath`: a negative index of an array counts from the end.
+fn get_by_path<'a>(
+    root: &'a jsonb::Value<'a>,
+    path: &[JsonPathElement],
+) -> Option<&'a jsonb::Value<'a>> {
+    let mut value = root;
+    for element in path {
+        value = match element {
+            JsonPathElement::Index(index) => {
+                let array = value.as_array()?;
+                let index = if *index < 0 {
+                    array.len().checked_sub(index.unsigned_abs() as usize)?
+                } else {
+                    *index as usize
+                };
+                array.get(index)?
+            }
+            JsonPathElement::Name(name) => value.as_object()?.get(name)?,
+        };
+    }
+    Some(value)
+}
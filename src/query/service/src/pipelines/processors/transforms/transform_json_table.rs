@@ -0,0 +1,156 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_transforms::processors::Transform;
+use jsonb::jsonpath::parse_json_path;
+use jsonb::RawJsonb;
+
+/// Expand each variant value of the source column into the rows of the values selected by
+/// `row_path`. The input columns are repeated for each row, and the value at the path of
+/// each column is appended, cast to the type of the column.
+pub struct TransformJsonTable {
+    func_ctx: FunctionContext,
+    source_offset: usize,
+    row_path: String,
+    paths: Vec<String>,
+    // The cast of each selected value to the type of the column.
+    casts: Vec<(Expr, DataType)>,
+}
+
+impl TransformJsonTable {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        source_offset: usize,
+        row_path: String,
+        columns: Vec<(String, DataType)>,
+    ) -> Result<Self> {
+        let variant_type = DataType::Variant.wrap_nullable();
+        let mut paths = Vec::with_capacity(columns.len());
+        let mut casts = Vec::with_capacity(columns.len());
+        for (id, (path, data_type)) in columns.into_iter().enumerate() {
+            let expr = Expr::ColumnRef {
+                span: None,
+                id,
+                data_type: variant_type.clone(),
+                display_name: path.clone(),
+            };
+            let expr = check_cast(None, true, expr, &data_type, &BUILTIN_FUNCTIONS)?;
+            paths.push(path);
+            casts.push((expr, data_type));
+        }
+        Ok(TransformJsonTable {
+            func_ctx,
+            source_offset,
+            row_path,
+            paths,
+            casts,
+        })
+    }
+}
+
+impl Transform for TransformJsonTable {
+    const NAME: &'static str = "TransformJsonTable";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let entry = block.get_by_offset(self.source_offset);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+        let validity = column.validity().1.cloned();
+        let Column::Variant(values) = column.remove_nullable() else {
+            return Err(ErrorCode::Internal(format!(
+                "JsonTable expects a variant column, but got {}",
+                entry.data_type
+            )));
+        };
+
+        let parse_path = |path: &str| {
+            parse_json_path(path.as_bytes()).map_err(|_| {
+                ErrorCode::BadArguments(format!("Invalid JSON path '{}' of JSON_TABLE", path))
+            })
+        };
+        let row_path = parse_path(&self.row_path)?;
+        let paths = self
+            .paths
+            .iter()
+            .map(|path| parse_path(path))
+            .collect::<Result<Vec<_>>>()?;
+
+        let variant_type = DataType::Variant.wrap_nullable();
+        let mut indices = Vec::with_capacity(num_rows);
+        let mut builders = paths
+            .iter()
+            .map(|_| ColumnBuilder::with_capacity(&variant_type, num_rows))
+            .collect::<Vec<_>>();
+        for (row, value) in values.iter().enumerate() {
+            if matches!(&validity, Some(validity) if !validity.get_bit(row)) {
+                continue;
+            }
+            let json_rows = RawJsonb::new(value)
+                .select_by_path(&row_path)
+                .map_err(|err| {
+                    ErrorCode::BadArguments(format!(
+                        "Select JSON path '{}' failed: {}",
+                        self.row_path, err
+                    ))
+                })?;
+            for json_row in json_rows {
+                indices.push(row as u32);
+                for ((path, json_path), builder) in
+                    self.paths.iter().zip(paths.iter()).zip(builders.iter_mut())
+                {
+                    let value = RawJsonb::new(json_row.as_ref())
+                        .select_first_by_path(json_path)
+                        .map_err(|err| {
+                            ErrorCode::BadArguments(format!(
+                                "Select JSON path '{}' failed: {}",
+                                path, err
+                            ))
+                        })?;
+                    match value {
+                        Some(value) => builder.push(ScalarRef::Variant(value.as_ref())),
+                        None => builder.push(ScalarRef::Null),
+                    }
+                }
+            }
+        }
+
+        let values = builders
+            .into_iter()
+            .map(|builder| BlockEntry::new(variant_type.clone(), Value::Column(builder.build())))
+            .collect();
+        let values = DataBlock::new(values, indices.len());
+        let evaluator = Evaluator::new(&values, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let mut block = block.take(&indices)?;
+        for (expr, data_type) in self.casts.iter() {
+            let value = evaluator.run(expr)?;
+            block.add_column(BlockEntry::new(data_type.clone(), value));
+        }
+        Ok(block)
+    }
+}
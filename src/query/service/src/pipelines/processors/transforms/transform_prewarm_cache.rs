@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_storages_result_cache::ResultCacheMetaManager;
+use databend_common_users::UserApiProvider;
+use futures_util::TryStreamExt;
+use log::info;
+use log::warn;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sql::Planner;
+
+/// Submit the queries of a `PrewarmCache` plan in a background task,
+/// so that their results are written into the query result cache.
+/// The source itself produces no data and finishes immediately.
+pub struct TransformPrewarmCache {
+    ctx: Arc<QueryContext>,
+    queries: Vec<String>,
+    max_bytes: u64,
+}
+
+impl TransformPrewarmCache {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        queries: Vec<String>,
+        max_bytes: u64,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output_port, TransformPrewarmCache {
+            ctx,
+            queries,
+            max_bytes,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for TransformPrewarmCache {
+    const NAME: &'static str = "PrewarmCache";
+
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let queries = std::mem::take(&mut self.queries);
+        if queries.is_empty() {
+            return Ok(None);
+        }
+
+        let ctx = self.ctx.clone();
+        let max_bytes = self.max_bytes;
+        GlobalIORuntime::instance().spawn(async move {
+            let mut cached_bytes = 0;
+            for query in queries {
+                if cached_bytes >= max_bytes {
+                    info!(
+                        "Prewarm cache stopped, {} bytes cached exceed the budget {}",
+                        cached_bytes, max_bytes
+                    );
+                    break;
+                }
+
+                match prewarm_query(&ctx, &query, max_bytes - cached_bytes).await {
+                    Ok(bytes) => cached_bytes += bytes,
+                    Err(cause) => warn!("Prewarm cache for query {} failed: {:?}", query, cause),
+                }
+            }
+        });
+
+        Ok(None)
+    }
+}
+
+/// Execute `query` in a new query context with the result cache enabled,
+/// returns the bytes of the result accepted by the result cache.
+async fn prewarm_query(ctx: &Arc<QueryContext>, query: &str, max_bytes: u64) -> Result<u64> {
+    let ctx = ctx.get_current_session().create_query_context().await?;
+
+    // Query level settings, the session settings are not changed.
+    let settings = HashMap::from([
+        ("enable_query_result_cache".to_string(), "1".to_string()),
+        (
+            "query_result_cache_min_execute_secs".to_string(),
+            "0".to_string(),
+        ),
+        (
+            "query_result_cache_max_bytes".to_string(),
+            max_bytes.to_string(),
+        ),
+        // The prewarm queries don't prewarm other queries.
+        (
+            "query_result_cache_prewarm_max_bytes".to_string(),
+            "0".to_string(),
+        ),
+    ]);
+    ctx.get_shared_settings()
+        .set_batch_settings(&settings, true)?;
+
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(query).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx.clone()).await?;
+    stream.try_collect::<Vec<_>>().await?;
+
+    // The result is not cached if it exceeds `max_bytes`, or it is read from the cache.
+    let query_id = ctx.get_id();
+    let Some(meta_key) = ctx.get_result_cache_key(&query_id) else {
+        return Ok(0);
+    };
+    let meta_mgr =
+        ResultCacheMetaManager::create(UserApiProvider::instance().get_meta_store_client(), 0);
+    match meta_mgr.get(meta_key).await? {
+        Some(value) if value.query_id == query_id => Ok(value.result_size as u64),
+        _ => Ok(0),
+    }
+}
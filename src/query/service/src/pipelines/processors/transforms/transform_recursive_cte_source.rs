@@ -240,12 +240,27 @@ async fn create_memory_table_for_cte_scan(
         PhysicalPlan::AggregateFinal(plan) => {
             create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
         }
+        PhysicalPlan::SortMergeAggregate(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
         PhysicalPlan::Window(plan) => {
             create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
         }
         PhysicalPlan::WindowPartition(plan) => {
             create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
         }
+        PhysicalPlan::TumblingWindow(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::SessionWindow(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::Watermark(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+            if let Some(late_output) = &plan.late_output {
+                create_memory_table_for_cte_scan(ctx, late_output.as_ref()).await?;
+            }
+        }
         PhysicalPlan::Sort(plan) => {
             create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
         }
@@ -263,6 +278,14 @@ async fn create_memory_table_for_cte_scan(
             create_memory_table_for_cte_scan(ctx, plan.left.as_ref()).await?;
             create_memory_table_for_cte_scan(ctx, plan.right.as_ref()).await?;
         }
+        PhysicalPlan::SortMergeJoin(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.left.as_ref()).await?;
+            create_memory_table_for_cte_scan(ctx, plan.right.as_ref()).await?;
+        }
+        PhysicalPlan::SemiHashJoin(plan) | PhysicalPlan::AntiHashJoin(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.build.as_ref()).await?;
+            create_memory_table_for_cte_scan(ctx, plan.probe.as_ref()).await?;
+        }
         PhysicalPlan::Exchange(plan) => {
             create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
         }
@@ -318,10 +341,127 @@ async fn create_memory_table_for_cte_scan(
         PhysicalPlan::AsyncFunction(plan) => {
             create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
         }
+        PhysicalPlan::SequenceNext(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::AbortIfEmpty(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::MaskApply(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::RowAccessPolicy(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::ConvertTimezone(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::MaterializeAgg(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::BloomBuild(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::WriteAheadLog(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::ConditionalLimit(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::EnforceSchema(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::SkewDetection(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::Correlation(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::TDigestAgg(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::CteMaterialization(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.body.as_ref()).await?;
+        }
+        PhysicalPlan::FlatMap(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::FuzzyMatch(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::GeoScan(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::BloomLookup(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.inner_scan.as_ref()).await?;
+        }
+        PhysicalPlan::BitmapIndexScan(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::BlockSample(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::MvRefreshPartial(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.delta_plan.as_ref()).await?;
+        }
+        PhysicalPlan::SchemaEvolve(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::SortedMerge(plan) => {
+            for input in plan.inputs.iter() {
+                create_memory_table_for_cte_scan(ctx, input).await?;
+            }
+        }
+        PhysicalPlan::Scatter(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::JsonEach(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::JsonTable(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::Classify(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::StreamOutput(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::Replicate(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::GroupingId(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::Emit(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::Histogram(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::Qualify(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::Transpose(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::JsonExtract(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::ClusterSort(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::SpillSort(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
+        PhysicalPlan::Zip(plan) => {
+            create_memory_table_for_cte_scan(ctx, plan.input.as_ref()).await?;
+        }
         PhysicalPlan::TableScan(_)
         | PhysicalPlan::ConstantTableScan(_)
         | PhysicalPlan::ExpressionScan(_)
         | PhysicalPlan::CacheScan(_)
+        | PhysicalPlan::ResultCacheScan(_)
         | PhysicalPlan::DistributedInsertSelect(_)
         | PhysicalPlan::ExchangeSource(_)
         | PhysicalPlan::ExchangeSink(_)
@@ -348,7 +488,11 @@ async fn create_memory_table_for_cte_scan(
         | PhysicalPlan::ChunkFillAndReorder(_)
         | PhysicalPlan::ChunkAppendData(_)
         | PhysicalPlan::ChunkMerge(_)
-        | PhysicalPlan::ChunkCommitInsert(_) => {}
+        | PhysicalPlan::ChunkCommitInsert(_)
+        | PhysicalPlan::PrewarmCache(_)
+        | PhysicalPlan::AsyncAggregate(_)
+        | PhysicalPlan::Compact(_)
+        | PhysicalPlan::FunctionImport(_) => {}
     }
     Ok(())
 }
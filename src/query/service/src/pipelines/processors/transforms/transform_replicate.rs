@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_sinks::Sink;
+use parking_lot::Mutex;
+
+use crate::pipelines::executor::WatchNotify;
+
+/// The result blocks of a `PhysicalPlan::Replicate`, shared by all the `CacheScan` sources
+/// reading the same cache key in a query.
+pub struct ReplicateState {
+    blocks: Mutex<Vec<DataBlock>>,
+    sinker_count: Mutex<usize>,
+    finished: Mutex<bool>,
+    finished_notify: Arc<WatchNotify>,
+}
+
+impl ReplicateState {
+    pub fn create() -> Arc<ReplicateState> {
+        Arc::new(ReplicateState {
+            blocks: Mutex::new(Vec::new()),
+            sinker_count: Mutex::new(0),
+            finished: Mutex::new(false),
+            finished_notify: Arc::new(WatchNotify::new()),
+        })
+    }
+
+    pub(crate) fn attach(&self) {
+        *self.sinker_count.lock() += 1;
+    }
+
+    pub(crate) fn detach(&self) {
+        let mut sinker_count = self.sinker_count.lock();
+        *sinker_count -= 1;
+        if *sinker_count == 0 {
+            *self.finished.lock() = true;
+            self.finished_notify.notify_waiters();
+        }
+    }
+
+    pub(crate) fn push(&self, data_block: DataBlock) {
+        if !data_block.is_empty() {
+            self.blocks.lock().push(data_block);
+        }
+    }
+
+    /// Wait until all the blocks of the input are stored.
+    pub async fn wait_finish(&self) -> Result<()> {
+        let notified = match *self.finished.lock() {
+            true => None,
+            false => Some(self.finished_notify.notified()),
+        };
+
+        if let Some(notified) = notified {
+            notified.await;
+        }
+        Ok(())
+    }
+
+    pub fn is_finished(&self) -> bool {
+        *self.finished.lock()
+    }
+
+    pub fn blocks(&self) -> Vec<DataBlock> {
+        self.blocks.lock().clone()
+    }
+}
+
+/// Store the blocks of the input of `PhysicalPlan::Replicate` into the `ReplicateState`.
+pub struct TransformReplicateSink {
+    state: Arc<ReplicateState>,
+}
+
+impl TransformReplicateSink {
+    pub fn create(state: Arc<ReplicateState>) -> Self {
+        state.attach();
+        TransformReplicateSink { state }
+    }
+}
+
+impl Sink for TransformReplicateSink {
+    const NAME: &'static str = "TransformReplicateSink";
+
+    fn on_finish(&mut self) -> Result<()> {
+        self.state.detach();
+        Ok(())
+    }
+
+    fn consume(&mut self, data_block: DataBlock) -> Result<()> {
+        self.state.push(data_block);
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_storage::DataOperator;
+use databend_common_storages_result_cache::ResultCacheReader;
+
+/// Output the blocks of a result cache file, the file is read by the first `generate`.
+pub struct TransformResultCacheScan {
+    location: String,
+    num_rows: usize,
+    blocks: Option<VecDeque<DataBlock>>,
+}
+
+impl TransformResultCacheScan {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output_port: Arc<OutputPort>,
+        location: String,
+        num_rows: usize,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx, output_port, TransformResultCacheScan {
+            location,
+            num_rows,
+            blocks: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for TransformResultCacheScan {
+    const NAME: &'static str = "ResultCacheScan";
+
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.blocks.is_none() {
+            // No file is written for an empty result.
+            let blocks = if self.num_rows == 0 {
+                vec![]
+            } else {
+                let operator = DataOperator::instance().operator();
+                ResultCacheReader::read_blocks(&operator, &self.location).await?
+            };
+            self.blocks = Some(blocks.into());
+        }
+        Ok(self.blocks.as_mut().and_then(|blocks| blocks.pop_front()))
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_core::processors::Exchange;
+
+/// Split a block into one block per target, each row goes to the first
+/// target whose condition is true. Rows matching no condition are dropped.
+pub struct ScatterExchange {
+    func_ctx: FunctionContext,
+    conditions: Vec<Expr>,
+    targets: Vec<usize>,
+}
+
+impl ScatterExchange {
+    pub fn create(
+        func_ctx: FunctionContext,
+        conditions: Vec<Expr>,
+        targets: Vec<usize>,
+    ) -> Arc<ScatterExchange> {
+        Arc::new(ScatterExchange {
+            func_ctx,
+            conditions,
+            targets,
+        })
+    }
+}
+
+impl Exchange for ScatterExchange {
+    const NAME: &'static str = "Scatter";
+    const SKIP_EMPTY_DATA_BLOCK: bool = true;
+
+    fn partition(&self, data_block: DataBlock, n: usize) -> Result<Vec<DataBlock>> {
+        let num_rows = data_block.num_rows();
+        let evaluator = Evaluator::new(&data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+
+        // The unmatched rows are scattered to the extra last block.
+        let mut indices = vec![n as u32; num_rows];
+        let mut unmatched = num_rows;
+        for (condition, target) in self.conditions.iter().zip(self.targets.iter()) {
+            if unmatched == 0 {
+                break;
+            }
+
+            let value = evaluator
+                .run(condition)
+                .map_err(|e| e.add_message("eval scatter condition failed:"))?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            match value {
+                Value::Scalar(true) => {
+                    for index in indices.iter_mut().filter(|index| **index == n as u32) {
+                        *index = *target as u32;
+                    }
+                    unmatched = 0;
+                }
+                Value::Scalar(false) => {}
+                Value::Column(bitmap) => {
+                    for (index, matched) in indices.iter_mut().zip(bitmap.iter()) {
+                        if matched && *index == n as u32 {
+                            *index = *target as u32;
+                            unmatched -= 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut blocks = data_block.scatter(&indices, n + 1)?;
+        blocks.truncate(n);
+        Ok(blocks)
+    }
+}
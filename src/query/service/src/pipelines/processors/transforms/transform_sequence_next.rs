@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio::sync::Mutex;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Value;
+use databend_common_meta_app::schema::GetSequenceNextValueReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+
+use crate::sessions::QueryContext;
+
+/// The values of a sequence fetched from the meta service and not yet used, shared by the
+/// parallel `TransformSequenceNext`s of one `SequenceNext` plan.
+pub struct SequenceCounter {
+    ctx: Arc<QueryContext>,
+    sequence_name: String,
+    batch_size: u64,
+    cached: Mutex<Range<u64>>,
+}
+
+impl SequenceCounter {
+    pub fn new(ctx: Arc<QueryContext>, sequence_name: String, batch_size: usize) -> Arc<Self> {
+        Arc::new(SequenceCounter {
+            ctx,
+            sequence_name,
+            batch_size: batch_size as u64,
+            cached: Mutex::new(0..0),
+        })
+    }
+
+    /// Take `count` unique values of the sequence, the cached values are used first, and the
+    /// rest are fetched in a batch of at least `batch_size`.
+    pub async fn next_values(&self, count: u64) -> Result<Vec<u64>> {
+        let mut values = Vec::with_capacity(count as usize);
+        // The lock is held while fetching, so the parallel transforms do not fetch at the
+        // same time.
+        let mut cached = self.cached.lock().await;
+        values.extend(cached.by_ref().take(count as usize));
+
+        let missing = count - values.len() as u64;
+        if missing > 0 {
+            let fetch = missing.max(self.batch_size);
+            let req = GetSequenceNextValueReq {
+                ident: SequenceIdent::new(self.ctx.get_tenant(), &self.sequence_name),
+                count: fetch,
+            };
+            let catalog = self.ctx.get_default_catalog()?;
+            let resp = catalog.get_sequence_next_value(req).await?;
+            values.extend(resp.start..resp.start + missing);
+            *cached = resp.start + missing..resp.start + fetch;
+        }
+        Ok(values)
+    }
+}
+
+/// Append a column of the next values of the sequence to each block.
+pub struct TransformSequenceNext {
+    counter: Arc<SequenceCounter>,
+}
+
+impl TransformSequenceNext {
+    pub fn new(counter: Arc<SequenceCounter>) -> Self {
+        TransformSequenceNext { counter }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformSequenceNext {
+    const NAME: &'static str = "SequenceNext";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        let count = data_block.num_rows() as u64;
+        let values = if count == 0 {
+            vec![]
+        } else {
+            self.counter.next_values(count).await?
+        };
+        data_block.add_column(BlockEntry {
+            data_type: DataType::Number(NumberDataType::UInt64),
+            value: Value::Column(UInt64Type::from_data(values)),
+        });
+        Ok(data_block)
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_transforms::processors::Transform;
+use log::warn;
+
+/// The row counts of the hash partitions, shared by the parallel `TransformSkewDetection`s
+/// of one `SkewDetection` plan. The last finished transform checks the skew.
+pub struct SkewDetectionState {
+    counts: Vec<AtomicU64>,
+    skew_threshold: f64,
+    running: AtomicUsize,
+}
+
+impl SkewDetectionState {
+    pub fn new(num_partitions: usize, skew_threshold: f64, num_transforms: usize) -> Arc<Self> {
+        Arc::new(SkewDetectionState {
+            counts: (0..num_partitions.max(1))
+                .map(|_| AtomicU64::new(0))
+                .collect(),
+            skew_threshold,
+            running: AtomicUsize::new(num_transforms),
+        })
+    }
+
+    /// Returns the most loaded partition, its rows and the average rows of the partitions
+    /// if the partition has more than `skew_threshold` times the average rows.
+    pub fn skewed_partition(&self) -> Option<(usize, u64, f64)> {
+        let counts = self
+            .counts
+            .iter()
+            .map(|count| count.load(Ordering::Relaxed))
+            .collect::<Vec<_>>();
+        let total = counts.iter().sum::<u64>();
+        let avg = total as f64 / counts.len() as f64;
+        let (partition, rows) = counts
+            .into_iter()
+            .enumerate()
+            .max_by_key(|(_, rows)| *rows)?;
+        (total > 0 && rows as f64 > self.skew_threshold * avg).then_some((partition, rows, avg))
+    }
+}
+
+/// Count the rows of each hash partition of the partition key, the block is passed
+/// through unchanged.
+pub struct TransformSkewDetection {
+    plan_id: u32,
+    func_ctx: FunctionContext,
+    partition_key: Vec<Expr>,
+    state: Arc<SkewDetectionState>,
+}
+
+impl TransformSkewDetection {
+    pub fn new(
+        plan_id: u32,
+        func_ctx: FunctionContext,
+        partition_key: &[RemoteExpr],
+        state: Arc<SkewDetectionState>,
+    ) -> Self {
+        let partition_key = partition_key
+            .iter()
+            .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+            .collect();
+        TransformSkewDetection {
+            plan_id,
+            func_ctx,
+            partition_key,
+            state,
+        }
+    }
+}
+
+impl Transform for TransformSkewDetection {
+    const NAME: &'static str = "TransformSkewDetection";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let evaluator = Evaluator::new(&data, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let keys = self
+            .partition_key
+            .iter()
+            .map(|expr| evaluator.run(expr))
+            .collect::<Result<Vec<_>>>()?;
+
+        let num_partitions = self.state.counts.len() as u64;
+        let mut counts = vec![0; self.state.counts.len()];
+        for row in 0..data.num_rows() {
+            let mut hasher = DefaultHasher::new();
+            for key in &keys {
+                key.index(row).hash(&mut hasher);
+            }
+            counts[(hasher.finish() % num_partitions) as usize] += 1;
+        }
+        for (count, rows) in self.state.counts.iter().zip(counts) {
+            count.fetch_add(rows, Ordering::Relaxed);
+        }
+        Ok(data)
+    }
+
+    fn on_finish(&mut self) -> Result<()> {
+        if self.state.running.fetch_sub(1, Ordering::AcqRel) != 1 {
+            return Ok(());
+        }
+        if let Some((partition, rows, avg)) = self.state.skewed_partition() {
+            warn!(
+                "Skewed hash exchange (plan id {}): partition {} has {} rows, {:.2} times the average {:.2} rows",
+                self.plan_id,
+                partition,
+                rows,
+                rows as f64 / avg,
+                avg
+            );
+        }
+        Ok(())
+    }
+}
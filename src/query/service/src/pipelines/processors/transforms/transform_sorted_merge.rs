@@ -0,0 +1,233 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::sync::Arc;
+
+use async_channel::Receiver;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::binary::BinaryColumn;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::RowConverter as CommonRowConverter;
+use databend_common_expression::SortColumnDescription;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_pipeline_transforms::processors::sort::RowConverter;
+use databend_common_pipeline_transforms::processors::sort::Rows;
+
+struct SortedStream {
+    block: DataBlock,
+    rows: BinaryColumn,
+    /// The next row to be merged.
+    offset: usize,
+}
+
+/// K-way merge the sorted streams of the input ports into one sorted stream.
+///
+/// The leading row of each stream is kept in a binary heap, the rows are taken
+/// from the stream of the smallest leading row, as long as they are not greater
+/// than the leading rows of the other streams.
+pub struct TransformSortedMerge {
+    inputs: Vec<Arc<InputPort>>,
+    output: Arc<OutputPort>,
+    sort_desc: Arc<Vec<SortColumnDescription>>,
+    converter: CommonRowConverter,
+    /// The number of columns of the output blocks, the columns after them are removed.
+    num_columns: usize,
+    block_size: usize,
+
+    streams: Vec<Option<SortedStream>>,
+    /// The leading rows of the streams and the indices of the streams.
+    heap: BinaryHeap<Reverse<(Vec<u8>, usize)>>,
+    output_data: Option<DataBlock>,
+}
+
+impl TransformSortedMerge {
+    pub fn try_create(
+        inputs: Vec<Arc<InputPort>>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        sort_desc: Arc<Vec<SortColumnDescription>>,
+        num_columns: usize,
+        block_size: usize,
+    ) -> Result<ProcessorPtr> {
+        let converter = CommonRowConverter::create(&sort_desc, schema)?;
+        let streams = inputs.iter().map(|_| None).collect();
+        Ok(ProcessorPtr::create(Box::new(TransformSortedMerge {
+            inputs,
+            output,
+            sort_desc,
+            converter,
+            num_columns,
+            block_size,
+            streams,
+            heap: BinaryHeap::new(),
+            output_data: None,
+        })))
+    }
+
+    fn add_stream(&mut self, index: usize, block: DataBlock) -> Result<()> {
+        let columns = self
+            .sort_desc
+            .iter()
+            .map(|desc| block.get_by_offset(desc.offset).clone())
+            .collect::<Vec<_>>();
+        let rows = self.converter.convert(&columns, block.num_rows())?;
+        self.heap.push(Reverse((rows.row(0).to_vec(), index)));
+        self.streams[index] = Some(SortedStream {
+            block,
+            rows,
+            offset: 0,
+        });
+        Ok(())
+    }
+}
+
+impl Processor for TransformSortedMerge {
+    fn name(&self) -> String {
+        "SortedMerge".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            for input in self.inputs.iter() {
+                input.finish();
+            }
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            for input in self.inputs.iter() {
+                input.set_not_need_data();
+            }
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_data.take() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        // The leading rows of all unfinished streams are needed before merging.
+        let mut need_data = false;
+        for index in 0..self.inputs.len() {
+            if self.streams[index].is_some() {
+                continue;
+            }
+            let input = self.inputs[index].clone();
+            if input.has_data() {
+                let block = input.pull_data().unwrap()?;
+                if !block.is_empty() {
+                    self.add_stream(index, block)?;
+                    continue;
+                }
+            }
+            if !input.is_finished() {
+                input.set_need_data();
+                need_data = true;
+            }
+        }
+
+        if need_data {
+            return Ok(Event::NeedData);
+        }
+
+        if self.heap.is_empty() {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        Ok(Event::Sync)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        let mut parts = Vec::new();
+        let mut num_rows = 0;
+        while num_rows < self.block_size {
+            let Some(Reverse((_, index))) = self.heap.pop() else {
+                break;
+            };
+            let stream = self.streams[index].as_mut().unwrap();
+            let start = stream.offset;
+            stream.offset += 1;
+            while stream.offset < stream.rows.len()
+                && num_rows + stream.offset - start < self.block_size
+                && self.heap.peek().is_none_or(|Reverse((row, other))| {
+                    (stream.rows.row(stream.offset), index) < (row.as_slice(), *other)
+                })
+            {
+                stream.offset += 1;
+            }
+
+            num_rows += stream.offset - start;
+            parts.push(stream.block.slice(start..stream.offset));
+
+            if stream.offset < stream.rows.len() {
+                let row = stream.rows.row(stream.offset).to_vec();
+                self.heap.push(Reverse((row, index)));
+            } else {
+                // The next block of the stream is needed to continue.
+                self.streams[index] = None;
+                break;
+            }
+        }
+
+        if !parts.is_empty() {
+            let mut block = DataBlock::concat(&parts)?;
+            if block.num_columns() > self.num_columns {
+                block = block.project(&(0..self.num_columns).collect());
+            }
+            self.output_data = Some(block);
+        }
+        Ok(())
+    }
+}
+
+/// Receive the blocks of a sorted stream which is built in another pipeline.
+pub struct SortedStreamSource {
+    receiver: Receiver<DataBlock>,
+}
+
+impl SortedStreamSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        receiver: Receiver<DataBlock>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx, output, SortedStreamSource { receiver })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for SortedStreamSource {
+    const NAME: &'static str = "SortedStreamSource";
+
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        Ok(self.receiver.recv().await.ok())
+    }
+}
@@ -0,0 +1,300 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::binary::BinaryColumn;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::RowConverter as CommonRowConverter;
+use databend_common_expression::SortColumnDescription;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_transforms::processors::sort::RowConverter;
+use databend_common_pipeline_transforms::processors::sort::Rows;
+use log::info;
+use log::warn;
+
+use crate::spillers::Location;
+use crate::spillers::Spiller;
+
+/// The block of a run being merged.
+struct RunBlock {
+    block: DataBlock,
+    rows: BinaryColumn,
+    /// The next row to be merged.
+    offset: usize,
+}
+
+/// External sort of the input stream.
+///
+/// Phase 1: the input blocks are buffered until they exceed `threshold_bytes`, then they are
+/// sorted and spilled as a run of blocks. If the input finishes before any run is spilled,
+/// the buffered blocks are sorted and output without spilling.
+///
+/// Phase 2: the runs are k-way merged, only the leading block of each run is restored, the
+/// next block of a run is restored once its leading block is merged.
+///
+/// Each spilled block is deleted once it is restored, the blocks not restored yet are deleted
+/// in the background if the processor is dropped on an error or the cancellation of the query.
+pub struct TransformSpillSort {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    spiller: Spiller,
+    sort_desc: Arc<Vec<SortColumnDescription>>,
+    converter: CommonRowConverter,
+    threshold_bytes: usize,
+    block_size: usize,
+
+    input_data: Vec<DataBlock>,
+    input_bytes: usize,
+    /// The locations of the blocks of each spilled run which are not restored yet.
+    runs: Vec<VecDeque<Location>>,
+
+    merging: bool,
+    streams: Vec<Option<RunBlock>>,
+    /// The leading rows of the restored blocks and the indices of the runs.
+    heap: BinaryHeap<Reverse<(Vec<u8>, usize)>>,
+    output_data: VecDeque<DataBlock>,
+}
+
+impl TransformSpillSort {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        sort_desc: Arc<Vec<SortColumnDescription>>,
+        spiller: Spiller,
+        threshold_bytes: usize,
+        block_size: usize,
+    ) -> Result<ProcessorPtr> {
+        let converter = CommonRowConverter::create(&sort_desc, schema)?;
+        Ok(ProcessorPtr::create(Box::new(TransformSpillSort {
+            input,
+            output,
+            spiller,
+            sort_desc,
+            converter,
+            threshold_bytes: threshold_bytes.max(1),
+            block_size: block_size.max(1),
+            input_data: vec![],
+            input_bytes: 0,
+            runs: vec![],
+            merging: false,
+            streams: vec![],
+            heap: BinaryHeap::new(),
+            output_data: VecDeque::new(),
+        })))
+    }
+
+    // Sort the buffered blocks, and split the result into blocks of `block_size` rows.
+    fn sort_input_data(&mut self) -> Result<Vec<DataBlock>> {
+        let block = DataBlock::concat(&std::mem::take(&mut self.input_data))?;
+        self.input_bytes = 0;
+        let sorted = DataBlock::sort(&block, &self.sort_desc, None)?;
+        Ok(sorted.split_by_rows_no_tail(self.block_size))
+    }
+
+    async fn spill_run(&mut self) -> Result<()> {
+        let bytes = self.input_bytes;
+        let mut run = VecDeque::new();
+        for block in self.sort_input_data()? {
+            run.push_back(self.spiller.spill(vec![block]).await?);
+        }
+        info!(
+            "Spilled sort run {} of {} blocks, {} bytes",
+            self.runs.len(),
+            run.len(),
+            bytes
+        );
+        self.runs.push(run);
+        Ok(())
+    }
+
+    fn need_restore(&self, index: usize) -> bool {
+        self.streams[index].is_none() && !self.runs[index].is_empty()
+    }
+
+    async fn restore(&mut self) -> Result<()> {
+        for index in 0..self.runs.len() {
+            if !self.need_restore(index) {
+                continue;
+            }
+            let location = self.runs[index].pop_front().unwrap();
+            let block = self.spiller.read_spilled_file(&location).await?;
+            if let Err(cause) = self.spiller.delete_spilled_file(&location).await {
+                warn!("Delete spilled file {:?} failed: {:?}", location, cause);
+            }
+            let columns = self
+                .sort_desc
+                .iter()
+                .map(|desc| block.get_by_offset(desc.offset).clone())
+                .collect::<Vec<_>>();
+            let rows = self.converter.convert(&columns, block.num_rows())?;
+            self.heap.push(Reverse((rows.row(0).to_vec(), index)));
+            self.streams[index] = Some(RunBlock {
+                block,
+                rows,
+                offset: 0,
+            });
+        }
+        Ok(())
+    }
+
+    // Merge the restored blocks until a block is output or the leading block of a run is
+    // merged, which needs to restore the next block of the run.
+    fn merge(&mut self) -> Result<()> {
+        let mut parts = Vec::new();
+        let mut num_rows = 0;
+        while num_rows < self.block_size {
+            let Some(Reverse((_, index))) = self.heap.pop() else {
+                break;
+            };
+            let stream = self.streams[index].as_mut().unwrap();
+            let start = stream.offset;
+            stream.offset += 1;
+            while stream.offset < stream.rows.len()
+                && num_rows + stream.offset - start < self.block_size
+                && self.heap.peek().is_none_or(|Reverse((row, other))| {
+                    (stream.rows.row(stream.offset), index) < (row.as_slice(), *other)
+                })
+            {
+                stream.offset += 1;
+            }
+
+            num_rows += stream.offset - start;
+            parts.push(stream.block.slice(start..stream.offset));
+
+            if stream.offset < stream.rows.len() {
+                let row = stream.rows.row(stream.offset).to_vec();
+                self.heap.push(Reverse((row, index)));
+            } else {
+                self.streams[index] = None;
+                if !self.runs[index].is_empty() {
+                    break;
+                }
+            }
+        }
+
+        if !parts.is_empty() {
+            self.output_data.push_back(DataBlock::concat(&parts)?);
+        }
+        Ok(())
+    }
+}
+
+impl Drop for TransformSpillSort {
+    fn drop(&mut self) {
+        let locations = self.runs.iter_mut().flat_map(std::mem::take).collect();
+        self.spiller.delete_spilled_files_in_background(locations);
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSpillSort {
+    fn name(&self) -> String {
+        "SpillSort".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_data.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.merging {
+            if (0..self.runs.len()).any(|index| self.need_restore(index)) {
+                return Ok(Event::Async);
+            }
+            if self.heap.is_empty() {
+                self.output.finish();
+                return Ok(Event::Finished);
+            }
+            return Ok(Event::Sync);
+        }
+
+        if self.input.has_data() {
+            let block = self.input.pull_data().unwrap()?;
+            if !block.is_empty() {
+                self.input_bytes += block.memory_size();
+                self.input_data.push(block);
+            }
+            if self.input_bytes >= self.threshold_bytes {
+                return Ok(Event::Async);
+            }
+        }
+
+        if !self.input.is_finished() {
+            self.input.set_need_data();
+            return Ok(Event::NeedData);
+        }
+
+        match (self.runs.is_empty(), self.input_data.is_empty()) {
+            (true, true) => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+            // All the rows fit in memory.
+            (true, false) => Ok(Event::Sync),
+            (false, _) => Ok(Event::Async),
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if self.merging {
+            return self.merge();
+        }
+        let sorted = self.sort_input_data()?;
+        self.output_data.extend(sorted);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if self.merging {
+            return self.restore().await;
+        }
+        if !self.input_data.is_empty() {
+            self.spill_run().await?;
+        }
+        if self.input.is_finished() {
+            self.merging = true;
+            self.streams = self.runs.iter().map(|_| None).collect();
+            self.restore().await?;
+        }
+        Ok(())
+    }
+}
@@ -23,6 +23,7 @@ use databend_common_expression::types::NumberColumn;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::BlockEntry;
 use databend_common_expression::Column;
@@ -30,6 +31,7 @@ use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Evaluator;
 use databend_common_expression::Expr;
+use databend_common_expression::FromData;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::ScalarRef;
 use databend_common_expression::Value;
@@ -52,6 +54,8 @@ pub struct TransformSRF {
     /// The output number of rows for each input row.
     num_rows: VecDeque<usize>,
     max_block_size: usize,
+    /// Whether to append a 1-based index column of the expanded rows for each input row.
+    with_ordinality: bool,
 }
 
 impl TransformSRF {
@@ -62,6 +66,7 @@ impl TransformSRF {
         projections: ColumnSet,
         srf_exprs: Vec<Expr>,
         max_block_size: usize,
+        with_ordinality: bool,
     ) -> Box<dyn Processor> {
         let srf_results = vec![VecDeque::new(); srf_exprs.len()];
         BlockingTransformer::create(input, output, TransformSRF {
@@ -72,6 +77,7 @@ impl TransformSRF {
             srf_results,
             num_rows: VecDeque::new(),
             max_block_size,
+            with_ordinality,
         })
     }
 }
@@ -429,6 +435,20 @@ impl BlockingTransform for TransformSRF {
             }
         }
 
+        if self.with_ordinality {
+            let ordinality = self
+                .num_rows
+                .iter()
+                .take(used)
+                .flat_map(|num_rows| 1..=*num_rows as u64)
+                .collect::<Vec<_>>();
+            debug_assert_eq!(ordinality.len(), result_size);
+            result.add_column(BlockEntry::new(
+                DataType::Number(NumberDataType::UInt64),
+                Value::Column(UInt64Type::from_data(ordinality)),
+            ));
+        }
+
         // Release consumed rows.
         self.num_rows.drain(0..used);
         // `self.srf_results` is already drained.
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::F64;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_functions::aggregates::QuantileTDigestState;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+
+/// Compute `APPROX_PERCENTILE_CONT(column, percentile)` of the column at `offset` with a
+/// t-digest sketch, which keeps a bounded number of centroids however many rows are added.
+///
+/// NULLs are skipped. The result is NULL for a nullable output without any rows, and 0
+/// otherwise, the same as the `quantile_tdigest` aggregate function.
+pub struct TransformTDigestAgg {
+    offset: usize,
+    percentile: f64,
+    output_type: DataType,
+    count: u64,
+    state: QuantileTDigestState,
+}
+
+impl TransformTDigestAgg {
+    pub fn new(offset: usize, percentile: f64, compression: f64, output_type: DataType) -> Self {
+        TransformTDigestAgg {
+            offset,
+            percentile,
+            output_type,
+            count: 0,
+            state: QuantileTDigestState::with_compression(compression),
+        }
+    }
+}
+
+impl AccumulatingTransform for TransformTDigestAgg {
+    const NAME: &'static str = "TransformTDigestAgg";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let column = data.get_by_offset(self.offset);
+        for row in 0..data.num_rows() {
+            if let Some(ScalarRef::Number(value)) = column.value.index(row) {
+                self.state.add(value.to_f64().into_inner(), None);
+                self.count += 1;
+            }
+        }
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        if !output {
+            return Ok(vec![]);
+        }
+        let value = if self.count == 0 && self.output_type.is_nullable() {
+            Scalar::Null
+        } else {
+            let value = self.state.quantile(self.percentile);
+            Scalar::Number(NumberScalar::Float64(F64::from(value)))
+        };
+        let mut builder = ColumnBuilder::with_capacity(&self.output_type, 1);
+        builder.push(value.as_ref());
+        Ok(vec![DataBlock::new_from_columns(vec![builder.build()])])
+    }
+}
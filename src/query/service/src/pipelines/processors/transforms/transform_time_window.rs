@@ -0,0 +1,381 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::get_states_layout;
+use databend_common_expression::types::NullableColumn;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::AggrState;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::InputColumns;
+use databend_common_expression::StatesLayout;
+use databend_common_functions::aggregates::AggregateFunctionRef;
+use databend_common_functions::aggregates::StateAddr;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_pipeline_transforms::processors::Transform;
+
+use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+use crate::pipelines::processors::transforms::ReplicateState;
+
+/// The aggregate states of the time windows, allocated in one arena.
+struct WindowStates {
+    arena: Bump,
+    states_layout: StatesLayout,
+    funcs: Vec<AggregateFunctionRef>,
+    arg_indices: Vec<Vec<usize>>,
+}
+
+impl WindowStates {
+    fn try_create(params: &AggregatorParams) -> Result<Self> {
+        Ok(WindowStates {
+            arena: Bump::new(),
+            states_layout: get_states_layout(&params.aggregate_functions)?,
+            funcs: params.aggregate_functions.clone(),
+            arg_indices: params.aggregate_functions_arguments.clone(),
+        })
+    }
+
+    fn new_state(&self) -> StateAddr {
+        let addr: StateAddr = self.arena.alloc_layout(self.states_layout.layout).into();
+        for (func, loc) in self.funcs.iter().zip(self.states_layout.states_loc.iter()) {
+            func.init_state(AggrState::new(addr, loc));
+        }
+        addr
+    }
+
+    /// Accumulate each row of `block` into the state of `places` at the same row.
+    fn accumulate(&self, places: &[StateAddr], block: &DataBlock) -> Result<()> {
+        for ((func, loc), indices) in self
+            .funcs
+            .iter()
+            .zip(self.states_layout.states_loc.iter())
+            .zip(self.arg_indices.iter())
+        {
+            let columns = InputColumns::new_block_proxy(indices.as_slice(), block);
+            func.accumulate_keys(places, loc, columns, block.num_rows())?;
+        }
+        Ok(())
+    }
+
+    /// Output a row for each of `(window start, window end, state)`, and destroy the states.
+    fn finish(&self, windows: &[(i64, i64, StateAddr)]) -> Result<DataBlock> {
+        let mut builders = self
+            .funcs
+            .iter()
+            .map(|func| {
+                Ok(ColumnBuilder::with_capacity(
+                    &func.return_type()?,
+                    windows.len(),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for (_, _, addr) in windows.iter() {
+            for ((func, loc), builder) in self
+                .funcs
+                .iter()
+                .zip(self.states_layout.states_loc.iter())
+                .zip(builders.iter_mut())
+            {
+                func.merge_result(AggrState::new(*addr, loc), builder)?;
+            }
+        }
+        for (_, _, addr) in windows.iter() {
+            self.drop_state(*addr);
+        }
+
+        let mut columns = Vec::with_capacity(builders.len() + 2);
+        columns.push(TimestampType::from_data(
+            windows
+                .iter()
+                .map(|(start, _, _)| *start)
+                .collect::<Vec<_>>(),
+        ));
+        columns.push(TimestampType::from_data(
+            windows.iter().map(|(_, end, _)| *end).collect::<Vec<_>>(),
+        ));
+        columns.extend(builders.into_iter().map(|builder| builder.build()));
+        Ok(DataBlock::new_from_columns(columns))
+    }
+
+    fn drop_state(&self, addr: StateAddr) {
+        for (func, loc) in self.funcs.iter().zip(self.states_layout.states_loc.iter()) {
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(AggrState::new(addr, loc)) }
+            }
+        }
+    }
+}
+
+// The timestamps of the column at `offset`, NULL timestamps are `None`.
+fn timestamps(block: &DataBlock, offset: usize) -> Result<Vec<Option<i64>>> {
+    match block.get_by_offset(offset).to_column(block.num_rows()) {
+        Column::Timestamp(values) => Ok(values.iter().map(|v| Some(*v)).collect()),
+        Column::Nullable(box NullableColumn {
+            column: Column::Timestamp(values),
+            validity,
+        }) => Ok(values
+            .iter()
+            .zip(validity.iter())
+            .map(|(v, valid)| valid.then_some(*v))
+            .collect()),
+        Column::Null { len } => Ok(vec![None; len]),
+        column => Err(ErrorCode::Internal(format!(
+            "Time window expects a timestamp column, but got {}",
+            column.data_type()
+        ))),
+    }
+}
+
+// Accumulate the rows of `block` with a timestamp into `places`, the rows with a NULL
+// timestamp are not in any window.
+fn accumulate_rows(
+    states: &WindowStates,
+    block: DataBlock,
+    rows: Vec<u32>,
+    places: Vec<StateAddr>,
+) -> Result<()> {
+    if places.is_empty() {
+        return Ok(());
+    }
+    let block = if rows.len() < block.num_rows() {
+        block.take(&rows)?
+    } else {
+        block
+    };
+    states.accumulate(&places, &block)
+}
+
+/// Aggregate the rows by the tumbling windows of the time column, the windows are output in
+/// the order of the window start when all the rows are accumulated.
+pub struct TransformTumblingWindow {
+    states: WindowStates,
+    time_offset: usize,
+    window_size: i64,
+    // Window start -> the aggregate states of the window.
+    windows: BTreeMap<i64, StateAddr>,
+}
+
+impl TransformTumblingWindow {
+    pub fn try_create(
+        params: &AggregatorParams,
+        time_offset: usize,
+        window_size: i64,
+    ) -> Result<Self> {
+        Ok(TransformTumblingWindow {
+            states: WindowStates::try_create(params)?,
+            time_offset,
+            window_size,
+            windows: BTreeMap::new(),
+        })
+    }
+}
+
+impl AccumulatingTransform for TransformTumblingWindow {
+    const NAME: &'static str = "TumblingWindowTransform";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let data = data.consume_convert_to_full();
+        let mut rows = Vec::with_capacity(data.num_rows());
+        let mut places = Vec::with_capacity(data.num_rows());
+        for (row, ts) in timestamps(&data, self.time_offset)?.into_iter().enumerate() {
+            let Some(ts) = ts else {
+                continue;
+            };
+            let start = ts.div_euclid(self.window_size) * self.window_size;
+            let place = *self
+                .windows
+                .entry(start)
+                .or_insert_with(|| self.states.new_state());
+            rows.push(row as u32);
+            places.push(place);
+        }
+        accumulate_rows(&self.states, data, rows, places)?;
+        Ok(vec![])
+    }
+
+    fn on_finish(&mut self, generate_data: bool) -> Result<Vec<DataBlock>> {
+        let windows = std::mem::take(&mut self.windows)
+            .into_iter()
+            .map(|(start, addr)| (start, start.saturating_add(self.window_size), addr))
+            .collect::<Vec<_>>();
+        if !generate_data {
+            for (_, _, addr) in windows {
+                self.states.drop_state(addr);
+            }
+            return Ok(vec![]);
+        }
+        if windows.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![self.states.finish(&windows)?])
+    }
+}
+
+struct Session {
+    start: i64,
+    last: i64,
+    state: StateAddr,
+}
+
+/// Aggregate the rows sorted by the time column into sessions. A session is closed by a row
+/// more than the gap after its last row, so it may span many blocks, and the closed sessions
+/// of a block are output after the block is accumulated.
+pub struct TransformSessionWindow {
+    states: WindowStates,
+    time_offset: usize,
+    gap: i64,
+    // The session of the last row, it's not closed until a later row or the end of the input.
+    current: Option<Session>,
+}
+
+impl TransformSessionWindow {
+    pub fn try_create(params: &AggregatorParams, time_offset: usize, gap: i64) -> Result<Self> {
+        Ok(TransformSessionWindow {
+            states: WindowStates::try_create(params)?,
+            time_offset,
+            gap,
+            current: None,
+        })
+    }
+
+    fn session_window(&self, session: &Session) -> (i64, i64, StateAddr) {
+        (
+            session.start,
+            session.last.saturating_add(self.gap),
+            session.state,
+        )
+    }
+}
+
+impl AccumulatingTransform for TransformSessionWindow {
+    const NAME: &'static str = "SessionWindowTransform";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let data = data.consume_convert_to_full();
+        let mut rows = Vec::with_capacity(data.num_rows());
+        let mut places = Vec::with_capacity(data.num_rows());
+        let mut closed = vec![];
+        for (row, ts) in timestamps(&data, self.time_offset)?.into_iter().enumerate() {
+            let Some(ts) = ts else {
+                continue;
+            };
+            let in_session =
+                matches!(&self.current, Some(session) if ts - session.last <= self.gap);
+            if !in_session {
+                if let Some(session) = self.current.take() {
+                    closed.push(self.session_window(&session));
+                }
+                self.current = Some(Session {
+                    start: ts,
+                    last: ts,
+                    state: self.states.new_state(),
+                });
+            }
+            let session = self.current.as_mut().unwrap();
+            session.last = ts;
+            rows.push(row as u32);
+            places.push(session.state);
+        }
+        // The closed sessions may have rows in this block.
+        accumulate_rows(&self.states, data, rows, places)?;
+
+        if closed.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![self.states.finish(&closed)?])
+    }
+
+    fn on_finish(&mut self, generate_data: bool) -> Result<Vec<DataBlock>> {
+        let Some(session) = self.current.take() else {
+            return Ok(vec![]);
+        };
+        if !generate_data {
+            self.states.drop_state(session.state);
+            return Ok(vec![]);
+        }
+        Ok(vec![self
+            .states
+            .finish(&[self.session_window(&session)])?])
+    }
+}
+
+/// Remove the late rows from the blocks by the watermark of the time column, which is the
+/// latest time of the rows arrived before minus the delay. The late rows are stored into
+/// `late_rows` if any, or dropped. The rows with a NULL time are never late.
+pub struct TransformWatermark {
+    time_offset: usize,
+    // In microseconds.
+    delay: i64,
+    max_time: Option<i64>,
+    late_rows: Option<Arc<ReplicateState>>,
+}
+
+impl TransformWatermark {
+    pub fn create(time_offset: usize, delay: i64, late_rows: Option<Arc<ReplicateState>>) -> Self {
+        // The late rows can be read once all the transforms are finished.
+        if let Some(late_rows) = &late_rows {
+            late_rows.attach();
+        }
+        TransformWatermark {
+            time_offset,
+            delay,
+            max_time: None,
+            late_rows,
+        }
+    }
+}
+
+impl Transform for TransformWatermark {
+    const NAME: &'static str = "WatermarkTransform";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let data = data.consume_convert_to_full();
+        let mut on_time = Vec::with_capacity(data.num_rows());
+        let mut late = vec![];
+        for (row, ts) in timestamps(&data, self.time_offset)?.into_iter().enumerate() {
+            let Some(ts) = ts else {
+                on_time.push(row as u32);
+                continue;
+            };
+            match self.max_time {
+                Some(max_time) if ts < max_time.saturating_sub(self.delay) => late.push(row as u32),
+                _ => on_time.push(row as u32),
+            }
+            self.max_time = Some(self.max_time.map_or(ts, |max_time| max_time.max(ts)));
+        }
+
+        if late.is_empty() {
+            return Ok(data);
+        }
+        if let Some(late_rows) = &self.late_rows {
+            late_rows.push(data.take(&late)?);
+        }
+        data.take(&on_time)
+    }
+
+    fn on_finish(&mut self) -> Result<()> {
+        if let Some(late_rows) = &self.late_rows {
+            late_rows.detach();
+        }
+        Ok(())
+    }
+}
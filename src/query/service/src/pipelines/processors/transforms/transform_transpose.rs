@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Value;
+use databend_common_pipeline_transforms::processors::Transform;
+
+/// Convert the columns at `unpivot_offsets` to rows, each row is expanded to one row
+/// per column. The names and the values of the columns are appended to the other columns.
+pub struct TransformTranspose {
+    unpivot_offsets: Vec<usize>,
+    names: Vec<String>,
+}
+
+impl TransformTranspose {
+    pub fn new(unpivot_offsets: Vec<usize>, names: Vec<String>) -> Self {
+        TransformTranspose {
+            unpivot_offsets,
+            names,
+        }
+    }
+}
+
+impl Transform for TransformTranspose {
+    const NAME: &'static str = "TransformTranspose";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let num_columns = self.unpivot_offsets.len();
+
+        // Each row of the other columns is repeated once per unpivoted column.
+        let row_indices = (0..num_rows as u32)
+            .flat_map(|row| std::iter::repeat(row).take(num_columns))
+            .collect::<Vec<_>>();
+        let columns = block
+            .columns()
+            .iter()
+            .enumerate()
+            .filter(|(offset, _)| !self.unpivot_offsets.contains(offset))
+            .map(|(_, entry)| entry.clone())
+            .collect::<Vec<_>>();
+        let mut output = DataBlock::new(columns, num_rows).take(&row_indices)?;
+
+        // The unpivoted columns are concatenated, and then their values are taken row by row.
+        let values = self
+            .unpivot_offsets
+            .iter()
+            .map(|offset| DataBlock::new(vec![block.get_by_offset(*offset).clone()], num_rows))
+            .collect::<Vec<_>>();
+        let value_indices = (0..num_rows)
+            .flat_map(|row| (0..num_columns).map(move |column| (column * num_rows + row) as u32))
+            .collect::<Vec<_>>();
+        let values = DataBlock::concat(&values)?.take(&value_indices)?;
+
+        let names = (0..num_rows)
+            .flat_map(|_| self.names.iter().cloned())
+            .collect::<Vec<_>>();
+        output.add_column(BlockEntry::new(
+            DataType::String,
+            Value::Column(StringType::from_data(names)),
+        ));
+        output.add_column(values.get_by_offset(0).clone());
+        Ok(output)
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::runtime::spawn;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use databend_storages_common_blocks::blocks_to_parquet;
+use databend_storages_common_table_meta::table::TableCompression;
+use opendal::Operator;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+/// The object written under the wal location once all the rows of the insert are logged.
+/// A wal without it is from an insert that failed before reading all of its input, and is
+/// never replayed.
+pub const WAL_COMPLETE_MARKER: &str = "_complete";
+
+/// The parallel `TransformWriteAheadLog`s of one `WriteAheadLog` plan. The last finished
+/// transform writes the complete marker.
+pub struct WriteAheadLogState {
+    running: AtomicUsize,
+}
+
+impl WriteAheadLogState {
+    pub fn new(num_transforms: usize) -> Arc<Self> {
+        Arc::new(WriteAheadLogState {
+            running: AtomicUsize::new(num_transforms),
+        })
+    }
+}
+
+/// Buffer the inserted columns of each block and flush them to a parquet file under the
+/// wal location every `flush_interval`, the block is passed through unchanged.
+pub struct TransformWriteAheadLog {
+    wal_columns: Vec<usize>,
+    wal_schema: TableSchemaRef,
+    flush_interval: Duration,
+    location: String,
+    operator: Operator,
+    state: Arc<WriteAheadLogState>,
+
+    buffer: Vec<DataBlock>,
+    last_flush: Instant,
+    flushing: Vec<JoinHandle<Result<()>>>,
+}
+
+impl TransformWriteAheadLog {
+    pub fn new(
+        wal_columns: Vec<usize>,
+        wal_schema: TableSchemaRef,
+        flush_interval_ms: u64,
+        location: String,
+        operator: Operator,
+        state: Arc<WriteAheadLogState>,
+    ) -> Self {
+        TransformWriteAheadLog {
+            wal_columns,
+            wal_schema,
+            flush_interval: Duration::from_millis(flush_interval_ms),
+            location,
+            operator,
+            state,
+            buffer: vec![],
+            last_flush: Instant::now(),
+            flushing: vec![],
+        }
+    }
+
+    // Write the buffered blocks in the background.
+    fn flush(&mut self) {
+        self.last_flush = Instant::now();
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let blocks = std::mem::take(&mut self.buffer);
+        let schema = self.wal_schema.clone();
+        let operator = self.operator.clone();
+        let path = format!("{}{}.parquet", self.location, Uuid::now_v7().simple());
+        self.flushing.push(spawn(async move {
+            let mut data = vec![];
+            blocks_to_parquet(&schema, blocks, &mut data, TableCompression::LZ4)?;
+            operator.write(&path, data).await?;
+            Ok(())
+        }));
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncAccumulatingTransform for TransformWriteAheadLog {
+    const NAME: &'static str = "TransformWriteAheadLog";
+
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if !data.is_empty() {
+            let columns = self
+                .wal_columns
+                .iter()
+                .map(|offset| data.get_by_offset(*offset).clone())
+                .collect();
+            self.buffer.push(DataBlock::new(columns, data.num_rows()));
+        }
+
+        if self.last_flush.elapsed() >= self.flush_interval {
+            self.flush();
+        }
+        Ok(Some(data))
+    }
+
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        self.flush();
+        for handle in std::mem::take(&mut self.flushing) {
+            handle.await.map_err(|e| {
+                ErrorCode::TokioError(format!("write-ahead log flush failed: {e}"))
+            })??;
+        }
+
+        if self.state.running.fetch_sub(1, Ordering::AcqRel) == 1 {
+            let marker = format!("{}{}", self.location, WAL_COMPLETE_MARKER);
+            self.operator.write(&marker, vec![]).await?;
+        }
+        Ok(None)
+    }
+}
@@ -28,6 +28,7 @@ use databend_common_sql::executor::physical_plans::HashJoin;
 use databend_common_sql::executor::physical_plans::MutationSource;
 use databend_common_sql::executor::physical_plans::Recluster;
 use databend_common_sql::executor::physical_plans::ReplaceInto;
+use databend_common_sql::executor::physical_plans::SemiHashJoin;
 use databend_common_sql::executor::physical_plans::TableScan;
 use databend_common_sql::executor::physical_plans::UnionAll;
 use databend_common_sql::executor::PhysicalPlanReplacer;
@@ -102,6 +103,7 @@ impl Fragmenter {
                 FragmentKind::Normal => Ok(Some(ShuffleDataExchange::create(
                     Self::get_executors(ctx),
                     plan.keys.clone(),
+                    plan.consistent_hash,
                 ))),
                 FragmentKind::Merge => Ok(Some(MergeExchange::create(
                     Self::get_local_executor(ctx),
@@ -242,6 +244,40 @@ impl PhysicalPlanReplacer for Fragmenter {
         }))
     }
 
+    fn replace_semi_hash_join(&mut self, plan: &SemiHashJoin) -> Result<PhysicalPlan> {
+        let mut fragments = vec![];
+        let build_input = self.replace(plan.build.as_ref())?;
+
+        // Consume current fragments to prevent them being consumed by `probe_input`.
+        fragments.append(&mut self.fragments);
+        let probe_input = self.replace(plan.probe.as_ref())?;
+        fragments.append(&mut self.fragments);
+        self.fragments = fragments;
+
+        Ok(PhysicalPlan::SemiHashJoin(SemiHashJoin {
+            build: Box::new(build_input),
+            probe: Box::new(probe_input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_anti_hash_join(&mut self, plan: &SemiHashJoin) -> Result<PhysicalPlan> {
+        let mut fragments = vec![];
+        let build_input = self.replace(plan.build.as_ref())?;
+
+        // Consume current fragments to prevent them being consumed by `probe_input`.
+        fragments.append(&mut self.fragments);
+        let probe_input = self.replace(plan.probe.as_ref())?;
+        fragments.append(&mut self.fragments);
+        self.fragments = fragments;
+
+        Ok(PhysicalPlan::AntiHashJoin(SemiHashJoin {
+            build: Box::new(build_input),
+            probe: Box::new(probe_input),
+            ..plan.clone()
+        }))
+    }
+
     fn replace_union(&mut self, plan: &UnionAll) -> Result<PhysicalPlan> {
         let mut fragments = vec![];
         let left_input = self.replace(plan.left.as_ref())?;
@@ -294,6 +330,7 @@ impl PhysicalPlanReplacer for Fragmenter {
             destination_fragment_id: usize::MAX,
             ignore_exchange: plan.ignore_exchange,
             allow_adjust_parallelism: plan.allow_adjust_parallelism,
+            consistent_hash: plan.consistent_hash,
         });
         let fragment_type = match self.state {
             State::SelectLeaf => FragmentType::Source,
@@ -326,6 +326,10 @@ impl PlanFragment {
         };
         let compact_block = match exchange_sink.input.as_ref() {
             PhysicalPlan::CompactSource(plan) => plan,
+            PhysicalPlan::Compact(plan) => match plan.input.as_ref() {
+                PhysicalPlan::CompactSource(plan) => plan,
+                _ => unreachable!("logic error"),
+            },
             _ => unreachable!("logic error"),
         };
 
@@ -515,6 +519,9 @@ impl PhysicalPlanReplacer for ReplaceReadSource {
             table_index: plan.table_index,
             stat_info: plan.stat_info.clone(),
             internal_column: plan.internal_column.clone(),
+            speculative_prefetch: plan.speculative_prefetch,
+            prefetch_depth: plan.prefetch_depth,
+            force_index: plan.force_index.clone(),
         }))
     }
 
@@ -20,6 +20,7 @@ use databend_common_expression::DataBlock;
 use databend_common_sql::planner::query_executor::QueryExecutor;
 use databend_common_sql::Planner;
 use futures_util::TryStreamExt;
+use log::info;
 
 use crate::interpreters::InterpreterFactory;
 use crate::pipelines::executor::ExecutorSettings;
@@ -44,6 +45,18 @@ pub async fn build_query_pipeline(
     plan: &PhysicalPlan,
     ignore_result: bool,
 ) -> Result<PipelineBuildResult> {
+    // The result cache may expire after the query found it, the plan of the query is
+    // executed then instead of reading the cache.
+    let plan = match plan {
+        PhysicalPlan::ResultCacheScan(scan) if scan.is_expired() => {
+            info!(
+                "Result cache {} has expired before the execution, fall back to execute the query",
+                scan.location
+            );
+            scan.input.as_ref()
+        }
+        plan => plan,
+    };
     let mut build_res = build_query_pipeline_without_render_result_set(ctx, plan).await?;
     let input_schema = plan.output_schema()?;
 
@@ -35,13 +35,19 @@ impl DataExchange {
 pub struct ShuffleDataExchange {
     pub destination_ids: Vec<String>,
     pub shuffle_keys: Vec<RemoteExpr>,
+    pub consistent_hash: bool,
 }
 
 impl ShuffleDataExchange {
-    pub fn create(destination_ids: Vec<String>, shuffle_keys: Vec<RemoteExpr>) -> DataExchange {
+    pub fn create(
+        destination_ids: Vec<String>,
+        shuffle_keys: Vec<RemoteExpr>,
+        consistent_hash: bool,
+    ) -> DataExchange {
         DataExchange::ShuffleDataExchange(ShuffleDataExchange {
             destination_ids,
             shuffle_keys,
+            consistent_hash,
         })
     }
 }
@@ -98,6 +98,7 @@ impl ExchangeInjector for DefaultExchangeInjector {
                     exchange.shuffle_keys.clone(),
                     exchange.destination_ids.len(),
                     local_pos,
+                    exchange.consistent_hash,
                 )?
             }
         }))
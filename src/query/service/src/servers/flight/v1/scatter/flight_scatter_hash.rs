@@ -43,6 +43,7 @@ pub struct HashFlightScatter {
     func_ctx: FunctionContext,
     hash_key: Vec<Expr>,
     scatter_size: usize,
+    consistent_hash: bool,
 }
 
 impl HashFlightScatter {
@@ -51,6 +52,7 @@ impl HashFlightScatter {
         hash_keys: Vec<RemoteExpr>,
         scatter_size: usize,
         local_pos: usize,
+        consistent_hash: bool,
     ) -> Result<Box<dyn FlightScatter>> {
         if hash_keys.len() == 1 {
             return OneHashKeyFlightScatter::try_create(
@@ -58,6 +60,7 @@ impl HashFlightScatter {
                 &hash_keys[0],
                 scatter_size,
                 local_pos,
+                consistent_hash,
             );
         }
         let hash_key = hash_keys
@@ -77,6 +80,7 @@ impl HashFlightScatter {
             func_ctx,
             scatter_size,
             hash_key,
+            consistent_hash,
         }))
     }
 }
@@ -87,6 +91,7 @@ struct OneHashKeyFlightScatter {
     func_ctx: FunctionContext,
     indices_scalar: Expr,
     default_scatter_index: u64,
+    consistent_hash: bool,
 }
 
 impl OneHashKeyFlightScatter {
@@ -95,12 +100,30 @@ impl OneHashKeyFlightScatter {
         hash_key: &RemoteExpr,
         scatter_size: usize,
         local_pos: usize,
+        consistent_hash: bool,
     ) -> Result<Box<dyn FlightScatter>> {
-        let default_scatter_index = if shuffle_by_block_id_in_merge_into(hash_key) {
-            local_pos as u64
-        } else {
-            0
-        };
+        let by_block_id = shuffle_by_block_id_in_merge_into(hash_key);
+        let default_scatter_index = if by_block_id { local_pos as u64 } else { 0 };
+
+        // The block id of merge into is already the index of the destination.
+        let consistent_hash = consistent_hash && !by_block_id;
+        if consistent_hash {
+            let indices_scalar = check_function(
+                None,
+                "siphash",
+                &[],
+                &[hash_key.as_expr(&BUILTIN_FUNCTIONS)],
+                &BUILTIN_FUNCTIONS,
+            )?;
+            return Ok(Box::new(OneHashKeyFlightScatter {
+                scatter_size,
+                func_ctx,
+                indices_scalar,
+                default_scatter_index,
+                consistent_hash,
+            }));
+        }
+
         let indices_scalar = check_function(
             None,
             "modulo",
@@ -127,6 +150,7 @@ impl OneHashKeyFlightScatter {
             func_ctx,
             indices_scalar,
             default_scatter_index,
+            consistent_hash,
         }))
     }
 }
@@ -138,6 +162,14 @@ impl FlightScatter for OneHashKeyFlightScatter {
 
         let indices = evaluator.run(&self.indices_scalar).unwrap();
         let indices = get_hash_values(indices, num, self.default_scatter_index)?;
+        let indices = match self.consistent_hash {
+            true => indices
+                .iter()
+                .map(|hash| jump_consistent_hash(*hash, self.scatter_size))
+                .collect::<Vec<_>>()
+                .into(),
+            false => indices,
+        };
         let data_blocks = DataBlock::scatter(&data_block, &indices, self.scatter_size)?;
 
         let block_meta = data_block.get_meta();
@@ -196,11 +228,33 @@ impl HashFlightScatter {
             }
         }
 
+        if self.consistent_hash {
+            return Ok(hash
+                .into_iter()
+                .map(|h| jump_consistent_hash(h.finish(), self.scatter_size))
+                .collect());
+        }
+
         let m = self.scatter_size as u64;
         Ok(hash.into_iter().map(|h| h.finish() % m).collect())
     }
 }
 
+/// Jump consistent hash (Lamping and Veach), maps `key` to a bucket in `[0, buckets)`.
+///
+/// A key is always routed to the same bucket for the same number of buckets, and only
+/// `1 / buckets` of the keys move to the new bucket when a bucket is added.
+pub fn jump_consistent_hash(mut key: u64, buckets: usize) -> u64 {
+    let mut b: i64 = -1;
+    let mut j: i64 = 0;
+    while j < buckets as i64 {
+        b = j;
+        key = key.wrapping_mul(2862933555777941757).wrapping_add(1);
+        j = ((b + 1) as f64 * ((1i64 << 31) as f64 / ((key >> 33) + 1) as f64)) as i64;
+    }
+    b as u64
+}
+
 fn shuffle_by_block_id_in_merge_into(expr: &RemoteExpr) -> bool {
     if let RemoteExpr::FunctionCall {
         id: FunctionID::Builtin { name, .. },
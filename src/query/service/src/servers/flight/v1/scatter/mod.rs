@@ -18,4 +18,5 @@ mod flight_scatter_hash;
 
 pub use flight_scatter::FlightScatter;
 pub use flight_scatter_broadcast::BroadcastFlightScatter;
+pub use flight_scatter_hash::jump_consistent_hash;
 pub use flight_scatter_hash::HashFlightScatter;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod persistent_queue_mgr;
 mod query_affect;
 pub mod query_ctx;
 mod query_ctx_shared;
@@ -27,6 +28,9 @@ mod session_status;
 mod session_type;
 
 pub use databend_common_catalog::table_context::TableContext;
+pub use persistent_queue_mgr::PersistentQueriesQueueManager;
+pub use persistent_queue_mgr::PersistentQueueEntry;
+pub use persistent_queue_mgr::PersistentQueueManager;
 pub use query_affect::QueryAffect;
 pub use query_ctx::convert_query_log_timestamp;
 pub use query_ctx::QueryContext;
@@ -37,6 +41,8 @@ pub use queue_mgr::QueriesQueueManager;
 pub use queue_mgr::QueryEntry;
 pub use queue_mgr::QueueData;
 pub use queue_mgr::QueueManager;
+pub use queue_mgr::QueueMetrics;
+pub use queue_mgr::QueueWaitHistogram;
 pub use session::Session;
 pub use session_ctx::SessionContext;
 pub use session_info::ProcessInfo;
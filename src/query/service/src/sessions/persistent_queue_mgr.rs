@@ -0,0 +1,208 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::Result;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::UpsertKV;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::sessions::AcquireQueueGuard;
+use crate::sessions::QueryEntry;
+use crate::sessions::QueueData;
+use crate::sessions::QueueManager;
+
+/// The state of a waiting entry persisted in the meta store.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PersistentQueueEntry {
+    pub query_id: String,
+    pub enqueue_time: SystemTime,
+}
+
+/// The restored entries which do not acquire again in it are dropped, the queries
+/// are usually retried with new ids by then.
+const RESTORED_GRACE_PERIOD: Duration = Duration::from_secs(10 * 60);
+
+/// A [`QueueManager`] which persists the waiting entries in the meta store,
+/// so that the queries waiting before restarting keep their original enqueue time
+/// when they acquire again.
+///
+/// Only the entries which have to wait are persisted, with the key and the enqueue
+/// time, the waker and the abort flag are created when the query acquires again.
+/// The persisted entries are taken out of the meta store on restarting, and persisted
+/// again if they wait again.
+pub struct PersistentQueueManager<Data: QueueData> {
+    inner: Arc<QueueManager<Data>>,
+    meta_store: Arc<MetaStore>,
+    prefix: String,
+    restored: Mutex<HashMap<String, SystemTime>>,
+    restored_at: Instant,
+}
+
+impl<Data: QueueData> PersistentQueueManager<Data> {
+    /// Wrap `inner` and restore the entries persisted under `prefix`, the prefix is
+    /// only used by one node.
+    #[async_backtrace::framed]
+    pub async fn create(
+        inner: Arc<QueueManager<Data>>,
+        meta_store: Arc<MetaStore>,
+        prefix: &str,
+    ) -> Result<Arc<Self>> {
+        let prefix = format!("{}/", prefix.trim_end_matches('/'));
+        let mut restored = HashMap::new();
+        for (key, value) in meta_store.prefix_list_kv(&prefix).await? {
+            // The entries which do not come back are never left behind.
+            delete_entry(&meta_store, &key).await;
+            match serde_json::from_slice::<PersistentQueueEntry>(&value.data) {
+                Ok(entry) => {
+                    restored.insert(entry.query_id, entry.enqueue_time);
+                }
+                Err(cause) => {
+                    warn!("cannot restore the queue entry {}, cause: {:?}", key, cause);
+                }
+            }
+        }
+        info!("restored {} persisted queue entries", restored.len());
+
+        Ok(Arc::new(PersistentQueueManager {
+            inner,
+            meta_store,
+            prefix,
+            restored: Mutex::new(restored),
+            restored_at: Instant::now(),
+        }))
+    }
+
+    /// Wrap the global queue manager and set it as the global instance.
+    #[async_backtrace::framed]
+    pub async fn init(
+        inner: Arc<QueueManager<Data>>,
+        meta_store: Arc<MetaStore>,
+        prefix: &str,
+    ) -> Result<()> {
+        GlobalInstance::set(Self::create(inner, meta_store, prefix).await?);
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<Self> {
+        GlobalInstance::get::<Arc<Self>>()
+    }
+
+    pub fn inner(&self) -> &Arc<QueueManager<Data>> {
+        &self.inner
+    }
+
+    /// The restored entries which have not acquired again, in the order of enqueue time.
+    pub fn restored(&self) -> Vec<PersistentQueueEntry> {
+        let mut restored = self.restored.lock();
+        self.expire_restored(&mut restored);
+        let mut entries = restored
+            .iter()
+            .map(|(query_id, enqueue_time)| PersistentQueueEntry {
+                query_id: query_id.clone(),
+                enqueue_time: *enqueue_time,
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by(|a, b| (a.enqueue_time, &a.query_id).cmp(&(b.enqueue_time, &b.query_id)));
+        entries
+    }
+
+    fn expire_restored(&self, restored: &mut HashMap<String, SystemTime>) {
+        if !restored.is_empty() && self.restored_at.elapsed() > RESTORED_GRACE_PERIOD {
+            info!("dropped {} restored queue entries", restored.len());
+            restored.clear();
+        }
+    }
+
+    /// Acquire from the queue like [`QueueManager::acquire`]. The entry is persisted
+    /// while waiting, a restored entry waits from its original enqueue time.
+    #[async_backtrace::framed]
+    pub async fn acquire(&self, data: Data) -> Result<AcquireQueueGuard> {
+        let query_id = data.get_key().to_string();
+        let restored = {
+            let mut restored = self.restored.lock();
+            self.expire_restored(&mut restored);
+            restored.remove(&query_id)
+        };
+
+        // Only the entries which have to wait are persisted.
+        if let Some(guard) = self.inner.try_acquire_now(&data) {
+            return Ok(guard);
+        }
+
+        let enqueue_time = restored.unwrap_or_else(SystemTime::now);
+        let entry = PersistentQueueEntry {
+            query_id,
+            enqueue_time,
+        };
+        let key = format!("{}{}", self.prefix, entry.query_id);
+        // The entry is deleted once the waiting is over, or the waiter is dropped.
+        let persisted = PersistedEntry {
+            meta_store: self.meta_store.clone(),
+            key: Some(key.clone()),
+        };
+        let upsert = UpsertKV::update(&key, &serde_json::to_vec(&entry)?);
+        if let Err(cause) = self.meta_store.upsert_kv(upsert).await {
+            warn!("cannot persist the queue entry {}, cause: {:?}", key, cause);
+        }
+
+        let waited = enqueue_time.elapsed().unwrap_or_default();
+        let res = self.inner.acquire_with_waited(data, waited).await;
+        persisted.delete().await;
+        res
+    }
+}
+
+/// An entry persisted in the meta store, which is deleted when it is dropped.
+struct PersistedEntry {
+    meta_store: Arc<MetaStore>,
+    key: Option<String>,
+}
+
+impl PersistedEntry {
+    async fn delete(mut self) {
+        if let Some(key) = self.key.take() {
+            delete_entry(&self.meta_store, &key).await;
+        }
+    }
+}
+
+impl Drop for PersistedEntry {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            let meta_store = self.meta_store.clone();
+            databend_common_base::runtime::spawn(async move {
+                delete_entry(&meta_store, &key).await;
+            });
+        }
+    }
+}
+
+// Deleting is best-effort, the entries left behind are deleted on restarting.
+async fn delete_entry(meta_store: &MetaStore, key: &str) {
+    if let Err(cause) = meta_store.upsert_kv(UpsertKV::delete(key)).await {
+        warn!("cannot delete the queue entry {}, cause: {:?}", key, cause);
+    }
+}
+
+pub type PersistentQueriesQueueManager = PersistentQueueManager<QueryEntry>;
@@ -52,6 +52,7 @@ use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterReady;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterStats;
 use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::ContextError;
@@ -118,6 +119,7 @@ use databend_storages_common_session::TxnManagerRef;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::TableMetaTimestamps;
 use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::OPT_KEY_ROW_ACCESS_POLICY;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use jiff::tz::TimeZone;
 use jiff::Zoned;
@@ -132,6 +134,7 @@ use crate::clusters::Cluster;
 use crate::clusters::ClusterHelper;
 use crate::locks::LockManager;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::transforms::ReplicateState;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::query_ctx_shared::MemoryUpdater;
@@ -307,6 +310,15 @@ impl QueryContext {
         self.shared.session.session_ctx.get_client_host()
     }
 
+    /// Record that `query` missed the query result cache in the session,
+    /// returns the hot queries of the session that read any of `tables`.
+    pub fn record_result_cache_miss(&self, query: &str, tables: HashSet<u64>) -> Vec<String> {
+        self.shared
+            .session
+            .session_ctx
+            .record_result_cache_miss(query, tables)
+    }
+
     pub fn get_affect(self: &Arc<Self>) -> Option<QueryAffect> {
         self.shared.get_affect()
     }
@@ -430,6 +442,11 @@ impl QueryContext {
         r.keys().cloned().collect()
     }
 
+    pub fn remove_spill_file(&self, location: &crate::spillers::Location) {
+        let mut w = self.shared.spilled_files.write();
+        w.remove(location);
+    }
+
     pub fn query_tenant_spill_prefix(&self) -> String {
         let tenant = self.get_tenant();
         format!("_query_spill/{}", tenant.tenant_name())
@@ -543,6 +560,12 @@ impl QueryContext {
         self.shared.set_query_memory_tracking(mem_stat)
     }
 
+    /// Get the shared `ReplicateState` of the cache key,
+    /// the flag is true if the state is created by this call.
+    pub fn get_or_create_replicate_state(&self, cache_key: u64) -> (Arc<ReplicateState>, bool) {
+        self.shared.get_or_create_replicate_state(cache_key)
+    }
+
     pub fn get_node_memory_updater(&self, node: &str) -> Arc<MemoryUpdater> {
         self.shared.get_node_memory_updater(node)
     }
@@ -766,6 +789,14 @@ impl TableContext for QueryContext {
         );
     }
 
+    fn append_returning_block(&self, block: DataBlock) {
+        self.shared.returning_blocks.lock().push(block);
+    }
+
+    fn get_returning_blocks(&self) -> Vec<DataBlock> {
+        self.shared.returning_blocks.lock().clone()
+    }
+
     fn attach_query_str(&self, kind: QueryKind, query: String) {
         self.shared.attach_query_str(kind, query);
     }
@@ -873,6 +904,14 @@ impl TableContext for QueryContext {
             .await
     }
 
+    fn get_row_access_policy(&self, table: &dyn Table) -> Result<Option<String>> {
+        Ok(table
+            .options()
+            .get(OPT_KEY_ROW_ACCESS_POLICY)
+            .filter(|policy| !policy.trim().is_empty())
+            .cloned())
+    }
+
     fn get_fuse_version(&self) -> String {
         let session = self.get_current_session();
         match session.get_type() {
@@ -1394,6 +1433,9 @@ impl TableContext for QueryContext {
                 for filter in filters.1.get_min_max() {
                     v.get_mut().add_min_max(filter.clone());
                 }
+                for stats in filters.1.get_bloom_stats() {
+                    v.get_mut().add_bloom_stats(stats.clone());
+                }
                 for filter in filters.1.blooms() {
                     v.get_mut().add_bloom(filter);
                 }
@@ -1451,6 +1493,28 @@ impl TableContext for QueryContext {
         }
     }
 
+    fn get_bloom_runtime_filter_stats_with_id(
+        &self,
+        id: IndexType,
+    ) -> Vec<(String, Arc<Mutex<RuntimeFilterStats>>)> {
+        let runtime_filters = self.shared.runtime_filters.read();
+        match runtime_filters.get(&id) {
+            Some(v) => (v.get_bloom_stats()).clone(),
+            None => vec![],
+        }
+    }
+
+    fn get_runtime_filter_stats(&self) -> Vec<(String, RuntimeFilterStats)> {
+        let runtime_filters = self.shared.runtime_filters.read();
+        let mut runtime_filters = runtime_filters.iter().collect::<Vec<_>>();
+        runtime_filters.sort_by_key(|(id, _)| **id);
+        runtime_filters
+            .into_iter()
+            .flat_map(|(_, v)| v.get_bloom_stats())
+            .map(|(name, stats)| (name.clone(), *stats.lock()))
+            .collect()
+    }
+
     fn get_inlist_runtime_filter_with_id(&self, id: IndexType) -> Vec<Expr<String>> {
         let runtime_filters = self.shared.runtime_filters.read();
         match runtime_filters.get(&id) {
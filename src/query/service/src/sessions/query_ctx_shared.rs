@@ -67,6 +67,7 @@ use uuid::Uuid;
 use crate::clusters::Cluster;
 use crate::clusters::ClusterDiscovery;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::transforms::ReplicateState;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
 use crate::storages::Table;
@@ -134,6 +135,10 @@ pub struct QueryContextShared {
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
     pub(in crate::sessions) can_scan_from_agg_index: Arc<AtomicBool>,
     pub(in crate::sessions) num_fragmented_block_hint: Arc<Mutex<HashMap<String, u64>>>,
+    /// The blocks of the `RETURNING` columns of insert statement.
+    pub(in crate::sessions) returning_blocks: Arc<Mutex<Vec<DataBlock>>>,
+    /// The result blocks of `Replicate` plans by the cache key.
+    pub(in crate::sessions) replicate_states: Arc<DashMap<u64, Arc<ReplicateState>>>,
     pub(in crate::sessions) enable_sort_spill: Arc<AtomicBool>,
     // Status info.
     pub(in crate::sessions) status: Arc<RwLock<String>>,
@@ -208,6 +213,8 @@ impl QueryContextShared {
             cacheable: Arc::new(AtomicBool::new(true)),
             can_scan_from_agg_index: Arc::new(AtomicBool::new(true)),
             num_fragmented_block_hint: Default::default(),
+            returning_blocks: Default::default(),
+            replicate_states: Default::default(),
             enable_sort_spill: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
             user_agent: Arc::new(RwLock::new("null".to_string())),
@@ -706,6 +713,19 @@ impl QueryContextShared {
         self.mem_stat.read().clone()
     }
 
+    pub fn get_or_create_replicate_state(&self, cache_key: u64) -> (Arc<ReplicateState>, bool) {
+        let mut created = false;
+        let state = self
+            .replicate_states
+            .entry(cache_key)
+            .or_insert_with(|| {
+                created = true;
+                ReplicateState::create()
+            })
+            .clone();
+        (state, created)
+    }
+
     pub fn get_node_memory_updater(&self, node: &str) -> Arc<MemoryUpdater> {
         {
             if let Some(v) = self.node_memory_usage.read().get(node) {
@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::future::Future;
 use std::hash::Hash;
 use std::pin::Pin;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::task::Context;
@@ -45,12 +48,12 @@ use databend_common_sql::plans::ModifyTableColumnPlan;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use log::info;
+use ordered_float::NotNan;
 use parking_lot::Mutex;
 use pin_project_lite::pin_project;
-use tokio::sync::AcquireError;
 use tokio::sync::OwnedSemaphorePermit;
 use tokio::sync::Semaphore;
-use tokio::time::error::Elapsed;
+use tokio::time::Sleep;
 
 use crate::sessions::QueryContext;
 
@@ -65,9 +68,41 @@ pub trait QueueData: Send + Sync + 'static {
 
     fn need_acquire_to_queue(&self) -> bool;
 
+    /// The max time the entry waits in the queue, unlike [`QueueData::timeout`] the
+    /// entry is only limited by it when it has to wait for a permit.
+    fn max_wait(&self) -> Option<Duration> {
+        None
+    }
+
     fn enter_wait_pending(&self) {}
 
     fn exit_wait_pending(&self, _wait_time: Duration) {}
+
+    /// The group sharing the permits with the other groups in proportion to the weights.
+    fn group(&self) -> String {
+        String::new()
+    }
+
+    /// The weight of the group of the entry, must be positive.
+    fn weight(&self) -> f64 {
+        1.0
+    }
+
+    /// The amount of service requested by the entry.
+    fn size(&self) -> f64 {
+        1.0
+    }
+
+    /// The priority of the entry, the entries of a higher priority are granted first.
+    fn priority(&self) -> u8 {
+        0
+    }
+
+    /// The user running the entry, the running entries of a user are limited by
+    /// the user permits of the [`QueueManager`].
+    fn user(&self) -> Option<String> {
+        None
+    }
 }
 
 pub(crate) struct Inner<Data: QueueData> {
@@ -75,17 +110,321 @@ pub(crate) struct Inner<Data: QueueData> {
     pub waker: Waker,
     pub instant: Instant,
     pub is_abort: Arc<AtomicBool>,
+    // The time the entry finishes in the weighted fair queuing, see [`Fairness`].
+    pub virtual_finish_time: f64,
 }
 
+/// The permits are granted by weighted fair queuing (WFQ) across the groups of the entries.
+///
+/// Every entry gets a virtual finish time, `max(arrival time, finish time of the previous entry
+/// of its group) + size / weight`, and the waiter with the smallest virtual finish time is
+/// granted the next permit. So a group with many entries can not starve the other groups,
+/// and the entries in a group are granted in the order of arrival, even if an entry arrived
+/// before the others but enters the queue later, see [`QueueManager::acquire_with_waited`].
+///
+/// The waiters of a higher [`QueueData::priority`] are granted before all the waiters of
+/// a lower one, the fair queuing only orders the waiters of the same priority.
+///
+/// If the user permits are set, a user can not run more entries than them at once. The
+/// waiters of the users at the limit are passed over until one of their entries finishes,
+/// so that a user can not hold all the permits.
 pub struct QueueManager<Data: QueueData> {
+    permits: usize,
     semaphore: Arc<Semaphore>,
+    // The max number of the running entries of a user, 0 for no limit.
+    max_user_permits: usize,
+    // The number of the running entries of each user.
+    user_permits: Mutex<HashMap<String, usize>>,
+    metrics: QueueMetricsRecorder,
     queue: Mutex<HashMap<Data::Key, Inner<Data>>>,
+    fairness: Mutex<Fairness<Data::Key>>,
+    // The entries restored from a checkpoint which have not acquired again.
+    restored: Mutex<HashMap<Data::Key, RestoredEntry<Data>>>,
+}
+
+/// An entry restored by [`QueueManager::restore`], the time of waiting is kept until
+/// the entry acquires again with the same key.
+struct RestoredEntry<Data> {
+    data: Arc<Data>,
+    instant: Instant,
+}
+
+/// The upper bounds of the buckets of [`QueueMetrics::wait_time_us`], in microseconds.
+const WAIT_TIME_BUCKETS_US: [u64; 12] = [
+    1_000,
+    5_000,
+    10_000,
+    50_000,
+    100_000,
+    500_000,
+    1_000_000,
+    5_000_000,
+    10_000_000,
+    30_000_000,
+    60_000_000,
+    300_000_000,
+];
+
+/// A snapshot of the metrics of a [`QueueManager`], see [`QueueManager::metrics`].
+#[derive(Clone, Debug, Default, serde::Serialize)]
+pub struct QueueMetrics {
+    /// The number of the entries which have waited in the queue.
+    pub total_enqueued: u64,
+    /// The number of the waiting entries which have been granted a permit.
+    pub total_dequeued: u64,
+    /// The number of the waiting entries which have been aborted or timed out.
+    pub total_aborted: u64,
+    /// The number of the entries waiting in the queue now.
+    pub queued: u64,
+    /// The time the granted entries have waited in the queue.
+    pub wait_time_us: QueueWaitHistogram,
+}
+
+/// A cumulative histogram of the wait time in microseconds, in the way of Prometheus.
+#[derive(Clone, Debug, Default, serde::Serialize)]
+pub struct QueueWaitHistogram {
+    /// The upper bounds of the buckets and the number of the samples not greater than them,
+    /// the samples greater than all the bounds are only counted by `count`.
+    pub buckets: Vec<(u64, u64)>,
+    pub sum: u64,
+    pub count: u64,
+}
+
+#[derive(Default)]
+struct QueueMetricsRecorder {
+    total_enqueued: AtomicU64,
+    total_dequeued: AtomicU64,
+    total_aborted: AtomicU64,
+    wait_time_buckets: [AtomicU64; WAIT_TIME_BUCKETS_US.len()],
+    wait_time_sum: AtomicU64,
+    wait_time_count: AtomicU64,
+}
+
+fn saturating_incr(counter: &AtomicU64, value: u64) {
+    let _ = counter.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| {
+        Some(v.saturating_add(value))
+    });
+}
+
+impl QueueMetricsRecorder {
+    fn record_enqueued(&self) {
+        saturating_incr(&self.total_enqueued, 1);
+    }
+
+    fn record_aborted(&self) {
+        saturating_incr(&self.total_aborted, 1);
+    }
+
+    fn record_dequeued(&self, wait_time: Duration) {
+        saturating_incr(&self.total_dequeued, 1);
+
+        let us = u64::try_from(wait_time.as_micros()).unwrap_or(u64::MAX);
+        if let Some(idx) = WAIT_TIME_BUCKETS_US.iter().position(|bound| us <= *bound) {
+            saturating_incr(&self.wait_time_buckets[idx], 1);
+        }
+        saturating_incr(&self.wait_time_sum, us);
+        saturating_incr(&self.wait_time_count, 1);
+    }
+
+    fn snapshot(&self, queued: usize) -> QueueMetrics {
+        let mut cumulative = 0_u64;
+        let buckets = WAIT_TIME_BUCKETS_US
+            .iter()
+            .zip(self.wait_time_buckets.iter())
+            .map(|(bound, count)| {
+                cumulative = cumulative.saturating_add(count.load(Ordering::Relaxed));
+                (*bound, cumulative)
+            })
+            .collect();
+
+        QueueMetrics {
+            total_enqueued: self.total_enqueued.load(Ordering::Relaxed),
+            total_dequeued: self.total_dequeued.load(Ordering::Relaxed),
+            total_aborted: self.total_aborted.load(Ordering::Relaxed),
+            queued: queued as u64,
+            wait_time_us: QueueWaitHistogram {
+                buckets,
+                sum: self.wait_time_sum.load(Ordering::Relaxed),
+                count: self.wait_time_count.load(Ordering::Relaxed),
+            },
+        }
+    }
+}
+
+/// Order the elements of a heap by `order` only.
+struct OrderBy<O, V> {
+    order: O,
+    value: V,
+}
+
+impl<O: Ord, V> PartialEq for OrderBy<O, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.order == other.order
+    }
+}
+
+impl<O: Ord, V> Eq for OrderBy<O, V> {}
+
+impl<O: Ord, V> PartialOrd for OrderBy<O, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<O: Ord, V> Ord for OrderBy<O, V> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.order.cmp(&other.order)
+    }
+}
+
+/// The weighted fair queuing state of the waiters.
+struct Fairness<Key> {
+    // The origin of the arrival times, in seconds.
+    epoch: Instant,
+    // Breaks the ties of the virtual finish times by the order of arrival.
+    seq: u64,
+    // The virtual finish time of the last entry of each group.
+    finish_times: HashMap<String, f64>,
+    // The waiters by priority, then by virtual finish time. The entries removed from
+    // the queue are dropped lazily when they reach the top.
+    heap: BinaryHeap<Reverse<OrderBy<(Reverse<u8>, NotNan<f64>, u64), Key>>>,
+}
+
+impl<Key: Eq + Hash + Clone> Fairness<Key> {
+    fn new() -> Self {
+        Fairness {
+            epoch: Instant::now(),
+            seq: 0,
+            finish_times: HashMap::new(),
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    // The arrival time of an entry waiting since `instant`, in seconds since the epoch.
+    fn arrival(&self, instant: Instant) -> f64 {
+        match instant.checked_duration_since(self.epoch) {
+            Some(duration) => duration.as_secs_f64(),
+            None => -self.epoch.duration_since(instant).as_secs_f64(),
+        }
+    }
+
+    // The virtual time to serve `data`.
+    fn cost<Data: QueueData>(data: &Data) -> f64 {
+        let weight = match data.weight() {
+            weight if weight > 0.0 => weight,
+            _ => 1.0,
+        };
+        data.size().max(0.0) / weight
+    }
+
+    fn virtual_finish_time<Data: QueueData>(&mut self, data: &Data, instant: Instant) -> f64 {
+        let arrival = self.arrival(instant);
+        // The groups which have been served up to now start over from the arrival time.
+        self.finish_times.retain(|_, finish| *finish > arrival);
+
+        let group = data.group();
+        let start = match self.finish_times.get(&group) {
+            Some(finish) => finish.max(arrival),
+            None => arrival,
+        };
+        let finish = start + Self::cost(data);
+        self.finish_times.insert(group, finish);
+        finish
+    }
+
+    /// Chain the virtual finish times of the waiters of `group` again in the order of
+    /// arrival, after the waiter of `key` arrived earlier than the others of the group,
+    /// e.g. a waiter restored from before restarting.
+    ///
+    /// The chain starts from the earliest virtual start time of the waiters, so the
+    /// group keeps the share it has been given.
+    fn rechain<Data: QueueData<Key = Key>>(
+        &mut self,
+        queue: &mut HashMap<Key, Inner<Data>>,
+        group: &str,
+        key: &Key,
+    ) {
+        let mut waiters = queue
+            .iter_mut()
+            .filter(|(_, inner)| inner.data.group() == group)
+            .collect::<Vec<_>>();
+        waiters.sort_by_key(|(_, inner)| inner.instant);
+
+        let mut finish = waiters
+            .iter()
+            .map(|(_, inner)| inner.virtual_finish_time - Self::cost(inner.data.as_ref()))
+            .fold(f64::INFINITY, f64::min);
+        for (waiter, inner) in waiters {
+            let start = finish.max(self.arrival(inner.instant));
+            finish = start + Self::cost(inner.data.as_ref());
+            if waiter == key || inner.virtual_finish_time != finish {
+                inner.virtual_finish_time = finish;
+                self.push(waiter.clone(), inner.data.priority(), finish);
+            }
+        }
+        self.finish_times.insert(group.to_string(), finish);
+    }
+
+    fn push(&mut self, key: Key, priority: u8, virtual_finish_time: f64) {
+        let finish =
+            NotNan::new(virtual_finish_time).unwrap_or_else(|_| NotNan::new(f64::MAX).unwrap());
+        self.seq += 1;
+        self.heap.push(Reverse(OrderBy {
+            order: (Reverse(priority), finish, self.seq),
+            value: key,
+        }));
+    }
+
+    /// The waiter of the highest priority with the smallest virtual finish time, the
+    /// waiters which are `blocked` are passed over.
+    fn head<Data: QueueData<Key = Key>>(
+        &mut self,
+        queue: &HashMap<Key, Inner<Data>>,
+        blocked: impl Fn(&Data) -> bool,
+    ) -> Option<Key> {
+        let mut passed = vec![];
+        let mut head = None;
+        while let Some(Reverse(top)) = self.heap.peek() {
+            let (_, finish, _) = top.order;
+            match queue.get(&top.value) {
+                Some(inner) if inner.virtual_finish_time == *finish => {
+                    if !blocked(inner.data.as_ref()) {
+                        head = Some(top.value.clone());
+                        break;
+                    }
+                    passed.extend(self.heap.pop());
+                }
+                _ => {
+                    self.heap.pop();
+                }
+            }
+        }
+        self.heap.extend(passed);
+        head
+    }
+}
+
+/// The serialized state of a [`QueueManager`], see [`QueueManager::checkpoint`].
+#[derive(serde::Serialize, serde::Deserialize)]
+struct QueueCheckpoint<D> {
+    permits: usize,
+    entries: Vec<QueueEntryCheckpoint<D>>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct QueueEntryCheckpoint<D> {
+    data: D,
+    // The time the entry has waited in the queue.
+    waited: Duration,
 }
 
 impl<Data: QueueData> QueueManager<Data> {
-    pub fn init(permits: usize) -> Result<()> {
-        info!("queue manager permits: {:?}", permits);
-        GlobalInstance::set(Self::create(permits));
+    pub fn init(permits: usize, user_permits: usize) -> Result<()> {
+        info!(
+            "queue manager permits: {:?}, user permits: {:?}",
+            permits, user_permits
+        );
+        GlobalInstance::set(Self::create_with_user_permits(permits, user_permits));
         Ok(())
     }
 
@@ -93,15 +432,34 @@ impl<Data: QueueData> QueueManager<Data> {
         GlobalInstance::get::<Arc<Self>>()
     }
 
-    pub fn create(mut permits: usize) -> Arc<QueueManager<Data>> {
+    pub fn create(permits: usize) -> Arc<QueueManager<Data>> {
+        Self::create_with_user_permits(permits, 0)
+    }
+
+    /// Same as [`QueueManager::create`], but a user can run at most `user_permits`
+    /// entries at once, 0 for no limit.
+    pub fn create_with_user_permits(
+        permits: usize,
+        user_permits: usize,
+    ) -> Arc<QueueManager<Data>> {
+        Arc::new(Self::new(permits, user_permits))
+    }
+
+    fn new(mut permits: usize, user_permits: usize) -> QueueManager<Data> {
         if permits == 0 {
             permits = usize::MAX >> 4;
         }
 
-        Arc::new(QueueManager {
+        QueueManager {
+            permits,
+            max_user_permits: user_permits,
+            user_permits: Mutex::new(HashMap::new()),
+            metrics: QueueMetricsRecorder::default(),
             queue: Mutex::new(HashMap::new()),
+            fairness: Mutex::new(Fairness::new()),
+            restored: Mutex::new(HashMap::new()),
             semaphore: Arc::new(Semaphore::new(permits)),
-        })
+        }
     }
 
     /// The length of the queue.
@@ -110,20 +468,77 @@ impl<Data: QueueData> QueueManager<Data> {
         queue.values().len()
     }
 
+    /// The metrics of the queue since the manager is created.
+    pub fn metrics(&self) -> QueueMetrics {
+        self.metrics.snapshot(self.length())
+    }
+
     pub fn list(&self) -> Vec<Arc<Data>> {
         let queue = self.queue.lock();
         queue.values().map(|x| x.data.clone()).collect::<Vec<_>>()
     }
 
+    /// The waiter which will be granted the next permit, without removing or waking it.
+    ///
+    /// It is the waiter of the highest priority with the smallest virtual finish time,
+    /// see [`QueueManager`].
+    pub fn peek_next(&self) -> Option<Arc<Data>> {
+        let queue = self.queue.lock();
+        queue
+            .values()
+            .min_by(|a, b| {
+                b.data
+                    .priority()
+                    .cmp(&a.data.priority())
+                    .then(a.virtual_finish_time.total_cmp(&b.virtual_finish_time))
+            })
+            .map(|inner| inner.data.clone())
+    }
+
+    /// The restored entries which have not acquired again, in the order of waiting.
+    ///
+    /// They are not a part of the queue, and are dropped once they have waited
+    /// for their [`QueueData::timeout`].
+    pub fn restored(&self) -> Vec<Arc<Data>> {
+        let mut restored = self.restored.lock();
+        Self::expire_restored(&mut restored);
+        let mut entries = restored.values().collect::<Vec<_>>();
+        entries.sort_by_key(|entry| entry.instant);
+        entries.iter().map(|entry| entry.data.clone()).collect()
+    }
+
+    // The time of waiting of the restored entry of `key`, which acquires again.
+    fn take_restored(&self, key: &Data::Key) -> Option<Instant> {
+        let mut restored = self.restored.lock();
+        Self::expire_restored(&mut restored);
+        restored.remove(key).map(|entry| entry.instant)
+    }
+
+    fn expire_restored(restored: &mut HashMap<Data::Key, RestoredEntry<Data>>) {
+        let now = Instant::now();
+        restored.retain(
+            |_, entry| match entry.instant.checked_add(entry.data.timeout()) {
+                Some(deadline) => deadline > now,
+                None => true,
+            },
+        );
+    }
+
     pub fn remove(&self, key: Data::Key) -> bool {
+        if self.restored.lock().remove(&key).is_some() {
+            return true;
+        }
+
         let mut queue = self.queue.lock();
         if let Some(inner) = queue.remove(&key) {
             let queue_len = queue.len();
             drop(queue);
             set_session_queued_queries(queue_len);
+            self.metrics.record_aborted();
             inner.data.exit_wait_pending(inner.instant.elapsed());
             inner.is_abort.store(true, Ordering::SeqCst);
             inner.waker.wake();
+            self.wake_next();
             true
         } else {
             set_session_queued_queries(queue.len());
@@ -131,7 +546,29 @@ impl<Data: QueueData> QueueManager<Data> {
         }
     }
 
+    /// Acquire a permit without waiting, `None` if the entry has to wait in the queue.
+    pub fn try_acquire_now(self: &Arc<Self>, data: &Data) -> Option<AcquireQueueGuard> {
+        if !data.need_acquire_to_queue() {
+            return Some(AcquireQueueGuard::create(None));
+        }
+
+        let permit = self.try_acquire(data)?;
+        self.take_restored(&data.get_key());
+        inc_session_running_acquired_queries();
+        Some(AcquireQueueGuard::create_with_queue(permit, self.clone()))
+    }
+
     pub async fn acquire(self: &Arc<Self>, data: Data) -> Result<AcquireQueueGuard> {
+        self.acquire_with_waited(data, Duration::ZERO).await
+    }
+
+    /// Same as [`QueueManager::acquire`], but the entry has already waited in the queue
+    /// for `waited`, e.g. before restarting, and is ordered by the original time of waiting.
+    pub async fn acquire_with_waited(
+        self: &Arc<Self>,
+        data: Data,
+        waited: Duration,
+    ) -> Result<AcquireQueueGuard> {
         if data.need_acquire_to_queue() {
             info!(
                 "preparing to acquire from query queue, length: {}",
@@ -139,11 +576,14 @@ impl<Data: QueueData> QueueManager<Data> {
             );
 
             let timeout = data.timeout();
+            let max_wait = data.max_wait();
             let future = AcquireQueueFuture::create(
                 Arc::new(data),
-                tokio::time::timeout(timeout, self.semaphore.clone().acquire_owned()),
+                tokio::time::sleep(timeout),
                 self.clone(),
-            );
+            )
+            .with_waited(waited)
+            .with_max_wait(max_wait);
             let start_time = SystemTime::now();
 
             return match future.await {
@@ -176,13 +616,35 @@ impl<Data: QueueData> QueueManager<Data> {
         Ok(AcquireQueueGuard::create(None))
     }
 
-    pub(crate) fn add_entity(&self, inner: Inner<Data>) -> Data::Key {
+    pub(crate) fn add_entity(&self, mut inner: Inner<Data>) -> Data::Key {
         inner.data.enter_wait_pending();
 
         let key = inner.data.get_key();
         let queue_len = {
             let mut queue = self.queue.lock();
-            queue.insert(key.clone(), inner);
+            let mut fairness = self.fairness.lock();
+            let group = inner.data.group();
+            let arrived_early = queue
+                .values()
+                .any(|other| other.instant > inner.instant && other.data.group() == group);
+            if arrived_early {
+                // Start from the arrival, the group is chained again in the order of arrival.
+                inner.virtual_finish_time = fairness.arrival(inner.instant)
+                    + Fairness::<Data::Key>::cost(inner.data.as_ref());
+                self.metrics.record_enqueued();
+                queue.insert(key.clone(), inner);
+                fairness.rechain(&mut queue, &group, &key);
+            } else {
+                inner.virtual_finish_time =
+                    fairness.virtual_finish_time(inner.data.as_ref(), inner.instant);
+                self.metrics.record_enqueued();
+                fairness.push(
+                    key.clone(),
+                    inner.data.priority(),
+                    inner.virtual_finish_time,
+                );
+                queue.insert(key.clone(), inner);
+            }
             queue.len()
         };
 
@@ -201,75 +663,277 @@ impl<Data: QueueData> QueueManager<Data> {
             None => None,
             Some(inner) => {
                 inner.data.exit_wait_pending(inner.instant.elapsed());
+                self.metrics.record_aborted();
+                self.wake_next();
                 Some(inner.data)
             }
         }
     }
+
+    // Update the waker of a waiter, returns false if it has been removed from the queue.
+    fn update_waker(&self, key: &Data::Key, waker: &Waker) -> bool {
+        let mut queue = self.queue.lock();
+        match queue.get_mut(key) {
+            None => false,
+            Some(inner) => {
+                if !inner.waker.will_wake(waker) {
+                    inner.waker = waker.clone();
+                }
+                true
+            }
+        }
+    }
+
+    // The user of `data` if it is limited by the user permits.
+    fn limited_user(&self, data: &Data) -> Option<String> {
+        match self.max_user_permits {
+            0 => None,
+            _ => data.user(),
+        }
+    }
+
+    // Whether the user of `data` is running as many entries as the user permits.
+    fn is_user_blocked(&self, running: &HashMap<String, usize>, data: &Data) -> bool {
+        match self.limited_user(data) {
+            None => false,
+            Some(user) => matches!(running.get(&user), Some(n) if *n >= self.max_user_permits),
+        }
+    }
+
+    // Acquire a permit without waiting, if no one is waiting for it.
+    fn try_acquire(&self, data: &Data) -> Option<(OwnedSemaphorePermit, Option<String>)> {
+        let queue = self.queue.lock();
+        let mut fairness = self.fairness.lock();
+        let mut running = self.user_permits.lock();
+        if self.is_user_blocked(&running, data) {
+            return None;
+        }
+        let blocked = |data: &Data| self.is_user_blocked(&running, data);
+        if fairness.head(&*queue, blocked).is_some() {
+            return None;
+        }
+        let permit = self.semaphore.clone().try_acquire_owned().ok()?;
+        let user = self.limited_user(data);
+        if let Some(user) = &user {
+            *running.entry(user.clone()).or_default() += 1;
+        }
+        Some((permit, user))
+    }
+
+    // Grant a permit to the waiter of `key` if it is the head of the queue.
+    fn try_dispatch(&self, key: &Data::Key) -> Option<(OwnedSemaphorePermit, Option<String>)> {
+        let mut queue = self.queue.lock();
+        let mut fairness = self.fairness.lock();
+        let mut running = self.user_permits.lock();
+        let blocked = |data: &Data| self.is_user_blocked(&running, data);
+        if fairness.head(&*queue, blocked).as_ref() != Some(key) {
+            return None;
+        }
+        let permit = self.semaphore.clone().try_acquire_owned().ok()?;
+        drop(fairness);
+
+        let inner = queue.remove(key);
+        let queue_len = queue.len();
+        drop(queue);
+
+        let user = inner
+            .as_ref()
+            .and_then(|inner| self.limited_user(inner.data.as_ref()));
+        if let Some(user) = &user {
+            *running.entry(user.clone()).or_default() += 1;
+        }
+        drop(running);
+
+        set_session_queued_queries(queue_len);
+        if let Some(inner) = inner {
+            let wait_time = inner.instant.elapsed();
+            inner.data.exit_wait_pending(wait_time);
+            self.metrics.record_dequeued(wait_time);
+        }
+        // There may be more permits for the next waiter.
+        self.wake_next();
+        Some((permit, user))
+    }
+
+    // Release the running entry of `user` after its permit is released.
+    fn release_user(&self, user: &str) {
+        let mut running = self.user_permits.lock();
+        if let Some(count) = running.get_mut(user) {
+            *count -= 1;
+            if *count == 0 {
+                running.remove(user);
+            }
+        }
+    }
+
+    // Wake the head of the queue to try to acquire a permit.
+    fn wake_next(&self) {
+        let waker = {
+            let queue = self.queue.lock();
+            let mut fairness = self.fairness.lock();
+            let running = self.user_permits.lock();
+            let blocked = |data: &Data| self.is_user_blocked(&running, data);
+            fairness
+                .head(&queue, blocked)
+                .and_then(|key| queue.get(&key).map(|inner| inner.waker.clone()))
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<Data> QueueManager<Data>
+where Data: QueueData + serde::Serialize + serde::de::DeserializeOwned
+{
+    /// Serialize the pending entries of the queue, so that they can be restored
+    /// by [`QueueManager::restore`] after restarting.
+    ///
+    /// The entries of the queries queue hold their query context and can not be
+    /// serialized, the queries queue is kept across restarts by `PersistentQueueManager`.
+    pub fn checkpoint(&self) -> Result<Vec<u8>> {
+        let entries = {
+            let queue = self.queue.lock();
+            let mut restored = self.restored.lock();
+            Self::expire_restored(&mut restored);
+            // The restored entries which have not acquired again are kept as well.
+            let mut entries = queue
+                .values()
+                .map(|inner| (&inner.data, inner.instant))
+                .chain(restored.values().map(|entry| (&entry.data, entry.instant)))
+                .collect::<Vec<_>>();
+            entries.sort_by_key(|(_, instant)| *instant);
+            bincode_v1::serialize(&QueueCheckpoint {
+                permits: self.permits,
+                entries: entries
+                    .into_iter()
+                    .map(|(data, instant)| QueueEntryCheckpoint {
+                        data: data.as_ref(),
+                        waited: instant.elapsed(),
+                    })
+                    .collect(),
+            })
+        };
+
+        entries.map_err(|cause| {
+            ErrorCode::Internal(format!("Cannot checkpoint the queue, cause: {:?}", cause))
+        })
+    }
+
+    /// Reconstruct a manager from the state serialized by [`QueueManager::checkpoint`].
+    ///
+    /// The restored entries are not in the queue, so they do not hold back the other
+    /// waiters. When a restored entry acquires again with the same key, it waits from
+    /// its original time of waiting, see [`QueueManager::restored`].
+    pub fn restore(bytes: &[u8]) -> Result<Self> {
+        Self::restore_with_user_permits(bytes, 0)
+    }
+
+    /// Same as [`QueueManager::restore`], but a user can run at most `user_permits`
+    /// entries at once, 0 for no limit. The limit is not a part of the checkpoint.
+    pub fn restore_with_user_permits(bytes: &[u8], user_permits: usize) -> Result<Self> {
+        let checkpoint: QueueCheckpoint<Data> =
+            bincode_v1::deserialize(bytes).map_err(|cause| {
+                ErrorCode::Internal(format!("Cannot restore the queue, cause: {:?}", cause))
+            })?;
+
+        let manager = Self::new(checkpoint.permits, user_permits);
+        let now = Instant::now();
+        let mut restored = manager.restored.lock();
+        for entry in checkpoint.entries {
+            restored.insert(entry.data.get_key(), RestoredEntry {
+                data: Arc::new(entry.data),
+                instant: now.checked_sub(entry.waited).unwrap_or(now),
+            });
+        }
+        drop(restored);
+        Ok(manager)
+    }
 }
 
 pub struct AcquireQueueGuard {
-    #[allow(dead_code)]
     permit: Option<OwnedSemaphorePermit>,
+    // Release the user and wake the next waiter after the permit is released.
+    wake_next: Option<Box<dyn FnOnce() + Send + Sync>>,
 }
 
 impl Drop for AcquireQueueGuard {
     fn drop(&mut self) {
-        if self.permit.is_some() {
+        if let Some(permit) = self.permit.take() {
+            drop(permit);
             dec_session_running_acquired_queries();
         }
+        if let Some(wake_next) = self.wake_next.take() {
+            wake_next();
+        }
     }
 }
 
 impl AcquireQueueGuard {
     pub fn create(permit: Option<OwnedSemaphorePermit>) -> Self {
-        AcquireQueueGuard { permit }
+        AcquireQueueGuard {
+            permit,
+            wake_next: None,
+        }
+    }
+
+    fn create_with_queue<Data: QueueData>(
+        (permit, user): (OwnedSemaphorePermit, Option<String>),
+        manager: Arc<QueueManager<Data>>,
+    ) -> Self {
+        AcquireQueueGuard {
+            permit: Some(permit),
+            wake_next: Some(Box::new(move || {
+                if let Some(user) = user {
+                    manager.release_user(&user);
+                }
+                manager.wake_next()
+            })),
+        }
     }
 }
 
 pin_project! {
-    pub struct AcquireQueueFuture<Data: QueueData, T>
-where T: Future<Output =  std::result::Result< std::result::Result<OwnedSemaphorePermit, AcquireError>, Elapsed>>
-{
-    #[pin]
-    inner: T,
+    pub struct AcquireQueueFuture<Data: QueueData> {
+        #[pin]
+        timeout: Sleep,
+        // Fails the waiter which has waited in the queue for too long.
+        #[pin]
+        max_wait: Option<Sleep>,
 
-
-    has_pending: bool,
-    is_abort: Arc<AtomicBool>,
-    data: Option<Arc<Data>>,
-    key: Option<Data::Key>,
-    manager: Arc<QueueManager<Data>>,
-}
+        waited: Duration,
+        is_abort: Arc<AtomicBool>,
+        data: Option<Arc<Data>>,
+        key: Option<Data::Key>,
+        manager: Arc<QueueManager<Data>>,
+    }
 }
 
-impl<Data: QueueData, T> AcquireQueueFuture<Data, T>
-where T: Future<
-        Output = std::result::Result<
-            std::result::Result<OwnedSemaphorePermit, AcquireError>,
-            Elapsed,
-        >,
-    >
-{
-    pub fn create(data: Arc<Data>, inner: T, mgr: Arc<QueueManager<Data>>) -> Self {
+impl<Data: QueueData> AcquireQueueFuture<Data> {
+    pub fn create(data: Arc<Data>, timeout: Sleep, mgr: Arc<QueueManager<Data>>) -> Self {
         AcquireQueueFuture {
-            inner,
+            timeout,
+            max_wait: None,
             key: None,
             manager: mgr,
             data: Some(data),
-            has_pending: false,
+            waited: Duration::ZERO,
             is_abort: Arc::new(AtomicBool::new(false)),
         }
     }
+
+    pub fn with_waited(mut self, waited: Duration) -> Self {
+        self.waited = waited;
+        self
+    }
+
+    pub fn with_max_wait(mut self, max_wait: Option<Duration>) -> Self {
+        self.max_wait = max_wait.map(tokio::time::sleep);
+        self
+    }
 }
 
-impl<Data: QueueData, T> Future for AcquireQueueFuture<Data, T>
-where T: Future<
-        Output = std::result::Result<
-            std::result::Result<OwnedSemaphorePermit, AcquireError>,
-            Elapsed,
-        >,
-    >
-{
+impl<Data: QueueData> Future for AcquireQueueFuture<Data> {
     type Output = Result<AcquireQueueGuard>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
@@ -279,38 +943,70 @@ where T: Future<
             return Poll::Ready(Err(Data::remove_error_message(this.key.take())));
         }
 
-        match this.inner.poll(cx) {
-            Poll::Ready(res) => {
-                if let Some(key) = this.key.take() {
-                    if this.manager.remove_entity(&key).is_none() {
-                        return Poll::Ready(Err(Data::remove_error_message(Some(key))));
-                    }
+        let key = match this.key {
+            Some(key) => {
+                if !this.manager.update_waker(key, cx.waker()) {
+                    return Poll::Ready(Err(Data::remove_error_message(this.key.take())));
                 }
-
-                Poll::Ready(match res {
-                    Ok(Ok(v)) => Ok(AcquireQueueGuard::create(Some(v))),
-                    Ok(Err(_)) => Err(ErrorCode::TokioError("acquire queue failure.")),
-                    Err(_elapsed) => Err(ErrorCode::Timeout("query queuing timeout")),
-                })
+                key.clone()
             }
-            Poll::Pending => {
-                if !*this.has_pending {
-                    *this.has_pending = true;
+            None => {
+                let Some(data) = this.data.take() else {
+                    return Poll::Ready(Err(ErrorCode::TokioError("acquire queue failure.")));
+                };
+
+                // A restored entry waits from its original time of waiting.
+                let now = Instant::now();
+                let mut instant = now.checked_sub(*this.waited).unwrap_or(now);
+                if let Some(restored) = this.manager.take_restored(&data.get_key()) {
+                    instant = instant.min(restored);
                 }
 
-                if let Some(data) = this.data.take() {
-                    let waker = cx.waker().clone();
-                    *this.key = Some(this.manager.add_entity(Inner {
-                        data,
-                        waker,
-                        instant: Instant::now(),
-                        is_abort: this.is_abort.clone(),
-                    }));
+                // Acquire directly if no one is waiting.
+                if let Some(permit) = this.manager.try_acquire(&data) {
+                    return Poll::Ready(Ok(AcquireQueueGuard::create_with_queue(
+                        permit,
+                        this.manager.clone(),
+                    )));
                 }
 
-                Poll::Pending
+                let key = this.manager.add_entity(Inner {
+                    data,
+                    waker: cx.waker().clone(),
+                    instant,
+                    is_abort: this.is_abort.clone(),
+                    virtual_finish_time: 0.0,
+                });
+                *this.key = Some(key.clone());
+                key
+            }
+        };
+
+        if let Some(permit) = this.manager.try_dispatch(&key) {
+            this.key.take();
+            return Poll::Ready(Ok(AcquireQueueGuard::create_with_queue(
+                permit,
+                this.manager.clone(),
+            )));
+        }
+
+        if this.timeout.poll(cx).is_ready() {
+            if let Some(key) = this.key.take() {
+                this.manager.remove_entity(&key);
+            }
+            return Poll::Ready(Err(ErrorCode::Timeout("query queuing timeout")));
+        }
+
+        if let Some(max_wait) = this.max_wait.as_pin_mut() {
+            if max_wait.poll(cx).is_ready() {
+                if let Some(key) = this.key.take() {
+                    this.manager.remove_entity(&key);
+                }
+                return Poll::Ready(Err(ErrorCode::Timeout("query waited too long in queue")));
             }
         }
+
+        Poll::Pending
     }
 }
 
@@ -322,6 +1018,13 @@ pub struct QueryEntry {
     pub user_info: UserInfo,
     pub timeout: Duration,
     pub need_acquire_to_queue: bool,
+    // The queries of a user share the queue with the other users by the weight.
+    pub group: String,
+    pub weight: f64,
+    // The queries of a higher priority are granted before the others.
+    pub priority: u8,
+    // The max time the query waits in the queue, in milliseconds.
+    pub max_queue_wait_ms: Option<u64>,
 }
 
 impl QueryEntry {
@@ -331,13 +1034,21 @@ impl QueryEntry {
         need_acquire_to_queue: bool,
     ) -> Result<QueryEntry> {
         let settings = ctx.get_settings();
+        let user_info = ctx.get_current_user()?;
         Ok(QueryEntry {
             ctx: ctx.clone(),
             need_acquire_to_queue,
             query_id: ctx.get_id(),
             create_time: ctx.get_created_time(),
             sql: plan_extras.statement.to_mask_sql(),
-            user_info: ctx.get_current_user()?,
+            group: user_info.name.clone(),
+            weight: settings.get_query_queue_weight()? as f64,
+            priority: settings.get_query_queue_priority()?,
+            max_queue_wait_ms: match settings.get_max_query_queue_wait_ms()? {
+                0 => None,
+                wait => Some(wait),
+            },
+            user_info,
             timeout: match settings.get_statement_queued_timeout()? {
                 0 => Duration::from_secs(60 * 60 * 24 * 365 * 35),
                 timeout => Duration::from_secs(timeout),
@@ -470,6 +1181,26 @@ impl QueueData for QueryEntry {
             .set_status_info(format!("resource scheduled(elapsed: {:?})", wait_time).as_str());
         self.ctx.set_query_queued_duration(wait_time)
     }
+
+    fn group(&self) -> String {
+        self.group.clone()
+    }
+
+    fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    fn priority(&self) -> u8 {
+        self.priority
+    }
+
+    fn user(&self) -> Option<String> {
+        Some(self.user_info.name.clone())
+    }
+
+    fn max_wait(&self) -> Option<Duration> {
+        self.max_queue_wait_ms.map(Duration::from_millis)
+    }
 }
 
 pub type QueriesQueueManager = QueueManager<QueryEntry>;
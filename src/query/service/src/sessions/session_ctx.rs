@@ -37,6 +37,11 @@ use parking_lot::RwLock;
 use super::SessionType;
 use crate::sessions::QueryContextShared;
 
+// The number of distinct queries tracked by `SessionContext::record_result_cache_miss`.
+const MAX_RESULT_CACHE_MISSES: usize = 128;
+// A query is hot once it missed the query result cache this many times.
+const HOT_QUERY_MIN_MISSES: usize = 2;
+
 pub struct SessionContext {
     abort: AtomicBool,
     settings: Arc<Settings>,
@@ -72,6 +77,9 @@ pub struct SessionContext {
     /// We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     /// query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    /// The queries of this session that missed the query result cache,
+    /// `query -> (number of misses, ids of the tables read by the query)`.
+    result_cache_misses: RwLock<HashMap<String, (usize, HashSet<u64>)>>,
     // Used in set variables inside session
     variables: Arc<RwLock<HashMap<String, Scalar>>>,
     typ: SessionType,
@@ -102,6 +110,7 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            result_cache_misses: Default::default(),
             variables: Default::default(),
             typ,
             txn_mgr: Mutex::new(TxnManager::init()),
@@ -321,6 +330,26 @@ impl SessionContext {
         HashSet::from_iter(lock.iter().map(|result| result.clone().0))
     }
 
+    /// Record that `query` missed the query result cache, returns the other hot queries,
+    /// which missed the cache at least `HOT_QUERY_MIN_MISSES` times and read any of `tables`.
+    pub fn record_result_cache_miss(&self, query: &str, tables: HashSet<u64>) -> Vec<String> {
+        let mut lock = self.result_cache_misses.write();
+        if lock.len() < MAX_RESULT_CACHE_MISSES || lock.contains_key(query) {
+            let (misses, read_tables) = lock.entry(query.to_string()).or_default();
+            *misses += 1;
+            *read_tables = tables.clone();
+        }
+
+        lock.iter()
+            .filter(|(hot_query, (misses, read_tables))| {
+                hot_query.as_str() != query
+                    && *misses >= HOT_QUERY_MIN_MISSES
+                    && !read_tables.is_disjoint(&tables)
+            })
+            .map(|(hot_query, _)| hot_query.clone())
+            .collect()
+    }
+
     pub fn txn_mgr(&self) -> TxnManagerRef {
         self.txn_mgr.lock().clone()
     }
@@ -28,11 +28,13 @@ use databend_common_base::base::GlobalUniqName;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_storages_common_cache::TempDir;
 use databend_storages_common_cache::TempPath;
+use log::warn;
 use opendal::Buffer;
 use opendal::Operator;
 use parking_lot::RwLock;
@@ -314,6 +316,35 @@ impl Spiller {
         self.read_unmanage_spilled_file(location, &layout).await
     }
 
+    /// Delete a file managed by this spiller once it has been read back, so the spilled data
+    /// doesn't stay in the storage until the end of the query.
+    pub async fn delete_spilled_file(&self, location: &Location) -> Result<()> {
+        self.private_spilled_files.write().remove(location);
+        self.ctx.remove_spill_file(location);
+        match location {
+            // The local file is removed once its last `TempPath` is dropped.
+            Location::Local(_) => {}
+            Location::Remote(loc) => self.operator.delete(loc).await?,
+        }
+        Ok(())
+    }
+
+    /// Delete the files in the background, for the callers which can't wait for it, e.g. the
+    /// processor dropped on an error or the cancellation of the query.
+    pub fn delete_spilled_files_in_background(&self, locations: Vec<Location>) {
+        if locations.is_empty() {
+            return;
+        }
+        let spiller = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            for location in locations {
+                if let Err(cause) = spiller.delete_spilled_file(&location).await {
+                    warn!("Delete spilled file {:?} failed: {:?}", location, cause);
+                }
+            }
+        });
+    }
+
     async fn read_unmanage_spilled_file(
         &self,
         location: &Location,
@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::query;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_async_aggregate() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    fixture
+        .execute_command("SET enable_async_aggregate = 1")
+        .await?;
+    let blocks = query(
+        &fixture,
+        "SELECT number % 3 AS k, count(*) AS c FROM numbers(30) GROUP BY k",
+    )
+    .await?;
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].num_rows(), 1);
+    let query_id = blocks[0]
+        .get_by_offset(0)
+        .to_column(1)
+        .index(0)
+        .unwrap()
+        .as_string()
+        .unwrap()
+        .to_string();
+
+    // Wait for the background query to write its result into the result cache.
+    let sql = format!("SELECT * FROM RESULT_SCAN('{query_id}') ORDER BY k");
+    let mut result = query(&fixture, &sql).await;
+    for _ in 0..50 {
+        if matches!(&result, Ok(blocks) if !blocks.is_empty()) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        result = query(&fixture, &sql).await;
+    }
+
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 0        | 10       |",
+        "| 1        | 10       |",
+        "| 2        | 10       |",
+        "+----------+----------+",
+    ];
+    assert_blocks_eq(expected, &result?);
+
+    Ok(())
+}
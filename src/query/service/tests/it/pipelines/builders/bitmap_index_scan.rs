@@ -0,0 +1,179 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::plan::bitmap_index_lookup;
+use databend_common_catalog::plan::BitmapIndex;
+use databend_common_exception::Result;
+use databend_common_expression::types::Bitmap;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_storages_fuse::FuseBlockPartInfo;
+use databend_query::pipelines::builders::prune_partitions_by_bitmap_index;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+use crate::pipelines::builders::query;
+
+/// A bitmap index which keeps the values of the rows of each block, the bitmaps are built
+/// when they are looked up.
+struct MockBitmapIndex {
+    blocks: BTreeMap<String, Vec<Scalar>>,
+}
+
+impl BitmapIndex for MockBitmapIndex {
+    fn lookup(&self, value: &Scalar) -> Result<HashMap<String, Bitmap>> {
+        Ok(self
+            .blocks
+            .iter()
+            .filter(|(_, values)| values.contains(value))
+            .map(|(location, values)| {
+                let bitmap = values.iter().map(|v| v == value).collect::<Bitmap>();
+                (location.clone(), bitmap)
+            })
+            .collect())
+    }
+}
+
+fn string(scalar: ScalarRef) -> String {
+    match scalar {
+        ScalarRef::String(s) => s.to_string(),
+        _ => unreachable!("String expected"),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_bitmap_index_scan() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (id INT, status STRING)"))
+        .await?;
+    // Each insert writes a block of 10 rows, only the blocks 3 and 7 hold an 'active' row
+    // and only the block 5 holds a 'pending' row.
+    for i in 0..10 {
+        let values = (0..10)
+            .map(|j| {
+                let status = match (i, j) {
+                    (3 | 7, 4) => "active",
+                    (5, 0) => "pending",
+                    _ if j % 2 == 0 => "closed",
+                    _ => "archived",
+                };
+                format!("({}, '{status}')", i * 10 + j)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        fixture
+            .execute_command(&format!("INSERT INTO {db}.t VALUES {values}"))
+            .await?;
+    }
+
+    // The table has no bitmap index, the equality is looked up in the bloom filters.
+    let sql = format!("SELECT id FROM {db}.t WHERE status = 'active' ORDER BY id");
+    let ctx = fixture.new_query_ctx().await?;
+    let plan = physical_plan(ctx.clone(), &sql).await?;
+    assert!(find_plan(&plan, |plan| matches!(
+        plan,
+        PhysicalPlan::BitmapIndexScan(_)
+    ))
+    .is_none());
+    assert!(find_plan(&plan, |plan| matches!(plan, PhysicalPlan::BloomLookup(_))).is_some());
+
+    // Build the bitmap index from the values of the rows of each block.
+    let blocks = query(
+        &fixture,
+        &format!("SELECT _block_name, status FROM {db}.t ORDER BY id"),
+    )
+    .await?;
+    let mut index = MockBitmapIndex {
+        blocks: BTreeMap::new(),
+    };
+    for block in &blocks {
+        for row in 0..block.num_rows() {
+            let value = |i: usize| block.get_by_offset(i).value.index(row).unwrap();
+            index
+                .blocks
+                .entry(string(value(0)))
+                .or_default()
+                .push(Scalar::String(string(value(1))));
+        }
+    }
+    assert_eq!(index.blocks.len(), 10);
+
+    let table = ctx.get_table("default", &db, "t").await?;
+    let (_, parts) = table.read_partitions(ctx.clone(), None, true).await?;
+    assert_eq!(parts.len(), 10);
+    let active = Scalar::String("active".to_string());
+    let pending = Scalar::String("pending".to_string());
+    for (values, expected_skip_rate) in [
+        (vec![active.clone()], 0.8),
+        (vec![active.clone(), pending.clone()], 0.7),
+        (vec![Scalar::String("unknown".to_string())], 1.0),
+    ] {
+        let pruned = prune_partitions_by_bitmap_index(&parts, &index, &values)?;
+        let skip_rate = 1.0 - pruned.len() as f64 / parts.len() as f64;
+        assert!((skip_rate - expected_skip_rate).abs() < 1e-9);
+    }
+
+    // The blocks kept are the blocks of the matching rows, one row of each.
+    let matched = bitmap_index_lookup(&index, &[active.clone()])?;
+    assert_eq!(matched.len(), 2);
+    assert!(matched.values().all(|bitmap| bitmap.true_count() == 1));
+    let pruned = prune_partitions_by_bitmap_index(&parts, &index, &[active])?;
+    let locations = pruned
+        .partitions
+        .iter()
+        .map(|part| Ok(FuseBlockPartInfo::from_part(part)?.location.clone()))
+        .collect::<Result<HashSet<_>>>()?;
+    let blocks = query(
+        &fixture,
+        &format!("SELECT _block_name FROM {db}.t WHERE status = 'active'"),
+    )
+    .await?;
+    let expected = blocks
+        .iter()
+        .flat_map(|block| {
+            (0..block.num_rows())
+                .map(|row| string(block.get_by_offset(0).value.index(row).unwrap()))
+                .collect::<Vec<_>>()
+        })
+        .collect::<HashSet<_>>();
+    assert_eq!(locations, expected);
+
+    // The bitmaps of the values are ORed, they cover the even rows of all the blocks.
+    let matched = bitmap_index_lookup(&index, &[
+        Scalar::String("active".to_string()),
+        pending,
+        Scalar::String("closed".to_string()),
+    ])?;
+    assert_eq!(matched.len(), 10);
+    assert_eq!(
+        matched
+            .values()
+            .map(|bitmap| bitmap.true_count())
+            .sum::<usize>(),
+        50
+    );
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::plan::sample_block;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::ScalarRef;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+use crate::pipelines::builders::query;
+
+// The `(block name, a)` of the rows returned by `sql`.
+async fn rows(fixture: &TestFixture, sql: &str) -> Result<BTreeSet<(String, i32)>> {
+    let mut rows = BTreeSet::new();
+    for block in query(fixture, sql).await? {
+        for row in 0..block.num_rows() {
+            let value = |i: usize| block.get_by_offset(i).value.index(row).unwrap();
+            let (ScalarRef::String(location), ScalarRef::Number(NumberScalar::Int32(a))) =
+                (value(0), value(1))
+            else {
+                unreachable!("String and number expected")
+            };
+            rows.insert((location.to_string(), a));
+        }
+    }
+    Ok(rows)
+}
+
+#[test]
+fn test_sample_block_rate() {
+    let blocks = (0..10000)
+        .map(|i| format!("1/10/_b/{i:032x}_v2.parquet"))
+        .collect::<Vec<_>>();
+    for probability in [0.1, 0.5, 0.9] {
+        for seed in [0, 42] {
+            let kept = blocks
+                .iter()
+                .filter(|block| sample_block(block.as_str(), probability, seed))
+                .count();
+            let skip_rate = 1.0 - kept as f64 / blocks.len() as f64;
+            assert!(
+                (skip_rate - (1.0 - probability)).abs() < 0.02,
+                "skip rate {skip_rate} of probability {probability} and seed {seed}"
+            );
+        }
+    }
+
+    // The same seed keeps the same blocks, another seed keeps others.
+    let kept = |seed| {
+        blocks
+            .iter()
+            .filter(|block| sample_block(block.as_str(), 0.5, seed))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(kept(7), kept(7));
+    assert_ne!(kept(7), kept(8));
+
+    assert!(blocks
+        .iter()
+        .all(|block| sample_block(block.as_str(), 1.0, 7)));
+    assert!(!blocks
+        .iter()
+        .any(|block| sample_block(block.as_str(), 0.0, 7)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_block_sample() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (a INT NOT NULL)"))
+        .await?;
+    // Each insert writes a block of 10 rows.
+    for i in 0..20 {
+        fixture
+            .execute_command(&format!(
+                "INSERT INTO {db}.t SELECT number + {} FROM numbers(10)",
+                i * 10
+            ))
+            .await?;
+    }
+
+    let sql = format!("SELECT _block_name, a FROM {db}.t TABLESAMPLE SYSTEM (50) REPEATABLE (42)");
+    let ctx = fixture.new_query_ctx().await?;
+    let plan = physical_plan(ctx, &sql).await?;
+    let Some(PhysicalPlan::BlockSample(block_sample)) =
+        find_plan(&plan, |plan| matches!(plan, PhysicalPlan::BlockSample(_)))
+    else {
+        unreachable!("BlockSample expected")
+    };
+    assert_eq!(block_sample.probability, 0.5);
+    assert_eq!(block_sample.seed, 42);
+
+    // Whole blocks are kept or skipped by the seed.
+    let all = rows(&fixture, &format!("SELECT _block_name, a FROM {db}.t")).await?;
+    assert_eq!(all.len(), 200);
+    let expected = all
+        .iter()
+        .filter(|(location, _)| sample_block(location.as_str(), 0.5, 42))
+        .cloned()
+        .collect::<BTreeSet<_>>();
+    let sampled = rows(&fixture, &sql).await?;
+    assert_eq!(sampled, expected);
+    assert_eq!(sampled.len() % 10, 0);
+    assert_eq!(rows(&fixture, &sql).await?, sampled);
+
+    let sql = format!("SELECT _block_name, a FROM {db}.t TABLESAMPLE SYSTEM (100)");
+    assert_eq!(rows(&fixture, &sql).await?, all);
+    let sql = format!("SELECT _block_name, a FROM {db}.t TABLESAMPLE SYSTEM (0)");
+    assert!(rows(&fixture, &sql).await?.is_empty());
+
+    Ok(())
+}
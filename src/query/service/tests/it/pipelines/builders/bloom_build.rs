@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_storages_fuse::io::BloomBlockFilterReader;
+use databend_common_storages_fuse::io::TableMetaLocationGenerator;
+use databend_common_storages_fuse::FuseTable;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::TestFixture;
+use databend_storages_common_index::filters::BlockFilter;
+use databend_storages_common_index::filters::Filter;
+use databend_storages_common_index::BloomIndex;
+use databend_storages_common_table_meta::meta::Versioned;
+
+use crate::pipelines::builders::query;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_bloom_build_on_insert() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!(
+            "CREATE TABLE {db}.t (a int, b string) bloom_index_columns='a'"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "INSERT INTO {db}.t SELECT number, to_string(number) FROM numbers(100)"
+        ))
+        .await?;
+
+    // The bloom index files of the blocks, the rest under the prefix is the sidecar file.
+    let blocks = query(
+        &fixture,
+        &format!("SELECT block_location FROM fuse_block('{db}', 't')"),
+    )
+    .await?;
+    let mut block_blooms = HashSet::new();
+    for block in blocks {
+        let column = block.get_by_offset(0).to_column(block.num_rows());
+        let column = StringType::try_downcast_column(&column).unwrap();
+        for location in column.iter() {
+            block_blooms.insert(
+                TableMetaLocationGenerator::gen_bloom_index_location_from_block_location(location),
+            );
+        }
+    }
+    assert!(!block_blooms.is_empty());
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx.get_table("default", &db, "t").await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let operator = fuse_table.get_operator();
+    let prefix = fuse_table
+        .meta_location_generator()
+        .block_bloom_index_prefix();
+    let sidecars = operator
+        .list(prefix)
+        .await?
+        .into_iter()
+        .map(|entry| entry.path().to_string())
+        .filter(|path| !block_blooms.contains(path))
+        .collect::<Vec<_>>();
+    assert_eq!(sidecars.len(), 1);
+
+    let size = operator.stat(&sidecars[0]).await?.content_length();
+    let location = (sidecars[0].clone(), BlockFilter::VERSION);
+    let columns = vec!["Bloom(a)".to_string(), "Bloom(b)".to_string()];
+    let filter = location.read_block_filter(operator, &columns, size).await?;
+
+    // Only the bloom-index column is in the sidecar file.
+    assert_eq!(filter.filter_schema.num_fields(), 1);
+    assert_eq!(filter.filter_schema.field(0).name(), "Bloom(a)");
+
+    let func_ctx = FunctionContext::default();
+    let data_type = DataType::Number(NumberDataType::Int32).wrap_nullable();
+    for value in [0, 42, 99] {
+        let scalar = Scalar::Number(NumberScalar::Int32(value));
+        let digest = BloomIndex::calculate_scalar_digest(&func_ctx, &scalar, &data_type)?;
+        assert!(filter.filters[0].contains_digest(digest));
+    }
+    Ok(())
+}
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_query::pipelines::builders::prune_partitions_by_bloom_lookup;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+use crate::pipelines::builders::query;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_bloom_lookup() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (pk INT, v STRING)"))
+        .await?;
+    // Each insert writes a segment of the keys i, i + 10, .., i + 90, the min/max ranges of
+    // all the segments contain 42, only the bloom filters tell the segments apart.
+    for i in 0..10 {
+        let values = (0..10)
+            .map(|j| format!("({}, 'v{}')", i + j * 10, i + j * 10))
+            .collect::<Vec<_>>()
+            .join(", ");
+        fixture
+            .execute_command(&format!("INSERT INTO {db}.t VALUES {values}"))
+            .await?;
+    }
+
+    let sql = format!("SELECT pk, v FROM {db}.t WHERE pk = 42");
+    let ctx = fixture.new_query_ctx().await?;
+    let plan = physical_plan(ctx.clone(), &sql).await?;
+    let Some(PhysicalPlan::BloomLookup(lookup)) =
+        find_plan(&plan, |plan| matches!(plan, PhysicalPlan::BloomLookup(_)))
+    else {
+        unreachable!("BloomLookup expected")
+    };
+    assert_eq!(lookup.lookup_value.to_string(), "42");
+    assert!(matches!(
+        lookup.inner_scan.as_ref(),
+        PhysicalPlan::TableScan(_)
+    ));
+
+    // Without the lookup all the segments are read, with it only the segment of the key 42.
+    let table = ctx.get_table("default", &db, "t").await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let segments = fuse_table
+        .bloom_lookup_segments(ctx.clone(), "pk", &lookup.lookup_value)
+        .await?;
+    assert_eq!(segments.len(), 1);
+    let (_, parts) = table.read_partitions(ctx.clone(), None, true).await?;
+    assert_eq!(parts.len(), 10);
+    let parts = prune_partitions_by_bloom_lookup(
+        ctx.clone(),
+        fuse_table,
+        "pk",
+        &lookup.lookup_value,
+        &parts,
+    )
+    .await?;
+    assert_eq!(parts.len(), 1);
+
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 42       | 'v42'    |",
+        "+----------+----------+",
+    ];
+    assert_blocks_eq(expected, &query(&fixture, &sql).await?);
+
+    // A range predicate is not a lookup.
+    let sql = format!("SELECT pk FROM {db}.t WHERE pk > 42");
+    let plan = physical_plan(ctx, &sql).await?;
+    assert!(find_plan(&plan, |plan| matches!(plan, PhysicalPlan::BloomLookup(_))).is_none());
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Expr;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_pipeline_core::processors::connect;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::pipelines::processors::transforms::TransformConditionalLimit;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+
+// Blocks of 10 rows, the second column is true for the even values of the first column.
+fn blocks(num_blocks: i32) -> Vec<DataBlock> {
+    (0..num_blocks)
+        .map(|i| {
+            let values = (i * 10..(i + 1) * 10).collect::<Vec<_>>();
+            let flags = values.iter().map(|v| v % 2 == 0).collect::<Vec<_>>();
+            DataBlock::new_from_columns(vec![
+                Int32Type::from_data(values),
+                BooleanType::from_data(flags),
+            ])
+        })
+        .collect()
+}
+
+// Run the processor over the blocks, returns the number of blocks read and the output values.
+fn run_conditional_limit(blocks: Vec<DataBlock>, limit: usize) -> Result<(usize, Vec<i32>)> {
+    let input = InputPort::create();
+    let output = OutputPort::create();
+    let condition = Expr::ColumnRef {
+        span: None,
+        id: 1,
+        data_type: DataType::Boolean,
+        display_name: "flag".to_string(),
+    };
+    let mut processor = TransformConditionalLimit::create(
+        input.clone(),
+        output.clone(),
+        condition,
+        limit,
+        FunctionContext::default(),
+        65536,
+    );
+
+    let upstream_output = OutputPort::create();
+    let downstream_input = InputPort::create();
+    unsafe {
+        connect(&input, &upstream_output);
+        connect(&downstream_input, &output);
+    }
+    downstream_input.set_need_data();
+
+    let mut num_read = 0;
+    let mut values = vec![];
+    loop {
+        match processor.event()? {
+            Event::NeedData => match blocks.get(num_read) {
+                Some(block) => {
+                    upstream_output.push_data(Ok(block.clone()));
+                    num_read += 1;
+                }
+                None => upstream_output.finish(),
+            },
+            Event::Sync => processor.process()?,
+            Event::NeedConsume => {
+                let block = downstream_input.pull_data().unwrap()?;
+                let column = block.get_by_offset(0).to_column(block.num_rows());
+                values.extend(Int32Type::try_downcast_column(&column).unwrap().iter());
+                downstream_input.set_need_data();
+            }
+            Event::Finished => break,
+            _ => unreachable!(),
+        }
+    }
+    assert!(input.is_finished());
+    Ok((num_read, values))
+}
+
+#[test]
+fn test_conditional_limit_early_termination() -> Result<()> {
+    // 5 rows of each block satisfy the condition, 7 rows are collected from 2 blocks.
+    let (num_read, values) = run_conditional_limit(blocks(10), 7)?;
+    assert_eq!(num_read, 2);
+    assert_eq!(values, vec![0, 2, 4, 6, 8, 10, 12]);
+
+    // Not enough rows satisfy the condition, all the blocks are read.
+    let (num_read, values) = run_conditional_limit(blocks(3), 100)?;
+    assert_eq!(num_read, 3);
+    assert_eq!(values.len(), 15);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_build_conditional_limit() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let sql = "SELECT number % 10 AS k, count(*) AS c FROM numbers(1000) GROUP BY k HAVING c > 50 LIMIT 3 OFFSET 2";
+    let plan = physical_plan(ctx.clone(), sql).await?;
+    match find_plan(&plan, |plan| {
+        matches!(plan, PhysicalPlan::ConditionalLimit(_))
+    }) {
+        Some(PhysicalPlan::ConditionalLimit(conditional_limit)) => {
+            assert_eq!(conditional_limit.limit, 5);
+            assert!(matches!(
+                conditional_limit.input.as_ref(),
+                PhysicalPlan::AggregateFinal(_)
+            ));
+        }
+        _ => panic!("ConditionalLimit plan expected"),
+    }
+
+    // Without LIMIT all the groups are needed.
+    let sql = "SELECT number % 10 AS k, count(*) AS c FROM numbers(1000) GROUP BY k HAVING c > 50";
+    let plan = physical_plan(ctx, sql).await?;
+    assert!(find_plan(&plan, |plan| matches!(
+        plan,
+        PhysicalPlan::ConditionalLimit(_)
+    ))
+    .is_none());
+    Ok(())
+}
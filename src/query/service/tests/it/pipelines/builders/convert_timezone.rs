@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_sql::executor::physical_plans::TzDirection;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::pipelines::processors::transforms::TransformConvertTimezone;
+use databend_query::test_kits::TestFixture;
+use jiff::Timestamp;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan_with;
+
+fn micros(ts: &str) -> i64 {
+    ts.parse::<Timestamp>().unwrap().as_microsecond()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_convert_timezone_plan() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (a int, ts timestamp)"))
+        .await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let sql = format!("SELECT a, ts FROM {db}.t");
+    let plan = physical_plan_with(ctx.clone(), &sql, |builder| {
+        builder.set_convert_timezone(Some("America/New_York".to_string()))
+    })
+    .await?;
+    let Some(PhysicalPlan::ConvertTimezone(convert)) = find_plan(&plan, |plan| {
+        matches!(plan, PhysicalPlan::ConvertTimezone(_))
+    }) else {
+        unreachable!("ConvertTimezone expected")
+    };
+    assert_eq!(convert.columns.len(), 1);
+    assert_eq!(convert.tz, "America/New_York");
+    assert_eq!(convert.direction, TzDirection::ToLocal);
+    assert!(matches!(*convert.input, PhysicalPlan::TableScan(_)));
+
+    // A scan without timestamp columns is left as it is.
+    let sql = format!("SELECT a FROM {db}.t");
+    let plan = physical_plan_with(ctx.clone(), &sql, |builder| {
+        builder.set_convert_timezone(Some("America/New_York".to_string()))
+    })
+    .await?;
+    assert!(find_plan(&plan, |plan| matches!(
+        plan,
+        PhysicalPlan::ConvertTimezone(_)
+    ))
+    .is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_convert_timezone_dst() -> Result<()> {
+    let to_local =
+        TransformConvertTimezone::try_new(vec![], "America/New_York", TzDirection::ToLocal)?;
+    let to_utc = TransformConvertTimezone::try_new(vec![], "America/New_York", TzDirection::ToUtc)?;
+
+    // Before and after the spring forward at 2024-03-10 02:00 local time.
+    assert_eq!(
+        to_local.convert(micros("2024-03-10T06:30:00Z"))?,
+        micros("2024-03-10T01:30:00Z")
+    );
+    assert_eq!(
+        to_local.convert(micros("2024-03-10T07:30:00Z"))?,
+        micros("2024-03-10T03:30:00Z")
+    );
+    // 02:30 doesn't exist on that day, it is moved forward by an hour.
+    assert_eq!(
+        to_utc.convert(micros("2024-03-10T02:30:00Z"))?,
+        micros("2024-03-10T07:30:00Z")
+    );
+
+    // 01:30 happens twice on 2024-11-03, both UTC times show the same wall-clock time.
+    assert_eq!(
+        to_local.convert(micros("2024-11-03T05:30:00Z"))?,
+        micros("2024-11-03T01:30:00Z")
+    );
+    assert_eq!(
+        to_local.convert(micros("2024-11-03T06:30:00Z"))?,
+        micros("2024-11-03T01:30:00Z")
+    );
+    // The repeated wall-clock time takes the offset before the transition.
+    assert_eq!(
+        to_utc.convert(micros("2024-11-03T01:30:00Z"))?,
+        micros("2024-11-03T05:30:00Z")
+    );
+
+    // Away from the transitions, the conversion round trips.
+    for ts in ["2024-01-15T12:00:00Z", "2024-07-04T23:59:59.123456Z"] {
+        assert_eq!(to_utc.convert(to_local.convert(micros(ts))?)?, micros(ts));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_convert_timezone_transform() -> Result<()> {
+    let mut transform =
+        TransformConvertTimezone::try_new(vec![1], "Asia/Shanghai", TzDirection::ToLocal)?;
+    let block = DataBlock::new_from_columns(vec![
+        TimestampType::from_data(vec![micros("2024-01-01T00:00:00Z")]),
+        TimestampType::from_opt_data(vec![Some(micros("2024-01-01T00:00:00Z"))]),
+    ]);
+    let block = transform.transform(block)?;
+
+    // Only the column at the given offset is converted, the nullable column keeps its type.
+    let column = block.get_by_offset(0).to_column(1);
+    let column = TimestampType::try_downcast_column(&column).unwrap();
+    assert_eq!(column[0], micros("2024-01-01T00:00:00Z"));
+    let column = block.get_by_offset(1).to_column(1);
+    assert!(column.data_type().is_nullable());
+    let column = column.remove_nullable();
+    let column = TimestampType::try_downcast_column(&column).unwrap();
+    assert_eq!(column[0], micros("2024-01-01T08:00:00Z"));
+    Ok(())
+}
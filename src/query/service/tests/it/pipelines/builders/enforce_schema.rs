@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt8Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_sql::executor::physical_plans::CastMode;
+use databend_query::pipelines::processors::transforms::TransformEnforceSchema;
+
+fn enforce_strings(values: Vec<&str>, target: DataType, cast_mode: CastMode) -> Result<DataBlock> {
+    let input_schema = DataSchemaRefExt::create(vec![DataField::new("c", DataType::String)]);
+    let target_schema = DataSchemaRefExt::create(vec![DataField::new("c", target)]);
+    let mut transform = TransformEnforceSchema::try_new(
+        input_schema,
+        target_schema,
+        cast_mode,
+        FunctionContext::default(),
+    )?;
+    let block = DataBlock::new_from_columns(vec![StringType::from_data(values)]);
+    transform.transform(block)
+}
+
+#[test]
+fn test_enforce_schema_string_to_integer() -> Result<()> {
+    let uint8 = DataType::Number(NumberDataType::UInt8);
+    let block = enforce_strings(vec!["1", "20", "255"], uint8.clone(), CastMode::Strict)?;
+    let column = block.get_by_offset(0).to_column(block.num_rows());
+    assert_eq!(column, UInt8Type::from_data(vec![1, 20, 255]));
+
+    let result = enforce_strings(vec!["1", "abc"], uint8, CastMode::Strict);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_enforce_schema_overflow() -> Result<()> {
+    let uint8 = DataType::Number(NumberDataType::UInt8);
+    let result = enforce_strings(vec!["255", "256"], uint8.clone(), CastMode::Strict);
+    assert!(result.is_err());
+
+    let block = enforce_strings(
+        vec!["255", "256"],
+        uint8.wrap_nullable(),
+        CastMode::Permissive,
+    )?;
+    let column = block.get_by_offset(0).to_column(block.num_rows());
+    assert_eq!(column, UInt8Type::from_opt_data(vec![Some(255), None]));
+    Ok(())
+}
+
+#[test]
+fn test_enforce_schema_null_fallback() -> Result<()> {
+    let uint8 = DataType::Number(NumberDataType::UInt8);
+    let block = enforce_strings(
+        vec!["7", "abc", "-1"],
+        uint8.wrap_nullable(),
+        CastMode::Permissive,
+    )?;
+    let column = block.get_by_offset(0).to_column(block.num_rows());
+    assert_eq!(column, UInt8Type::from_opt_data(vec![Some(7), None, None]));
+
+    // A NOT NULL column can't fall back to NULL.
+    let result = enforce_strings(vec!["7", "abc"], uint8.clone(), CastMode::Permissive);
+    assert!(result.is_err());
+    let block = enforce_strings(vec!["7", "8"], uint8, CastMode::Permissive)?;
+    let column = block.get_by_offset(0).to_column(block.num_rows());
+    assert_eq!(column, UInt8Type::from_data(vec![7, 8]));
+    Ok(())
+}
@@ -0,0 +1,192 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::plan::BoundingBox;
+use databend_common_catalog::plan::SpatialIndex;
+use databend_common_catalog::plan::SpatialPredicate;
+use databend_common_catalog::plan::SpatialRelation;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::ScalarRef;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_storages_fuse::FuseBlockPartInfo;
+use databend_query::pipelines::builders::prune_partitions_by_spatial_index;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+use crate::pipelines::builders::query;
+
+/// An R-tree of a single level, which holds the bounding box of each block.
+struct MockRTree {
+    leaves: Vec<(String, BoundingBox)>,
+}
+
+impl SpatialIndex for MockRTree {
+    fn candidate_blocks(&self, predicate: &SpatialPredicate) -> Result<HashSet<String>> {
+        Ok(self
+            .leaves
+            .iter()
+            .filter(|(_, bbox)| bbox.intersects(&predicate.bbox))
+            .map(|(location, _)| location.clone())
+            .collect())
+    }
+}
+
+fn float(scalar: ScalarRef) -> f64 {
+    match scalar {
+        ScalarRef::Number(NumberScalar::Float64(v)) => v.0,
+        _ => unreachable!("Float64 expected"),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_geo_scan() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command("SET enable_geo_create_table = 1")
+        .await?;
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (id INT, p GEOMETRY)"))
+        .await?;
+    // Each insert writes a block of points around (1, 1), (11, 11) and (21, 21).
+    for values in [
+        "(1, 'POINT(0 0)'), (2, 'POINT(1 1)'), (3, 'POINT(2 2)')",
+        "(4, 'POINT(10 10)'), (5, 'POINT(11 11)'), (6, 'POINT(12 14)')",
+        "(7, 'POINT(20 20)'), (8, 'POINT(21 21)'), (9, 'POINT(22 22)')",
+    ] {
+        fixture
+            .execute_command(&format!("INSERT INTO {db}.t VALUES {values}"))
+            .await?;
+    }
+
+    let sql = format!(
+        "SELECT id FROM {db}.t \
+        WHERE ST_WITHIN(p, TO_GEOMETRY('POLYGON((9 9, 9 13, 13 13, 13 9, 9 9))')) ORDER BY id"
+    );
+    let ctx = fixture.new_query_ctx().await?;
+    let plan = physical_plan(ctx.clone(), &sql).await?;
+    let Some(PhysicalPlan::GeoScan(geo_scan)) =
+        find_plan(&plan, |plan| matches!(plan, PhysicalPlan::GeoScan(_)))
+    else {
+        unreachable!("GeoScan expected")
+    };
+    assert_eq!(geo_scan.spatial_predicate.relation, SpatialRelation::Within);
+    assert_eq!(geo_scan.spatial_predicate.bbox, BoundingBox {
+        min_x: 9.0,
+        min_y: 9.0,
+        max_x: 13.0,
+        max_y: 13.0,
+    });
+    // The only predicate is evaluated by the `Filter` above.
+    assert!(geo_scan.fallback_filter.is_none());
+    assert!(matches!(
+        geo_scan.input.as_ref(),
+        PhysicalPlan::TableScan(_)
+    ));
+
+    // Build the R-tree from the bounding boxes of the points in each block.
+    let blocks = query(
+        &fixture,
+        &format!(
+            "SELECT _block_name, min(st_x(p)), min(st_y(p)), max(st_x(p)), max(st_y(p)) \
+            FROM {db}.t GROUP BY _block_name"
+        ),
+    )
+    .await?;
+    let mut leaves = vec![];
+    for block in &blocks {
+        for row in 0..block.num_rows() {
+            let value = |i: usize| block.get_by_offset(i).value.index(row).unwrap();
+            let ScalarRef::String(location) = value(0) else {
+                unreachable!("String expected")
+            };
+            leaves.push((location.to_string(), BoundingBox {
+                min_x: float(value(1)),
+                min_y: float(value(2)),
+                max_x: float(value(3)),
+                max_y: float(value(4)),
+            }));
+        }
+    }
+    let rtree = MockRTree { leaves };
+
+    // Only the block of the points around (11, 11) is a candidate.
+    let table = ctx.get_table("default", &db, "t").await?;
+    let (_, parts) = table.read_partitions(ctx.clone(), None, true).await?;
+    assert_eq!(parts.len(), 3);
+    let parts = prune_partitions_by_spatial_index(&parts, &rtree, &geo_scan.spatial_predicate)?;
+    assert_eq!(parts.len(), 1);
+    let blocks = query(
+        &fixture,
+        &format!("SELECT _block_name FROM {db}.t WHERE id = 4"),
+    )
+    .await?;
+    let location = blocks[0].get_by_offset(0).value.index(0).unwrap();
+    assert_eq!(
+        ScalarRef::String(
+            FuseBlockPartInfo::from_part(&parts.partitions[0])?
+                .location
+                .as_str()
+        ),
+        location
+    );
+
+    // The point (12, 14) of the candidate block is outside of the polygon.
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 4        |",
+        "| 5        |",
+        "+----------+",
+    ];
+    assert_blocks_eq(expected, &query(&fixture, &sql).await?);
+
+    // The spatial predicate is evaluated by the `GeoScan` with the other predicates.
+    let sql = format!(
+        "SELECT id FROM {db}.t \
+        WHERE ST_INTERSECTS(TO_GEOMETRY('POLYGON((9 9, 9 13, 13 13, 13 9, 9 9))'), p) AND id > 4"
+    );
+    let ctx = fixture.new_query_ctx().await?;
+    let plan = physical_plan(ctx, &sql).await?;
+    let Some(PhysicalPlan::GeoScan(geo_scan)) =
+        find_plan(&plan, |plan| matches!(plan, PhysicalPlan::GeoScan(_)))
+    else {
+        unreachable!("GeoScan expected")
+    };
+    assert_eq!(
+        geo_scan.spatial_predicate.relation,
+        SpatialRelation::Intersects
+    );
+    assert!(geo_scan.fallback_filter.is_some());
+
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 5        |",
+        "+----------+",
+    ];
+    assert_blocks_eq(expected, &query(&fixture, &sql).await?);
+
+    Ok(())
+}
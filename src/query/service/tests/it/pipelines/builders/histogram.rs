@@ -0,0 +1,151 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::pipelines::processors::transforms::TransformHistogram;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan_with;
+
+fn u64_column(block: &DataBlock, offset: usize) -> Vec<u64> {
+    let column = block.get_by_offset(offset).to_column(block.num_rows());
+    UInt64Type::try_downcast_column(&column)
+        .unwrap()
+        .iter()
+        .copied()
+        .collect()
+}
+
+fn run_histogram(blocks: Vec<DataBlock>, num_buckets: usize) -> Result<DataBlock> {
+    let bound_type = DataType::Number(NumberDataType::Int32);
+    let mut histogram = TransformHistogram::new(0, bound_type, num_buckets);
+    for block in blocks {
+        assert!(histogram.transform(block)?.is_empty());
+    }
+    let mut output = histogram.on_finish(true)?;
+    assert_eq!(output.len(), 1);
+    Ok(output.remove(0))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_histogram_plan() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    let sql = "SELECT histogram(number, 10) FROM numbers(100) WHERE number > 10";
+
+    let plan = physical_plan_with(ctx.clone(), sql, |builder| {
+        builder.set_streaming_histogram(true)
+    })
+    .await?;
+    let Some(plan) = find_plan(&plan, |plan| matches!(plan, PhysicalPlan::Histogram(_))) else {
+        unreachable!("Histogram expected")
+    };
+    let PhysicalPlan::Histogram(histogram) = plan else {
+        unreachable!()
+    };
+    assert_eq!(histogram.num_buckets, 10);
+    assert!(find_plan(plan, |plan| matches!(
+        plan,
+        PhysicalPlan::AggregatePartial(_)
+    ))
+    .is_none());
+    let schema = plan.output_schema()?;
+    let names = schema
+        .fields()
+        .iter()
+        .map(|f| f.name().as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["bucket_lower", "bucket_upper", "count", "ndv"]);
+
+    // The aggregate function is kept without streaming histogram.
+    let plan = physical_plan_with(ctx.clone(), sql, |builder| {
+        builder.set_streaming_histogram(false)
+    })
+    .await?;
+    assert!(find_plan(&plan, |plan| matches!(plan, PhysicalPlan::Histogram(_))).is_none());
+    assert!(find_plan(&plan, |plan| matches!(
+        plan,
+        PhysicalPlan::AggregateFinal(_)
+    ))
+    .is_some());
+
+    // Only a single histogram without group by is replaced.
+    let sql = "SELECT histogram(number, 10) FROM numbers(100) GROUP BY number % 3";
+    let plan = physical_plan_with(ctx.clone(), sql, |builder| {
+        builder.set_streaming_histogram(true)
+    })
+    .await?;
+    assert!(find_plan(&plan, |plan| matches!(plan, PhysicalPlan::Histogram(_))).is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_histogram_exact() -> Result<()> {
+    // 0, 0, 1, 1, ..., 49, 49 in two blocks.
+    let values = (0..100).map(|v| v / 2).collect::<Vec<i32>>();
+    let blocks = values
+        .chunks(30)
+        .map(|chunk| DataBlock::new_from_columns(vec![Int32Type::from_data(chunk.to_vec())]))
+        .collect();
+    let block = run_histogram(blocks, 10)?;
+
+    assert_eq!(block.num_rows(), 10);
+    let counts = u64_column(&block, 2);
+    assert_eq!(counts, vec![10; 10]);
+    assert_eq!(u64_column(&block, 3), vec![5; 10]);
+
+    let lower = block.get_by_offset(0).to_column(block.num_rows());
+    let lower = Int32Type::try_downcast_column(&lower).unwrap();
+    assert_eq!(lower.iter().copied().collect::<Vec<_>>(), vec![
+        0, 5, 10, 15, 20, 25, 30, 35, 40, 45
+    ]);
+    let upper = block.get_by_offset(1).to_column(block.num_rows());
+    let upper = Int32Type::try_downcast_column(&upper).unwrap();
+    assert_eq!(upper.iter().copied().collect::<Vec<_>>(), vec![
+        4, 9, 14, 19, 24, 29, 34, 39, 44, 49
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn test_histogram_sampled() -> Result<()> {
+    // The reservoir keeps 1024 values per bucket, the input is sampled.
+    let num_rows = 100_000;
+    let blocks = (0..num_rows)
+        .collect::<Vec<i32>>()
+        .chunks(8192)
+        .map(|chunk| DataBlock::new_from_columns(vec![Int32Type::from_data(chunk.to_vec())]))
+        .collect();
+    let block = run_histogram(blocks, 4)?;
+
+    assert_eq!(block.num_rows(), 4);
+    let counts = u64_column(&block, 2);
+    assert_eq!(counts.iter().sum::<u64>(), num_rows as u64);
+    assert!(counts.iter().all(|count| *count == num_rows as u64 / 4));
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_sql::executor::physical_plans::Limit;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::test_kits::ClusterDescriptor;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+
+fn find_limit(plan: &PhysicalPlan) -> &Limit {
+    match find_plan(plan, |plan| matches!(plan, PhysicalPlan::Limit(_))) {
+        Some(PhysicalPlan::Limit(limit)) => limit,
+        _ => panic!("Limit plan expected"),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_limit_pre_limit() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let plan = physical_plan(ctx.clone(), "SELECT * FROM numbers(1000) LIMIT 10 OFFSET 5").await?;
+    let limit = find_limit(&plan);
+    assert_eq!(limit.limit, Some(10));
+    assert_eq!(limit.offset, 5);
+    assert_eq!(limit.pre_limit, Some(15));
+
+    // Without a limit all the rows after the offset are needed.
+    let plan = physical_plan(ctx, "SELECT * FROM numbers(1000) OFFSET 5").await?;
+    assert_eq!(find_limit(&plan).pre_limit, None);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_distributed_limit_pre_limit() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let num_workers = 3;
+    let mut cluster_desc = ClusterDescriptor::new().with_local_id("node1");
+    for i in 1..=num_workers {
+        cluster_desc =
+            cluster_desc.with_node(format!("node{i}"), format!("127.0.0.1:{}", 9090 + i));
+    }
+    let ctx = fixture.new_query_ctx_with_cluster(cluster_desc).await?;
+
+    let plan = physical_plan(ctx, "SELECT * FROM numbers(100000) LIMIT 10").await?;
+    let exchange = find_plan(&plan, |plan| matches!(plan, PhysicalPlan::Exchange(_)))
+        .expect("Exchange plan expected");
+
+    // Each worker stops after producing `pre_limit` rows, so no more than
+    // `10 * num_workers` rows are sent to the coordinator.
+    let worker_limit = find_limit(exchange);
+    let pre_limit = worker_limit.pre_limit.expect("pre_limit expected");
+    assert_eq!(worker_limit.limit, Some(10));
+    assert!(pre_limit * num_workers <= 10 * num_workers);
+    Ok(())
+}
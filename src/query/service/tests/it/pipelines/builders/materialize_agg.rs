@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::pretty_format_blocks;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+use crate::pipelines::builders::query_map;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_materialize_agg() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.raw (k int, x int, y int)"))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "INSERT INTO {db}.raw SELECT number % 5, number, number * 2 FROM numbers(100)"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "CREATE TABLE {db}.r (k int, total bigint, cnt bigint unsigned, top int) \
+             rollup_keys='k' rollup_count='cnt'"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "INSERT INTO {db}.r SELECT k, SUM(x), COUNT(*), MAX(y) FROM {db}.raw GROUP BY k"
+        ))
+        .await?;
+
+    let sql = format!(
+        "SELECT k, SUM(total), COUNT(*), MAX(top) FROM {db}.r WHERE k > 0 GROUP BY k ORDER BY k"
+    );
+    let ctx = fixture.new_query_ctx().await?;
+    let plan = physical_plan(ctx.clone(), &sql).await?;
+    assert!(find_plan(&plan, |plan| matches!(
+        plan,
+        PhysicalPlan::MaterializeAgg(_)
+    ))
+    .is_some());
+    assert!(find_plan(&plan, |plan| matches!(
+        plan,
+        PhysicalPlan::AggregatePartial(_)
+    ))
+    .is_none());
+
+    // Reading the rollup rows gives the same result as aggregating the raw rows.
+    let expected = query_map(
+        &fixture,
+        &format!(
+            "SELECT k, SUM(x), COUNT(*), MAX(y) FROM {db}.raw WHERE k > 0 GROUP BY k ORDER BY k"
+        ),
+        pretty_format_blocks,
+    )
+    .await?;
+    assert_eq!(
+        query_map(&fixture, &sql, pretty_format_blocks).await?,
+        expected
+    );
+
+    // The aggregation is kept unless it groups by exactly the rollup keys.
+    let sql = format!("SELECT SUM(total) FROM {db}.r");
+    let plan = physical_plan(ctx.clone(), &sql).await?;
+    assert!(find_plan(&plan, |plan| matches!(
+        plan,
+        PhysicalPlan::MaterializeAgg(_)
+    ))
+    .is_none());
+
+    // An invalid rollup key is rejected.
+    let res = fixture
+        .execute_command(&format!(
+            "CREATE TABLE {db}.r2 (k int, total bigint) rollup_keys='k,z'"
+        ))
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
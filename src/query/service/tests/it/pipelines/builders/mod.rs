@@ -12,4 +12,109 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_query::pipelines::executor::ExecutorSettings;
+use databend_query::pipelines::executor::PipelinePullingExecutor;
+use databend_query::schedulers::build_query_pipeline_without_render_result_set;
+use databend_query::sessions::QueryContext;
+use databend_query::stream::PullingExecutorStream;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+mod async_aggregate;
+mod bitmap_index_scan;
+mod block_sample;
+mod bloom_build;
+mod bloom_lookup;
+mod conditional_limit;
+mod convert_timezone;
+mod enforce_schema;
+mod geo_scan;
+mod histogram;
+mod limit;
+mod materialize_agg;
+mod network_read;
+mod prewarm_cache;
+mod range_join;
+mod replicate;
+mod row_access_policy;
 mod runtime_filter;
+mod scan_prefetch;
+mod schema_evolve;
+mod semi_hash_join;
+mod skew_detection;
+mod snapshot;
+mod sort_merge_aggregate;
+mod sort_merge_join;
+mod sorted_merge;
+mod spill_sort;
+mod stream_output;
+mod tdigest_agg;
+mod top_n;
+mod watermark;
+mod write_ahead_log;
+
+/// Build the physical plan of the query `sql`.
+pub async fn physical_plan(ctx: Arc<QueryContext>, sql: &str) -> Result<PhysicalPlan> {
+    physical_plan_with(ctx, sql, |_| {}).await
+}
+
+/// Build the physical plan of the query `sql` with the builder configured by `f`.
+pub async fn physical_plan_with(
+    ctx: Arc<QueryContext>,
+    sql: &str,
+    f: impl FnOnce(&mut PhysicalPlanBuilder),
+) -> Result<PhysicalPlan> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let Plan::Query {
+        s_expr,
+        metadata,
+        bind_context,
+        ..
+    } = plan
+    else {
+        unreachable!("Query plan expected")
+    };
+    let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, false);
+    f(&mut builder);
+    builder.build(&s_expr, bind_context.column_set()).await
+}
+
+/// Find the first plan matching `f` in the pre-order of the plan tree.
+pub fn find_plan(plan: &PhysicalPlan, f: fn(&PhysicalPlan) -> bool) -> Option<&PhysicalPlan> {
+    if f(plan) {
+        return Some(plan);
+    }
+    plan.children().find_map(|child| find_plan(child, f))
+}
+
+/// Execute the query `sql` and collect the result blocks.
+pub async fn query(fixture: &TestFixture, sql: &str) -> Result<Vec<DataBlock>> {
+    fixture.execute_query(sql).await?.try_collect().await
+}
+
+/// Execute the query `sql` and map the result blocks with `f`.
+pub async fn query_map<T>(
+    fixture: &TestFixture,
+    sql: &str,
+    f: impl FnOnce(&[DataBlock]) -> Result<T>,
+) -> Result<T> {
+    f(&query(fixture, sql).await?)
+}
+
+/// Build the pipelines of the physical plan `plan`, execute them and collect the result blocks.
+pub async fn execute_plan(ctx: Arc<QueryContext>, plan: &PhysicalPlan) -> Result<Vec<DataBlock>> {
+    let build_res = build_query_pipeline_without_render_result_set(&ctx, plan).await?;
+    let settings = ExecutorSettings::try_create(ctx.clone())?;
+    let executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+    ctx.set_executor(executor.get_inner())?;
+    PullingExecutorStream::create(executor)?.try_collect().await
+}
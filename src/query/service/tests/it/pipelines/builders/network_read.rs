@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_query::test_kits::TestFixture;
+use wiremock::matchers::header;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+use crate::pipelines::builders::query;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_network_read() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let body = (0..1000)
+        .map(|i| format!("{i},name_{i}\n"))
+        .collect::<String>();
+    let mock_server = MockServer::builder().start().await;
+    for http_method in ["HEAD", "GET"] {
+        Mock::given(method(http_method))
+            .and(path("/data.csv"))
+            .and(header("Authorization", "Bearer secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body.clone()))
+            .mount(&mock_server)
+            .await;
+    }
+
+    let sql = format!(
+        "SELECT count(*), sum($1::int) FROM '{}/data.csv' (file_format => 'csv')",
+        mock_server.uri()
+    );
+
+    // The endpoint rejects the requests without the token.
+    assert!(query(&fixture, &sql).await.is_err());
+
+    fixture
+        .execute_command("SET http_authorization_token = 'secret'")
+        .await?;
+
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1000     | 499500   |",
+        "+----------+----------+",
+    ];
+    assert_blocks_eq(expected, &query(&fixture, &sql).await?);
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_sql::executor::physical_plans::PrewarmCache;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_storages_result_cache::gen_result_cache_prefix;
+use databend_common_storages_result_cache::ResultCacheMetaManager;
+use databend_common_users::UserApiProvider;
+use databend_query::pipelines::executor::ExecutorSettings;
+use databend_query::pipelines::executor::PipelineCompleteExecutor;
+use databend_query::schedulers::build_query_pipeline_without_render_result_set;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_prewarm_cache() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (a int)"))
+        .await?;
+    fixture
+        .execute_command(&format!("INSERT INTO {db}.t VALUES (1), (2), (3)"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let plan = PhysicalPlan::PrewarmCache(Box::new(PrewarmCache::create(
+        vec![format!("SELECT * FROM {db}.t")],
+        1024 * 1024,
+    )));
+    let build_res = build_query_pipeline_without_render_result_set(&ctx, &plan).await?;
+    let executor_settings = ExecutorSettings::try_create(ctx.clone())?;
+    let executor =
+        PipelineCompleteExecutor::try_create(build_res.main_pipeline, executor_settings)?;
+    ctx.set_executor(executor.get_inner())?;
+    // The queries are submitted in background, the pipeline finishes without waiting for them.
+    executor.execute()?;
+
+    assert_eq!(
+        wait_cached_rows(&fixture, |rows| !rows.is_empty()).await?,
+        vec![3]
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_prewarm_cache_of_hot_queries() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (a int)"))
+        .await?;
+    fixture
+        .execute_command("SET enable_query_result_cache = 1")
+        .await?;
+    fixture
+        .execute_command("SET query_result_cache_min_execute_secs = 0")
+        .await?;
+    fixture
+        .execute_command("SET query_result_cache_prewarm_max_bytes = 1048576")
+        .await?;
+
+    // The table changes before each run, so the query misses the result cache twice.
+    for i in 0..2 {
+        fixture
+            .execute_command(&format!("INSERT INTO {db}.t VALUES ({i})"))
+            .await?;
+        fixture
+            .execute_command(&format!("SELECT * FROM {db}.t"))
+            .await?;
+    }
+    fixture
+        .execute_command(&format!("INSERT INTO {db}.t VALUES (2)"))
+        .await?;
+    assert!(!wait_cached_rows(&fixture, |_| true).await?.contains(&3));
+
+    // Another query of the table misses the result cache, the hot query is run again
+    // in background and caches the 3 rows of the table.
+    fixture
+        .execute_command(&format!("SELECT count(*) FROM {db}.t"))
+        .await?;
+    let rows = wait_cached_rows(&fixture, |rows| rows.contains(&3)).await?;
+    assert_eq!(rows.len(), 2);
+    Ok(())
+}
+
+/// Wait until the rows of the cached results satisfy `f`, returns the rows.
+async fn wait_cached_rows(fixture: &TestFixture, f: fn(&[usize]) -> bool) -> Result<Vec<usize>> {
+    let meta_client = UserApiProvider::instance().get_meta_store_client();
+    let result_cache_mgr = ResultCacheMetaManager::create(meta_client, 0);
+    let ctx = fixture.new_query_ctx().await?;
+    let prefix = gen_result_cache_prefix(ctx.get_tenant().tenant_name());
+    let mut rows = vec![];
+    for _ in 0..100 {
+        rows = result_cache_mgr
+            .list(prefix.as_str())
+            .await?
+            .iter()
+            .map(|value| value.num_rows)
+            .collect::<Vec<_>>();
+        if f(&rows) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    Ok(rows)
+}
@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_sql::executor::physical_plans::RangeJoinType;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+use crate::pipelines::builders::query;
+
+// Returns the values of the single row of the query, and the time to run it.
+async fn query_row(fixture: &TestFixture, sql: &str) -> Result<(Vec<Scalar>, Duration)> {
+    let start = Instant::now();
+    let blocks = query(fixture, sql).await?;
+    let elapsed = start.elapsed();
+    let block = DataBlock::concat(&blocks)?;
+    assert_eq!(block.num_rows(), 1);
+    let row = block
+        .columns()
+        .iter()
+        .map(|entry| entry.value.index(0).unwrap().to_owned())
+        .collect();
+    Ok((row, elapsed))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_interval_join() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.a (ts INT)"))
+        .await?;
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.b (id INT, lo INT, hi INT)"))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "INSERT INTO {db}.a SELECT number FROM numbers(10000)"
+        ))
+        .await?;
+    // The intervals have different lengths, and the last ones are beyond the keys.
+    fixture
+        .execute_command(&format!(
+            "INSERT INTO {db}.b SELECT number, number * 10, number * 10 + number % 50 \
+            FROM numbers(1000)"
+        ))
+        .await?;
+    let expected = (0..1000u64)
+        .map(|n| (n * 10 + n % 50).min(9999) - n * 10 + 1)
+        .sum::<u64>();
+
+    let ctx = fixture.new_query_ctx().await?;
+    for (swap, condition) in [
+        (false, "a.ts BETWEEN b.lo AND b.hi"),
+        (true, "a.ts BETWEEN b.lo AND b.hi"),
+        (false, "b.lo < a.ts AND a.ts < b.hi"),
+        (true, "b.hi > a.ts AND b.lo < a.ts"),
+    ] {
+        let from = match swap {
+            false => format!("{db}.a JOIN {db}.b"),
+            true => format!("{db}.b JOIN {db}.a"),
+        };
+        let sql = format!("SELECT count(*), sum(a.ts - b.lo) FROM {from} ON {condition}");
+        let plan = physical_plan(ctx.clone(), &sql).await?;
+        let Some(PhysicalPlan::RangeJoin(range_join)) =
+            find_plan(&plan, |plan| matches!(plan, PhysicalPlan::RangeJoin(_)))
+        else {
+            unreachable!("RangeJoin expected")
+        };
+        assert!(matches!(
+            range_join.range_join_type,
+            RangeJoinType::Interval
+        ));
+
+        // The non-equi left join is executed as a nested loop join, the matched rows are
+        // the same as the inner join.
+        let nested_loop_sql = format!(
+            "SELECT count(b.id), sum(a.ts - b.lo) FROM {db}.a LEFT JOIN {db}.b ON {condition}"
+        );
+        let plan = physical_plan(ctx.clone(), &nested_loop_sql).await?;
+        assert!(find_plan(&plan, |plan| matches!(plan, PhysicalPlan::RangeJoin(_))).is_none());
+
+        let (row, elapsed) = query_row(&fixture, &sql).await?;
+        let (nested_loop_row, nested_loop_elapsed) = query_row(&fixture, &nested_loop_sql).await?;
+        assert_eq!(row, nested_loop_row);
+        if condition.contains("BETWEEN") {
+            assert_eq!(row[0], Scalar::Number(NumberScalar::UInt64(expected)));
+        }
+        // The nested loop join evaluates the conditions on 10M pairs of rows.
+        assert!(
+            elapsed < nested_loop_elapsed,
+            "interval join {elapsed:?} is slower than nested loop join {nested_loop_elapsed:?}"
+        );
+    }
+
+    Ok(())
+}
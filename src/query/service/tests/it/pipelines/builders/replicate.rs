@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::DataBlock;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::Planner;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+use crate::pipelines::builders::physical_plan;
+
+fn find_replicates(plan: &PhysicalPlan, cache_keys: &mut Vec<u64>) {
+    if let PhysicalPlan::Replicate(replicate) = plan {
+        cache_keys.push(replicate.cache_key);
+    }
+    plan.children()
+        .for_each(|child| find_replicates(child, cache_keys));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_replicate_scalar_subquery() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_max_threads(8)?;
+    let sql = "SELECT number, (SELECT count() FROM numbers(10)) AS c FROM numbers(1000)";
+
+    // The scalar subquery is not replicated by default.
+    let mut cache_keys = vec![];
+    find_replicates(&physical_plan(ctx.clone(), sql).await?, &mut cache_keys);
+    assert!(cache_keys.is_empty());
+
+    ctx.get_settings().set_setting(
+        "enable_replicate_scalar_subquery".to_string(),
+        "1".to_string(),
+    )?;
+    find_replicates(&physical_plan(ctx.clone(), sql).await?, &mut cache_keys);
+    assert_eq!(cache_keys.len(), 1);
+
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx.clone()).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let block = DataBlock::concat(&blocks)?;
+    assert_eq!(block.num_rows(), 1000);
+    let counts = block.get_by_offset(1).to_column(block.num_rows());
+    let counts = counts.remove_nullable();
+    let counts = UInt64Type::try_downcast_column(&counts).unwrap();
+    assert!(counts.iter().all(|count| *count == 10));
+
+    // The subquery is executed only once by all the workers, so a single row is stored.
+    let (state, created) = ctx.get_or_create_replicate_state(cache_keys[0]);
+    assert!(!created);
+    assert!(state.is_finished());
+    let stored_rows = state
+        .blocks()
+        .iter()
+        .map(|block| block.num_rows())
+        .sum::<usize>();
+    assert_eq!(stored_rows, 1);
+
+    Ok(())
+}
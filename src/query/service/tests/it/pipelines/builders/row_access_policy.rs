@@ -0,0 +1,148 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_common_expression::DataBlock;
+use databend_common_meta_app::principal::AuthInfo;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::Planner;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::SessionType;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+
+/// Create a query context for the user `name`, who is granted all the global privileges.
+async fn new_query_ctx(fixture: &TestFixture, name: &str) -> Result<Arc<QueryContext>> {
+    let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    let mut user_info = UserInfo::new(name, "%", AuthInfo::None);
+    user_info.grants.grant_privileges(
+        &GrantObject::Global,
+        UserPrivilegeSet::available_privileges_on_global(),
+    );
+    session.set_authed_user(user_info, None).await?;
+    session.create_query_context().await
+}
+
+async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<Vec<DataBlock>> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    interpreter.execute(ctx).await?.try_collect().await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_row_access_policy() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (id int, owner string)"))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            r#"INSERT INTO {db}.t VALUES
+                (1, '''alice''@''%'''), (2, '''bob''@''%'''), (3, '''alice''@''%''')"#
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "ALTER TABLE {db}.t SET OPTIONS (row_access_policy = 'owner = current_user()')"
+        ))
+        .await?;
+
+    // The policy sits right above the scan, and the predicate of the query is not pushed
+    // down into the scan, so it never sees the rows of the other users.
+    let sql = format!("SELECT id FROM {db}.t WHERE id > 1 ORDER BY id");
+    let ctx = new_query_ctx(&fixture, "alice").await?;
+    let plan = physical_plan(ctx.clone(), &sql).await?;
+    let Some(PhysicalPlan::RowAccessPolicy(policy)) = find_plan(&plan, |plan| {
+        matches!(plan, PhysicalPlan::RowAccessPolicy(_))
+    }) else {
+        unreachable!("RowAccessPolicy expected")
+    };
+    let Some(PhysicalPlan::TableScan(scan)) = find_plan(&policy.input, |plan| {
+        matches!(plan, PhysicalPlan::TableScan(_))
+    }) else {
+        unreachable!("TableScan expected")
+    };
+    let push_downs = scan.source.push_downs.as_ref().unwrap();
+    assert!(push_downs.filters.is_none());
+    assert!(push_downs.prewhere.is_none());
+
+    let expect = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 3        |",
+        "+----------+",
+    ];
+    assert_blocks_eq(expect, &execute_sql(ctx, &sql).await?);
+
+    let ctx = new_query_ctx(&fixture, "bob").await?;
+    let expect = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 2        |",
+        "+----------+",
+    ];
+    assert_blocks_eq(expect, &execute_sql(ctx, &sql).await?);
+
+    // A user owning no rows sees nothing, even without a predicate.
+    let ctx = new_query_ctx(&fixture, "carol").await?;
+    let blocks = execute_sql(ctx, &format!("SELECT count(*) FROM {db}.t")).await?;
+    let expect = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 0        |",
+        "+----------+",
+    ];
+    assert_blocks_eq(expect, &blocks);
+
+    // The policy is dropped with the table option.
+    fixture
+        .execute_command(&format!(
+            "ALTER TABLE {db}.t UNSET OPTIONS (row_access_policy)"
+        ))
+        .await?;
+    let ctx = new_query_ctx(&fixture, "carol").await?;
+    let plan = physical_plan(ctx, &sql).await?;
+    assert!(find_plan(&plan, |plan| matches!(
+        plan,
+        PhysicalPlan::RowAccessPolicy(_)
+    ))
+    .is_none());
+
+    // An invalid policy is rejected.
+    let res = fixture
+        .execute_command(&format!(
+            "ALTER TABLE {db}.t SET OPTIONS (row_access_policy = 'owner =')"
+        ))
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
@@ -19,7 +19,6 @@ use databend_common_exception::Result;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_sql::executor::physical_plans::HashJoin;
 use databend_common_sql::executor::PhysicalPlan;
-use databend_common_sql::executor::PhysicalPlanBuilder;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::Planner;
 use databend_query::interpreters::InterpreterFactory;
@@ -30,6 +29,8 @@ use databend_query::sessions::QueryContext;
 use databend_query::sessions::TableContext;
 use databend_query::test_kits::TestFixture;
 
+use crate::pipelines::builders::physical_plan;
+
 async fn plan_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<Plan> {
     let mut planner = Planner::new(ctx.clone());
     let (plan, _) = planner.plan_sql(sql).await?;
@@ -42,22 +43,6 @@ async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<SendableDataBl
     it.execute(ctx).await
 }
 
-async fn physical_plan(ctx: Arc<QueryContext>, sql: &str) -> Result<PhysicalPlan> {
-    let plan = plan_sql(ctx.clone(), sql).await?;
-    match plan {
-        Plan::Query {
-            s_expr,
-            metadata,
-            bind_context,
-            ..
-        } => {
-            let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, false);
-            builder.build(&s_expr, bind_context.column_set()).await
-        }
-        _ => unreachable!("Query plan expected"),
-    }
-}
-
 // The method is used to find the join in the physical plan.
 // The physical plan should be a simple tree which only contains one binary operator and the binary operator is join.
 fn find_join(plan: &PhysicalPlan) -> Result<HashJoin> {
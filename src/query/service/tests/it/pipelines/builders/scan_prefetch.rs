@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::pretty_format_blocks;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+use crate::pipelines::builders::query_map;
+
+async fn scan_prefetch(ctx: Arc<QueryContext>, sql: &str) -> Result<(bool, usize)> {
+    let plan = physical_plan(ctx, sql).await?;
+    let Some(PhysicalPlan::TableScan(scan)) =
+        find_plan(&plan, |plan| matches!(plan, PhysicalPlan::TableScan(_)))
+    else {
+        unreachable!("TableScan expected")
+    };
+    Ok((scan.speculative_prefetch, scan.prefetch_depth))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_scan_prefetch_plan() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (a int, b string)"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let sql = format!("SELECT * FROM {db}.t");
+    assert_eq!(scan_prefetch(ctx.clone(), &sql).await?, (false, 0));
+
+    ctx.get_settings()
+        .set_setting("scan_prefetch_depth".to_string(), "4".to_string())?;
+    assert_eq!(scan_prefetch(ctx.clone(), &sql).await?, (true, 4));
+
+    // Scans that may skip blocks don't read ahead.
+    for sql in [
+        format!("SELECT * FROM {db}.t WHERE a > 1"),
+        format!("SELECT * FROM {db}.t LIMIT 1"),
+    ] {
+        assert_eq!(scan_prefetch(ctx.clone(), &sql).await?, (false, 0));
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_scan_prefetch_read() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!(
+            "CREATE TABLE {db}.t (a int, b string) row_per_block = 100"
+        ))
+        .await?;
+    for i in 0..10 {
+        fixture
+            .execute_command(&format!(
+                "INSERT INTO {db}.t SELECT number + {}, to_string(number) FROM numbers(1000)",
+                i * 1000
+            ))
+            .await?;
+    }
+
+    let sql = format!("SELECT sum(a), count(), max(b) FROM {db}.t");
+    let start = Instant::now();
+    let expected = query_map(&fixture, &sql, pretty_format_blocks).await?;
+    let without_prefetch = start.elapsed();
+
+    fixture
+        .execute_command("SET scan_prefetch_depth = 4")
+        .await?;
+    let start = Instant::now();
+    assert_eq!(
+        query_map(&fixture, &sql, pretty_format_blocks).await?,
+        expected
+    );
+    let with_prefetch = start.elapsed();
+    log::info!("scan without prefetch: {without_prefetch:?}, with prefetch: {with_prefetch:?}");
+
+    // Every block is read once.
+    let sql = format!("SELECT count() FROM (SELECT DISTINCT a FROM {db}.t)");
+    assert_eq!(
+        query_map(&fixture, &sql, pretty_format_blocks).await?,
+        query_map(
+            &fixture,
+            &format!("SELECT count() FROM {db}.t"),
+            pretty_format_blocks
+        )
+        .await?
+    );
+    Ok(())
+}
@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_sql::executor::physical_plans::SchemaEvolve;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::execute_plan;
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+
+fn column_values(blocks: &[DataBlock], offset: usize) -> Vec<i32> {
+    blocks
+        .iter()
+        .flat_map(|block| {
+            let column = block.get_by_offset(offset).to_column(block.num_rows());
+            Int32Type::try_downcast_column(&column)
+                .unwrap()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_schema_evolve_fill_defaults() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!(
+            "CREATE TABLE {db}.t (a INT NOT NULL, b INT NOT NULL, c INT NOT NULL)"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!("INSERT INTO {db}.t VALUES (1, 2, 3), (4, 5, 6)"))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "ALTER TABLE {db}.t ADD COLUMN d INT NOT NULL DEFAULT 7"
+        ))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let plan = physical_plan(ctx.clone(), &format!("SELECT a, b, c, d FROM {db}.t")).await?;
+    // The table schema is the same as the schema of the data source.
+    assert!(find_plan(&plan, |plan| matches!(plan, PhysicalPlan::SchemaEvolve(_))).is_none());
+    let Some(PhysicalPlan::TableScan(scan)) =
+        find_plan(&plan, |plan| matches!(plan, PhysicalPlan::TableScan(_)))
+    else {
+        unreachable!("TableScan expected")
+    };
+
+    // Read the old blocks with 3 columns, the added column is filled by its default.
+    let target_schema = scan.output_schema()?;
+    let mut scan = scan.clone();
+    scan.name_mapping.remove("d");
+    let mut plan = PhysicalPlan::SchemaEvolve(Box::new(SchemaEvolve {
+        plan_id: 0,
+        input: Box::new(PhysicalPlan::TableScan(scan)),
+        target_schema,
+        fill_defaults: vec![(3, RemoteExpr::Constant {
+            span: None,
+            scalar: Scalar::Number(NumberScalar::Int32(7)),
+            data_type: DataType::Number(NumberDataType::Int32),
+        })],
+        stat_info: None,
+    }));
+    plan.adjust_plan_id(&mut 0);
+    assert_eq!(plan.output_schema()?.num_fields(), 4);
+
+    let mut blocks = execute_plan(ctx.clone(), &plan).await?;
+    blocks.retain(|block| !block.is_empty());
+
+    let mut rows = (0..4)
+        .map(|offset| column_values(&blocks, offset))
+        .collect::<Vec<_>>();
+    let mut order = (0..rows[0].len()).collect::<Vec<_>>();
+    order.sort_by_key(|row| rows[0][*row]);
+    for column in rows.iter_mut() {
+        *column = order.iter().map(|row| column[*row]).collect();
+    }
+    assert_eq!(rows, vec![vec![1, 4], vec![2, 5], vec![3, 6], vec![7, 7]]);
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_pipeline_sinks::Sink;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::pipelines::processors::transforms::SemiHashJoinState;
+use databend_query::pipelines::processors::transforms::TransformSemiHashJoinBuild;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_semi_hash_join_distinct_build_keys() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.b (k UInt64)"))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "INSERT INTO {db}.b SELECT number % 3 FROM numbers(30)"
+        ))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings()
+        .set_setting("enable_semi_anti_hash_join".to_string(), "1".to_string())?;
+    let sql =
+        format!("SELECT a.number FROM numbers(1000) a LEFT SEMI JOIN {db}.b b ON a.number = b.k");
+    let plan = physical_plan(ctx.clone(), &sql).await?;
+    let Some(PhysicalPlan::SemiHashJoin(join)) =
+        find_plan(&plan, |plan| matches!(plan, PhysicalPlan::SemiHashJoin(_)))
+    else {
+        unreachable!("SemiHashJoin expected")
+    };
+    assert_eq!(join.build.output_schema()?.num_fields(), 1);
+
+    // The build side of 1M rows only has 7 distinct keys, which are duplicated in and across
+    // the blocks. The kept keys don't grow with the duplicated rows.
+    let state = SemiHashJoinState::create(ctx.get_function_context()?, join);
+    let mut build = TransformSemiHashJoinBuild::create(state.clone());
+    for round in 0..100 {
+        let keys = (0..10_000).map(|number| number % 7).collect::<Vec<u64>>();
+        build.consume(DataBlock::new_from_columns(vec![UInt64Type::from_data(
+            keys,
+        )]))?;
+        assert_eq!(state.num_keys(), 7, "round {round}");
+    }
+    build.on_finish()?;
+    assert_eq!(state.num_keys(), 7);
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_query::pipelines::processors::transforms::SkewDetectionState;
+use databend_query::pipelines::processors::transforms::TransformSkewDetection;
+
+fn run_skew_detection(
+    values: Vec<i32>,
+    num_partitions: usize,
+    skew_threshold: f64,
+) -> Result<Arc<SkewDetectionState>> {
+    let key = RemoteExpr::ColumnRef {
+        span: None,
+        id: 0,
+        data_type: DataType::Number(NumberDataType::Int32),
+        display_name: "key".to_string(),
+    };
+    let state = SkewDetectionState::new(num_partitions, skew_threshold, 1);
+    let mut transform =
+        TransformSkewDetection::new(0, FunctionContext::default(), &[key], state.clone());
+    for chunk in values.chunks(1000) {
+        let block = DataBlock::new_from_columns(vec![Int32Type::from_data(chunk.to_vec())]);
+        let output = transform.transform(block.clone())?;
+        assert_eq!(output.num_rows(), block.num_rows());
+    }
+    transform.on_finish()?;
+    Ok(state)
+}
+
+#[test]
+fn test_skew_detection_balanced() -> Result<()> {
+    let values = (0..10_000).collect::<Vec<i32>>();
+    let state = run_skew_detection(values, 4, 2.0)?;
+    assert!(state.skewed_partition().is_none());
+    Ok(())
+}
+
+#[test]
+fn test_skew_detection_imbalanced() -> Result<()> {
+    // 9000 rows of a hot key and 1000 distinct keys.
+    let mut values = vec![7; 9000];
+    values.extend(0..1000);
+    let state = run_skew_detection(values, 4, 2.0)?;
+
+    let Some((_, rows, avg)) = state.skewed_partition() else {
+        unreachable!("Skew expected")
+    };
+    assert!(rows >= 9000);
+    assert_eq!(avg, 2500.0);
+    Ok(())
+}
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_common_expression::DataBlock;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::query;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_snapshot() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (a int)"))
+        .await?;
+    fixture
+        .execute_command(&format!("INSERT INTO {db}.t VALUES (1), (2)"))
+        .await?;
+
+    let blocks = query(
+        &fixture,
+        &format!("SELECT snapshot_id FROM fuse_snapshot('{db}', 't')"),
+    )
+    .await?;
+    let block = DataBlock::concat(&blocks)?;
+    assert_eq!(block.num_rows(), 1);
+    let snapshot_id = block.columns()[0]
+        .value
+        .index(0)
+        .unwrap()
+        .as_string()
+        .unwrap()
+        .to_string();
+
+    fixture
+        .execute_command(&format!("INSERT INTO {db}.t VALUES (3)"))
+        .await?;
+
+    let sql = format!("SELECT a FROM {db}.t AT (SNAPSHOT => '{snapshot_id}') ORDER BY a");
+
+    // Only the rows of the snapshot are read.
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 1        |",
+        "| 2        |",
+        "+----------+",
+    ];
+    assert_blocks_eq(expected, &query(&fixture, &sql).await?);
+
+    Ok(())
+}
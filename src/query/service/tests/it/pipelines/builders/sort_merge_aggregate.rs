@@ -0,0 +1,167 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::AggregateHashTable;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::FromData;
+use databend_common_expression::HashTableConfig;
+use databend_common_expression::InputColumns;
+use databend_common_expression::ProbeState;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_query::pipelines::processors::transforms::aggregator::AggregatorParams;
+use databend_query::pipelines::processors::transforms::aggregator::TransformSortMergeAggregate;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+use crate::pipelines::builders::query;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sort_merge_aggregate() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.execute_command("SET max_threads = 4").await?;
+    // The groups span the blocks, and the results are output in many blocks.
+    fixture.execute_command("SET max_block_size = 3").await?;
+
+    let sorted = "SELECT k, count(*), sum(n) \
+        FROM (SELECT number % 7 AS k, number AS n FROM numbers(1000) ORDER BY k LIMIT 1000) \
+        GROUP BY k ORDER BY k";
+    let unsorted = "SELECT k, count(*), sum(n) \
+        FROM (SELECT number % 7 AS k, number AS n FROM numbers(1000)) \
+        GROUP BY k ORDER BY k";
+
+    let ctx = fixture.new_query_ctx().await?;
+    let plan = physical_plan(ctx.clone(), sorted).await?;
+    assert!(find_plan(&plan, |plan| plan.name() == "SortMergeAggregate").is_some());
+    assert!(find_plan(&plan, |plan| plan.name() == "AggregateFinal").is_none());
+    let plan = physical_plan(ctx, unsorted).await?;
+    assert!(find_plan(&plan, |plan| plan.name() == "SortMergeAggregate").is_none());
+    assert!(find_plan(&plan, |plan| plan.name() == "AggregateFinal").is_some());
+
+    let expected = vec![
+        "+----------+----------+----------+",
+        "| Column 0 | Column 1 | Column 2 |",
+        "+----------+----------+----------+",
+        "| 0        | 143      | 71071    |",
+        "| 1        | 143      | 71214    |",
+        "| 2        | 143      | 71357    |",
+        "| 3        | 143      | 71500    |",
+        "| 4        | 143      | 71643    |",
+        "| 5        | 143      | 71786    |",
+        "| 6        | 142      | 70929    |",
+        "+----------+----------+----------+",
+    ];
+    for sql in [sorted, unsorted] {
+        let blocks = query(&fixture, sql).await?;
+        assert_blocks_eq(expected.clone(), &blocks);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_sort_merge_aggregate_memory() -> Result<()> {
+    let uint64 = DataType::Number(NumberDataType::UInt64);
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("k", uint64.clone()),
+        DataField::new("v", uint64.clone()),
+    ]);
+    let sum =
+        AggregateFunctionFactory::instance().get("sum", vec![], vec![uint64.clone()], vec![])?;
+    let params = AggregatorParams::try_create(
+        schema,
+        vec![uint64.clone()],
+        &[0],
+        &[sum.clone()],
+        &[vec![1]],
+        true,
+        false,
+        1000,
+        1,
+    )?;
+
+    // 100000 groups of 2 rows, sorted by the key.
+    let blocks = (0..200_000u64)
+        .collect::<Vec<_>>()
+        .chunks(1000)
+        .map(|chunk| {
+            DataBlock::new_from_columns(vec![
+                UInt64Type::from_data(chunk.iter().map(|n| n / 2).collect::<Vec<_>>()),
+                UInt64Type::from_data(chunk.to_vec()),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let mut sort_merge = TransformSortMergeAggregate::try_create(&params)?;
+    let mut groups = 0;
+    let mut total = 0;
+    let mut sort_merge_bytes = 0;
+    for block in blocks.iter() {
+        for output in sort_merge.transform(block.clone())? {
+            groups += output.num_rows();
+            total += sum_column(&output, 0);
+        }
+        sort_merge_bytes = sort_merge_bytes.max(sort_merge.allocated_bytes());
+    }
+    for output in sort_merge.on_finish(true)? {
+        groups += output.num_rows();
+        total += sum_column(&output, 0);
+    }
+    assert_eq!(groups, 100_000);
+    assert_eq!(total, (0..200_000u64).sum::<u64>());
+
+    // The hash table of the final aggregation keeps the states of all the groups.
+    let mut hashtable = AggregateHashTable::new(
+        vec![uint64],
+        vec![sum],
+        HashTableConfig::default(),
+        Arc::new(Bump::new()),
+    );
+    let mut probe_state = ProbeState::default();
+    for block in blocks.iter() {
+        let params_columns = vec![InputColumns::new_block_proxy(&[1], block)];
+        hashtable.add_groups(
+            &mut probe_state,
+            InputColumns::new_block_proxy(&[0], block),
+            &params_columns,
+            (&[]).into(),
+            block.num_rows(),
+        )?;
+    }
+    assert_eq!(hashtable.len(), 100_000);
+    assert!(sort_merge_bytes * 10 < hashtable.allocated_bytes());
+
+    Ok(())
+}
+
+fn sum_column(block: &DataBlock, offset: usize) -> u64 {
+    let column = block.get_by_offset(offset).to_column(block.num_rows());
+    UInt64Type::try_downcast_column(&column)
+        .unwrap()
+        .iter()
+        .sum()
+}
@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::plans::JoinType;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+use crate::pipelines::builders::query;
+
+// Returns the rows of the query in order, each row is formatted as a string.
+async fn query_rows(fixture: &TestFixture, sql: &str) -> Result<Vec<String>> {
+    let blocks = query(fixture, sql).await?;
+    let mut rows = vec![];
+    for block in blocks {
+        for row in 0..block.num_rows() {
+            let values = block
+                .columns()
+                .iter()
+                .map(|entry| entry.value.index(row).unwrap().to_string())
+                .collect::<Vec<_>>();
+            rows.push(values.join(", "));
+        }
+    }
+    rows.sort();
+    Ok(rows)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sort_merge_join() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.a (k INT NULL, v INT)"))
+        .await?;
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.b (k INT NULL, w INT)"))
+        .await?;
+    // Both the sides have ties of the keys and NULL keys, in several blocks.
+    for i in 0..3 {
+        fixture
+            .execute_command(&format!(
+                "INSERT INTO {db}.a \
+                SELECT if(number % 7 = 0, NULL, number % 50), number + {i} * 100 \
+                FROM numbers(100)"
+            ))
+            .await?;
+        fixture
+            .execute_command(&format!(
+                "INSERT INTO {db}.b \
+                SELECT if(number % 5 = 0, NULL, number % 30 + 20), number + {i} * 100 \
+                FROM numbers(60)"
+            ))
+            .await?;
+    }
+
+    let ctx = fixture.new_query_ctx().await?;
+    for (join, join_type) in [("JOIN", JoinType::Inner), ("LEFT JOIN", JoinType::Left)] {
+        for order in ["", " DESC NULLS FIRST"] {
+            let sql = format!(
+                "SELECT a.k, a.v, b.k, b.w \
+                FROM (SELECT k, v FROM {db}.a ORDER BY k{order}) a \
+                {join} (SELECT k, w FROM {db}.b ORDER BY k{order}) b ON a.k = b.k"
+            );
+            let plan = physical_plan(ctx.clone(), &sql).await?;
+            let Some(PhysicalPlan::SortMergeJoin(sort_merge_join)) =
+                find_plan(&plan, |plan| matches!(plan, PhysicalPlan::SortMergeJoin(_)))
+            else {
+                unreachable!("SortMergeJoin expected")
+            };
+            assert_eq!(sort_merge_join.join_type, join_type);
+            assert_eq!(sort_merge_join.left_keys.len(), 1);
+
+            // The same join of the unsorted sides is a hash join.
+            let hash_join_sql =
+                format!("SELECT a.k, a.v, b.k, b.w FROM {db}.a a {join} {db}.b b ON a.k = b.k");
+            let plan = physical_plan(ctx.clone(), &hash_join_sql).await?;
+            assert!(
+                find_plan(&plan, |plan| matches!(plan, PhysicalPlan::SortMergeJoin(_))).is_none()
+            );
+
+            let rows = query_rows(&fixture, &sql).await?;
+            let hash_join_rows = query_rows(&fixture, &hash_join_sql).await?;
+            assert!(!rows.is_empty());
+            assert_eq!(rows, hash_join_rows);
+        }
+    }
+
+    // The sides sorted in different orders can not be merged.
+    let sql = format!(
+        "SELECT a.v, b.w FROM (SELECT k, v FROM {db}.a ORDER BY k) a \
+        JOIN (SELECT k, w FROM {db}.b ORDER BY k DESC) b ON a.k = b.k"
+    );
+    let plan = physical_plan(ctx.clone(), &sql).await?;
+    assert!(find_plan(&plan, |plan| matches!(plan, PhysicalPlan::SortMergeJoin(_))).is_none());
+
+    Ok(())
+}
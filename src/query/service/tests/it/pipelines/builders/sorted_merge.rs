@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::ValueType;
+use databend_common_sql::executor::physical_plans::SortDesc;
+use databend_common_sql::executor::physical_plans::SortedMerge;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::execute_plan;
+use crate::pipelines::builders::physical_plan;
+
+fn find_sorted_merge(plan: &PhysicalPlan) -> bool {
+    matches!(plan, PhysicalPlan::SortedMerge(_)) || plan.children().any(find_sorted_merge)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sorted_merge_streams() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_max_threads(4)?;
+
+    // Four sorted streams with interleaved values.
+    let mut inputs = Vec::with_capacity(4);
+    for k in 0..4 {
+        let sql = format!("SELECT number * 4 + {k} AS n FROM numbers(1000) ORDER BY n");
+        inputs.push(physical_plan(ctx.clone(), &sql).await?);
+    }
+    let index = inputs[0]
+        .output_schema()?
+        .field(0)
+        .name()
+        .parse::<usize>()?;
+
+    let mut plan = PhysicalPlan::SortedMerge(Box::new(SortedMerge {
+        plan_id: 0,
+        inputs,
+        order_by: vec![SortDesc {
+            asc: true,
+            nulls_first: false,
+            order_by: index,
+            display_name: "n".to_string(),
+        }],
+        stat_info: None,
+    }));
+    plan.adjust_plan_id(&mut 0);
+
+    let blocks = execute_plan(ctx.clone(), &plan).await?;
+
+    let values = blocks
+        .iter()
+        .flat_map(|block| {
+            let column = block.get_by_offset(0).to_column(block.num_rows());
+            UInt64Type::try_downcast_column(&column)
+                .unwrap()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(values, (0..4000).collect::<Vec<u64>>());
+
+    // No merge exchange in single node mode, the setting has no effect.
+    ctx.get_settings()
+        .set_setting("enable_sorted_merge".to_string(), "1".to_string())?;
+    let plan = physical_plan(ctx, "SELECT number FROM numbers(1000) ORDER BY number").await?;
+    assert!(!find_sorted_merge(&plan));
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::ValueType;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_storage::DataOperator;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::execute_plan;
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+
+async fn execute(ctx: Arc<QueryContext>, plan: &PhysicalPlan) -> Result<Vec<u64>> {
+    let blocks = execute_plan(ctx, plan).await?;
+    Ok(blocks
+        .iter()
+        .flat_map(|block| {
+            let column = block.get_by_offset(0).to_column(block.num_rows());
+            UInt64Type::try_downcast_column(&column)
+                .unwrap()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>()
+        })
+        .collect())
+}
+
+// The files left in the spill location of the query.
+async fn spilled_files(ctx: &QueryContext) -> Result<Vec<String>> {
+    let prefix = format!("{}/{}/", ctx.query_tenant_spill_prefix(), ctx.get_id());
+    let entries = DataOperator::instance()
+        .spill_operator()
+        .list_with(&prefix)
+        .recursive(true)
+        .await?;
+    Ok(entries
+        .into_iter()
+        .filter(|entry| entry.metadata().is_file())
+        .map(|entry| entry.path().to_string())
+        .collect())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spill_sort() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    // A permutation of 0..200000, 1.6MB of values with a threshold of 400KB, the same ratio
+    // as sorting 2GB with a threshold of 512MB.
+    let sql = "SELECT (number * 7919) % 200000 AS k FROM numbers(200000) ORDER BY k DESC";
+
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_max_threads(4)?;
+    ctx.get_settings().set_max_block_size(1000)?;
+    let plan = physical_plan(ctx.clone(), sql).await?;
+    assert!(find_plan(&plan, |plan| plan.name() == "Sort").is_some());
+    assert!(find_plan(&plan, |plan| plan.name() == "SpillSort").is_none());
+
+    ctx.get_settings().set_setting(
+        "sort_spill_threshold_bytes".to_string(),
+        (400 * 1024).to_string(),
+    )?;
+    let plan = physical_plan(ctx.clone(), sql).await?;
+    assert!(find_plan(&plan, |plan| plan.name() == "SpillSort").is_some());
+    assert!(find_plan(&plan, |plan| plan.name() == "Sort").is_none());
+
+    let values = execute(ctx.clone(), &plan).await?;
+    assert_eq!(values, (0..200_000u64).rev().collect::<Vec<_>>());
+    // Every pipe spills runs of at most 100KB, so each pipe has several runs to merge.
+    assert!(ctx.get_spill_file_stats(None).file_nums > 4);
+    // The runs are deleted once they are merged.
+    assert!(ctx.get_spilled_files().is_empty());
+    assert!(spilled_files(&ctx).await?.is_empty());
+
+    // Every block of 512KB exceeds the threshold of the pipe, it is spilled as a run alone.
+    let block_ctx = fixture.new_query_ctx().await?;
+    block_ctx.get_settings().set_max_threads(4)?;
+    block_ctx.get_settings().set_max_block_size(65536)?;
+    block_ctx.get_settings().set_setting(
+        "sort_spill_threshold_bytes".to_string(),
+        (400 * 1024).to_string(),
+    )?;
+    let plan = physical_plan(block_ctx.clone(), sql).await?;
+    assert!(find_plan(&plan, |plan| plan.name() == "SpillSort").is_some());
+    let values = execute(block_ctx.clone(), &plan).await?;
+    assert_eq!(values, (0..200_000u64).rev().collect::<Vec<_>>());
+    assert!(block_ctx.get_spill_file_stats(None).file_nums > 0);
+    assert!(spilled_files(&block_ctx).await?.is_empty());
+
+    // The sort with limit keeps only the top rows, it's never spilled.
+    let plan = physical_plan(ctx, &format!("{sql} LIMIT 10")).await?;
+    assert!(find_plan(&plan, |plan| plan.name() == "SpillSort").is_none());
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
+use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_sql::executor::physical_plans::StreamOutput;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::execute_plan;
+use crate::pipelines::builders::physical_plan;
+use crate::pipelines::builders::query_map;
+
+fn u64_value(block: &DataBlock, row: usize, offset: usize) -> u64 {
+    let value = block.get_by_offset(offset).value.index(row).unwrap();
+    match value {
+        ScalarRef::Number(NumberScalar::UInt64(v)) => v,
+        v => unreachable!("UInt64 expected, got {:?}", v),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_stream_output_parquet() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let input = physical_plan(ctx.clone(), "SELECT number FROM numbers(1000000)").await?;
+
+    let mut plan = PhysicalPlan::StreamOutput(Box::new(StreamOutput {
+        plan_id: 0,
+        input: Box::new(input),
+        path: "stream_output/".to_string(),
+        format: FileFormatParams::default_by_type(StageFileFormatType::Parquet)?,
+        compression: StageFileCompression::Zstd,
+        max_file_size: Some(1024 * 1024),
+        stat_info: None,
+    }));
+    plan.adjust_plan_id(&mut 0);
+
+    let blocks = execute_plan(ctx.clone(), &plan).await?;
+    let summary = DataBlock::concat(&blocks)?;
+    assert_eq!(u64_value(&summary, 0, 0), 1000000);
+
+    // The output is split into numbered parts by the max file size.
+    let files = query_map(
+        &fixture,
+        "SELECT count(*) FROM list_stage(location => '@~/stream_output/')",
+        DataBlock::concat,
+    )
+    .await?;
+    assert!(u64_value(&files, 0, 0) > 1);
+
+    // All the rows can be read back from the files.
+    let result = query_map(
+        &fixture,
+        "SELECT count(*), sum(\"0\"::UInt64) FROM @~/stream_output/ (FILE_FORMAT => 'parquet')",
+        DataBlock::concat,
+    )
+    .await?;
+    assert_eq!(u64_value(&result, 0, 0), 1000000);
+    assert_eq!(u64_value(&result, 0, 1), 999999 * 1000000 / 2);
+
+    // Parquet files are always compressed with zstd.
+    let PhysicalPlan::StreamOutput(mut output) = plan else {
+        unreachable!()
+    };
+    output.compression = StageFileCompression::Gzip;
+    assert!(output.file_format_params().is_err());
+
+    Ok(())
+}
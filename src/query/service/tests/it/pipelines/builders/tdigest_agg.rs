@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::pipelines::processors::transforms::TransformTDigestAgg;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::physical_plan;
+use crate::pipelines::builders::query;
+
+fn f64_value(block: &DataBlock) -> f64 {
+    let column = block.get_by_offset(0).to_column(block.num_rows());
+    Float64Type::try_downcast_column(&column.remove_nullable()).unwrap()[0].0
+}
+
+fn assert_relative_error(actual: f64, expected: f64, max_error: f64) {
+    let error = (actual - expected).abs() / expected;
+    assert!(
+        error < max_error,
+        "{actual} is not within {max_error} of {expected}"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tdigest_agg_plan() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    let sql = "SELECT APPROX_PERCENTILE_CONT(number, 0.95) FROM numbers(1000)";
+
+    let plan = physical_plan(ctx.clone(), sql).await?;
+    let Some(PhysicalPlan::TDigestAgg(tdigest_agg)) =
+        find_plan(&plan, |plan| matches!(plan, PhysicalPlan::TDigestAgg(_)))
+    else {
+        unreachable!("TDigestAgg expected")
+    };
+    assert_eq!(tdigest_agg.percentile, 0.95);
+    assert!(find_plan(&plan, |plan| matches!(
+        plan,
+        PhysicalPlan::AggregatePartial(_)
+    ))
+    .is_none());
+
+    let blocks = query(&fixture, sql).await?;
+    assert_relative_error(f64_value(&blocks[0]), 949.05, 0.01);
+
+    // With group by it's computed by the aggregate function.
+    let sql = "SELECT APPROX_PERCENTILE_CONT(number, 0.95) FROM numbers(1000) GROUP BY number % 3";
+    let plan = physical_plan(ctx, sql).await?;
+    assert!(find_plan(&plan, |plan| matches!(plan, PhysicalPlan::TDigestAgg(_))).is_none());
+    assert!(find_plan(&plan, |plan| matches!(
+        plan,
+        PhysicalPlan::AggregateFinal(_)
+    ))
+    .is_some());
+
+    Ok(())
+}
+
+#[test]
+fn test_tdigest_agg_error() -> Result<()> {
+    // A permutation of 0..10M, 7919 is coprime to 10M.
+    let num_rows = 10_000_000u64;
+    let values = (0..num_rows)
+        .map(|i| i * 7919 % num_rows)
+        .collect::<Vec<_>>();
+    let output_type = DataType::Number(NumberDataType::Float64);
+
+    for percentile in [0.001, 0.5, 0.999] {
+        let mut transform = TransformTDigestAgg::new(0, percentile, 100.0, output_type.clone());
+        for chunk in values.chunks(65536) {
+            let block = DataBlock::new_from_columns(vec![UInt64Type::from_data(chunk.to_vec())]);
+            assert!(transform.transform(block)?.is_empty());
+        }
+        let output = transform.on_finish(true)?;
+        assert_eq!(output.len(), 1);
+
+        let expected = percentile * (num_rows - 1) as f64;
+        assert_relative_error(f64_value(&output[0]), expected, 0.01);
+    }
+
+    Ok(())
+}
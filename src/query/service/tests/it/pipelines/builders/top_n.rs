@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::DataBlock;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::physical_plan;
+use crate::pipelines::builders::query_map;
+
+// The limits of all the `Sort` plans in the tree.
+fn sort_limits(plan: &PhysicalPlan) -> Vec<Option<usize>> {
+    let mut limits = match plan {
+        PhysicalPlan::Sort(sort) => vec![sort.limit],
+        _ => vec![],
+    };
+    limits.extend(plan.children().flat_map(sort_limits));
+    limits
+}
+
+fn u64_values(blocks: &[DataBlock]) -> Result<Vec<u64>> {
+    Ok(blocks
+        .iter()
+        .flat_map(|block| {
+            let column = block.get_by_offset(0).to_column(block.num_rows());
+            UInt64Type::try_downcast_column(&column)
+                .unwrap()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>()
+        })
+        .collect())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_top_n() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    // The limit is fused into the sort below it, which only keeps the top `limit + offset`
+    // rows of the blocks, so the memory is bounded by the limit instead of the input.
+    let sql = "SELECT number FROM numbers(10000000) ORDER BY number DESC LIMIT 10 OFFSET 5";
+
+    let ctx = fixture.new_query_ctx().await?;
+    let plan = physical_plan(ctx.clone(), sql).await?;
+    let PhysicalPlan::Limit(limit) = &plan else {
+        unreachable!("Limit expected")
+    };
+    assert_eq!((limit.limit, limit.offset), (Some(10), 5));
+    let limits = sort_limits(&plan);
+    assert!(!limits.is_empty());
+    assert!(limits.iter().all(|limit| *limit == Some(15)));
+
+    let values = query_map(&fixture, sql, u64_values).await?;
+    assert_eq!(values, (9_999_985..9_999_995u64).rev().collect::<Vec<_>>());
+
+    // The limit larger than `max_push_down_limit` is not fused, the whole input is sorted.
+    ctx.get_settings()
+        .set_setting("max_push_down_limit".to_string(), "10".to_string())?;
+    let plan = physical_plan(ctx, sql).await?;
+    let limits = sort_limits(&plan);
+    assert!(!limits.is_empty());
+    assert!(limits.iter().all(|limit| limit.is_none()));
+
+    Ok(())
+}
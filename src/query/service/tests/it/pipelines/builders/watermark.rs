@@ -0,0 +1,184 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_query::pipelines::processors::transforms::ReplicateState;
+use databend_query::pipelines::processors::transforms::TransformWatermark;
+use databend_query::sessions::QueryContext;
+use databend_query::test_kits::TestFixture;
+
+use crate::pipelines::builders::find_plan;
+use crate::pipelines::builders::query;
+
+const MINUTE: i64 = 60 * 1000 * 1000;
+
+async fn streaming_plan(ctx: Arc<QueryContext>, sql: &str) -> Result<PhysicalPlan> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let Plan::Query {
+        s_expr,
+        metadata,
+        bind_context,
+        ..
+    } = plan
+    else {
+        unreachable!("Query plan expected")
+    };
+    let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, false);
+    builder
+        .build_streaming(
+            &s_expr,
+            bind_context.column_set(),
+            10 * 60 * 1000,
+            Some("late/".to_string()),
+        )
+        .await
+}
+
+fn block(minutes: Vec<Option<i64>>, values: Vec<i32>) -> DataBlock {
+    DataBlock::new_from_columns(vec![
+        TimestampType::from_opt_data(minutes.into_iter().map(|m| m.map(|m| m * MINUTE)).collect()),
+        Int32Type::from_data(values),
+    ])
+}
+
+#[test]
+fn test_transform_watermark() -> Result<()> {
+    let late_rows = ReplicateState::create();
+    let mut transform = TransformWatermark::create(0, 10 * MINUTE, Some(late_rows.clone()));
+
+    // The watermark is 00:20 after 00:30, so 00:15 is late but 00:25 is not.
+    let output = transform.transform(block(
+        vec![Some(0), Some(30), Some(15), Some(25), None],
+        vec![1, 2, 3, 4, 5],
+    ))?;
+    assert_eq!(
+        output,
+        block(vec![Some(0), Some(30), Some(25), None], vec![1, 2, 4, 5])
+    );
+
+    // The watermark is kept across the blocks.
+    let output = transform.transform(block(vec![Some(19), Some(70), Some(50), Some(65)], vec![
+        6, 7, 8, 9,
+    ]))?;
+    assert_eq!(output, block(vec![Some(70), Some(65)], vec![7, 9]));
+
+    assert!(!late_rows.is_finished());
+    transform.on_finish()?;
+    assert!(late_rows.is_finished());
+    assert_eq!(late_rows.blocks(), vec![
+        block(vec![Some(15)], vec![3]),
+        block(vec![Some(19), Some(50)], vec![6, 8]),
+    ]);
+
+    // The late rows are dropped without a late output.
+    let mut transform = TransformWatermark::create(0, 0, None);
+    let output = transform.transform(block(vec![Some(10), Some(5)], vec![1, 2]))?;
+    assert_eq!(output, block(vec![Some(10)], vec![1]));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_watermark() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (ts TIMESTAMP, v INT)"))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "INSERT INTO {db}.t VALUES \
+            ('2024-01-01 00:00:00', 1), ('2024-01-01 00:30:00', 2), \
+            ('2024-01-01 00:15:00', 3), ('2024-01-01 00:25:00', 4), \
+            ('2024-01-01 01:10:00', 5), ('2024-01-01 00:50:00', 6), \
+            ('2024-01-01 01:05:00', 7)"
+        ))
+        .await?;
+    let sql = format!(
+        "SELECT window_start, count(*), sum(v) FROM {db}.t \
+        GROUP BY TUMBLING WINDOW(ts, INTERVAL '1 hour') \
+        ORDER BY window_start"
+    );
+
+    // The watermark is injected under the window aggregation.
+    let ctx = fixture.new_query_ctx().await?;
+    let plan = streaming_plan(ctx, &sql).await?;
+    let Some(PhysicalPlan::TumblingWindow(window)) = find_plan(&plan, |plan| {
+        matches!(plan, PhysicalPlan::TumblingWindow(_))
+    }) else {
+        unreachable!("TumblingWindow expected")
+    };
+    let PhysicalPlan::Watermark(watermark) = window.input.as_ref() else {
+        unreachable!("Watermark expected")
+    };
+    assert_eq!(watermark.watermark_delay_ms, 10 * 60 * 1000);
+    assert!(matches!(
+        watermark.late_output.as_deref(),
+        Some(PhysicalPlan::StreamOutput(_))
+    ));
+
+    // The rows are read in the order of insertion.
+    fixture.execute_command("SET max_threads = 1").await?;
+    fixture
+        .execute_command("SET enable_streaming_watermark = 1")
+        .await?;
+    fixture
+        .execute_command("SET streaming_watermark_delay_ms = 600000")
+        .await?;
+    fixture
+        .execute_command("SET streaming_late_output_path = 'late/'")
+        .await?;
+    let expected = vec![
+        "+----------------------------+----------+----------+",
+        "| Column 0                   | Column 1 | Column 2 |",
+        "+----------------------------+----------+----------+",
+        "| 2024-01-01 00:00:00.000000 | 3        | 7        |",
+        "| 2024-01-01 01:00:00.000000 | 2        | 12       |",
+        "+----------------------------+----------+----------+",
+    ];
+    assert_blocks_eq(expected, &query(&fixture, &sql).await?);
+
+    // The late rows are written to the late output path.
+    let expected = vec![
+        "+----------------------------+----------+",
+        "| Column 0                   | Column 1 |",
+        "+----------------------------+----------+",
+        "| 2024-01-01 00:15:00.000000 | 3        |",
+        "| 2024-01-01 00:50:00.000000 | 6        |",
+        "+----------------------------+----------+",
+    ];
+    let late = query(
+        &fixture,
+        "SELECT * FROM @~/late/ (FILE_FORMAT => 'parquet') ORDER BY 1",
+    )
+    .await?;
+    assert_blocks_eq(expected, &late);
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_storages_fuse::FuseTable;
+use databend_query::pipelines::processors::transforms::WAL_COMPLETE_MARKER;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::TestFixture;
+use databend_storages_common_blocks::blocks_to_parquet;
+use databend_storages_common_table_meta::table::TableCompression;
+
+use crate::pipelines::builders::query;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_recover_from_write_ahead_log() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (a int, b string)"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx.get_table("default", &db, "t").await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let operator = fuse_table.get_operator();
+    let prefix = format!(
+        "{}{}/",
+        fuse_table.meta_location_generator().wal_location_prefix(),
+        ctx.get_cluster().local_id()
+    );
+
+    // The logs left by an insert that crashed after logging all of its rows, and by one that
+    // crashed before.
+    let schema = TableSchemaRefExt::create(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::UInt64)),
+        TableField::new("b", TableDataType::String),
+    ]);
+    for (query_id, values, complete) in [
+        ("crashed", [100, 101, 102], true),
+        ("unfinished", [200, 201, 202], false),
+    ] {
+        let block = DataBlock::new_from_columns(vec![
+            UInt64Type::from_data(values.to_vec()),
+            StringType::from_data(values.iter().map(|v| format!("v{v}")).collect::<Vec<_>>()),
+        ]);
+        let mut data = vec![];
+        blocks_to_parquet(&schema, vec![block], &mut data, TableCompression::LZ4)?;
+        let location = format!("{prefix}{query_id}/");
+        operator
+            .write(&format!("{location}0.parquet"), data)
+            .await?;
+        if complete {
+            operator
+                .write(&format!("{location}{WAL_COMPLETE_MARKER}"), vec![])
+                .await?;
+        }
+    }
+
+    fixture.execute_command("SET enable_wal = 1").await?;
+    let inserted = query(
+        &fixture,
+        &format!("INSERT INTO {db}.t SELECT number, to_string(number) FROM numbers(3)"),
+    )
+    .await?;
+    // The replayed rows are not counted as inserted.
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 3        |",
+        "+----------+",
+    ];
+    assert_blocks_eq(expected, &inserted);
+
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 0        | '0'      |",
+        "| 1        | '1'      |",
+        "| 2        | '2'      |",
+        "| 100      | 'v100'   |",
+        "| 101      | 'v101'   |",
+        "| 102      | 'v102'   |",
+        "+----------+----------+",
+    ];
+    let blocks = query(&fixture, &format!("SELECT a, b FROM {db}.t ORDER BY a")).await?;
+    assert_blocks_eq(expected, &blocks);
+
+    // The replayed logs, the incomplete log and the log of the committed insert are removed.
+    let files = operator
+        .list_with(&prefix)
+        .recursive(true)
+        .await?
+        .into_iter()
+        .filter(|entry| !entry.metadata().is_dir())
+        .count();
+    assert_eq!(files, 0);
+
+    Ok(())
+}
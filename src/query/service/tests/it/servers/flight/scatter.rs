@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_query::servers::flight::v1::scatter::jump_consistent_hash;
+use databend_query::servers::flight::v1::scatter::HashFlightScatter;
+
+const NUM_KEYS: u64 = 1_000_000;
+
+/// The destination of each key when scattered to `scatter_size` nodes.
+fn route_keys(scatter_size: usize, consistent_hash: bool) -> Result<Vec<usize>> {
+    let key = RemoteExpr::ColumnRef {
+        span: None,
+        id: 0,
+        data_type: DataType::Number(NumberDataType::UInt64),
+        display_name: "k".to_string(),
+    };
+    let scatter = HashFlightScatter::try_create(
+        FunctionContext::default(),
+        vec![key],
+        scatter_size,
+        0,
+        consistent_hash,
+    )?;
+
+    let block = DataBlock::new_from_columns(vec![UInt64Type::from_data(
+        (0..NUM_KEYS).collect::<Vec<_>>(),
+    )]);
+    let mut routes = vec![usize::MAX; NUM_KEYS as usize];
+    for (index, block) in scatter.execute(block)?.into_iter().enumerate() {
+        let column = block.get_by_offset(0).to_column(block.num_rows());
+        for key in UInt64Type::try_downcast_column(&column).unwrap().iter() {
+            routes[*key as usize] = index;
+        }
+    }
+    assert!(routes.iter().all(|index| *index < scatter_size));
+    Ok(routes)
+}
+
+/// The fraction of keys which stay on the same node after adding a node,
+/// i.e. the rate the caches of the nodes are still hit.
+fn cache_hit_rate(consistent_hash: bool) -> Result<f64> {
+    let before = route_keys(8, consistent_hash)?;
+    let after = route_keys(9, consistent_hash)?;
+    let hits = before.iter().zip(after.iter()).filter(|(a, b)| a == b);
+    Ok(hits.count() as f64 / NUM_KEYS as f64)
+}
+
+#[test]
+fn test_jump_consistent_hash() {
+    for key in [0, 1, u64::MAX, 0xdead_beef] {
+        assert_eq!(jump_consistent_hash(key, 1), 0);
+        for buckets in 2..64 {
+            assert!(jump_consistent_hash(key, buckets) < buckets as u64);
+        }
+    }
+
+    // The keys are balanced between the buckets.
+    let mut counts = [0; 8];
+    for key in 0..80_000u64 {
+        counts[jump_consistent_hash(key.wrapping_mul(0x9e37_79b9_7f4a_7c15), 8) as usize] += 1;
+    }
+    assert!(counts.iter().all(|count| (9_000..11_000).contains(count)));
+}
+
+#[test]
+fn test_consistent_hash_scatter_cache_hit_rate() -> Result<()> {
+    // Both routings are balanced.
+    for consistent_hash in [true, false] {
+        let mut counts = [0; 8];
+        for index in route_keys(8, consistent_hash)? {
+            counts[index] += 1;
+        }
+        let expected = NUM_KEYS as usize / 8;
+        assert!(counts
+            .iter()
+            .all(|count| count.abs_diff(expected) < expected / 20));
+    }
+
+    // Adding a node moves about 1/9 of the keys with consistent hash,
+    // but almost all the keys with modulo hash.
+    let consistent = cache_hit_rate(true)?;
+    let modulo = cache_hit_rate(false)?;
+    assert!(consistent > 0.85, "consistent hash hit rate {}", consistent);
+    assert!(modulo < 0.2, "modulo hash hit rate {}", modulo);
+
+    Ok(())
+}
@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_base::base::tokio;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterStats;
 use databend_common_exception::Result;
 use databend_common_meta_app::storage::StorageFsConfig;
 use databend_common_meta_app::storage::StorageParams;
@@ -20,11 +24,14 @@ use databend_common_meta_app::storage::StorageS3Config;
 use databend_query::sessions::TableContext;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
+use parking_lot::Mutex;
 use wiremock::matchers::method;
 use wiremock::matchers::path;
 use wiremock::Mock;
 use wiremock::MockServer;
 use wiremock::ResponseTemplate;
+use xorf::BinaryFuse16;
+use xorf::Filter;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_get_storage_accessor_s3() -> Result<()> {
@@ -65,3 +72,55 @@ async fn test_get_storage_accessor_fs() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_runtime_filter_stats() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // The build side has the keys [0, 100).
+    let build_keys = (0..100).collect::<Vec<u64>>();
+    let filter = BinaryFuse16::try_from(&build_keys)?;
+    let stats = RuntimeFilterStats {
+        build_rows: build_keys.len() as u64,
+        ..Default::default()
+    };
+    let mut runtime_filter = RuntimeFilterInfo::default();
+    runtime_filter.add_bloom(("a".to_string(), filter.clone()));
+    runtime_filter.add_bloom_stats(("a".to_string(), Arc::new(Mutex::new(stats))));
+    ctx.set_runtime_filter((0, runtime_filter));
+
+    // The probe side has the keys [0, 1000) in 10 blocks, only 1/10 of them are matched.
+    let bloom_stats = ctx.get_bloom_runtime_filter_stats_with_id(0);
+    assert_eq!(bloom_stats.len(), 1);
+    for block in 0..10u64 {
+        let keys = block * 100..(block + 1) * 100;
+        let passed = keys.filter(|key| filter.contains(key)).collect::<Vec<_>>();
+        let mut stats = bloom_stats[0].1.lock();
+        stats.filtered_rows += 100 - passed.len() as u64;
+        stats.false_positive_rows += passed.iter().filter(|key| **key >= 100).count() as u64;
+    }
+
+    let stats = ctx.get_runtime_filter_stats();
+    assert_eq!(stats.len(), 1);
+    let (name, stats) = &stats[0];
+    assert_eq!(name, "a");
+    assert_eq!(stats.build_rows, 100);
+    assert_eq!(stats.filtered_rows + stats.false_positive_rows, 900);
+    // The false positive rate of the filter is about 1/65536.
+    assert!(stats.filtered_rows >= 890);
+
+    // The stats of another probe column of the same table are merged.
+    let mut runtime_filter = RuntimeFilterInfo::default();
+    runtime_filter.add_bloom_stats((
+        "b".to_string(),
+        Arc::new(Mutex::new(RuntimeFilterStats::default())),
+    ));
+    ctx.set_runtime_filter((0, runtime_filter));
+    let stats = ctx.get_runtime_filter_stats();
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats[0].1.build_rows, 100);
+    assert_eq!(stats[1], ("b".to_string(), RuntimeFilterStats::default()));
+
+    Ok(())
+}
@@ -21,15 +21,19 @@ use std::time::UNIX_EPOCH;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_kvapi::kvapi::KVApi;
 use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
 use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::PersistentQueueManager;
 use databend_query::sessions::QueryEntry;
 use databend_query::sessions::QueueData;
 use databend_query::sessions::QueueManager;
 use databend_query::test_kits::TestFixture;
 use log::error;
+use parking_lot::Mutex;
 
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct TestData<const PASSED: bool = false>(String);
 
 impl<const PASSED: bool> QueueData for TestData<PASSED> {
@@ -207,6 +211,679 @@ async fn test_list_acquire() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_peek_next() -> Result<()> {
+    let queue = QueueManager::<TestData>::create(1);
+    assert!(queue.peek_next().is_none());
+
+    // Hold the only permit, the following queries wait in the queue.
+    let _guard = queue.acquire(TestData("TestData0".to_string())).await?;
+
+    let test_count = 5;
+    let mut join_handles = Vec::with_capacity(test_count);
+    for index in 1..=test_count {
+        join_handles.push({
+            let queue = queue.clone();
+            databend_common_base::runtime::spawn(async move {
+                let _guard = queue
+                    .acquire(TestData(format!("TestData{}", index)))
+                    .await?;
+                Result::<()>::Ok(())
+            })
+        });
+
+        // Make sure the queries enter the queue in order.
+        while queue.length() < index {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    assert_eq!(
+        queue.peek_next().map(|data| data.0.clone()),
+        Some("TestData1".to_string())
+    );
+    // Peeking neither removes nor wakes the waiter.
+    assert_eq!(
+        queue.peek_next().map(|data| data.0.clone()),
+        Some("TestData1".to_string())
+    );
+    assert_eq!(queue.length(), test_count);
+
+    assert!(queue.remove("TestData1".to_string()));
+    assert_eq!(
+        queue.peek_next().map(|data| data.0.clone()),
+        Some("TestData2".to_string())
+    );
+
+    for key in 2..=test_count {
+        queue.remove(format!("TestData{}", key));
+    }
+    for join_handle in join_handles {
+        let _ = join_handle.await;
+    }
+    assert!(queue.peek_next().is_none());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_acquire_with_waited() -> Result<()> {
+    let queue = QueueManager::<TestData>::create(1);
+
+    // Hold the only permit, the following queries wait in the queue.
+    let _guard = queue.acquire(TestData("TestData0".to_string())).await?;
+
+    // The queries enter the queue in the reverse order of the time they have waited.
+    let test_count = 3;
+    let mut join_handles = Vec::with_capacity(test_count);
+    for index in (1..=test_count).rev() {
+        join_handles.push({
+            let queue = queue.clone();
+            let waited = Duration::from_secs((test_count + 1 - index) as u64);
+            databend_common_base::runtime::spawn(async move {
+                let _guard = queue
+                    .acquire_with_waited(TestData(format!("TestData{}", index)), waited)
+                    .await?;
+                Result::<()>::Ok(())
+            })
+        });
+
+        while queue.length() < test_count + 1 - index {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    // They are granted in the order of waiting.
+    for index in 1..=test_count {
+        assert_eq!(
+            queue.peek_next().map(|data| data.0.clone()),
+            Some(format!("TestData{}", index))
+        );
+        assert!(queue.remove(format!("TestData{}", index)));
+    }
+    for join_handle in join_handles {
+        let _ = join_handle.await;
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_checkpoint_restore() -> Result<()> {
+    let queue = QueueManager::<TestData>::create(1);
+
+    // Hold the only permit, the following queries wait in the queue.
+    let _guard = queue.acquire(TestData("TestData0".to_string())).await?;
+
+    let test_count = 3;
+    let mut join_handles = Vec::with_capacity(test_count);
+    for index in 1..=test_count {
+        join_handles.push({
+            let queue = queue.clone();
+            databend_common_base::runtime::spawn(async move {
+                let _guard = queue
+                    .acquire(TestData(format!("TestData{}", index)))
+                    .await?;
+                Result::<()>::Ok(())
+            })
+        });
+
+        // Make sure the queries enter the queue in order.
+        while queue.length() < index {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    let bytes = queue.checkpoint()?;
+    let restored = Arc::new(QueueManager::<TestData>::restore(&bytes)?);
+
+    // All the waiters are restored in the order of waiting, but not in the queue.
+    assert_eq!(restored.length(), 0);
+    assert_eq!(restored.metrics().queued, 0);
+    assert_eq!(
+        restored
+            .restored()
+            .iter()
+            .map(|data| data.0.clone())
+            .collect::<Vec<_>>(),
+        vec!["TestData1", "TestData2", "TestData3"]
+    );
+
+    // The restored entries are kept by the checkpoint of the restored manager.
+    let restored_again = QueueManager::<TestData>::restore(&restored.checkpoint()?)?;
+    assert_eq!(restored_again.restored().len(), test_count);
+
+    // The restored entries can be removed.
+    assert!(restored.remove("TestData1".to_string()));
+    assert_eq!(restored.restored().len(), test_count - 1);
+
+    // The restored entries acquire again in the reverse order, but wait from the
+    // original time of waiting.
+    let _restored_guard = restored.acquire(TestData("TestData0".to_string())).await?;
+    let mut restored_handles = Vec::with_capacity(test_count - 1);
+    for index in (2..=test_count).rev() {
+        restored_handles.push({
+            let restored = restored.clone();
+            databend_common_base::runtime::spawn(async move {
+                let _guard = restored
+                    .acquire(TestData(format!("TestData{}", index)))
+                    .await?;
+                Result::<()>::Ok(())
+            })
+        });
+
+        while restored.length() < test_count + 1 - index {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+    assert!(restored.restored().is_empty());
+    assert_eq!(
+        restored.peek_next().map(|data| data.0.clone()),
+        Some("TestData2".to_string())
+    );
+
+    for key in 1..=test_count {
+        restored.remove(format!("TestData{}", key));
+        queue.remove(format!("TestData{}", key));
+    }
+    for join_handle in join_handles.into_iter().chain(restored_handles) {
+        let _ = join_handle.await;
+    }
+    assert_eq!(restored.length(), 0);
+
+    // Restoring an invalid state fails.
+    assert!(QueueManager::<TestData>::restore(&bytes[..bytes.len() / 2]).is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_restore_with_user_permits() -> Result<()> {
+    // A checkpoint holds the permits and the waiting entries only.
+    let entries = vec![(TestData("TestData1".to_string()), Duration::from_secs(1))];
+    let bytes = bincode_v1::serialize(&(1_usize, entries)).unwrap();
+
+    let restored = QueueManager::<TestData>::restore_with_user_permits(&bytes, 1)?;
+    assert_eq!(
+        restored
+            .restored()
+            .iter()
+            .map(|data| data.0.clone())
+            .collect::<Vec<_>>(),
+        vec!["TestData1"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_persistent_queue_restart() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let meta_store = UserApiProvider::instance().get_meta_store_client();
+    let prefix = "_test_queue/persistent";
+
+    let queue = PersistentQueueManager::create(
+        QueueManager::<TestData>::create(1),
+        meta_store.clone(),
+        prefix,
+    )
+    .await?;
+    assert!(queue.restored().is_empty());
+
+    // Hold the only permit, the following queries wait in the queue.
+    let _guard = queue.acquire(TestData("TestData0".to_string())).await?;
+
+    // The query acquiring directly is not persisted.
+    let persisted_prefix = format!("{}/", prefix);
+    assert!(meta_store
+        .prefix_list_kv(&persisted_prefix)
+        .await?
+        .is_empty());
+
+    let test_count = 3;
+    let mut waiters = Vec::with_capacity(test_count);
+    for index in 1..=test_count {
+        let mut waiter = Box::pin(queue.acquire(TestData(format!("TestData{}", index))));
+
+        // Make sure the queries enter the queue in order.
+        while queue.inner().length() < index {
+            assert!(futures::poll!(waiter.as_mut()).is_pending());
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        waiters.push(waiter);
+    }
+    assert_eq!(
+        meta_store.prefix_list_kv(&persisted_prefix).await?.len(),
+        test_count
+    );
+
+    // Crash without dropping the waiters, the waiting entries are left in the meta store.
+    std::mem::forget(waiters);
+
+    // Restart, the entries are restored in the order of enqueue time, and taken out of
+    // the meta store.
+    let restarted = PersistentQueueManager::create(
+        QueueManager::<TestData>::create(1),
+        meta_store.clone(),
+        prefix,
+    )
+    .await?;
+    assert!(meta_store
+        .prefix_list_kv(&persisted_prefix)
+        .await?
+        .is_empty());
+    let restored = restarted.restored();
+    assert_eq!(
+        restored
+            .iter()
+            .map(|entry| entry.query_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["TestData1", "TestData2", "TestData3"]
+    );
+
+    // The queries reconnect in the reverse order, but keep the original order of waiting.
+    let _guard = restarted.acquire(TestData("TestData0".to_string())).await?;
+    let mut join_handles = Vec::with_capacity(test_count);
+    for index in (1..=test_count).rev() {
+        join_handles.push({
+            let queue = restarted.clone();
+            databend_common_base::runtime::spawn(async move {
+                let _guard = queue
+                    .acquire(TestData(format!("TestData{}", index)))
+                    .await?;
+                Result::<()>::Ok(())
+            })
+        });
+
+        while restarted.inner().length() < test_count + 1 - index {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+    assert!(restarted.restored().is_empty());
+    assert_eq!(
+        restarted.inner().peek_next().map(|data| data.0.clone()),
+        Some("TestData1".to_string())
+    );
+    // They wait again, so they are persisted again.
+    assert_eq!(
+        meta_store.prefix_list_kv(&persisted_prefix).await?.len(),
+        test_count
+    );
+
+    for key in 1..=test_count {
+        restarted.inner().remove(format!("TestData{}", key));
+    }
+    for join_handle in join_handles {
+        let _ = join_handle.await;
+    }
+
+    // The dequeued entries are removed from the meta store.
+    assert!(meta_store
+        .prefix_list_kv(&persisted_prefix)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
+
+#[derive(Debug)]
+struct GroupData {
+    key: String,
+    group: String,
+    weight: f64,
+    priority: u8,
+    max_wait: Option<Duration>,
+}
+
+impl GroupData {
+    fn new(key: String, group: &str, weight: f64) -> Self {
+        GroupData {
+            key,
+            group: group.to_string(),
+            weight,
+            priority: 0,
+            max_wait: None,
+        }
+    }
+
+    fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+}
+
+impl QueueData for GroupData {
+    type Key = String;
+
+    fn get_key(&self) -> Self::Key {
+        self.key.clone()
+    }
+
+    fn remove_error_message(key: Option<Self::Key>) -> ErrorCode {
+        ErrorCode::Internal(format!("{:?}", key))
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(1000)
+    }
+
+    fn need_acquire_to_queue(&self) -> bool {
+        true
+    }
+
+    fn group(&self) -> String {
+        self.group.clone()
+    }
+
+    fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    fn priority(&self) -> u8 {
+        self.priority
+    }
+
+    fn user(&self) -> Option<String> {
+        Some(self.group.clone())
+    }
+
+    fn max_wait(&self) -> Option<Duration> {
+        self.max_wait
+    }
+}
+
+/// Enqueue the entries in order while the only permit is held, and return the groups
+/// of the entries in the order of being granted.
+async fn granted_groups(entries: Vec<GroupData>) -> Result<Vec<String>> {
+    let queue = QueueManager::<GroupData>::create(1);
+    let guard = queue
+        .acquire(GroupData::new("blocker".to_string(), "blocker", 1.0))
+        .await?;
+
+    let granted = Arc::new(Mutex::new(Vec::new()));
+    let mut join_handles = Vec::with_capacity(entries.len());
+    for (index, data) in entries.into_iter().enumerate() {
+        join_handles.push({
+            let queue = queue.clone();
+            let granted = granted.clone();
+            databend_common_base::runtime::spawn(async move {
+                let group = data.group.clone();
+                let _guard = queue.acquire(data).await?;
+                granted.lock().push(group);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                Result::<()>::Ok(())
+            })
+        });
+
+        // Make sure the entries enter the queue in order.
+        while queue.length() <= index {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    drop(guard);
+    for join_handle in join_handles {
+        join_handle.await.unwrap()?;
+    }
+    assert_eq!(queue.length(), 0);
+
+    let granted = granted.lock().clone();
+    Ok(granted)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fair_acquire() -> Result<()> {
+    // A heavy group floods the queue before a group of short jobs arrives.
+    let mut entries = (0..20)
+        .map(|index| GroupData::new(format!("heavy{}", index), "heavy", 1.0))
+        .collect::<Vec<_>>();
+    entries.extend((0..2).map(|index| GroupData::new(format!("short{}", index), "short", 1.0)));
+
+    let granted = granted_groups(entries).await?;
+    assert_eq!(granted.len(), 22);
+
+    // The short jobs are interleaved with the heavy ones instead of waiting for all of them.
+    let short = granted
+        .iter()
+        .enumerate()
+        .filter(|(_, group)| *group == "short")
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    assert_eq!(short.len(), 2);
+    assert!(short.iter().all(|index| *index < 4), "{:?}", granted);
+
+    // All the heavy jobs are granted as well.
+    let heavy = granted.iter().filter(|group| *group == "heavy").count();
+    assert_eq!(heavy, 20);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_weighted_acquire() -> Result<()> {
+    let mut entries = (0..6)
+        .map(|index| GroupData::new(format!("a{}", index), "a", 3.0))
+        .collect::<Vec<_>>();
+    entries.extend((0..6).map(|index| GroupData::new(format!("b{}", index), "b", 1.0)));
+
+    let granted = granted_groups(entries).await?;
+
+    // The group `a` is granted three permits for every one of the group `b`.
+    let count_b = |n: usize| granted[..n].iter().filter(|group| *group == "b").count();
+    assert_eq!(count_b(4), 1, "{:?}", granted);
+    assert_eq!(count_b(8), 2, "{:?}", granted);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_priority_acquire() -> Result<()> {
+    // The high priority entries arrive after the low priority ones of the same group.
+    let entries = vec![
+        GroupData::new("low0".to_string(), "low0", 1.0),
+        GroupData::new("low1".to_string(), "low1", 1.0),
+        GroupData::new("high0".to_string(), "high0", 1.0).with_priority(2),
+        GroupData::new("mid0".to_string(), "mid0", 1.0).with_priority(1),
+        GroupData::new("high1".to_string(), "high1", 1.0).with_priority(2),
+        GroupData::new("low2".to_string(), "low2", 1.0),
+    ];
+
+    // The ties of the same priority are granted in the order of arrival.
+    let granted = granted_groups(entries).await?;
+    assert_eq!(granted, vec![
+        "high0", "high1", "mid0", "low0", "low1", "low2"
+    ]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_priority_abort() -> Result<()> {
+    let queue = QueueManager::<GroupData>::create(1);
+    let high = queue
+        .acquire(GroupData::new("high0".to_string(), "a", 1.0).with_priority(1))
+        .await?;
+
+    let acquire = |data: GroupData| {
+        let queue = queue.clone();
+        databend_common_base::runtime::spawn(async move {
+            let _guard = queue.acquire(data).await?;
+            Result::<()>::Ok(())
+        })
+    };
+    let low = acquire(GroupData::new("low0".to_string(), "a", 1.0));
+    while queue.length() < 1 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let waiting_high = acquire(GroupData::new("high1".to_string(), "a", 1.0).with_priority(1));
+    while queue.length() < 2 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(queue.peek_next().unwrap().key, "high1");
+
+    // Aborting the waiting high priority entry makes the low priority one the next.
+    assert!(queue.remove("high1".to_string()));
+    assert!(waiting_high.await.unwrap().is_err());
+    assert_eq!(queue.peek_next().unwrap().key, "low0");
+
+    // Aborting the high priority query in flight grants the permit to the low priority one.
+    drop(high);
+    low.await.unwrap()?;
+    assert_eq!(queue.length(), 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_user_permits_acquire() -> Result<()> {
+    let queue = QueueManager::<GroupData>::create_with_user_permits(4, 1);
+    let a0 = queue
+        .acquire(GroupData::new("a0".to_string(), "a", 1.0))
+        .await?;
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let a1 = {
+        let queue = queue.clone();
+        databend_common_base::runtime::spawn(async move {
+            let guard = queue
+                .acquire(GroupData::new("a1".to_string(), "a", 1.0))
+                .await?;
+            tx.send(()).unwrap();
+            drop(guard);
+            Result::<()>::Ok(())
+        })
+    };
+    while queue.length() < 1 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    // The user a is at the limit, the user b is granted while the global permits are spare.
+    let b0 = queue
+        .acquire(GroupData::new("b0".to_string(), "b", 1.0))
+        .await?;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(rx.try_recv().is_err());
+    assert_eq!(queue.peek_next().unwrap().key, "a1");
+
+    // The waiter of the user a is granted after the running query of the user finishes.
+    drop(a0);
+    a1.await.unwrap()?;
+    assert!(rx.try_recv().is_ok());
+    assert_eq!(queue.length(), 0);
+    drop(b0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_max_wait_acquire() -> Result<()> {
+    let queue = QueueManager::<GroupData>::create(1);
+
+    // The max wait does not limit the entry which is granted without waiting.
+    let guard = queue
+        .acquire(GroupData::new("a0".to_string(), "a", 1.0).with_max_wait(Duration::ZERO))
+        .await?;
+
+    let waiter = {
+        let queue = queue.clone();
+        databend_common_base::runtime::spawn(async move {
+            queue
+                .acquire(GroupData::new("a1".to_string(), "a", 1.0))
+                .await
+                .map(|_| ())
+        })
+    };
+    while queue.length() < 1 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    let instant = Instant::now();
+    let res = queue
+        .acquire(
+            GroupData::new("a2".to_string(), "a", 1.0).with_max_wait(Duration::from_millis(100)),
+        )
+        .await;
+    assert!(instant.elapsed() >= Duration::from_millis(100));
+    assert_eq!(res.err().unwrap().code(), ErrorCode::TIMEOUT);
+    // The entry is removed from the queue, the other waiter keeps waiting.
+    assert_eq!(queue.length(), 1);
+    assert_eq!(queue.peek_next().unwrap().key, "a1");
+
+    drop(guard);
+    waiter.await.unwrap()?;
+    assert_eq!(queue.length(), 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_queue_metrics() -> Result<()> {
+    let queue = QueueManager::<GroupData>::create(1);
+    // Granted without waiting, it is not counted.
+    let guard = queue
+        .acquire(GroupData::new("a0".to_string(), "a", 1.0))
+        .await?;
+
+    let acquire = |data: GroupData| {
+        let queue = queue.clone();
+        databend_common_base::runtime::spawn(async move {
+            let _guard = queue.acquire(data).await?;
+            Result::<()>::Ok(())
+        })
+    };
+    let granted = acquire(GroupData::new("a1".to_string(), "a", 1.0));
+    let aborted = acquire(GroupData::new("a2".to_string(), "a", 1.0));
+    let timed_out = acquire(
+        GroupData::new("a3".to_string(), "a", 1.0).with_max_wait(Duration::from_millis(50)),
+    );
+    while queue.length() < 3 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    assert!(queue.remove("a2".to_string()));
+    assert!(aborted.await.unwrap().is_err());
+    assert!(timed_out.await.unwrap().is_err());
+
+    let metrics = queue.metrics();
+    assert_eq!(metrics.total_enqueued, 3);
+    assert_eq!(metrics.total_dequeued, 0);
+    assert_eq!(metrics.total_aborted, 2);
+    assert_eq!(metrics.queued, 1);
+    assert_eq!(metrics.wait_time_us.count, 0);
+
+    drop(guard);
+    granted.await.unwrap()?;
+
+    let metrics = queue.metrics();
+    assert_eq!(metrics.total_enqueued, 3);
+    assert_eq!(metrics.total_dequeued, 1);
+    assert_eq!(metrics.total_aborted, 2);
+    assert_eq!(metrics.queued, 0);
+    // The granted entry has waited for the timeout of the other one at least.
+    let histogram = &metrics.wait_time_us;
+    assert_eq!(histogram.count, 1);
+    assert!(histogram.sum >= 50_000);
+    assert!(histogram.buckets.windows(2).all(|w| w[0].1 <= w[1].1));
+    assert_eq!(
+        histogram
+            .buckets
+            .iter()
+            .find(|(bound, _)| *bound == 10_000)
+            .unwrap()
+            .1,
+        0
+    );
+    assert_eq!(histogram.buckets.last().unwrap().1, 1);
+    assert!(serde_json::to_string(&metrics).is_ok());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_heavy_actions() -> Result<()> {
     struct Query {
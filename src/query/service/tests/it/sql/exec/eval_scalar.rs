@@ -0,0 +1,80 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_query::sessions::QueryContext;
+use databend_query::test_kits::TestFixture;
+
+async fn physical_plan(ctx: Arc<QueryContext>, sql: &str) -> Result<PhysicalPlan> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    match plan {
+        Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } => {
+            let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, false);
+            builder.build(&s_expr, bind_context.column_set()).await
+        }
+        _ => unreachable!("Query plan expected"),
+    }
+}
+
+fn collect_eval_scalars<'a>(plan: &'a PhysicalPlan, eval_scalars: &mut Vec<&'a PhysicalPlan>) {
+    if matches!(plan, PhysicalPlan::EvalScalar(_)) {
+        eval_scalars.push(plan);
+    }
+    for child in plan.children() {
+        collect_eval_scalars(child, eval_scalars);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_eval_scalar_without_dead_exprs() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    // Only `a + 1` is consumed, `b` and the derived `a` are not evaluated.
+    let sql = "SELECT a + 1 FROM (SELECT number + 1 AS a, number + 2 AS b FROM numbers(10))";
+    let plan = physical_plan(fixture.new_query_ctx().await?, sql).await?;
+    let mut eval_scalars = vec![];
+    collect_eval_scalars(&plan, &mut eval_scalars);
+    assert!(!eval_scalars.is_empty());
+    for plan in eval_scalars {
+        let PhysicalPlan::EvalScalar(eval_scalar) = plan else {
+            unreachable!("EvalScalar expected");
+        };
+        // The output columns of the expressions follow the input columns.
+        let num_input_columns = eval_scalar.input.output_schema()?.num_fields();
+        for i in 0..eval_scalar.exprs.len() {
+            assert!(eval_scalar.projections.contains(&(num_input_columns + i)));
+        }
+    }
+
+    // An alias evaluates nothing, no `EvalScalar` is built for it.
+    let sql = "SELECT number AS a FROM numbers(10)";
+    let plan = physical_plan(fixture.new_query_ctx().await?, sql).await?;
+    let mut eval_scalars = vec![];
+    collect_eval_scalars(&plan, &mut eval_scalars);
+    assert!(eval_scalars.is_empty());
+    Ok(())
+}
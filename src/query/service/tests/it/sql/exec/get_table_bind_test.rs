@@ -33,6 +33,7 @@ use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterReady;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterStats;
 use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::ContextError;
@@ -707,6 +708,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_row_access_policy(&self, _table: &dyn Table) -> Result<Option<String>> {
+        todo!()
+    }
+
     fn get_fuse_version(&self) -> String {
         todo!()
     }
@@ -952,6 +957,17 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_bloom_runtime_filter_stats_with_id(
+        &self,
+        _id: usize,
+    ) -> Vec<(String, Arc<Mutex<RuntimeFilterStats>>)> {
+        todo!()
+    }
+
+    fn get_runtime_filter_stats(&self) -> Vec<(String, RuntimeFilterStats)> {
+        todo!()
+    }
+
     fn get_inlist_runtime_filter_with_id(&self, _id: usize) -> Vec<Expr<String>> {
         todo!()
     }
@@ -0,0 +1,143 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::VariantType;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::pipelines::processors::transforms::JsonPathElement;
+use databend_query::pipelines::processors::transforms::TransformJsonExtract;
+use databend_query::sessions::QueryContext;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+use jsonb::RawJsonb;
+
+async fn physical_plan(ctx: Arc<QueryContext>, sql: &str) -> Result<PhysicalPlan> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    match plan {
+        Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } => {
+            let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, false);
+            builder.build(&s_expr, bind_context.column_set()).await
+        }
+        _ => unreachable!("Query plan expected"),
+    }
+}
+
+fn collect_json_extracts<'a>(plan: &'a PhysicalPlan, extracts: &mut Vec<&'a PhysicalPlan>) {
+    if matches!(plan, PhysicalPlan::JsonExtract(_)) {
+        extracts.push(plan);
+    }
+    for child in plan.children() {
+        collect_json_extracts(child, extracts);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_json_extract_plan() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let sql = "create table t_json_extract(a int, v variant null)";
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let _ = interpreter
+        .execute(ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    // The key paths of `v` are extracted by a single JsonExtract, and the
+    // repeated key path is only extracted once.
+    let sql = "select v['a'][0], v['b'], v['a'][0]::int + 1 from t_json_extract";
+    let plan = physical_plan(ctx.clone(), sql).await?;
+    let mut extracts = vec![];
+    collect_json_extracts(&plan, &mut extracts);
+    assert_eq!(extracts.len(), 1);
+    let PhysicalPlan::JsonExtract(json_extract) = extracts[0] else {
+        unreachable!()
+    };
+    let paths = json_extract
+        .paths
+        .iter()
+        .map(|(_, path)| path.join(","))
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec![r#""a",0"#, r#""b""#]);
+
+    // A single key path is evaluated by get_by_keypath.
+    let sql = "select v['a'][0], v['a'][0]::int + 1 from t_json_extract";
+    let plan = physical_plan(ctx.clone(), sql).await?;
+    let mut extracts = vec![];
+    collect_json_extracts(&plan, &mut extracts);
+    assert!(extracts.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_transform_json_extract() -> Result<()> {
+    let values = [r#"{"a":[1,2,3],"b":{"c":10}}"#, r#"{"b":"x"}"#, "[1,2]"];
+    let column = VariantType::from_data(
+        values
+            .iter()
+            .map(|v| jsonb::parse_value(v.as_bytes()).unwrap().to_vec())
+            .collect(),
+    );
+    let block = DataBlock::new_from_columns(vec![column]);
+
+    let paths = vec![
+        JsonPathElement::parse_path(&[r#""a""#.to_string(), "0".to_string()])?,
+        JsonPathElement::parse_path(&[r#""a""#.to_string(), "-1".to_string()])?,
+        JsonPathElement::parse_path(&[r#""b""#.to_string()])?,
+    ];
+    let mut transform = TransformJsonExtract::new(0, paths);
+    let block = transform.transform(block)?;
+    assert_eq!(block.num_columns(), 4);
+
+    let expected = [
+        [Some("1"), Some("3"), Some(r#"{"c":10}"#)],
+        [None, None, Some(r#""x""#)],
+        [None, None, None],
+    ];
+    for (row, expected) in expected.iter().enumerate() {
+        for (i, expected) in expected.iter().enumerate() {
+            let column = block.get_by_offset(i + 1).to_column(block.num_rows());
+            let Column::Nullable(column) = column else {
+                unreachable!()
+            };
+            let value = column.validity.get_bit(row).then(|| {
+                RawJsonb::new(column.column.as_variant().unwrap().index(row).unwrap()).to_string()
+            });
+            assert_eq!(value.as_deref(), *expected);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_sql::executor::physical_plans::Exchange;
+use databend_common_sql::executor::physical_plans::FragmentKind;
+use databend_common_sql::executor::physical_plans::Limit;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::executor::PhysicalPlanValidator;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<()> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let _ = interpreter
+        .execute(ctx)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(())
+}
+
+async fn physical_plan(ctx: Arc<QueryContext>, sql: &str) -> Result<PhysicalPlan> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    match plan {
+        Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } => {
+            let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, false);
+            builder.build(&s_expr, bind_context.column_set()).await
+        }
+        _ => unreachable!("Query plan expected"),
+    }
+}
+
+fn find_plan<'a>(
+    plan: &'a PhysicalPlan,
+    predicate: &impl Fn(&PhysicalPlan) -> bool,
+) -> Option<&'a PhysicalPlan> {
+    if predicate(plan) {
+        return Some(plan);
+    }
+    plan.children()
+        .find_map(|child| find_plan(child, predicate))
+}
+
+fn exchange(input: PhysicalPlan, kind: FragmentKind) -> PhysicalPlan {
+    PhysicalPlan::Exchange(Exchange {
+        plan_id: 0,
+        input: Box::new(input),
+        kind,
+        keys: vec![],
+        ignore_exchange: false,
+        allow_adjust_parallelism: true,
+        consistent_hash: false,
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_validate_merge_inside_broadcast() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let scan = physical_plan(
+        fixture.new_query_ctx().await?,
+        "SELECT number FROM numbers(10)",
+    )
+    .await?;
+    PhysicalPlanValidator::validate(&scan)?;
+
+    let valid = exchange(
+        exchange(scan.clone(), FragmentKind::Expansive),
+        FragmentKind::Merge,
+    );
+    PhysicalPlanValidator::validate(&valid)?;
+
+    let invalid = exchange(exchange(scan, FragmentKind::Merge), FragmentKind::Expansive);
+    let err = PhysicalPlanValidator::validate(&invalid).unwrap_err();
+    assert!(err.message().contains("Merge exchange"), "{}", err);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_validate_dangling_aggregate_partial() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let plan = physical_plan(
+        fixture.new_query_ctx().await?,
+        "SELECT number % 3 AS k, count(*) FROM numbers(10) GROUP BY k",
+    )
+    .await?;
+    PhysicalPlanValidator::validate(&plan)?;
+
+    let partial = find_plan(&plan, &|plan| {
+        matches!(plan, PhysicalPlan::AggregatePartial(_))
+    })
+    .expect("AggregatePartial expected")
+    .clone();
+
+    // A partial aggregation at the root of a plan.
+    let err = PhysicalPlanValidator::validate(&partial).unwrap_err();
+    assert!(err.message().contains("AggregatePartial"), "{}", err);
+
+    // A partial aggregation followed by an operator other than Exchange or AggregateFinal.
+    let invalid = PhysicalPlan::Limit(Limit {
+        plan_id: 0,
+        input: Box::new(partial.clone()),
+        limit: Some(1),
+        offset: 0,
+        pre_limit: Some(1),
+        stat_info: None,
+    });
+    let err = PhysicalPlanValidator::validate(&invalid).unwrap_err();
+    assert!(err.message().contains("AggregatePartial"), "{}", err);
+
+    let valid = exchange(partial, FragmentKind::Normal);
+    PhysicalPlanValidator::validate(&valid)?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_validate_orphaned_runtime_filter() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    execute_sql(
+        fixture.new_query_ctx().await?,
+        "CREATE TABLE probe_t (number int) AS SELECT number FROM numbers(1000)",
+    )
+    .await?;
+    execute_sql(
+        fixture.new_query_ctx().await?,
+        "CREATE TABLE build_t (number int) AS SELECT number FROM numbers(10)",
+    )
+    .await?;
+
+    let plan = physical_plan(
+        fixture.new_query_ctx().await?,
+        "SELECT * FROM probe_t JOIN build_t ON probe_t.number = build_t.number",
+    )
+    .await?;
+    PhysicalPlanValidator::validate(&plan)?;
+
+    let mut join = match find_plan(&plan, &|plan| matches!(plan, PhysicalPlan::HashJoin(_))) {
+        Some(PhysicalPlan::HashJoin(join)) => join.clone(),
+        _ => unreachable!("HashJoin expected"),
+    };
+    let (_, scan_id) = join
+        .probe_keys_rt
+        .iter_mut()
+        .flatten()
+        .next()
+        .expect("runtime filter expected");
+    *scan_id = usize::MAX;
+
+    let err = PhysicalPlanValidator::validate(&PhysicalPlan::HashJoin(join)).unwrap_err();
+    assert!(err.message().contains("runtime filter"), "{}", err);
+    Ok(())
+}
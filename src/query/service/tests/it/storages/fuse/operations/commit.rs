@@ -32,6 +32,7 @@ use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterReady;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterStats;
 use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::ContextError;
@@ -41,11 +42,13 @@ use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::GrantObject;
@@ -217,6 +220,60 @@ async fn test_fuse_occ_retry() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_write_conflict_retry() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (id INT)"))
+        .await?;
+    // Each insert writes a segment of its own, the deletes below touch disjoint segments.
+    let num_rows = 8;
+    for i in 0..num_rows {
+        fixture
+            .execute_command(&format!("INSERT INTO {db}.t VALUES ({i})"))
+            .await?;
+    }
+
+    // All the deletes start from the same snapshot, all but the first committed one
+    // conflict with a concurrent delete and are retried on the latest snapshot.
+    let deletes = (0..num_rows).map(|i| {
+        fixture.execute_command(&format!(
+            "SETTINGS (write_conflict_retry_count = 100, write_conflict_backoff_ms = 1) DELETE FROM {db}.t WHERE id = {i}"
+        ))
+    });
+    let results = futures::future::join_all(deletes).await;
+    let succeeded = results.iter().filter(|res| res.is_ok()).count();
+    assert!(succeeded >= 1);
+    for res in results {
+        if let Err(e) = res {
+            assert!(
+                [
+                    ErrorCode::TABLE_VERSION_MISMATCHED,
+                    ErrorCode::OCC_RETRY_FAILURE,
+                    ErrorCode::UNRESOLVABLE_CONFLICT
+                ]
+                .contains(&e.code()),
+                "unexpected error: {e}"
+            );
+        }
+    }
+
+    let blocks = fixture
+        .execute_query(&format!("SELECT COUNT(*) FROM {db}.t"))
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let count = blocks[0].get_by_offset(0).value.index(0).unwrap();
+    assert_eq!(
+        count,
+        ScalarRef::Number(NumberScalar::UInt64((num_rows - succeeded) as u64))
+    );
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_last_snapshot_hint() -> Result<()> {
     let fixture = TestFixture::setup().await?;
@@ -596,6 +653,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_row_access_policy(&self, _table: &dyn Table) -> Result<Option<String>> {
+        todo!()
+    }
+
     fn get_fuse_version(&self) -> String {
         todo!()
     }
@@ -817,6 +878,17 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_bloom_runtime_filter_stats_with_id(
+        &self,
+        _id: usize,
+    ) -> Vec<(String, Arc<Mutex<RuntimeFilterStats>>)> {
+        todo!()
+    }
+
+    fn get_runtime_filter_stats(&self) -> Vec<(String, RuntimeFilterStats)> {
+        todo!()
+    }
+
     fn get_inlist_runtime_filter_with_id(&self, _id: usize) -> Vec<Expr<String>> {
         todo!()
     }
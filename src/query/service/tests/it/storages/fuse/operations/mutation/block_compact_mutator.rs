@@ -142,6 +142,8 @@ async fn do_compact(ctx: Arc<QueryContext>, table: Arc<dyn Table>) -> Result<boo
             },
             update_stream_meta: vec![],
             deduplicated_label: None,
+            write_conflict_retry_count: settings.get_write_conflict_retry_count()?,
+            write_conflict_backoff_ms: settings.get_write_conflict_backoff_ms()?,
             plan_id: u32::MAX,
             recluster_info: None,
             table_meta_timestamps,
@@ -0,0 +1,131 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::plan::PrewhereInfo;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_sql::executor::physical_plans::TableScan;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::executor::PhysicalPlanReplacer;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_query::pipelines::executor::ExecutorSettings;
+use databend_query::pipelines::executor::PipelinePullingExecutor;
+use databend_query::schedulers::build_query_pipeline_without_render_result_set;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
+use databend_query::stream::PullingExecutorStream;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+/// Replace the prewhere filter of the table scan with a constant `false`,
+/// so that only the row selector column can select the rows.
+struct MockPrewhereFilter {
+    prewhere: Option<PrewhereInfo>,
+}
+
+impl PhysicalPlanReplacer for MockPrewhereFilter {
+    fn replace_table_scan(&mut self, plan: &TableScan) -> Result<PhysicalPlan> {
+        let mut plan = plan.clone();
+        if let Some(prewhere) = plan
+            .source
+            .push_downs
+            .as_mut()
+            .and_then(|push_downs| push_downs.prewhere.as_mut())
+        {
+            prewhere.filter = RemoteExpr::Constant {
+                span: None,
+                scalar: Scalar::Boolean(false),
+                data_type: DataType::Boolean,
+            };
+            self.prewhere = Some(prewhere.clone());
+        }
+        Ok(PhysicalPlan::TableScan(plan))
+    }
+}
+
+async fn execute_with_mock_filter(
+    ctx: Arc<QueryContext>,
+    sql: &str,
+) -> Result<(PrewhereInfo, usize)> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let Plan::Query {
+        s_expr,
+        metadata,
+        bind_context,
+        ..
+    } = plan
+    else {
+        unreachable!("Query plan expected")
+    };
+    let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx.clone(), false);
+    let plan = builder.build(&s_expr, bind_context.column_set()).await?;
+
+    let mut replacer = MockPrewhereFilter { prewhere: None };
+    let plan = replacer.replace(&plan)?;
+    let prewhere = replacer
+        .prewhere
+        .expect("the table scan should have prewhere");
+
+    let build_res = build_query_pipeline_without_render_result_set(&ctx, &plan).await?;
+    let settings = ExecutorSettings::try_create(ctx.clone())?;
+    let executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+    ctx.set_executor(executor.get_inner())?;
+    let blocks = PullingExecutorStream::create(executor)?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    Ok((prewhere, blocks.iter().map(|block| block.num_rows()).sum()))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_prewhere_row_selector() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!(
+            "CREATE TABLE {db}.t (a INT NOT NULL, sel BOOLEAN NOT NULL) storage_format = 'native'"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "INSERT INTO {db}.t VALUES (1, true), (2, false), (3, true), (4, true)"
+        ))
+        .await?;
+    let sql = format!("SELECT a FROM {db}.t WHERE sel");
+
+    // The mocked prewhere filter is evaluated, all the rows are filtered out.
+    let ctx = fixture.new_query_ctx().await?;
+    let (prewhere, rows) = execute_with_mock_filter(ctx, &sql).await?;
+    assert_eq!(prewhere.row_selector_column, None);
+    assert_eq!(rows, 0);
+
+    // The rows are selected by the column `sel` and the mocked filter is not evaluated.
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings()
+        .set_setting("enable_prewhere_row_selector".to_string(), "1".to_string())?;
+    let (prewhere, rows) = execute_with_mock_filter(ctx, &sql).await?;
+    assert_eq!(prewhere.row_selector_column, Some(1));
+    assert_eq!(rows, 3);
+
+    Ok(())
+}
@@ -14,5 +14,6 @@
 
 mod fuse;
 mod null;
+mod result_cache;
 mod statistics;
 mod system;
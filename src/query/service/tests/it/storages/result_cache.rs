@@ -0,0 +1,300 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_types::UpsertKV;
+use databend_common_sql::executor::physical_plans::ResultCacheScan;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_storages_result_cache::gen_result_cache_prefix;
+use databend_common_storages_result_cache::ResultCacheInvalidator;
+use databend_common_storages_result_cache::ResultCacheMetaManager;
+use databend_common_users::UserApiProvider;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::pipelines::executor::ExecutorSettings;
+use databend_query::pipelines::executor::PipelinePullingExecutor;
+use databend_query::schedulers::build_query_pipeline;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
+use databend_query::stream::PullingExecutorStream;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+/// Run `sql` with the result cache enabled, returns the number of rows of the result.
+async fn query_with_result_cache(ctx: Arc<QueryContext>, sql: &str) -> Result<usize> {
+    let settings = ctx.get_settings();
+    settings.set_setting("enable_query_result_cache".to_string(), "1".to_string())?;
+    settings.set_setting(
+        "query_result_cache_min_execute_secs".to_string(),
+        "0".to_string(),
+    )?;
+
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let blocks = interpreter
+        .execute(ctx)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    Ok(blocks.iter().map(|block| block.num_rows()).sum())
+}
+
+/// The `columns_used` of the cache entries, waits for the entries to be written.
+async fn cached_columns(tenant: &str, expected: usize) -> Result<Vec<Vec<(u64, u32)>>> {
+    let meta_mgr =
+        ResultCacheMetaManager::create(UserApiProvider::instance().get_meta_store_client(), 0);
+    let prefix = gen_result_cache_prefix(tenant);
+    let mut values = vec![];
+    for _ in 0..100 {
+        values = meta_mgr.list(&prefix).await?;
+        if values.len() == expected {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    Ok(values.into_iter().map(|value| value.columns_used).collect())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_result_cache_invalidate_for_column_drop() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (a INT, b INT, c INT)"))
+        .await?;
+    fixture
+        .execute_command(&format!("INSERT INTO {db}.t VALUES (1, 2, 3), (4, 5, 6)"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let tenant = ctx.get_tenant();
+    let tenant = tenant.tenant_name();
+    let table_id = ctx.get_table("default", &db, "t").await?.get_id();
+
+    // The cache entry is tagged with the columns read by the query.
+    query_with_result_cache(ctx, &format!("SELECT a FROM {db}.t WHERE b > 0")).await?;
+    assert_eq!(cached_columns(tenant, 1).await?, vec![vec![
+        (table_id, 0),
+        (table_id, 1)
+    ]]);
+
+    // Dropping a column not read by the query keeps the entry.
+    let invalidator =
+        ResultCacheInvalidator::create(UserApiProvider::instance().get_meta_store_client());
+    assert_eq!(
+        invalidator
+            .invalidate_for_column_drop(tenant, table_id, 2)
+            .await?,
+        0
+    );
+    assert_eq!(
+        invalidator
+            .invalidate_for_column_drop(tenant, table_id + 1, 1)
+            .await?,
+        0
+    );
+    assert_eq!(cached_columns(tenant, 1).await?.len(), 1);
+
+    // Dropping a column read by the query removes the entry.
+    assert_eq!(
+        invalidator
+            .invalidate_for_column_drop(tenant, table_id, 1)
+            .await?,
+        1
+    );
+    assert!(cached_columns(tenant, 0).await?.is_empty());
+
+    // ALTER TABLE DROP COLUMN invalidates the entries.
+    let ctx = fixture.new_query_ctx().await?;
+    query_with_result_cache(ctx, &format!("SELECT a FROM {db}.t")).await?;
+    assert_eq!(cached_columns(tenant, 1).await?.len(), 1);
+    fixture
+        .execute_command(&format!("ALTER TABLE {db}.t DROP COLUMN a"))
+        .await?;
+    assert!(cached_columns(tenant, 0).await?.is_empty());
+
+    Ok(())
+}
+
+/// Execute the plan of `sql` as a `ResultCacheScan` of the cached result at `location`,
+/// returns the number of rows of the result.
+async fn execute_result_cache_scan(
+    ctx: Arc<QueryContext>,
+    sql: &str,
+    location: &str,
+    num_rows: usize,
+    cache_expiry: Option<SystemTime>,
+) -> Result<usize> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let Plan::Query {
+        s_expr,
+        metadata,
+        bind_context,
+        ..
+    } = plan
+    else {
+        unreachable!("Query plan expected")
+    };
+    let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx.clone(), false);
+    let input = builder.build(&s_expr, bind_context.column_set()).await?;
+    let result_schema = DataSchemaRefExt::create(
+        bind_context
+            .columns
+            .iter()
+            .map(|column| DataField::new(&column.index.to_string(), *column.data_type.clone()))
+            .collect(),
+    );
+
+    let mut plan = PhysicalPlan::ResultCacheScan(Box::new(ResultCacheScan {
+        plan_id: 0,
+        input: Box::new(input),
+        location: location.to_string(),
+        num_rows,
+        result_schema,
+        cache_expiry,
+    }));
+    plan.adjust_plan_id(&mut 0);
+
+    let build_res = build_query_pipeline(&ctx, &bind_context.columns, &plan, false).await?;
+    let settings = ExecutorSettings::try_create(ctx.clone())?;
+    let executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+    ctx.set_executor(executor.get_inner())?;
+    let blocks = PullingExecutorStream::create(executor)?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    Ok(blocks.iter().map(|block| block.num_rows()).sum())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_result_cache_scan_expiry() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (a INT)"))
+        .await?;
+    fixture
+        .execute_command(&format!("INSERT INTO {db}.t VALUES (1), (2), (3)"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let tenant = ctx.get_tenant();
+    let tenant = tenant.tenant_name();
+    let sql = format!("SELECT a FROM {db}.t ORDER BY a");
+    query_with_result_cache(ctx, &sql).await?;
+    assert_eq!(cached_columns(tenant, 1).await?.len(), 1);
+    let meta_mgr =
+        ResultCacheMetaManager::create(UserApiProvider::instance().get_meta_store_client(), 0);
+    let value = meta_mgr
+        .list(&gen_result_cache_prefix(tenant))
+        .await?
+        .remove(0);
+    assert_eq!(value.num_rows, 3);
+
+    // The live result differs from the cached one from now on.
+    fixture
+        .execute_command(&format!("INSERT INTO {db}.t VALUES (4)"))
+        .await?;
+
+    // The cache is read before it expires.
+    let ctx = fixture.new_query_ctx().await?;
+    let expiry = SystemTime::now() + Duration::from_secs(3600);
+    let rows =
+        execute_result_cache_scan(ctx, &sql, &value.location, value.num_rows, Some(expiry)).await?;
+    assert_eq!(rows, 3);
+
+    // A cache of 1ms TTL expires before the execution starts, the query is executed.
+    let ctx = fixture.new_query_ctx().await?;
+    let expiry = SystemTime::now() + Duration::from_millis(1);
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    let rows =
+        execute_result_cache_scan(ctx, &sql, &value.location, value.num_rows, Some(expiry)).await?;
+    assert_eq!(rows, 4);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_result_cache_refresh_after_expiry() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t (a INT)"))
+        .await?;
+    fixture
+        .execute_command(&format!("INSERT INTO {db}.t VALUES (1), (2), (3)"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let tenant = ctx.get_tenant();
+    let tenant = tenant.tenant_name();
+    let sql = format!("SELECT a FROM {db}.t");
+    assert_eq!(query_with_result_cache(ctx, &sql).await?, 3);
+    assert_eq!(cached_columns(tenant, 1).await?.len(), 1);
+    fixture
+        .execute_command(&format!("INSERT INTO {db}.t VALUES (4)"))
+        .await?;
+
+    // Backdate the entry so that it has expired, but is still kept by the meta store.
+    let kv_store = UserApiProvider::instance().get_meta_store_client();
+    let (meta_key, entry) = kv_store
+        .prefix_list_kv(&gen_result_cache_prefix(tenant))
+        .await?
+        .remove(0);
+    let mut value: serde_json::Value = serde_json::from_slice(&entry.data)?;
+    value["query_time"] = 0.into();
+    kv_store
+        .upsert_kv(UpsertKV::update(&meta_key, &serde_json::to_vec(&value)?))
+        .await?;
+
+    // The expired entry is not read even if the inconsistent result is allowed, the query is
+    // executed and its result refreshes the entry.
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_setting(
+        "query_result_cache_allow_inconsistent".to_string(),
+        "1".to_string(),
+    )?;
+    assert_eq!(query_with_result_cache(ctx.clone(), &sql).await?, 4);
+    assert!(ctx.get_result_cache_key(&ctx.get_id()).is_none());
+    let meta_mgr = ResultCacheMetaManager::create(kv_store, 0);
+    let mut num_rows = 0;
+    for _ in 0..100 {
+        num_rows = meta_mgr
+            .get(meta_key.clone())
+            .await?
+            .map_or(0, |v| v.num_rows);
+        if num_rows == 4 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(num_rows, 4);
+
+    Ok(())
+}
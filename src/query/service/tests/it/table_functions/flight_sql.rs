@@ -0,0 +1,125 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow_flight::flight_service_server::FlightServiceServer;
+use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::net::TcpListener;
+use databend_common_base::runtime::Runtime;
+use databend_common_config::InnerConfig;
+use databend_common_config::UserAuthConfig;
+use databend_common_config::UserConfig;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_sorted_eq;
+use databend_common_meta_app::principal::PasswordHashMethod;
+use databend_query::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestFixture;
+use futures::TryStreamExt;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::transport::Server;
+
+const TEST_USER: &str = "test_user";
+const TEST_PASSWORD: &str = "test_password";
+
+fn prepare_config() -> InnerConfig {
+    let hash_method = PasswordHashMethod::DoubleSha1;
+    let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
+
+    let user_config = UserConfig {
+        name: TEST_USER.to_string(),
+        auth: UserAuthConfig {
+            auth_type: "double_sha1_password".to_string(),
+            auth_string: Some(hex::encode(hash_value)),
+        },
+    };
+    ConfigBuilder::create()
+        .add_user(TEST_USER, user_config)
+        .build()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_flight_sql_table_function() -> Result<()> {
+    let fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    let runtime = Runtime::with_default_worker_threads()?;
+    runtime.block_on(async move {
+        // The remote Flight SQL service, it's listening once the listener is bound.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let serve_future = Server::builder()
+            .add_service(FlightServiceServer::new(FlightSqlServiceImpl::create()))
+            .serve_with_incoming_shutdown(TcpListenerStream::new(listener), async {
+                shutdown_rx.await.unwrap()
+            });
+
+        let request_future = async {
+            // All the remote columns.
+            let query = format!(
+                "SELECT * FROM flight_sql('{endpoint}', 'SELECT number, number * 10 AS ten, concat(''n'', number::String) AS name FROM numbers(3)', '{TEST_USER}', '{TEST_PASSWORD}')"
+            );
+            let blocks = fixture
+                .execute_query(&query)
+                .await?
+                .try_collect::<Vec<_>>()
+                .await?;
+            let expected = vec![
+                "+----------+----------+----------+",
+                "| Column 0 | Column 1 | Column 2 |",
+                "+----------+----------+----------+",
+                "| 0        | 0        | 'n0'     |",
+                "| 1        | 10       | 'n1'     |",
+                "| 2        | 20       | 'n2'     |",
+                "+----------+----------+----------+",
+            ];
+            assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+            // Only the used remote columns are read.
+            let query = format!(
+                "SELECT name FROM flight_sql('{endpoint}', 'SELECT number, concat(''n'', number::String) AS name FROM numbers(3)', '{TEST_USER}', '{TEST_PASSWORD}') WHERE number > 0"
+            );
+            let blocks = fixture
+                .execute_query(&query)
+                .await?
+                .try_collect::<Vec<_>>()
+                .await?;
+            let expected = vec![
+                "+----------+",
+                "| Column 0 |",
+                "+----------+",
+                "| 'n1'     |",
+                "| 'n2'     |",
+                "+----------+",
+            ];
+            assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+            // Wrong password.
+            let query = format!(
+                "SELECT * FROM flight_sql('{endpoint}', 'SELECT 1', '{TEST_USER}', 'wrong_password')"
+            );
+            assert!(fixture.execute_query(&query).await.is_err());
+
+            Ok::<_, databend_common_exception::ErrorCode>(())
+        };
+        tokio::pin!(serve_future);
+
+        let result = tokio::select! {
+            _ = &mut serve_future => panic!("server returned first"),
+            result = request_future => result,
+        };
+        shutdown_tx.send(()).unwrap();
+        serve_future.await.unwrap();
+        result
+    })
+}
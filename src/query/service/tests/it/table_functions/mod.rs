@@ -13,4 +13,5 @@
 //  limitations under the License.W
 
 mod ai_to_sql;
+mod flight_sql;
 mod numbers_table;
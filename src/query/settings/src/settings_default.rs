@@ -317,6 +317,13 @@ impl DefaultSettings {
                     scope: SettingScope::Session,
                     range: None,
                 }),
+                ("http_authorization_token", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "Sets the bearer token sent in the Authorization header when reading files from HTTP URLs.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Session,
+                    range: None,
+                }),
                 ("enable_dphyp", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables dphyp join order algorithm.",
@@ -422,6 +429,20 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_semi_anti_hash_join", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables the dedicated executor for LEFT SEMI/ANTI JOIN without other conditions, which only keeps the distinct build keys.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_replicate_scalar_subquery", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables executing the uncorrelated scalar subqueries only once and sharing the results with all the workers.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("max_execute_time_in_seconds", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum query execution time in seconds. Setting it to 0 means no limit.",
@@ -471,6 +492,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("scan_prefetch_depth", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the number of blocks a full table scan reads ahead of the block being processed, 0 disables read-ahead.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=64)),
+                }),
                 ("load_file_metadata_expire_hours", DefaultSettingValue {
                     value: UserSettingValue::UInt64(24),
                     desc: "Sets the hours that the metadata of files you load data from with COPY INTO will expire in.",
@@ -535,6 +563,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("query_result_cache_prewarm_max_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum byte size of the results cached in background for the queries of the session that keep missing the result cache, 0 disables it.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("enable_hive_parquet_predict_pushdown", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables hive parquet predict pushdown  by setting this variable to 1, default value: 1",
@@ -556,6 +591,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=100)),
                 }),
+                ("agg_partial_early_termination_ratio", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the ratio (in percent) of distinct groups to input rows at which the partial aggregator stops accumulating and passes rows through to the final aggregator, 0 is disabled.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=100)),
+                }),
                 ("window_partition_spilling_memory_ratio", DefaultSettingValue {
                     value: UserSettingValue::UInt64(60),
                     desc: "Sets the maximum memory ratio in bytes that a window partitioner can use before spilling data to storage during query execution.",
@@ -605,6 +647,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(4 * 1024..=u64::MAX)),
                 }),
+                ("sort_spill_threshold_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sorts with external spill if estimated_rows * avg_row_bytes of a sort without limit exceeds this number of bytes, which is also the size of each spilled run, 0 to disable.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("group_by_shuffle_mode", DefaultSettingValue {
                     value: UserSettingValue::String(String::from("before_merge")),
                     desc: "Group by shuffle mode, 'before_partial' is more balanced, but more data needs to exchange.",
@@ -612,6 +661,34 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::String(vec!["before_partial".into(), "before_merge".into()])),
                 }),
+                ("enable_consistent_hash_exchange", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables jump consistent hash routing for hash exchanges of high-cardinality keys.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_skew_detection", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables logging a warning when the rows of a hash exchange are skewed across partitions.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("skew_detection_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(4),
+                    desc: "A hash exchange is skewed if its most loaded partition has more rows than this times the average.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(1..=u64::MAX)),
+                }),
+                ("enable_convert_timezone", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables reading the TIMESTAMP columns of a SELECT as wall-clock times of the session timezone.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("efficiently_memory_group_by", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Memory is used efficiently, but this may cause performance degradation.",
@@ -884,6 +961,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_prewhere_row_selector", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables using a boolean column computed ahead, e.g. by an accelerator, as the prewhere row selector instead of evaluating the prewhere filter",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_experimental_aggregate_hashtable", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables experimental aggregate hashtable",
@@ -1018,6 +1102,83 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("query_queue_weight", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Sets the weight of the user in the query queue, the users share the queue in proportion to their weights.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(1..=1000)),
+                }),
+                ("query_queue_priority", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the priority of the query in the query queue, the queries of a higher priority are granted before the queries of a lower priority.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u8::MAX as u64)),
+                }),
+                ("max_query_queue_wait_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum time in milliseconds a query waits in the query queue before it fails, 0 for no limit.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("enable_wal", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables writing the rows of INSERT INTO ... SELECT to a write-ahead log, so that they can be recovered if the commit fails.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("wal_flush_interval_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the interval in milliseconds at which the buffered rows are flushed to the write-ahead log.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("enable_async_aggregate", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables running the queries with aggregation in the background, the query returns a query id at once, and the result is read by RESULT_SCAN(query_id) once finished.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("async_aggregate_notify_webhook", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "Sets the URL notified by a POST request when a background aggregation finishes.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: None,
+                }),
+                ("sequence_next_batch_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Sets the minimum number of sequence values fetched from the meta service at a time by nextval, the values left unused by a query are skipped.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(1..=u64::MAX)),
+                }),
+                ("enable_streaming_watermark", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables tracking the watermark of the time column before the time window aggregations, the rows arriving after the watermark are late and not aggregated.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("streaming_watermark_delay_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the delay in milliseconds of the watermark behind the latest time seen, a row is late if its time is before the watermark.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("streaming_late_output_path", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "Sets the path in the user stage the late rows are written to as parquet files, the late rows are dropped if empty.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: None,
+                }),
                 ("geometry_output_format", DefaultSettingValue {
                     value: UserSettingValue::String("GeoJSON".to_owned()),
                     desc: "Display format for GEOMETRY values.",
@@ -1053,6 +1214,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("max_pivot_columns", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "The maximum count of columns generated by a PIVOT in a query.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(1..=u64::MAX)),
+                }),
                 ("enable_loser_tree_merge_sort", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables loser tree merge sort",
@@ -1067,6 +1235,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_sorted_merge", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables merging the sorted streams received by the coordinator with SortedMerge in distributed sort",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_last_snapshot_location_hint", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables writing last_snapshot_location_hint object",
@@ -1123,6 +1298,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("cte_materialization_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Materializes a CTE referenced more than once if estimated_rows * avg_row_bytes * (consumer_count - 1) exceeds this number of bytes, 0 to always inline CTEs.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("flight_connection_max_retry_times", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "The maximum retry count for cluster flight. Disable if 0.",
@@ -1242,6 +1424,20 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("write_conflict_retry_count", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "The maximum number of times a commit is retried after a write conflict with a concurrent transaction, 0 to retry until the retry timeout.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u32::MAX as u64)),
+                }),
+                ("write_conflict_backoff_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(5),
+                    desc: "The initial delay in milliseconds before retrying a commit after a write conflict, doubled on each retry.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(1..=60 * 1000)),
+                }),
             ]);
 
             Ok(Arc::new(DefaultSettings {
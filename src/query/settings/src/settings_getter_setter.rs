@@ -218,6 +218,10 @@ impl Settings {
         }
     }
 
+    pub fn get_scan_prefetch_depth(&self) -> Result<u64> {
+        self.try_get_u64("scan_prefetch_depth")
+    }
+
     pub fn get_max_memory_usage(&self) -> Result<u64> {
         self.try_get_u64("max_memory_usage")
     }
@@ -357,6 +361,14 @@ impl Settings {
         Ok(self.try_get_u64("enable_bloom_runtime_filter")? != 0)
     }
 
+    pub fn get_enable_semi_anti_hash_join(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_semi_anti_hash_join")? != 0)
+    }
+
+    pub fn get_enable_replicate_scalar_subquery(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_replicate_scalar_subquery")? != 0)
+    }
+
     pub fn get_prefer_broadcast_join(&self) -> Result<bool> {
         Ok(self.try_get_u64("prefer_broadcast_join")? != 0)
     }
@@ -418,6 +430,10 @@ impl Settings {
         self.try_get_string("query_tag")
     }
 
+    pub fn get_http_authorization_token(&self) -> Result<String> {
+        self.try_get_string("http_authorization_token")
+    }
+
     pub fn get_hide_options_in_show_create_table(&self) -> Result<bool> {
         Ok(self.try_get_u64("hide_options_in_show_create_table")? != 0)
     }
@@ -454,10 +470,18 @@ impl Settings {
         Ok(self.try_get_u64("query_result_cache_allow_inconsistent")? != 0)
     }
 
+    pub fn get_query_result_cache_prewarm_max_bytes(&self) -> Result<u64> {
+        self.try_get_u64("query_result_cache_prewarm_max_bytes")
+    }
+
     pub fn get_aggregate_spilling_memory_ratio(&self) -> Result<usize> {
         Ok(self.try_get_u64("aggregate_spilling_memory_ratio")? as usize)
     }
 
+    pub fn get_agg_partial_early_termination_ratio(&self) -> Result<f64> {
+        Ok(self.try_get_u64("agg_partial_early_termination_ratio")? as f64 / 100.0)
+    }
+
     pub fn get_window_partition_spilling_to_disk_bytes_limit(&self) -> Result<usize> {
         Ok(self.try_get_u64("window_partition_spilling_to_disk_bytes_limit")? as usize)
     }
@@ -486,10 +510,30 @@ impl Settings {
         Ok(self.try_get_u64("sort_spilling_memory_ratio")? as usize)
     }
 
+    pub fn get_sort_spill_threshold_bytes(&self) -> Result<u64> {
+        self.try_get_u64("sort_spill_threshold_bytes")
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
 
+    pub fn get_enable_consistent_hash_exchange(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_consistent_hash_exchange")? == 1)
+    }
+
+    pub fn get_enable_skew_detection(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_skew_detection")? == 1)
+    }
+
+    pub fn get_enable_convert_timezone(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_convert_timezone")? == 1)
+    }
+
+    pub fn get_skew_detection_threshold(&self) -> Result<u64> {
+        self.try_get_u64("skew_detection_threshold")
+    }
+
     pub fn get_efficiently_memory_group_by(&self) -> Result<bool> {
         Ok(self.try_get_u64("efficiently_memory_group_by")? == 1)
     }
@@ -659,6 +703,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_parquet_prewhere")? != 0)
     }
 
+    pub fn get_enable_prewhere_row_selector(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_prewhere_row_selector")? != 0)
+    }
+
     pub fn get_numeric_cast_option(&self) -> Result<String> {
         self.try_get_string("numeric_cast_option")
     }
@@ -766,6 +814,50 @@ impl Settings {
         self.try_get_u64("statement_queued_timeout_in_seconds")
     }
 
+    pub fn get_query_queue_weight(&self) -> Result<u64> {
+        self.try_get_u64("query_queue_weight")
+    }
+
+    pub fn get_query_queue_priority(&self) -> Result<u8> {
+        Ok(self.try_get_u64("query_queue_priority")? as u8)
+    }
+
+    pub fn get_max_query_queue_wait_ms(&self) -> Result<u64> {
+        self.try_get_u64("max_query_queue_wait_ms")
+    }
+
+    pub fn get_enable_wal(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_wal")? != 0)
+    }
+
+    pub fn get_wal_flush_interval_ms(&self) -> Result<u64> {
+        self.try_get_u64("wal_flush_interval_ms")
+    }
+
+    pub fn get_enable_async_aggregate(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_async_aggregate")? != 0)
+    }
+
+    pub fn get_async_aggregate_notify_webhook(&self) -> Result<String> {
+        self.try_get_string("async_aggregate_notify_webhook")
+    }
+
+    pub fn get_sequence_next_batch_size(&self) -> Result<u64> {
+        self.try_get_u64("sequence_next_batch_size")
+    }
+
+    pub fn get_enable_streaming_watermark(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_streaming_watermark")? != 0)
+    }
+
+    pub fn get_streaming_watermark_delay_ms(&self) -> Result<u64> {
+        self.try_get_u64("streaming_watermark_delay_ms")
+    }
+
+    pub fn get_streaming_late_output_path(&self) -> Result<String> {
+        self.try_get_string("streaming_late_output_path")
+    }
+
     pub fn get_geometry_output_format(&self) -> Result<GeometryDataType> {
         let v = self.try_get_string("geometry_output_format")?;
         v.parse()
@@ -783,6 +875,10 @@ impl Settings {
         self.try_get_u64("max_set_operator_count")
     }
 
+    pub fn get_max_pivot_columns(&self) -> Result<u64> {
+        self.try_get_u64("max_pivot_columns")
+    }
+
     pub fn get_enable_loser_tree_merge_sort(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_loser_tree_merge_sort")? == 1)
     }
@@ -791,6 +887,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_parallel_multi_merge_sort")? == 1)
     }
 
+    pub fn get_enable_sorted_merge(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_sorted_merge")? == 1)
+    }
+
     pub fn get_format_null_as_str(&self) -> Result<bool> {
         Ok(self.try_get_u64("format_null_as_str")? == 1)
     }
@@ -839,6 +939,10 @@ impl Settings {
         Ok(self.try_get_u64("persist_materialized_cte")? != 0)
     }
 
+    pub fn get_cte_materialization_threshold(&self) -> Result<u64> {
+        self.try_get_u64("cte_materialization_threshold")
+    }
+
     pub fn get_flight_max_retry_times(&self) -> Result<u64> {
         self.try_get_u64("flight_connection_max_retry_times")
     }
@@ -924,4 +1028,12 @@ impl Settings {
     pub fn get_enable_use_vacuum2_to_purge_transient_table_data(&self) -> Result<bool> {
         Ok(self.try_get_u64("use_vacuum2_to_purge_transient_table_data")? == 1)
     }
+
+    pub fn get_write_conflict_retry_count(&self) -> Result<u32> {
+        Ok(self.try_get_u64("write_conflict_retry_count")? as u32)
+    }
+
+    pub fn get_write_conflict_backoff_ms(&self) -> Result<u64> {
+        self.try_get_u64("write_conflict_backoff_ms")
+    }
 }
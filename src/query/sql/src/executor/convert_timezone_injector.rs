@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+
+use crate::executor::physical_plans::ConvertTimezone;
+use crate::executor::physical_plans::TableScan;
+use crate::executor::physical_plans::TzDirection;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanReplacer;
+use crate::IndexType;
+
+/// Put a `ConvertTimezone` on top of each `TableScan` that reads `TIMESTAMP` columns,
+/// so the timestamps stored as UTC are read as wall-clock times of `tz`.
+pub struct ConvertTimezoneInjector {
+    tz: String,
+}
+
+impl ConvertTimezoneInjector {
+    pub fn inject(plan: &PhysicalPlan, tz: String) -> Result<PhysicalPlan> {
+        ConvertTimezoneInjector { tz }.replace(plan)
+    }
+}
+
+impl PhysicalPlanReplacer for ConvertTimezoneInjector {
+    fn replace_table_scan(&mut self, plan: &TableScan) -> Result<PhysicalPlan> {
+        let scan = PhysicalPlan::TableScan(plan.clone());
+        let mut columns = Vec::new();
+        for field in plan.output_schema()?.fields() {
+            if field.data_type().remove_nullable() == DataType::Timestamp {
+                columns.push(field.name().parse::<IndexType>()?);
+            }
+        }
+        if columns.is_empty() {
+            return Ok(scan);
+        }
+
+        Ok(PhysicalPlan::ConvertTimezone(ConvertTimezone {
+            plan_id: 0,
+            input: Box::new(scan),
+            columns,
+            tz: self.tz.clone(),
+            direction: TzDirection::ToLocal,
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+}
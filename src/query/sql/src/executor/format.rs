@@ -14,6 +14,8 @@
 
 use std::collections::HashMap;
 
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_ast::ast::FormatTreeNode;
 use databend_common_base::base::format_byte_size;
 use databend_common_base::runtime::profile::get_statistics_desc;
@@ -28,43 +30,91 @@ use itertools::Itertools;
 use super::physical_plans::AddStreamColumn;
 use crate::binder::MutationType;
 use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::AbortIfEmpty;
 use crate::executor::physical_plans::AggregateExpand;
 use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregateFunctionDesc;
 use crate::executor::physical_plans::AggregatePartial;
+use crate::executor::physical_plans::AsyncAggregate;
 use crate::executor::physical_plans::AsyncFunction;
+use crate::executor::physical_plans::BitmapIndexScan;
+use crate::executor::physical_plans::BlockSample;
+use crate::executor::physical_plans::BloomBuild;
+use crate::executor::physical_plans::BloomLookup;
 use crate::executor::physical_plans::CacheScan;
+use crate::executor::physical_plans::Classify;
+use crate::executor::physical_plans::ClusterSort;
 use crate::executor::physical_plans::ColumnMutation;
 use crate::executor::physical_plans::CommitSink;
+use crate::executor::physical_plans::Compact;
+use crate::executor::physical_plans::ConditionalLimit;
 use crate::executor::physical_plans::ConstantTableScan;
+use crate::executor::physical_plans::ConvertTimezone;
 use crate::executor::physical_plans::CopyIntoLocation;
 use crate::executor::physical_plans::CopyIntoTable;
+use crate::executor::physical_plans::Correlation;
+use crate::executor::physical_plans::CteMaterialization;
 use crate::executor::physical_plans::DistributedInsertSelect;
+use crate::executor::physical_plans::Emit;
+use crate::executor::physical_plans::EnforceSchema;
 use crate::executor::physical_plans::EvalScalar;
 use crate::executor::physical_plans::Exchange;
 use crate::executor::physical_plans::ExchangeSink;
 use crate::executor::physical_plans::ExchangeSource;
 use crate::executor::physical_plans::ExpressionScan;
 use crate::executor::physical_plans::Filter;
+use crate::executor::physical_plans::FlatMap;
 use crate::executor::physical_plans::FragmentKind;
+use crate::executor::physical_plans::FunctionImport;
+use crate::executor::physical_plans::FuzzyMatch;
+use crate::executor::physical_plans::GeoScan;
+use crate::executor::physical_plans::GroupingId;
 use crate::executor::physical_plans::HashJoin;
+use crate::executor::physical_plans::Histogram;
+use crate::executor::physical_plans::JsonEach;
+use crate::executor::physical_plans::JsonExtract;
+use crate::executor::physical_plans::JsonTable;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::MaskApply;
+use crate::executor::physical_plans::MaterializeAgg;
 use crate::executor::physical_plans::Mutation;
 use crate::executor::physical_plans::MutationManipulate;
 use crate::executor::physical_plans::MutationOrganize;
 use crate::executor::physical_plans::MutationSource;
 use crate::executor::physical_plans::MutationSplit;
+use crate::executor::physical_plans::MvRefreshPartial;
+use crate::executor::physical_plans::PrewarmCache;
 use crate::executor::physical_plans::ProjectSet;
+use crate::executor::physical_plans::Qualify;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::RangeJoinType;
+use crate::executor::physical_plans::Replicate;
+use crate::executor::physical_plans::ResultCacheScan;
+use crate::executor::physical_plans::RowAccessPolicy;
 use crate::executor::physical_plans::RowFetch;
+use crate::executor::physical_plans::SchemaEvolve;
+use crate::executor::physical_plans::SemiHashJoin;
+use crate::executor::physical_plans::SequenceNext;
+use crate::executor::physical_plans::SessionWindow;
+use crate::executor::physical_plans::SkewDetection;
 use crate::executor::physical_plans::Sort;
+use crate::executor::physical_plans::SortMergeAggregate;
+use crate::executor::physical_plans::SortMergeJoin;
+use crate::executor::physical_plans::SortedMerge;
+use crate::executor::physical_plans::SpillSort;
+use crate::executor::physical_plans::StreamOutput;
+use crate::executor::physical_plans::TDigestAgg;
 use crate::executor::physical_plans::TableScan;
+use crate::executor::physical_plans::Transpose;
+use crate::executor::physical_plans::TumblingWindow;
 use crate::executor::physical_plans::Udf;
 use crate::executor::physical_plans::UnionAll;
+use crate::executor::physical_plans::Watermark;
 use crate::executor::physical_plans::Window;
 use crate::executor::physical_plans::WindowFunction;
 use crate::executor::physical_plans::WindowPartition;
+use crate::executor::physical_plans::WriteAheadLog;
+use crate::executor::physical_plans::Zip;
 use crate::executor::PhysicalPlan;
 use crate::planner::Metadata;
 use crate::planner::MetadataRef;
@@ -137,6 +187,20 @@ impl PhysicalPlan {
                     children,
                 ))
             }
+            PhysicalPlan::SemiHashJoin(plan) | PhysicalPlan::AntiHashJoin(plan) => {
+                let build_child = plan.build.format_join(metadata)?;
+                let probe_child = plan.probe.format_join(metadata)?;
+
+                let children = vec![
+                    FormatTreeNode::with_children("Build".to_string(), vec![build_child]),
+                    FormatTreeNode::with_children("Probe".to_string(), vec![probe_child]),
+                ];
+
+                Ok(FormatTreeNode::with_children(
+                    format!("{}: {}", self.name(), plan.join_type),
+                    children,
+                ))
+            }
             PhysicalPlan::RangeJoin(plan) => {
                 let left_child = plan.left.format_join(metadata)?;
                 let right_child = plan.right.format_join(metadata)?;
@@ -157,6 +221,20 @@ impl PhysicalPlan {
                     children,
                 ))
             }
+            PhysicalPlan::SortMergeJoin(plan) => {
+                let left_child = plan.left.format_join(metadata)?;
+                let right_child = plan.right.format_join(metadata)?;
+
+                let children = vec![
+                    FormatTreeNode::with_children("Left".to_string(), vec![left_child]),
+                    FormatTreeNode::with_children("Right".to_string(), vec![right_child]),
+                ];
+
+                Ok(FormatTreeNode::with_children(
+                    format!("SortMergeJoin: {}", plan.join_type),
+                    children,
+                ))
+            }
             PhysicalPlan::UnionAll(union_all) => {
                 let left_child = union_all.left.format_join(metadata)?;
                 let right_child = union_all.right.format_join(metadata)?;
@@ -262,6 +340,23 @@ pub fn format_partial_tree(
                 children,
             ))
         }
+        PhysicalPlan::SemiHashJoin(semi_join) | PhysicalPlan::AntiHashJoin(semi_join) => {
+            let build_child = format_partial_tree(&semi_join.build, metadata, profs)?;
+            let probe_child = format_partial_tree(&semi_join.probe, metadata, profs)?;
+            let mut children = vec![];
+            if let Some(info) = &semi_join.stat_info {
+                let items = plan_stats_info_to_format_tree(info);
+                children.extend(items);
+            }
+            append_output_rows_info(&mut children, profs, semi_join.plan_id);
+            children.push(build_child);
+            children.push(probe_child);
+
+            Ok(FormatTreeNode::with_children(
+                format!("{}: {}", plan.name(), semi_join.join_type),
+                children,
+            ))
+        }
         PhysicalPlan::RangeJoin(plan) => {
             let left_child = format_partial_tree(&plan.left, metadata, profs)?;
             let right_child = format_partial_tree(&plan.right, metadata, profs)?;
@@ -282,6 +377,20 @@ pub fn format_partial_tree(
                 children,
             ))
         }
+        PhysicalPlan::SortMergeJoin(plan) => {
+            let left_child = format_partial_tree(&plan.left, metadata, profs)?;
+            let right_child = format_partial_tree(&plan.right, metadata, profs)?;
+
+            let children = vec![
+                FormatTreeNode::with_children("Left".to_string(), vec![left_child]),
+                FormatTreeNode::with_children("Right".to_string(), vec![right_child]),
+            ];
+
+            Ok(FormatTreeNode::with_children(
+                format!("SortMergeJoin: {}", plan.join_type),
+                children,
+            ))
+        }
         PhysicalPlan::UnionAll(union_all) => {
             let left_child = format_partial_tree(&union_all.left, metadata, profs)?;
             let right_child = format_partial_tree(&union_all.right, metadata, profs)?;
@@ -351,32 +460,61 @@ fn to_format_tree(
             aggregate_partial_to_format_tree(plan, metadata, profs)
         }
         PhysicalPlan::AggregateFinal(plan) => aggregate_final_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::SortMergeAggregate(plan) => {
+            sort_merge_aggregate_to_format_tree(plan, metadata, profs)
+        }
+        PhysicalPlan::MaterializeAgg(plan) => materialize_agg_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Window(plan) => window_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::TumblingWindow(plan) => tumbling_window_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::SessionWindow(plan) => session_window_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::Watermark(plan) => watermark_to_format_tree(plan, metadata, profs),
         PhysicalPlan::WindowPartition(plan) => {
             window_partition_to_format_tree(plan, metadata, profs)
         }
         PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::ConditionalLimit(plan) => {
+            conditional_limit_to_format_tree(plan, metadata, profs)
+        }
         PhysicalPlan::RowFetch(plan) => row_fetch_to_format_tree(plan, metadata, profs),
         PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::SemiHashJoin(plan) => {
+            semi_hash_join_to_format_tree("SemiHashJoin", plan, metadata, profs)
+        }
+        PhysicalPlan::AntiHashJoin(plan) => {
+            semi_hash_join_to_format_tree("AntiHashJoin", plan, metadata, profs)
+        }
         PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::SkewDetection(plan) => skew_detection_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::ConvertTimezone(plan) => {
+            convert_timezone_to_format_tree(plan, metadata, profs)
+        }
+        PhysicalPlan::MaskApply(plan) => mask_apply_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::RowAccessPolicy(plan) => {
+            row_access_policy_to_format_tree(plan, metadata, profs)
+        }
         PhysicalPlan::UnionAll(plan) => union_all_to_format_tree(plan, metadata, profs),
         PhysicalPlan::ExchangeSource(plan) => exchange_source_to_format_tree(plan, metadata),
         PhysicalPlan::ExchangeSink(plan) => exchange_sink_to_format_tree(plan, metadata, profs),
         PhysicalPlan::DistributedInsertSelect(plan) => {
             distributed_insert_to_format_tree(plan.as_ref(), metadata, profs)
         }
+        PhysicalPlan::BloomBuild(plan) => bloom_build_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::WriteAheadLog(plan) => write_ahead_log_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Recluster(_) => Ok(FormatTreeNode::new("Recluster".to_string())),
         PhysicalPlan::HilbertPartition(_) => {
             Ok(FormatTreeNode::new("HilbertPartition".to_string()))
         }
         PhysicalPlan::CompactSource(_) => Ok(FormatTreeNode::new("CompactSource".to_string())),
+        PhysicalPlan::Compact(plan) => compact_to_format_tree(plan, metadata, profs),
         PhysicalPlan::CommitSink(plan) => commit_sink_to_format_tree(plan, metadata, profs),
         PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Udf(plan) => udf_to_format_tree(plan, metadata, profs),
         PhysicalPlan::RangeJoin(plan) => range_join_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::SortMergeJoin(plan) => sort_merge_join_to_format_tree(plan, metadata, profs),
         PhysicalPlan::CopyIntoTable(plan) => copy_into_table(plan),
         PhysicalPlan::CopyIntoLocation(plan) => copy_into_location(plan),
+        PhysicalPlan::EnforceSchema(plan) => enforce_schema_to_format_tree(plan, metadata, profs),
         PhysicalPlan::ReplaceAsyncSourcer(_) => {
             Ok(FormatTreeNode::new("ReplaceAsyncSourcer".to_string()))
         }
@@ -399,6 +537,10 @@ fn to_format_tree(
         PhysicalPlan::ConstantTableScan(plan) => constant_table_scan_to_format_tree(plan, metadata),
         PhysicalPlan::ExpressionScan(plan) => expression_scan_to_format_tree(plan, metadata, profs),
         PhysicalPlan::CacheScan(plan) => cache_scan_to_format_tree(plan, metadata),
+        PhysicalPlan::ResultCacheScan(plan) => {
+            result_cache_scan_to_format_tree(plan, metadata, profs)
+        }
+        PhysicalPlan::FunctionImport(plan) => function_import_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Duplicate(plan) => {
             let mut children = Vec::new();
             children.push(FormatTreeNode::new(format!(
@@ -412,6 +554,22 @@ fn to_format_tree(
                 children,
             ))
         }
+        PhysicalPlan::Scatter(plan) => {
+            let mut children = Vec::new();
+            for (condition, target) in plan.conditions.iter().zip(plan.targets.iter()) {
+                children.push(FormatTreeNode::new(format!(
+                    "branch {}: {}",
+                    target,
+                    condition.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+                )));
+            }
+            append_profile_info(&mut children, profs, plan.plan_id);
+            children.push(to_format_tree(&plan.input, metadata, profs)?);
+            Ok(FormatTreeNode::with_children(
+                "Scatter".to_string(),
+                children,
+            ))
+        }
         PhysicalPlan::Shuffle(plan) => to_format_tree(&plan.input, metadata, profs), /* will be hided in explain */
         PhysicalPlan::ChunkFilter(plan) => {
             if plan.predicates.iter().all(|x| x.is_none()) {
@@ -485,6 +643,57 @@ fn to_format_tree(
             ))
         }
         PhysicalPlan::AsyncFunction(plan) => async_function_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::SequenceNext(plan) => sequence_next_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::AbortIfEmpty(plan) => abort_if_empty_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::PrewarmCache(plan) => {
+            let mut children = vec![FormatTreeNode::new(format!(
+                "max bytes: {}",
+                plan.max_bytes
+            ))];
+            children.extend(
+                plan.queries
+                    .iter()
+                    .map(|query| FormatTreeNode::new(format!("query: {}", query))),
+            );
+            append_profile_info(&mut children, profs, plan.plan_id);
+            Ok(FormatTreeNode::with_children(
+                "PrewarmCache".to_string(),
+                children,
+            ))
+        }
+        PhysicalPlan::AsyncAggregate(plan) => async_aggregate_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::Zip(plan) => zip_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::FlatMap(plan) => flat_map_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::ClusterSort(plan) => cluster_sort_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::SpillSort(plan) => spill_sort_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::JsonExtract(plan) => json_extract_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::Transpose(plan) => transpose_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::Qualify(plan) => qualify_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::Histogram(plan) => histogram_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::Correlation(plan) => correlation_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::TDigestAgg(plan) => tdigest_agg_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::Emit(plan) => emit_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::GroupingId(plan) => grouping_id_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::Replicate(plan) => replicate_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::CteMaterialization(plan) => {
+            cte_materialization_to_format_tree(plan, metadata, profs)
+        }
+        PhysicalPlan::StreamOutput(plan) => stream_output_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::JsonEach(plan) => json_each_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::JsonTable(plan) => json_table_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::Classify(plan) => classify_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::SortedMerge(plan) => sorted_merge_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::SchemaEvolve(plan) => schema_evolve_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::MvRefreshPartial(plan) => {
+            mv_refresh_partial_to_format_tree(plan, metadata, profs)
+        }
+        PhysicalPlan::BlockSample(plan) => block_sample_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::FuzzyMatch(plan) => fuzzy_match_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::GeoScan(plan) => geo_scan_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::BloomLookup(plan) => bloom_lookup_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::BitmapIndexScan(plan) => {
+            bitmap_index_scan_to_format_tree(plan, metadata, profs)
+        }
     }
 }
 
@@ -729,8 +938,53 @@ fn copy_into_table(plan: &CopyIntoTable) -> Result<FormatTreeNode<String>> {
     )))
 }
 
-fn copy_into_location(_: &CopyIntoLocation) -> Result<FormatTreeNode<String>> {
-    Ok(FormatTreeNode::new("CopyIntoLocation".to_string()))
+fn copy_into_location(plan: &CopyIntoLocation) -> Result<FormatTreeNode<String>> {
+    if plan.partition_by.is_empty() {
+        return Ok(FormatTreeNode::new("CopyIntoLocation".to_string()));
+    }
+    let partition_by = plan
+        .partition_by
+        .iter()
+        .map(|offset| plan.input_schema.field(*offset).name().as_str())
+        .collect::<Vec<_>>();
+    Ok(FormatTreeNode::with_children(
+        "CopyIntoLocation".to_string(),
+        vec![FormatTreeNode::new(format!(
+            "partition by: [{}]",
+            partition_by.join(", ")
+        ))],
+    ))
+}
+
+fn enforce_schema_to_format_tree(
+    plan: &EnforceSchema,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let target_columns = plan
+        .target_schema
+        .fields()
+        .iter()
+        .map(|field| format!("{} {}", field.name(), field.data_type()))
+        .join(", ");
+    let mut children = vec![
+        FormatTreeNode::new(format!("target columns: [{target_columns}]")),
+        FormatTreeNode::new(format!("cast mode: {}", plan.cast_mode)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "EnforceSchema".to_string(),
+        children,
+    ))
 }
 
 fn table_scan_to_format_tree(
@@ -865,6 +1119,17 @@ fn table_scan_to_format_tree(
         children.push(FormatTreeNode::new(text));
     }
 
+    if let Some(force_index) = &plan.force_index {
+        children.push(FormatTreeNode::new(format!("force index: {force_index}")));
+    }
+
+    if plan.speculative_prefetch {
+        children.push(FormatTreeNode::new(format!(
+            "prefetch depth: {}",
+            plan.prefetch_depth
+        )));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -947,6 +1212,27 @@ fn cache_scan_to_format_tree(
                 column_indexes
             )));
         }
+        CacheSource::Replicate((cache_key, column_indexes)) => {
+            children.push(FormatTreeNode::new(format!("cache key: {}", cache_key)));
+            children.push(FormatTreeNode::new(format!(
+                "column indexes: {:?}",
+                column_indexes
+            )));
+        }
+        CacheSource::Cte((cte_id, column_indexes)) => {
+            children.push(FormatTreeNode::new(format!("cte id: {}", cte_id)));
+            children.push(FormatTreeNode::new(format!(
+                "column indexes: {:?}",
+                column_indexes
+            )));
+        }
+        CacheSource::FlightSql((import_id, column_indexes)) => {
+            children.push(FormatTreeNode::new(format!("import id: {}", import_id)));
+            children.push(FormatTreeNode::new(format!(
+                "column indexes: {:?}",
+                column_indexes
+            )));
+        }
     }
 
     Ok(FormatTreeNode::with_children(
@@ -955,23 +1241,53 @@ fn cache_scan_to_format_tree(
     ))
 }
 
-fn filter_to_format_tree(
-    plan: &Filter,
+fn result_cache_scan_to_format_tree(
+    plan: &ResultCacheScan,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    let filter = plan
-        .predicates
-        .iter()
-        .map(|pred| pred.as_expr(&BUILTIN_FUNCTIONS).sql_display())
-        .join(", ");
+    let cache_expiry = match plan.cache_expiry {
+        Some(expiry) => DateTime::<Utc>::from(expiry).to_rfc3339(),
+        None => "NONE".to_string(),
+    };
     let mut children = vec![
         FormatTreeNode::new(format!(
             "output columns: [{}]",
             format_output_columns(plan.output_schema()?, metadata, true)
         )),
-        FormatTreeNode::new(format!("filters: [{filter}]")),
+        FormatTreeNode::new(format!("location: {}", plan.location)),
+        FormatTreeNode::new(format!("rows: {}", plan.num_rows)),
+        FormatTreeNode::new(format!("cache expiry: {}", cache_expiry)),
+    ];
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(FormatTreeNode::with_children("fallback".to_string(), vec![
+        to_format_tree(&plan.input, metadata, profs)?,
+    ]));
+
+    Ok(FormatTreeNode::with_children(
+        "ResultCacheScan".to_string(),
+        children,
+    ))
+}
+
+fn function_import_to_format_tree(
+    plan: &FunctionImport,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("endpoint: {}", plan.endpoint)),
+        FormatTreeNode::new(format!("query: {}", plan.query)),
     ];
+    if let Some(auth) = &plan.auth {
+        children.push(FormatTreeNode::new(format!("user: {}", auth.username)));
+    }
 
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
@@ -980,34 +1296,28 @@ fn filter_to_format_tree(
 
     append_profile_info(&mut children, profs, plan.plan_id);
 
-    children.push(to_format_tree(&plan.input, metadata, profs)?);
-
     Ok(FormatTreeNode::with_children(
-        "Filter".to_string(),
+        "FunctionImport".to_string(),
         children,
     ))
 }
 
-fn eval_scalar_to_format_tree(
-    plan: &EvalScalar,
+fn filter_to_format_tree(
+    plan: &Filter,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    if plan.exprs.is_empty() {
-        return to_format_tree(&plan.input, metadata, profs);
-    }
-    let scalars = plan
-        .exprs
+    let filter = plan
+        .predicates
         .iter()
-        .map(|(expr, _)| expr.as_expr(&BUILTIN_FUNCTIONS).sql_display())
-        .collect::<Vec<_>>()
+        .map(|pred| pred.as_expr(&BUILTIN_FUNCTIONS).sql_display())
         .join(", ");
     let mut children = vec![
         FormatTreeNode::new(format!(
             "output columns: [{}]",
             format_output_columns(plan.output_schema()?, metadata, true)
         )),
-        FormatTreeNode::new(format!("expressions: [{scalars}]")),
+        FormatTreeNode::new(format!("filters: [{filter}]")),
     ];
 
     if let Some(info) = &plan.stat_info {
@@ -1020,20 +1330,21 @@ fn eval_scalar_to_format_tree(
     children.push(to_format_tree(&plan.input, metadata, profs)?);
 
     Ok(FormatTreeNode::with_children(
-        "EvalScalar".to_string(),
+        "Filter".to_string(),
         children,
     ))
 }
 
-fn async_function_to_format_tree(
-    plan: &AsyncFunction,
+fn histogram_to_format_tree(
+    plan: &Histogram,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    let mut children = vec![FormatTreeNode::new(format!(
-        "output columns: [{}]",
-        format_output_columns(plan.output_schema()?, metadata, true)
-    ))];
+    let column = metadata.column(plan.column).name();
+    let mut children = vec![
+        FormatTreeNode::new(format!("column: {} (#{})", column, plan.column)),
+        FormatTreeNode::new(format!("num buckets: {}", plan.num_buckets)),
+    ];
 
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
@@ -1045,48 +1356,28 @@ fn async_function_to_format_tree(
     children.push(to_format_tree(&plan.input, metadata, profs)?);
 
     Ok(FormatTreeNode::with_children(
-        "AsyncFunction".to_string(),
+        "Histogram".to_string(),
         children,
     ))
 }
 
-pub fn pretty_display_agg_desc(desc: &AggregateFunctionDesc, metadata: &Metadata) -> String {
-    format!(
-        "{}({})",
-        desc.sig.name,
-        desc.arg_indices
-            .iter()
-            .map(|&index| { metadata.column(index).name() })
-            .collect::<Vec<_>>()
-            .join(", ")
-    )
-}
-
-fn aggregate_expand_to_format_tree(
-    plan: &AggregateExpand,
+fn correlation_to_format_tree(
+    plan: &Correlation,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    let sets = plan
-        .grouping_sets
-        .sets
-        .iter()
-        .map(|set| {
-            set.iter()
-                .map(|&index| metadata.column(index).name())
-                .collect::<Vec<_>>()
-                .join(", ")
-        })
-        .map(|s| format!("({})", s))
-        .collect::<Vec<_>>()
-        .join(", ");
-
+    let x = metadata.column(plan.x_col).name();
+    let y = metadata.column(plan.y_col).name();
     let mut children = vec![
         FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
+            "output columns: [{} (#{})]",
+            metadata.column(plan.output_col).name(),
+            plan.output_col
+        )),
+        FormatTreeNode::new(format!(
+            "arguments: [{} (#{}), {} (#{})]",
+            x, plan.x_col, y, plan.y_col
         )),
-        FormatTreeNode::new(format!("grouping sets: [{sets}]")),
     ];
 
     if let Some(info) = &plan.stat_info {
@@ -1099,31 +1390,29 @@ fn aggregate_expand_to_format_tree(
     children.push(to_format_tree(&plan.input, metadata, profs)?);
 
     Ok(FormatTreeNode::with_children(
-        "AggregateExpand".to_string(),
+        "Correlation".to_string(),
         children,
     ))
 }
 
-fn aggregate_partial_to_format_tree(
-    plan: &AggregatePartial,
+fn tdigest_agg_to_format_tree(
+    plan: &TDigestAgg,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    let group_by = plan
-        .group_by
-        .iter()
-        .map(|&index| metadata.column(index).name())
-        .join(", ");
-    let agg_funcs = plan
-        .agg_funcs
-        .iter()
-        .map(|agg| pretty_display_agg_desc(agg, metadata))
-        .collect::<Vec<_>>()
-        .join(", ");
-
     let mut children = vec![
-        FormatTreeNode::new(format!("group by: [{group_by}]")),
-        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+        FormatTreeNode::new(format!(
+            "output columns: [{} (#{})]",
+            metadata.column(plan.output_col).name(),
+            plan.output_col
+        )),
+        FormatTreeNode::new(format!(
+            "argument: {} (#{})",
+            metadata.column(plan.column).name(),
+            plan.column
+        )),
+        FormatTreeNode::new(format!("percentile: {}", plan.percentile)),
+        FormatTreeNode::new(format!("compression: {}", plan.compression)),
     ];
 
     if let Some(info) = &plan.stat_info {
@@ -1131,40 +1420,91 @@ fn aggregate_partial_to_format_tree(
         children.extend(items);
     }
 
-    if let Some((_, r)) = &plan.rank_limit {
-        children.push(FormatTreeNode::new(format!("rank limit: {r}")));
-    }
-
     append_profile_info(&mut children, profs, plan.plan_id);
 
     children.push(to_format_tree(&plan.input, metadata, profs)?);
 
     Ok(FormatTreeNode::with_children(
-        "AggregatePartial".to_string(),
+        "TDigestAgg".to_string(),
         children,
     ))
 }
 
-fn aggregate_final_to_format_tree(
-    plan: &AggregateFinal,
+fn emit_to_format_tree(
+    plan: &Emit,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    let group_by = plan
-        .group_by
+    let returning = plan
+        .returning_exprs
         .iter()
-        .map(|&index| {
-            let name = metadata.column(index).name();
-            Ok(name)
+        .map(|(expr, index)| {
+            format!(
+                "{} (#{})",
+                expr.as_expr(&BUILTIN_FUNCTIONS).sql_display(),
+                index
+            )
         })
-        .collect::<Result<Vec<_>>>()?
         .join(", ");
+    let mut children = vec![
+        FormatTreeNode::new(format!("table: {}", plan.table_info.name)),
+        FormatTreeNode::new(format!("returning: [{}]", returning)),
+    ];
 
-    let agg_funcs = plan
-        .agg_funcs
-        .iter()
-        .map(|agg| pretty_display_agg_desc(agg, metadata))
-        .collect::<Vec<_>>()
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children("Emit".to_string(), children))
+}
+
+fn qualify_to_format_tree(
+    plan: &Qualify,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let filter = plan
+        .predicates
+        .iter()
+        .map(|pred| pred.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .join(", ");
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("filters: [{filter}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "Qualify".to_string(),
+        children,
+    ))
+}
+
+fn json_extract_to_format_tree(
+    plan: &JsonExtract,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let paths = plan
+        .paths
+        .iter()
+        .map(|(index, path)| format!("{{{}}} (#{})", path.join(","), index))
         .join(", ");
 
     let mut children = vec![
@@ -1172,8 +1512,12 @@ fn aggregate_final_to_format_tree(
             "output columns: [{}]",
             format_output_columns(plan.output_schema()?, metadata, true)
         )),
-        FormatTreeNode::new(format!("group by: [{group_by}]")),
-        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+        FormatTreeNode::new(format!(
+            "source column: {} (#{})",
+            metadata.column(plan.source_col).name(),
+            plan.source_col
+        )),
+        FormatTreeNode::new(format!("key paths: [{paths}]")),
     ];
 
     if let Some(info) = &plan.stat_info {
@@ -1186,95 +1530,1066 @@ fn aggregate_final_to_format_tree(
     children.push(to_format_tree(&plan.input, metadata, profs)?);
 
     Ok(FormatTreeNode::with_children(
-        "AggregateFinal".to_string(),
+        "JsonExtract".to_string(),
         children,
     ))
 }
 
-fn window_to_format_tree(
-    plan: &Window,
+fn json_each_to_format_tree(
+    plan: &JsonEach,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    let partition_by = plan
-        .partition_by
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!(
+            "source column: {} (#{})",
+            metadata.column(plan.source_col).name(),
+            plan.source_col
+        )),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "JsonEach".to_string(),
+        children,
+    ))
+}
+
+fn json_table_to_format_tree(
+    plan: &JsonTable,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!(
+            "source column: {} (#{})",
+            metadata.column(plan.source_col).name(),
+            plan.source_col
+        )),
+        FormatTreeNode::new(format!("row path: {}", plan.row_path)),
+        FormatTreeNode::new(format!(
+            "paths: [{}]",
+            plan.paths
+                .iter()
+                .map(|(index, path, _)| format!(
+                    "{} (#{}): {}",
+                    metadata.column(*index).name(),
+                    index,
+                    path
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "JsonTable".to_string(),
+        children,
+    ))
+}
+
+fn classify_to_format_tree(
+    plan: &Classify,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("model: {}", plan.model_location)),
+        FormatTreeNode::new(format!(
+            "input columns: [{}]",
+            plan.input_cols
+                .iter()
+                .map(|index| format!("{} (#{})", metadata.column(*index).name(), index))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+        FormatTreeNode::new(format!("batch size: {}", plan.batch_size)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "Classify".to_string(),
+        children,
+    ))
+}
+
+fn eval_scalar_to_format_tree(
+    plan: &EvalScalar,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    if plan.exprs.is_empty() {
+        return to_format_tree(&plan.input, metadata, profs);
+    }
+    let scalars = plan
+        .exprs
         .iter()
-        .map(|&index| {
-            let name = metadata.column(index).name();
-            Ok(name)
-        })
-        .collect::<Result<Vec<_>>>()?
+        .map(|(expr, _)| expr.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
         .join(", ");
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("expressions: [{scalars}]")),
+    ];
 
-    let order_by = plan
-        .order_by
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "EvalScalar".to_string(),
+        children,
+    ))
+}
+
+fn async_function_to_format_tree(
+    plan: &AsyncFunction,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "output columns: [{}]",
+        format_output_columns(plan.output_schema()?, metadata, true)
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "AsyncFunction".to_string(),
+        children,
+    ))
+}
+
+fn sequence_next_to_format_tree(
+    plan: &SequenceNext,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("sequence: {}", plan.sequence_name)),
+        FormatTreeNode::new(format!("batch size: {}", plan.batch_size)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "SequenceNext".to_string(),
+        children,
+    ))
+}
+
+fn abort_if_empty_to_format_tree(
+    plan: &AbortIfEmpty,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("error message: {}", plan.error_message)),
+    ];
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "AbortIfEmpty".to_string(),
+        children,
+    ))
+}
+
+fn tumbling_window_to_format_tree(
+    plan: &TumblingWindow,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let agg_funcs = plan
+        .agg_funcs
         .iter()
-        .map(|v| v.display_name.clone())
+        .map(|agg| pretty_display_agg_desc(agg, metadata))
         .collect::<Vec<_>>()
         .join(", ");
 
-    let frame = plan.window_frame.to_string();
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!(
+            "time column: {}",
+            metadata.column(plan.time_col).name()
+        )),
+        FormatTreeNode::new(format!("window size: {} microseconds", plan.window_size)),
+        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "TumblingWindow".to_string(),
+        children,
+    ))
+}
+
+fn session_window_to_format_tree(
+    plan: &SessionWindow,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let agg_funcs = plan
+        .agg_funcs
+        .iter()
+        .map(|agg| pretty_display_agg_desc(agg, metadata))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!(
+            "time column: {}",
+            metadata.column(plan.time_col).name()
+        )),
+        FormatTreeNode::new(format!("gap: {} microseconds", plan.window_size)),
+        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "SessionWindow".to_string(),
+        children,
+    ))
+}
+
+fn watermark_to_format_tree(
+    plan: &Watermark,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "event time column: {}",
+            metadata.column(plan.event_time_col).name()
+        )),
+        FormatTreeNode::new(format!("delay: {} milliseconds", plan.watermark_delay_ms)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+    if let Some(late_output) = &plan.late_output {
+        let mut late_child = to_format_tree(late_output, metadata, profs)?;
+        late_child.payload = format!("{}(Late)", late_child.payload);
+        children.push(late_child);
+    }
+
+    Ok(FormatTreeNode::with_children(
+        "Watermark".to_string(),
+        children,
+    ))
+}
+
+pub fn pretty_display_agg_desc(desc: &AggregateFunctionDesc, metadata: &Metadata) -> String {
+    format!(
+        "{}({})",
+        desc.sig.name,
+        desc.arg_indices
+            .iter()
+            .map(|&index| { metadata.column(index).name() })
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn aggregate_expand_to_format_tree(
+    plan: &AggregateExpand,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let sets = plan
+        .grouping_sets
+        .sets
+        .iter()
+        .map(|set| {
+            set.iter()
+                .map(|&index| metadata.column(index).name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .map(|s| format!("({})", s))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("grouping sets: [{sets}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "AggregateExpand".to_string(),
+        children,
+    ))
+}
+
+fn aggregate_partial_to_format_tree(
+    plan: &AggregatePartial,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let group_by = plan
+        .group_by
+        .iter()
+        .map(|&index| metadata.column(index).name())
+        .join(", ");
+    let agg_funcs = plan
+        .agg_funcs
+        .iter()
+        .map(|agg| pretty_display_agg_desc(agg, metadata))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!("group by: [{group_by}]")),
+        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some((_, r)) = &plan.rank_limit {
+        children.push(FormatTreeNode::new(format!("rank limit: {r}")));
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "AggregatePartial".to_string(),
+        children,
+    ))
+}
+
+fn aggregate_final_to_format_tree(
+    plan: &AggregateFinal,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let group_by = plan
+        .group_by
+        .iter()
+        .map(|&index| {
+            let name = metadata.column(index).name();
+            Ok(name)
+        })
+        .collect::<Result<Vec<_>>>()?
+        .join(", ");
+
+    let agg_funcs = plan
+        .agg_funcs
+        .iter()
+        .map(|agg| pretty_display_agg_desc(agg, metadata))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("group by: [{group_by}]")),
+        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "AggregateFinal".to_string(),
+        children,
+    ))
+}
+
+fn sort_merge_aggregate_to_format_tree(
+    plan: &SortMergeAggregate,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let group_by = plan
+        .group_by
+        .iter()
+        .map(|&index| metadata.column(index).name())
+        .join(", ");
+
+    let agg_funcs = plan
+        .agg_funcs
+        .iter()
+        .map(|agg| pretty_display_agg_desc(agg, metadata))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("group by: [{group_by}]")),
+        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "SortMergeAggregate".to_string(),
+        children,
+    ))
+}
+
+fn materialize_agg_to_format_tree(
+    plan: &MaterializeAgg,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let group_by = plan
+        .group_by
+        .iter()
+        .map(|&index| metadata.column(index).name())
+        .join(", ");
+
+    let agg_funcs = plan
+        .original_agg_funcs
+        .iter()
+        .map(|agg| agg.display.as_str())
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("group by: [{group_by}]")),
+        FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
+    ];
+
+    if let Some(count_column) = plan.pre_agg_meta.count_column {
+        children.push(FormatTreeNode::new(format!(
+            "count column: {}",
+            metadata.column(count_column).name()
+        )));
+    }
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "MaterializeAgg".to_string(),
+        children,
+    ))
+}
+
+fn window_to_format_tree(
+    plan: &Window,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let partition_by = plan
+        .partition_by
+        .iter()
+        .map(|&index| {
+            let name = metadata.column(index).name();
+            Ok(name)
+        })
+        .collect::<Result<Vec<_>>>()?
+        .join(", ");
+
+    let order_by = plan
+        .order_by
+        .iter()
+        .map(|v| v.display_name.clone())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let frame = plan.window_frame.to_string();
+
+    let func = match &plan.func {
+        WindowFunction::Aggregate(agg) => pretty_display_agg_desc(agg, metadata),
+        func => format!("{}", func),
+    };
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("aggregate function: [{func}]")),
+        FormatTreeNode::new(format!("partition by: [{partition_by}]")),
+        FormatTreeNode::new(format!("order by: [{order_by}]")),
+        FormatTreeNode::new(format!("frame: [{frame}]")),
+    ];
+
+    if let Some(limit) = plan.limit {
+        children.push(FormatTreeNode::new(format!("limit: [{limit}]")))
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "Window".to_string(),
+        children,
+    ))
+}
+
+fn sort_to_format_tree(
+    plan: &Sort,
+    metadata: &Metadata,
+    prof_span_set: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let sort_keys = plan
+        .order_by
+        .iter()
+        .map(|sort_key| {
+            Ok(format!(
+                "{} {} {}",
+                sort_key.display_name,
+                if sort_key.asc { "ASC" } else { "DESC" },
+                if sort_key.nulls_first {
+                    "NULLS FIRST"
+                } else {
+                    "NULLS LAST"
+                }
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("sort keys: [{sort_keys}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children("Sort".to_string(), children))
+}
+
+fn sorted_merge_to_format_tree(
+    plan: &SortedMerge,
+    metadata: &Metadata,
+    prof_span_set: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let sort_keys = plan
+        .order_by
+        .iter()
+        .map(|sort_key| {
+            format!(
+                "{} {} {}",
+                sort_key.display_name,
+                if sort_key.asc { "ASC" } else { "DESC" },
+                if sort_key.nulls_first {
+                    "NULLS FIRST"
+                } else {
+                    "NULLS LAST"
+                }
+            )
+        })
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("sort keys: [{sort_keys}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    for input in plan.inputs.iter() {
+        children.push(to_format_tree(input, metadata, prof_span_set)?);
+    }
+
+    Ok(FormatTreeNode::with_children(
+        "SortedMerge".to_string(),
+        children,
+    ))
+}
+
+fn cluster_sort_to_format_tree(
+    plan: &ClusterSort,
+    metadata: &Metadata,
+    prof_span_set: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let sort_keys = plan
+        .order_by
+        .iter()
+        .map(|sort_key| {
+            format!(
+                "{} {} {}",
+                sort_key.display_name,
+                if sort_key.asc { "ASC" } else { "DESC" },
+                if sort_key.nulls_first {
+                    "NULLS FIRST"
+                } else {
+                    "NULLS LAST"
+                }
+            )
+        })
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("sort keys: [{sort_keys}]")),
+        FormatTreeNode::new(format!("already sorted: {}", plan.is_already_sorted)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "ClusterSort".to_string(),
+        children,
+    ))
+}
+
+fn spill_sort_to_format_tree(
+    plan: &SpillSort,
+    metadata: &Metadata,
+    prof_span_set: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let sort_keys = plan
+        .order_by
+        .iter()
+        .map(|sort_key| {
+            format!(
+                "{} {} {}",
+                sort_key.display_name,
+                if sort_key.asc { "ASC" } else { "DESC" },
+                if sort_key.nulls_first {
+                    "NULLS FIRST"
+                } else {
+                    "NULLS LAST"
+                }
+            )
+        })
+        .join(", ");
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("sort keys: [{sort_keys}]")),
+        FormatTreeNode::new(format!(
+            "spill threshold: {} bytes",
+            plan.spill_threshold_bytes
+        )),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "SpillSort".to_string(),
+        children,
+    ))
+}
+
+fn mv_refresh_partial_to_format_tree(
+    plan: &MvRefreshPartial,
+    metadata: &Metadata,
+    prof_span_set: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let table_name = metadata.table(plan.base_table_index).name().to_string();
+    let mut children = vec![
+        FormatTreeNode::new(format!("index id: {}", plan.index_id)),
+        FormatTreeNode::new(format!("base table: {}", table_name)),
+        FormatTreeNode::new(format!(
+            "changed partitions: {}",
+            plan.changed_partitions.len()
+        )),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.delta_plan, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "MvRefreshPartial".to_string(),
+        children,
+    ))
+}
+
+fn compact_to_format_tree(
+    plan: &Compact,
+    metadata: &Metadata,
+    prof_span_set: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "target segment size: {} MiB",
+            plan.target_segment_size_mb
+        )),
+        FormatTreeNode::new(format!(
+            "target block size: {} rows",
+            plan.target_block_size_rows
+        )),
+        FormatTreeNode::new(format!("max tasks: {}", plan.max_tasks)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "Compact".to_string(),
+        children,
+    ))
+}
+
+fn geo_scan_to_format_tree(
+    plan: &GeoScan,
+    metadata: &Metadata,
+    prof_span_set: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "column: {}",
+            metadata.column(plan.geo_column).name()
+        )),
+        FormatTreeNode::new(format!("spatial predicate: {}", plan.spatial_predicate)),
+    ];
+    if let Some(filter) = &plan.fallback_filter {
+        children.push(FormatTreeNode::new(format!(
+            "fallback filter: {}",
+            filter.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+        )));
+    }
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "GeoScan".to_string(),
+        children,
+    ))
+}
+
+fn bloom_lookup_to_format_tree(
+    plan: &BloomLookup,
+    metadata: &Metadata,
+    prof_span_set: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "column: {}",
+            metadata.column(plan.lookup_column).name()
+        )),
+        FormatTreeNode::new(format!("lookup value: {}", plan.lookup_value)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.inner_scan, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "BloomLookup".to_string(),
+        children,
+    ))
+}
+
+fn bitmap_index_scan_to_format_tree(
+    plan: &BitmapIndexScan,
+    metadata: &Metadata,
+    prof_span_set: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let values = plan
+        .predicate_values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>();
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "indexed column: {}",
+            metadata.column(plan.indexed_column).name()
+        )),
+        FormatTreeNode::new(format!("predicate values: [{}]", values.join(", "))),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "BitmapIndexScan".to_string(),
+        children,
+    ))
+}
+
+fn fuzzy_match_to_format_tree(
+    plan: &FuzzyMatch,
+    metadata: &Metadata,
+    prof_span_set: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let query_vector = plan
+        .query_vector
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>();
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "vector column: {}",
+            metadata.column(plan.vector_column).name()
+        )),
+        FormatTreeNode::new(format!("query vector: [{}]", query_vector.join(", "))),
+        FormatTreeNode::new(format!("metric: {}", plan.metric)),
+        FormatTreeNode::new(format!("k: {}", plan.k)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
-    let func = match &plan.func {
-        WindowFunction::Aggregate(agg) => pretty_display_agg_desc(agg, metadata),
-        func => format!("{}", func),
-    };
+    Ok(FormatTreeNode::with_children(
+        "FuzzyMatch".to_string(),
+        children,
+    ))
+}
 
+fn block_sample_to_format_tree(
+    plan: &BlockSample,
+    metadata: &Metadata,
+    prof_span_set: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
     let mut children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!("aggregate function: [{func}]")),
-        FormatTreeNode::new(format!("partition by: [{partition_by}]")),
-        FormatTreeNode::new(format!("order by: [{order_by}]")),
-        FormatTreeNode::new(format!("frame: [{frame}]")),
+        FormatTreeNode::new(format!("probability: {}", plan.probability)),
+        FormatTreeNode::new(format!("seed: {}", plan.seed)),
     ];
 
-    if let Some(limit) = plan.limit {
-        children.push(FormatTreeNode::new(format!("limit: [{limit}]")))
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
     }
 
-    append_profile_info(&mut children, profs, plan.plan_id);
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
 
-    children.push(to_format_tree(&plan.input, metadata, profs)?);
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
     Ok(FormatTreeNode::with_children(
-        "Window".to_string(),
+        "BlockSample".to_string(),
         children,
     ))
 }
 
-fn sort_to_format_tree(
-    plan: &Sort,
+fn schema_evolve_to_format_tree(
+    plan: &SchemaEvolve,
     metadata: &Metadata,
     prof_span_set: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
-    let sort_keys = plan
-        .order_by
+    let mut children = vec![FormatTreeNode::new(format!(
+        "output columns: [{}]",
+        format_output_columns(plan.output_schema()?, metadata, true)
+    ))];
+
+    let fill_defaults = plan
+        .fill_defaults
         .iter()
-        .map(|sort_key| {
+        .map(|(offset, default)| {
+            let index = plan.target_schema.field(*offset).name().parse::<usize>()?;
             Ok(format!(
-                "{} {} {}",
-                sort_key.display_name,
-                if sort_key.asc { "ASC" } else { "DESC" },
-                if sort_key.nulls_first {
-                    "NULLS FIRST"
-                } else {
-                    "NULLS LAST"
-                }
+                "{} = {}",
+                metadata.column(index).name(),
+                default.as_expr(&BUILTIN_FUNCTIONS).sql_display()
             ))
         })
-        .collect::<Result<Vec<_>>>()?
-        .join(", ");
-
-    let mut children = vec![
-        FormatTreeNode::new(format!(
-            "output columns: [{}]",
-            format_output_columns(plan.output_schema()?, metadata, true)
-        )),
-        FormatTreeNode::new(format!("sort keys: [{sort_keys}]")),
-    ];
+        .collect::<Result<Vec<_>>>()?;
+    children.push(FormatTreeNode::new(format!(
+        "fill defaults: [{}]",
+        fill_defaults.join(", ")
+    )));
 
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
@@ -1285,7 +2600,10 @@ fn sort_to_format_tree(
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
-    Ok(FormatTreeNode::with_children("Sort".to_string(), children))
+    Ok(FormatTreeNode::with_children(
+        "SchemaEvolve".to_string(),
+        children,
+    ))
 }
 
 fn window_partition_to_format_tree(
@@ -1360,6 +2678,38 @@ fn limit_to_format_tree(
     Ok(FormatTreeNode::with_children("Limit".to_string(), children))
 }
 
+fn conditional_limit_to_format_tree(
+    plan: &ConditionalLimit,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("limit: {}", plan.limit)),
+        FormatTreeNode::new(format!(
+            "condition: {}",
+            plan.condition.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+        )),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "ConditionalLimit".to_string(),
+        children,
+    ))
+}
+
 fn row_fetch_to_format_tree(
     plan: &RowFetch,
     metadata: &Metadata,
@@ -1453,11 +2803,62 @@ fn range_join_to_format_tree(
         match plan.range_join_type {
             RangeJoinType::IEJoin => "IEJoin".to_string(),
             RangeJoinType::Merge => "MergeJoin".to_string(),
+            RangeJoinType::Interval => "IntervalJoin".to_string(),
         },
         children,
     ))
 }
 
+fn sort_merge_join_to_format_tree(
+    plan: &SortMergeJoin,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let left_keys = plan
+        .left_keys
+        .iter()
+        .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let right_keys = plan
+        .right_keys
+        .iter()
+        .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut left_child = to_format_tree(&plan.left, metadata, profs)?;
+    let mut right_child = to_format_tree(&plan.right, metadata, profs)?;
+
+    left_child.payload = format!("{}(Left)", left_child.payload);
+    right_child.payload = format!("{}(Right)", right_child.payload);
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("join type: {}", plan.join_type)),
+        FormatTreeNode::new(format!("left keys: [{left_keys}]")),
+        FormatTreeNode::new(format!("right keys: [{right_keys}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(left_child);
+    children.push(right_child);
+
+    Ok(FormatTreeNode::with_children(
+        "SortMergeJoin".to_string(),
+        children,
+    ))
+}
+
 fn hash_join_to_format_tree(
     plan: &HashJoin,
     metadata: &Metadata,
@@ -1527,6 +2928,54 @@ fn hash_join_to_format_tree(
     ))
 }
 
+fn semi_hash_join_to_format_tree(
+    name: &str,
+    plan: &SemiHashJoin,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let build_keys = plan
+        .build_keys
+        .iter()
+        .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let probe_keys = plan
+        .probe_keys
+        .iter()
+        .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut build_child = to_format_tree(&plan.build, metadata, profs)?;
+    let mut probe_child = to_format_tree(&plan.probe, metadata, profs)?;
+
+    build_child.payload = format!("{}(Build)", build_child.payload);
+    probe_child.payload = format!("{}(Probe)", probe_child.payload);
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("join type: {}", plan.join_type)),
+        FormatTreeNode::new(format!("build keys: [{build_keys}]")),
+        FormatTreeNode::new(format!("probe keys: [{probe_keys}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(build_child);
+    children.push(probe_child);
+
+    Ok(FormatTreeNode::with_children(name.to_string(), children))
+}
+
 fn exchange_to_format_tree(
     plan: &Exchange,
     metadata: &Metadata,
@@ -1540,7 +2989,11 @@ fn exchange_to_format_tree(
         FormatTreeNode::new(format!("exchange type: {}", match plan.kind {
             FragmentKind::Init => "Init-Partition".to_string(),
             FragmentKind::Normal => format!(
-                "Hash({})",
+                "{}({})",
+                match plan.consistent_hash {
+                    true => "ConsistentHash",
+                    false => "Hash",
+                },
                 plan.keys
                     .iter()
                     .map(|key| { key.as_expr(&BUILTIN_FUNCTIONS).sql_display() })
@@ -1554,6 +3007,134 @@ fn exchange_to_format_tree(
     ]))
 }
 
+fn skew_detection_to_format_tree(
+    plan: &SkewDetection,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let partition_key = plan
+        .partition_key
+        .iter()
+        .map(|key| key.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut children = vec![
+        FormatTreeNode::new(format!("partition key: [{partition_key}]")),
+        FormatTreeNode::new(format!("skew threshold: {}", plan.skew_threshold)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "SkewDetection".to_string(),
+        children,
+    ))
+}
+
+fn convert_timezone_to_format_tree(
+    plan: &ConvertTimezone,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let columns = plan
+        .columns
+        .iter()
+        .map(|&index| format!("{} (#{index})", metadata.column(index).name()))
+        .join(", ");
+    let mut children = vec![
+        FormatTreeNode::new(format!("columns: [{columns}]")),
+        FormatTreeNode::new(format!("timezone: {}", plan.tz)),
+        FormatTreeNode::new(format!("direction: {}", plan.direction)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "ConvertTimezone".to_string(),
+        children,
+    ))
+}
+
+fn mask_apply_to_format_tree(
+    plan: &MaskApply,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let masking_rules = plan
+        .masking_rules
+        .iter()
+        .map(|(index, expr)| {
+            format!(
+                "{} (#{index}): {}",
+                metadata.column(*index).name(),
+                expr.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+            )
+        })
+        .join(", ");
+    let mut children = vec![FormatTreeNode::new(format!(
+        "masking rules: [{masking_rules}]"
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "MaskApply".to_string(),
+        children,
+    ))
+}
+
+fn row_access_policy_to_format_tree(
+    plan: &RowAccessPolicy,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!(
+            "policy filter: {}",
+            plan.policy_filter.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+        )),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "RowAccessPolicy".to_string(),
+        children,
+    ))
+}
+
 fn union_all_to_format_tree(
     plan: &UnionAll,
     metadata: &Metadata,
@@ -1723,6 +3304,91 @@ fn distributed_insert_to_format_tree(
     ))
 }
 
+fn bloom_build_to_format_tree(
+    plan: &BloomBuild,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let bloom_columns = plan
+        .bloom_fields
+        .iter()
+        .map(|field| field.name().as_str())
+        .join(", ");
+    let mut children = vec![
+        FormatTreeNode::new(format!("table: {}", plan.table_info.desc)),
+        FormatTreeNode::new(format!("bloom columns: [{bloom_columns}]")),
+        FormatTreeNode::new(format!("segment location: {}", plan.segment_location)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "BloomBuild".to_string(),
+        children,
+    ))
+}
+
+fn async_aggregate_to_format_tree(
+    plan: &AsyncAggregate,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "result cache key: {}",
+        plan.result_cache_key
+    ))];
+    if let Some(webhook) = &plan.notify_webhook {
+        children.push(FormatTreeNode::new(format!("notify webhook: {webhook}")));
+    }
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.inner, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "AsyncAggregate".to_string(),
+        children,
+    ))
+}
+
+fn write_ahead_log_to_format_tree(
+    plan: &WriteAheadLog,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!("table: {}", plan.table_info.desc)),
+        FormatTreeNode::new(format!("wal location: {}", plan.wal_location)),
+        FormatTreeNode::new(format!("flush interval: {}ms", plan.flush_interval_ms)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "WriteAheadLog".to_string(),
+        children,
+    ))
+}
+
 fn commit_sink_to_format_tree(
     plan: &CommitSink,
     metadata: &Metadata,
@@ -1769,6 +3435,232 @@ fn project_set_to_format_tree(
     ))
 }
 
+fn zip_to_format_tree(
+    plan: &Zip,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "output columns: [{}]",
+        format_output_columns(plan.output_schema()?, metadata, true)
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(FormatTreeNode::new(format!(
+        "arrays: {}",
+        plan.arrays
+            .iter()
+            .map(|(expr, _)| expr.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+            .collect::<Vec<_>>()
+            .join(", ")
+    )));
+    children.push(FormatTreeNode::new(format!(
+        "with ordinality: {}",
+        plan.with_ordinality.is_some()
+    )));
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children("Zip".to_string(), children))
+}
+
+fn flat_map_to_format_tree(
+    plan: &FlatMap,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "output columns: [{}]",
+        format_output_columns(plan.output_schema()?, metadata, true)
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(FormatTreeNode::new(format!(
+        "table function: {}({})",
+        plan.func_name,
+        plan.args
+            .iter()
+            .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+            .collect::<Vec<_>>()
+            .join(", ")
+    )));
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "FlatMap".to_string(),
+        children,
+    ))
+}
+
+fn transpose_to_format_tree(
+    plan: &Transpose,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!(
+            "columns to unpivot: [{}]",
+            plan.columns_to_unpivot
+                .iter()
+                .map(|index| format!("{} (#{})", metadata.column(*index).name(), index))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+        FormatTreeNode::new(format!("names: [{}]", plan.names.join(", "))),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "Transpose".to_string(),
+        children,
+    ))
+}
+
+fn grouping_id_to_format_tree(
+    plan: &GroupingId,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!(
+            "grouping columns: [{}]",
+            plan.grouping_columns.iter().join(", ")
+        )),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "GroupingId".to_string(),
+        children,
+    ))
+}
+
+fn replicate_to_format_tree(
+    plan: &Replicate,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("cache key: {}", plan.cache_key)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "Replicate".to_string(),
+        children,
+    ))
+}
+
+fn cte_materialization_to_format_tree(
+    plan: &CteMaterialization,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("cte id: {}", plan.cte_id)),
+        FormatTreeNode::new(format!("consumer count: {}", plan.consumer_count)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.body, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "CteMaterialization".to_string(),
+        children,
+    ))
+}
+
+fn stream_output_to_format_tree(
+    plan: &StreamOutput,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!("path: {}", plan.path)),
+        FormatTreeNode::new(format!("format: {:?}", plan.format.get_type())),
+        FormatTreeNode::new(format!("compression: {:?}", plan.compression)),
+    ];
+    if let Some(max_file_size) = plan.max_file_size {
+        children.push(FormatTreeNode::new(format!(
+            "max file size: {}",
+            max_file_size
+        )));
+    }
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(to_format_tree(&plan.input, metadata, profs)?);
+
+    Ok(FormatTreeNode::with_children(
+        "StreamOutput".to_string(),
+        children,
+    ))
+}
+
 fn udf_to_format_tree(
     plan: &Udf,
     metadata: &Metadata,
@@ -12,19 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod convert_timezone_injector;
 mod explain;
 mod format;
 mod physical_plan;
 mod physical_plan_builder;
+mod physical_plan_validator;
 mod physical_plan_visitor;
 pub mod physical_plans;
+mod skew_detection_injector;
 mod util;
+mod watermark_injector;
 
 pub mod table_read_plan;
 
+pub use convert_timezone_injector::ConvertTimezoneInjector;
 pub use format::format_partial_tree;
 pub use physical_plan::PhysicalPlan;
 pub use physical_plan_builder::MutationBuildInfo;
 pub use physical_plan_builder::PhysicalPlanBuilder;
+pub use physical_plan_validator::PhysicalPlanValidator;
 pub use physical_plan_visitor::PhysicalPlanReplacer;
+pub use skew_detection_injector::SkewDetectionInjector;
 pub use util::*;
+pub use watermark_injector::WatermarkInjector;
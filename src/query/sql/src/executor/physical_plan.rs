@@ -30,10 +30,16 @@ use super::physical_plans::MutationManipulate;
 use super::physical_plans::MutationOrganize;
 use super::physical_plans::MutationSource;
 use super::physical_plans::MutationSplit;
+use crate::executor::physical_plans::AbortIfEmpty;
 use crate::executor::physical_plans::AggregateExpand;
 use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregatePartial;
+use crate::executor::physical_plans::AsyncAggregate;
 use crate::executor::physical_plans::AsyncFunction;
+use crate::executor::physical_plans::BitmapIndexScan;
+use crate::executor::physical_plans::BlockSample;
+use crate::executor::physical_plans::BloomBuild;
+use crate::executor::physical_plans::BloomLookup;
 use crate::executor::physical_plans::CacheScan;
 use crate::executor::physical_plans::ChunkAppendData;
 use crate::executor::physical_plans::ChunkCastSchema;
@@ -42,39 +48,82 @@ use crate::executor::physical_plans::ChunkEvalScalar;
 use crate::executor::physical_plans::ChunkFillAndReorder;
 use crate::executor::physical_plans::ChunkFilter;
 use crate::executor::physical_plans::ChunkMerge;
+use crate::executor::physical_plans::Classify;
+use crate::executor::physical_plans::ClusterSort;
 use crate::executor::physical_plans::ColumnMutation;
 use crate::executor::physical_plans::CommitSink;
+use crate::executor::physical_plans::Compact;
 use crate::executor::physical_plans::CompactSource;
+use crate::executor::physical_plans::ConditionalLimit;
 use crate::executor::physical_plans::ConstantTableScan;
+use crate::executor::physical_plans::ConvertTimezone;
 use crate::executor::physical_plans::CopyIntoLocation;
 use crate::executor::physical_plans::CopyIntoTable;
 use crate::executor::physical_plans::CopyIntoTableSource;
+use crate::executor::physical_plans::Correlation;
+use crate::executor::physical_plans::CteMaterialization;
 use crate::executor::physical_plans::DistributedInsertSelect;
 use crate::executor::physical_plans::Duplicate;
+use crate::executor::physical_plans::Emit;
+use crate::executor::physical_plans::EnforceSchema;
 use crate::executor::physical_plans::EvalScalar;
 use crate::executor::physical_plans::Exchange;
 use crate::executor::physical_plans::ExchangeSink;
 use crate::executor::physical_plans::ExchangeSource;
 use crate::executor::physical_plans::ExpressionScan;
 use crate::executor::physical_plans::Filter;
+use crate::executor::physical_plans::FlatMap;
+use crate::executor::physical_plans::FunctionImport;
+use crate::executor::physical_plans::FuzzyMatch;
+use crate::executor::physical_plans::GeoScan;
+use crate::executor::physical_plans::GroupingId;
 use crate::executor::physical_plans::HashJoin;
+use crate::executor::physical_plans::Histogram;
+use crate::executor::physical_plans::JsonEach;
+use crate::executor::physical_plans::JsonExtract;
+use crate::executor::physical_plans::JsonTable;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::MaskApply;
+use crate::executor::physical_plans::MaterializeAgg;
 use crate::executor::physical_plans::Mutation;
+use crate::executor::physical_plans::MvRefreshPartial;
+use crate::executor::physical_plans::PrewarmCache;
 use crate::executor::physical_plans::ProjectSet;
+use crate::executor::physical_plans::Qualify;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::Recluster;
 use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::ReplaceAsyncSourcer;
 use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
+use crate::executor::physical_plans::Replicate;
+use crate::executor::physical_plans::ResultCacheScan;
+use crate::executor::physical_plans::RowAccessPolicy;
 use crate::executor::physical_plans::RowFetch;
+use crate::executor::physical_plans::Scatter;
+use crate::executor::physical_plans::SchemaEvolve;
+use crate::executor::physical_plans::SemiHashJoin;
+use crate::executor::physical_plans::SequenceNext;
+use crate::executor::physical_plans::SessionWindow;
 use crate::executor::physical_plans::Shuffle;
+use crate::executor::physical_plans::SkewDetection;
 use crate::executor::physical_plans::Sort;
+use crate::executor::physical_plans::SortMergeAggregate;
+use crate::executor::physical_plans::SortMergeJoin;
+use crate::executor::physical_plans::SortedMerge;
+use crate::executor::physical_plans::SpillSort;
+use crate::executor::physical_plans::StreamOutput;
+use crate::executor::physical_plans::TDigestAgg;
 use crate::executor::physical_plans::TableScan;
+use crate::executor::physical_plans::Transpose;
+use crate::executor::physical_plans::TumblingWindow;
 use crate::executor::physical_plans::Udf;
 use crate::executor::physical_plans::UnionAll;
+use crate::executor::physical_plans::Watermark;
 use crate::executor::physical_plans::Window;
 use crate::executor::physical_plans::WindowPartition;
+use crate::executor::physical_plans::WriteAheadLog;
+use crate::executor::physical_plans::Zip;
 
 #[derive(serde::Serialize, serde::Deserialize, Educe, EnumAsInner)]
 #[educe(
@@ -86,27 +135,70 @@ pub enum PhysicalPlan {
     TableScan(TableScan),
     Filter(Filter),
     EvalScalar(EvalScalar),
+    JsonExtract(Box<JsonExtract>),
+    SchemaEvolve(Box<SchemaEvolve>),
+    BlockSample(Box<BlockSample>),
+    FuzzyMatch(Box<FuzzyMatch>),
+    GeoScan(Box<GeoScan>),
+    BloomLookup(Box<BloomLookup>),
+    BitmapIndexScan(Box<BitmapIndexScan>),
+    MvRefreshPartial(Box<MvRefreshPartial>),
     ProjectSet(ProjectSet),
+    Zip(Zip),
+    FlatMap(FlatMap),
+    Transpose(Transpose),
+    Qualify(Qualify),
+    Histogram(Histogram),
+    Correlation(Correlation),
+    TDigestAgg(TDigestAgg),
+    Emit(Emit),
+    GroupingId(GroupingId),
+    Replicate(Replicate),
+    StreamOutput(Box<StreamOutput>),
+    JsonEach(Box<JsonEach>),
+    JsonTable(Box<JsonTable>),
+    Classify(Box<Classify>),
     AggregateExpand(AggregateExpand),
     AggregatePartial(AggregatePartial),
     AggregateFinal(AggregateFinal),
+    SortMergeAggregate(Box<SortMergeAggregate>),
+    MaterializeAgg(Box<MaterializeAgg>),
     Window(Window),
     Sort(Sort),
+    ClusterSort(ClusterSort),
+    SpillSort(Box<SpillSort>),
+    SortedMerge(Box<SortedMerge>),
     WindowPartition(WindowPartition),
+    TumblingWindow(TumblingWindow),
+    SessionWindow(SessionWindow),
+    Watermark(Box<Watermark>),
     Limit(Limit),
+    ConditionalLimit(Box<ConditionalLimit>),
     RowFetch(RowFetch),
     HashJoin(HashJoin),
     RangeJoin(RangeJoin),
+    SortMergeJoin(SortMergeJoin),
+    SemiHashJoin(SemiHashJoin),
+    AntiHashJoin(SemiHashJoin),
     Exchange(Exchange),
+    SkewDetection(SkewDetection),
+    ConvertTimezone(ConvertTimezone),
+    MaskApply(MaskApply),
+    RowAccessPolicy(RowAccessPolicy),
     UnionAll(UnionAll),
     ConstantTableScan(ConstantTableScan),
     ExpressionScan(ExpressionScan),
     CacheScan(CacheScan),
+    ResultCacheScan(Box<ResultCacheScan>),
+    FunctionImport(FunctionImport),
+    CteMaterialization(Box<CteMaterialization>),
     Udf(Udf),
     RecursiveCteScan(RecursiveCteScan),
 
     /// For insert into ... select ... in cluster
     DistributedInsertSelect(Box<DistributedInsertSelect>),
+    BloomBuild(Box<BloomBuild>),
+    WriteAheadLog(Box<WriteAheadLog>),
 
     /// Synthesized by fragmented
     ExchangeSource(ExchangeSource),
@@ -115,6 +207,7 @@ pub enum PhysicalPlan {
     /// Copy into table
     CopyIntoTable(Box<CopyIntoTable>),
     CopyIntoLocation(Box<CopyIntoLocation>),
+    EnforceSchema(Box<EnforceSchema>),
 
     /// Replace
     ReplaceAsyncSourcer(ReplaceAsyncSourcer),
@@ -132,6 +225,7 @@ pub enum PhysicalPlan {
 
     /// Compact
     CompactSource(Box<CompactSource>),
+    Compact(Box<Compact>),
 
     /// Commit
     CommitSink(Box<CommitSink>),
@@ -143,6 +237,7 @@ pub enum PhysicalPlan {
     /// Multi table insert
     Duplicate(Box<Duplicate>),
     Shuffle(Box<Shuffle>),
+    Scatter(Box<Scatter>),
     ChunkFilter(Box<ChunkFilter>),
     ChunkEvalScalar(Box<ChunkEvalScalar>),
     ChunkCastSchema(Box<ChunkCastSchema>),
@@ -151,8 +246,16 @@ pub enum PhysicalPlan {
     ChunkMerge(Box<ChunkMerge>),
     ChunkCommitInsert(Box<ChunkCommitInsert>),
 
+    /// Result cache
+    PrewarmCache(Box<PrewarmCache>),
+    AsyncAggregate(Box<AsyncAggregate>),
+
     // async function call
     AsyncFunction(AsyncFunction),
+    SequenceNext(Box<SequenceNext>),
+
+    /// Fail the query if no rows are returned
+    AbortIfEmpty(Box<AbortIfEmpty>),
 }
 
 impl PhysicalPlan {
@@ -166,6 +269,16 @@ impl PhysicalPlan {
                 plan.plan_id = *next_id;
                 *next_id += 1;
             }
+            PhysicalPlan::SequenceNext(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::AbortIfEmpty(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
             PhysicalPlan::TableScan(plan) => {
                 plan.plan_id = *next_id;
                 *next_id += 1;
@@ -200,11 +313,34 @@ impl PhysicalPlan {
                 *next_id += 1;
                 plan.input.adjust_plan_id(next_id);
             }
+            PhysicalPlan::SortMergeAggregate(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
             PhysicalPlan::Window(plan) => {
                 plan.plan_id = *next_id;
                 *next_id += 1;
                 plan.input.adjust_plan_id(next_id);
             }
+            PhysicalPlan::TumblingWindow(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::SessionWindow(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::Watermark(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+                if let Some(late_output) = &mut plan.late_output {
+                    late_output.adjust_plan_id(next_id);
+                }
+            }
             PhysicalPlan::WindowPartition(plan) => {
                 plan.plan_id = *next_id;
                 *next_id += 1;
@@ -237,6 +373,18 @@ impl PhysicalPlan {
                 plan.left.adjust_plan_id(next_id);
                 plan.right.adjust_plan_id(next_id);
             }
+            PhysicalPlan::SortMergeJoin(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.left.adjust_plan_id(next_id);
+                plan.right.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::SemiHashJoin(plan) | PhysicalPlan::AntiHashJoin(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.probe.adjust_plan_id(next_id);
+                plan.build.adjust_plan_id(next_id);
+            }
             PhysicalPlan::Exchange(plan) => {
                 plan.plan_id = *next_id;
                 *next_id += 1;
@@ -264,6 +412,11 @@ impl PhysicalPlan {
                 plan.plan_id = *next_id;
                 *next_id += 1;
             }
+            PhysicalPlan::ResultCacheScan(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
             PhysicalPlan::Udf(plan) => {
                 plan.plan_id = *next_id;
                 *next_id += 1;
@@ -405,6 +558,206 @@ impl PhysicalPlan {
                 *next_id += 1;
                 plan.input.adjust_plan_id(next_id);
             }
+            PhysicalPlan::MaskApply(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::RowAccessPolicy(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::ConvertTimezone(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::MaterializeAgg(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::BloomBuild(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::WriteAheadLog(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::ConditionalLimit(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::EnforceSchema(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::FunctionImport(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+            }
+            PhysicalPlan::SkewDetection(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::Correlation(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::TDigestAgg(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::CteMaterialization(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.body.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::FlatMap(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::FuzzyMatch(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::GeoScan(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::BloomLookup(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.inner_scan.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::BitmapIndexScan(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::Compact(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::BlockSample(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::MvRefreshPartial(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.delta_plan.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::SchemaEvolve(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::SortedMerge(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                for input in plan.inputs.iter_mut() {
+                    input.adjust_plan_id(next_id);
+                }
+            }
+            PhysicalPlan::Scatter(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::JsonEach(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::JsonTable(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::Classify(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::StreamOutput(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::Replicate(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::GroupingId(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::Emit(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::Histogram(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::Qualify(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::Transpose(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::JsonExtract(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::ClusterSort(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::SpillSort(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::Zip(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.input.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::PrewarmCache(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+            }
+            PhysicalPlan::AsyncAggregate(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.inner.adjust_plan_id(next_id);
+            }
         }
     }
 
@@ -412,6 +765,8 @@ impl PhysicalPlan {
     pub fn get_id(&self) -> u32 {
         match self {
             PhysicalPlan::AsyncFunction(v) => v.plan_id,
+            PhysicalPlan::SequenceNext(v) => v.plan_id,
+            PhysicalPlan::AbortIfEmpty(v) => v.plan_id,
             PhysicalPlan::TableScan(v) => v.plan_id,
             PhysicalPlan::Filter(v) => v.plan_id,
             PhysicalPlan::EvalScalar(v) => v.plan_id,
@@ -419,13 +774,20 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateExpand(v) => v.plan_id,
             PhysicalPlan::AggregatePartial(v) => v.plan_id,
             PhysicalPlan::AggregateFinal(v) => v.plan_id,
+            PhysicalPlan::SortMergeAggregate(v) => v.plan_id,
             PhysicalPlan::Window(v) => v.plan_id,
+            PhysicalPlan::TumblingWindow(v) => v.plan_id,
+            PhysicalPlan::SessionWindow(v) => v.plan_id,
+            PhysicalPlan::Watermark(v) => v.plan_id,
             PhysicalPlan::WindowPartition(v) => v.plan_id,
             PhysicalPlan::Sort(v) => v.plan_id,
             PhysicalPlan::Limit(v) => v.plan_id,
             PhysicalPlan::RowFetch(v) => v.plan_id,
             PhysicalPlan::HashJoin(v) => v.plan_id,
             PhysicalPlan::RangeJoin(v) => v.plan_id,
+            PhysicalPlan::SortMergeJoin(v) => v.plan_id,
+            PhysicalPlan::SemiHashJoin(v) => v.plan_id,
+            PhysicalPlan::AntiHashJoin(v) => v.plan_id,
             PhysicalPlan::Exchange(v) => v.plan_id,
             PhysicalPlan::UnionAll(v) => v.plan_id,
             PhysicalPlan::DistributedInsertSelect(v) => v.plan_id,
@@ -434,6 +796,7 @@ impl PhysicalPlan {
             PhysicalPlan::ConstantTableScan(v) => v.plan_id,
             PhysicalPlan::ExpressionScan(v) => v.plan_id,
             PhysicalPlan::CacheScan(v) => v.plan_id,
+            PhysicalPlan::ResultCacheScan(v) => v.plan_id,
             PhysicalPlan::Udf(v) => v.plan_id,
             PhysicalPlan::MutationSource(v) => v.plan_id,
             PhysicalPlan::ColumnMutation(v) => v.plan_id,
@@ -461,19 +824,65 @@ impl PhysicalPlan {
             PhysicalPlan::ChunkMerge(v) => v.plan_id,
             PhysicalPlan::ChunkCommitInsert(v) => v.plan_id,
             PhysicalPlan::RecursiveCteScan(v) => v.plan_id,
+            PhysicalPlan::MaskApply(v) => v.plan_id,
+            PhysicalPlan::RowAccessPolicy(v) => v.plan_id,
+            PhysicalPlan::ConvertTimezone(v) => v.plan_id,
+            PhysicalPlan::MaterializeAgg(v) => v.plan_id,
+            PhysicalPlan::BloomBuild(v) => v.plan_id,
+            PhysicalPlan::WriteAheadLog(v) => v.plan_id,
+            PhysicalPlan::ConditionalLimit(v) => v.plan_id,
+            PhysicalPlan::EnforceSchema(v) => v.plan_id,
+            PhysicalPlan::FunctionImport(v) => v.plan_id,
+            PhysicalPlan::SkewDetection(v) => v.plan_id,
+            PhysicalPlan::Correlation(v) => v.plan_id,
+            PhysicalPlan::TDigestAgg(v) => v.plan_id,
+            PhysicalPlan::CteMaterialization(v) => v.plan_id,
+            PhysicalPlan::FlatMap(v) => v.plan_id,
+            PhysicalPlan::FuzzyMatch(v) => v.plan_id,
+            PhysicalPlan::GeoScan(v) => v.plan_id,
+            PhysicalPlan::BloomLookup(v) => v.plan_id,
+            PhysicalPlan::BitmapIndexScan(v) => v.plan_id,
+            PhysicalPlan::Compact(v) => v.plan_id,
+            PhysicalPlan::BlockSample(v) => v.plan_id,
+            PhysicalPlan::MvRefreshPartial(v) => v.plan_id,
+            PhysicalPlan::SchemaEvolve(v) => v.plan_id,
+            PhysicalPlan::SortedMerge(v) => v.plan_id,
+            PhysicalPlan::Scatter(v) => v.plan_id,
+            PhysicalPlan::JsonEach(v) => v.plan_id,
+            PhysicalPlan::JsonTable(v) => v.plan_id,
+            PhysicalPlan::Classify(v) => v.plan_id,
+            PhysicalPlan::StreamOutput(v) => v.plan_id,
+            PhysicalPlan::Replicate(v) => v.plan_id,
+            PhysicalPlan::GroupingId(v) => v.plan_id,
+            PhysicalPlan::Emit(v) => v.plan_id,
+            PhysicalPlan::Histogram(v) => v.plan_id,
+            PhysicalPlan::Qualify(v) => v.plan_id,
+            PhysicalPlan::Transpose(v) => v.plan_id,
+            PhysicalPlan::JsonExtract(v) => v.plan_id,
+            PhysicalPlan::ClusterSort(v) => v.plan_id,
+            PhysicalPlan::SpillSort(v) => v.plan_id,
+            PhysicalPlan::Zip(v) => v.plan_id,
+            PhysicalPlan::PrewarmCache(v) => v.plan_id,
+            PhysicalPlan::AsyncAggregate(v) => v.plan_id,
         }
     }
 
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         match self {
             PhysicalPlan::AsyncFunction(plan) => plan.output_schema(),
+            PhysicalPlan::SequenceNext(plan) => plan.output_schema(),
+            PhysicalPlan::AbortIfEmpty(plan) => plan.output_schema(),
             PhysicalPlan::TableScan(plan) => plan.output_schema(),
             PhysicalPlan::Filter(plan) => plan.output_schema(),
             PhysicalPlan::EvalScalar(plan) => plan.output_schema(),
             PhysicalPlan::AggregateExpand(plan) => plan.output_schema(),
             PhysicalPlan::AggregatePartial(plan) => plan.output_schema(),
             PhysicalPlan::AggregateFinal(plan) => plan.output_schema(),
+            PhysicalPlan::SortMergeAggregate(plan) => plan.output_schema(),
             PhysicalPlan::Window(plan) => plan.output_schema(),
+            PhysicalPlan::TumblingWindow(plan) => plan.output_schema(),
+            PhysicalPlan::SessionWindow(plan) => plan.output_schema(),
+            PhysicalPlan::Watermark(plan) => plan.output_schema(),
             PhysicalPlan::WindowPartition(plan) => plan.output_schema(),
             PhysicalPlan::Sort(plan) => plan.output_schema(),
             PhysicalPlan::Limit(plan) => plan.output_schema(),
@@ -485,11 +894,15 @@ impl PhysicalPlan {
             PhysicalPlan::UnionAll(plan) => plan.output_schema(),
             PhysicalPlan::ProjectSet(plan) => plan.output_schema(),
             PhysicalPlan::RangeJoin(plan) => plan.output_schema(),
+            PhysicalPlan::SortMergeJoin(plan) => plan.output_schema(),
+            PhysicalPlan::SemiHashJoin(plan) => plan.output_schema(),
+            PhysicalPlan::AntiHashJoin(plan) => plan.output_schema(),
             PhysicalPlan::CopyIntoTable(plan) => plan.output_schema(),
             PhysicalPlan::CopyIntoLocation(plan) => plan.output_schema(),
             PhysicalPlan::ConstantTableScan(plan) => plan.output_schema(),
             PhysicalPlan::ExpressionScan(plan) => plan.output_schema(),
             PhysicalPlan::CacheScan(plan) => plan.output_schema(),
+            PhysicalPlan::ResultCacheScan(plan) => plan.output_schema(),
             PhysicalPlan::RecursiveCteScan(plan) => plan.output_schema(),
             PhysicalPlan::Udf(plan) => plan.output_schema(),
             PhysicalPlan::MutationSource(plan) => plan.output_schema(),
@@ -516,6 +929,46 @@ impl PhysicalPlan {
             PhysicalPlan::ChunkAppendData(_) => todo!(),
             PhysicalPlan::ChunkMerge(_) => todo!(),
             PhysicalPlan::ChunkCommitInsert(_) => todo!(),
+            PhysicalPlan::MaskApply(plan) => plan.output_schema(),
+            PhysicalPlan::RowAccessPolicy(plan) => plan.output_schema(),
+            PhysicalPlan::ConvertTimezone(plan) => plan.output_schema(),
+            PhysicalPlan::MaterializeAgg(plan) => plan.output_schema(),
+            PhysicalPlan::BloomBuild(plan) => plan.output_schema(),
+            PhysicalPlan::WriteAheadLog(plan) => plan.output_schema(),
+            PhysicalPlan::ConditionalLimit(plan) => plan.output_schema(),
+            PhysicalPlan::EnforceSchema(plan) => plan.output_schema(),
+            PhysicalPlan::FunctionImport(plan) => plan.output_schema(),
+            PhysicalPlan::SkewDetection(plan) => plan.output_schema(),
+            PhysicalPlan::Correlation(plan) => plan.output_schema(),
+            PhysicalPlan::TDigestAgg(plan) => plan.output_schema(),
+            PhysicalPlan::CteMaterialization(plan) => plan.output_schema(),
+            PhysicalPlan::FlatMap(plan) => plan.output_schema(),
+            PhysicalPlan::FuzzyMatch(plan) => plan.output_schema(),
+            PhysicalPlan::GeoScan(plan) => plan.output_schema(),
+            PhysicalPlan::BloomLookup(plan) => plan.output_schema(),
+            PhysicalPlan::BitmapIndexScan(plan) => plan.output_schema(),
+            PhysicalPlan::Compact(plan) => plan.output_schema(),
+            PhysicalPlan::BlockSample(plan) => plan.output_schema(),
+            PhysicalPlan::MvRefreshPartial(plan) => plan.output_schema(),
+            PhysicalPlan::SchemaEvolve(plan) => plan.output_schema(),
+            PhysicalPlan::SortedMerge(plan) => plan.output_schema(),
+            PhysicalPlan::Scatter(plan) => plan.output_schema(),
+            PhysicalPlan::JsonEach(plan) => plan.output_schema(),
+            PhysicalPlan::JsonTable(plan) => plan.output_schema(),
+            PhysicalPlan::Classify(plan) => plan.output_schema(),
+            PhysicalPlan::StreamOutput(plan) => plan.output_schema(),
+            PhysicalPlan::Replicate(plan) => plan.output_schema(),
+            PhysicalPlan::GroupingId(plan) => plan.output_schema(),
+            PhysicalPlan::Emit(plan) => plan.output_schema(),
+            PhysicalPlan::Histogram(plan) => plan.output_schema(),
+            PhysicalPlan::Qualify(plan) => plan.output_schema(),
+            PhysicalPlan::Transpose(plan) => plan.output_schema(),
+            PhysicalPlan::JsonExtract(plan) => plan.output_schema(),
+            PhysicalPlan::ClusterSort(plan) => plan.output_schema(),
+            PhysicalPlan::SpillSort(plan) => plan.output_schema(),
+            PhysicalPlan::Zip(plan) => plan.output_schema(),
+            PhysicalPlan::PrewarmCache(plan) => plan.output_schema(),
+            PhysicalPlan::AsyncAggregate(plan) => plan.output_schema(),
         }
     }
 
@@ -529,12 +982,18 @@ impl PhysicalPlan {
                 DataSourceInfo::ORCSource(_) => "OrcScan".to_string(),
             },
             PhysicalPlan::AsyncFunction(_) => "AsyncFunction".to_string(),
+            PhysicalPlan::SequenceNext(_) => "SequenceNext".to_string(),
+            PhysicalPlan::AbortIfEmpty(_) => "AbortIfEmpty".to_string(),
             PhysicalPlan::Filter(_) => "Filter".to_string(),
             PhysicalPlan::EvalScalar(_) => "EvalScalar".to_string(),
             PhysicalPlan::AggregateExpand(_) => "AggregateExpand".to_string(),
             PhysicalPlan::AggregatePartial(_) => "AggregatePartial".to_string(),
             PhysicalPlan::AggregateFinal(_) => "AggregateFinal".to_string(),
+            PhysicalPlan::SortMergeAggregate(_) => "SortMergeAggregate".to_string(),
             PhysicalPlan::Window(_) => "Window".to_string(),
+            PhysicalPlan::TumblingWindow(_) => "TumblingWindow".to_string(),
+            PhysicalPlan::SessionWindow(_) => "SessionWindow".to_string(),
+            PhysicalPlan::Watermark(_) => "Watermark".to_string(),
             PhysicalPlan::WindowPartition(_) => "WindowPartition".to_string(),
             PhysicalPlan::Sort(_) => "Sort".to_string(),
             PhysicalPlan::Limit(_) => "Limit".to_string(),
@@ -549,6 +1008,9 @@ impl PhysicalPlan {
             PhysicalPlan::CompactSource(_) => "CompactBlock".to_string(),
             PhysicalPlan::CommitSink(_) => "CommitSink".to_string(),
             PhysicalPlan::RangeJoin(_) => "RangeJoin".to_string(),
+            PhysicalPlan::SortMergeJoin(_) => "SortMergeJoin".to_string(),
+            PhysicalPlan::SemiHashJoin(_) => "SemiHashJoin".to_string(),
+            PhysicalPlan::AntiHashJoin(_) => "AntiHashJoin".to_string(),
             PhysicalPlan::CopyIntoTable(_) => "CopyIntoTable".to_string(),
             PhysicalPlan::CopyIntoLocation(_) => "CopyIntoLocation".to_string(),
             PhysicalPlan::ReplaceAsyncSourcer(_) => "ReplaceAsyncSourcer".to_string(),
@@ -565,6 +1027,7 @@ impl PhysicalPlan {
             PhysicalPlan::ConstantTableScan(_) => "PhysicalConstantTableScan".to_string(),
             PhysicalPlan::ExpressionScan(_) => "ExpressionScan".to_string(),
             PhysicalPlan::CacheScan(_) => "CacheScan".to_string(),
+            PhysicalPlan::ResultCacheScan(_) => "ResultCacheScan".to_string(),
             PhysicalPlan::Recluster(_) => "Recluster".to_string(),
             PhysicalPlan::HilbertPartition(_) => "HilbertPartition".to_string(),
             PhysicalPlan::Udf(_) => "Udf".to_string(),
@@ -577,6 +1040,46 @@ impl PhysicalPlan {
             PhysicalPlan::ChunkAppendData(_) => "WriteData".to_string(),
             PhysicalPlan::ChunkMerge(_) => "ChunkMerge".to_string(),
             PhysicalPlan::ChunkCommitInsert(_) => "Commit".to_string(),
+            PhysicalPlan::MaskApply(_) => "MaskApply".to_string(),
+            PhysicalPlan::RowAccessPolicy(_) => "RowAccessPolicy".to_string(),
+            PhysicalPlan::ConvertTimezone(_) => "ConvertTimezone".to_string(),
+            PhysicalPlan::MaterializeAgg(_) => "MaterializeAgg".to_string(),
+            PhysicalPlan::BloomBuild(_) => "BloomBuild".to_string(),
+            PhysicalPlan::WriteAheadLog(_) => "WriteAheadLog".to_string(),
+            PhysicalPlan::ConditionalLimit(_) => "ConditionalLimit".to_string(),
+            PhysicalPlan::EnforceSchema(_) => "EnforceSchema".to_string(),
+            PhysicalPlan::FunctionImport(_) => "FunctionImport".to_string(),
+            PhysicalPlan::SkewDetection(_) => "SkewDetection".to_string(),
+            PhysicalPlan::Correlation(_) => "Correlation".to_string(),
+            PhysicalPlan::TDigestAgg(_) => "TDigestAgg".to_string(),
+            PhysicalPlan::CteMaterialization(_) => "CteMaterialization".to_string(),
+            PhysicalPlan::FlatMap(_) => "FlatMap".to_string(),
+            PhysicalPlan::FuzzyMatch(_) => "FuzzyMatch".to_string(),
+            PhysicalPlan::GeoScan(_) => "GeoScan".to_string(),
+            PhysicalPlan::BloomLookup(_) => "BloomLookup".to_string(),
+            PhysicalPlan::BitmapIndexScan(_) => "BitmapIndexScan".to_string(),
+            PhysicalPlan::Compact(_) => "Compact".to_string(),
+            PhysicalPlan::BlockSample(_) => "BlockSample".to_string(),
+            PhysicalPlan::MvRefreshPartial(_) => "MvRefreshPartial".to_string(),
+            PhysicalPlan::SchemaEvolve(_) => "SchemaEvolve".to_string(),
+            PhysicalPlan::SortedMerge(_) => "SortedMerge".to_string(),
+            PhysicalPlan::Scatter(_) => "Scatter".to_string(),
+            PhysicalPlan::JsonEach(_) => "JsonEach".to_string(),
+            PhysicalPlan::JsonTable(_) => "JsonTable".to_string(),
+            PhysicalPlan::Classify(_) => "Classify".to_string(),
+            PhysicalPlan::StreamOutput(_) => "StreamOutput".to_string(),
+            PhysicalPlan::Replicate(_) => "Replicate".to_string(),
+            PhysicalPlan::GroupingId(_) => "GroupingId".to_string(),
+            PhysicalPlan::Emit(_) => "Emit".to_string(),
+            PhysicalPlan::Histogram(_) => "Histogram".to_string(),
+            PhysicalPlan::Qualify(_) => "Qualify".to_string(),
+            PhysicalPlan::Transpose(_) => "Transpose".to_string(),
+            PhysicalPlan::JsonExtract(_) => "JsonExtract".to_string(),
+            PhysicalPlan::ClusterSort(_) => "ClusterSort".to_string(),
+            PhysicalPlan::SpillSort(_) => "SpillSort".to_string(),
+            PhysicalPlan::Zip(_) => "Zip".to_string(),
+            PhysicalPlan::PrewarmCache(_) => "PrewarmCache".to_string(),
+            PhysicalPlan::AsyncAggregate(_) => "AsyncAggregate".to_string(),
         }
     }
 
@@ -585,6 +1088,7 @@ impl PhysicalPlan {
             PhysicalPlan::TableScan(_)
             | PhysicalPlan::ConstantTableScan(_)
             | PhysicalPlan::CacheScan(_)
+            | PhysicalPlan::ResultCacheScan(_)
             | PhysicalPlan::ExchangeSource(_)
             | PhysicalPlan::CompactSource(_)
             | PhysicalPlan::ReplaceAsyncSourcer(_)
@@ -596,7 +1100,15 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateExpand(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregatePartial(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregateFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::SortMergeAggregate(plan) => {
+                Box::new(std::iter::once(plan.input.as_ref()))
+            }
             PhysicalPlan::Window(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::TumblingWindow(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::SessionWindow(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Watermark(plan) => {
+                Box::new(std::iter::once(plan.input.as_ref()).chain(plan.late_output.as_deref()))
+            }
             PhysicalPlan::WindowPartition(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Sort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Limit(plan) => Box::new(std::iter::once(plan.input.as_ref())),
@@ -618,6 +1130,12 @@ impl PhysicalPlan {
             PhysicalPlan::RangeJoin(plan) => Box::new(
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
             ),
+            PhysicalPlan::SortMergeJoin(plan) => Box::new(
+                std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
+            ),
+            PhysicalPlan::SemiHashJoin(plan) | PhysicalPlan::AntiHashJoin(plan) => Box::new(
+                std::iter::once(plan.probe.as_ref()).chain(std::iter::once(plan.build.as_ref())),
+            ),
             PhysicalPlan::ReplaceDeduplicate(plan) => {
                 Box::new(std::iter::once(plan.input.as_ref()))
             }
@@ -633,6 +1151,8 @@ impl PhysicalPlan {
             PhysicalPlan::AddStreamColumn(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Udf(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AsyncFunction(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::SequenceNext(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::AbortIfEmpty(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::CopyIntoLocation(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Duplicate(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Shuffle(plan) => Box::new(std::iter::once(plan.input.as_ref())),
@@ -645,6 +1165,49 @@ impl PhysicalPlan {
             PhysicalPlan::ChunkAppendData(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::ChunkMerge(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::ChunkCommitInsert(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::PrewarmCache(_) => Box::new(std::iter::empty()),
+            // The inner plan is run by its own pipeline.
+            PhysicalPlan::AsyncAggregate(_) => Box::new(std::iter::empty()),
+            PhysicalPlan::Zip(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::ClusterSort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::SpillSort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::JsonExtract(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Transpose(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Qualify(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Histogram(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Emit(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::GroupingId(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Replicate(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::StreamOutput(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::JsonEach(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::JsonTable(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Classify(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Scatter(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::SortedMerge(plan) => Box::new(plan.inputs.iter()),
+            PhysicalPlan::SchemaEvolve(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::MvRefreshPartial(plan) => {
+                Box::new(std::iter::once(plan.delta_plan.as_ref()))
+            }
+            PhysicalPlan::BlockSample(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Compact(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::GeoScan(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::BloomLookup(plan) => Box::new(std::iter::once(plan.inner_scan.as_ref())),
+            PhysicalPlan::BitmapIndexScan(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::FuzzyMatch(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::FlatMap(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::CteMaterialization(plan) => Box::new(std::iter::once(plan.body.as_ref())),
+            PhysicalPlan::Correlation(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::TDigestAgg(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::SkewDetection(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::FunctionImport(_) => Box::new(std::iter::empty()),
+            PhysicalPlan::EnforceSchema(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::ConditionalLimit(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::BloomBuild(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::WriteAheadLog(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::MaterializeAgg(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::ConvertTimezone(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::MaskApply(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::RowAccessPolicy(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::CopyIntoTable(v) => match &v.source {
                 CopyIntoTableSource::Query(v) => Box::new(std::iter::once(v.as_ref())),
                 CopyIntoTableSource::Stage(v) => Box::new(std::iter::once(v.as_ref())),
@@ -669,14 +1232,56 @@ impl PhysicalPlan {
             PhysicalPlan::RowFetch(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::Udf(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::AsyncFunction(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::SequenceNext(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::AbortIfEmpty(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::CopyIntoLocation(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::MaskApply(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::RowAccessPolicy(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::ConvertTimezone(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::MaterializeAgg(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::BloomBuild(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::WriteAheadLog(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::ConditionalLimit(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::EnforceSchema(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::SkewDetection(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::Correlation(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::TDigestAgg(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::FlatMap(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::FuzzyMatch(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::GeoScan(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::BloomLookup(plan) => plan.inner_scan.try_find_single_data_source(),
+            PhysicalPlan::BitmapIndexScan(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::BlockSample(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::MvRefreshPartial(plan) => plan.delta_plan.try_find_single_data_source(),
+            PhysicalPlan::SchemaEvolve(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::JsonEach(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::JsonTable(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::Classify(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::StreamOutput(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::Replicate(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::GroupingId(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::Emit(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::Histogram(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::Qualify(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::Transpose(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::JsonExtract(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::ClusterSort(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::SpillSort(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::Zip(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::UnionAll(_)
             | PhysicalPlan::ExchangeSource(_)
             | PhysicalPlan::HashJoin(_)
             | PhysicalPlan::RangeJoin(_)
+            | PhysicalPlan::SortMergeJoin(_)
+            | PhysicalPlan::SemiHashJoin(_)
+            | PhysicalPlan::AntiHashJoin(_)
             | PhysicalPlan::AggregateExpand(_)
             | PhysicalPlan::AggregateFinal(_)
+            | PhysicalPlan::SortMergeAggregate(_)
             | PhysicalPlan::AggregatePartial(_)
+            | PhysicalPlan::TumblingWindow(_)
+            | PhysicalPlan::SessionWindow(_)
+            | PhysicalPlan::Watermark(_)
             | PhysicalPlan::CompactSource(_)
             | PhysicalPlan::CommitSink(_)
             | PhysicalPlan::CopyIntoTable(_)
@@ -693,6 +1298,7 @@ impl PhysicalPlan {
             | PhysicalPlan::ConstantTableScan(_)
             | PhysicalPlan::ExpressionScan(_)
             | PhysicalPlan::CacheScan(_)
+            | PhysicalPlan::ResultCacheScan(_)
             | PhysicalPlan::RecursiveCteScan(_)
             | PhysicalPlan::Recluster(_)
             | PhysicalPlan::HilbertPartition(_)
@@ -704,7 +1310,14 @@ impl PhysicalPlan {
             | PhysicalPlan::ChunkFillAndReorder(_)
             | PhysicalPlan::ChunkAppendData(_)
             | PhysicalPlan::ChunkMerge(_)
-            | PhysicalPlan::ChunkCommitInsert(_) => None,
+            | PhysicalPlan::ChunkCommitInsert(_)
+            | PhysicalPlan::PrewarmCache(_)
+            | PhysicalPlan::AsyncAggregate(_)
+            | PhysicalPlan::Scatter(_)
+            | PhysicalPlan::SortedMerge(_)
+            | PhysicalPlan::Compact(_)
+            | PhysicalPlan::CteMaterialization(_)
+            | PhysicalPlan::FunctionImport(_) => None,
         }
     }
 
@@ -731,6 +1344,7 @@ impl PhysicalPlan {
                 v.source.source_info.catalog_name(),
                 v.source.source_info.desc()
             ),
+            PhysicalPlan::FunctionImport(v) => v.endpoint.clone(),
             PhysicalPlan::Filter(v) => match v.predicates.is_empty() {
                 true => String::new(),
                 false => v.predicates[0].as_expr(&BUILTIN_FUNCTIONS).sql_display(),
@@ -741,6 +1355,21 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateFinal(v) => {
                 v.agg_funcs.iter().map(|x| x.display.clone()).join(", ")
             }
+            PhysicalPlan::SortMergeAggregate(v) => {
+                v.agg_funcs.iter().map(|x| x.display.clone()).join(", ")
+            }
+            PhysicalPlan::TumblingWindow(v) => {
+                v.agg_funcs.iter().map(|x| x.display.clone()).join(", ")
+            }
+            PhysicalPlan::SessionWindow(v) => {
+                v.agg_funcs.iter().map(|x| x.display.clone()).join(", ")
+            }
+            PhysicalPlan::Watermark(v) => format!("delay: {}ms", v.watermark_delay_ms),
+            PhysicalPlan::MaterializeAgg(v) => v
+                .original_agg_funcs
+                .iter()
+                .map(|x| x.display.clone())
+                .join(", "),
             PhysicalPlan::Sort(v) => v
                 .order_by
                 .iter()
@@ -753,10 +1382,64 @@ impl PhysicalPlan {
                     )
                 })
                 .join(", "),
+            PhysicalPlan::ClusterSort(v) => v
+                .order_by
+                .iter()
+                .map(|x| {
+                    format!(
+                        "{}{}{}",
+                        x.display_name,
+                        if x.asc { "" } else { " DESC" },
+                        if x.nulls_first { " NULLS FIRST" } else { "" },
+                    )
+                })
+                .join(", "),
+            PhysicalPlan::SpillSort(v) => v
+                .order_by
+                .iter()
+                .map(|x| {
+                    format!(
+                        "{}{}{}",
+                        x.display_name,
+                        if x.asc { "" } else { " DESC" },
+                        if x.nulls_first { " NULLS FIRST" } else { "" },
+                    )
+                })
+                .join(", "),
+            PhysicalPlan::SortedMerge(v) => v
+                .order_by
+                .iter()
+                .map(|x| {
+                    format!(
+                        "{}{}{}",
+                        x.display_name,
+                        if x.asc { "" } else { " DESC" },
+                        if x.nulls_first { " NULLS FIRST" } else { "" },
+                    )
+                })
+                .join(", "),
+            PhysicalPlan::JsonExtract(v) => v
+                .paths
+                .iter()
+                .map(|(_, path)| format!("{{{}}}", path.join(",")))
+                .join(", "),
+            PhysicalPlan::JsonEach(v) => format!("#{}", v.source_col),
+            PhysicalPlan::JsonTable(v) => format!("#{}, {}", v.source_col, v.row_path),
+            PhysicalPlan::Classify(v) => format!("predict({})", v.model_location),
+            PhysicalPlan::SchemaEvolve(v) => v
+                .fill_defaults
+                .iter()
+                .map(|(offset, _)| v.target_schema.field(*offset).name().clone())
+                .join(", "),
             PhysicalPlan::Limit(v) => match v.limit {
                 Some(limit) => format!("LIMIT {} OFFSET {}", limit, v.offset),
                 None => format!("OFFSET {}", v.offset),
             },
+            PhysicalPlan::ConditionalLimit(v) => format!(
+                "LIMIT {} WHERE {}",
+                v.limit,
+                v.condition.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+            ),
             PhysicalPlan::EvalScalar(v) => v
                 .exprs
                 .iter()
@@ -784,11 +1467,91 @@ impl PhysicalPlan {
 
                 conditions.join(" AND ")
             }
+            PhysicalPlan::SortMergeJoin(v) => v
+                .left_keys
+                .iter()
+                .zip(v.right_keys.iter())
+                .map(|(l, r)| {
+                    format!(
+                        "({} = {})",
+                        l.as_expr(&BUILTIN_FUNCTIONS).sql_display(),
+                        r.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+                    )
+                })
+                .join(" AND "),
+            PhysicalPlan::SemiHashJoin(v) | PhysicalPlan::AntiHashJoin(v) => v
+                .build_keys
+                .iter()
+                .zip(v.probe_keys.iter())
+                .map(|(l, r)| {
+                    format!(
+                        "({} = {})",
+                        l.as_expr(&BUILTIN_FUNCTIONS).sql_display(),
+                        r.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+                    )
+                })
+                .join(" AND "),
             PhysicalPlan::ProjectSet(v) => v
                 .srf_exprs
                 .iter()
                 .map(|(x, _)| x.as_expr(&BUILTIN_FUNCTIONS).sql_display())
                 .join(", "),
+            PhysicalPlan::Transpose(v) => v.names.join(", "),
+            PhysicalPlan::Histogram(v) => format!("#{}, {}", v.column, v.num_buckets),
+            PhysicalPlan::Correlation(v) => format!("corr(#{}, #{})", v.x_col, v.y_col),
+            PhysicalPlan::TDigestAgg(v) => {
+                format!("approx_percentile_cont(#{}, {})", v.column, v.percentile)
+            }
+            PhysicalPlan::GroupingId(v) => format!(
+                "grouping_id<{}>(#{})",
+                v.grouping_columns.iter().join(", "),
+                v.grouping_id_index
+            ),
+            PhysicalPlan::SkewDetection(v) => v
+                .partition_key
+                .iter()
+                .map(|x| x.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                .join(", "),
+            PhysicalPlan::ConvertTimezone(v) => format!("{} {}", v.direction, v.tz),
+            PhysicalPlan::MaskApply(v) => v
+                .masking_rules
+                .iter()
+                .map(|(index, _)| format!("#{index}"))
+                .join(", "),
+            PhysicalPlan::RowAccessPolicy(v) => {
+                v.policy_filter.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+            }
+            PhysicalPlan::Replicate(v) => format!("cache key: {}", v.cache_key),
+            PhysicalPlan::AsyncAggregate(v) => format!("result cache key: {}", v.result_cache_key),
+            PhysicalPlan::CteMaterialization(v) => {
+                format!("cte id: {}, {} consumers", v.cte_id, v.consumer_count)
+            }
+            PhysicalPlan::StreamOutput(v) => v.path.clone(),
+            PhysicalPlan::BloomBuild(v) => v.segment_location.clone(),
+            PhysicalPlan::WriteAheadLog(v) => v.wal_location.clone(),
+            PhysicalPlan::Emit(v) => v
+                .returning_exprs
+                .iter()
+                .map(|(expr, _)| expr.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                .join(", "),
+            PhysicalPlan::Qualify(v) => v
+                .predicates
+                .iter()
+                .map(|x| x.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                .join(" AND "),
+            PhysicalPlan::Zip(v) => v
+                .arrays
+                .iter()
+                .map(|(x, _)| x.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                .join(", "),
+            PhysicalPlan::FlatMap(v) => format!(
+                "{}({})",
+                v.func_name,
+                v.args
+                    .iter()
+                    .map(|x| x.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                    .join(", ")
+            ),
             PhysicalPlan::AggregateExpand(v) => v
                 .grouping_sets
                 .sets
@@ -872,6 +1635,26 @@ impl PhysicalPlan {
                 .iter()
                 .map(|x| x.display_name.clone())
                 .join(", "),
+            PhysicalPlan::SequenceNext(v) => format!("nextval({})", v.sequence_name),
+            PhysicalPlan::AbortIfEmpty(v) => v.error_message.clone(),
+            PhysicalPlan::ResultCacheScan(v) => v.location.clone(),
+            PhysicalPlan::FuzzyMatch(v) => format!("{} k={}", v.metric, v.k),
+            PhysicalPlan::GeoScan(v) => v.spatial_predicate.to_string(),
+            PhysicalPlan::BloomLookup(v) => format!("#{} = {}", v.lookup_column, v.lookup_value),
+            PhysicalPlan::BitmapIndexScan(v) => format!(
+                "#{} IN ({})",
+                v.indexed_column,
+                v.predicate_values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            PhysicalPlan::Compact(v) => format!("{} rows per block", v.target_block_size_rows),
+            PhysicalPlan::BlockSample(v) => {
+                format!("probability: {}, seed: {}", v.probability, v.seed)
+            }
+            PhysicalPlan::MvRefreshPartial(v) => format!("index {}", v.index_id),
             _ => String::new(),
         })
     }
@@ -913,6 +1696,15 @@ impl PhysicalPlan {
                         .collect(),
                 );
             }
+            PhysicalPlan::Qualify(v) => {
+                labels.insert(
+                    String::from("Qualify condition"),
+                    v.predicates
+                        .iter()
+                        .map(|x| x.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                        .collect(),
+                );
+            }
             PhysicalPlan::Limit(v) => {
                 labels.insert(String::from("Offset"), vec![v.offset.to_string()]);
 
@@ -920,6 +1712,13 @@ impl PhysicalPlan {
                     labels.insert(String::from("Number of rows"), vec![limit.to_string()]);
                 }
             }
+            PhysicalPlan::ConditionalLimit(v) => {
+                labels.insert(String::from("Number of rows"), vec![v.limit.to_string()]);
+                labels.insert(String::from("Limit condition"), vec![v
+                    .condition
+                    .as_expr(&BUILTIN_FUNCTIONS)
+                    .sql_display()]);
+            }
             PhysicalPlan::EvalScalar(v) => {
                 labels.insert(
                     String::from("List of Expressions"),
@@ -953,6 +1752,29 @@ impl PhysicalPlan {
                     );
                 }
             }
+            PhysicalPlan::SortMergeAggregate(v) => {
+                if !v.group_by_display.is_empty() {
+                    labels.insert(String::from("Grouping keys"), v.group_by_display.clone());
+                }
+
+                if !v.agg_funcs.is_empty() {
+                    labels.insert(
+                        String::from("Aggregate Functions"),
+                        v.agg_funcs.iter().map(|x| x.display.clone()).collect(),
+                    );
+                }
+            }
+            PhysicalPlan::MaterializeAgg(v) => {
+                if !v.original_agg_funcs.is_empty() {
+                    labels.insert(
+                        String::from("Aggregate Functions"),
+                        v.original_agg_funcs
+                            .iter()
+                            .map(|x| x.display.clone())
+                            .collect(),
+                    );
+                }
+            }
             PhysicalPlan::HashJoin(v) => {
                 labels.insert(String::from("Join Type"), vec![v.join_type.to_string()]);
 
@@ -986,6 +1808,40 @@ impl PhysicalPlan {
                     );
                 }
             }
+            PhysicalPlan::SortMergeJoin(v) => {
+                labels.insert(String::from("Join Type"), vec![v.join_type.to_string()]);
+                labels.insert(
+                    String::from("Join Left Side Keys"),
+                    v.left_keys
+                        .iter()
+                        .map(|x| x.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                        .collect(),
+                );
+                labels.insert(
+                    String::from("Join Right Side Keys"),
+                    v.right_keys
+                        .iter()
+                        .map(|x| x.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                        .collect(),
+                );
+            }
+            PhysicalPlan::SemiHashJoin(v) | PhysicalPlan::AntiHashJoin(v) => {
+                labels.insert(String::from("Join Type"), vec![v.join_type.to_string()]);
+                labels.insert(
+                    String::from("Join Build Side Keys"),
+                    v.build_keys
+                        .iter()
+                        .map(|x| x.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                        .collect(),
+                );
+                labels.insert(
+                    String::from("Join Probe Side Keys"),
+                    v.probe_keys
+                        .iter()
+                        .map(|x| x.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                        .collect(),
+                );
+            }
             _ => {}
         };
 
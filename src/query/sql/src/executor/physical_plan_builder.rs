@@ -25,7 +25,11 @@ use databend_storages_common_table_meta::meta::TableMetaTimestamps;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 
 use crate::executor::explain::PlanStatsInfo;
+use crate::executor::ConvertTimezoneInjector;
 use crate::executor::PhysicalPlan;
+#[cfg(debug_assertions)]
+use crate::executor::PhysicalPlanValidator;
+use crate::executor::SkewDetectionInjector;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
@@ -39,6 +43,12 @@ pub struct PhysicalPlanBuilder {
     pub(crate) dry_run: bool,
     // DataMutation info, used to build MergeInto physical plan
     pub(crate) mutation_build_info: Option<MutationBuildInfo>,
+    // Build `histogram(col, n)` aggregates as a single pass `Histogram`, used by analyze table.
+    pub(crate) streaming_histogram: bool,
+    // Read the `TIMESTAMP` columns of table scans as wall-clock times of this timezone.
+    pub(crate) convert_timezone: Option<String>,
+    // The depth of nested `build` calls, the children of a plan are built by nested calls.
+    build_depth: usize,
 }
 
 impl PhysicalPlanBuilder {
@@ -50,6 +60,9 @@ impl PhysicalPlanBuilder {
             func_ctx,
             dry_run,
             mutation_build_info: None,
+            streaming_histogram: false,
+            convert_timezone: None,
+            build_depth: 0,
         }
     }
 
@@ -63,9 +76,30 @@ impl PhysicalPlanBuilder {
     }
 
     pub async fn build(&mut self, s_expr: &SExpr, required: ColumnSet) -> Result<PhysicalPlan> {
-        let mut plan = self.build_physical_plan(s_expr, required).await?;
+        self.build_depth += 1;
+        let plan = self.build_physical_plan(s_expr, required).await;
+        self.build_depth -= 1;
+
+        let mut plan = plan?;
+        if self.build_depth == 0 {
+            let settings = self.ctx.get_settings();
+            if settings.get_enable_skew_detection()? {
+                let skew_threshold = settings.get_skew_detection_threshold()? as f64;
+                plan = SkewDetectionInjector::inject(&plan, skew_threshold)?;
+            }
+            if let Some(tz) = &self.convert_timezone {
+                plan = ConvertTimezoneInjector::inject(&plan, tz.clone())?;
+            }
+        }
         plan.adjust_plan_id(&mut 0);
 
+        // Only the whole plan is validated, a sub-plan may be incomplete, e.g. an `AggregatePartial`
+        // whose `AggregateFinal` is not built yet.
+        #[cfg(debug_assertions)]
+        if self.build_depth == 0 {
+            PhysicalPlanValidator::validate(&plan)?;
+        }
+
         Ok(plan)
     }
 
@@ -94,6 +128,10 @@ impl PhysicalPlanBuilder {
             RelOperator::Window(window) => {
                 self.build_window(s_expr, window, required, stat_info).await
             }
+            RelOperator::WindowAgg(window_agg) => {
+                self.build_window_agg(s_expr, window_agg, required, stat_info)
+                    .await
+            }
             RelOperator::Sort(sort) => self.build_sort(s_expr, sort, required, stat_info).await,
             RelOperator::Limit(limit) => self.build_limit(s_expr, limit, required, stat_info).await,
             RelOperator::Exchange(exchange) => {
@@ -103,10 +141,29 @@ impl PhysicalPlanBuilder {
                 self.build_union_all(s_expr, union_all, required, stat_info)
                     .await
             }
+            RelOperator::Except(except) => {
+                self.build_except(s_expr, except, required, stat_info).await
+            }
+            RelOperator::Intersect(intersect) => {
+                self.build_intersect(s_expr, intersect, required, stat_info)
+                    .await
+            }
             RelOperator::ProjectSet(project_set) => {
                 self.build_project_set(s_expr, project_set, required, stat_info)
                     .await
             }
+            RelOperator::Qualify(qualify) => {
+                self.build_qualify(s_expr, qualify, required, stat_info)
+                    .await
+            }
+            RelOperator::Unpivot(unpivot) => {
+                self.build_transpose(s_expr, unpivot, required, stat_info)
+                    .await
+            }
+            RelOperator::JsonTable(json_table) => {
+                self.build_json_table(s_expr, json_table, required, stat_info)
+                    .await
+            }
             RelOperator::ConstantTableScan(scan) => {
                 self.build_constant_table_scan(scan, required).await
             }
@@ -128,7 +185,7 @@ impl PhysicalPlanBuilder {
             RelOperator::MutationSource(mutation_source) => {
                 self.build_mutation_source(mutation_source).await
             }
-            RelOperator::CompactBlock(compact) => self.build_compact_block(compact).await,
+            RelOperator::CompactBlock(compact) => self.build_compact(compact).await,
         }
     }
 
@@ -136,6 +193,14 @@ impl PhysicalPlanBuilder {
         self.mutation_build_info = Some(mutation_build_info);
     }
 
+    pub fn set_streaming_histogram(&mut self, streaming_histogram: bool) {
+        self.streaming_histogram = streaming_histogram;
+    }
+
+    pub fn set_convert_timezone(&mut self, tz: Option<String>) {
+        self.convert_timezone = tz;
+    }
+
     pub fn set_metadata(&mut self, metadata: MetadataRef) {
         self.metadata = metadata;
     }
@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::executor::physical_plans::FragmentKind;
+use crate::executor::PhysicalPlan;
+
+/// Check the invariants of a physical plan before execution:
+/// - No `Merge` exchange inside the sub-tree of an `Expansive` (broadcast) exchange.
+/// - An `AggregatePartial` is always followed by an `Exchange` or an `AggregateFinal`.
+/// - The runtime filters of a `HashJoin` are always applied to a `TableScan` in the plan.
+pub struct PhysicalPlanValidator;
+
+impl PhysicalPlanValidator {
+    pub fn validate(plan: &PhysicalPlan) -> Result<()> {
+        let mut scan_ids = HashSet::new();
+        Self::collect_scan_ids(plan, &mut scan_ids);
+        Self::validate_plan(plan, None, false, &scan_ids)
+    }
+
+    fn collect_scan_ids(plan: &PhysicalPlan, scan_ids: &mut HashSet<usize>) {
+        if let PhysicalPlan::TableScan(scan) = plan {
+            scan_ids.insert(scan.scan_id);
+        }
+        for child in plan.children() {
+            Self::collect_scan_ids(child, scan_ids);
+        }
+    }
+
+    #[recursive::recursive]
+    fn validate_plan(
+        plan: &PhysicalPlan,
+        parent: Option<&PhysicalPlan>,
+        under_expansive: bool,
+        scan_ids: &HashSet<usize>,
+    ) -> Result<()> {
+        match plan {
+            PhysicalPlan::Exchange(exchange)
+                if under_expansive && matches!(exchange.kind, FragmentKind::Merge) =>
+            {
+                return Err(ErrorCode::Internal(format!(
+                    "Invalid physical plan: Merge exchange (plan id {}) inside a broadcast exchange",
+                    exchange.plan_id
+                )));
+            }
+            PhysicalPlan::AggregatePartial(partial)
+                if !matches!(
+                    parent,
+                    Some(PhysicalPlan::Exchange(_) | PhysicalPlan::AggregateFinal(_))
+                ) =>
+            {
+                return Err(ErrorCode::Internal(format!(
+                    "Invalid physical plan: AggregatePartial (plan id {}) must be followed by Exchange or AggregateFinal, but got {}",
+                    partial.plan_id,
+                    parent.map_or("nothing".to_string(), |parent| parent.name())
+                )));
+            }
+            PhysicalPlan::HashJoin(join) => {
+                for (_, scan_id) in join.probe_keys_rt.iter().flatten() {
+                    if !scan_ids.contains(scan_id) {
+                        return Err(ErrorCode::Internal(format!(
+                            "Invalid physical plan: the runtime filter of HashJoin (plan id {}) is applied to a missing table scan {}",
+                            join.plan_id, scan_id
+                        )));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        let under_expansive = under_expansive
+            || matches!(plan, PhysicalPlan::Exchange(exchange) if matches!(exchange.kind, FragmentKind::Expansive));
+        for child in plan.children() {
+            Self::validate_plan(child, Some(plan), under_expansive, scan_ids)?;
+        }
+        Ok(())
+    }
+}
@@ -23,10 +23,16 @@ use super::physical_plans::MutationOrganize;
 use super::physical_plans::MutationSplit;
 use super::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plan::PhysicalPlan;
+use crate::executor::physical_plans::AbortIfEmpty;
 use crate::executor::physical_plans::AggregateExpand;
 use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregatePartial;
+use crate::executor::physical_plans::AsyncAggregate;
 use crate::executor::physical_plans::AsyncFunction;
+use crate::executor::physical_plans::BitmapIndexScan;
+use crate::executor::physical_plans::BlockSample;
+use crate::executor::physical_plans::BloomBuild;
+use crate::executor::physical_plans::BloomLookup;
 use crate::executor::physical_plans::ChunkAppendData;
 use crate::executor::physical_plans::ChunkCastSchema;
 use crate::executor::physical_plans::ChunkCommitInsert;
@@ -34,38 +40,81 @@ use crate::executor::physical_plans::ChunkEvalScalar;
 use crate::executor::physical_plans::ChunkFillAndReorder;
 use crate::executor::physical_plans::ChunkFilter;
 use crate::executor::physical_plans::ChunkMerge;
+use crate::executor::physical_plans::Classify;
+use crate::executor::physical_plans::ClusterSort;
 use crate::executor::physical_plans::ColumnMutation;
 use crate::executor::physical_plans::CommitSink;
+use crate::executor::physical_plans::Compact;
 use crate::executor::physical_plans::CompactSource;
+use crate::executor::physical_plans::ConditionalLimit;
 use crate::executor::physical_plans::ConstantTableScan;
+use crate::executor::physical_plans::ConvertTimezone;
 use crate::executor::physical_plans::CopyIntoLocation;
 use crate::executor::physical_plans::CopyIntoTable;
 use crate::executor::physical_plans::CopyIntoTableSource;
+use crate::executor::physical_plans::Correlation;
+use crate::executor::physical_plans::CteMaterialization;
 use crate::executor::physical_plans::DistributedInsertSelect;
 use crate::executor::physical_plans::Duplicate;
+use crate::executor::physical_plans::Emit;
+use crate::executor::physical_plans::EnforceSchema;
 use crate::executor::physical_plans::EvalScalar;
 use crate::executor::physical_plans::Exchange;
 use crate::executor::physical_plans::ExchangeSink;
 use crate::executor::physical_plans::ExchangeSource;
 use crate::executor::physical_plans::Filter;
+use crate::executor::physical_plans::FlatMap;
+use crate::executor::physical_plans::FunctionImport;
+use crate::executor::physical_plans::FuzzyMatch;
+use crate::executor::physical_plans::GeoScan;
+use crate::executor::physical_plans::GroupingId;
 use crate::executor::physical_plans::HashJoin;
+use crate::executor::physical_plans::Histogram;
+use crate::executor::physical_plans::JsonEach;
+use crate::executor::physical_plans::JsonExtract;
+use crate::executor::physical_plans::JsonTable;
 use crate::executor::physical_plans::Limit;
+use crate::executor::physical_plans::MaskApply;
+use crate::executor::physical_plans::MaterializeAgg;
 use crate::executor::physical_plans::Mutation;
 use crate::executor::physical_plans::MutationSource;
+use crate::executor::physical_plans::MvRefreshPartial;
+use crate::executor::physical_plans::PrewarmCache;
 use crate::executor::physical_plans::ProjectSet;
+use crate::executor::physical_plans::Qualify;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::Recluster;
 use crate::executor::physical_plans::ReplaceAsyncSourcer;
 use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
+use crate::executor::physical_plans::Replicate;
+use crate::executor::physical_plans::ResultCacheScan;
+use crate::executor::physical_plans::RowAccessPolicy;
 use crate::executor::physical_plans::RowFetch;
+use crate::executor::physical_plans::Scatter;
+use crate::executor::physical_plans::SchemaEvolve;
+use crate::executor::physical_plans::SemiHashJoin;
+use crate::executor::physical_plans::SequenceNext;
+use crate::executor::physical_plans::SessionWindow;
 use crate::executor::physical_plans::Shuffle;
+use crate::executor::physical_plans::SkewDetection;
 use crate::executor::physical_plans::Sort;
+use crate::executor::physical_plans::SortMergeAggregate;
+use crate::executor::physical_plans::SortMergeJoin;
+use crate::executor::physical_plans::SortedMerge;
+use crate::executor::physical_plans::SpillSort;
+use crate::executor::physical_plans::StreamOutput;
+use crate::executor::physical_plans::TDigestAgg;
 use crate::executor::physical_plans::TableScan;
+use crate::executor::physical_plans::Transpose;
+use crate::executor::physical_plans::TumblingWindow;
 use crate::executor::physical_plans::Udf;
 use crate::executor::physical_plans::UnionAll;
+use crate::executor::physical_plans::Watermark;
 use crate::executor::physical_plans::Window;
 use crate::executor::physical_plans::WindowPartition;
+use crate::executor::physical_plans::WriteAheadLog;
+use crate::executor::physical_plans::Zip;
 
 pub trait PhysicalPlanReplacer {
     fn replace(&mut self, plan: &PhysicalPlan) -> Result<PhysicalPlan> {
@@ -77,7 +126,11 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::AggregateExpand(plan) => self.replace_aggregate_expand(plan),
             PhysicalPlan::AggregatePartial(plan) => self.replace_aggregate_partial(plan),
             PhysicalPlan::AggregateFinal(plan) => self.replace_aggregate_final(plan),
+            PhysicalPlan::SortMergeAggregate(plan) => self.replace_sort_merge_aggregate(plan),
             PhysicalPlan::Window(plan) => self.replace_window(plan),
+            PhysicalPlan::TumblingWindow(plan) => self.replace_tumbling_window(plan),
+            PhysicalPlan::SessionWindow(plan) => self.replace_session_window(plan),
+            PhysicalPlan::Watermark(plan) => self.replace_watermark(plan),
             PhysicalPlan::WindowPartition(plan) => self.replace_window_partition(plan),
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
             PhysicalPlan::Limit(plan) => self.replace_limit(plan),
@@ -92,6 +145,9 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::CompactSource(plan) => self.replace_compact_source(plan),
             PhysicalPlan::CommitSink(plan) => self.replace_commit_sink(plan),
             PhysicalPlan::RangeJoin(plan) => self.replace_range_join(plan),
+            PhysicalPlan::SortMergeJoin(plan) => self.replace_sort_merge_join(plan),
+            PhysicalPlan::SemiHashJoin(plan) => self.replace_semi_hash_join(plan),
+            PhysicalPlan::AntiHashJoin(plan) => self.replace_anti_hash_join(plan),
             PhysicalPlan::CopyIntoTable(plan) => self.replace_copy_into_table(plan),
             PhysicalPlan::CopyIntoLocation(plan) => self.replace_copy_into_location(plan),
             PhysicalPlan::ReplaceAsyncSourcer(plan) => self.replace_async_sourcer(plan),
@@ -107,10 +163,13 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::ConstantTableScan(plan) => self.replace_constant_table_scan(plan),
             PhysicalPlan::ExpressionScan(plan) => self.replace_expression_scan(plan),
             PhysicalPlan::CacheScan(plan) => self.replace_cache_scan(plan),
+            PhysicalPlan::ResultCacheScan(plan) => self.replace_result_cache_scan(plan),
             PhysicalPlan::Recluster(plan) => self.replace_recluster(plan),
             PhysicalPlan::HilbertPartition(plan) => self.replace_hilbert_serialize(plan),
             PhysicalPlan::Udf(plan) => self.replace_udf(plan),
             PhysicalPlan::AsyncFunction(plan) => self.replace_async_function(plan),
+            PhysicalPlan::SequenceNext(plan) => self.replace_sequence_next(plan),
+            PhysicalPlan::AbortIfEmpty(plan) => self.replace_abort_if_empty(plan),
             PhysicalPlan::Duplicate(plan) => self.replace_duplicate(plan),
             PhysicalPlan::Shuffle(plan) => self.replace_shuffle(plan),
             PhysicalPlan::ChunkFilter(plan) => self.replace_chunk_filter(plan),
@@ -120,6 +179,46 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::ChunkAppendData(plan) => self.replace_chunk_append_data(plan),
             PhysicalPlan::ChunkMerge(plan) => self.replace_chunk_merge(plan),
             PhysicalPlan::ChunkCommitInsert(plan) => self.replace_chunk_commit_insert(plan),
+            PhysicalPlan::MaskApply(plan) => self.replace_mask_apply(plan),
+            PhysicalPlan::RowAccessPolicy(plan) => self.replace_row_access_policy(plan),
+            PhysicalPlan::ConvertTimezone(plan) => self.replace_convert_timezone(plan),
+            PhysicalPlan::MaterializeAgg(plan) => self.replace_materialize_agg(plan),
+            PhysicalPlan::BloomBuild(plan) => self.replace_bloom_build(plan),
+            PhysicalPlan::WriteAheadLog(plan) => self.replace_write_ahead_log(plan),
+            PhysicalPlan::ConditionalLimit(plan) => self.replace_conditional_limit(plan),
+            PhysicalPlan::EnforceSchema(plan) => self.replace_enforce_schema(plan),
+            PhysicalPlan::FunctionImport(plan) => self.replace_function_import(plan),
+            PhysicalPlan::SkewDetection(plan) => self.replace_skew_detection(plan),
+            PhysicalPlan::Correlation(plan) => self.replace_correlation(plan),
+            PhysicalPlan::TDigestAgg(plan) => self.replace_tdigest_agg(plan),
+            PhysicalPlan::CteMaterialization(plan) => self.replace_cte_materialization(plan),
+            PhysicalPlan::FlatMap(plan) => self.replace_flat_map(plan),
+            PhysicalPlan::FuzzyMatch(plan) => self.replace_fuzzy_match(plan),
+            PhysicalPlan::GeoScan(plan) => self.replace_geo_scan(plan),
+            PhysicalPlan::BloomLookup(plan) => self.replace_bloom_lookup(plan),
+            PhysicalPlan::BitmapIndexScan(plan) => self.replace_bitmap_index_scan(plan),
+            PhysicalPlan::Compact(plan) => self.replace_compact(plan),
+            PhysicalPlan::BlockSample(plan) => self.replace_block_sample(plan),
+            PhysicalPlan::MvRefreshPartial(plan) => self.replace_mv_refresh_partial(plan),
+            PhysicalPlan::SchemaEvolve(plan) => self.replace_schema_evolve(plan),
+            PhysicalPlan::SortedMerge(plan) => self.replace_sorted_merge(plan),
+            PhysicalPlan::Scatter(plan) => self.replace_scatter(plan),
+            PhysicalPlan::JsonEach(plan) => self.replace_json_each(plan),
+            PhysicalPlan::JsonTable(plan) => self.replace_json_table(plan),
+            PhysicalPlan::Classify(plan) => self.replace_classify(plan),
+            PhysicalPlan::StreamOutput(plan) => self.replace_stream_output(plan),
+            PhysicalPlan::Replicate(plan) => self.replace_replicate(plan),
+            PhysicalPlan::GroupingId(plan) => self.replace_grouping_id(plan),
+            PhysicalPlan::Emit(plan) => self.replace_emit(plan),
+            PhysicalPlan::Histogram(plan) => self.replace_histogram(plan),
+            PhysicalPlan::Qualify(plan) => self.replace_qualify(plan),
+            PhysicalPlan::Transpose(plan) => self.replace_transpose(plan),
+            PhysicalPlan::JsonExtract(plan) => self.replace_json_extract(plan),
+            PhysicalPlan::ClusterSort(plan) => self.replace_cluster_sort(plan),
+            PhysicalPlan::SpillSort(plan) => self.replace_spill_sort(plan),
+            PhysicalPlan::Zip(plan) => self.replace_zip(plan),
+            PhysicalPlan::PrewarmCache(plan) => self.replace_prewarm_cache(plan),
+            PhysicalPlan::AsyncAggregate(plan) => self.replace_async_aggregate(plan),
         }
     }
 
@@ -155,6 +254,14 @@ pub trait PhysicalPlanReplacer {
         Ok(PhysicalPlan::CacheScan(plan.clone()))
     }
 
+    fn replace_result_cache_scan(&mut self, plan: &ResultCacheScan) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::ResultCacheScan(Box::new(ResultCacheScan {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
     fn replace_filter(&mut self, plan: &Filter) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -203,6 +310,7 @@ pub trait PhysicalPlanReplacer {
             agg_funcs: plan.agg_funcs.clone(),
             stat_info: plan.stat_info.clone(),
             rank_limit: plan.rank_limit.clone(),
+            early_termination_threshold: plan.early_termination_threshold,
         }))
     }
 
@@ -220,6 +328,21 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_sort_merge_aggregate(&mut self, plan: &SortMergeAggregate) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::SortMergeAggregate(Box::new(
+            SortMergeAggregate {
+                plan_id: plan.plan_id,
+                input: Box::new(input),
+                group_by: plan.group_by.clone(),
+                agg_funcs: plan.agg_funcs.clone(),
+                group_by_display: plan.group_by_display.clone(),
+                stat_info: plan.stat_info.clone(),
+            },
+        )))
+    }
+
     fn replace_window(&mut self, plan: &Window) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -235,6 +358,35 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_tumbling_window(&mut self, plan: &TumblingWindow) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::TumblingWindow(TumblingWindow {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_session_window(&mut self, plan: &SessionWindow) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::SessionWindow(SessionWindow {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_watermark(&mut self, plan: &Watermark) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        let late_output = match &plan.late_output {
+            Some(late_output) => Some(Box::new(self.replace(late_output)?)),
+            None => None,
+        };
+        Ok(PhysicalPlan::Watermark(Box::new(Watermark {
+            input: Box::new(input),
+            late_output,
+            ..plan.clone()
+        })))
+    }
+
     fn replace_window_partition(&mut self, plan: &WindowPartition) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -270,6 +422,7 @@ pub trait PhysicalPlanReplacer {
             probe_to_build: plan.probe_to_build.clone(),
             output_schema: plan.output_schema.clone(),
             need_hold_hash_table: plan.need_hold_hash_table,
+            build_dedup: plan.build_dedup,
             stat_info: plan.stat_info.clone(),
             probe_keys_rt: plan.probe_keys_rt.clone(),
             enable_bloom_runtime_filter: plan.enable_bloom_runtime_filter,
@@ -295,6 +448,39 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_sort_merge_join(&mut self, plan: &SortMergeJoin) -> Result<PhysicalPlan> {
+        let left = self.replace(&plan.left)?;
+        let right = self.replace(&plan.right)?;
+
+        Ok(PhysicalPlan::SortMergeJoin(SortMergeJoin {
+            left: Box::new(left),
+            right: Box::new(right),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_semi_hash_join(&mut self, plan: &SemiHashJoin) -> Result<PhysicalPlan> {
+        let build = self.replace(&plan.build)?;
+        let probe = self.replace(&plan.probe)?;
+
+        Ok(PhysicalPlan::SemiHashJoin(SemiHashJoin {
+            build: Box::new(build),
+            probe: Box::new(probe),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_anti_hash_join(&mut self, plan: &SemiHashJoin) -> Result<PhysicalPlan> {
+        let build = self.replace(&plan.build)?;
+        let probe = self.replace(&plan.probe)?;
+
+        Ok(PhysicalPlan::AntiHashJoin(SemiHashJoin {
+            build: Box::new(build),
+            probe: Box::new(probe),
+            ..plan.clone()
+        }))
+    }
+
     fn replace_sort(&mut self, plan: &Sort) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -305,6 +491,7 @@ pub trait PhysicalPlanReplacer {
             limit: plan.limit,
             after_exchange: plan.after_exchange,
             pre_projection: plan.pre_projection.clone(),
+            parallelism: plan.parallelism,
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -317,6 +504,7 @@ pub trait PhysicalPlanReplacer {
             input: Box::new(input),
             limit: plan.limit,
             offset: plan.offset,
+            pre_limit: plan.pre_limit,
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -346,6 +534,7 @@ pub trait PhysicalPlanReplacer {
             keys: plan.keys.clone(),
             ignore_exchange: plan.ignore_exchange,
             allow_adjust_parallelism: plan.allow_adjust_parallelism,
+            consistent_hash: plan.consistent_hash,
         }))
     }
 
@@ -369,6 +558,7 @@ pub trait PhysicalPlanReplacer {
             query_id: plan.query_id.clone(),
             ignore_exchange: plan.ignore_exchange,
             allow_adjust_parallelism: plan.allow_adjust_parallelism,
+            consistent_hash: plan.consistent_hash,
         }))
     }
 
@@ -545,6 +735,22 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_sequence_next(&mut self, plan: &SequenceNext) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::SequenceNext(Box::new(SequenceNext {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_abort_if_empty(&mut self, plan: &AbortIfEmpty) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::AbortIfEmpty(Box::new(AbortIfEmpty {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
     fn replace_duplicate(&mut self, plan: &Duplicate) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
         Ok(PhysicalPlan::Duplicate(Box::new(Duplicate {
@@ -625,6 +831,320 @@ pub trait PhysicalPlanReplacer {
             },
         )))
     }
+
+    fn replace_prewarm_cache(&mut self, plan: &PrewarmCache) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::PrewarmCache(Box::new(plan.clone())))
+    }
+
+    fn replace_async_aggregate(&mut self, plan: &AsyncAggregate) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::AsyncAggregate(Box::new(plan.clone())))
+    }
+
+    fn replace_zip(&mut self, plan: &Zip) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Zip(Zip {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_cluster_sort(&mut self, plan: &ClusterSort) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::ClusterSort(ClusterSort {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_spill_sort(&mut self, plan: &SpillSort) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::SpillSort(Box::new(SpillSort {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_json_extract(&mut self, plan: &JsonExtract) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::JsonExtract(Box::new(JsonExtract {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_transpose(&mut self, plan: &Transpose) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Transpose(Transpose {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_qualify(&mut self, plan: &Qualify) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Qualify(Qualify {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_histogram(&mut self, plan: &Histogram) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Histogram(Histogram {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_emit(&mut self, plan: &Emit) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Emit(Emit {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_grouping_id(&mut self, plan: &GroupingId) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::GroupingId(GroupingId {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_replicate(&mut self, plan: &Replicate) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Replicate(Replicate {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_stream_output(&mut self, plan: &StreamOutput) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::StreamOutput(Box::new(StreamOutput {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_json_each(&mut self, plan: &JsonEach) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::JsonEach(Box::new(JsonEach {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_json_table(&mut self, plan: &JsonTable) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::JsonTable(Box::new(JsonTable {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_classify(&mut self, plan: &Classify) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Classify(Box::new(Classify {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_scatter(&mut self, plan: &Scatter) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Scatter(Box::new(Scatter {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_sorted_merge(&mut self, plan: &SortedMerge) -> Result<PhysicalPlan> {
+        let inputs = plan
+            .inputs
+            .iter()
+            .map(|input| self.replace(input))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(PhysicalPlan::SortedMerge(Box::new(SortedMerge {
+            inputs,
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_schema_evolve(&mut self, plan: &SchemaEvolve) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::SchemaEvolve(Box::new(SchemaEvolve {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_mv_refresh_partial(&mut self, plan: &MvRefreshPartial) -> Result<PhysicalPlan> {
+        let delta_plan = self.replace(&plan.delta_plan)?;
+        Ok(PhysicalPlan::MvRefreshPartial(Box::new(MvRefreshPartial {
+            delta_plan: Box::new(delta_plan),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_block_sample(&mut self, plan: &BlockSample) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::BlockSample(Box::new(BlockSample {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_compact(&mut self, plan: &Compact) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Compact(Box::new(Compact {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_geo_scan(&mut self, plan: &GeoScan) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::GeoScan(Box::new(GeoScan {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_bloom_lookup(&mut self, plan: &BloomLookup) -> Result<PhysicalPlan> {
+        let inner_scan = self.replace(&plan.inner_scan)?;
+        Ok(PhysicalPlan::BloomLookup(Box::new(BloomLookup {
+            inner_scan: Box::new(inner_scan),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_bitmap_index_scan(&mut self, plan: &BitmapIndexScan) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::BitmapIndexScan(Box::new(BitmapIndexScan {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_fuzzy_match(&mut self, plan: &FuzzyMatch) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::FuzzyMatch(Box::new(FuzzyMatch {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_flat_map(&mut self, plan: &FlatMap) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::FlatMap(FlatMap {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_cte_materialization(&mut self, plan: &CteMaterialization) -> Result<PhysicalPlan> {
+        let body = self.replace(&plan.body)?;
+        Ok(PhysicalPlan::CteMaterialization(Box::new(
+            CteMaterialization {
+                body: Box::new(body),
+                ..plan.clone()
+            },
+        )))
+    }
+
+    fn replace_correlation(&mut self, plan: &Correlation) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Correlation(Correlation {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_tdigest_agg(&mut self, plan: &TDigestAgg) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::TDigestAgg(TDigestAgg {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_skew_detection(&mut self, plan: &SkewDetection) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::SkewDetection(SkewDetection {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_function_import(&mut self, plan: &FunctionImport) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::FunctionImport(plan.clone()))
+    }
+
+    fn replace_enforce_schema(&mut self, plan: &EnforceSchema) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::EnforceSchema(Box::new(EnforceSchema {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_conditional_limit(&mut self, plan: &ConditionalLimit) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::ConditionalLimit(Box::new(ConditionalLimit {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_bloom_build(&mut self, plan: &BloomBuild) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::BloomBuild(Box::new(BloomBuild {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_write_ahead_log(&mut self, plan: &WriteAheadLog) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::WriteAheadLog(Box::new(WriteAheadLog {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_materialize_agg(&mut self, plan: &MaterializeAgg) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::MaterializeAgg(Box::new(MaterializeAgg {
+            input: Box::new(input),
+            ..plan.clone()
+        })))
+    }
+
+    fn replace_convert_timezone(&mut self, plan: &ConvertTimezone) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::ConvertTimezone(ConvertTimezone {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_mask_apply(&mut self, plan: &MaskApply) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::MaskApply(MaskApply {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_row_access_policy(&mut self, plan: &RowAccessPolicy) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::RowAccessPolicy(RowAccessPolicy {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
 }
 
 impl PhysicalPlan {
@@ -643,11 +1163,15 @@ impl PhysicalPlan {
                 | PhysicalPlan::ConstantTableScan(_)
                 | PhysicalPlan::ExpressionScan(_)
                 | PhysicalPlan::CacheScan(_)
+                | PhysicalPlan::ResultCacheScan(_)
                 | PhysicalPlan::Recluster(_)
                 | PhysicalPlan::HilbertPartition(_)
                 | PhysicalPlan::ExchangeSource(_)
                 | PhysicalPlan::CompactSource(_)
-                | PhysicalPlan::MutationSource(_) => {}
+                | PhysicalPlan::MutationSource(_)
+                | PhysicalPlan::PrewarmCache(_)
+                | PhysicalPlan::AsyncAggregate(_)
+                | PhysicalPlan::FunctionImport(_) => {}
                 PhysicalPlan::Filter(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -663,9 +1187,24 @@ impl PhysicalPlan {
                 PhysicalPlan::AggregateFinal(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::SortMergeAggregate(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::Window(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::TumblingWindow(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::SessionWindow(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::Watermark(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                    if let Some(late_output) = &plan.late_output {
+                        Self::traverse(late_output, pre_visit, visit, post_visit);
+                    }
+                }
                 PhysicalPlan::WindowPartition(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -713,6 +1252,14 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::SortMergeJoin(plan) => {
+                    Self::traverse(&plan.left, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.right, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::SemiHashJoin(plan) | PhysicalPlan::AntiHashJoin(plan) => {
+                    Self::traverse(&plan.build, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.probe, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::CommitSink(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -746,6 +1293,12 @@ impl PhysicalPlan {
                 PhysicalPlan::AsyncFunction(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::SequenceNext(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::AbortIfEmpty(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::Duplicate(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -773,6 +1326,119 @@ impl PhysicalPlan {
                 PhysicalPlan::ChunkCommitInsert(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::MaskApply(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::RowAccessPolicy(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::ConvertTimezone(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::MaterializeAgg(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::BloomBuild(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::WriteAheadLog(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::ConditionalLimit(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::EnforceSchema(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::SkewDetection(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::Correlation(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::TDigestAgg(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::CteMaterialization(plan) => {
+                    Self::traverse(&plan.body, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::FlatMap(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::FuzzyMatch(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::GeoScan(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::BloomLookup(plan) => {
+                    Self::traverse(&plan.inner_scan, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::BitmapIndexScan(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::Compact(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::BlockSample(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::MvRefreshPartial(plan) => {
+                    Self::traverse(&plan.delta_plan, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::SchemaEvolve(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::SortedMerge(plan) => {
+                    for input in plan.inputs.iter() {
+                        Self::traverse(input, pre_visit, visit, post_visit);
+                    }
+                }
+                PhysicalPlan::Scatter(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::JsonEach(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::JsonTable(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::Classify(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::StreamOutput(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::Replicate(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::GroupingId(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::Emit(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::Histogram(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::Qualify(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::Transpose(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::JsonExtract(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::ClusterSort(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::SpillSort(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::Zip(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
             }
             post_visit(plan);
         }
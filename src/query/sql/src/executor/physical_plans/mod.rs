@@ -13,35 +13,66 @@
 // limitations under the License.
 
 mod common;
+mod physical_abort_if_empty;
 mod physical_add_stream_column;
 mod physical_aggregate_expand;
 mod physical_aggregate_final;
 mod physical_aggregate_partial;
+mod physical_async_aggregate;
 mod physical_async_func;
+mod physical_bitmap_index_scan;
+mod physical_block_sample;
+mod physical_bloom_build;
+mod physical_bloom_lookup;
 mod physical_cache_scan;
+mod physical_classify;
+mod physical_cluster_sort;
 mod physical_column_mutation;
 mod physical_commit_sink;
+mod physical_compact;
 mod physical_compact_source;
+mod physical_conditional_limit;
 mod physical_constant_table_scan;
+mod physical_convert_timezone;
 mod physical_copy_into_location;
 mod physical_copy_into_table;
+mod physical_correlation;
+mod physical_cte_materialization;
 mod physical_distributed_insert_select;
+mod physical_emit;
+mod physical_enforce_schema;
 mod physical_eval_scalar;
+mod physical_except;
 mod physical_exchange;
 mod physical_exchange_sink;
 mod physical_exchange_source;
 mod physical_expression_scan;
 mod physical_filter;
+mod physical_flat_map;
+mod physical_function_import;
+mod physical_fuzzy_match;
+mod physical_geo_scan;
+mod physical_grouping_id;
 mod physical_hash_join;
+mod physical_histogram;
+mod physical_intersect;
 mod physical_join;
+mod physical_json_each;
+mod physical_json_extract;
+mod physical_json_table;
 mod physical_limit;
+mod physical_mask_apply;
+mod physical_materialize_agg;
 mod physical_multi_table_insert;
 mod physical_mutation;
 mod physical_mutation_into_organize;
 mod physical_mutation_into_split;
 mod physical_mutation_manipulate;
 mod physical_mutation_source;
+mod physical_mv_refresh_partial;
+mod physical_prewarm_cache;
 mod physical_project_set;
+mod physical_qualify;
 mod physical_r_cte_scan;
 mod physical_range_join;
 mod physical_recluster;
@@ -49,45 +80,97 @@ mod physical_refresh_index;
 mod physical_replace_async_source;
 mod physical_replace_deduplicate;
 mod physical_replace_into;
+mod physical_replicate;
+mod physical_result_cache_scan;
+mod physical_row_access_policy;
 mod physical_row_fetch;
+mod physical_schema_evolve;
+mod physical_semi_hash_join;
+mod physical_sequence_next;
+mod physical_skew_detection;
 mod physical_sort;
+mod physical_sort_merge_aggregate;
+mod physical_sort_merge_join;
+mod physical_sorted_merge;
+mod physical_spill_sort;
+mod physical_stream_output;
 mod physical_table_scan;
+mod physical_tdigest_agg;
+mod physical_time_window;
+mod physical_transpose;
 mod physical_udf;
 mod physical_union_all;
+mod physical_watermark;
 mod physical_window;
 mod physical_window_partition;
+mod physical_write_ahead_log;
+mod physical_zip;
 
 pub use common::*;
+pub use physical_abort_if_empty::AbortIfEmpty;
 pub use physical_add_stream_column::AddStreamColumn;
 pub use physical_aggregate_expand::AggregateExpand;
 pub use physical_aggregate_final::AggregateFinal;
 pub use physical_aggregate_partial::AggregatePartial;
+pub use physical_async_aggregate::AsyncAggregate;
 pub use physical_async_func::AsyncFunction;
 pub use physical_async_func::AsyncFunctionDesc;
+pub use physical_bitmap_index_scan::BitmapIndexScan;
+pub use physical_block_sample::BlockSample;
+pub use physical_bloom_build::BloomBuild;
+pub use physical_bloom_lookup::BloomLookup;
 pub use physical_cache_scan::CacheScan;
+pub use physical_classify::Classify;
+pub use physical_cluster_sort::ClusterSort;
 pub use physical_column_mutation::ColumnMutation;
 pub use physical_commit_sink::*;
+pub use physical_compact::Compact;
 pub use physical_compact_source::CompactSource;
+pub use physical_conditional_limit::ConditionalLimit;
 pub use physical_constant_table_scan::ConstantTableScan;
+pub use physical_convert_timezone::ConvertTimezone;
+pub use physical_convert_timezone::TzDirection;
 pub use physical_copy_into_location::CopyIntoLocation;
 pub use physical_copy_into_table::*;
+pub use physical_correlation::Correlation;
+pub use physical_cte_materialization::CteMaterialization;
 pub use physical_distributed_insert_select::DistributedInsertSelect;
+pub use physical_emit::Emit;
+pub use physical_enforce_schema::CastMode;
+pub use physical_enforce_schema::EnforceSchema;
 pub use physical_eval_scalar::EvalScalar;
 pub use physical_exchange::Exchange;
 pub use physical_exchange_sink::ExchangeSink;
 pub use physical_exchange_source::ExchangeSource;
 pub use physical_expression_scan::ExpressionScan;
 pub use physical_filter::Filter;
+pub use physical_flat_map::FlatMap;
+pub use physical_function_import::FunctionImport;
+pub use physical_fuzzy_match::DistanceMetric;
+pub use physical_fuzzy_match::FuzzyMatch;
+pub use physical_geo_scan::GeoScan;
+pub use physical_grouping_id::GroupingId;
 pub use physical_hash_join::HashJoin;
+pub use physical_histogram::Histogram;
 pub use physical_join::PhysicalJoinType;
+pub use physical_json_each::JsonEach;
+pub use physical_json_extract::JsonExtract;
+pub use physical_json_table::JsonTable;
 pub use physical_limit::Limit;
+pub use physical_mask_apply::MaskApply;
+pub use physical_materialize_agg::MaterializeAgg;
+pub use physical_materialize_agg::PreAggMeta;
 pub use physical_multi_table_insert::*;
 pub use physical_mutation::*;
 pub use physical_mutation_into_organize::MutationOrganize;
 pub use physical_mutation_into_split::MutationSplit;
 pub use physical_mutation_manipulate::MutationManipulate;
 pub use physical_mutation_source::*;
+pub use physical_mv_refresh_partial::MvRefreshPartial;
+pub use physical_mv_refresh_partial::PartitionId;
+pub use physical_prewarm_cache::PrewarmCache;
 pub use physical_project_set::ProjectSet;
+pub use physical_qualify::Qualify;
 pub use physical_r_cte_scan::RecursiveCteScan;
 pub use physical_range_join::*;
 pub use physical_recluster::HilbertPartition;
@@ -96,11 +179,30 @@ pub use physical_refresh_index::RefreshIndex;
 pub use physical_replace_async_source::ReplaceAsyncSourcer;
 pub use physical_replace_deduplicate::*;
 pub use physical_replace_into::ReplaceInto;
+pub use physical_replicate::Replicate;
+pub use physical_result_cache_scan::ResultCacheScan;
+pub use physical_row_access_policy::RowAccessPolicy;
 pub use physical_row_fetch::RowFetch;
+pub use physical_schema_evolve::SchemaEvolve;
+pub use physical_semi_hash_join::SemiHashJoin;
+pub use physical_sequence_next::SequenceNext;
+pub use physical_skew_detection::SkewDetection;
 pub use physical_sort::Sort;
+pub use physical_sort_merge_aggregate::SortMergeAggregate;
+pub use physical_sort_merge_join::*;
+pub use physical_sorted_merge::SortedMerge;
+pub use physical_spill_sort::SpillSort;
+pub use physical_stream_output::StreamOutput;
 pub use physical_table_scan::TableScan;
+pub use physical_tdigest_agg::TDigestAgg;
+pub use physical_time_window::SessionWindow;
+pub use physical_time_window::TumblingWindow;
+pub use physical_transpose::Transpose;
 pub use physical_udf::Udf;
 pub use physical_udf::UdfFunctionDesc;
 pub use physical_union_all::UnionAll;
+pub use physical_watermark::Watermark;
 pub use physical_window::*;
 pub use physical_window_partition::*;
+pub use physical_write_ahead_log::WriteAheadLog;
+pub use physical_zip::Zip;
@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+
+/// Pass through the rows of `input`, and fail the query with `error_message`
+/// if `input` produces no rows.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AbortIfEmpty {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub error_message: String,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl AbortIfEmpty {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Wrap the physical plan of a query with an `AbortIfEmpty`.
+    pub fn build_abort_if_empty(
+        &self,
+        input: PhysicalPlan,
+        error_message: String,
+    ) -> Result<PhysicalPlan> {
+        let mut plan = PhysicalPlan::AbortIfEmpty(Box::new(AbortIfEmpty {
+            plan_id: 0,
+            input: Box::new(input),
+            error_message,
+            stat_info: None,
+        }));
+        plan.adjust_plan_id(&mut 0);
+        Ok(plan)
+    }
+}
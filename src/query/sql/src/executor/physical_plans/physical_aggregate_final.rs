@@ -21,6 +21,10 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::RemoteExpr;
 
+use super::physical_correlation::correlation_argument;
+use super::physical_histogram::histogram_argument;
+use super::physical_sort_merge_aggregate::is_sorted_by_group;
+use super::physical_tdigest_agg::tdigest_argument;
 use super::SortDesc;
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::physical_plans::AggregateExpand;
@@ -28,6 +32,8 @@ use crate::executor::physical_plans::AggregateFunctionDesc;
 use crate::executor::physical_plans::AggregateFunctionSignature;
 use crate::executor::physical_plans::AggregatePartial;
 use crate::executor::physical_plans::Exchange;
+use crate::executor::physical_plans::GroupingId;
+use crate::executor::physical_plans::SortMergeAggregate;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::SExpr;
@@ -93,11 +99,39 @@ impl PhysicalPlanBuilder {
             required.insert(i.index);
         });
 
+        // The `GROUPING_ID` columns are computed after the final aggregation.
+        let grouping_ids = match &agg.grouping_sets {
+            Some(grouping_sets) => grouping_sets
+                .grouping_ids
+                .iter()
+                .filter(|(_, index)| required.remove(index))
+                .cloned()
+                .collect::<Vec<_>>(),
+            None => vec![],
+        };
+
         if agg.group_items.is_empty() && used.is_empty() {
             let expr = SExpr::create_leaf(Arc::new(DummyTableScan.into()));
             return self.build(&expr, required).await;
         }
 
+        if self.streaming_histogram && agg.mode == AggregateMode::Final {
+            if let Some((column, num_buckets)) = histogram_argument(agg) {
+                return self
+                    .build_histogram(s_expr, column, num_buckets, stat_info)
+                    .await;
+            }
+        }
+
+        if agg.mode == AggregateMode::Final {
+            if let Some(columns) = correlation_argument(agg) {
+                return self.build_correlation(s_expr, columns, stat_info).await;
+            }
+            if let Some(argument) = tdigest_argument(agg) {
+                return self.build_tdigest_agg(s_expr, argument, stat_info).await;
+            }
+        }
+
         let agg = crate::plans::Aggregate {
             group_items: agg.group_items.clone(),
             aggregate_functions: used,
@@ -107,12 +141,21 @@ impl PhysicalPlanBuilder {
             grouping_sets: agg.grouping_sets.clone(),
         };
 
+        if agg.mode == AggregateMode::Final {
+            if let Some(pre_agg_meta) = self.pre_agg_argument(s_expr, &agg) {
+                return self
+                    .build_materialize_agg(s_expr, &agg, pre_agg_meta, stat_info)
+                    .await;
+            }
+        }
+
         // 2. Build physical plan.
         let input = self.build(s_expr.child(0)?, required).await?;
         let input_schema = input.output_schema()?;
         let group_items = agg.group_items.iter().map(|v| v.index).collect::<Vec<_>>();
+        let grouping_id_stat_info = stat_info.clone();
 
-        let result = match &agg.mode {
+        let mut result = match &agg.mode {
             AggregateMode::Partial => {
                 let group_by_display = agg
                     .group_items
@@ -230,6 +273,8 @@ impl PhysicalPlanBuilder {
                 let group_by_shuffle_mode = settings.get_group_by_shuffle_mode()?;
                 let enable_experimental_aggregate_hashtable =
                     settings.get_enable_experimental_aggregate_hashtable()?;
+                let early_termination_threshold =
+                    settings.get_agg_partial_early_termination_ratio()?;
 
                 if let Some(grouping_sets) = agg.grouping_sets.as_ref() {
                     assert_eq!(grouping_sets.dup_group_items.len(), group_items.len() - 1); // ignore `_grouping_id`.
@@ -282,6 +327,7 @@ impl PhysicalPlanBuilder {
                                 group_by: group_items,
                                 stat_info: Some(stat_info),
                                 rank_limit: None,
+                                early_termination_threshold,
                             }
                         } else {
                             AggregatePartial {
@@ -293,6 +339,7 @@ impl PhysicalPlanBuilder {
                                 group_by: group_items,
                                 stat_info: Some(stat_info),
                                 rank_limit,
+                                early_termination_threshold,
                             }
                         };
 
@@ -314,6 +361,7 @@ impl PhysicalPlanBuilder {
                             plan_id: 0,
                             kind,
                             allow_adjust_parallelism: true,
+                            consistent_hash: false,
                             ignore_exchange: false,
                             input: Box::new(PhysicalPlan::AggregatePartial(aggregate_partial)),
                             keys,
@@ -337,6 +385,7 @@ impl PhysicalPlanBuilder {
                                 input: Box::new(PhysicalPlan::AggregateExpand(expand)),
                                 stat_info: Some(stat_info),
                                 rank_limit: None,
+                                early_termination_threshold,
                             })
                         } else {
                             PhysicalPlan::AggregatePartial(AggregatePartial {
@@ -348,6 +397,7 @@ impl PhysicalPlanBuilder {
                                 input: Box::new(input),
                                 stat_info: Some(stat_info),
                                 rank_limit,
+                                early_termination_threshold,
                             })
                         }
                     }
@@ -492,6 +542,24 @@ impl PhysicalPlanBuilder {
                 }
 
                 match input {
+                    // The rows of a group are adjacent in the sorted input, they are
+                    // aggregated in one pass without the partial aggregation.
+                    PhysicalPlan::AggregatePartial(partial)
+                        if agg.grouping_sets.is_none()
+                            && partial.rank_limit.is_none()
+                            && is_sorted_by_group(&partial.input, &group_items) =>
+                    {
+                        PhysicalPlan::SortMergeAggregate(Box::new(SortMergeAggregate {
+                            plan_id: 0,
+                            input: partial.input,
+                            group_by: group_items,
+                            agg_funcs,
+                            group_by_display: partial.group_by_display,
+
+                            stat_info: Some(stat_info),
+                        }))
+                    }
+
                     PhysicalPlan::AggregatePartial(ref partial) => {
                         let before_group_by_schema = partial.input.output_schema()?;
 
@@ -538,6 +606,21 @@ impl PhysicalPlanBuilder {
             }
         };
 
+        if agg.mode == AggregateMode::Final {
+            if let Some(grouping_sets) = &agg.grouping_sets {
+                for (grouping_columns, output_col) in grouping_ids {
+                    result = PhysicalPlan::GroupingId(GroupingId {
+                        plan_id: 0,
+                        input: Box::new(result),
+                        grouping_columns,
+                        grouping_id_index: grouping_sets.grouping_id_index,
+                        output_col,
+                        stat_info: Some(grouping_id_stat_info.clone()),
+                    });
+                }
+            }
+        }
+
         Ok(result)
     }
 }
@@ -38,6 +38,9 @@ pub struct AggregatePartial {
 
     // Order by keys if keys are subset of group by key, then we can use rank to filter data in previous
     pub rank_limit: Option<(Vec<SortDesc>, usize)>,
+    // Ratio of distinct groups to rows seen so far, the partial aggregator stops accumulating
+    // and passes rows through once it is reached, 0 means disabled.
+    pub early_termination_threshold: f64,
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
 }
@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::BaseTableColumn;
+use crate::ColumnBinding;
+use crate::ColumnEntry;
+
+/// Run `inner` in a background task and write its result into the query result cache
+/// under `result_cache_key`. The plan outputs the query id of the background task at once,
+/// the result is read by `RESULT_SCAN(query_id)` when the task finishes.
+///
+/// `inner` is built into its own pipeline, so it is not a child of this plan.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AsyncAggregate {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub inner: Box<PhysicalPlan>,
+    // The output columns of the query.
+    pub result_columns: Vec<ColumnBinding>,
+    pub result_cache_key: String,
+    // The `(table id, column id)` of the table columns read by the query.
+    pub result_cache_columns_used: Vec<(u64, u32)>,
+    // The url the query id is POSTed to when the task finishes.
+    pub notify_webhook: Option<String>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl AsyncAggregate {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(DataSchemaRefExt::create(vec![DataField::new(
+            "query_id",
+            DataType::String,
+        )]))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Build the physical plan of the query `s_expr` to be run in the background.
+    pub async fn build_async(
+        &mut self,
+        s_expr: &SExpr,
+        required: ColumnSet,
+        result_columns: Vec<ColumnBinding>,
+        result_cache_key: String,
+    ) -> Result<PhysicalPlan> {
+        let inner = self.build(s_expr, required).await?;
+        let used_columns = s_expr.derive_relational_prop()?.used_columns.clone();
+        let mut result_cache_columns_used = {
+            let metadata = self.metadata.read();
+            used_columns
+                .iter()
+                .filter_map(|index| match metadata.column(*index) {
+                    ColumnEntry::BaseTableColumn(BaseTableColumn {
+                        table_index,
+                        column_id: Some(column_id),
+                        ..
+                    }) => Some((metadata.table(*table_index).table().get_id(), *column_id)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        result_cache_columns_used.sort();
+        result_cache_columns_used.dedup();
+        let notify_webhook = self
+            .ctx
+            .get_settings()
+            .get_async_aggregate_notify_webhook()?;
+
+        let mut plan = PhysicalPlan::AsyncAggregate(Box::new(AsyncAggregate {
+            plan_id: 0,
+            inner: Box::new(inner),
+            result_columns,
+            result_cache_key,
+            result_cache_columns_used,
+            notify_webhook: (!notify_webhook.is_empty()).then_some(notify_webhook),
+            stat_info: None,
+        }));
+        plan.adjust_plan_id(&mut 0);
+        Ok(plan)
+    }
+}
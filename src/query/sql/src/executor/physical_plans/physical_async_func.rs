@@ -20,10 +20,12 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 
 use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::SequenceNext;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::SExpr;
 use crate::plans::AsyncFunctionArgument;
+use crate::plans::AsyncFunctionCall;
 use crate::ColumnSet;
 use crate::IndexType;
 use crate::ScalarExpr;
@@ -84,6 +86,19 @@ impl PhysicalPlanBuilder {
             return self.build(s_expr.child(0)?, required).await;
         }
         let input = self.build(s_expr.child(0)?, required).await?;
+
+        // `nextval` is evaluated by `SequenceNext` to fetch the values in batches.
+        let mut sequences = vec![];
+        used.retain(|item| match &item.scalar {
+            ScalarExpr::AsyncFunctionCall(AsyncFunctionCall {
+                func_arg: AsyncFunctionArgument::SequenceFunction(sequence_name),
+                ..
+            }) => {
+                sequences.push((sequence_name.clone(), item.index));
+                false
+            }
+            _ => true,
+        });
         let input_schema = input.output_schema()?;
 
         let async_func_descs = used
@@ -128,11 +143,30 @@ impl PhysicalPlanBuilder {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        Ok(PhysicalPlan::AsyncFunction(AsyncFunction {
-            plan_id: 0,
-            input: Box::new(input),
-            async_func_descs,
-            stat_info: Some(stat_info),
-        }))
+        let mut plan = if async_func_descs.is_empty() {
+            input
+        } else {
+            PhysicalPlan::AsyncFunction(AsyncFunction {
+                plan_id: 0,
+                input: Box::new(input),
+                async_func_descs,
+                stat_info: Some(stat_info.clone()),
+            })
+        };
+
+        if !sequences.is_empty() {
+            let batch_size = self.ctx.get_settings().get_sequence_next_batch_size()? as usize;
+            for (sequence_name, output_col) in sequences {
+                plan = PhysicalPlan::SequenceNext(Box::new(SequenceNext {
+                    plan_id: 0,
+                    input: Box::new(plan),
+                    sequence_name,
+                    batch_size,
+                    output_col,
+                    stat_info: Some(stat_info.clone()),
+                }));
+            }
+        }
+        Ok(plan)
     }
 }
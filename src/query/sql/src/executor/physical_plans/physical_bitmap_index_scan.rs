@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Scalar;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::plans::ScalarExpr;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::IndexType;
+
+/// Read the blocks of the table scan `input` holding a row with `indexed_column` equal to
+/// any of `predicate_values`. The bitmaps of the values are looked up in the bitmap index
+/// of the column and ORed, the blocks without any matching row are skipped. All the blocks
+/// are read if the column has no bitmap index.
+///
+/// The rows of the blocks read are evaluated by the `Filter` above.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct BitmapIndexScan {
+    /// A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub table_index: IndexType,
+    pub indexed_column: IndexType,
+    pub predicate_values: Vec<Scalar>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl BitmapIndexScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Wrap the table scan `input` of a `Filter` with a `BitmapIndexScan` if one of the
+    /// `predicates` is an equality between a bitmap indexed column of the table and a
+    /// constant, or a disjunction of such equalities on the same column, e.g. `IN` lists.
+    pub(crate) fn build_bitmap_index_scan(
+        &self,
+        input: PhysicalPlan,
+        predicates: &[ScalarExpr],
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let table_index = match &input {
+            PhysicalPlan::TableScan(scan) => scan.table_index,
+            _ => None,
+        };
+        let Some(table_index) = table_index else {
+            return Ok(input);
+        };
+
+        for predicate in predicates {
+            let mut values = vec![];
+            let Some(column) = equality_values(predicate, &mut values) else {
+                continue;
+            };
+            let metadata = self.metadata.read();
+            let column_id = match metadata.column(column) {
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    table_index: index,
+                    column_id: Some(column_id),
+                    path_indices: None,
+                    virtual_expr: None,
+                    ..
+                }) if *index == table_index => *column_id,
+                _ => continue,
+            };
+            let table = metadata.table(table_index).table();
+            if table.bitmap_index(column_id).is_none() {
+                continue;
+            }
+            values.dedup();
+            drop(metadata);
+
+            return Ok(PhysicalPlan::BitmapIndexScan(Box::new(BitmapIndexScan {
+                plan_id: 0,
+                input: Box::new(input),
+                table_index,
+                indexed_column: column,
+                predicate_values: values,
+                stat_info: Some(stat_info),
+            })));
+        }
+        Ok(input)
+    }
+}
+
+/// Push the constants of the equalities in `predicate` to `values`, if it's an equality
+/// between a column and a constant, or a disjunction of such equalities on the same column.
+/// Returns the column.
+fn equality_values(predicate: &ScalarExpr, values: &mut Vec<Scalar>) -> Option<IndexType> {
+    let ScalarExpr::FunctionCall(func) = predicate else {
+        return None;
+    };
+    match (func.func_name.as_str(), func.arguments.as_slice()) {
+        ("eq", [ScalarExpr::BoundColumnRef(column), ScalarExpr::ConstantExpr(c)])
+        | ("eq", [ScalarExpr::ConstantExpr(c), ScalarExpr::BoundColumnRef(column)])
+            if c.value != Scalar::Null =>
+        {
+            values.push(c.value.clone());
+            Some(column.column.index)
+        }
+        ("or", [left, right]) => {
+            let column = equality_values(left, values)?;
+            (equality_values(right, values)? == column).then_some(column)
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::SampleConfig;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+
+/// Sample the blocks of the table scan in `input` for `TABLESAMPLE SYSTEM`.
+///
+/// Each block is kept or skipped as a whole by a hash of the block and `seed`, so the
+/// skipped blocks are never read, and the same seed keeps the same blocks. The blocks are
+/// sampled by the pruners of the table scan with the push downs.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct BlockSample {
+    /// A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    /// The probability in `[0, 1]` to keep a block.
+    pub probability: f64,
+    pub seed: u64,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl BlockSample {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Wrap the table scan `input` with a `BlockSample` if the table is sampled by
+    /// `TABLESAMPLE SYSTEM`.
+    pub(crate) fn build_block_sample(
+        &self,
+        input: PhysicalPlan,
+        sample: Option<&SampleConfig>,
+        stat_info: PlanStatsInfo,
+    ) -> PhysicalPlan {
+        let Some(system) = sample.and_then(|sample| sample.system.as_ref()) else {
+            return input;
+        };
+        PhysicalPlan::BlockSample(Box::new(BlockSample {
+            plan_id: 0,
+            input: Box::new(input),
+            probability: system.probability / 100.0,
+            // The seed is fixed when the table is bound.
+            seed: system.seed.unwrap_or_default(),
+            stat_info: Some(stat_info),
+        }))
+    }
+}
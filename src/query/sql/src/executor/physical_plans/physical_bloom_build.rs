@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::TableField;
+use databend_common_meta_app::schema::TableInfo;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::ColumnBinding;
+use crate::IndexType;
+
+/// Build the bloom filters of the bloom-index columns over all the rows written by an insert,
+/// and write them to a sidecar file of the new segment. The input is passed through unchanged.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct BloomBuild {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub table_info: TableInfo,
+    // The offsets of the bloom-index columns in the input schema.
+    pub bloom_columns: Vec<IndexType>,
+    // The table field of each column in `bloom_columns`.
+    pub bloom_fields: Vec<TableField>,
+    pub segment_location: String,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl BloomBuild {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Put a `BloomBuild` on the select plan of an `INSERT INTO ... SELECT`, the select
+    /// columns are bound to `insert_schema` by position. The bloom fields that are not
+    /// inserted are skipped, and `input` is returned as is if none is left.
+    pub fn build_bloom_build(
+        &self,
+        input: PhysicalPlan,
+        table_info: TableInfo,
+        insert_schema: &DataSchemaRef,
+        select_column_bindings: &[ColumnBinding],
+        bloom_fields: Vec<TableField>,
+        segment_location: String,
+    ) -> Result<PhysicalPlan> {
+        let input_schema = input.output_schema()?;
+        let mut columns = Vec::with_capacity(bloom_fields.len());
+        let mut fields = Vec::with_capacity(bloom_fields.len());
+        for field in bloom_fields {
+            let Ok(position) = insert_schema.index_of(field.name()) else {
+                continue;
+            };
+            let Some(binding) = select_column_bindings.get(position) else {
+                continue;
+            };
+            columns.push(input_schema.index_of(&binding.index.to_string())?);
+            fields.push(field);
+        }
+
+        if columns.is_empty() {
+            return Ok(input);
+        }
+
+        Ok(PhysicalPlan::BloomBuild(Box::new(BloomBuild {
+            plan_id: 0,
+            input: Box::new(input),
+            table_info,
+            bloom_columns: columns,
+            bloom_fields: fields,
+            segment_location,
+            stat_info: None,
+        })))
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Scalar;
+use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::plans::ScalarExpr;
+use crate::BaseTableColumn;
+use crate::BloomIndexColumns;
+use crate::ColumnEntry;
+use crate::IndexType;
+
+/// Read the segments of the fuse table scan `inner_scan` which may contain the rows with
+/// `lookup_column = lookup_value`. The segments whose blocks all test negative in the bloom
+/// filters of the column are skipped entirely, the rows of the others are evaluated by the
+/// `Filter` above.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct BloomLookup {
+    /// A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub table_index: IndexType,
+    pub lookup_column: IndexType,
+    pub lookup_value: Scalar,
+    pub inner_scan: Box<PhysicalPlan>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl BloomLookup {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.inner_scan.output_schema()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Wrap the table scan `input` of a `Filter` with a `BloomLookup` if the only predicate
+    /// is an equality between a bloom indexed column of a fuse table and a constant.
+    pub(crate) fn build_bloom_lookup(
+        &self,
+        input: PhysicalPlan,
+        predicates: &[ScalarExpr],
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let table_index = match &input {
+            PhysicalPlan::TableScan(scan) => scan.table_index,
+            _ => None,
+        };
+        let (Some(table_index), [ScalarExpr::FunctionCall(func)]) = (table_index, predicates)
+        else {
+            return Ok(input);
+        };
+        let (column, value) = match (
+            func.func_name.as_str(),
+            func.arguments.first(),
+            func.arguments.get(1),
+        ) {
+            ("eq", Some(ScalarExpr::BoundColumnRef(column)), Some(ScalarExpr::ConstantExpr(c)))
+            | ("eq", Some(ScalarExpr::ConstantExpr(c)), Some(ScalarExpr::BoundColumnRef(column)))
+                if func.arguments.len() == 2 && c.value != Scalar::Null =>
+            {
+                (column, c.value.clone())
+            }
+            _ => return Ok(input),
+        };
+
+        let metadata = self.metadata.read();
+        let column_name = match metadata.column(column.column.index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                table_index: index,
+                column_name,
+                path_indices: None,
+                virtual_expr: None,
+                ..
+            }) if *index == table_index => column_name.clone(),
+            _ => return Ok(input),
+        };
+        let table = metadata.table(table_index).table();
+        if table.engine() != "FUSE" {
+            return Ok(input);
+        }
+        let bloom_index_cols = table
+            .options()
+            .get(OPT_KEY_BLOOM_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::All);
+        let indexed = match bloom_index_cols {
+            // The same types as the bloom filters support.
+            BloomIndexColumns::All => matches!(
+                column.column.data_type.remove_nullable(),
+                DataType::Number(_) | DataType::String | DataType::Timestamp | DataType::Date
+            ),
+            BloomIndexColumns::Specify(cols) => cols.contains(&column_name),
+            BloomIndexColumns::None => false,
+        };
+        if !indexed {
+            return Ok(input);
+        }
+
+        Ok(PhysicalPlan::BloomLookup(Box::new(BloomLookup {
+            plan_id: 0,
+            table_index,
+            lookup_column: column.column.index,
+            lookup_value: value,
+            inner_scan: Box::new(input),
+            stat_info: Some(stat_info),
+        })))
+    }
+}
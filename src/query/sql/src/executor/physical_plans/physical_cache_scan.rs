@@ -52,6 +52,18 @@ impl PhysicalPlanBuilder {
                 new_scan.schema.fields().clone(),
             )
         };
+        if let CacheSource::Cte((cte_id, column_indexes)) = &cache_source {
+            let output_schema = DataSchemaRefExt::create(fields);
+            return self
+                .build_cte_materialization(*cte_id, column_indexes, output_schema)
+                .await;
+        }
+        if let CacheSource::FlightSql((import_id, column_indexes)) = &cache_source {
+            let output_schema = DataSchemaRefExt::create(fields);
+            return self
+                .build_function_import(*import_id, column_indexes, output_schema)
+                .await;
+        }
         // 2. Build physical plan.
         Ok(PhysicalPlan::CacheScan(CacheScan {
             plan_id: 0,
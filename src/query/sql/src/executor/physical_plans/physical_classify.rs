@@ -0,0 +1,51 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::IndexType;
+
+/// Run the ONNX model at `model_location` of a stage on the `input_cols` of each block, and
+/// append the `output_cols` of the predictions. The model is loaded once and shared by the
+/// parallel transforms of the plan, the rows are fed to it at most `batch_size` at a time.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Classify {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub model_location: String,
+    pub input_cols: Vec<IndexType>,
+    pub output_cols: Vec<(IndexType, DataType)>,
+    pub batch_size: usize,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Classify {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        for (index, data_type) in self.output_cols.iter() {
+            fields.push(DataField::new(&index.to_string(), data_type.clone()));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
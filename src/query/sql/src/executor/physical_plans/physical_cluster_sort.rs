@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Expr;
+use databend_storages_common_table_meta::table::ClusterType;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::common::SortDesc;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::parse_cluster_keys;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::IndexType;
+
+/// Sort the data read from a table which is linear clustered by the sort keys.
+///
+/// The blocks of such a table are sorted by the cluster keys when they are written,
+/// so if `is_already_sorted`, the blocks only need to be merged, not sorted again.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ClusterSort {
+    /// A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub order_by: Vec<SortDesc>,
+    /// limit = Limit.limit + Limit.offset
+    pub limit: Option<usize>,
+    pub is_already_sorted: bool,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl ClusterSort {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Check if the blocks produced by `input` are already sorted by `order_by`,
+    /// that is, the input reads a linear clustered table without changing the order of rows,
+    /// and `order_by` is a prefix of the cluster keys in ascending order with nulls last.
+    pub(crate) fn is_sorted_by_cluster_keys(
+        &self,
+        input: &PhysicalPlan,
+        order_by: &[SortDesc],
+    ) -> Result<bool> {
+        let Some(table_index) = order_preserving_scan(input) else {
+            return Ok(false);
+        };
+        if order_by.is_empty() {
+            return Ok(false);
+        }
+
+        let mut columns = Vec::with_capacity(order_by.len());
+        for desc in order_by {
+            if !desc.asc || desc.nulls_first {
+                return Ok(false);
+            }
+            match self.metadata.read().column(desc.order_by) {
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    table_index: index,
+                    column_name,
+                    path_indices: None,
+                    virtual_expr: None,
+                    ..
+                }) if *index == table_index => columns.push(column_name.clone()),
+                _ => return Ok(false),
+            }
+        }
+
+        let table = self.metadata.read().table(table_index).table();
+        if table.cluster_type() != Some(ClusterType::Linear) {
+            return Ok(false);
+        }
+        let Some(ast_exprs) = table.resolve_cluster_keys(self.ctx.clone()) else {
+            return Ok(false);
+        };
+        let cluster_keys = parse_cluster_keys(self.ctx.clone(), table.clone(), ast_exprs)?;
+        if cluster_keys.len() < columns.len() {
+            return Ok(false);
+        }
+
+        let schema = table.schema();
+        Ok(columns.iter().zip(cluster_keys.iter()).all(|(name, key)| {
+            matches!(key, Expr::ColumnRef { id, .. } if schema.field(*id).name() == name)
+        }))
+    }
+}
+
+/// Returns the table index of the scan if the plan only consists of operators which
+/// keep the order of rows in each block on top of a table scan.
+fn order_preserving_scan(plan: &PhysicalPlan) -> Option<IndexType> {
+    match plan {
+        PhysicalPlan::TableScan(scan) => scan.table_index,
+        PhysicalPlan::Filter(plan) => order_preserving_scan(&plan.input),
+        PhysicalPlan::Qualify(plan) => order_preserving_scan(&plan.input),
+        PhysicalPlan::EvalScalar(plan) => order_preserving_scan(&plan.input),
+        _ => None,
+    }
+}
@@ -36,6 +36,11 @@ pub struct CommitSink {
     pub update_stream_meta: Vec<UpdateStreamMetaReq>,
     pub deduplicated_label: Option<String>,
     pub table_meta_timestamps: TableMetaTimestamps,
+    /// The maximum number of retries of a commit which conflicts with a concurrent
+    /// transaction, 0 to retry until the retry timeout.
+    pub write_conflict_retry_count: u32,
+    /// The delay before the first retry of a conflicting commit, doubled on each retry.
+    pub write_conflict_backoff_ms: u64,
 
     // Used for recluster.
     pub recluster_info: Option<ReclusterInfoSideCar>,
@@ -0,0 +1,156 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_catalog::plan::PartInfoType;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::CommitSink;
+use crate::executor::physical_plans::CommitType;
+use crate::executor::physical_plans::CompactSource;
+use crate::executor::physical_plans::Exchange;
+use crate::executor::physical_plans::FragmentKind;
+use crate::executor::physical_plans::MutationKind;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::IndexType;
+
+/// Merge the small blocks read by the `CompactSource` in `input` into blocks of
+/// `target_block_size_rows` rows, and group them into segments of about `target_segment_size_mb`.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Compact {
+    /// A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub table_index: IndexType,
+    pub target_segment_size_mb: u64,
+    pub target_block_size_rows: u64,
+    /// The max number of compact tasks running in parallel.
+    pub max_tasks: usize,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Compact {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub async fn build_compact(
+        &mut self,
+        compact_block: &crate::plans::OptimizeCompactBlock,
+    ) -> Result<PhysicalPlan> {
+        let crate::plans::OptimizeCompactBlock {
+            catalog: catalog_name,
+            database,
+            table,
+            limit,
+        } = compact_block;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let tbl = catalog.get_table(&tenant, database, table).await?;
+        // check mutability
+        tbl.check_mutable()?;
+
+        let table_info = tbl.get_table_info().clone();
+
+        let Some((parts, snapshot)) = tbl.compact_blocks(self.ctx.clone(), limit.clone()).await?
+        else {
+            return Err(ErrorCode::NoNeedToCompact(format!(
+                "No need to do compact for '{database}'.'{table}'"
+            )));
+        };
+
+        let table_meta_timestamps = self
+            .ctx
+            .get_table_meta_timestamps(tbl.as_ref(), Some(snapshot.clone()))?;
+
+        let thresholds = tbl.get_block_thresholds();
+        let target_segment_size_mb = (thresholds.block_per_segment
+            * thresholds.max_compressed_per_block)
+            .div_ceil(1024 * 1024) as u64;
+        let max_tasks = self.ctx.get_settings().get_max_threads()? as usize;
+        let table_index = self.metadata.write().add_table(
+            catalog_name.clone(),
+            database.clone(),
+            tbl.clone(),
+            None,
+            false,
+            false,
+            false,
+            None,
+        );
+
+        let merge_meta = parts.partitions_type() == PartInfoType::LazyLevel;
+        let mut root = PhysicalPlan::CompactSource(Box::new(CompactSource {
+            parts,
+            table_info: table_info.clone(),
+            column_ids: snapshot.schema.to_leaf_column_id_set(),
+            plan_id: u32::MAX,
+            table_meta_timestamps,
+        }));
+        root = PhysicalPlan::Compact(Box::new(Compact {
+            plan_id: u32::MAX,
+            input: Box::new(root),
+            table_index,
+            target_segment_size_mb,
+            target_block_size_rows: thresholds.max_rows_per_block as u64,
+            max_tasks,
+            stat_info: Some(PlanStatsInfo {
+                estimated_rows: snapshot.summary.row_count as f64,
+            }),
+        }));
+
+        let is_distributed = (!self.ctx.get_cluster().is_empty())
+            && self.ctx.get_settings().get_enable_distributed_compact()?;
+        if is_distributed {
+            root = PhysicalPlan::Exchange(Exchange {
+                plan_id: 0,
+                input: Box::new(root),
+                kind: FragmentKind::Merge,
+                keys: vec![],
+                allow_adjust_parallelism: true,
+                consistent_hash: false,
+                ignore_exchange: false,
+            });
+        }
+
+        root = PhysicalPlan::CommitSink(Box::new(CommitSink {
+            input: Box::new(root),
+            table_info,
+            snapshot: Some(snapshot),
+            commit_type: CommitType::Mutation {
+                kind: MutationKind::Compact,
+                merge_meta,
+            },
+            update_stream_meta: vec![],
+            deduplicated_label: None,
+            write_conflict_retry_count: self.ctx.get_settings().get_write_conflict_retry_count()?,
+            write_conflict_backoff_ms: self.ctx.get_settings().get_write_conflict_backoff_ms()?,
+            plan_id: u32::MAX,
+            recluster_info: None,
+            table_meta_timestamps,
+        }));
+
+        root.adjust_plan_id(&mut 0);
+        Ok(root)
+    }
+}
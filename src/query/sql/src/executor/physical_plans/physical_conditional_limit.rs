@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::Filter;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+
+/// Output the rows satisfying `condition` until `limit` of them are collected, then stop
+/// reading the input, so the remaining groups of a final aggregation are not produced
+/// once enough of them pass the `HAVING` filter.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ConditionalLimit {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub limit: usize,
+    pub condition: RemoteExpr,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl ConditionalLimit {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Insert a `ConditionalLimit` between the `HAVING` filter and the final aggregation
+    /// below a `LIMIT`, the filter is kept to project the output columns.
+    pub(crate) fn build_conditional_limit(
+        &self,
+        input: PhysicalPlan,
+        limit: &crate::plans::Limit,
+    ) -> Result<PhysicalPlan> {
+        let Some(n) = limit.limit else {
+            return Ok(input);
+        };
+        match input {
+            PhysicalPlan::Filter(filter)
+                if matches!(
+                    filter.input.as_ref(),
+                    PhysicalPlan::AggregateFinal(_) | PhysicalPlan::SortMergeAggregate(_)
+                ) =>
+            {
+                let condition = filter
+                    .predicates
+                    .iter()
+                    .map(|predicate| predicate.as_expr(&BUILTIN_FUNCTIONS))
+                    .try_reduce(|lhs, rhs| {
+                        check_function(None, "and_filters", &[], &[lhs, rhs], &BUILTIN_FUNCTIONS)
+                    })?;
+                let Some(condition) = condition else {
+                    return Ok(PhysicalPlan::Filter(filter));
+                };
+                Ok(PhysicalPlan::Filter(Filter {
+                    input: Box::new(PhysicalPlan::ConditionalLimit(Box::new(ConditionalLimit {
+                        plan_id: 0,
+                        input: filter.input.clone(),
+                        limit: n + limit.offset,
+                        condition: condition.as_remote_expr(),
+                        stat_info: filter.stat_info.clone(),
+                    }))),
+                    ..filter
+                }))
+            }
+            PhysicalPlan::EvalScalar(mut eval_scalar)
+                if matches!(eval_scalar.input.as_ref(), PhysicalPlan::Filter(_)) =>
+            {
+                eval_scalar.input =
+                    Box::new(self.build_conditional_limit(*eval_scalar.input, limit)?);
+                Ok(PhysicalPlan::EvalScalar(eval_scalar))
+            }
+            _ => Ok(input),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::IndexType;
+
+/// The direction of a `ConvertTimezone`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TzDirection {
+    /// A UTC timestamp becomes the wall-clock time of the timezone.
+    ToLocal,
+    /// A wall-clock time of the timezone becomes a UTC timestamp.
+    ToUtc,
+}
+
+impl Display for TzDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TzDirection::ToLocal => write!(f, "to local"),
+            TzDirection::ToUtc => write!(f, "to utc"),
+        }
+    }
+}
+
+/// Convert the `TIMESTAMP` columns in `columns` between UTC and the wall-clock time of
+/// the timezone `tz`, the other columns are passed through unchanged.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ConvertTimezone {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub columns: Vec<IndexType>,
+    // The IANA name of the timezone, e.g. `America/New_York`.
+    pub tz: String,
+    pub direction: TzDirection,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl ConvertTimezone {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
@@ -12,16 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::CopyIntoLocationOptions;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_exception::Result;
+use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_storage::StageFilesInfo;
 
 use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
 use crate::ColumnBinding;
+use crate::IndexType;
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct CopyIntoLocation {
@@ -30,6 +36,8 @@ pub struct CopyIntoLocation {
     pub project_columns: Vec<ColumnBinding>,
     pub input_schema: DataSchemaRef,
     pub to_stage_info: StageTableInfo,
+    /// Offsets in `input_schema` of the columns naming the sub-directory of each file.
+    pub partition_by: Vec<IndexType>,
 }
 
 impl CopyIntoLocation {
@@ -41,3 +49,50 @@ impl CopyIntoLocation {
         ]))
     }
 }
+
+impl PhysicalPlanBuilder {
+    /// Build the plan of `COPY INTO @stage`, which serializes the output of `input` into
+    /// files under `path` of the stage. All blocks are written into one file if
+    /// `options.single`, otherwise a new file is started once `options.max_file_size` is reached.
+    /// With `options.partition_by`, the rows of each partition are written under
+    /// `path/<column>=<value>/...`.
+    pub fn build_copy_into_stage(
+        &self,
+        input: PhysicalPlan,
+        project_columns: Vec<ColumnBinding>,
+        input_schema: DataSchemaRef,
+        stage: &StageInfo,
+        path: &str,
+        options: &CopyIntoLocationOptions,
+    ) -> Result<PhysicalPlan> {
+        let table_schema = infer_table_schema(&input_schema)?;
+        let partition_by = options
+            .partition_by
+            .iter()
+            .map(|name| input_schema.index_of(name))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(PhysicalPlan::CopyIntoLocation(Box::new(CopyIntoLocation {
+            plan_id: 0,
+            input: Box::new(input),
+            project_columns,
+            input_schema,
+            partition_by,
+            to_stage_info: StageTableInfo {
+                schema: table_schema,
+                stage_info: stage.clone(),
+                files_info: StageFilesInfo {
+                    path: path.to_string(),
+                    files: None,
+                    pattern: None,
+                },
+                files_to_copy: None,
+                duplicated_files_detected: vec![],
+                is_select: false,
+                default_values: None,
+                copy_into_location_options: options.clone(),
+                copy_into_table_options: Default::default(),
+                stage_root: "".to_string(),
+            },
+        })))
+    }
+}
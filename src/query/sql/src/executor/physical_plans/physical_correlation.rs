@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::AggregateMode;
+use crate::plans::RelOperator;
+use crate::IndexType;
+use crate::ScalarExpr;
+
+/// Compute the Pearson correlation coefficient of `x_col` and `y_col` in a single pass
+/// over the input with Welford's online algorithm. It outputs one row.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Correlation {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub x_col: IndexType,
+    pub y_col: IndexType,
+    pub output_col: IndexType,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Correlation {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut data_type = DataType::Number(NumberDataType::Float64);
+        for col in [self.x_col, self.y_col] {
+            if input_schema
+                .field_with_name(&col.to_string())?
+                .data_type()
+                .is_nullable_or_null()
+            {
+                data_type = data_type.wrap_nullable();
+            }
+        }
+        Ok(DataSchemaRefExt::create(vec![DataField::new(
+            &self.output_col.to_string(),
+            data_type,
+        )]))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Build a `Correlation` for the final aggregate of `SELECT CORR(x, y) FROM ...`,
+    /// the partial aggregate below it (and the exchange between them) is replaced.
+    pub(crate) async fn build_correlation(
+        &mut self,
+        s_expr: &SExpr,
+        (x_col, y_col, output_col): (IndexType, IndexType, IndexType),
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let mut child = s_expr.child(0)?;
+        if let RelOperator::Exchange(_) = child.plan() {
+            child = child.child(0)?;
+        }
+        if !matches!(
+            child.plan(),
+            RelOperator::Aggregate(agg) if agg.mode == AggregateMode::Partial
+        ) {
+            return Err(ErrorCode::Internal(
+                "Correlation expects a partial aggregate as the input of the final aggregate",
+            ));
+        }
+
+        let input = self
+            .build(child.child(0)?, ColumnSet::from([x_col, y_col]))
+            .await?;
+
+        Ok(PhysicalPlan::Correlation(Correlation {
+            plan_id: 0,
+            input: Box::new(input),
+            x_col,
+            y_col,
+            output_col,
+            stat_info: Some(stat_info),
+        }))
+    }
+}
+
+/// Returns the argument columns and the output column if the aggregate only computes
+/// `corr(x, y)` without group by.
+pub(crate) fn correlation_argument(
+    agg: &crate::plans::Aggregate,
+) -> Option<(IndexType, IndexType, IndexType)> {
+    if !agg.group_items.is_empty() || agg.grouping_sets.is_some() {
+        return None;
+    }
+    let [item] = agg.aggregate_functions.as_slice() else {
+        return None;
+    };
+    let ScalarExpr::AggregateFunction(func) = &item.scalar else {
+        return None;
+    };
+    if !func.func_name.eq_ignore_ascii_case("corr") || func.distinct {
+        return None;
+    }
+    let [ScalarExpr::BoundColumnRef(x), ScalarExpr::BoundColumnRef(y)] = func.args.as_slice()
+    else {
+        return None;
+    };
+    Some((x.column.index, y.column.index, item.index))
+}
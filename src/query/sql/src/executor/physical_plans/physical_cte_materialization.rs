@@ -0,0 +1,134 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::RemoteExpr;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::EvalScalar;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+
+// The estimated size of a value of a variable-length type, such as String.
+pub(crate) const VARIABLE_VALUE_BYTES: usize = 16;
+
+/// Execute the body of a cte referenced more than once only once, and share the result
+/// blocks with all the consumers. Each consumer of the cte is a `CteMaterialization` with
+/// the same `cte_id`, only the first one built executes the body, the others just read the
+/// stored blocks.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CteMaterialization {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub cte_id: usize,
+    pub body: Box<PhysicalPlan>,
+    pub consumer_count: usize,
+    /// The offsets in the body output of the consumer columns.
+    pub column_indexes: Vec<usize>,
+    pub output_schema: DataSchemaRef,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl CteMaterialization {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.output_schema.clone())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Build a consumer of a shared cte. The body is materialized if
+    /// `estimated_rows * avg_row_bytes * (consumer_count - 1)` exceeds the setting
+    /// `cte_materialization_threshold`, otherwise it's inlined into the consumer.
+    pub(crate) async fn build_cte_materialization(
+        &mut self,
+        cte_id: usize,
+        column_indexes: &[usize],
+        output_schema: DataSchemaRef,
+    ) -> Result<PhysicalPlan> {
+        let cte = self.metadata.read().shared_cte(cte_id).clone();
+        let required: ColumnSet = cte.columns.iter().map(|column| column.index).collect();
+        let body = self.build(&cte.s_expr, required).await?;
+        let body_schema = body.output_schema()?;
+        let column_indexes = column_indexes
+            .iter()
+            .map(|idx| body_schema.index_of(&cte.columns[*idx].index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let stat_info = self.build_plan_stat_info(&cte.s_expr)?;
+        let avg_row_bytes: usize = body_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                field
+                    .data_type()
+                    .remove_nullable()
+                    .numeric_byte_size()
+                    .unwrap_or(VARIABLE_VALUE_BYTES)
+            })
+            .sum();
+        let materialized_bytes = stat_info.estimated_rows
+            * avg_row_bytes as f64
+            * cte.consumer_count.saturating_sub(1) as f64;
+        let threshold = self
+            .ctx
+            .get_settings()
+            .get_cte_materialization_threshold()?;
+
+        if threshold > 0 && materialized_bytes > threshold as f64 {
+            return Ok(PhysicalPlan::CteMaterialization(Box::new(
+                CteMaterialization {
+                    plan_id: 0,
+                    cte_id,
+                    body: Box::new(body),
+                    consumer_count: cte.consumer_count,
+                    column_indexes,
+                    output_schema,
+                    stat_info: Some(stat_info),
+                },
+            )));
+        }
+
+        // Inline the body, and rename its output columns to the columns of the consumer.
+        let body_columns = body_schema.num_fields();
+        let exprs = column_indexes
+            .iter()
+            .zip(output_schema.fields().iter())
+            .map(|(offset, field)| {
+                let body_field = body_schema.field(*offset);
+                let index = field.name().parse::<usize>()?;
+                Ok((
+                    RemoteExpr::ColumnRef {
+                        span: None,
+                        id: *offset,
+                        data_type: body_field.data_type().clone(),
+                        display_name: body_field.name().clone(),
+                    },
+                    index,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let projections = (body_columns..body_columns + exprs.len()).collect();
+        Ok(PhysicalPlan::EvalScalar(EvalScalar {
+            plan_id: 0,
+            projections,
+            input: Box::new(body),
+            exprs,
+            stat_info: Some(stat_info),
+        }))
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::RemoteExpr;
+use databend_common_meta_app::schema::TableInfo;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::ReplaceAsyncSourcer;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::plans::InsertInputSource;
+use crate::IndexType;
+
+/// Emit the `RETURNING` columns of an insert statement.
+///
+/// The rows from input are filled and reordered into the schema of the table, then the
+/// `returning_exprs` are evaluated on them before they are forwarded to the table writer.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Emit {
+    /// A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub table_info: TableInfo,
+    /// The schema of the rows from input.
+    pub insert_schema: DataSchemaRef,
+    pub returning_exprs: Vec<(RemoteExpr, IndexType)>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Emit {
+    /// The rows are forwarded to the table writer, in the order of the table schema.
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let schema = self.table_info.schema().remove_virtual_computed_fields();
+        Ok(Arc::new(DataSchema::from(&schema)))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub fn build_insert_returning(
+        &mut self,
+        insert: &crate::plans::Insert,
+        table_info: TableInfo,
+    ) -> Result<PhysicalPlan> {
+        let InsertInputSource::Values(source) = &insert.source else {
+            return Err(ErrorCode::Internal(
+                "RETURNING is only supported in INSERT ... VALUES statement",
+            ));
+        };
+
+        let input = PhysicalPlan::ReplaceAsyncSourcer(ReplaceAsyncSourcer {
+            plan_id: 0,
+            schema: insert.dest_schema(),
+            source: source.clone(),
+        });
+
+        let mut plan = PhysicalPlan::Emit(Emit {
+            plan_id: 0,
+            input: Box::new(input),
+            table_info,
+            insert_schema: insert.dest_schema(),
+            returning_exprs: insert.returning.clone(),
+            stat_info: None,
+        });
+        plan.adjust_plan_id(&mut 0);
+        Ok(plan)
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+
+/// How `EnforceSchema` handles a value that can't be cast to the target type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum CastMode {
+    /// Fail the query.
+    Strict,
+    /// Replace the value with NULL.
+    Permissive,
+}
+
+impl Display for CastMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CastMode::Strict => write!(f, "strict"),
+            CastMode::Permissive => write!(f, "permissive"),
+        }
+    }
+}
+
+/// Cast each column of the input to the type of the field at the same position in
+/// `target_schema`, such as the columns read from a stage to the columns of the table.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct EnforceSchema {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub target_schema: DataSchemaRef,
+    pub cast_mode: CastMode,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl EnforceSchema {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.target_schema.clone())
+    }
+}
@@ -100,11 +100,19 @@ impl PhysicalPlanBuilder {
         } else {
             let child = s_expr.child(0)?;
             let input = if let RelOperator::ProjectSet(project_set) = child.plan() {
-                let new_project_set =
-                    self.prune_flatten_columns(eval_scalar, project_set, &required);
-                let mut new_child = child.clone();
-                new_child.plan = Arc::new(new_project_set.into());
-                self.build(&new_child, required).await?
+                match self
+                    .build_json_each(child, project_set, &mut used, required.clone(), &stat_info)
+                    .await?
+                {
+                    Some(json_each) => json_each,
+                    None => {
+                        let new_project_set =
+                            self.prune_flatten_columns(eval_scalar, project_set, &required);
+                        let mut new_child = child.clone();
+                        new_child.plan = Arc::new(new_project_set.into());
+                        self.build(&new_child, required).await?
+                    }
+                }
             } else {
                 self.build(child, required).await?
             };
@@ -114,6 +122,7 @@ impl PhysicalPlanBuilder {
                 .cloned()
                 .collect();
             let column_projections = column_projections.clone().into_iter().collect::<Vec<_>>();
+            let input = self.build_json_extract(&mut used, input, &stat_info)?;
             let eval_scalar = crate::plans::EvalScalar { items: used };
             self.create_eval_scalar(&eval_scalar, column_projections, input, stat_info)
         }
@@ -140,9 +149,13 @@ impl PhysicalPlanBuilder {
             })
             .collect::<Result<Vec<_>>>()?;
 
+        // Drop the expressions whose output columns are not projected, they are never consumed.
         let exprs = exprs
             .into_iter()
             .filter(|(scalar, idx)| {
+                if !column_projections.contains(idx) {
+                    return false;
+                }
                 if let RemoteExpr::ColumnRef { id, .. } = scalar {
                     return idx.to_string() != input_schema.field(*id).name().as_str();
                 }
@@ -157,10 +170,11 @@ impl PhysicalPlanBuilder {
             }
         }
         let input_column_nums = input_schema.num_fields();
-        for (index, (_, idx)) in exprs.iter().enumerate() {
-            if column_projections.contains(idx) {
-                projections.insert(index + input_column_nums);
-            }
+        projections.extend(input_column_nums..input_column_nums + exprs.len());
+
+        // An `EvalScalar` without expressions which projects all the input columns is a no-op.
+        if exprs.is_empty() && projections.len() == input_column_nums {
+            return Ok(input);
         }
         Ok(PhysicalPlan::EvalScalar(EvalScalar {
             plan_id: 0,
@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::RemoteExpr;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::SemiHashJoin;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::JoinType;
+
+impl PhysicalPlanBuilder {
+    /// `EXCEPT` is executed as an `AntiHashJoin` on all the output columns with NULL-equal keys,
+    /// the distinct of `EXCEPT` is applied above it by the binder.
+    /// `EXCEPT ALL` counts the build keys so that each right row only eliminates one left row.
+    pub(crate) async fn build_except(
+        &mut self,
+        s_expr: &SExpr,
+        except: &crate::plans::Except,
+        required: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. All the columns are required to compare the rows.
+        let left_required: ColumnSet = except.left_outputs.iter().map(|c| c.index).collect();
+        let right_required: ColumnSet = except.right_outputs.iter().map(|c| c.index).collect();
+
+        // 2. Build physical plan.
+        let probe = self.build(s_expr.child(0)?, left_required).await?;
+        let build = self.build(s_expr.child(1)?, right_required).await?;
+        let probe_schema = probe.output_schema()?;
+        let build_schema = build.output_schema()?;
+
+        let column_ref = |offset: usize, field: &DataField| RemoteExpr::ColumnRef {
+            span: None,
+            id: offset,
+            data_type: field.data_type().clone(),
+            display_name: field.name().clone(),
+        };
+
+        let mut probe_keys = Vec::with_capacity(except.left_outputs.len());
+        let mut build_keys = Vec::with_capacity(except.right_outputs.len());
+        for (left, right) in except.left_outputs.iter().zip(except.right_outputs.iter()) {
+            let probe_offset = probe_schema.index_of(&left.index.to_string())?;
+            let build_offset = build_schema.index_of(&right.index.to_string())?;
+            probe_keys.push(column_ref(probe_offset, probe_schema.field(probe_offset)));
+            build_keys.push(column_ref(build_offset, build_schema.field(build_offset)));
+        }
+
+        // 3. Only output the required columns of the left side.
+        let mut projections = ColumnSet::new();
+        let mut fields = Vec::new();
+        for (offset, field) in probe_schema.fields().iter().enumerate() {
+            let index = field.name().parse::<usize>()?;
+            if required.contains(&index) {
+                projections.insert(offset);
+                fields.push(field.clone());
+            }
+        }
+
+        Ok(PhysicalPlan::AntiHashJoin(SemiHashJoin {
+            plan_id: 0,
+            projections,
+            build: Box::new(build),
+            probe: Box::new(probe),
+            build_keys,
+            probe_keys,
+            join_type: JoinType::LeftAnti,
+            is_null_equal: true,
+            counting: except.all,
+            output_schema: DataSchemaRefExt::create(fields),
+            stat_info: Some(stat_info),
+        }))
+    }
+}
@@ -21,10 +21,15 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use crate::executor::physical_plans::common::FragmentKind;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::ColumnSet;
+use crate::ScalarExpr;
 use crate::TypeCheck;
 
+/// The hash keys with more estimated distinct values are routed by consistent hash.
+const CONSISTENT_HASH_MIN_NDV: f64 = 1_000_000.0;
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Exchange {
     // A unique id of operator in a `PhysicalPlan` tree, only used for display.
@@ -35,6 +40,8 @@ pub struct Exchange {
     pub keys: Vec<RemoteExpr>,
     pub ignore_exchange: bool,
     pub allow_adjust_parallelism: bool,
+    /// Route the rows of a hash exchange with jump consistent hash instead of modulo.
+    pub consistent_hash: bool,
 }
 
 impl Exchange {
@@ -62,6 +69,7 @@ impl PhysicalPlanBuilder {
         let input_schema = input.output_schema()?;
         let mut keys = vec![];
         let mut allow_adjust_parallelism = true;
+        let mut consistent_hash = false;
         let kind = match exchange {
             crate::plans::Exchange::Hash(scalars) => {
                 for scalar in scalars {
@@ -73,6 +81,7 @@ impl PhysicalPlanBuilder {
                     let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
                     keys.push(expr.as_remote_expr());
                 }
+                consistent_hash = self.is_high_cardinality_keys(s_expr, scalars)?;
                 FragmentKind::Normal
             }
             crate::plans::Exchange::Broadcast => FragmentKind::Expansive,
@@ -89,6 +98,33 @@ impl PhysicalPlanBuilder {
             keys,
             allow_adjust_parallelism,
             ignore_exchange: false,
+            consistent_hash,
         }))
     }
+
+    /// Whether the estimated NDV of the hash keys exceeds [`CONSISTENT_HASH_MIN_NDV`].
+    /// Routing such keys with consistent hash keeps each key on a stable worker.
+    fn is_high_cardinality_keys(&self, s_expr: &SExpr, keys: &[ScalarExpr]) -> Result<bool> {
+        if !self
+            .ctx
+            .get_settings()
+            .get_enable_consistent_hash_exchange()?
+        {
+            return Ok(false);
+        }
+
+        let stat_info = RelExpr::with_s_expr(s_expr.child(0)?).derive_cardinality()?;
+        let columns = keys
+            .iter()
+            .flat_map(|key| key.used_columns())
+            .collect::<ColumnSet>();
+        let mut ndv = 1.0;
+        for column in columns {
+            match stat_info.statistics.column_stats.get(&column) {
+                Some(column_stat) => ndv *= column_stat.ndv,
+                None => return Ok(false),
+            }
+        }
+        Ok(ndv.min(stat_info.cardinality) > CONSISTENT_HASH_MIN_NDV)
+    }
 }
@@ -36,6 +36,7 @@ pub struct ExchangeSink {
     pub query_id: String,
     pub ignore_exchange: bool,
     pub allow_adjust_parallelism: bool,
+    pub consistent_hash: bool,
 }
 
 impl ExchangeSink {
@@ -67,7 +67,12 @@ impl PhysicalPlanBuilder {
         });
 
         // 2. Build physical plan.
-        let input = Box::new(self.build(s_expr.child(0)?, used).await?);
+        let input = self.build(s_expr.child(0)?, used).await?;
+        let (input, geo_predicate) =
+            self.build_geo_scan(input, &filter.predicates, stat_info.clone())?;
+        let input = self.build_bitmap_index_scan(input, &filter.predicates, stat_info.clone())?;
+        let input =
+            Box::new(self.build_bloom_lookup(input, &filter.predicates, stat_info.clone())?);
         required = required
             .union(self.metadata.read().get_retained_column())
             .cloned()
@@ -88,7 +93,9 @@ impl PhysicalPlanBuilder {
             predicates: filter
                 .predicates
                 .iter()
-                .map(|scalar| {
+                .enumerate()
+                .filter(|(i, _)| geo_predicate != Some(*i))
+                .map(|(_, scalar)| {
                     let expr = scalar
                         .type_check(input_schema.as_ref())?
                         .project_column_ref(|index| {
@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::plans::ScalarItem;
+use crate::ScalarExpr;
+use crate::TypeCheck;
+
+/// Call a scalar table function returning an array for each input row,
+/// and flatly concatenate the array elements as the output rows.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct FlatMap {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub projections: ColumnSet,
+    pub input: Box<PhysicalPlan>,
+    pub func_name: String,
+    pub args: Vec<RemoteExpr>,
+    pub output_schema: DataSchemaRef,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl FlatMap {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.output_schema.clone())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Build a `FlatMap` from the `unnest(func(args))` bound for a lateral scalar table function.
+    pub(crate) fn build_flat_map(
+        &self,
+        input: PhysicalPlan,
+        srf: &ScalarItem,
+        projections: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let func = match &srf.scalar {
+            ScalarExpr::FunctionCall(unnest) if unnest.func_name == "unnest" => {
+                match unnest.arguments.as_slice() {
+                    [ScalarExpr::FunctionCall(func)] => Some(func),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+        .ok_or_else(|| {
+            ErrorCode::Internal("FlatMap can only be built from the unnest of a table function")
+        })?;
+
+        let input_schema = input.output_schema()?;
+        let args = func
+            .arguments
+            .iter()
+            .map(|arg| {
+                let expr = arg
+                    .type_check(input_schema.as_ref())?
+                    .project_column_ref(|index| input_schema.index_of(&index.to_string()).unwrap());
+                let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                Ok(expr.as_remote_expr())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let data_type = srf
+            .scalar
+            .type_check(input_schema.as_ref())?
+            .data_type()
+            .clone();
+
+        let mut fields = Vec::with_capacity(projections.len() + 1);
+        for (i, field) in input_schema.fields().iter().enumerate() {
+            if projections.contains(&i) {
+                fields.push(field.clone());
+            }
+        }
+        fields.push(DataField::new(&srf.index.to_string(), data_type));
+
+        Ok(PhysicalPlan::FlatMap(FlatMap {
+            plan_id: 0,
+            projections,
+            input: Box::new(input),
+            func_name: func.func_name.clone(),
+            args,
+            output_schema: DataSchemaRefExt::create(fields),
+            stat_info: Some(stat_info),
+        }))
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::flight_sql_client::AuthCredentials;
+use databend_common_expression::DataSchemaRef;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+
+/// Send `query` to a remote Arrow Flight SQL service and stream the returned record
+/// batches, built from the `flight_sql(endpoint, query [, username, password])` table function.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct FunctionImport {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub endpoint: String,
+    pub query: String,
+    /// The output schema, the fields are named by the column indexes.
+    pub schema: DataSchemaRef,
+    /// The offsets in the remote result of the output columns.
+    pub projection: Vec<usize>,
+    pub auth: Option<AuthCredentials>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl FunctionImport {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_function_import(
+        &mut self,
+        import_id: usize,
+        column_indexes: &[usize],
+        output_schema: DataSchemaRef,
+    ) -> Result<PhysicalPlan> {
+        let import = self.metadata.read().function_import(import_id).clone();
+        Ok(PhysicalPlan::FunctionImport(FunctionImport {
+            plan_id: 0,
+            endpoint: import.endpoint,
+            query: import.query,
+            schema: output_schema,
+            projection: column_indexes.to_vec(),
+            auth: import.auth,
+            stat_info: None,
+        }))
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::SExpr;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::Sort;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::IndexType;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum DistanceMetric {
+    Cosine,
+    L2,
+}
+
+impl DistanceMetric {
+    fn from_func_name(func_name: &str) -> Option<Self> {
+        match func_name {
+            "cosine_distance" => Some(DistanceMetric::Cosine),
+            "l2_distance" => Some(DistanceMetric::L2),
+            _ => None,
+        }
+    }
+}
+
+impl Display for DistanceMetric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DistanceMetric::Cosine => write!(f, "cosine"),
+            DistanceMetric::L2 => write!(f, "l2"),
+        }
+    }
+}
+
+/// Find the `k` nearest neighbors of `query_vector` in `vector_column`,
+/// e.g. `ORDER BY cosine_distance(v, [1, 0]) LIMIT k`.
+///
+/// The tables don't have a vector index yet, so every row of `input` is a candidate,
+/// the candidates are ranked by the exact distance in the `Sort` above and the other
+/// columns are fetched after the `Limit`.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct FuzzyMatch {
+    /// A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub table_index: IndexType,
+    pub vector_column: IndexType,
+    pub query_vector: Vec<f32>,
+    pub metric: DistanceMetric,
+    pub k: usize,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl FuzzyMatch {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Wrap the `input` of a `Sort` with a `FuzzyMatch` if the sort is
+    /// `ORDER BY distance(column, constant) LIMIT k` on a vector column of a table.
+    pub(crate) fn build_fuzzy_match(
+        &self,
+        s_expr: &SExpr,
+        sort: &Sort,
+        input: PhysicalPlan,
+        stat_info: &PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let (Some(k), [item]) = (sort.limit, sort.items.as_slice()) else {
+            return Ok(input);
+        };
+        if !item.asc || sort.window_partition.is_some() {
+            return Ok(input);
+        }
+        let RelOperator::EvalScalar(eval_scalar) = s_expr.child(0)?.plan() else {
+            return Ok(input);
+        };
+        let Some(scalar) = eval_scalar
+            .items
+            .iter()
+            .find(|scalar_item| scalar_item.index == item.index)
+            .map(|scalar_item| &scalar_item.scalar)
+        else {
+            return Ok(input);
+        };
+        let ScalarExpr::FunctionCall(func) = scalar else {
+            return Ok(input);
+        };
+        let Some(metric) = DistanceMetric::from_func_name(&func.func_name) else {
+            return Ok(input);
+        };
+        let ([ScalarExpr::BoundColumnRef(column), ScalarExpr::ConstantExpr(constant)]
+        | [ScalarExpr::ConstantExpr(constant), ScalarExpr::BoundColumnRef(column)]) =
+            func.arguments.as_slice()
+        else {
+            return Ok(input);
+        };
+        let Some(query_vector) = vector_values(&constant.value) else {
+            return Ok(input);
+        };
+
+        let vector_column = column.column.index;
+        let table_index = match self.metadata.read().column(vector_column) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                table_index,
+                path_indices: None,
+                virtual_expr: None,
+                ..
+            }) => *table_index,
+            _ => return Ok(input),
+        };
+
+        Ok(PhysicalPlan::FuzzyMatch(Box::new(FuzzyMatch {
+            plan_id: 0,
+            input: Box::new(input),
+            table_index,
+            vector_column,
+            query_vector,
+            metric,
+            k,
+            stat_info: Some(PlanStatsInfo {
+                estimated_rows: stat_info.estimated_rows.min(k as f64),
+            }),
+        })))
+    }
+}
+
+fn vector_values(value: &Scalar) -> Option<Vec<f32>> {
+    let Scalar::Array(values) = value else {
+        return None;
+    };
+    values
+        .iter()
+        .map(|value| match value {
+            ScalarRef::Number(number) => Some(number.to_f64().into_inner() as f32),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,168 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_catalog::plan::BoundingBox;
+use databend_common_catalog::plan::SpatialPredicate;
+use databend_common_catalog::plan::SpatialRelation;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::executor::cast_expr_to_non_null_boolean;
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::plans::ConstantExpr;
+use crate::plans::ScalarExpr;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::TypeCheck;
+
+/// Read the blocks of the table scan `input` which may hold a geometry of `geo_column`
+/// matching `spatial_predicate`, the candidate blocks are looked up in the spatial index
+/// of the column. All the blocks are read if the column has no spatial index.
+///
+/// The rows of the candidate blocks are evaluated by `fallback_filter`, which is `None`
+/// if it's the only predicate of the `Filter` above and evaluated there.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct GeoScan {
+    /// A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub table_index: IndexType,
+    pub geo_column: IndexType,
+    pub spatial_predicate: SpatialPredicate,
+    pub fallback_filter: Option<RemoteExpr>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl GeoScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Wrap the table scan `input` of a `Filter` with a `GeoScan` if one of the `predicates`
+    /// is `ST_WITHIN` or `ST_INTERSECTS` between a geometry column of the table and a constant.
+    ///
+    /// Returns the index of the predicate evaluated by the `GeoScan`, which the `Filter`
+    /// doesn't need to evaluate again.
+    pub(crate) fn build_geo_scan(
+        &self,
+        input: PhysicalPlan,
+        predicates: &[ScalarExpr],
+        stat_info: PlanStatsInfo,
+    ) -> Result<(PhysicalPlan, Option<usize>)> {
+        let table_index = match &input {
+            PhysicalPlan::TableScan(scan) => scan.table_index,
+            _ => None,
+        };
+        let Some(table_index) = table_index else {
+            return Ok((input, None));
+        };
+
+        for (i, predicate) in predicates.iter().enumerate() {
+            let ScalarExpr::FunctionCall(func) = predicate else {
+                continue;
+            };
+            if func.arguments.len() != 2 {
+                continue;
+            }
+            let (column, geometry, relation) = match (
+                func.func_name.as_str(),
+                &func.arguments[0],
+                &func.arguments[1],
+            ) {
+                (
+                    "st_within",
+                    ScalarExpr::BoundColumnRef(column),
+                    ScalarExpr::ConstantExpr(constant),
+                ) => (column, constant, SpatialRelation::Within),
+                (
+                    "st_within",
+                    ScalarExpr::ConstantExpr(constant),
+                    ScalarExpr::BoundColumnRef(column),
+                ) => (column, constant, SpatialRelation::Contains),
+                (
+                    "st_intersects",
+                    ScalarExpr::BoundColumnRef(column),
+                    ScalarExpr::ConstantExpr(constant),
+                )
+                | (
+                    "st_intersects",
+                    ScalarExpr::ConstantExpr(constant),
+                    ScalarExpr::BoundColumnRef(column),
+                ) => (column, constant, SpatialRelation::Intersects),
+                _ => continue,
+            };
+            let ConstantExpr {
+                value: Scalar::Geometry(geometry),
+                ..
+            } = geometry
+            else {
+                continue;
+            };
+            if column.column.data_type.remove_nullable() != DataType::Geometry {
+                continue;
+            }
+            if !matches!(
+                self.metadata.read().column(column.column.index),
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    table_index: index,
+                    path_indices: None,
+                    virtual_expr: None,
+                    ..
+                }) if *index == table_index
+            ) {
+                continue;
+            }
+            // The rows never match an empty geometry, which is left to the filter.
+            let Some(bbox) = BoundingBox::from_ewkb(geometry)? else {
+                continue;
+            };
+
+            let (fallback_filter, evaluated) = if predicates.len() > 1 {
+                let input_schema = input.output_schema()?;
+                let expr = predicate
+                    .type_check(input_schema.as_ref())?
+                    .project_column_ref(|index| input_schema.index_of(&index.to_string()).unwrap());
+                let expr = cast_expr_to_non_null_boolean(expr)?;
+                let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                (Some(expr.as_remote_expr()), Some(i))
+            } else {
+                (None, None)
+            };
+
+            let plan = PhysicalPlan::GeoScan(Box::new(GeoScan {
+                plan_id: 0,
+                input: Box::new(input),
+                table_index,
+                geo_column: column.column.index,
+                spatial_predicate: SpatialPredicate { relation, bbox },
+                fallback_filter,
+                stat_info: Some(stat_info),
+            }));
+            return Ok((plan, evaluated));
+        }
+        Ok((input, None))
+    }
+}
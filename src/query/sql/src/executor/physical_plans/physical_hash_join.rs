@@ -74,6 +74,9 @@ pub struct HashJoin {
     // if we execute distributed merge into, we need to hold the
     // hash table to get not match data from source.
     pub need_hold_hash_table: bool,
+    // Only keep the first build row of each key, used by joins rewritten from `EXISTS` and `IN`
+    // subqueries, whose results do not depend on the duplicate build rows.
+    pub build_dedup: bool,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -111,7 +114,9 @@ impl PhysicalPlanBuilder {
         stat_info: PlanStatsInfo,
     ) -> Result<PhysicalPlan> {
         let mut probe_side = Box::new(self.build(s_expr.child(0)?, left_required).await?);
-        let mut build_side = Box::new(self.build(s_expr.child(1)?, right_required).await?);
+        let build_side = self.build(s_expr.child(1)?, right_required.clone()).await?;
+        let mut build_side =
+            Box::new(self.build_replicate(join, s_expr.child(1)?, &right_required, build_side)?);
 
         let retained_columns = self.metadata.read().get_retained_column().clone();
         required = required.union(&retained_columns).cloned().collect();
@@ -503,7 +508,16 @@ impl PhysicalPlanBuilder {
             }
         }
         let output_schema = DataSchemaRefExt::create(output_fields);
-        Ok(PhysicalPlan::HashJoin(HashJoin {
+
+        // The build side of a semi/anti/mark join rewritten from `EXISTS` or `IN` subquery is only
+        // used to check whether a key exists, so the duplicate keys can be removed when building.
+        let build_dedup = right_join_conditions.len() == 1
+            && join.non_equi_conditions.is_empty()
+            && !join.need_hold_hash_table
+            && (join.join_type == JoinType::RightMark
+                || (join.from_correlated_subquery
+                    && matches!(join.join_type, JoinType::LeftSemi | JoinType::LeftAnti)));
+        let hash_join = HashJoin {
             plan_id: 0,
             projections,
             build_projections,
@@ -533,6 +547,7 @@ impl PhysicalPlanBuilder {
             probe_to_build,
             output_schema,
             need_hold_hash_table: join.need_hold_hash_table,
+            build_dedup,
             stat_info: Some(stat_info),
             broadcast: is_broadcast,
             single_to_inner: join.single_to_inner.clone(),
@@ -544,7 +559,8 @@ impl PhysicalPlanBuilder {
             )
             .await?,
             build_side_cache_info,
-        }))
+        };
+        self.build_semi_hash_join(hash_join)
     }
 }
 
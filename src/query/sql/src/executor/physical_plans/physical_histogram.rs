@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::Scalar;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::AggregateMode;
+use crate::plans::RelOperator;
+use crate::IndexType;
+use crate::ScalarExpr;
+
+/// Compute an equi-depth histogram of `column` in a single pass over the input,
+/// the buckets are built from a reservoir sample of the column values.
+/// It outputs one row per bucket.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Histogram {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub column: IndexType,
+    pub num_buckets: usize,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Histogram {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let bound_type = input_schema
+            .field_with_name(&self.column.to_string())?
+            .data_type()
+            .remove_nullable();
+        Ok(DataSchemaRefExt::create(vec![
+            DataField::new("bucket_lower", bound_type.clone()),
+            DataField::new("bucket_upper", bound_type),
+            DataField::new("count", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("ndv", DataType::Number(NumberDataType::UInt64)),
+        ]))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Build a `Histogram` for the final aggregate of `SELECT histogram(col, n) FROM ...`,
+    /// the partial aggregate below it (and the exchange between them) is replaced.
+    pub(crate) async fn build_histogram(
+        &mut self,
+        s_expr: &SExpr,
+        column: IndexType,
+        num_buckets: usize,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let mut child = s_expr.child(0)?;
+        if let RelOperator::Exchange(_) = child.plan() {
+            child = child.child(0)?;
+        }
+        if !matches!(
+            child.plan(),
+            RelOperator::Aggregate(agg) if agg.mode == AggregateMode::Partial
+        ) {
+            return Err(ErrorCode::Internal(
+                "Histogram expects a partial aggregate as the input of the final aggregate",
+            ));
+        }
+
+        let input = self
+            .build(child.child(0)?, ColumnSet::from([column]))
+            .await?;
+
+        Ok(PhysicalPlan::Histogram(Histogram {
+            plan_id: 0,
+            input: Box::new(input),
+            column,
+            num_buckets,
+            stat_info: Some(stat_info),
+        }))
+    }
+}
+
+/// Returns the argument column and the number of buckets if the aggregate only computes
+/// `histogram(col, n)` without group by.
+pub(crate) fn histogram_argument(agg: &crate::plans::Aggregate) -> Option<(IndexType, usize)> {
+    if !agg.group_items.is_empty() || agg.grouping_sets.is_some() {
+        return None;
+    }
+    let [item] = agg.aggregate_functions.as_slice() else {
+        return None;
+    };
+    let ScalarExpr::AggregateFunction(func) = &item.scalar else {
+        return None;
+    };
+    if !func.func_name.eq_ignore_ascii_case("histogram") || func.distinct {
+        return None;
+    }
+    let [ScalarExpr::BoundColumnRef(col)] = func.args.as_slice() else {
+        return None;
+    };
+    let num_buckets = match func.params.first() {
+        Some(Scalar::Number(number)) => number.integer_to_i128().filter(|n| *n > 0)? as usize,
+        Some(_) => return None,
+        None => 128,
+    };
+    Some((col.column.index, num_buckets))
+}
@@ -18,6 +18,7 @@ use databend_common_exception::Result;
 
 use crate::binder::JoinPredicate;
 use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::sorted_join_keys;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::RelExpr;
@@ -30,6 +31,8 @@ use crate::ScalarExpr;
 
 pub enum PhysicalJoinType {
     Hash,
+    // The orders of the equi join keys, both the sides are sorted by them.
+    SortMerge(Vec<(bool, bool)>),
     // The first arg is range conditions, the second arg is other conditions
     RangeJoin(Vec<ScalarExpr>, Vec<ScalarExpr>),
 }
@@ -37,6 +40,10 @@ pub enum PhysicalJoinType {
 // Choose physical join type by join conditions
 pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
     if !join.equi_conditions.is_empty() {
+        // Both the sides are sorted by the equi join keys, merge them without hash table.
+        if let Some(key_orders) = sorted_join_keys(join, s_expr)? {
+            return Ok(PhysicalJoinType::SortMerge(key_orders));
+        }
         // Contain equi condition, use hash join
         return Ok(PhysicalJoinType::Hash);
     }
@@ -174,6 +181,17 @@ impl PhysicalPlanBuilder {
                 )
                 .await
             }
+            PhysicalJoinType::SortMerge(key_orders) => {
+                self.build_sort_merge_join(
+                    join,
+                    s_expr,
+                    left_required,
+                    right_required,
+                    key_orders,
+                    stat_info,
+                )
+                .await
+            }
             PhysicalJoinType::RangeJoin(range, other) => {
                 self.build_range_join(s_expr, left_required, right_required, range, other)
                     .await
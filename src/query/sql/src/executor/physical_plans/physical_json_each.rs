@@ -0,0 +1,208 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::Scalar;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::FunctionCall;
+use crate::plans::ProjectSet;
+use crate::plans::ScalarItem;
+use crate::ColumnBindingBuilder;
+use crate::IndexType;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Expand each variant value of `source_col` into `(key, value)` rows for the `json_each`
+/// table function. The keys of an array are the indexes of the elements, the other values
+/// produce no rows. The input columns are repeated for each output row.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct JsonEach {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub source_col: IndexType,
+    pub key_col: IndexType,
+    pub value_col: IndexType,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl JsonEach {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        fields.push(DataField::new(
+            &self.key_col.to_string(),
+            DataType::Nullable(Box::new(DataType::String)),
+        ));
+        fields.push(DataField::new(
+            &self.value_col.to_string(),
+            DataType::Nullable(Box::new(DataType::Variant)),
+        ));
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Build a `JsonEach` instead of the `ProjectSet` of `s_expr` if it only evaluates
+    /// `json_each`, and the result tuple is only accessed by the items of `items` which
+    /// extract the key or the value. These items are replaced with the output columns.
+    pub(crate) async fn build_json_each(
+        &mut self,
+        s_expr: &SExpr,
+        project_set: &ProjectSet,
+        items: &mut [ScalarItem],
+        mut required: ColumnSet,
+        stat_info: &PlanStatsInfo,
+    ) -> Result<Option<PhysicalPlan>> {
+        let [srf] = project_set.srfs.as_slice() else {
+            return Ok(None);
+        };
+        let ScalarExpr::FunctionCall(func) = &srf.scalar else {
+            return Ok(None);
+        };
+        let [arg] = func.arguments.as_slice() else {
+            return Ok(None);
+        };
+        if func.func_name != "json_each" || arg.data_type()?.remove_nullable() != DataType::Variant
+        {
+            return Ok(None);
+        }
+
+        let mut key_col = None;
+        let mut value_col = None;
+        for item in items.iter() {
+            match as_json_each_field(&item.scalar, srf.index) {
+                Some(1) => key_col = key_col.or(Some(item.index)),
+                Some(2) => value_col = value_col.or(Some(item.index)),
+                // The result tuple is used in other ways.
+                _ if item.scalar.used_columns().contains(&srf.index) => return Ok(None),
+                _ => {}
+            }
+        }
+
+        let key_type = DataType::Nullable(Box::new(DataType::String));
+        let value_type = DataType::Nullable(Box::new(DataType::Variant));
+        let key_col = key_col.unwrap_or_else(|| {
+            self.metadata
+                .write()
+                .add_derived_column("key".to_string(), key_type.clone(), None)
+        });
+        let value_col = value_col.unwrap_or_else(|| {
+            self.metadata
+                .write()
+                .add_derived_column("value".to_string(), value_type.clone(), None)
+        });
+        for item in items.iter_mut() {
+            let data_type = match as_json_each_field(&item.scalar, srf.index) {
+                Some(1) => key_type.clone(),
+                Some(2) => value_type.clone(),
+                _ => continue,
+            };
+            item.scalar = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: ColumnBindingBuilder::new(
+                    item.index.to_string(),
+                    item.index,
+                    Box::new(data_type),
+                    Visibility::Visible,
+                )
+                .build(),
+            });
+        }
+
+        required.remove(&srf.index);
+        required.remove(&key_col);
+        required.remove(&value_col);
+        required.extend(arg.used_columns());
+        let mut input = self.build(s_expr.child(0)?, required).await?;
+
+        // Evaluate the argument first if it's not a column, such as a constant.
+        let source_col = match arg {
+            ScalarExpr::BoundColumnRef(column_ref) => column_ref.column.index,
+            _ => {
+                let index = self.metadata.write().add_derived_column(
+                    "json_each_source".to_string(),
+                    arg.data_type()?,
+                    None,
+                );
+                let mut column_projections = input
+                    .output_schema()?
+                    .fields()
+                    .iter()
+                    .filter_map(|field| field.name().parse::<IndexType>().ok())
+                    .collect::<Vec<_>>();
+                column_projections.push(index);
+                let eval_scalar = EvalScalar {
+                    items: vec![ScalarItem {
+                        scalar: arg.clone(),
+                        index,
+                    }],
+                };
+                input = self.create_eval_scalar(
+                    &eval_scalar,
+                    column_projections,
+                    input,
+                    stat_info.clone(),
+                )?;
+                index
+            }
+        };
+
+        Ok(Some(PhysicalPlan::JsonEach(Box::new(JsonEach {
+            plan_id: 0,
+            input: Box::new(input),
+            source_col,
+            key_col,
+            value_col,
+            stat_info: Some(stat_info.clone()),
+        }))))
+    }
+}
+
+/// Returns the position of the field if `scalar` is `get(position)(srf_index)`.
+fn as_json_each_field(scalar: &ScalarExpr, srf_index: IndexType) -> Option<i64> {
+    let ScalarExpr::FunctionCall(FunctionCall {
+        func_name,
+        params,
+        arguments,
+        ..
+    }) = scalar
+    else {
+        return None;
+    };
+    if func_name != "get" {
+        return None;
+    }
+    match (params.as_slice(), arguments.as_slice()) {
+        (
+            [Scalar::Number(NumberScalar::Int64(position))],
+            [ScalarExpr::BoundColumnRef(column_ref)],
+        ) if column_ref.column.index == srf_index => Some(*position),
+        _ => None,
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::Scalar;
+use jsonb::keypath::parse_key_paths;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::plans::walk_expr;
+use crate::plans::walk_expr_mut;
+use crate::plans::BoundColumnRef;
+use crate::plans::ConstantExpr;
+use crate::plans::FunctionCall;
+use crate::plans::ScalarItem;
+use crate::plans::Visitor;
+use crate::plans::VisitorMut;
+use crate::ColumnBindingBuilder;
+use crate::IndexType;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Extract the values of several key paths from a variant column.
+///
+/// Each variant value is decoded once and all the key paths are extracted from
+/// the decoded value, instead of evaluating a `get_by_keypath` for each key path.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct JsonExtract {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    /// The variant column to extract from.
+    pub source_col: IndexType,
+    /// The output column and the elements of the key path, such as `["\"a\"", "0"]`
+    /// for `v['a'][0]`, of each extraction.
+    pub paths: Vec<(IndexType, Vec<String>)>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl JsonExtract {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        for (index, _) in self.paths.iter() {
+            fields.push(DataField::new(
+                &index.to_string(),
+                DataType::Nullable(Box::new(DataType::Variant)),
+            ));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// If more than one key path of a variant column is extracted by `get_by_keypath`
+    /// in `items`, extract them with a `JsonExtract` on top of `input`, and replace
+    /// the `get_by_keypath` calls in `items` with the extracted columns.
+    pub(crate) fn build_json_extract(
+        &mut self,
+        items: &mut [ScalarItem],
+        mut input: PhysicalPlan,
+        stat_info: &PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let mut collector = KeyPathCollector::default();
+        for item in items.iter() {
+            collector.visit(&item.scalar)?;
+        }
+
+        let input_schema = input.output_schema()?;
+        for (source, key_paths) in collector.sources {
+            if key_paths.len() < 2 || input_schema.index_of(&source.to_string()).is_err() {
+                continue;
+            }
+
+            let mut paths = Vec::with_capacity(key_paths.len());
+            let mut replaced = Vec::with_capacity(key_paths.len());
+            for key_path in key_paths {
+                let Ok(parsed) = parse_key_paths(key_path.as_bytes()) else {
+                    continue;
+                };
+                // Reuse the index of the item if it only extracts the key path.
+                let index = items
+                    .iter()
+                    .find(|item| as_key_path(&item.scalar) == Some((source, &key_path)))
+                    .map(|item| item.index)
+                    .unwrap_or_else(|| {
+                        self.metadata.write().add_derived_column(
+                            format!("get_by_keypath({}, '{}')", source, key_path),
+                            DataType::Nullable(Box::new(DataType::Variant)),
+                            None,
+                        )
+                    });
+                let elements = parsed.paths.iter().map(|path| path.to_string()).collect();
+                paths.push((index, elements));
+                replaced.push((key_path, index));
+            }
+            if paths.len() < 2 {
+                continue;
+            }
+
+            let mut replacer = KeyPathReplacer { source, replaced };
+            for item in items.iter_mut() {
+                replacer.visit(&mut item.scalar)?;
+            }
+
+            input = PhysicalPlan::JsonExtract(Box::new(JsonExtract {
+                plan_id: 0,
+                input: Box::new(input),
+                source_col: source,
+                paths,
+                stat_info: Some(stat_info.clone()),
+            }));
+        }
+
+        Ok(input)
+    }
+}
+
+/// Returns the variant column and the key path if `scalar` is `get_by_keypath(column, 'key_path')`.
+fn as_key_path(scalar: &ScalarExpr) -> Option<(IndexType, &String)> {
+    let ScalarExpr::FunctionCall(FunctionCall {
+        func_name,
+        params,
+        arguments,
+        ..
+    }) = scalar
+    else {
+        return None;
+    };
+    if func_name != "get_by_keypath" || !params.is_empty() {
+        return None;
+    }
+    match arguments.as_slice() {
+        [ScalarExpr::BoundColumnRef(BoundColumnRef { column, .. }), ScalarExpr::ConstantExpr(ConstantExpr {
+            value: Scalar::String(key_path),
+            ..
+        })] if column.data_type.remove_nullable() == DataType::Variant => {
+            Some((column.index, key_path))
+        }
+        _ => None,
+    }
+}
+
+/// Collect the distinct key paths of each variant column in order of appearance.
+#[derive(Default)]
+struct KeyPathCollector {
+    sources: Vec<(IndexType, Vec<String>)>,
+}
+
+impl<'a> Visitor<'a> for KeyPathCollector {
+    fn visit(&mut self, expr: &'a ScalarExpr) -> Result<()> {
+        let Some((source, key_path)) = as_key_path(expr) else {
+            return walk_expr(self, expr);
+        };
+        match self.sources.iter_mut().find(|(index, _)| *index == source) {
+            Some((_, key_paths)) if key_paths.contains(key_path) => {}
+            Some((_, key_paths)) => key_paths.push(key_path.clone()),
+            None => self.sources.push((source, vec![key_path.clone()])),
+        }
+        Ok(())
+    }
+}
+
+/// Replace the `get_by_keypath` calls of the extracted key paths with the extracted columns.
+struct KeyPathReplacer {
+    source: IndexType,
+    replaced: Vec<(String, IndexType)>,
+}
+
+impl<'a> VisitorMut<'a> for KeyPathReplacer {
+    fn visit(&mut self, expr: &'a mut ScalarExpr) -> Result<()> {
+        let index = match as_key_path(expr) {
+            Some((source, key_path)) if source == self.source => self
+                .replaced
+                .iter()
+                .find(|(replaced, _)| replaced == key_path)
+                .map(|(_, index)| *index),
+            _ => None,
+        };
+        let Some(index) = index else {
+            return walk_expr_mut(self, expr);
+        };
+
+        *expr = ScalarExpr::BoundColumnRef(BoundColumnRef {
+            span: None,
+            column: ColumnBindingBuilder::new(
+                index.to_string(),
+                index,
+                Box::new(DataType::Nullable(Box::new(DataType::Variant))),
+                Visibility::Visible,
+            )
+            .build(),
+        });
+        Ok(())
+    }
+}
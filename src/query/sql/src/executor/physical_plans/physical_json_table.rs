@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::IndexType;
+
+/// Convert the JSON values to rows for `JSON_TABLE`, each input row is expanded to one row
+/// per JSON value selected by `row_path` from `source_col`.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct JsonTable {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub source_col: IndexType,
+    pub row_path: String,
+    /// The output column, the JSON path relative to the row and the type of each column.
+    pub paths: Vec<(IndexType, String, DataType)>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl JsonTable {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        for (index, _, data_type) in self.paths.iter() {
+            fields.push(DataField::new(&index.to_string(), data_type.clone()));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_json_table(
+        &mut self,
+        s_expr: &SExpr,
+        json_table: &crate::plans::JsonTable,
+        mut required: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns.
+        for (index, _, _) in json_table.columns.iter() {
+            required.remove(index);
+        }
+        required.insert(json_table.source_col);
+
+        // 2. Build physical plan.
+        let input = self.build(s_expr.child(0)?, required).await?;
+        Ok(PhysicalPlan::JsonTable(Box::new(JsonTable {
+            plan_id: 0,
+            input: Box::new(input),
+            source_col: json_table.source_col,
+            row_path: json_table.row_path.clone(),
+            paths: json_table.columns.clone(),
+            stat_info: Some(stat_info),
+        })))
+    }
+}
@@ -34,6 +34,9 @@ pub struct Limit {
     pub input: Box<PhysicalPlan>,
     pub limit: Option<usize>,
     pub offset: usize,
+    /// The rows each parallel branch of the input needs to produce at most, which is
+    /// `limit + offset`, used to stop reading as soon as enough rows are collected locally.
+    pub pre_limit: Option<usize>,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -65,6 +68,8 @@ impl PhysicalPlanBuilder {
 
         // 2. Build physical plan.
         let input_plan = self.build(s_expr.child(0)?, required).await?;
+        let input_plan = self.build_conditional_limit(input_plan, limit)?;
+        let pre_limit = limit.limit.map(|l| l + limit.offset);
         let metadata = self.metadata.read().clone();
         if limit.before_exchange || metadata.lazy_columns().is_empty() {
             return Ok(PhysicalPlan::Limit(Limit {
@@ -72,6 +77,7 @@ impl PhysicalPlanBuilder {
                 input: Box::new(input_plan),
                 limit: limit.limit,
                 offset: limit.offset,
+                pre_limit,
                 stat_info: Some(stat_info),
             }));
         }
@@ -92,6 +98,7 @@ impl PhysicalPlanBuilder {
                 input: Box::new(input_plan),
                 limit: limit.limit,
                 offset: limit.offset,
+                pre_limit,
                 stat_info: Some(stat_info),
             }));
         }
@@ -116,6 +123,7 @@ impl PhysicalPlanBuilder {
                 input: Box::new(input_plan),
                 limit: limit.limit,
                 offset: limit.offset,
+                pre_limit,
                 stat_info: Some(stat_info),
             }));
         }
@@ -155,6 +163,7 @@ impl PhysicalPlanBuilder {
                 input: Box::new(input_plan),
                 limit: limit.limit,
                 offset: limit.offset,
+                pre_limit,
                 stat_info: Some(stat_info.clone()),
             })),
             source: Box::new(source_info),
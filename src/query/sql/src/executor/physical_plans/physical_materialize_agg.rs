@@ -0,0 +1,257 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_COUNT;
+use databend_storages_common_table_meta::table::OPT_KEY_ROLLUP_KEYS;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::AggregateFunctionDesc;
+use crate::executor::physical_plans::AggregateFunctionSignature;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::AggregateMode;
+use crate::plans::RelOperator;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::ScalarExpr;
+
+/// The layout of a rollup table, declared by the `rollup_keys` and `rollup_count` table
+/// options. Each row of a rollup table pre-aggregates the raw rows of one group.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PreAggMeta {
+    pub table_index: IndexType,
+    // The rows of the rollup table are unique on these columns.
+    pub group_keys: Vec<IndexType>,
+    // The number of raw rows of each rollup row, `COUNT(*)` reads it.
+    pub count_column: Option<IndexType>,
+}
+
+/// Answer the final aggregate of a rollup table grouped by exactly its rollup keys. Each
+/// group has a single rollup row, so `SUM`, `MIN` and `MAX` of a column are the column
+/// itself and `COUNT(*)` is the count column, no aggregation is needed.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MaterializeAgg {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub pre_agg_meta: PreAggMeta,
+    // The `arg_indices` of each function is the column it reads, the count column
+    // for `COUNT(*)`.
+    pub original_agg_funcs: Vec<AggregateFunctionDesc>,
+    pub group_by: Vec<IndexType>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl MaterializeAgg {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = Vec::with_capacity(self.original_agg_funcs.len() + self.group_by.len());
+        for agg in self.original_agg_funcs.iter() {
+            let data_type = agg.sig.return_type.clone();
+            fields.push(DataField::new(&agg.output_column.to_string(), data_type));
+        }
+        for id in self.group_by.iter() {
+            let data_type = input_schema
+                .field_with_name(&id.to_string())?
+                .data_type()
+                .clone();
+            fields.push(DataField::new(&id.to_string(), data_type));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Build a `MaterializeAgg` for the final aggregate of a rollup table, the partial
+    /// aggregate below it (and the exchange between them) is replaced.
+    pub(crate) async fn build_materialize_agg(
+        &mut self,
+        s_expr: &SExpr,
+        agg: &crate::plans::Aggregate,
+        pre_agg_meta: PreAggMeta,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let mut child = s_expr.child(0)?;
+        if let RelOperator::Exchange(_) = child.plan() {
+            child = child.child(0)?;
+        }
+        if !matches!(
+            child.plan(),
+            RelOperator::Aggregate(agg) if agg.mode == AggregateMode::Partial
+        ) {
+            return Err(ErrorCode::Internal(
+                "MaterializeAgg expects a partial aggregate as the input of the final aggregate",
+            ));
+        }
+
+        let mut required = pre_agg_meta
+            .group_keys
+            .iter()
+            .cloned()
+            .collect::<ColumnSet>();
+        let mut agg_funcs = Vec::with_capacity(agg.aggregate_functions.len());
+        for item in agg.aggregate_functions.iter() {
+            let ScalarExpr::AggregateFunction(func) = &item.scalar else {
+                return Err(ErrorCode::Internal(
+                    "MaterializeAgg expects plain aggregate functions",
+                ));
+            };
+            let (arg_index, args) = match func.args.as_slice() {
+                [ScalarExpr::BoundColumnRef(col)] => {
+                    (col.column.index, vec![*col.column.data_type.clone()])
+                }
+                [] => match pre_agg_meta.count_column {
+                    Some(count_column) => (count_column, vec![]),
+                    None => {
+                        return Err(ErrorCode::Internal(
+                            "MaterializeAgg expects a count column for COUNT(*)",
+                        ));
+                    }
+                },
+                _ => {
+                    return Err(ErrorCode::Internal(
+                        "MaterializeAgg expects an argument column",
+                    ));
+                }
+            };
+            required.insert(arg_index);
+            agg_funcs.push(AggregateFunctionDesc {
+                sig: AggregateFunctionSignature {
+                    name: func.func_name.clone(),
+                    udaf: None,
+                    return_type: *func.return_type.clone(),
+                    args,
+                    params: vec![],
+                    sort_descs: vec![],
+                },
+                output_column: item.index,
+                arg_indices: vec![arg_index],
+                sort_desc_indices: vec![],
+                display: item.scalar.as_expr()?.sql_display(),
+            });
+        }
+
+        let input = self.build(child.child(0)?, required).await?;
+
+        Ok(PhysicalPlan::MaterializeAgg(Box::new(MaterializeAgg {
+            plan_id: 0,
+            input: Box::new(input),
+            group_by: agg.group_items.iter().map(|item| item.index).collect(),
+            pre_agg_meta,
+            original_agg_funcs: agg_funcs,
+            stat_info: Some(stat_info),
+        })))
+    }
+
+    /// Returns the rollup layout if the final aggregate groups a rollup table by exactly
+    /// its rollup keys and only computes `SUM`, `MIN` and `MAX` of columns and `COUNT(*)`.
+    pub(crate) fn pre_agg_argument(
+        &self,
+        s_expr: &SExpr,
+        agg: &crate::plans::Aggregate,
+    ) -> Option<PreAggMeta> {
+        if agg.grouping_sets.is_some() || agg.rank_limit.is_some() || agg.group_items.is_empty() {
+            return None;
+        }
+
+        // Final -> [Exchange] -> Partial -> [Filter]* -> Scan
+        let mut child = s_expr.child(0).ok()?;
+        if let RelOperator::Exchange(_) = child.plan() {
+            child = child.child(0).ok()?;
+        }
+        if !matches!(child.plan(), RelOperator::Aggregate(_)) {
+            return None;
+        }
+        child = child.child(0).ok()?;
+        while let RelOperator::Filter(_) = child.plan() {
+            child = child.child(0).ok()?;
+        }
+        let RelOperator::Scan(scan) = child.plan() else {
+            return None;
+        };
+
+        let metadata = self.metadata.read();
+        let table = metadata.table(scan.table_index).table();
+        let options = table.options();
+        let keys = options
+            .get(OPT_KEY_ROLLUP_KEYS)?
+            .split(',')
+            .map(|key| key.trim())
+            .filter(|key| !key.is_empty())
+            .collect::<HashSet<_>>();
+        let count = options.get(OPT_KEY_ROLLUP_COUNT).map(|count| count.trim());
+
+        let mut group_keys = Vec::with_capacity(agg.group_items.len());
+        let mut group_names = HashSet::with_capacity(agg.group_items.len());
+        for item in agg.group_items.iter() {
+            let ScalarExpr::BoundColumnRef(col) = &item.scalar else {
+                return None;
+            };
+            let ColumnEntry::BaseTableColumn(column) = metadata.column(col.column.index) else {
+                return None;
+            };
+            if column.table_index != scan.table_index {
+                return None;
+            }
+            group_keys.push(col.column.index);
+            group_names.insert(column.column_name.as_str());
+        }
+        if group_names != keys {
+            return None;
+        }
+
+        let count_column = count.and_then(|count| {
+            metadata
+                .columns_by_table_index(scan.table_index)
+                .into_iter()
+                .find(|column| column.name() == count)
+                .map(|column| column.index())
+        });
+        for item in agg.aggregate_functions.iter() {
+            let ScalarExpr::AggregateFunction(func) = &item.scalar else {
+                return None;
+            };
+            if func.distinct || !func.params.is_empty() || !func.sort_descs.is_empty() {
+                return None;
+            }
+            let supported = match func.func_name.to_lowercase().as_str() {
+                "sum" | "min" | "max" => {
+                    matches!(func.args.as_slice(), [ScalarExpr::BoundColumnRef(_)])
+                }
+                "count" => func.args.is_empty() && count_column.is_some(),
+                _ => false,
+            };
+            if !supported {
+                return None;
+            }
+        }
+
+        Some(PreAggMeta {
+            table_index: scan.table_index,
+            group_keys,
+            count_column,
+        })
+    }
+}
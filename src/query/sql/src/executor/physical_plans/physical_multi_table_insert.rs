@@ -24,7 +24,9 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_storages_common_table_meta::meta::TableMetaTimestamps;
 
+use crate::executor::explain::PlanStatsInfo;
 use crate::executor::PhysicalPlan;
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Duplicate {
     pub plan_id: u32,
@@ -32,6 +34,28 @@ pub struct Duplicate {
     pub n: usize,
 }
 
+/// Route each input row to the first target whose condition is true.
+/// Used by `INSERT FIRST` instead of `Duplicate` and `ChunkFilter`
+/// when every branch inserts into a single table, so each row is written once.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Scatter {
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    /// The conditions in the order of `WHEN` clauses, `ELSE` is a constant `true`.
+    pub conditions: Vec<RemoteExpr>,
+    /// The branch that rows matching the condition of the same position are routed to.
+    pub targets: Vec<usize>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Scatter {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Shuffle {
     pub plan_id: u32,
@@ -153,6 +153,14 @@ impl PhysicalPlanBuilder {
                 },
                 update_stream_meta: vec![],
                 deduplicated_label: unsafe { self.ctx.get_settings().get_deduplicate_label()? },
+                write_conflict_retry_count: self
+                    .ctx
+                    .get_settings()
+                    .get_write_conflict_retry_count()?,
+                write_conflict_backoff_ms: self
+                    .ctx
+                    .get_settings()
+                    .get_write_conflict_backoff_ms()?,
                 plan_id: u32::MAX,
                 recluster_info: None,
                 table_meta_timestamps: mutation_build_info.table_meta_timestamps,
@@ -230,6 +238,7 @@ impl PhysicalPlanBuilder {
                     kind: FragmentKind::Merge,
                     keys: vec![],
                     allow_adjust_parallelism: true,
+                    consistent_hash: false,
                     ignore_exchange: false,
                 });
             }
@@ -245,6 +254,14 @@ impl PhysicalPlanBuilder {
                 },
                 update_stream_meta: vec![],
                 deduplicated_label: unsafe { self.ctx.get_settings().get_deduplicate_label()? },
+                write_conflict_retry_count: self
+                    .ctx
+                    .get_settings()
+                    .get_write_conflict_retry_count()?,
+                write_conflict_backoff_ms: self
+                    .ctx
+                    .get_settings()
+                    .get_write_conflict_backoff_ms()?,
                 plan_id: u32::MAX,
                 recluster_info: None,
                 table_meta_timestamps: mutation_build_info.table_meta_timestamps,
@@ -460,6 +477,7 @@ impl PhysicalPlanBuilder {
                 kind: FragmentKind::Merge,
                 keys: vec![],
                 allow_adjust_parallelism: true,
+                consistent_hash: false,
                 ignore_exchange: false,
             })
         };
@@ -481,6 +499,8 @@ impl PhysicalPlanBuilder {
             },
             update_stream_meta: mutation_build_info.update_stream_meta,
             deduplicated_label: unsafe { self.ctx.get_settings().get_deduplicate_label()? },
+            write_conflict_retry_count: self.ctx.get_settings().get_write_conflict_retry_count()?,
+            write_conflict_backoff_ms: self.ctx.get_settings().get_write_conflict_backoff_ms()?,
             plan_id: u32::MAX,
             recluster_info: None,
             table_meta_timestamps: mutation_build_info.table_meta_timestamps,
@@ -565,6 +585,7 @@ pub fn build_block_id_shuffle_exchange(
         kind: FragmentKind::Normal,
         keys: vec![block_id_shuffle_key.as_remote_expr()],
         allow_adjust_parallelism: true,
+        consistent_hash: false,
         ignore_exchange: false,
     })
 }
@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::IndexType;
+
+/// The location of a block of the base table.
+pub type PartitionId = String;
+
+/// Incrementally refresh an aggregating index, the materialized view of databend.
+///
+/// The table scan of `delta_plan` only reads the `changed_partitions` of the base table,
+/// i.e. the blocks created or rewritten since the last refresh.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MvRefreshPartial {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    // The id of the aggregating index.
+    pub index_id: u64,
+    pub base_table_index: IndexType,
+    pub changed_partitions: Vec<PartitionId>,
+    pub delta_plan: Box<PhysicalPlan>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl MvRefreshPartial {
+    pub fn try_create(
+        index_id: u64,
+        delta_plan: PhysicalPlan,
+        changed_partitions: Vec<PartitionId>,
+    ) -> Result<Self> {
+        let mut base_table_index = None;
+        PhysicalPlan::traverse(
+            &delta_plan,
+            &mut |_| true,
+            &mut |plan| {
+                if let PhysicalPlan::TableScan(scan) = plan {
+                    base_table_index = scan.table_index;
+                }
+            },
+            &mut |_| {},
+        );
+        let base_table_index = base_table_index.ok_or_else(|| {
+            ErrorCode::Internal("The delta plan of refreshing index should scan the base table")
+        })?;
+
+        Ok(MvRefreshPartial {
+            plan_id: 0,
+            index_id,
+            base_table_index,
+            changed_partitions,
+            delta_plan: Box::new(delta_plan),
+            stat_info: None,
+        })
+    }
+
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.delta_plan.output_schema()
+    }
+}
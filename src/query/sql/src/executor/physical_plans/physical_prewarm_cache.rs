@@ -0,0 +1,41 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+
+/// Populate the query result cache for `queries` in background tasks.
+/// The submitting query does not wait for the queries, and no more queries are
+/// submitted once the results already cached exceed `max_bytes`.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PrewarmCache {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub queries: Vec<String>,
+    pub max_bytes: u64,
+}
+
+impl PrewarmCache {
+    pub fn create(queries: Vec<String>, max_bytes: u64) -> Self {
+        PrewarmCache {
+            plan_id: 0,
+            queries,
+            max_bytes,
+        }
+    }
+
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(DataSchemaRef::default())
+    }
+}
@@ -21,11 +21,13 @@ use databend_common_expression::RemoteExpr;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 
 use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::Zip;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::SExpr;
 use crate::IndexType;
+use crate::ScalarExpr;
 use crate::TypeCheck;
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -96,6 +98,27 @@ impl PhysicalPlanBuilder {
             }
         }
 
+        if project_set.flat_map && srf_exprs.len() == 1 {
+            return self.build_flat_map(input, &project_set.srfs[0], projections, stat_info);
+        }
+
+        // Multiple `unnest` are zipped together, with an optional ordinality column.
+        let is_zip = project_set.ordinality.is_some()
+            || (project_set.srfs.len() > 1
+                && project_set.srfs.iter().all(|item| {
+                    matches!(&item.scalar, ScalarExpr::FunctionCall(func) if func.func_name == "unnest")
+                }));
+        if is_zip {
+            return Ok(PhysicalPlan::Zip(Zip {
+                plan_id: 0,
+                input: Box::new(input),
+                arrays: srf_exprs,
+                with_ordinality: project_set.ordinality,
+                projections,
+                stat_info: Some(stat_info),
+            }));
+        }
+
         Ok(PhysicalPlan::ProjectSet(ProjectSet {
             plan_id: 0,
             input: Box::new(input),
@@ -64,6 +64,10 @@ impl RangeJoin {
 pub enum RangeJoinType {
     IEJoin,
     Merge,
+    // The two conditions bound the same key of one side by the lower and upper bounds of the
+    // other side, e.g. `a.ts BETWEEN b.start AND b.end`. The first condition is the lower
+    // bound, the intervals are probed with an interval tree.
+    Interval,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -105,6 +109,25 @@ impl PhysicalPlanBuilder {
         let left_schema = left_side.output_schema()?;
         let right_schema = right_side.output_schema()?;
 
+        let mut conditions = range_conditions
+            .iter()
+            .map(|scalar| {
+                resolve_range_condition(
+                    scalar,
+                    &left_schema,
+                    &right_schema,
+                    &left_prop,
+                    &right_prop,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let range_join_type = match range_join_type {
+            RangeJoinType::IEJoin if interval_conditions(&mut conditions) => {
+                RangeJoinType::Interval
+            }
+            range_join_type => range_join_type,
+        };
+
         let merged_schema = DataSchemaRefExt::create(
             left_side
                 .output_schema()?
@@ -119,18 +142,7 @@ impl PhysicalPlanBuilder {
             plan_id: 0,
             left: Box::new(left_side),
             right: Box::new(right_side),
-            conditions: range_conditions
-                .iter()
-                .map(|scalar| {
-                    resolve_range_condition(
-                        scalar,
-                        &left_schema,
-                        &right_schema,
-                        &left_prop,
-                        &right_prop,
-                    )
-                })
-                .collect::<Result<_>>()?,
+            conditions,
             other_conditions: other_conditions
                 .iter()
                 .map(|scalar| resolve_scalar(scalar, &merged_schema))
@@ -142,6 +154,36 @@ impl PhysicalPlanBuilder {
     }
 }
 
+/// Returns true if the two conditions bound the same key of one side with two keys of the
+/// other side, the conditions are reordered so the lower bound comes first.
+fn interval_conditions(conditions: &mut [RangeJoinCondition]) -> bool {
+    let [first, second] = conditions else {
+        return false;
+    };
+    let is_lower = |op: &str, key_on_left: bool| match op {
+        "gt" | "gte" => key_on_left,
+        _ => !key_on_left,
+    };
+    let key_on_left = if first.left_expr == second.left_expr {
+        true
+    } else if first.right_expr == second.right_expr {
+        false
+    } else {
+        return false;
+    };
+    match (
+        is_lower(&first.operator, key_on_left),
+        is_lower(&second.operator, key_on_left),
+    ) {
+        (true, false) => true,
+        (false, true) => {
+            conditions.swap(0, 1);
+            true
+        }
+        _ => false,
+    }
+}
+
 fn resolve_range_condition(
     expr: &ScalarExpr,
     left_schema: &DataSchemaRef,
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use itertools::Itertools;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::Join;
+use crate::plans::JoinType;
+
+/// Execute `input` only once and share the result blocks with all the pipeline workers.
+/// The blocks are stored in the query context with `cache_key`, the `Replicate` plans with
+/// the same `cache_key` in a query only read the stored blocks instead of executing `input` again.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Replicate {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub cache_key: u64,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Replicate {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Wrap the build side of an uncorrelated scalar subquery join in `Replicate`
+    /// if `enable_replicate_scalar_subquery` is set.
+    pub(crate) fn build_replicate(
+        &self,
+        join: &Join,
+        s_expr: &SExpr,
+        required: &ColumnSet,
+        input: PhysicalPlan,
+    ) -> Result<PhysicalPlan> {
+        if !self.ctx.get_settings().get_enable_replicate_scalar_subquery()?
+            || join.join_type != JoinType::LeftSingle
+            || join.from_correlated_subquery
+            // The replicated blocks are only shared in the local node.
+            || !self.ctx.get_cluster().is_empty()
+            || input.is_distributed_plan()
+        {
+            return Ok(input);
+        }
+
+        // The same subquery with the same required columns produces the same result.
+        let mut hasher = std::hash::DefaultHasher::new();
+        s_expr.hash(&mut hasher);
+        required
+            .iter()
+            .sorted()
+            .for_each(|index| index.hash(&mut hasher));
+
+        Ok(PhysicalPlan::Replicate(Replicate {
+            plan_id: 0,
+            input: Box::new(input),
+            cache_key: hasher.finish(),
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
+}
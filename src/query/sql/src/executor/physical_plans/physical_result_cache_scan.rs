@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::SystemTime;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+
+use crate::executor::PhysicalPlan;
+
+/// Read the result of a query from the result cache. The cache entry may expire after the
+/// query found it, so the expiry is checked again when the execution starts, and the plan
+/// of the query in `input` is executed instead if the entry has expired.
+///
+/// The `input` is not a child of the plan, it's never executed with the cache read.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ResultCacheScan {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub location: String,
+    pub num_rows: usize,
+    /// The schema of the cached blocks, the fields are named by the indexes of the result
+    /// columns of the query.
+    pub result_schema: DataSchemaRef,
+    /// The entry never expires if it's `None`.
+    pub cache_expiry: Option<SystemTime>,
+}
+
+impl ResultCacheScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.result_schema.clone())
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.cache_expiry
+            .is_some_and(|expiry| SystemTime::now() > expiry)
+    }
+}
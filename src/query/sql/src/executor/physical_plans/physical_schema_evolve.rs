@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::TableSchemaRef;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::TableScan;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::field_default_value;
+use crate::IndexType;
+
+/// Map the columns read with the schema of the data source to the schema of the table,
+/// the columns missing in the data source, e.g. added after the data is written,
+/// are filled with their default values.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SchemaEvolve {
+    /// A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub target_schema: DataSchemaRef,
+    /// The offsets in `target_schema` of the missing columns and their default values.
+    pub fill_defaults: Vec<(IndexType, RemoteExpr)>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl SchemaEvolve {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.target_schema.clone())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Wrap the `scan` with a `SchemaEvolve` if some columns of `table_schema` required by
+    /// the query are missing in the schema of the data source.
+    pub(crate) fn build_schema_evolve(
+        &self,
+        mut scan: TableScan,
+        table_schema: &TableSchemaRef,
+    ) -> Result<PhysicalPlan> {
+        let source_schema = scan.source.schema();
+        if scan
+            .name_mapping
+            .keys()
+            .all(|name| source_schema.has_field(name))
+        {
+            return Ok(PhysicalPlan::TableScan(scan));
+        }
+
+        let target_fields = TableScan::output_fields(table_schema.clone(), &scan.name_mapping)?;
+        let target_schema = DataSchemaRefExt::create(target_fields);
+        let mut fill_defaults = vec![];
+        for (name, index) in scan.name_mapping.iter() {
+            if source_schema.has_field(name) {
+                continue;
+            }
+            let field = table_schema.field_with_name(name)?;
+            let offset = target_schema.index_of(&index.to_string())?;
+            let scalar = field_default_value(self.ctx.clone(), field)?;
+            fill_defaults.push((offset, RemoteExpr::Constant {
+                span: None,
+                scalar,
+                data_type: DataType::from(field.data_type()),
+            }));
+        }
+        scan.name_mapping
+            .retain(|name, _| source_schema.has_field(name));
+
+        let stat_info = scan.stat_info.clone();
+        Ok(PhysicalPlan::SchemaEvolve(Box::new(SchemaEvolve {
+            plan_id: 0,
+            input: Box::new(PhysicalPlan::TableScan(scan)),
+            target_schema,
+            fill_defaults,
+            stat_info,
+        })))
+    }
+}
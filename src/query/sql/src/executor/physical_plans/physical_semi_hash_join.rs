@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::RemoteExpr;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::HashJoin;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::plans::JoinType;
+
+/// A hash join which only checks the existence of the probe keys in the build side.
+/// It is used by both `PhysicalPlan::SemiHashJoin` and `PhysicalPlan::AntiHashJoin`,
+/// the build side only records the distinct keys instead of the whole rows.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SemiHashJoin {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    // The indexes of probe side columns which will be output.
+    pub projections: ColumnSet,
+
+    pub build: Box<PhysicalPlan>,
+    pub probe: Box<PhysicalPlan>,
+    pub build_keys: Vec<RemoteExpr>,
+    pub probe_keys: Vec<RemoteExpr>,
+    pub join_type: JoinType,
+    // NULL keys are equal to each other, used by `EXCEPT` and `INTERSECT`.
+    pub is_null_equal: bool,
+    // Each build row only matches one probe row with the same key, used by `EXCEPT ALL` and
+    // `INTERSECT ALL`.
+    pub counting: bool,
+    pub output_schema: DataSchemaRef,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl SemiHashJoin {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.output_schema.clone())
+    }
+
+    pub fn is_anti(&self) -> bool {
+        self.join_type == JoinType::LeftAnti
+    }
+
+    /// Check if the hash join only needs to know whether the probe keys exist in the build side.
+    fn can_apply(join: &HashJoin) -> bool {
+        matches!(join.join_type, JoinType::LeftSemi | JoinType::LeftAnti)
+            && !join.build_keys.is_empty()
+            && join.non_equi_conditions.is_empty()
+            && join.is_null_equal.iter().all(|is_null_equal| !is_null_equal)
+            && join.marker_index.is_none()
+            && join.probe_to_build.is_empty()
+            && join.single_to_inner.is_none()
+            && join.build_side_cache_info.is_none()
+            && !join.need_hold_hash_table
+            // Exchanges between the two sides are only handled by `HashJoin`.
+            && !join.probe.is_distributed_plan()
+            && !join.build.is_distributed_plan()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Convert a LEFT SEMI/ANTI `HashJoin` without other conditions to a `SemiHashJoin`
+    /// or `AntiHashJoin` if `enable_semi_anti_hash_join` is set.
+    pub(crate) fn build_semi_hash_join(&self, join: HashJoin) -> Result<PhysicalPlan> {
+        if !self.ctx.get_settings().get_enable_semi_anti_hash_join()?
+            || !SemiHashJoin::can_apply(&join)
+        {
+            return Ok(PhysicalPlan::HashJoin(join));
+        }
+
+        // `HashJoin` applies `probe_projections` to the probe block first and then `projections`
+        // to the projected block, merge them into the indexes of the probe side schema.
+        let probe_schema = join.probe.output_schema()?;
+        let mut projections = ColumnSet::new();
+        let mut projected_index = 0;
+        for index in 0..probe_schema.num_fields() {
+            if join.probe_projections.contains(&index) {
+                if join.projections.contains(&projected_index) {
+                    projections.insert(index);
+                }
+                projected_index += 1;
+            }
+        }
+
+        let semi_join = SemiHashJoin {
+            plan_id: 0,
+            projections,
+            build: join.build,
+            probe: join.probe,
+            build_keys: join.build_keys,
+            probe_keys: join.probe_keys,
+            join_type: join.join_type,
+            is_null_equal: false,
+            counting: false,
+            output_schema: join.output_schema,
+            stat_info: join.stat_info,
+        };
+
+        Ok(match semi_join.is_anti() {
+            true => PhysicalPlan::AntiHashJoin(semi_join),
+            false => PhysicalPlan::SemiHashJoin(semi_join),
+        })
+    }
+}
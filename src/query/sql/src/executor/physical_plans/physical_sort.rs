@@ -23,6 +23,10 @@ use itertools::Itertools;
 
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::physical_plans::common::SortDesc;
+use crate::executor::physical_plans::physical_cte_materialization::VARIABLE_VALUE_BYTES;
+use crate::executor::physical_plans::ClusterSort;
+use crate::executor::physical_plans::SortedMerge;
+use crate::executor::physical_plans::SpillSort;
 use crate::executor::physical_plans::WindowPartition;
 use crate::executor::physical_plans::WindowPartitionTopN;
 use crate::executor::physical_plans::WindowPartitionTopNFunc;
@@ -45,6 +49,8 @@ pub struct Sort {
     /// It's [None] if the sorting plan is in single node mode.
     pub after_exchange: Option<bool>,
     pub pre_projection: Option<Vec<IndexType>>,
+    /// The number of tasks to sort a large block in parallel, computed from `max_threads`.
+    pub parallelism: usize,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -124,6 +130,7 @@ impl PhysicalPlanBuilder {
         };
 
         let input_plan = self.build(s_expr.child(0)?, required).await?;
+        let input_plan = self.build_fuzzy_match(s_expr, sort, input_plan, &stat_info)?;
 
         let order_by = sort
             .items
@@ -164,6 +171,63 @@ impl PhysicalPlanBuilder {
         };
 
         // 2. Build physical plan.
+        if sort.after_exchange == Some(true) && self.is_sorted_streams(&input_plan)? {
+            return Ok(PhysicalPlan::SortedMerge(Box::new(SortedMerge {
+                plan_id: 0,
+                inputs: vec![input_plan],
+                order_by,
+                stat_info: Some(stat_info),
+            })));
+        }
+
+        // The blocks of a table clustered by the sort keys only need to be merged in single node mode.
+        if sort.after_exchange.is_none()
+            && self.is_sorted_by_cluster_keys(&input_plan, &order_by)?
+        {
+            return Ok(PhysicalPlan::ClusterSort(ClusterSort {
+                plan_id: 0,
+                input: Box::new(input_plan),
+                order_by,
+                limit: sort.limit,
+                is_already_sorted: true,
+                stat_info: Some(stat_info),
+            }));
+        }
+
+        // The sort in single node mode without limit keeps all the rows in memory, spill them
+        // if `estimated_rows * avg_row_bytes` exceeds the setting `sort_spill_threshold_bytes`.
+        let spill_threshold_bytes = self.ctx.get_settings().get_sort_spill_threshold_bytes()?;
+        if sort.after_exchange.is_none() && sort.limit.is_none() && spill_threshold_bytes > 0 {
+            let avg_row_bytes: usize = input_plan
+                .output_schema()?
+                .fields()
+                .iter()
+                .map(|field| {
+                    field
+                        .data_type()
+                        .remove_nullable()
+                        .numeric_byte_size()
+                        .unwrap_or(VARIABLE_VALUE_BYTES)
+                })
+                .sum();
+            if stat_info.estimated_rows * avg_row_bytes as f64 > spill_threshold_bytes as f64 {
+                let spill_location = format!(
+                    "_query_spill/{}/{}",
+                    self.ctx.get_tenant().tenant_name(),
+                    self.ctx.get_id()
+                );
+                return Ok(PhysicalPlan::SpillSort(Box::new(SpillSort {
+                    plan_id: 0,
+                    input: Box::new(input_plan),
+                    order_by,
+                    spill_threshold_bytes,
+                    spill_location,
+                    stat_info: Some(stat_info),
+                })));
+            }
+        }
+
+        let parallelism = self.ctx.get_settings().get_max_threads()? as usize;
         Ok(PhysicalPlan::Sort(Sort {
             plan_id: 0,
             input: Box::new(input_plan),
@@ -171,6 +235,7 @@ impl PhysicalPlanBuilder {
             limit: sort.limit,
             after_exchange: sort.after_exchange,
             pre_projection,
+            parallelism,
             stat_info: Some(stat_info),
         }))
     }
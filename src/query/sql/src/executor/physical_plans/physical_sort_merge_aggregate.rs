@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::AggregateFunctionDesc;
+use crate::executor::PhysicalPlan;
+use crate::IndexType;
+
+/// Aggregate the rows sorted by the group by columns. The rows of a group are adjacent, so
+/// only the states of the current group are kept, they are output when the group key
+/// changes, and no hash table is built.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SortMergeAggregate {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub group_by: Vec<IndexType>,
+    pub agg_funcs: Vec<AggregateFunctionDesc>,
+    pub group_by_display: Vec<String>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl SortMergeAggregate {
+    /// The same as the output schema of `AggregateFinal`.
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = Vec::with_capacity(self.agg_funcs.len() + self.group_by.len());
+        for agg in self.agg_funcs.iter() {
+            let data_type = agg.sig.return_type.clone();
+            fields.push(DataField::new(&agg.output_column.to_string(), data_type));
+        }
+        for id in self.group_by.iter() {
+            let data_type = input_schema
+                .field_with_name(&id.to_string())?
+                .data_type()
+                .clone();
+            fields.push(DataField::new(&id.to_string(), data_type));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+/// The columns the output of `plan` is sorted by, in order. It's empty if the output is not
+/// known to be sorted.
+pub(crate) fn output_sorted(plan: &PhysicalPlan) -> Vec<IndexType> {
+    match plan {
+        PhysicalPlan::ClusterSort(plan) => plan.order_by.iter().map(|d| d.order_by).collect(),
+        PhysicalPlan::SortedMerge(plan) => plan.order_by.iter().map(|d| d.order_by).collect(),
+        PhysicalPlan::SpillSort(plan) => plan.order_by.iter().map(|d| d.order_by).collect(),
+        // The sort before the exchange only sorts the rows of one node.
+        PhysicalPlan::Sort(plan) if plan.after_exchange != Some(false) => {
+            plan.order_by.iter().map(|d| d.order_by).collect()
+        }
+        // Removing rows or adding columns keeps the order of the rows.
+        PhysicalPlan::Limit(plan) => output_sorted(&plan.input),
+        PhysicalPlan::Filter(plan) => output_sorted(&plan.input),
+        PhysicalPlan::EvalScalar(plan) => output_sorted(&plan.input),
+        _ => vec![],
+    }
+}
+
+/// The rows of each group are adjacent in the output of `plan`, if it's sorted by the
+/// group by columns in any order before any other column.
+pub(crate) fn is_sorted_by_group(plan: &PhysicalPlan, group_by: &[IndexType]) -> bool {
+    let sorted = output_sorted(plan);
+    !group_by.is_empty()
+        && sorted.len() >= group_by.len()
+        && group_by
+            .iter()
+            .all(|index| sorted[..group_by.len()].contains(index))
+}
@@ -0,0 +1,187 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::RemoteExpr;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::Sort;
+use crate::ScalarExpr;
+use crate::TypeCheck;
+
+/// Join the two sides which are both sorted by the equi join keys by merging them, without
+/// building a hash table.
+///
+/// The rows with NULL keys never match, and every row of the left side is joined with all the
+/// rows of the right side with the same key.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SortMergeJoin {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub left: Box<PhysicalPlan>,
+    pub right: Box<PhysicalPlan>,
+    pub left_keys: Vec<RemoteExpr>,
+    pub right_keys: Vec<RemoteExpr>,
+    // The order of each key in both the sides, `(asc, nulls_first)`.
+    pub key_orders: Vec<(bool, bool)>,
+    // Only support inner join and left join.
+    pub join_type: JoinType,
+    pub output_schema: DataSchemaRef,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl SortMergeJoin {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.output_schema.clone())
+    }
+}
+
+/// Returns the orders of the equi join keys if both the children of the join are sorted by
+/// them in the same order, then the children can be merged.
+pub fn sorted_join_keys(join: &Join, s_expr: &SExpr) -> Result<Option<Vec<(bool, bool)>>> {
+    if !matches!(join.join_type, JoinType::Inner | JoinType::Left)
+        || join.equi_conditions.is_empty()
+        || !join.non_equi_conditions.is_empty()
+        || join.build_side_cache_info.is_some()
+        || join.equi_conditions.iter().any(|c| c.is_null_equal)
+    {
+        return Ok(None);
+    }
+
+    let (RelOperator::Sort(left), RelOperator::Sort(right)) =
+        (s_expr.child(0)?.plan(), s_expr.child(1)?.plan())
+    else {
+        return Ok(None);
+    };
+    // The partial sort before the exchange does not sort the whole side.
+    let is_sorted = |sort: &Sort| {
+        sort.window_partition.is_none()
+            && sort.after_exchange != Some(false)
+            && sort.items.len() >= join.equi_conditions.len()
+    };
+    if !is_sorted(left) || !is_sorted(right) {
+        return Ok(None);
+    }
+
+    let mut orders = Vec::with_capacity(join.equi_conditions.len());
+    for (condition, (left_item, right_item)) in join
+        .equi_conditions
+        .iter()
+        .zip(left.items.iter().zip(right.items.iter()))
+    {
+        let (ScalarExpr::BoundColumnRef(left_column), ScalarExpr::BoundColumnRef(right_column)) =
+            (&condition.left, &condition.right)
+        else {
+            return Ok(None);
+        };
+        if left_column.column.index != left_item.index
+            || right_column.column.index != right_item.index
+            || left_item.asc != right_item.asc
+            || left_item.nulls_first != right_item.nulls_first
+        {
+            return Ok(None);
+        }
+        // The keys of both the sides are compared in the same comparable format.
+        let data_type = left_column.column.data_type.remove_nullable();
+        if data_type != right_column.column.data_type.remove_nullable()
+            || !matches!(
+                data_type,
+                DataType::Boolean
+                    | DataType::Number(_)
+                    | DataType::Decimal(_)
+                    | DataType::Timestamp
+                    | DataType::Date
+                    | DataType::String
+                    | DataType::Binary
+            )
+        {
+            return Ok(None);
+        }
+        orders.push((left_item.asc, left_item.nulls_first));
+    }
+    Ok(Some(orders))
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_sort_merge_join(
+        &mut self,
+        join: &Join,
+        s_expr: &SExpr,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+        key_orders: Vec<(bool, bool)>,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let left_side = self.build(s_expr.child(0)?, left_required).await?;
+        let right_side = self.build(s_expr.child(1)?, right_required).await?;
+
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+        let resolve = |scalar: &ScalarExpr, schema: &DataSchemaRef| -> Result<RemoteExpr> {
+            let expr = scalar
+                .type_check(schema.as_ref())?
+                .project_column_ref(|index| schema.index_of(&index.to_string()).unwrap());
+            Ok(expr.as_remote_expr())
+        };
+        let left_keys = join
+            .equi_conditions
+            .iter()
+            .map(|condition| resolve(&condition.left, &left_schema))
+            .collect::<Result<_>>()?;
+        let right_keys = join
+            .equi_conditions
+            .iter()
+            .map(|condition| resolve(&condition.right, &right_schema))
+            .collect::<Result<_>>()?;
+
+        // The right side of left join is NULL for the unmatched rows.
+        let mut fields = left_schema.fields().clone();
+        fields.extend(
+            right_schema
+                .fields()
+                .iter()
+                .map(|field| match join.join_type {
+                    JoinType::Left => {
+                        DataField::new(field.name(), field.data_type().wrap_nullable())
+                    }
+                    _ => field.clone(),
+                }),
+        );
+
+        Ok(PhysicalPlan::SortMergeJoin(SortMergeJoin {
+            plan_id: 0,
+            left: Box::new(left_side),
+            right: Box::new(right_side),
+            left_keys,
+            right_keys,
+            key_orders,
+            join_type: join.join_type.clone(),
+            output_schema: DataSchemaRefExt::create(fields),
+            stat_info: Some(stat_info),
+        }))
+    }
+}
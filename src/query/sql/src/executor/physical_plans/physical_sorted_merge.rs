@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_pipeline_transforms::processors::sort::utils::ORDER_COL_NAME;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::common::SortDesc;
+use crate::executor::physical_plans::Exchange;
+use crate::executor::physical_plans::FragmentKind;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+
+/// Merge the sorted streams of the inputs into a globally sorted stream.
+///
+/// Each output stream of every input must be sorted by `order_by`, e.g. the streams
+/// received by the coordinator from the cluster nodes in distributed sort.
+/// The order column appended by the sort of the cluster nodes is removed.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SortedMerge {
+    /// A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    /// The inputs have the same output schema.
+    pub inputs: Vec<PhysicalPlan>,
+    pub order_by: Vec<SortDesc>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl SortedMerge {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.inputs[0].output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        if fields
+            .last()
+            .is_some_and(|field| field.name() == ORDER_COL_NAME)
+        {
+            fields.pop();
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// The input of the sort after a merge exchange is the streams of the cluster nodes,
+    /// which are already sorted and only need to be merged by a `SortedMerge`.
+    pub(crate) fn is_sorted_streams(&self, input: &PhysicalPlan) -> Result<bool> {
+        let is_merge_exchange = matches!(
+            input,
+            PhysicalPlan::Exchange(Exchange {
+                kind: FragmentKind::Merge,
+                ..
+            })
+        );
+        Ok(is_merge_exchange && self.ctx.get_settings().get_enable_sorted_merge()?)
+    }
+}
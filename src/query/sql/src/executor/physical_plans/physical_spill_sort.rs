@@ -0,0 +1,45 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::common::SortDesc;
+use crate::executor::PhysicalPlan;
+
+/// Sort the rows which are not expected to fit in memory, used instead of `Sort` in single
+/// node mode without limit.
+///
+/// The input is sorted in chunks of at most `spill_threshold_bytes`, every sorted chunk is
+/// spilled to `spill_location` as a run, then the runs are merged with only the leading
+/// block of each run in memory.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SpillSort {
+    /// A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub order_by: Vec<SortDesc>,
+    pub spill_threshold_bytes: u64,
+    /// The location prefix of the spilled runs in the spill storage.
+    pub spill_location: String,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl SpillSort {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+
+/// Stream the result blocks of `input` into the files of `path` in the stage of the current user.
+/// The blocks are compressed while writing, and the output is split into numbered parts
+/// once a file reaches `max_file_size`.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct StreamOutput {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub path: String,
+    pub format: FileFormatParams,
+    pub compression: StageFileCompression,
+    pub max_file_size: Option<u64>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl StreamOutput {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(DataSchemaRefExt::create(vec![
+            DataField::new("rows_unloaded", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("input_bytes", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("output_bytes", DataType::Number(NumberDataType::UInt64)),
+        ]))
+    }
+
+    /// The file format params with the compression of the output applied.
+    pub fn file_format_params(&self) -> Result<FileFormatParams> {
+        let mut params = self.format.clone();
+        match &mut params {
+            FileFormatParams::Csv(v) => v.compression = self.compression,
+            FileFormatParams::Tsv(v) => v.compression = self.compression,
+            FileFormatParams::NdJson(v) => v.compression = self.compression,
+            FileFormatParams::Json(v) => v.compression = self.compression,
+            FileFormatParams::Xml(v) => v.compression = self.compression,
+            // The parquet writer always compresses the pages with zstd.
+            FileFormatParams::Parquet(_)
+                if matches!(
+                    self.compression,
+                    StageFileCompression::Zstd | StageFileCompression::Auto
+                ) => {}
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Compression {:?} is not supported for {:?} output",
+                    self.compression,
+                    params.get_type()
+                )));
+            }
+        }
+        Ok(params)
+    }
+}
@@ -17,7 +17,10 @@ use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::tokenize_sql;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::plan::sample_block;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::Filters;
 use databend_common_catalog::plan::InternalColumn;
@@ -43,29 +46,41 @@ use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserPrivilegeType;
 use itertools::Itertools;
 use rand::distributions::Bernoulli;
 use rand::distributions::Distribution;
 use rand::thread_rng;
 
+use crate::binder::contain_subquery;
+use crate::binder::ColumnBindingBuilder;
 use crate::binder::INTERNAL_COLUMN_FACTORY;
 use crate::executor::cast_expr_to_non_null_boolean;
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::physical_plans::AddStreamColumn;
+use crate::executor::physical_plans::Filter;
+use crate::executor::physical_plans::MaskApply;
+use crate::executor::physical_plans::RowAccessPolicy;
 use crate::executor::table_read_plan::ToReadDataSourcePlan;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::plans::FunctionCall;
 use crate::BaseTableColumn;
+use crate::BindContext;
 use crate::ColumnEntry;
 use crate::ColumnSet;
 use crate::DerivedColumn;
 use crate::IndexType;
 use crate::Metadata;
+use crate::NameResolutionContext;
 use crate::ScalarExpr;
+use crate::TableEntry;
 use crate::TableInternalColumn;
 use crate::TypeCheck;
+use crate::TypeChecker;
 use crate::VirtualColumn;
+use crate::Visibility;
 use crate::DUMMY_COLUMN_INDEX;
 use crate::DUMMY_TABLE_INDEX;
 
@@ -80,6 +95,14 @@ pub struct TableScan {
 
     pub table_index: Option<IndexType>,
     pub stat_info: Option<PlanStatsInfo>,
+
+    // Read `prefetch_depth` blocks ahead of the one being processed, so the I/O overlaps
+    // with the processing. Only set for full table scans, which read all the blocks in order.
+    pub speculative_prefetch: bool,
+    pub prefetch_depth: usize,
+
+    // The index the scan is forced to read by an index hint of the query.
+    pub force_index: Option<String>,
 }
 
 impl TableScan {
@@ -116,9 +139,28 @@ impl PhysicalPlanBuilder {
     pub(crate) async fn build_table_scan(
         &mut self,
         scan: &crate::plans::Scan,
-        required: ColumnSet,
+        mut required: ColumnSet,
         stat_info: PlanStatsInfo,
     ) -> Result<PhysicalPlan> {
+        // The row access policy must see the rows before any predicate of the query does,
+        // so nothing is pushed down into the scan, the predicates are evaluated above the policy.
+        let mut scan = scan.clone();
+        let mut deferred_predicates = vec![];
+        let row_access_policy = self.bind_row_access_policy(scan.table_index)?;
+        if let Some(policy) = &row_access_policy {
+            if let Some(prewhere) = scan.prewhere.take() {
+                required.extend(prewhere.prewhere_columns);
+                deferred_predicates = prewhere.predicates;
+            }
+            scan.push_down_predicates = None;
+            scan.limit = None;
+            scan.order_by = None;
+            scan.agg_index = None;
+            let policy_columns = policy.used_columns();
+            scan.columns.extend(policy_columns.iter());
+            required.extend(policy_columns);
+        }
+
         // 1. Prune unused Columns.
         // Some table may not have any column,
         // e.g. `system.sync_crash_me`
@@ -242,9 +284,17 @@ impl PhysicalPlanBuilder {
                 self.dry_run,
             )
             .await?;
-        if let Some(sample) = scan.sample
+        if let Some(sample) = &scan.sample
             && !table.use_own_sample_block()
         {
+            if let Some((probability, seed)) =
+                PushDownInfo::system_sample_of_push_downs(&source.push_downs)
+            {
+                source
+                    .parts
+                    .partitions
+                    .retain(|part| sample_block(&part.hash(), probability, seed));
+            }
             if let Some(block_sample_value) = sample.block_level {
                 if block_sample_value > 100.0 {
                     return Err(ErrorCode::SyntaxException(format!(
@@ -285,15 +335,123 @@ impl PhysicalPlanBuilder {
             metadata.set_table_source(scan.table_index, source.clone());
         }
 
-        let mut plan = PhysicalPlan::TableScan(TableScan {
+        // The data mask policies are applied by a `MaskApply` above the scan,
+        // so that the masking can be skipped for the users with `UNMASKED` privilege.
+        let has_masked_column = name_mapping.values().any(|index| {
+            matches!(
+                metadata.column(*index),
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    masking_policy: Some(_),
+                    ..
+                })
+            )
+        });
+        let mut masking_rules = vec![];
+        if has_masked_column
+            && let Some(data_mask_policy) = source.data_mask_policy.take()
+            && !self.has_unmasked_privilege(table_entry).await?
+        {
+            let source_schema = source.schema();
+            for (field_index, expr) in data_mask_policy {
+                let name = source_schema.field(field_index).name();
+                if let Some(index) = name_mapping.get(name) {
+                    masking_rules.push((*index, expr));
+                }
+            }
+        }
+
+        let push_downs = source.push_downs.clone();
+        let prefetch_depth = self.ctx.get_settings().get_scan_prefetch_depth()? as usize;
+        let speculative_prefetch = prefetch_depth > 0
+            && !scan.is_lazy_table
+            && push_downs.as_ref().is_none_or(|push_downs| {
+                push_downs.filters.is_none()
+                    && push_downs.prewhere.is_none()
+                    && push_downs.limit.is_none()
+                    && push_downs.agg_index.is_none()
+            });
+        // The optimizer only chose among the hinted indexes, if none of them is applied
+        // the hint is not applicable to the query.
+        let force_index = match metadata.get_table_index_hint(&scan.table_index) {
+            Some(index_hint) => {
+                let applied = scan.agg_index.as_ref().and_then(|agg_index| {
+                    index_hint
+                        .agg_indexes
+                        .iter()
+                        .find(|(index_id, _)| *index_id == agg_index.index_id)
+                });
+                if applied.is_none() {
+                    self.ctx.push_warning(format!(
+                        "Index hint '{}' is not applicable to the scan of table '{}'",
+                        index_hint.hint,
+                        metadata.table(scan.table_index).name()
+                    ));
+                }
+                applied.map(|(_, index_name)| index_name.clone())
+            }
+            None => None,
+        };
+        let scan_plan = TableScan {
             plan_id: 0,
             scan_id: scan.scan_id,
             name_mapping,
             source: Box::new(source),
             table_index: Some(scan.table_index),
-            stat_info: Some(stat_info),
+            stat_info: Some(stat_info.clone()),
             internal_column,
-        });
+            speculative_prefetch,
+            prefetch_depth: if speculative_prefetch {
+                prefetch_depth
+            } else {
+                0
+            },
+            force_index,
+        };
+        let mut plan = self.build_schema_evolve(scan_plan, &table_schema)?;
+
+        plan = self.build_block_sample(plan, scan.sample.as_ref(), stat_info.clone());
+
+        if let Some(policy) = row_access_policy {
+            // The result depends on the current user, it can not be shared by others.
+            self.ctx.set_cacheable(false);
+            let input_schema = plan.output_schema()?;
+            let policy_filter = policy
+                .type_check(input_schema.as_ref())?
+                .project_column_ref(|index| input_schema.index_of(&index.to_string()).unwrap());
+            let policy_filter = cast_expr_to_non_null_boolean(policy_filter)?;
+            let (policy_filter, _) =
+                ConstantFolder::fold(&policy_filter, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            plan = PhysicalPlan::RowAccessPolicy(RowAccessPolicy {
+                plan_id: 0,
+                input: Box::new(plan),
+                policy_filter: policy_filter.as_remote_expr(),
+                stat_info: Some(stat_info.clone()),
+            });
+
+            if !deferred_predicates.is_empty() {
+                let predicates = deferred_predicates
+                    .iter()
+                    .map(|scalar| {
+                        let expr = scalar
+                            .type_check(input_schema.as_ref())?
+                            .project_column_ref(|index| {
+                                input_schema.index_of(&index.to_string()).unwrap()
+                            });
+                        let expr = cast_expr_to_non_null_boolean(expr)?;
+                        let (expr, _) =
+                            ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                        Ok(expr.as_remote_expr())
+                    })
+                    .collect::<Result<_>>()?;
+                plan = PhysicalPlan::Filter(Filter {
+                    plan_id: 0,
+                    projections: (0..input_schema.num_fields()).collect(),
+                    input: Box::new(plan),
+                    predicates,
+                    stat_info: Some(stat_info.clone()),
+                });
+            }
+        }
 
         // Update stream columns if needed.
         if scan.update_stream_columns {
@@ -305,9 +463,107 @@ impl PhysicalPlanBuilder {
             )?));
         }
 
+        if !masking_rules.is_empty() {
+            plan = PhysicalPlan::MaskApply(MaskApply {
+                plan_id: 0,
+                input: Box::new(plan),
+                masking_rules,
+                stat_info: Some(stat_info),
+            });
+        }
+
         Ok(plan)
     }
 
+    /// Bind the row access policy of the table, if any, to the columns of the table.
+    fn bind_row_access_policy(&self, table_index: IndexType) -> Result<Option<ScalarExpr>> {
+        let table = self.metadata.read().table(table_index).table();
+        let Some(policy) = self.ctx.get_row_access_policy(table.as_ref())? else {
+            return Ok(None);
+        };
+
+        let mut bind_context = BindContext::new();
+        let columns = self.metadata.read().columns_by_table_index(table_index);
+        for column in columns {
+            if let ColumnEntry::BaseTableColumn(BaseTableColumn {
+                column_index,
+                column_name,
+                data_type,
+                path_indices: None,
+                ..
+            }) = column
+            {
+                let column_binding = ColumnBindingBuilder::new(
+                    column_name,
+                    column_index,
+                    Box::new((&data_type).into()),
+                    Visibility::Visible,
+                )
+                .table_name(Some(table.name().to_string()))
+                .table_index(Some(table_index))
+                .build();
+                bind_context.add_column_binding(column_binding);
+            }
+        }
+
+        let settings = self.ctx.get_settings();
+        let tokens = tokenize_sql(&policy)?;
+        let ast_expr = parse_expr(&tokens, settings.get_sql_dialect()?)?;
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let mut type_checker = TypeChecker::try_create(
+            &mut bind_context,
+            self.ctx.clone(),
+            &name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            true,
+        )?;
+        let (scalar, _) = *type_checker.resolve(&ast_expr)?;
+        if contain_subquery(&scalar) {
+            return Err(ErrorCode::SemanticError(format!(
+                "row access policy of table {} can not contain subquery",
+                table.name()
+            )));
+        }
+        Ok(Some(scalar))
+    }
+
+    /// Check if the current user is granted to read the raw values of the masked columns
+    /// in the table, by the table name or the table id.
+    async fn has_unmasked_privilege(&self, table_entry: &TableEntry) -> Result<bool> {
+        let catalog_name = table_entry.catalog();
+        let db_name = table_entry.database();
+        let table = table_entry.table();
+        let by_name = GrantObject::Table(
+            catalog_name.to_string(),
+            db_name.to_string(),
+            table.name().to_string(),
+        );
+        if self
+            .ctx
+            .validate_privilege(&by_name, UserPrivilegeType::Unmasked, false)
+            .await
+            .is_ok()
+        {
+            return Ok(true);
+        }
+
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let db = catalog
+            .get_database(&self.ctx.get_tenant(), db_name)
+            .await?;
+        let by_id = GrantObject::TableById(
+            catalog_name.to_string(),
+            db.get_db_info().database_id.db_id,
+            table.get_id(),
+        );
+        Ok(self
+            .ctx
+            .validate_privilege(&by_id, UserPrivilegeType::Unmasked, false)
+            .await
+            .is_ok())
+    }
+
     pub(crate) async fn build_dummy_table_scan(&mut self) -> Result<PhysicalPlan> {
         let catalogs = CatalogManager::instance();
         let table = catalogs
@@ -332,6 +588,9 @@ impl PhysicalPlanBuilder {
                 estimated_rows: 1.0,
             }),
             internal_column: None,
+            speculative_prefetch: false,
+            prefetch_depth: 0,
+            force_index: None,
         }))
     }
 
@@ -467,6 +726,12 @@ impl PhysicalPlanBuilder {
                 let filter = filter.as_remote_expr();
                 let virtual_column_ids =
                     self.build_prewhere_virtual_column_ids(&prewhere.prewhere_columns);
+                let row_selector_column =
+                    if self.ctx.get_settings().get_enable_prewhere_row_selector()? {
+                        Self::build_prewhere_row_selector(&metadata, table_schema, &predicate)
+                    } else {
+                        None
+                    };
 
                 Ok::<PrewhereInfo, ErrorCode>(PrewhereInfo {
                     output_columns,
@@ -474,6 +739,7 @@ impl PhysicalPlanBuilder {
                     remain_columns,
                     filter,
                     virtual_column_ids,
+                    row_selector_column,
                 })
             })
             .transpose()?;
@@ -559,6 +825,30 @@ impl PhysicalPlanBuilder {
         }
     }
 
+    /// If the prewhere predicate is a boolean column of the table, the column already
+    /// holds the result of the predicate and can be used as the row selector.
+    fn build_prewhere_row_selector(
+        metadata: &Metadata,
+        table_schema: &TableSchema,
+        predicate: &ScalarExpr,
+    ) -> Option<FieldIndex> {
+        let ScalarExpr::BoundColumnRef(column_ref) = predicate else {
+            return None;
+        };
+        if column_ref.column.data_type.remove_nullable() != DataType::Boolean {
+            return None;
+        }
+        match metadata.column(column_ref.column.index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                column_name,
+                path_indices: None,
+                virtual_expr: None,
+                ..
+            }) => table_schema.index_of(column_name).ok(),
+            _ => None,
+        }
+    }
+
     fn build_virtual_column(&self, indices: &ColumnSet) -> Option<VirtualColumnInfo> {
         let mut source_column_ids = HashSet::new();
         let mut column_and_indices = Vec::new();
@@ -0,0 +1,139 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::Scalar;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::AggregateMode;
+use crate::plans::RelOperator;
+use crate::IndexType;
+use crate::ScalarExpr;
+
+/// The compression of the sketch, the same as the `quantile_tdigest` aggregate function.
+const TDIGEST_COMPRESSION: f64 = 100.0;
+
+/// Compute `APPROX_PERCENTILE_CONT(column, percentile)` in a single pass over the input
+/// with a t-digest sketch. It outputs one row.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TDigestAgg {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub column: IndexType,
+    pub percentile: f64,
+    pub compression: f64,
+    pub output_col: IndexType,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl TDigestAgg {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut data_type = DataType::Number(NumberDataType::Float64);
+        if input_schema
+            .field_with_name(&self.column.to_string())?
+            .data_type()
+            .is_nullable_or_null()
+        {
+            data_type = data_type.wrap_nullable();
+        }
+        Ok(DataSchemaRefExt::create(vec![DataField::new(
+            &self.output_col.to_string(),
+            data_type,
+        )]))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Build a `TDigestAgg` for the final aggregate of
+    /// `SELECT APPROX_PERCENTILE_CONT(column, percentile) FROM ...`, the partial aggregate
+    /// below it (and the exchange between them) is replaced.
+    pub(crate) async fn build_tdigest_agg(
+        &mut self,
+        s_expr: &SExpr,
+        (column, percentile, output_col): (IndexType, f64, IndexType),
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let mut child = s_expr.child(0)?;
+        if let RelOperator::Exchange(_) = child.plan() {
+            child = child.child(0)?;
+        }
+        if !matches!(
+            child.plan(),
+            RelOperator::Aggregate(agg) if agg.mode == AggregateMode::Partial
+        ) {
+            return Err(ErrorCode::Internal(
+                "TDigestAgg expects a partial aggregate as the input of the final aggregate",
+            ));
+        }
+
+        let input = self
+            .build(child.child(0)?, ColumnSet::from([column]))
+            .await?;
+
+        Ok(PhysicalPlan::TDigestAgg(TDigestAgg {
+            plan_id: 0,
+            input: Box::new(input),
+            column,
+            percentile,
+            compression: TDIGEST_COMPRESSION,
+            output_col,
+            stat_info: Some(stat_info),
+        }))
+    }
+}
+
+/// Returns the argument column, the percentile and the output column if the aggregate only
+/// computes `approx_percentile_cont(column, percentile)` without group by.
+pub(crate) fn tdigest_argument(
+    agg: &crate::plans::Aggregate,
+) -> Option<(IndexType, f64, IndexType)> {
+    if !agg.group_items.is_empty() || agg.grouping_sets.is_some() {
+        return None;
+    }
+    let [item] = agg.aggregate_functions.as_slice() else {
+        return None;
+    };
+    let ScalarExpr::AggregateFunction(func) = &item.scalar else {
+        return None;
+    };
+    if !func
+        .func_name
+        .eq_ignore_ascii_case("approx_percentile_cont")
+        || func.distinct
+    {
+        return None;
+    }
+    let [ScalarExpr::BoundColumnRef(column)] = func.args.as_slice() else {
+        return None;
+    };
+    let [Scalar::Number(NumberScalar::Float64(percentile))] = func.params.as_slice() else {
+        return None;
+    };
+    Some((column.column.index, percentile.0, item.index))
+}
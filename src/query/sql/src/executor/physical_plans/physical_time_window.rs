@@ -0,0 +1,244 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::AggregateFunctionDesc;
+use crate::executor::physical_plans::AggregateFunctionSignature;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::SExpr;
+use crate::plans::ScalarItem;
+use crate::plans::TimeWindowKind;
+use crate::plans::WindowAgg;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::ScalarExpr;
+
+/// Aggregate the rows by fixed-size, non-overlapping windows of `time_col`. The window of a
+/// row starts at the largest multiple of `window_size` microseconds not after its timestamp.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TumblingWindow {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub time_col: IndexType,
+    // In microseconds.
+    pub window_size: i64,
+    pub output_window_start: IndexType,
+    pub output_window_end: IndexType,
+    pub agg_funcs: Vec<AggregateFunctionDesc>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl TumblingWindow {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(time_window_schema(
+            self.output_window_start,
+            self.output_window_end,
+            &self.agg_funcs,
+        ))
+    }
+}
+
+/// Aggregate the rows by sessions of `time_col`, a session is closed when the next row comes
+/// more than `window_size` microseconds after the last one, and ends `window_size` after it.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SessionWindow {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub time_col: IndexType,
+    // The gap of the sessions, in microseconds.
+    pub window_size: i64,
+    pub output_window_start: IndexType,
+    pub output_window_end: IndexType,
+    pub agg_funcs: Vec<AggregateFunctionDesc>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl SessionWindow {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(time_window_schema(
+            self.output_window_start,
+            self.output_window_end,
+            &self.agg_funcs,
+        ))
+    }
+}
+
+fn time_window_schema(
+    window_start: IndexType,
+    window_end: IndexType,
+    agg_funcs: &[AggregateFunctionDesc],
+) -> DataSchemaRef {
+    let mut fields = Vec::with_capacity(agg_funcs.len() + 2);
+    fields.push(DataField::new(
+        &window_start.to_string(),
+        DataType::Timestamp,
+    ));
+    fields.push(DataField::new(&window_end.to_string(), DataType::Timestamp));
+    for agg in agg_funcs.iter() {
+        fields.push(DataField::new(
+            &agg.output_column.to_string(),
+            agg.sig.return_type.clone(),
+        ));
+    }
+    DataSchemaRefExt::create(fields)
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_window_agg(
+        &mut self,
+        s_expr: &SExpr,
+        window_agg: &WindowAgg,
+        mut required: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns.
+        let mut used = vec![];
+        for item in &window_agg.aggregate_functions {
+            if required.contains(&item.index) {
+                required.extend(item.scalar.used_columns());
+                used.push(item.clone());
+            }
+        }
+        required.insert(window_agg.time_column);
+
+        // 2. Build physical plan.
+        let input = self.build(s_expr.child(0)?, required).await?;
+        let input_schema = input.output_schema()?;
+        let agg_funcs = used
+            .iter()
+            .map(|item| time_window_agg_func_desc(&input_schema, item))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(match window_agg.kind {
+            TimeWindowKind::Tumbling => PhysicalPlan::TumblingWindow(TumblingWindow {
+                plan_id: 0,
+                input: Box::new(input),
+                time_col: window_agg.time_column,
+                window_size: window_agg.window_size,
+                output_window_start: window_agg.window_start,
+                output_window_end: window_agg.window_end,
+                agg_funcs,
+                stat_info: Some(stat_info),
+            }),
+            TimeWindowKind::Session => PhysicalPlan::SessionWindow(SessionWindow {
+                plan_id: 0,
+                input: Box::new(input),
+                time_col: window_agg.time_column,
+                window_size: window_agg.window_size,
+                output_window_start: window_agg.window_start,
+                output_window_end: window_agg.window_end,
+                agg_funcs,
+                stat_info: Some(stat_info),
+            }),
+        })
+    }
+}
+
+fn time_window_agg_func_desc(
+    input_schema: &DataSchemaRef,
+    item: &ScalarItem,
+) -> Result<AggregateFunctionDesc> {
+    let column_index = |arg: &ScalarExpr| {
+        if let ScalarExpr::BoundColumnRef(col) = arg {
+            Ok(col.column.index)
+        } else {
+            Err(ErrorCode::Internal(
+                "Aggregate function argument must be a BoundColumnRef".to_string(),
+            ))
+        }
+    };
+    let arg_types = |arg_indices: &[IndexType]| {
+        arg_indices
+            .iter()
+            .map(|i| {
+                Ok(input_schema
+                    .field_with_name(&i.to_string())?
+                    .data_type()
+                    .clone())
+            })
+            .collect::<Result<Vec<_>>>()
+    };
+
+    match &item.scalar {
+        ScalarExpr::AggregateFunction(agg) => {
+            let arg_indices = agg
+                .args
+                .iter()
+                .map(column_index)
+                .collect::<Result<Vec<_>>>()?;
+            let sort_desc_indices = agg
+                .sort_descs
+                .iter()
+                .map(|desc| column_index(&desc.expr))
+                .collect::<Result<_>>()?;
+            let sort_descs = agg
+                .sort_descs
+                .iter()
+                .map(|desc| desc.try_into())
+                .collect::<Result<_>>()?;
+            Ok(AggregateFunctionDesc {
+                sig: AggregateFunctionSignature {
+                    name: agg.func_name.clone(),
+                    udaf: None,
+                    return_type: *agg.return_type.clone(),
+                    args: arg_types(&arg_indices)?,
+                    params: agg.params.clone(),
+                    sort_descs,
+                },
+                output_column: item.index,
+                arg_indices,
+                sort_desc_indices,
+                display: item.scalar.as_expr()?.sql_display(),
+            })
+        }
+        ScalarExpr::UDAFCall(udaf) => {
+            let arg_indices = udaf
+                .arguments
+                .iter()
+                .map(column_index)
+                .collect::<Result<Vec<_>>>()?;
+            Ok(AggregateFunctionDesc {
+                sig: AggregateFunctionSignature {
+                    name: udaf.name.clone(),
+                    udaf: Some((udaf.udf_type.clone(), udaf.state_fields.clone())),
+                    return_type: *udaf.return_type.clone(),
+                    args: arg_types(&arg_indices)?,
+                    params: vec![],
+                    sort_descs: vec![],
+                },
+                output_column: item.index,
+                arg_indices,
+                sort_desc_indices: vec![],
+                display: item.scalar.as_expr()?.sql_display(),
+            })
+        }
+        _ => Err(ErrorCode::Internal(
+            "Expected aggregate function".to_string(),
+        )),
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::IndexType;
+
+/// Convert the columns to rows for `UNPIVOT`, each input row is expanded to one row
+/// per column in `columns_to_unpivot`.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Transpose {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub columns_to_unpivot: Vec<IndexType>,
+    /// The values of `name_col` for each of `columns_to_unpivot`.
+    pub names: Vec<String>,
+    pub name_col: IndexType,
+    pub value_col: IndexType,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Transpose {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let value_type = input_schema
+            .field_with_name(&self.columns_to_unpivot[0].to_string())?
+            .data_type()
+            .clone();
+
+        let mut fields = input_schema
+            .fields()
+            .iter()
+            .filter(|field| {
+                !self
+                    .columns_to_unpivot
+                    .iter()
+                    .any(|index| field.name() == &index.to_string())
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        fields.push(DataField::new(&self.name_col.to_string(), DataType::String));
+        fields.push(DataField::new(&self.value_col.to_string(), value_type));
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_transpose(
+        &mut self,
+        s_expr: &SExpr,
+        unpivot: &crate::plans::Unpivot,
+        mut required: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns.
+        required.remove(&unpivot.name_col);
+        required.remove(&unpivot.value_col);
+        required.extend(unpivot.columns_to_unpivot.iter().copied());
+
+        // 2. Build physical plan.
+        let input = self.build(s_expr.child(0)?, required).await?;
+        Ok(PhysicalPlan::Transpose(Transpose {
+            plan_id: 0,
+            input: Box::new(input),
+            columns_to_unpivot: unpivot.columns_to_unpivot.clone(),
+            names: unpivot.names.clone(),
+            name_col: unpivot.name_col,
+            value_col: unpivot.value_col,
+            stat_info: Some(stat_info),
+        }))
+    }
+}
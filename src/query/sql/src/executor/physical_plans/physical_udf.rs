@@ -21,6 +21,7 @@ use databend_common_expression::DataSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 
 use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::Classify;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::SExpr;
@@ -87,7 +88,40 @@ impl PhysicalPlanBuilder {
             return self.build(s_expr.child(0)?, required).await;
         }
         let input = self.build(s_expr.child(0)?, required).await?;
-        let input_schema = input.output_schema()?;
+
+        // The `PREDICT` calls run the models in `Classify`.
+        let (models, used): (Vec<_>, Vec<_>) = used.into_iter().partition(
+            |item| matches!(&item.scalar, ScalarExpr::UDFCall(func) if func.udf_type.is_model()),
+        );
+        let mut plan = input;
+        for item in models {
+            let ScalarExpr::UDFCall(func) = &item.scalar else {
+                unreachable!()
+            };
+            let input_cols = func
+                .arguments
+                .iter()
+                .map(|arg| match arg {
+                    ScalarExpr::BoundColumnRef(col) => Ok(col.column.index),
+                    _ => Err(ErrorCode::Internal(
+                        "Predict function's argument must be a BoundColumnRef".to_string(),
+                    )),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            plan = PhysicalPlan::Classify(Box::new(Classify {
+                plan_id: 0,
+                input: Box::new(plan),
+                model_location: func.udf_type.as_model().unwrap().clone(),
+                input_cols,
+                output_cols: vec![(item.index, *func.return_type.clone())],
+                batch_size: self.ctx.get_settings().get_max_block_size()? as usize,
+                stat_info: Some(stat_info.clone()),
+            }));
+        }
+        if used.is_empty() {
+            return Ok(plan);
+        }
+        let input_schema = plan.output_schema()?;
 
         let udf_funcs = used
             .iter()
@@ -144,7 +178,7 @@ impl PhysicalPlanBuilder {
 
         Ok(PhysicalPlan::Udf(Udf {
             plan_id: 0,
-            input: Box::new(input),
+            input: Box::new(plan),
             udf_funcs,
             script_udf: udf_plan.script_udf,
             stat_info: Some(stat_info),
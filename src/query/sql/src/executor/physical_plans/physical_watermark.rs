@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::CacheScan;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::executor::WatermarkInjector;
+use crate::optimizer::SExpr;
+use crate::plans::CacheSource;
+use crate::ColumnSet;
+use crate::IndexType;
+
+/// Track the watermark of `event_time_col` for a streaming window aggregation. The watermark
+/// is the latest event time seen minus `watermark_delay_ms`, the rows with an event time
+/// before the watermark are late, they are removed from the output and routed to
+/// `late_output` if any, or dropped.
+///
+/// The late rows are stored in the query context with `late_cache_key`, `late_output` reads
+/// them by a `CacheScan` of the key, see `Watermark::late_rows_scan`.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Watermark {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub event_time_col: IndexType,
+    pub watermark_delay_ms: u64,
+    pub late_output: Option<Box<PhysicalPlan>>,
+    pub late_cache_key: u64,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Watermark {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+
+    /// Read the late rows of the `Watermark` with `late_cache_key`, whose input has the schema
+    /// `input_schema`. The rows are read once all the rows of the input have arrived.
+    pub fn late_rows_scan(late_cache_key: u64, input_schema: DataSchemaRef) -> PhysicalPlan {
+        let column_indexes = (0..input_schema.num_fields()).collect();
+        PhysicalPlan::CacheScan(CacheScan {
+            plan_id: 0,
+            cache_source: CacheSource::Replicate((late_cache_key, column_indexes)),
+            output_schema: input_schema,
+        })
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Build the physical plan of a streaming query, a `Watermark` is injected before each
+    /// time window aggregation, and the late rows are written to the files of
+    /// `late_output_path` in the user stage if any.
+    pub async fn build_streaming(
+        &mut self,
+        s_expr: &SExpr,
+        required: ColumnSet,
+        watermark_delay_ms: u64,
+        late_output_path: Option<String>,
+    ) -> Result<PhysicalPlan> {
+        let plan = self.build(s_expr, required).await?;
+        let mut plan = WatermarkInjector::inject(&plan, watermark_delay_ms, late_output_path)?;
+        plan.adjust_plan_id(&mut 0);
+        Ok(plan)
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_meta_app::schema::TableInfo;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::ColumnBinding;
+use crate::IndexType;
+
+/// Write the rows of an insert to a write-ahead log under `wal_location` in the background,
+/// so that they can be replayed into the table if the commit fails. The input is passed
+/// through unchanged.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct WriteAheadLog {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub input: Box<PhysicalPlan>,
+    pub table_info: TableInfo,
+    pub wal_location: String,
+    pub flush_interval_ms: u64,
+    // The offsets of the inserted columns in the input schema.
+    pub wal_columns: Vec<IndexType>,
+    // The schema of the logged blocks, named after the inserted table columns.
+    pub wal_schema: DataSchemaRef,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl WriteAheadLog {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+impl PhysicalPlanBuilder {
+    /// Put a `WriteAheadLog` on the select plan of an `INSERT INTO ... SELECT`, the select
+    /// columns are bound to `insert_schema` by position.
+    pub fn build_insert_with_wal(
+        &self,
+        input: PhysicalPlan,
+        table_info: TableInfo,
+        insert_schema: &DataSchemaRef,
+        select_column_bindings: &[ColumnBinding],
+        wal_location: String,
+    ) -> Result<PhysicalPlan> {
+        let flush_interval_ms = self.ctx.get_settings().get_wal_flush_interval_ms()?;
+        let input_schema = input.output_schema()?;
+        let mut columns = Vec::with_capacity(insert_schema.num_fields());
+        let mut fields = Vec::with_capacity(insert_schema.num_fields());
+        for (field, binding) in insert_schema.fields().iter().zip(select_column_bindings) {
+            let offset = input_schema.index_of(&binding.index.to_string())?;
+            columns.push(offset);
+            fields.push(DataField::new(
+                field.name(),
+                input_schema.field(offset).data_type().clone(),
+            ));
+        }
+
+        Ok(PhysicalPlan::WriteAheadLog(Box::new(WriteAheadLog {
+            plan_id: 0,
+            input: Box::new(input),
+            table_info,
+            wal_location,
+            flush_interval_ms,
+            wal_columns: columns,
+            wal_schema: DataSchemaRef::new(DataSchema::new(fields)),
+            stat_info: None,
+        })))
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::optimizer::ColumnSet;
+use crate::IndexType;
+
+/// Unnest several arrays of each input row side by side, the shorter arrays
+/// are padded with NULLs to the length of the longest one.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Zip {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub projections: ColumnSet,
+    pub input: Box<PhysicalPlan>,
+    /// The `unnest` expressions of the arrays and their output columns.
+    pub arrays: Vec<(RemoteExpr, IndexType)>,
+    /// The output column of the 1-based index, set by `WITH ORDINALITY`.
+    pub with_ordinality: Option<IndexType>,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Zip {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = Vec::with_capacity(input_schema.num_fields() + self.arrays.len() + 1);
+        for (i, field) in input_schema.fields().iter().enumerate() {
+            if self.projections.contains(&i) {
+                fields.push(field.clone());
+            }
+        }
+        fields.extend(self.arrays.iter().map(|(array, index)| {
+            DataField::new(
+                &index.to_string(),
+                array.as_expr(&BUILTIN_FUNCTIONS).data_type().clone(),
+            )
+        }));
+        if let Some(index) = self.with_ordinality {
+            fields.push(DataField::new(
+                &index.to_string(),
+                DataType::Number(NumberDataType::UInt64),
+            ));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
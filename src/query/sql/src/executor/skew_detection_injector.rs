@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+
+use crate::executor::physical_plans::common::FragmentKind;
+use crate::executor::physical_plans::Exchange;
+use crate::executor::physical_plans::SkewDetection;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanReplacer;
+
+/// Put a `SkewDetection` on top of each hash `Exchange`, so the rows received from
+/// the exchange are checked against the hash keys of the exchange.
+///
+/// The exchanges of partial aggregates are skipped, they carry aggregate states
+/// instead of rows and the final aggregate must read them directly.
+pub struct SkewDetectionInjector {
+    skew_threshold: f64,
+}
+
+impl SkewDetectionInjector {
+    pub fn inject(plan: &PhysicalPlan, skew_threshold: f64) -> Result<PhysicalPlan> {
+        SkewDetectionInjector { skew_threshold }.replace(plan)
+    }
+}
+
+impl PhysicalPlanReplacer for SkewDetectionInjector {
+    fn replace_exchange(&mut self, plan: &Exchange) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        let is_partial_aggregate = matches!(input, PhysicalPlan::AggregatePartial(_));
+        let exchange = PhysicalPlan::Exchange(Exchange {
+            input: Box::new(input),
+            ..plan.clone()
+        });
+        if plan.kind != FragmentKind::Normal
+            || plan.keys.is_empty()
+            || plan.ignore_exchange
+            || is_partial_aggregate
+        {
+            return Ok(exchange);
+        }
+
+        Ok(PhysicalPlan::SkewDetection(SkewDetection {
+            plan_id: 0,
+            input: Box::new(exchange),
+            partition_key: plan.keys.clone(),
+            skew_threshold: self.skew_threshold,
+            stat_info: None,
+        }))
+    }
+}
@@ -279,6 +279,7 @@ impl ToReadDataSourcePlan for dyn Table {
             // Set a dummy id, will be set real id later
             table_index: usize::MAX,
             scan_id: usize::MAX,
+            prefetch_depth: 0,
         })
     }
 }
@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
+use databend_common_meta_app::principal::StageFileFormatType;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::SessionWindow;
+use crate::executor::physical_plans::StreamOutput;
+use crate::executor::physical_plans::TumblingWindow;
+use crate::executor::physical_plans::Watermark;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanReplacer;
+use crate::IndexType;
+
+/// Put a `Watermark` of the time column under each `TumblingWindow` and `SessionWindow`,
+/// so the rows arriving after the watermark are not aggregated.
+pub struct WatermarkInjector {
+    watermark_delay_ms: u64,
+    late_output_path: Option<String>,
+    // The number of injected watermarks, to give each one its own cache key.
+    watermarks: usize,
+}
+
+impl WatermarkInjector {
+    pub fn inject(
+        plan: &PhysicalPlan,
+        watermark_delay_ms: u64,
+        late_output_path: Option<String>,
+    ) -> Result<PhysicalPlan> {
+        WatermarkInjector {
+            watermark_delay_ms,
+            late_output_path,
+            watermarks: 0,
+        }
+        .replace(plan)
+    }
+
+    fn watermark(
+        &mut self,
+        input: PhysicalPlan,
+        event_time_col: IndexType,
+        stat_info: Option<PlanStatsInfo>,
+    ) -> Result<PhysicalPlan> {
+        let mut hasher = DefaultHasher::new();
+        ("watermark", self.watermarks).hash(&mut hasher);
+        let late_cache_key = hasher.finish();
+        self.watermarks += 1;
+
+        let late_output = match &self.late_output_path {
+            Some(path) => {
+                let late_rows = Watermark::late_rows_scan(late_cache_key, input.output_schema()?);
+                let output = PhysicalPlan::StreamOutput(Box::new(StreamOutput {
+                    plan_id: 0,
+                    input: Box::new(late_rows),
+                    path: path.clone(),
+                    format: FileFormatParams::default_by_type(StageFileFormatType::Parquet)?,
+                    compression: StageFileCompression::Zstd,
+                    max_file_size: None,
+                    stat_info: None,
+                }));
+                Some(Box::new(output))
+            }
+            None => None,
+        };
+
+        Ok(PhysicalPlan::Watermark(Box::new(Watermark {
+            plan_id: 0,
+            input: Box::new(input),
+            event_time_col,
+            watermark_delay_ms: self.watermark_delay_ms,
+            late_output,
+            late_cache_key,
+            stat_info,
+        })))
+    }
+}
+
+impl PhysicalPlanReplacer for WatermarkInjector {
+    fn replace_tumbling_window(&mut self, plan: &TumblingWindow) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        let input = self.watermark(input, plan.time_col, plan.stat_info.clone())?;
+        Ok(PhysicalPlan::TumblingWindow(TumblingWindow {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_session_window(&mut self, plan: &SessionWindow) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        let input = self.watermark(input, plan.time_col, plan.stat_info.clone())?;
+        Ok(PhysicalPlan::SessionWindow(SessionWindow {
+            input: Box::new(input),
+            ..plan.clone()
+        }))
+    }
+}
@@ -27,7 +27,9 @@ use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
+use databend_common_expression::ConstantFolder;
 use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use indexmap::Equivalent;
 use itertools::Itertools;
 
@@ -54,9 +56,11 @@ use crate::plans::FunctionCall;
 use crate::plans::GroupingSets;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
+use crate::plans::TimeWindowKind;
 use crate::plans::UDAFCall;
 use crate::plans::Visitor;
 use crate::plans::VisitorMut;
+use crate::plans::WindowAgg;
 use crate::BindContext;
 use crate::IndexType;
 use crate::MetadataRef;
@@ -125,6 +129,24 @@ pub struct GroupingSetsInfo {
     ///
     /// we should use the original column `a` data instead of the column data after filling dummy NULLs.
     pub dup_group_items: Vec<(IndexType, DataType)>,
+    /// The columns of `GROUPING_ID` functions, each of them is computed from `_grouping_id` by
+    /// the offsets of the arguments in the group items, and stored in a column with the index.
+    pub grouping_ids: Vec<(Vec<usize>, IndexType)>,
+}
+
+/// Information of `GROUP BY TUMBLING WINDOW (..)` and `GROUP BY SESSION WINDOW (..)`.
+///
+/// The rows are grouped by the windows of the time column only, and the bounds of each window
+/// are output in the virtual columns `window_start` and `window_end`, which are the group items.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TimeWindowInfo {
+    pub kind: TimeWindowKind,
+    /// The time column, evaluated before the aggregation.
+    pub time_column: ScalarItem,
+    /// The window size of tumbling windows, or the gap of session windows, in microseconds.
+    pub window_size: i64,
+    pub window_start: ColumnBinding,
+    pub window_end: ColumnBinding,
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug)]
@@ -157,6 +179,9 @@ pub struct AggregateInfo {
 
     /// Information of grouping sets
     pub grouping_sets: Option<GroupingSetsInfo>,
+
+    /// Information of time windows
+    pub time_window: Option<TimeWindowInfo>,
 }
 
 impl AggregateInfo {
@@ -451,16 +476,10 @@ impl<'a> AggregateRewriter<'a> {
         // ba -> 00 -> 0
         // _a -> 01 -> 1
         // grouping(b, a) will be rewritten to grouping<1, 0>(grouping_id).
-        let mut replaced_params = Vec::with_capacity(function.arguments.len());
-        for arg in &function.arguments {
-            if let Some(index) = agg_info.group_items_map.get(arg) {
-                replaced_params.push(Scalar::Number(NumberScalar::Int64(*index as _)));
-            } else {
-                return Err(ErrorCode::BadArguments(
-                    "Arguments of grouping should be group by expressions",
-                ));
-            }
-        }
+        let replaced_params = Self::grouping_offsets(agg_info, function)?
+            .into_iter()
+            .map(|offset| Scalar::Number(NumberScalar::Int64(offset as _)))
+            .collect();
 
         let replaced_func = FunctionCall {
             span: function.span,
@@ -474,6 +493,64 @@ impl<'a> AggregateRewriter<'a> {
 
         Ok(replaced_func)
     }
+
+    /// Rewrite `grouping_id(b, a)` to a column computed by the `GroupingId` plan
+    /// after the aggregation, the bits of the column are the same as `grouping(b, a)`.
+    fn replace_grouping_id(&mut self, function: &FunctionCall) -> Result<ScalarExpr> {
+        let agg_info = &mut self.bind_context.aggregate_info;
+        if agg_info.grouping_sets.is_none() {
+            return Err(ErrorCode::SemanticError(
+                "grouping_id can only be called in GROUP BY GROUPING SETS clauses",
+            ));
+        }
+        let offsets = Self::grouping_offsets(agg_info, function)?;
+        let grouping_sets = agg_info.grouping_sets.as_mut().unwrap();
+        let index = match grouping_sets
+            .grouping_ids
+            .iter()
+            .find(|(columns, _)| columns == &offsets)
+        {
+            Some((_, index)) => *index,
+            None => {
+                let index = self.metadata.write().add_derived_column(
+                    format!("grouping_id({})", offsets.iter().join(", ")),
+                    DataType::Number(NumberDataType::UInt32),
+                    None,
+                );
+                grouping_sets.grouping_ids.push((offsets, index));
+                index
+            }
+        };
+
+        let column = ColumnBindingBuilder::new(
+            "grouping_id".to_string(),
+            index,
+            Box::new(DataType::Number(NumberDataType::UInt32)),
+            Visibility::Visible,
+        )
+        .build();
+        Ok(BoundColumnRef {
+            span: function.span,
+            column,
+        }
+        .into())
+    }
+
+    /// The offsets of the arguments of `grouping` in the group items.
+    fn grouping_offsets(agg_info: &AggregateInfo, function: &FunctionCall) -> Result<Vec<usize>> {
+        function
+            .arguments
+            .iter()
+            .map(|arg| {
+                agg_info.group_items_map.get(arg).copied().ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "Arguments of {} should be group by expressions",
+                        function.func_name
+                    ))
+                })
+            })
+            .collect()
+    }
 }
 
 impl<'a> VisitorMut<'a> for AggregateRewriter<'a> {
@@ -487,6 +564,12 @@ impl<'a> VisitorMut<'a> for AggregateRewriter<'a> {
                 *expr = self.replace_udaf_call(udaf)?;
                 Ok(())
             }
+            ScalarExpr::FunctionCall(func)
+                if func.func_name.eq_ignore_ascii_case("grouping_id") =>
+            {
+                *expr = self.replace_grouping_id(func)?;
+                Ok(())
+            }
             _ => walk_expr_mut(self, expr),
         }
     }
@@ -580,15 +663,130 @@ impl Binder {
             GroupBy::GroupingSets(sets) => {
                 self.resolve_grouping_sets(bind_context, select_list, sets, &available_aliases)?;
             }
+            // The group items of time windows are added by `analyze_time_window`.
+            GroupBy::TumblingWindow { .. } | GroupBy::SessionWindow { .. } => {}
             _ => unreachable!(),
         }
         bind_context.set_expr_context(original_context);
         Ok(())
     }
 
+    /// Bind the time column and the window size of `GROUP BY TUMBLING WINDOW (..)` and
+    /// `GROUP BY SESSION WINDOW (..)`, and add the window bounds as the group items.
+    ///
+    /// The window bounds are added to `bind_context` as invisible columns `window_start` and
+    /// `window_end`, so this should be called before `normalize_select_list` to make them
+    /// available in the select list.
+    pub fn analyze_time_window(
+        &mut self,
+        bind_context: &mut BindContext,
+        group_by: &GroupBy,
+    ) -> Result<()> {
+        let (kind, time_column, size) = match group_by {
+            GroupBy::TumblingWindow { time_column, size } => {
+                (TimeWindowKind::Tumbling, time_column, size)
+            }
+            GroupBy::SessionWindow { time_column, gap } => {
+                (TimeWindowKind::Session, time_column, gap)
+            }
+            _ => return Ok(()),
+        };
+
+        let original_context = bind_context.expr_context.clone();
+        bind_context.set_expr_context(ExprContext::GroupClaue);
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (time_scalar, time_type) = scalar_binder.bind(time_column)?;
+        let (size_scalar, _) = scalar_binder.bind(size)?;
+        bind_context.set_expr_context(original_context);
+
+        if time_type.remove_nullable() != DataType::Timestamp {
+            return Err(ErrorCode::SemanticError(format!(
+                "The time column of a time window must be a TIMESTAMP, but got {time_type}"
+            ))
+            .set_span(time_column.span()));
+        }
+        let time_index = if let ScalarExpr::BoundColumnRef(col) = &time_scalar {
+            col.column.index
+        } else {
+            self.metadata.write().add_derived_column(
+                format!("{:#}", time_column),
+                time_type,
+                Some(time_scalar.clone()),
+            )
+        };
+
+        let (size_expr, _) = ConstantFolder::fold(
+            &size_scalar.as_expr()?,
+            &self.ctx.get_function_context()?,
+            &BUILTIN_FUNCTIONS,
+        );
+        // Months are not a fixed duration, they can't be used to split the time.
+        let window_size = match size_expr {
+            databend_common_expression::Expr::Constant {
+                scalar: Scalar::Interval(interval),
+                ..
+            } if interval.months() == 0 => interval.try_total_micros(),
+            _ => None,
+        }
+        .filter(|window_size| *window_size > 0)
+        .ok_or_else(|| {
+            ErrorCode::SemanticError(format!(
+                "The size of a time window must be a positive constant INTERVAL without months, but got {size}"
+            ))
+            .set_span(size.span())
+        })?;
+
+        let mut window_bound = |name: &str| {
+            let mut column =
+                self.create_derived_column_binding(name.to_string(), DataType::Timestamp, None);
+            // Not expanded by `SELECT *`.
+            column.visibility = Visibility::InVisible;
+            bind_context.columns.push(column.clone());
+
+            let scalar: ScalarExpr = BoundColumnRef {
+                span: None,
+                column: column.clone(),
+            }
+            .into();
+            let agg_info = &mut bind_context.aggregate_info;
+            agg_info
+                .group_items_map
+                .insert(scalar.clone(), agg_info.group_items.len());
+            agg_info.group_items.push(ScalarItem {
+                scalar,
+                index: column.index,
+            });
+            column
+        };
+        let window_start = window_bound("window_start");
+        let window_end = window_bound("window_end");
+
+        bind_context.aggregate_info.time_window = Some(TimeWindowInfo {
+            kind,
+            time_column: ScalarItem {
+                scalar: time_scalar,
+                index: time_index,
+            },
+            window_size,
+            window_start,
+            window_end,
+        });
+        Ok(())
+    }
+
     pub fn expand_group(group_by: GroupBy) -> Result<GroupBy> {
         match group_by {
-            GroupBy::Normal(_) | GroupBy::All | GroupBy::GroupingSets(_) => Ok(group_by),
+            GroupBy::Normal(_)
+            | GroupBy::All
+            | GroupBy::GroupingSets(_)
+            | GroupBy::TumblingWindow { .. }
+            | GroupBy::SessionWindow { .. } => Ok(group_by),
             GroupBy::Cube(exprs) => {
                 // Expand CUBE to GroupingSets
                 let sets = Self::generate_cube_sets(exprs);
@@ -667,6 +865,10 @@ impl Binder {
         // Enter in_grouping state
         bind_context.in_grouping = true;
 
+        if bind_context.aggregate_info.time_window.is_some() {
+            return self.bind_time_window_aggregate(bind_context, child);
+        }
+
         // Build a ProjectPlan, which will produce aggregate arguments and group items
         let agg_info = &bind_context.aggregate_info;
         let mut scalar_items: Vec<ScalarItem> = Vec::with_capacity(
@@ -712,6 +914,7 @@ impl Binder {
                 grouping_id_index: g.grouping_id_column.index,
                 sets: g.sets.clone(),
                 dup_group_items: g.dup_group_items.clone(),
+                grouping_ids: g.grouping_ids.clone(),
             }),
         };
         new_expr = SExpr::create_unary(Arc::new(aggregate_plan.into()), Arc::new(new_expr));
@@ -719,6 +922,44 @@ impl Binder {
         Ok(new_expr)
     }
 
+    // Bind the aggregation of time windows, the rows are grouped by the windows of the time
+    // column instead of the group items.
+    fn bind_time_window_aggregate(
+        &mut self,
+        bind_context: &mut BindContext,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        let agg_info = &bind_context.aggregate_info;
+        let time_window = agg_info.time_window.as_ref().unwrap();
+
+        let mut scalar_items: Vec<ScalarItem> = agg_info
+            .aggregate_arguments
+            .iter()
+            .chain(agg_info.aggregate_sort_descs.iter())
+            .chain(std::iter::once(&time_window.time_column))
+            .cloned()
+            .collect();
+        scalar_items.sort_by_key(|item| item.index);
+        scalar_items.dedup_by_key(|item| item.index);
+        let eval_scalar = EvalScalar {
+            items: scalar_items,
+        };
+        let new_expr = SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(child));
+
+        let window_agg = WindowAgg {
+            kind: time_window.kind,
+            time_column: time_window.time_column.index,
+            window_size: time_window.window_size,
+            window_start: time_window.window_start.index,
+            window_end: time_window.window_end.index,
+            aggregate_functions: agg_info.aggregate_functions.clone(),
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(window_agg.into()),
+            Arc::new(new_expr),
+        ))
+    }
+
     fn resolve_grouping_sets(
         &mut self,
         bind_context: &mut BindContext,
@@ -795,6 +1036,7 @@ impl Binder {
             grouping_id_column,
             sets: grouping_sets,
             dup_group_items,
+            grouping_ids: vec![],
         };
 
         agg_info.grouping_sets = Some(grouping_sets_info);
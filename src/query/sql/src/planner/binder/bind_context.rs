@@ -234,6 +234,8 @@ pub struct CteInfo {
     pub cte_idx: IndexType,
     // If cte is materialized, save its columns
     pub columns: Vec<ColumnBinding>,
+    /// The number of references to the cte in the query.
+    pub consumer_count: usize,
 }
 
 impl BindContext {
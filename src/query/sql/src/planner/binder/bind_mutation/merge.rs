@@ -57,6 +57,7 @@ impl Binder {
             alias: stmt.target_alias.clone(),
             temporal: None,
             with_options: None,
+            index_hint: None,
             pivot: None,
             unpivot: None,
             sample: None,
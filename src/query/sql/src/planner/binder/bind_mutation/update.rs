@@ -70,6 +70,7 @@ impl Binder {
             alias: table_alias.clone(),
             temporal: None,
             with_options: None,
+            index_hint: None,
             pivot: None,
             unpivot: None,
             sample: None,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_ast::ast::CreateOption;
@@ -22,6 +23,7 @@ use databend_common_ast::ast::ExprReplacer;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TableType;
 use databend_common_ast::ast::With;
 use databend_common_ast::ast::CTE;
@@ -29,6 +31,8 @@ use databend_common_ast::Span;
 use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use derive_visitor::Drive;
+use derive_visitor::Visitor;
 
 use crate::binder::CteInfo;
 use crate::normalize_identifier;
@@ -40,6 +44,7 @@ use crate::plans::BoundColumnRef;
 use crate::plans::ScalarExpr;
 use crate::plans::Sort;
 use crate::plans::SortItem;
+use crate::NameResolutionContext;
 
 impl Binder {
     pub(crate) fn bind_query(
@@ -49,6 +54,7 @@ impl Binder {
     ) -> Result<(SExpr, BindContext)> {
         // Initialize cte map.
         self.init_cte(bind_context, &query.with)?;
+        self.count_cte_consumers(bind_context, query);
 
         // Extract limit and offset from query.
         let (limit, offset) = self.extract_limit_and_offset(query)?;
@@ -98,6 +104,7 @@ impl Binder {
                 cte_idx: idx,
                 columns: vec![],
                 materialized: cte.materialized,
+                consumer_count: 0,
             };
             // If the CTE is materialized, we'll construct a temp table for it.
             if cte.materialized {
@@ -112,6 +119,24 @@ impl Binder {
         Ok(())
     }
 
+    // Count the references to each cte in the query, including the ones in the other ctes.
+    fn count_cte_consumers(&self, bind_context: &mut BindContext, query: &Query) {
+        let Some(with) = &query.with else {
+            return;
+        };
+        let mut visitor = CteRefVisitor {
+            name_resolution_ctx: self.name_resolution_ctx.clone(),
+            counts: HashMap::new(),
+        };
+        query.drive(&mut visitor);
+        for cte in with.ctes.iter() {
+            let table_name = self.normalize_identifier(&cte.alias.name).name;
+            if let Some(cte_info) = bind_context.cte_context.cte_map.get_mut(&table_name) {
+                cte_info.consumer_count = visitor.counts.get(&table_name).copied().unwrap_or(0);
+            }
+        }
+    }
+
     pub(crate) fn bind_query_order_by(
         &mut self,
         bind_context: &mut BindContext,
@@ -247,3 +272,24 @@ impl Binder {
             .evict_table_from_cache(CATALOG_DEFAULT, &database, &table_name)
     }
 }
+
+#[derive(Visitor)]
+#[visitor(TableReference(enter))]
+struct CteRefVisitor {
+    name_resolution_ctx: NameResolutionContext,
+    counts: HashMap<String, usize>,
+}
+
+impl CteRefVisitor {
+    fn enter_table_reference(&mut self, table_ref: &TableReference) {
+        if let TableReference::Table {
+            database: None,
+            table,
+            ..
+        } = table_ref
+        {
+            let table_name = normalize_identifier(table, &self.name_resolution_ctx).name;
+            *self.counts.entry(table_name).or_default() += 1;
+        }
+    }
+}
@@ -19,7 +19,6 @@ use databend_common_ast::ast::ColumnID;
 use databend_common_ast::ast::ColumnPosition;
 use databend_common_ast::ast::ColumnRef;
 use databend_common_ast::ast::Expr;
-use databend_common_ast::ast::Expr::Array;
 use databend_common_ast::ast::FunctionCall;
 use databend_common_ast::ast::GroupBy;
 use databend_common_ast::ast::Identifier;
@@ -33,7 +32,6 @@ use databend_common_ast::ast::PivotValues;
 use databend_common_ast::ast::SelectStmt;
 use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::TableReference;
-use databend_common_ast::ast::UnpivotName;
 use databend_common_ast::Span;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -111,7 +109,8 @@ impl Binder {
             from_context.all_column_bindings(),
             self.name_resolution_ctx.unquoted_ident_case_sensitive,
         )
-        .with_subquery_executor(self.subquery_executor.clone());
+        .with_subquery_executor(self.subquery_executor.clone())
+        .with_max_pivot_columns(self.ctx.get_settings().get_max_pivot_columns()? as usize);
         let new_stmt = rewriter.rewrite(stmt)?;
         let stmt = new_stmt.as_ref().unwrap_or(stmt);
 
@@ -119,6 +118,11 @@ impl Binder {
         // This operation should be before `normalize_select_list` because window functions can be used in select list.
         self.analyze_window_definition(&mut from_context, &stmt.window_list)?;
 
+        // The window bounds of time windows can be used in select list.
+        if let Some(group_by) = stmt.group_by.as_ref() {
+            self.analyze_time_window(&mut from_context, group_by)?;
+        }
+
         // Generate a analyzed select list with from context
         let mut select_list = self.normalize_select_list(&mut from_context, &stmt.select_list)?;
 
@@ -157,6 +161,17 @@ impl Binder {
         // we should bind where after `select_list` is rewritten.
         let where_scalar = if let Some(expr) = &stmt.selection {
             let (new_expr, scalar) = self.bind_where(&mut from_context, &aliases, expr, s_expr)?;
+            if let Some(time_window) = &from_context.aggregate_info.time_window {
+                let used_columns = scalar.used_columns();
+                if used_columns.contains(&time_window.window_start.index)
+                    || used_columns.contains(&time_window.window_end.index)
+                {
+                    return Err(ErrorCode::SemanticError(
+                        "window_start and window_end can't be used in WHERE clause".to_string(),
+                    )
+                    .set_span(expr.span()));
+                }
+            }
             s_expr = new_expr;
             Some(scalar)
         } else {
@@ -288,6 +303,7 @@ struct SelectRewriter<'a> {
     new_stmt: Option<SelectStmt>,
     is_unquoted_ident_case_sensitive: bool,
     subquery_executor: Option<Arc<dyn QueryExecutor>>,
+    max_pivot_columns: usize,
 }
 
 // helper functions to SelectRewriter
@@ -351,38 +367,6 @@ impl SelectRewriter<'_> {
         }
     }
 
-    fn expr_literal_array_from_unpivot_names(names: &[UnpivotName]) -> Expr {
-        Array {
-            span: Span::default(),
-            exprs: names
-                .iter()
-                .map(|name| Expr::Literal {
-                    span: name.ident.span,
-                    value: Literal::String(
-                        name.alias.as_ref().unwrap_or(&name.ident.name).to_string(),
-                    ),
-                })
-                .collect(),
-        }
-    }
-
-    fn expr_column_ref_array_from_vec_ident(exprs: Vec<Identifier>) -> Expr {
-        Array {
-            span: Span::default(),
-            exprs: exprs
-                .into_iter()
-                .map(|expr| Expr::ColumnRef {
-                    span: None,
-                    column: ColumnRef {
-                        database: None,
-                        table: None,
-                        column: ColumnID::Name(expr),
-                    },
-                })
-                .collect(),
-        }
-    }
-
     // For Expr::Literal, expr.to_string() is quoted, sometimes we need the raw string.
     fn raw_string_from_literal_expr(expr: &Expr) -> Option<String> {
         match expr {
@@ -402,6 +386,7 @@ impl<'a> SelectRewriter<'a> {
             new_stmt: None,
             is_unquoted_ident_case_sensitive,
             subquery_executor: None,
+            max_pivot_columns: usize::MAX,
         }
     }
 
@@ -413,9 +398,13 @@ impl<'a> SelectRewriter<'a> {
         self
     }
 
+    pub fn with_max_pivot_columns(mut self, max_pivot_columns: usize) -> Self {
+        self.max_pivot_columns = max_pivot_columns;
+        self
+    }
+
     fn rewrite(&mut self, stmt: &SelectStmt) -> Result<Option<SelectStmt>> {
         self.rewrite_pivot(stmt)?;
-        self.rewrite_unpivot(stmt)?;
         Ok(self.new_stmt.take())
     }
 
@@ -535,6 +524,14 @@ impl<'a> SelectRewriter<'a> {
         new_select_list: &mut Vec<SelectTarget>,
         stmt: &SelectStmt,
     ) -> Result<()> {
+        if values.len() > self.max_pivot_columns {
+            return Err(ErrorCode::ExceedPivotLimit(format!(
+                "PIVOT generates {} columns, exceeds the limit {}, please adjust the setting `max_pivot_columns`",
+                values.len(),
+                self.max_pivot_columns
+            ))
+            .set_span(pivot.value_column.span));
+        }
         for value in values {
             let mut args = aggregate_args.to_vec();
             args.push(Self::expr_eq_from_col_and_value(
@@ -586,46 +583,6 @@ impl<'a> SelectRewriter<'a> {
         }
         Ok(values)
     }
-
-    fn rewrite_unpivot(&mut self, stmt: &SelectStmt) -> Result<()> {
-        if stmt.from.len() != 1 {
-            return Ok(());
-        }
-        let Some(unpivot) = stmt.from[0].unpivot() else {
-            return Ok(());
-        };
-        let mut new_select_list = stmt.select_list.clone();
-        let columns = unpivot
-            .column_names
-            .iter()
-            .map(|name| (name.ident.to_owned()))
-            .collect::<Vec<_>>();
-        if let Some(star) = new_select_list.iter_mut().find(|target| target.is_star()) {
-            star.exclude(columns.clone());
-        };
-        new_select_list.push(Self::target_func_from_name_args(
-            Identifier::from_name(stmt.span, "unnest"),
-            vec![Self::expr_literal_array_from_unpivot_names(
-                &unpivot.column_names,
-            )],
-            Some(unpivot.unpivot_column.clone()),
-        ));
-        new_select_list.push(Self::target_func_from_name_args(
-            Identifier::from_name(stmt.span, "unnest"),
-            vec![Self::expr_column_ref_array_from_vec_ident(columns)],
-            Some(unpivot.value_column.clone()),
-        ));
-
-        if let Some(ref mut new_stmt) = self.new_stmt {
-            new_stmt.select_list = new_select_list;
-        } else {
-            self.new_stmt = Some(SelectStmt {
-                select_list: new_select_list,
-                ..stmt.clone()
-            });
-        };
-        Ok(())
-    }
 }
 
 #[derive(Visitor)]
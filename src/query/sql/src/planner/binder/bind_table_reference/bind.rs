@@ -25,7 +25,7 @@ impl Binder {
         bind_context: &mut BindContext,
         table_ref: &TableReference,
     ) -> Result<(SExpr, BindContext)> {
-        match table_ref {
+        let (s_expr, result_context) = match table_ref {
             TableReference::Table {
                 span,
                 catalog,
@@ -36,6 +36,7 @@ impl Binder {
                 pivot: _,
                 unpivot: _,
                 with_options,
+                index_hint,
                 sample,
             } => self.bind_table(
                 bind_context,
@@ -46,6 +47,7 @@ impl Binder {
                 alias,
                 temporal,
                 with_options,
+                index_hint,
                 sample,
             ),
             TableReference::TableFunction {
@@ -53,6 +55,7 @@ impl Binder {
                 name,
                 params,
                 named_params,
+                with_ordinality,
                 alias,
                 sample,
                 ..
@@ -62,6 +65,7 @@ impl Binder {
                 name,
                 params,
                 named_params,
+                *with_ordinality,
                 alias,
                 sample,
             ),
@@ -80,6 +84,12 @@ impl Binder {
                 alias,
             } => self.bind_location(bind_context, location, options, alias),
             TableReference::Join { join, .. } => self.bind_join(bind_context, join),
+            TableReference::JsonTable { .. } => self.bind_json_table(bind_context, None, table_ref),
+        }?;
+
+        match table_ref.unpivot() {
+            Some(unpivot) => self.bind_unpivot(result_context, s_expr, unpivot),
+            None => Ok((s_expr, result_context)),
         }
     }
 }
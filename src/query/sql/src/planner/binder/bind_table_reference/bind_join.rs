@@ -20,6 +20,7 @@ use databend_common_ast::ast::split_equivalent_predicate_expr;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::JoinCondition;
 use databend_common_ast::ast::JoinOperator;
+use databend_common_ast::ast::TableReference;
 use databend_common_ast::Span;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -85,6 +86,9 @@ impl Binder {
             )?;
             return Ok((result_expr, bind_context));
         }
+        if let TableReference::JsonTable { .. } = join.right.as_ref() {
+            return self.bind_json_table(&mut left_context, Some(left_child), &join.right);
+        }
         let (right_child, mut right_context) = if join.right.is_lateral_subquery() {
             self.bind_table_reference(&mut left_context, &join.right)?
         } else {
@@ -0,0 +1,139 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::Span;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use jsonb::jsonpath::parse_json_path;
+
+use crate::binder::scalar::ScalarBinder;
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::CastExpr;
+use crate::plans::DummyTableScan;
+use crate::plans::EvalScalar;
+use crate::plans::JsonTable;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::resolve_type_name;
+use crate::BindContext;
+
+impl Binder {
+    /// Bind `JSON_TABLE`, each row of `child` is expanded to the rows of its JSON values.
+    /// With `child` of the left table of a join, the source can reference the columns of
+    /// the left table and they are in the returned context, otherwise the source is
+    /// evaluated once.
+    pub(crate) fn bind_json_table(
+        &mut self,
+        parent_context: &mut BindContext,
+        child: Option<SExpr>,
+        table_ref: &TableReference,
+    ) -> Result<(SExpr, BindContext)> {
+        let TableReference::JsonTable {
+            span,
+            source,
+            row_path,
+            columns,
+            alias,
+        } = table_ref
+        else {
+            unreachable!()
+        };
+        check_json_path(span, row_path)?;
+
+        let mut scalar_binder = ScalarBinder::new(
+            parent_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (mut scalar, data_type) = scalar_binder.bind(source)?;
+        if data_type.remove_nullable() != DataType::Variant {
+            let target_type = match data_type.is_nullable() {
+                true => DataType::Variant.wrap_nullable(),
+                false => DataType::Variant,
+            };
+            scalar = ScalarExpr::CastExpr(CastExpr {
+                span: *span,
+                is_try: false,
+                argument: Box::new(scalar),
+                target_type: Box::new(target_type),
+            });
+        }
+
+        let lateral = child.is_some();
+        let child = child.unwrap_or_else(|| SExpr::create_leaf(Arc::new(DummyTableScan.into())));
+        // Evaluate the source into a column if it's not a column of the child.
+        let (source_col, child) = match &scalar {
+            ScalarExpr::BoundColumnRef(column_ref) if lateral => (column_ref.column.index, child),
+            _ => {
+                let column_binding = self.create_derived_column_binding(
+                    source.to_string(),
+                    scalar.data_type()?,
+                    Some(scalar.clone()),
+                );
+                let eval_scalar = EvalScalar {
+                    items: vec![ScalarItem {
+                        scalar,
+                        index: column_binding.index,
+                    }],
+                };
+                let child = SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(child));
+                (column_binding.index, child)
+            }
+        };
+
+        let mut bind_context = BindContext::with_parent(parent_context.clone())?;
+        let mut json_columns = Vec::with_capacity(columns.len());
+        for column in columns.iter() {
+            check_json_path(span, &column.path)?;
+            let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+            // The value is NULL if the path is not found or it can't be cast to the type.
+            let data_type =
+                DataType::from(&resolve_type_name(&column.data_type, true)?).wrap_nullable();
+            let column_binding = self.create_derived_column_binding(name, data_type.clone(), None);
+            json_columns.push((column_binding.index, column.path.clone(), data_type));
+            bind_context.add_column_binding(column_binding);
+        }
+        if let Some(alias) = alias {
+            bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+        if lateral {
+            let mut new_columns = parent_context.columns.clone();
+            new_columns.extend_from_slice(&bind_context.columns);
+            bind_context.columns = new_columns;
+        }
+
+        let json_table = JsonTable {
+            source_col,
+            row_path: row_path.clone(),
+            columns: json_columns,
+        };
+        let s_expr = SExpr::create_unary(Arc::new(json_table.into()), Arc::new(child));
+        Ok((s_expr, bind_context))
+    }
+}
+
+fn check_json_path(span: &Span, path: &str) -> Result<()> {
+    parse_json_path(path.as_bytes()).map_err(|_| {
+        ErrorCode::SemanticError(format!("Invalid JSON path '{}' of JSON_TABLE", path))
+            .set_span(*span)
+    })?;
+    Ok(())
+}
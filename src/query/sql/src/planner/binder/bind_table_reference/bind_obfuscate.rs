@@ -192,6 +192,7 @@ fn build_subquery(
         alias: None,
         temporal: None,
         with_options: None,
+        index_hint: None,
         pivot: None,
         unpivot: None,
         sample: None,
@@ -16,9 +16,15 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::IndexHint;
+use databend_common_ast::ast::Indirection;
+use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SampleConfig;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TemporalClause;
 use databend_common_ast::ast::WithOptions;
 use databend_common_ast::parser::parse_sql;
@@ -60,6 +66,7 @@ impl Binder {
         alias: &Option<TableAlias>,
         temporal: &Option<TemporalClause>,
         with_options: &Option<WithOptions>,
+        index_hint: &Option<IndexHint>,
         sample: &Option<SampleConfig>,
     ) -> Result<(SExpr, BindContext)> {
         let table_identifier = TableIdentifier::new(self, catalog, database, table, alias);
@@ -105,6 +112,16 @@ impl Binder {
                     } else {
                         self.bind_r_cte(*span, bind_context, cte_info, &table_name, alias)
                     }
+                } else if cte_info.consumer_count > 1
+                    && self
+                        .ctx
+                        .get_settings()
+                        .get_cte_materialization_threshold()?
+                        > 0
+                    // The materialized blocks are only shared in the local node.
+                    && self.ctx.get_cluster().is_empty()
+                {
+                    self.bind_shared_cte(*span, bind_context, &table_name, alias, cte_info)
                 } else {
                     self.bind_cte(*span, bind_context, &table_name, alias, cte_info)
                 };
@@ -259,8 +276,29 @@ impl Binder {
                         false,
                         None,
                     );
-                    let (s_expr, mut new_bind_context) =
-                        self.bind_query(&mut new_bind_context, query)?;
+                    // The view entry above is kept even if the view is simplified,
+                    // the privileges are checked on it rather than on the base table.
+                    let (s_expr, mut new_bind_context) = match Self::simplify_view(query) {
+                        Some(TableReference::Table {
+                            span,
+                            catalog,
+                            database,
+                            table,
+                            ..
+                        }) => self.bind_table(
+                            &mut new_bind_context,
+                            span,
+                            catalog,
+                            database,
+                            table,
+                            &None,
+                            &None,
+                            &None,
+                            &None,
+                            &None,
+                        )?,
+                        _ => self.bind_query(&mut new_bind_context, query)?,
+                    };
                     if let Some(alias) = alias {
                         // view maybe has alias, e.g. select v1.col1 from v as v1;
                         new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
@@ -291,6 +329,11 @@ impl Binder {
                     false,
                     cte_suffix_name,
                 );
+                if let Some(index_hint) = index_hint {
+                    self.metadata
+                        .write()
+                        .set_table_index_hint(table_index, index_hint.clone());
+                }
 
                 let (s_expr, mut bind_context) = self.bind_base_table(
                     bind_context,
@@ -317,6 +360,53 @@ impl Binder {
         }
     }
 
+    /// Returns the base table of a view defined as a plain `SELECT * FROM table`,
+    /// which is bound in place of the view query. Any other clause, such as a filter,
+    /// a projection or an alias, keeps the view query.
+    fn simplify_view(query: &Query) -> Option<&TableReference> {
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            return None;
+        }
+        let SetExpr::Select(select) = &query.body else {
+            return None;
+        };
+        if select.hints.is_some()
+            || select.distinct
+            || select.top_n.is_some()
+            || select.selection.is_some()
+            || select.group_by.is_some()
+            || select.having.is_some()
+            || select.window_list.is_some()
+            || select.qualify.is_some()
+        {
+            return None;
+        }
+        match select.select_list.as_slice() {
+            [SelectTarget::StarColumns {
+                qualified,
+                column_filter: None,
+            }] if matches!(qualified.as_slice(), [Indirection::Star(_)]) => {}
+            _ => return None,
+        }
+        match select.from.as_slice() {
+            [table @ TableReference::Table {
+                alias: None,
+                temporal: None,
+                with_options: None,
+                index_hint: None,
+                pivot: None,
+                unpivot: None,
+                sample: None,
+                ..
+            }] => Some(table),
+            _ => None,
+        }
+    }
+
     pub(crate) fn check_view_dep(
         bind_context: &BindContext,
         database: &str,
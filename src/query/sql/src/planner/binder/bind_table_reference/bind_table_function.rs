@@ -30,7 +30,13 @@ use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_function::TableFunction;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::flight_sql_client::AuthCredentials;
+use databend_common_expression::flight_sql_client::FlightSqlClient;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::FunctionKind;
 use databend_common_expression::Scalar;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -45,11 +51,16 @@ use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
+use crate::plans::CacheScan;
+use crate::plans::CacheSource;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::ProjectSet;
 use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
 use crate::BindContext;
+use crate::FunctionImportInfo;
+use crate::IndexType;
 use crate::ScalarExpr;
 
 impl Binder {
@@ -61,11 +72,23 @@ impl Binder {
         name: &Identifier,
         params: &[Expr],
         named_params: &[(Identifier, Expr)],
+        with_ordinality: bool,
         alias: &Option<TableAlias>,
         sample: &Option<SampleConfig>,
     ) -> Result<(SExpr, BindContext)> {
         let func_name = normalize_identifier(name, &self.name_resolution_ctx);
 
+        if func_name.name.eq_ignore_ascii_case("unnest") && (params.len() > 1 || with_ordinality) {
+            return self.bind_zip_unnest(bind_context, span, params, with_ordinality, alias);
+        }
+        if with_ordinality {
+            return Err(ErrorCode::SemanticError(format!(
+                "WITH ORDINALITY is only supported by the table function 'unnest', but got '{}'",
+                func_name
+            ))
+            .set_span(*span));
+        }
+
         if BUILTIN_FUNCTIONS
             .get_property(&func_name.name)
             .map(|p| p.kind == FunctionKind::SRF)
@@ -131,6 +154,8 @@ impl Binder {
 
         if func_name.name.eq_ignore_ascii_case("result_scan") {
             self.bind_result_scan(bind_context, span, alias, &table_args)
+        } else if func_name.name.eq_ignore_ascii_case("flight_sql") {
+            self.bind_flight_sql(bind_context, span, alias, &table_args)
         } else {
             // Other table functions always reside is default catalog
             let table_meta: Arc<dyn TableFunction> = self
@@ -225,6 +250,157 @@ impl Binder {
         })
     }
 
+    /// Bind `flight_sql(endpoint, query [, username, password])`, the schema of the remote
+    /// query is fetched from the Flight SQL service when binding.
+    fn bind_flight_sql(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        alias: &Option<TableAlias>,
+        table_args: &TableArgs,
+    ) -> Result<(SExpr, BindContext)> {
+        let (endpoint, query, auth) =
+            parse_flight_sql_args(table_args).map_err(|e| e.set_span(*span))?;
+        let schema = databend_common_base::runtime::block_on(async {
+            let mut client = FlightSqlClient::connect(&endpoint, auth.as_ref()).await?;
+            client.query_schema(&query).await
+        })
+        .map_err(|e| e.set_span(*span))?;
+
+        let import_id = self
+            .metadata
+            .write()
+            .add_function_import(FunctionImportInfo {
+                endpoint,
+                query,
+                auth,
+            });
+
+        let mut new_bind_ctx = BindContext::with_parent(bind_context.clone())?;
+        let mut fields = Vec::with_capacity(schema.num_fields());
+        for field in schema.fields() {
+            let index = self.metadata.write().add_derived_column(
+                field.name().clone(),
+                field.data_type().clone(),
+                None,
+            );
+            fields.push(DataField::new(
+                index.to_string().as_str(),
+                field.data_type().clone(),
+            ));
+            new_bind_ctx.add_column_binding(
+                ColumnBindingBuilder::new(
+                    field.name().clone(),
+                    index,
+                    Box::new(field.data_type().clone()),
+                    Visibility::Visible,
+                )
+                .build(),
+            );
+        }
+        if let Some(alias) = alias {
+            new_bind_ctx.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+
+        let cache_scan = CacheScan {
+            cache_source: CacheSource::FlightSql((import_id, (0..fields.len()).collect())),
+            columns: new_bind_ctx
+                .columns
+                .iter()
+                .map(|column| column.index)
+                .collect(),
+            schema: DataSchemaRefExt::create(fields),
+        };
+        Ok((
+            SExpr::create_leaf(Arc::new(RelOperator::CacheScan(cache_scan))),
+            new_bind_ctx,
+        ))
+    }
+
+    /// Bind `unnest(array1, array2, ...) [WITH ORDINALITY]`, the arrays are zipped together
+    /// and an extra 1-based index column is appended if `with_ordinality` is true.
+    fn bind_zip_unnest(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        params: &[Expr],
+        with_ordinality: bool,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        if params.is_empty() {
+            return Err(ErrorCode::InvalidArgument(
+                "The table function 'unnest' requires at least one argument",
+            )
+            .set_span(*span));
+        }
+
+        let select_list = params
+            .iter()
+            .map(|param| SelectTarget::AliasedExpr {
+                expr: Box::new(Expr::FunctionCall {
+                    span: *span,
+                    func: ASTFunctionCall {
+                        distinct: false,
+                        name: Identifier::from_name(*span, "unnest"),
+                        params: vec![],
+                        args: vec![param.clone()],
+                        order_by: vec![],
+                        window: None,
+                        lambda: None,
+                    },
+                }),
+                alias: None,
+            })
+            .collect();
+        let select_stmt = SelectStmt {
+            span: *span,
+            hints: None,
+            distinct: false,
+            top_n: None,
+            select_list,
+            from: vec![],
+            selection: None,
+            group_by: None,
+            having: None,
+            window_list: None,
+            qualify: None,
+        };
+        let (mut srf_expr, mut bind_context) =
+            self.bind_select(bind_context, &select_stmt, &[], None)?;
+
+        // Set names for the unnest result columns
+        if bind_context.columns.len() == 1 {
+            bind_context.columns[0].column_name = "value".to_string();
+        } else {
+            for (i, column) in bind_context.columns.iter_mut().enumerate() {
+                column.column_name = format!("value_{}", i + 1);
+            }
+        }
+
+        if with_ordinality {
+            let data_type = DataType::Number(NumberDataType::UInt64);
+            let index = self.metadata.write().add_derived_column(
+                "ordinality".to_string(),
+                data_type.clone(),
+                None,
+            );
+            srf_expr = set_project_set_ordinality(&srf_expr, index)?;
+            let column_binding = ColumnBindingBuilder::new(
+                "ordinality".to_string(),
+                index,
+                Box::new(data_type),
+                Visibility::Visible,
+            )
+            .build();
+            bind_context.add_column_binding(column_binding);
+        }
+
+        if let Some(alias) = alias {
+            bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+        Ok((srf_expr, bind_context))
+    }
+
     /// Extract the srf inner tuple fields as columns.
     fn extract_srf_table_function_columns(
         &mut self,
@@ -326,21 +502,31 @@ impl Binder {
                 name,
                 params,
                 named_params,
+                with_ordinality,
                 alias,
                 ..
             } => {
                 let mut bind_context = BindContext::with_parent(parent_context.clone())?;
                 let func_name = normalize_identifier(name, &self.name_resolution_ctx);
+                if *with_ordinality {
+                    return Err(ErrorCode::SemanticError(
+                        "WITH ORDINALITY is not supported for lateral table functions",
+                    )
+                    .set_span(*span));
+                }
 
-                if BUILTIN_FUNCTIONS
+                let is_srf = BUILTIN_FUNCTIONS
                     .get_property(&func_name.name)
                     .map(|p| p.kind == FunctionKind::SRF)
-                    .unwrap_or(false)
-                {
+                    .unwrap_or(false);
+                // A scalar function returning an array, such as `split`, is evaluated
+                // for each input row and its result flattened into rows.
+                let is_flat_map = !is_srf && BUILTIN_FUNCTIONS.contains(&func_name.name);
+                if is_srf || is_flat_map {
                     let args = parse_table_function_args(span, &func_name, params, named_params)?;
 
                     // convert lateral join table function to srf function
-                    let srf = Expr::FunctionCall {
+                    let mut srf = Expr::FunctionCall {
                         span: *span,
                         func: ASTFunctionCall {
                             distinct: false,
@@ -352,6 +538,20 @@ impl Binder {
                             lambda: None,
                         },
                     };
+                    if is_flat_map {
+                        srf = Expr::FunctionCall {
+                            span: *span,
+                            func: ASTFunctionCall {
+                                distinct: false,
+                                name: Identifier::from_name(*span, "unnest"),
+                                args: vec![srf],
+                                params: vec![],
+                                order_by: vec![],
+                                window: None,
+                                lambda: None,
+                            },
+                        };
+                    }
                     let select_list = vec![SelectTarget::AliasedExpr {
                         expr: Box::new(srf.clone()),
                         alias: None,
@@ -361,7 +561,10 @@ impl Binder {
                     // analyze Set-returning functions.
                     self.analyze_project_set_select(&mut bind_context, &mut select_list)?;
                     // bind Set-returning functions.
-                    let srf_expr = self.bind_project_set(&mut bind_context, child, false)?;
+                    let mut srf_expr = self.bind_project_set(&mut bind_context, child, false)?;
+                    if is_flat_map {
+                        srf_expr = set_project_set_flat_map(&srf_expr)?;
+                    }
                     // clear Set-returning functions, avoid duplicate bind.
                     bind_context.srf_info = Default::default();
 
@@ -420,7 +623,7 @@ impl Binder {
                     }
                 } else {
                     Err(ErrorCode::InvalidArgument(format!(
-                        "The function '{}' is not supported for lateral joins. Lateral joins currently support only Set Returning Functions (SRFs).",
+                        "The function '{}' is not supported for lateral joins. Lateral joins currently support only Set Returning Functions (SRFs) and scalar functions returning arrays.",
                         func_name
                     ))
                     .set_span(*span))
@@ -506,3 +709,85 @@ pub fn parse_result_scan_args(table_args: &TableArgs) -> Result<String> {
     let args = table_args.expect_all_positioned("RESULT_SCAN", Some(1))?;
     string_value(&args[0])
 }
+
+fn parse_flight_sql_args(
+    table_args: &TableArgs,
+) -> Result<(String, String, Option<AuthCredentials>)> {
+    let args = table_args.expect_all_positioned("FLIGHT_SQL", None)?;
+    if args.len() != 2 && args.len() != 4 {
+        return Err(ErrorCode::BadArguments(
+            "FLIGHT_SQL must accept 2 positioned args (endpoint, query) or 4 positioned args (endpoint, query, username, password)",
+        ));
+    }
+    let endpoint = string_value(&args[0])?;
+    let query = string_value(&args[1])?;
+    let auth = if args.len() == 4 {
+        Some(AuthCredentials {
+            username: string_value(&args[2])?,
+            password: string_value(&args[3])?,
+        })
+    } else {
+        None
+    };
+    Ok((endpoint, query, auth))
+}
+
+// Mark the `ProjectSet` evaluating the `unnest` functions to output the ordinality column.
+fn set_project_set_ordinality(s_expr: &SExpr, ordinality: IndexType) -> Result<SExpr> {
+    match s_expr.plan() {
+        RelOperator::ProjectSet(project_set) => {
+            let project_set = ProjectSet {
+                srfs: project_set.srfs.clone(),
+                ordinality: Some(ordinality),
+                flat_map: project_set.flat_map,
+            };
+            Ok(s_expr.replace_plan(Arc::new(project_set.into())))
+        }
+        _ if s_expr.arity() == 1 => {
+            let child = set_project_set_ordinality(s_expr.unary_child(), ordinality)?;
+            Ok(s_expr.replace_children([Arc::new(child)]))
+        }
+        _ => Err(ErrorCode::Internal(
+            "Failed to find the ProjectSet of the table function 'unnest'",
+        )),
+    }
+}
+
+fn parse_flight_sql_args(
+    table_args: &TableArgs,
+) -> Result<(String, String, Option<AuthCredentials>)> {
+    let args = table_args.expect_all_positioned("FLIGHT_SQL", None)?;
+    if args.len() != 2 && args.len() != 4 {
+        return Err(ErrorCode::BadArguments(
+            "FLIGHT_SQL must accept 2 positioned args (endpoint, query) or 4 positioned args (endpoint, query, username, password)",
+        ));
+    }
+    let endpoint = string_value(&args[0])?;
+    let query = string_value(&args[1])?;
+    let auth = if args.len() == 4 {
+        Some(AuthCredentials {
+            username: string_value(&args[2])?,
+            password: string_value(&args[3])?,
+        })
+    } else {
+        None
+    };
+    Ok((endpoint, query, auth))
+}
+
+// Mark the `ProjectSet` evaluating a lateral scalar table function to be built as a `FlatMap`.
+fn set_project_set_flat_map(s_expr: &SExpr) -> Result<SExpr> {
+    match s_expr.plan() {
+        RelOperator::ProjectSet(project_set) => {
+            let project_set = ProjectSet {
+                srfs: project_set.srfs.clone(),
+                ordinality: project_set.ordinality,
+                flat_map: true,
+            };
+            Ok(s_expr.replace_plan(Arc::new(project_set.into())))
+        }
+        _ => Err(ErrorCode::Internal(
+            "Failed to find the ProjectSet of a lateral table function",
+        )),
+    }
+}
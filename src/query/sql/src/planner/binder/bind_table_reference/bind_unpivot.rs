@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::Unpivot;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::common_super_type;
+use databend_common_expression::types::DataType;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::BindContext;
+use crate::Visibility;
+
+impl Binder {
+    /// Bind `UNPIVOT` of a table reference, the unpivoted columns are replaced by
+    /// the name column and the value column in the returned context.
+    pub(crate) fn bind_unpivot(
+        &mut self,
+        mut bind_context: BindContext,
+        s_expr: SExpr,
+        unpivot: &Unpivot,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut columns = Vec::with_capacity(unpivot.column_names.len());
+        for name in unpivot.column_names.iter() {
+            let column_name = normalize_identifier(&name.ident, &self.name_resolution_ctx).name;
+            let Some(column) = bind_context.columns.iter().find(|column| {
+                column.column_name == column_name && column.visibility == Visibility::Visible
+            }) else {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Column {} of UNPIVOT is not found",
+                    name.ident
+                ))
+                .set_span(name.ident.span));
+            };
+            columns.push(column.clone());
+        }
+
+        // The values of the columns are in the same column, cast them to the common type.
+        let mut value_type = *columns[0].data_type.clone();
+        for column in columns.iter().skip(1) {
+            value_type = common_super_type(
+                value_type.clone(),
+                *column.data_type.clone(),
+                &BUILTIN_FUNCTIONS.default_cast_rules,
+            )
+            .ok_or_else(|| {
+                ErrorCode::SemanticError(format!(
+                    "The types of the columns of UNPIVOT cannot be matched, column {} has type {}, but the others have type {}",
+                    column.column_name, column.data_type, value_type
+                ))
+            })?;
+        }
+
+        let mut items = vec![];
+        let mut columns_to_unpivot = Vec::with_capacity(columns.len());
+        for column in columns.iter() {
+            if *column.data_type == value_type {
+                columns_to_unpivot.push(column.index);
+                continue;
+            }
+            let scalar = ScalarExpr::CastExpr(CastExpr {
+                span: None,
+                is_try: false,
+                argument: Box::new(
+                    BoundColumnRef {
+                        span: None,
+                        column: column.clone(),
+                    }
+                    .into(),
+                ),
+                target_type: Box::new(value_type.clone()),
+            });
+            let column_binding = self.create_derived_column_binding(
+                column.column_name.clone(),
+                value_type.clone(),
+                Some(scalar.clone()),
+            );
+            items.push(ScalarItem {
+                scalar,
+                index: column_binding.index,
+            });
+            columns_to_unpivot.push(column_binding.index);
+        }
+        let s_expr = match items.is_empty() {
+            true => s_expr,
+            false => SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(s_expr)),
+        };
+
+        let name_column = self.create_derived_column_binding(
+            normalize_identifier(&unpivot.unpivot_column, &self.name_resolution_ctx).name,
+            DataType::String,
+            None,
+        );
+        let value_column = self.create_derived_column_binding(
+            normalize_identifier(&unpivot.value_column, &self.name_resolution_ctx).name,
+            value_type,
+            None,
+        );
+        let unpivot_plan = crate::plans::Unpivot {
+            columns_to_unpivot,
+            names: unpivot
+                .column_names
+                .iter()
+                .map(|name| name.alias.as_ref().unwrap_or(&name.ident.name).to_string())
+                .collect(),
+            name_col: name_column.index,
+            value_col: value_column.index,
+        };
+
+        bind_context
+            .columns
+            .retain(|binding| !columns.iter().any(|column| column.index == binding.index));
+        bind_context.add_column_binding(name_column);
+        bind_context.add_column_binding(value_column);
+
+        let s_expr = SExpr::create_unary(Arc::new(unpivot_plan.into()), Arc::new(s_expr));
+        Ok((s_expr, bind_context))
+    }
+}
@@ -14,11 +14,13 @@
 
 mod bind;
 mod bind_join;
+mod bind_json_table;
 mod bind_location;
 mod bind_obfuscate;
 mod bind_subquery;
 mod bind_table;
 mod bind_table_function;
+mod bind_unpivot;
 
 pub use bind_join::JoinConditions;
 pub use bind_table_function::parse_result_scan_args;
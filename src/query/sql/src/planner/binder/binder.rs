@@ -188,16 +188,41 @@ impl<'a> Binder {
                 } else {
                     None
                 };
+                let rewrite_kind = if !query.ignore_result
+                    && self.ctx.get_settings().get_enable_async_aggregate()?
+                    && s_expr.has_aggregate()
+                {
+                    Some(RewriteKind::AsyncAggregate)
+                } else {
+                    None
+                };
                 Plan::Query {
                     s_expr: Box::new(s_expr),
                     metadata: self.metadata.clone(),
                     bind_context: Box::new(bind_context),
-                    rewrite_kind: None,
+                    rewrite_kind,
                     ignore_result: query.ignore_result,
                     formatted_ast,
                 }
             }
 
+            Statement::AssertExists { query, message } => {
+                let (mut s_expr, bind_context) = self.bind_query(bind_context, query)?;
+                (s_expr, _) = self.construct_expression_scan(&s_expr, self.metadata.clone())?;
+                let message = message
+                    .clone()
+                    .unwrap_or_else(|| "ASSERT EXISTS query returned no rows".to_string());
+                // The result is not cached, an empty result must fail every time.
+                Plan::Query {
+                    s_expr: Box::new(s_expr),
+                    metadata: self.metadata.clone(),
+                    bind_context: Box::new(bind_context),
+                    rewrite_kind: Some(RewriteKind::AssertExists(message)),
+                    ignore_result: false,
+                    formatted_ast: None,
+                }
+            }
+
             Statement::StatementWithSettings { settings, stmt } => {
                 self.bind_statement_settings(bind_context, settings, stmt)
                     .await?
@@ -1012,6 +1037,13 @@ impl<'a> Binder {
                 }
                 f.scalars().is_empty()
             }
+            RelOperator::WindowAgg(window_agg) => {
+                f.reset_finder();
+                for item in &window_agg.aggregate_functions {
+                    f.visit(&item.scalar)?;
+                }
+                f.scalars().is_empty()
+            }
             RelOperator::Exchange(exchange) => {
                 f.reset_finder();
                 if let crate::plans::Exchange::Hash(hash) = exchange {
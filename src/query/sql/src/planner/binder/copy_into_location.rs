@@ -51,6 +51,11 @@ impl Binder {
                 "include_query_id=false can only be set when use_raw_path=true",
             ));
         }
+        if !stmt.options.partition_by.is_empty() && stmt.options.single {
+            return Err(ErrorCode::InvalidArgument(
+                "partition by can not be set when single=true",
+            ));
+        }
 
         let query = match &stmt.src {
             CopyIntoLocationSource::Table(table) => {
@@ -110,6 +115,33 @@ impl Binder {
             }
         }?;
 
+        let mut options = stmt.options.clone();
+        if let Plan::Query { bind_context, .. } = &query {
+            // Use the names of the output columns, the identifiers are matched case-insensitively.
+            options.partition_by = options
+                .partition_by
+                .iter()
+                .map(|name| {
+                    bind_context
+                        .columns
+                        .iter()
+                        .find(|column| &column.column_name == name)
+                        .or_else(|| {
+                            bind_context
+                                .columns
+                                .iter()
+                                .find(|column| column.column_name.eq_ignore_ascii_case(name))
+                        })
+                        .map(|column| column.column_name.clone())
+                        .ok_or_else(|| {
+                            ErrorCode::BadArguments(format!(
+                                "partition by column {name} is not in the output of the query"
+                            ))
+                        })
+                })
+                .collect::<Result<_>>()?;
+        }
+
         let (mut stage_info, path) = resolve_file_location(self.ctx.as_ref(), &stmt.dst).await?;
 
         if stmt.options.use_raw_path {
@@ -139,7 +171,7 @@ impl Binder {
             stage: Box::new(stage_info),
             path,
             from: Box::new(query),
-            options: stmt.options.clone(),
+            options,
         }))
     }
 }
@@ -23,6 +23,7 @@ use databend_common_ast::ast::DropIndexStmt;
 use databend_common_ast::ast::DropInvertedIndexStmt;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::IndexHintKind;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::RefreshIndexStmt;
 use databend_common_ast::ast::RefreshInvertedIndexStmt;
@@ -138,13 +139,22 @@ impl Binder {
 
         for table_entry in tables {
             let table = table_entry.table();
+            let index_hint = metadata
+                .read()
+                .get_table_index_hint(&table_entry.index())
+                .map(|hint| hint.hint.clone());
+            // `FORCE INDEX` applies the hinted indexes even if the index scans are disabled.
+            let forced = index_hint
+                .as_ref()
+                .is_some_and(|hint| hint.kind == IndexHintKind::Force);
             // Avoid death loop
             let mut agg_indexes = vec![];
             if self.ctx.get_can_scan_from_agg_index()
-                && self
-                    .ctx
-                    .get_settings()
-                    .get_enable_aggregating_index_scan()?
+                && (forced
+                    || self
+                        .ctx
+                        .get_settings()
+                        .get_enable_aggregating_index_scan()?)
                 && !bind_context.planning_agg_index
                 && table.support_index()
                 && !matches!(table.engine(), "VIEW" | "STREAM")
@@ -154,13 +164,37 @@ impl Binder {
                     .check_enterprise_enabled(self.ctx.get_license_key(), AggregateIndex)
                     .is_ok()
                 {
-                    let indexes = self
+                    let mut indexes = self
                         .resolve_table_indexes(
                             &self.ctx.get_tenant(),
                             catalog.as_str(),
                             table.get_id(),
                         )
                         .await?;
+                    // Only the hinted indexes are candidates of the optimizer.
+                    if let Some(index_hint) = &index_hint {
+                        let names = index_hint
+                            .indexes
+                            .iter()
+                            .map(|index| self.normalize_object_identifier(index))
+                            .collect::<Vec<_>>();
+                        for name in names.iter() {
+                            if !indexes.iter().any(|(_, index_name, _)| index_name == name) {
+                                self.ctx.push_warning(format!(
+                                    "Index hint ignores unknown aggregating index '{name}' of table '{}'",
+                                    table.name()
+                                ));
+                            }
+                        }
+                        indexes.retain(|(_, index_name, _)| names.contains(index_name));
+                        metadata.write().resolve_table_index_hint(
+                            table_entry.index(),
+                            indexes
+                                .iter()
+                                .map(|(index_id, index_name, _)| (*index_id, index_name.clone()))
+                                .collect(),
+                        );
+                    }
 
                     let mut s_exprs = Vec::with_capacity(indexes.len());
                     for (index_id, _, index_meta) in indexes {
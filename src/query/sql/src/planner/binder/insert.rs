@@ -12,26 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Indirection;
 use databend_common_ast::ast::InsertSource;
 use databend_common_ast::ast::InsertStmt;
+use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::Statement;
+use databend_common_catalog::table::Table;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::DataSchema;
+use databend_common_expression::RemoteExpr;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRefExt;
+use derive_visitor::DriveMut;
 
+use super::project::RemoveIdentifierQuote;
 use super::util::TableIdentifier;
 use crate::binder::Binder;
 use crate::normalize_identifier;
+use crate::planner::expression_parser::bind_table;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::Insert;
 use crate::plans::InsertInputSource;
 use crate::plans::InsertValue;
 use crate::plans::Plan;
 use crate::BindContext;
+use crate::IndexType;
+use crate::TypeChecker;
 
 impl Binder {
     pub fn schema_project(
@@ -81,6 +95,7 @@ impl Binder {
             columns,
             source,
             overwrite,
+            returning,
             ..
         } = stmt;
 
@@ -101,6 +116,13 @@ impl Binder {
 
         let schema = self.schema_project(&table.schema(), columns)?;
 
+        let (returning, returning_names) = self.bind_insert_returning(table.clone(), returning)?;
+        if !returning.is_empty() && !matches!(source, InsertSource::Values { .. }) {
+            return Err(ErrorCode::SemanticError(
+                "RETURNING is only supported in INSERT ... VALUES statement",
+            ));
+        }
+
         let input_source: Result<InsertInputSource> = match source.clone() {
             InsertSource::Values { rows } => {
                 let mut new_rows = Vec::with_capacity(rows.len());
@@ -160,8 +182,112 @@ impl Binder {
             overwrite: *overwrite,
             source: input_source?,
             table_info: None,
+            returning,
+            returning_names,
         };
 
         Ok(Plan::Insert(Box::new(plan)))
     }
+
+    /// Bind the `RETURNING` targets of an insert statement.
+    /// The targets are evaluated on the inserted rows, which are in the order of the table schema
+    /// with the stored computed columns filled, so the column references are resolved to those offsets.
+    fn bind_insert_returning(
+        &mut self,
+        table: Arc<dyn Table>,
+        returning: &[SelectTarget],
+    ) -> Result<(Vec<(RemoteExpr, IndexType)>, Vec<String>)> {
+        if returning.is_empty() {
+            return Ok((vec![], vec![]));
+        }
+
+        let row_schema = DataSchema::from(table.schema().remove_virtual_computed_fields());
+        let (mut table_context, table_metadata) = bind_table(table)?;
+        let mut type_checker = TypeChecker::try_create(
+            &mut table_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            table_metadata.clone(),
+            &[],
+            false,
+        )?;
+
+        let mut items = Vec::with_capacity(returning.len());
+        for target in returning {
+            match target {
+                SelectTarget::AliasedExpr { expr, alias } => {
+                    let (scalar, data_type) = *type_checker.resolve(expr)?;
+                    let scalar_expr = scalar.as_expr()?;
+                    let mut offsets = HashMap::new();
+                    for index in scalar_expr.column_refs().keys() {
+                        let column_name = table_metadata.read().column(*index).name();
+                        let offset = row_schema.index_of(&column_name).map_err(|_| {
+                            ErrorCode::SemanticError(format!(
+                                "Column `{column_name}` can't be referenced in RETURNING"
+                            ))
+                            .set_span(expr.span())
+                        })?;
+                        offsets.insert(*index, offset);
+                    }
+                    let remote_expr = scalar_expr
+                        .project_column_ref(|index| offsets[index])
+                        .as_remote_expr();
+
+                    // Generate the name of the returned column in the same way as the select list.
+                    let name = match (expr.as_ref(), alias) {
+                        (_, Some(alias)) => {
+                            normalize_identifier(alias, &self.name_resolution_ctx).name
+                        }
+                        (
+                            Expr::ColumnRef {
+                                column:
+                                    ColumnRef {
+                                        column: ColumnID::Name(column),
+                                        ..
+                                    },
+                                ..
+                            },
+                            None,
+                        ) => normalize_identifier(column, &self.name_resolution_ctx).name,
+                        _ => {
+                            let mut expr = expr.clone();
+                            let mut remove_quote_visitor = RemoveIdentifierQuote;
+                            expr.drive_mut(&mut remove_quote_visitor);
+                            format!("{:#}", expr)
+                        }
+                    };
+                    items.push((remote_expr, name, data_type));
+                }
+                SelectTarget::StarColumns {
+                    qualified,
+                    column_filter: None,
+                } if qualified.len() == 1 && matches!(qualified[0], Indirection::Star(_)) => {
+                    for (offset, field) in row_schema.fields().iter().enumerate() {
+                        let remote_expr = RemoteExpr::ColumnRef {
+                            span: None,
+                            id: offset,
+                            data_type: field.data_type().clone(),
+                            display_name: field.name().clone(),
+                        };
+                        items.push((remote_expr, field.name().clone(), field.data_type().clone()));
+                    }
+                }
+                SelectTarget::StarColumns { .. } => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Unsupported RETURNING target: {target}"
+                    )));
+                }
+            }
+        }
+
+        let mut metadata = self.metadata.write();
+        let (exprs, names) = items
+            .into_iter()
+            .map(|(expr, name, data_type)| {
+                let index = metadata.add_derived_column(name.clone(), data_type, None);
+                ((expr, index), name)
+            })
+            .unzip();
+        Ok((exprs, names))
+    }
 }
@@ -553,6 +553,18 @@ pub async fn parse_uri_location(
         Scheme::Oss => parse_oss_params(l, root)?,
         Scheme::Cos => parse_cos_params(l, root)?,
         Scheme::Http => {
+            let token = match ctx {
+                Some(ctx) => ctx
+                    .get_settings()
+                    .get_http_authorization_token()
+                    .map_err(|err| {
+                        Error::new(
+                            ErrorKind::InvalidInput,
+                            anyhow!("fail to get http_authorization_token: {err:?}"),
+                        )
+                    })?,
+                None => String::new(),
+            };
             // Make sure path has been percent decoded before parse pattern.
             let cfg = StorageHttpConfig {
                 endpoint_url: format!("{}://{}", l.protocol, l.name),
@@ -565,6 +577,7 @@ pub async fn parse_uri_location(
                     })?
                     .iter()
                     .collect(),
+                token,
             };
 
             // HTTP is special that we don't support dir, always return / instead.
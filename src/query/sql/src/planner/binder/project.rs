@@ -67,7 +67,7 @@ use crate::WindowChecker;
 
 #[derive(VisitorMut)]
 #[visitor(Identifier(enter))]
-struct RemoveIdentifierQuote;
+pub(super) struct RemoveIdentifierQuote;
 
 impl RemoveIdentifierQuote {
     fn enter_identifier(&mut self, ident: &mut Identifier) {
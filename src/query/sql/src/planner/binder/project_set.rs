@@ -269,7 +269,11 @@ impl Binder {
             }
         }
 
-        let project_set = ProjectSet { srfs };
+        let project_set = ProjectSet {
+            srfs,
+            ordinality: None,
+            flat_map: false,
+        };
         let new_expr = SExpr::create_unary(Arc::new(project_set.into()), Arc::new(child));
 
         Ok(new_expr)
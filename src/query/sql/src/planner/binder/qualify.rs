@@ -29,7 +29,7 @@ use crate::optimizer::SExpr;
 use crate::planner::semantic::GroupingChecker;
 use crate::plans::walk_expr_mut;
 use crate::plans::BoundColumnRef;
-use crate::plans::Filter;
+use crate::plans::Qualify;
 use crate::plans::ScalarExpr;
 use crate::plans::SubqueryExpr;
 use crate::plans::Visitor;
@@ -93,10 +93,10 @@ impl Binder {
 
         let predicates = split_conjunctions(&scalar);
 
-        let filter = Filter { predicates };
+        let qualify = Qualify { predicates };
 
         Ok(SExpr::create_unary(
-            Arc::new(filter.into()),
+            Arc::new(qualify.into()),
             Arc::new(child),
         ))
     }
@@ -32,7 +32,6 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 
 use super::sort::OrderItem;
 use super::Finder;
-use crate::binder::bind_table_reference::JoinConditions;
 use crate::binder::scalar_common::split_conjunctions;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::ExprContext;
@@ -43,8 +42,10 @@ use crate::planner::binder::BindContext;
 use crate::planner::binder::Binder;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::Except;
 use crate::plans::Filter;
-use crate::plans::JoinType;
+use crate::plans::Intersect;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::UnionAll;
@@ -157,28 +158,24 @@ impl Binder {
         }
 
         match (op, all) {
-            (SetOperator::Intersect, false) => {
-                // Transfer Intersect to Semi join
-                self.bind_intersect(
-                    left.span(),
-                    right.span(),
-                    left_bind_context,
-                    right_bind_context,
-                    left_expr,
-                    right_expr,
-                )
-            }
-            (SetOperator::Except, false) => {
-                // Transfer Except to Anti join
-                self.bind_except(
-                    left.span(),
-                    right.span(),
-                    left_bind_context,
-                    right_bind_context,
-                    left_expr,
-                    right_expr,
-                )
-            }
+            (SetOperator::Intersect, all) => self.bind_intersect(
+                left.span(),
+                right.span(),
+                left_bind_context,
+                right_bind_context,
+                left_expr,
+                right_expr,
+                *all,
+            ),
+            (SetOperator::Except, all) => self.bind_except(
+                left.span(),
+                right.span(),
+                left_bind_context,
+                right_bind_context,
+                left_expr,
+                right_expr,
+                *all,
+            ),
             (SetOperator::Union, true) => self.bind_union(
                 left.span(),
                 right.span(),
@@ -199,9 +196,6 @@ impl Binder {
                 true,
                 cte_name,
             ),
-            _ => Err(ErrorCode::Unimplemented(
-                "Unsupported query type, currently, databend only support intersect distinct and except distinct",
-            )),
         }
     }
 
@@ -227,31 +221,7 @@ impl Binder {
                 ));
             }
         } else {
-            for (left_col, right_col) in left_context
-                .columns
-                .iter()
-                .zip(right_context.columns.iter())
-            {
-                if left_col.data_type != right_col.data_type {
-                    if let Some(data_type) = common_super_type(
-                        *left_col.data_type.clone(),
-                        *right_col.data_type.clone(),
-                        &BUILTIN_FUNCTIONS.default_cast_rules,
-                    ) {
-                        coercion_types.push(data_type);
-                    } else {
-                        return Err(ErrorCode::SemanticError(format!(
-                            "SetOperation's types cannot be matched, left column {:?}, type: {:?}, right column {:?}, type: {:?}",
-                            left_col.column_name,
-                            left_col.data_type,
-                            right_col.column_name,
-                            right_col.data_type
-                        )));
-                    }
-                } else {
-                    coercion_types.push(*left_col.data_type.clone());
-                }
-            }
+            coercion_types = Self::set_operation_coercion_types(&left_context, &right_context)?;
         }
 
         let (mut new_bind_context, left_outputs, right_outputs) = self.coercion_union_type(
@@ -306,6 +276,9 @@ impl Binder {
         Ok((new_expr, new_bind_context))
     }
 
+    /// Bind `INTERSECT [ALL]` to an `Intersect` operator, which is executed by a semi hash join.
+    /// The distinct of `INTERSECT` is applied to the result of the `Intersect`.
+    #[allow(clippy::too_many_arguments)]
     pub fn bind_intersect(
         &mut self,
         left_span: Span,
@@ -314,18 +287,49 @@ impl Binder {
         right_context: BindContext,
         left_expr: SExpr,
         right_expr: SExpr,
+        all: bool,
     ) -> Result<(SExpr, BindContext)> {
-        self.bind_intersect_or_except(
-            left_span,
+        let coercion_types = Self::set_operation_coercion_types(&left_context, &right_context)?;
+        let (left_expr, mut left_context) =
+            self.coerce_set_operation_input(left_span, left_context, left_expr, &coercion_types)?;
+        let (right_expr, right_context) = self.coerce_set_operation_input(
             right_span,
-            left_context,
             right_context,
-            left_expr,
             right_expr,
-            JoinType::LeftSemi,
-        )
+            &coercion_types,
+        )?;
+
+        let intersect = Intersect {
+            left_outputs: left_context.columns.clone(),
+            right_outputs: right_context.columns.clone(),
+            all,
+        };
+        let mut s_expr = SExpr::create_binary(
+            Arc::new(intersect.into()),
+            Arc::new(left_expr),
+            Arc::new(right_expr),
+        );
+
+        if !all {
+            let columns = left_context.all_column_bindings().to_vec();
+            s_expr = self.bind_distinct(
+                left_span,
+                &mut left_context,
+                &columns,
+                &mut HashMap::new(),
+                s_expr,
+            )?;
+        }
+
+        left_context
+            .cte_context
+            .set_cte_context(right_context.cte_context);
+        Ok((s_expr, left_context))
     }
 
+    /// Bind `EXCEPT [ALL]` to an `Except` operator, which is executed by an anti hash join.
+    /// The distinct of `EXCEPT` is applied to the result of the `Except`.
+    #[allow(clippy::too_many_arguments)]
     pub fn bind_except(
         &mut self,
         left_span: Span,
@@ -334,79 +338,124 @@ impl Binder {
         right_context: BindContext,
         left_expr: SExpr,
         right_expr: SExpr,
+        all: bool,
     ) -> Result<(SExpr, BindContext)> {
-        self.bind_intersect_or_except(
-            left_span,
+        let coercion_types = Self::set_operation_coercion_types(&left_context, &right_context)?;
+        let (left_expr, mut left_context) =
+            self.coerce_set_operation_input(left_span, left_context, left_expr, &coercion_types)?;
+        let (right_expr, right_context) = self.coerce_set_operation_input(
             right_span,
-            left_context,
             right_context,
-            left_expr,
             right_expr,
-            JoinType::LeftAnti,
-        )
+            &coercion_types,
+        )?;
+
+        let except = Except {
+            left_outputs: left_context.columns.clone(),
+            right_outputs: right_context.columns.clone(),
+            all,
+        };
+        let mut s_expr = SExpr::create_binary(
+            Arc::new(except.into()),
+            Arc::new(left_expr),
+            Arc::new(right_expr),
+        );
+
+        if !all {
+            let columns = left_context.all_column_bindings().to_vec();
+            s_expr = self.bind_distinct(
+                left_span,
+                &mut left_context,
+                &columns,
+                &mut HashMap::new(),
+                s_expr,
+            )?;
+        }
+
+        left_context
+            .cte_context
+            .set_cte_context(right_context.cte_context);
+        Ok((s_expr, left_context))
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub fn bind_intersect_or_except(
-        &mut self,
-        left_span: Span,
-        right_span: Span,
-        mut left_context: BindContext,
-        right_context: BindContext,
-        left_expr: SExpr,
-        right_expr: SExpr,
-        join_type: JoinType,
-    ) -> Result<(SExpr, BindContext)> {
-        let columns = left_context.all_column_bindings().to_vec();
-        let left_expr = self.bind_distinct(
-            left_span,
-            &mut left_context,
-            &columns,
-            &mut HashMap::new(),
-            left_expr,
-        )?;
-        let mut left_conditions = Vec::with_capacity(left_context.columns.len());
-        let mut right_conditions = Vec::with_capacity(right_context.columns.len());
-        assert_eq!(left_context.columns.len(), right_context.columns.len());
-        for (left_column, right_column) in left_context
+    fn set_operation_coercion_types(
+        left_context: &BindContext,
+        right_context: &BindContext,
+    ) -> Result<Vec<DataType>> {
+        let mut coercion_types = Vec::with_capacity(left_context.columns.len());
+        for (left_col, right_col) in left_context
             .columns
             .iter()
             .zip(right_context.columns.iter())
         {
-            left_conditions.push(
-                BoundColumnRef {
-                    span: left_span,
-                    column: left_column.clone(),
-                }
-                .into(),
-            );
-            right_conditions.push(
-                BoundColumnRef {
-                    span: right_span,
-                    column: right_column.clone(),
+            if left_col.data_type != right_col.data_type {
+                if let Some(data_type) = common_super_type(
+                    *left_col.data_type.clone(),
+                    *right_col.data_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                ) {
+                    coercion_types.push(data_type);
+                } else {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "SetOperation's types cannot be matched, left column {:?}, type: {:?}, right column {:?}, type: {:?}",
+                        left_col.column_name,
+                        left_col.data_type,
+                        right_col.column_name,
+                        right_col.data_type
+                    )));
                 }
-                .into(),
+            } else {
+                coercion_types.push(*left_col.data_type.clone());
+            }
+        }
+        Ok(coercion_types)
+    }
+
+    /// Cast the columns of a set operation input to `coercion_types` with an `EvalScalar`,
+    /// the cast columns are replaced by the derived columns in the returned context.
+    fn coerce_set_operation_input(
+        &mut self,
+        span: Span,
+        mut bind_context: BindContext,
+        s_expr: SExpr,
+        coercion_types: &[DataType],
+    ) -> Result<(SExpr, BindContext)> {
+        let mut items = vec![];
+        for (column, data_type) in bind_context.columns.iter_mut().zip(coercion_types) {
+            if *column.data_type == *data_type {
+                continue;
+            }
+            let scalar = ScalarExpr::CastExpr(CastExpr {
+                span,
+                is_try: false,
+                argument: Box::new(
+                    BoundColumnRef {
+                        span,
+                        column: column.clone(),
+                    }
+                    .into(),
+                ),
+                target_type: Box::new(data_type.clone()),
+            });
+            let mut column_binding = self.create_derived_column_binding(
+                column.column_name.clone(),
+                data_type.clone(),
+                Some(scalar.clone()),
             );
+            column_binding.visibility = column.visibility.clone();
+            items.push(ScalarItem {
+                scalar,
+                index: column_binding.index,
+            });
+            *column = column_binding;
         }
-        let is_null_equal = (0..left_conditions.len()).collect();
-        let join_conditions = JoinConditions {
-            left_conditions,
-            right_conditions,
-            non_equi_conditions: vec![],
-            other_conditions: vec![],
-        };
-        let s_expr = self.bind_join_with_type(
-            join_type,
-            join_conditions,
-            left_expr,
-            right_expr,
-            is_null_equal,
-            None,
-        )?;
-        left_context
-            .cte_context
-            .set_cte_context(right_context.cte_context);
-        Ok((s_expr, left_context))
+
+        if items.is_empty() {
+            return Ok((s_expr, bind_context));
+        }
+        let eval_scalar = EvalScalar { items };
+        let s_expr = SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(s_expr));
+        Ok((s_expr, bind_context))
     }
 
     #[allow(clippy::type_complexity)]
@@ -44,6 +44,7 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::FunctionContext;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::principal::StageInfo;
@@ -64,6 +65,8 @@ use crate::binder::Visibility;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::CacheScan;
+use crate::plans::CacheSource;
 use crate::plans::DummyTableScan;
 use crate::plans::RecursiveCteScan;
 use crate::plans::RelOperator;
@@ -73,6 +76,7 @@ use crate::BaseTableColumn;
 use crate::BindContext;
 use crate::ColumnEntry;
 use crate::IndexType;
+use crate::SharedCte;
 
 impl Binder {
     pub fn bind_dummy_table(
@@ -228,6 +232,80 @@ impl Binder {
         Ok((s_expr, res_bind_context))
     }
 
+    /// Bind a consumer of a cte referenced more than once. The body is bound only once by the
+    /// first consumer and shared by all the consumers, which read its output by `CacheScan`.
+    /// The physical plan builder decides whether to materialize the body or inline it.
+    pub(crate) fn bind_shared_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let cte_id = self
+            .metadata
+            .read()
+            .get_shared_cte_id(table_name, &cte_info.query);
+        let cte_id = match cte_id {
+            Some(cte_id) => cte_id,
+            None => {
+                let (s_expr, body_context) =
+                    self.bind_cte(span, bind_context, table_name, &None, cte_info)?;
+                self.metadata.write().add_shared_cte(SharedCte {
+                    name: table_name.to_string(),
+                    query: cte_info.query.clone(),
+                    s_expr,
+                    columns: body_context.columns,
+                    consumer_count: cte_info.consumer_count,
+                    optimized: false,
+                })
+            }
+        };
+
+        let columns = self.metadata.read().shared_cte(cte_id).columns.clone();
+        let mut new_bind_ctx = BindContext::with_parent(bind_context.clone())?;
+        let mut fields = Vec::with_capacity(columns.len());
+        for column in columns.iter() {
+            let index = self.metadata.write().add_derived_column(
+                column.column_name.clone(),
+                *column.data_type.clone(),
+                None,
+            );
+            fields.push(DataField::new(
+                index.to_string().as_str(),
+                *column.data_type.clone(),
+            ));
+            new_bind_ctx.add_column_binding(
+                ColumnBindingBuilder::new(
+                    column.column_name.clone(),
+                    index,
+                    column.data_type.clone(),
+                    column.visibility.clone(),
+                )
+                .table_name(column.table_name.clone())
+                .build(),
+            );
+        }
+        if let Some(alias) = alias {
+            new_bind_ctx.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+
+        let cache_scan = CacheScan {
+            cache_source: CacheSource::Cte((cte_id, (0..fields.len()).collect())),
+            columns: new_bind_ctx
+                .columns
+                .iter()
+                .map(|column| column.index)
+                .collect(),
+            schema: DataSchemaRefExt::create(fields),
+        };
+        Ok((
+            SExpr::create_leaf(Arc::new(RelOperator::CacheScan(cache_scan))),
+            new_bind_ctx,
+        ))
+    }
+
     pub(crate) fn bind_r_cte_scan(
         &mut self,
         bind_context: &mut BindContext,
@@ -407,6 +485,19 @@ impl Binder {
             .write()
             .add_base_column_scan_id(base_column_scan_id);
 
+        let mut sample = sample.clone();
+        if let Some(system) = sample.as_mut().and_then(|sample| sample.system.as_mut()) {
+            if !(0.0..=100.0).contains(&system.probability) {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Sample value should be between 0 and 100, but got {}",
+                    system.probability
+                )));
+            }
+            // The seed is fixed here, so the blocks are sampled the same by all the pruners
+            // of the scan, even on different nodes.
+            system.seed.get_or_insert_with(rand::random);
+        }
+
         Ok((
             SExpr::create_leaf(Arc::new(
                 Scan {
@@ -414,7 +505,7 @@ impl Binder {
                     columns: columns.into_iter().map(|col| col.index()).collect(),
                     statistics: Arc::new(Statistics::default()),
                     change_type,
-                    sample: sample.clone(),
+                    sample,
                     scan_id,
                     ..Default::default()
                 }
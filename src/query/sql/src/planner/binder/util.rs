@@ -48,16 +48,22 @@ impl Binder {
         cte_types: &mut Vec<DataType>,
     ) -> Result<()> {
         match expr.plan() {
-            RelOperator::Join(_) | RelOperator::UnionAll(_) => {
+            RelOperator::Join(_)
+            | RelOperator::UnionAll(_)
+            | RelOperator::Except(_)
+            | RelOperator::Intersect(_) => {
                 self.count_r_cte_scan(expr.child(0)?, cte_scan_names, cte_types)?;
                 self.count_r_cte_scan(expr.child(1)?, cte_scan_names, cte_types)?;
             }
 
             RelOperator::ProjectSet(_)
+            | RelOperator::Unpivot(_)
+            | RelOperator::JsonTable(_)
             | RelOperator::AsyncFunction(_)
             | RelOperator::Udf(_)
             | RelOperator::EvalScalar(_)
-            | RelOperator::Filter(_) => {
+            | RelOperator::Filter(_)
+            | RelOperator::Qualify(_) => {
                 self.count_r_cte_scan(expr.child(0)?, cte_scan_names, cte_types)?;
             }
             RelOperator::RecursiveCteScan(plan) => {
@@ -80,6 +86,7 @@ impl Binder {
             RelOperator::Sort(_)
             | RelOperator::Limit(_)
             | RelOperator::Aggregate(_)
+            | RelOperator::WindowAgg(_)
             | RelOperator::Window(_)
             | RelOperator::Mutation(_)
             | RelOperator::MutationSource(_)
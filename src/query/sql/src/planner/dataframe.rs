@@ -63,6 +63,7 @@ impl Dataframe {
             alias: None,
             temporal: None,
             with_options: None,
+            index_hint: None,
             pivot: None,
             unpivot: None,
             sample: None,
@@ -430,6 +431,7 @@ impl Dataframe {
             dataframe.bind_context,
             self.s_expr,
             dataframe.s_expr,
+            false,
         )?;
         self.s_expr = s_expr;
         self.bind_context = bind_context;
@@ -444,6 +446,7 @@ impl Dataframe {
             dataframe.bind_context,
             self.s_expr,
             dataframe.s_expr,
+            false,
         )?;
         self.s_expr = s_expr;
         self.bind_context = bind_context;
@@ -466,6 +469,7 @@ impl Dataframe {
                 alias: None,
                 temporal: None,
                 with_options: None,
+                index_hint: None,
                 pivot: None,
                 unpivot: None,
                 sample: None,
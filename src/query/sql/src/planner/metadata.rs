@@ -20,11 +20,14 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 
 use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::IndexHint;
 use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Query;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::InternalColumn;
 use databend_common_catalog::table::Table;
 use databend_common_expression::display::display_tuple_field_name;
+use databend_common_expression::flight_sql_client::AuthCredentials;
 use databend_common_expression::types::DataType;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::Scalar;
@@ -33,6 +36,7 @@ use databend_common_expression::TableField;
 use parking_lot::RwLock;
 
 use crate::optimizer::SExpr;
+use crate::ColumnBinding;
 use crate::ScalarExpr;
 
 /// Planner use [`usize`] as it's index type.
@@ -62,6 +66,8 @@ pub struct Metadata {
     /// Table column indexes that are lazy materialized.
     table_lazy_columns: HashMap<IndexType, ColumnSet>,
     table_source: HashMap<IndexType, DataSourcePlan>,
+    /// The index hints of the tables, e.g. `FROM t USE INDEX (idx)`.
+    table_index_hints: HashMap<IndexType, TableIndexHint>,
     retained_columns: HashSet<IndexType>,
     /// Columns that are lazy materialized.
     lazy_columns: HashSet<IndexType>,
@@ -79,6 +85,10 @@ pub struct Metadata {
     next_scan_id: usize,
     /// Mappings from base column index to scan id.
     base_column_scan_id: HashMap<IndexType, usize>,
+    /// The bodies of the CTEs shared by their consumers, indexed by the CTE id.
+    shared_ctes: Vec<SharedCte>,
+    /// The remote queries of the `flight_sql` table functions, indexed by the import id.
+    function_imports: Vec<FunctionImportInfo>,
 }
 
 impl Metadata {
@@ -157,6 +167,28 @@ impl Metadata {
         self.table_source.get(table_index)
     }
 
+    pub fn set_table_index_hint(&mut self, table_index: IndexType, hint: IndexHint) {
+        self.table_index_hints.insert(table_index, TableIndexHint {
+            hint,
+            agg_indexes: vec![],
+        });
+    }
+
+    pub fn get_table_index_hint(&self, table_index: &IndexType) -> Option<&TableIndexHint> {
+        self.table_index_hints.get(table_index)
+    }
+
+    /// Record the aggregating indexes named by the index hint of the table.
+    pub fn resolve_table_index_hint(
+        &mut self,
+        table_index: IndexType,
+        agg_indexes: Vec<(u64, String)>,
+    ) {
+        if let Some(hint) = self.table_index_hints.get_mut(&table_index) {
+            hint.agg_indexes = agg_indexes;
+        }
+    }
+
     pub fn is_lazy_column(&self, index: usize) -> bool {
         self.lazy_columns.contains(&index)
     }
@@ -252,6 +284,7 @@ impl Metadata {
             path_indices,
             column_id,
             virtual_expr,
+            masking_policy: None,
         });
         self.columns.push(column_entry);
         column_index
@@ -376,6 +409,7 @@ impl Metadata {
             source_of_stage,
         };
         self.tables.push(table_entry);
+        let first_column_index = self.columns.len();
         let table_schema = table_meta.schema_with_stream();
         let mut index = 0;
         let mut fields = VecDeque::with_capacity(table_schema.fields().len());
@@ -455,6 +489,17 @@ impl Metadata {
             }
         }
 
+        if let Some(column_mask_policy) = &table_meta.get_table_info().meta.column_mask_policy {
+            for column in self.columns[first_column_index..].iter_mut() {
+                if let ColumnEntry::BaseTableColumn(column) = column {
+                    if column.path_indices.is_none() {
+                        column.masking_policy =
+                            column_mask_policy.get(&column.column_name).cloned();
+                    }
+                }
+            }
+        }
+
         table_index
     }
 
@@ -490,6 +535,38 @@ impl Metadata {
         self.base_column_scan_id.get(&column_index).cloned()
     }
 
+    pub fn add_shared_cte(&mut self, cte: SharedCte) -> usize {
+        self.shared_ctes.push(cte);
+        self.shared_ctes.len() - 1
+    }
+
+    pub fn shared_cte(&self, cte_id: usize) -> &SharedCte {
+        self.shared_ctes
+            .get(cte_id)
+            .expect("metadata must contain shared cte")
+    }
+
+    pub fn shared_ctes_mut(&mut self) -> &mut [SharedCte] {
+        &mut self.shared_ctes
+    }
+
+    pub fn get_shared_cte_id(&self, name: &str, query: &Query) -> Option<usize> {
+        self.shared_ctes
+            .iter()
+            .position(|cte| cte.name == name && &cte.query == query)
+    }
+
+    pub fn add_function_import(&mut self, import: FunctionImportInfo) -> usize {
+        self.function_imports.push(import);
+        self.function_imports.len() - 1
+    }
+
+    pub fn function_import(&self, import_id: usize) -> &FunctionImportInfo {
+        self.function_imports
+            .get(import_id)
+            .expect("metadata must contain function import")
+    }
+
     fn remove_cte_suffix(mut table_name: String, cte_suffix_name: Option<String>) -> String {
         if let Some(suffix) = cte_suffix_name {
             if table_name.ends_with(&suffix) {
@@ -506,6 +583,38 @@ impl Metadata {
     }
 }
 
+/// A CTE referenced more than once, whose body is bound only once and shared by
+/// all the consumers, which are bound as `CacheScan` with `CacheSource::Cte`.
+#[derive(Clone, Debug)]
+pub struct SharedCte {
+    pub name: String,
+    pub query: Query,
+    pub s_expr: SExpr,
+    /// The output columns of the body.
+    pub columns: Vec<ColumnBinding>,
+    pub consumer_count: usize,
+    /// Whether the body has been optimized.
+    pub optimized: bool,
+}
+
+/// A query sent to a remote Arrow Flight SQL service by the `flight_sql` table function,
+/// which is bound as `CacheScan` with `CacheSource::FlightSql`.
+#[derive(Clone, Debug)]
+pub struct FunctionImportInfo {
+    pub endpoint: String,
+    pub query: String,
+    pub auth: Option<AuthCredentials>,
+}
+
+/// The index hint of a table in the query.
+#[derive(Clone, Debug)]
+pub struct TableIndexHint {
+    pub hint: IndexHint,
+    /// The ids and names of the aggregating indexes of the table named by the hint,
+    /// resolved by the binder.
+    pub agg_indexes: Vec<(u64, String)>,
+}
+
 #[derive(Clone)]
 pub struct TableEntry {
     catalog: String,
@@ -620,6 +729,8 @@ pub struct BaseTableColumn {
     pub column_id: Option<u32>,
     /// Virtual computed expression, generated in query.
     pub virtual_expr: Option<String>,
+    /// The name of the data mask policy attached to the column.
+    pub masking_policy: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -82,14 +82,21 @@ impl DefaultCostModel {
             RelOperator::ConstantTableScan(plan) => self.compute_cost_constant_scan(plan),
             RelOperator::DummyTableScan(_) => Ok(Cost(0.0)),
             RelOperator::Join(plan) => self.compute_cost_join(memo, m_expr, plan),
-            RelOperator::UnionAll(_) => self.compute_cost_union_all(memo, m_expr),
-            RelOperator::Aggregate(_) => self.compute_aggregate(memo, m_expr),
+            RelOperator::UnionAll(_) | RelOperator::Except(_) | RelOperator::Intersect(_) => {
+                self.compute_cost_union_all(memo, m_expr)
+            }
+            RelOperator::Aggregate(_) | RelOperator::WindowAgg(_) => {
+                self.compute_aggregate(memo, m_expr)
+            }
 
             RelOperator::EvalScalar(_)
             | RelOperator::Filter(_)
+            | RelOperator::Qualify(_)
             | RelOperator::Window(_)
             | RelOperator::Sort(_)
             | RelOperator::ProjectSet(_)
+            | RelOperator::Unpivot(_)
+            | RelOperator::JsonTable(_)
             | RelOperator::Udf(_)
             | RelOperator::Limit(_) => self.compute_cost_unary_common_operator(memo, m_expr),
 
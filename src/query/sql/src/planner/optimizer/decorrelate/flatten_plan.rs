@@ -231,7 +231,14 @@ impl SubqueryRewriter {
             .map(|index| Self::scalar_item_from_index(*index, "outer.", &metadata))
             .collect();
         Ok(SExpr::create_unary(
-            Arc::new(ProjectSet { srfs }.into()),
+            Arc::new(
+                ProjectSet {
+                    srfs,
+                    ordinality: project_set.ordinality,
+                    flat_map: project_set.flat_map,
+                }
+                .into(),
+            ),
             Arc::new(SExpr::create_unary(
                 Arc::new(
                     EvalScalar {
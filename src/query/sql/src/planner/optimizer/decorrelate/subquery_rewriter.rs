@@ -109,6 +109,16 @@ impl SubqueryRewriter {
 
                 Ok(SExpr::create_unary(Arc::new(plan.into()), Arc::new(input)))
             }
+            RelOperator::Qualify(mut plan) => {
+                let mut input = self.rewrite(s_expr.child(0)?)?;
+                for pred in plan.predicates.iter_mut() {
+                    let res = self.try_rewrite_subquery(pred, &input, true)?;
+                    input = res.1;
+                    *pred = res.0;
+                }
+
+                Ok(SExpr::create_unary(Arc::new(plan.into()), Arc::new(input)))
+            }
             RelOperator::ProjectSet(mut plan) => {
                 let mut input = self.rewrite(s_expr.child(0)?)?;
                 for item in plan.srfs.iter_mut() {
@@ -137,6 +147,18 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(Arc::new(plan.into()), Arc::new(input)))
             }
 
+            RelOperator::WindowAgg(mut plan) => {
+                let mut input = self.rewrite(s_expr.child(0)?)?;
+
+                for item in plan.aggregate_functions.iter_mut() {
+                    let res = self.try_rewrite_subquery(&item.scalar, &input, false)?;
+                    input = res.1;
+                    item.scalar = res.0;
+                }
+
+                Ok(SExpr::create_unary(Arc::new(plan.into()), Arc::new(input)))
+            }
+
             RelOperator::Window(mut plan) => {
                 let mut input = self.rewrite(s_expr.child(0)?)?;
 
@@ -178,18 +200,23 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(Arc::new(sort.into()), Arc::new(input)))
             }
 
-            RelOperator::Join(_) | RelOperator::UnionAll(_) => Ok(SExpr::create_binary(
+            RelOperator::Join(_)
+            | RelOperator::UnionAll(_)
+            | RelOperator::Except(_)
+            | RelOperator::Intersect(_) => Ok(SExpr::create_binary(
                 Arc::new(s_expr.plan().clone()),
                 Arc::new(self.rewrite(s_expr.child(0)?)?),
                 Arc::new(self.rewrite(s_expr.child(1)?)?),
             )),
 
-            RelOperator::Limit(_) | RelOperator::Udf(_) | RelOperator::AsyncFunction(_) => {
-                Ok(SExpr::create_unary(
-                    Arc::new(s_expr.plan().clone()),
-                    Arc::new(self.rewrite(s_expr.child(0)?)?),
-                ))
-            }
+            RelOperator::Limit(_)
+            | RelOperator::Udf(_)
+            | RelOperator::AsyncFunction(_)
+            | RelOperator::Unpivot(_)
+            | RelOperator::JsonTable(_) => Ok(SExpr::create_unary(
+                Arc::new(s_expr.plan().clone()),
+                Arc::new(self.rewrite(s_expr.child(0)?)?),
+            )),
 
             RelOperator::DummyTableScan(_)
             | RelOperator::Scan(_)
@@ -81,6 +81,12 @@ pub async fn dynamic_sample(
             )
             .await
         }
+        // The predicates of `Qualify` are evaluated on the results of window functions,
+        // which are not sampled.
+        RelOperator::Qualify(_) => {
+            let rel_expr = RelExpr::with_s_expr(s_expr);
+            rel_expr.derive_cardinality()
+        }
         RelOperator::Join(_) => {
             join_selectivity_sample(ctx, metadata, s_expr, sample_executor).await
         }
@@ -111,6 +117,23 @@ pub async fn dynamic_sample(
             let limit = Limit::try_from(s_expr.plan().clone())?;
             limit.derive_limit_stats(child_stat_info)
         }
+        RelOperator::Except(except) => {
+            let left_stat_info =
+                dynamic_sample(ctx, metadata, s_expr.child(0)?, sample_executor).await?;
+            except.derive_except_stats(left_stat_info)
+        }
+        RelOperator::Intersect(intersect) => {
+            let left_stat_info = dynamic_sample(
+                ctx.clone(),
+                metadata.clone(),
+                s_expr.child(0)?,
+                sample_executor.clone(),
+            )
+            .await?;
+            let right_stat_info =
+                dynamic_sample(ctx, metadata, s_expr.child(1)?, sample_executor).await?;
+            intersect.derive_intersect_stats(left_stat_info, right_stat_info)
+        }
         RelOperator::UnionAll(_) => {
             let left_stat_info = dynamic_sample(
                 ctx.clone(),
@@ -133,11 +156,28 @@ pub async fn dynamic_sample(
             let project_set = ProjectSet::try_from(s_expr.plan().clone())?;
             project_set.derive_project_set_stats(&mut child_stat_info)
         }
+        RelOperator::Unpivot(unpivot) => {
+            let mut child_stat_info =
+                dynamic_sample(ctx, metadata, s_expr.child(0)?, sample_executor)
+                    .await?
+                    .deref()
+                    .clone();
+            unpivot.derive_unpivot_stats(&mut child_stat_info)
+        }
+        RelOperator::JsonTable(json_table) => {
+            let mut child_stat_info =
+                dynamic_sample(ctx, metadata, s_expr.child(0)?, sample_executor)
+                    .await?
+                    .deref()
+                    .clone();
+            json_table.derive_json_table_stats(&mut child_stat_info)
+        }
 
         RelOperator::EvalScalar(_)
         | RelOperator::Sort(_)
         | RelOperator::Exchange(_)
         | RelOperator::Window(_)
+        | RelOperator::WindowAgg(_)
         | RelOperator::Udf(_)
         | RelOperator::AsyncFunction(_) => {
             dynamic_sample(ctx, metadata, s_expr.child(0)?, sample_executor).await
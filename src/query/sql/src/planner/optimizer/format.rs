@@ -43,10 +43,13 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::Join(_) => "Join".to_string(),
         RelOperator::EvalScalar(_) => "EvalScalar".to_string(),
         RelOperator::Filter(_) => "Filter".to_string(),
+        RelOperator::Qualify(_) => "Qualify".to_string(),
         RelOperator::Aggregate(_) => "Aggregate".to_string(),
         RelOperator::Sort(_) => "Sort".to_string(),
         RelOperator::Limit(_) => "Limit".to_string(),
         RelOperator::UnionAll(_) => "UnionAll".to_string(),
+        RelOperator::Except(_) => "Except".to_string(),
+        RelOperator::Intersect(_) => "Intersect".to_string(),
         RelOperator::Exchange(op) => {
             format!("Exchange: ({})", match op {
                 Exchange::Hash(scalars) => format!(
@@ -64,11 +67,14 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         }
         RelOperator::DummyTableScan(_) => "DummyTableScan".to_string(),
         RelOperator::ProjectSet(_) => "ProjectSet".to_string(),
+        RelOperator::Unpivot(_) => "Unpivot".to_string(),
+        RelOperator::JsonTable(_) => "JsonTable".to_string(),
         RelOperator::Window(_) => "WindowFunc".to_string(),
         RelOperator::ConstantTableScan(s) => s.name().to_string(),
         RelOperator::ExpressionScan(_) => "ExpressionScan".to_string(),
         RelOperator::CacheScan(_) => "CacheScan".to_string(),
         RelOperator::Udf(_) => "Udf".to_string(),
+        RelOperator::WindowAgg(_) => "WindowAgg".to_string(),
         RelOperator::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
         RelOperator::AsyncFunction(_) => "AsyncFunction".to_string(),
         RelOperator::Mutation(_) => "MergeInto".to_string(),
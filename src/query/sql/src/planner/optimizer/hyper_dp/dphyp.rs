@@ -178,6 +178,7 @@ impl DPhpy {
                     left_op,
                     RelOperator::EvalScalar(_)
                         | RelOperator::Aggregate(_)
+                        | RelOperator::WindowAgg(_)
                         | RelOperator::Sort(_)
                         | RelOperator::Limit(_)
                         | RelOperator::ProjectSet(_)
@@ -190,6 +191,7 @@ impl DPhpy {
                     right_op,
                     RelOperator::EvalScalar(_)
                         | RelOperator::Aggregate(_)
+                        | RelOperator::WindowAgg(_)
                         | RelOperator::Sort(_)
                         | RelOperator::Limit(_)
                         | RelOperator::ProjectSet(_)
@@ -246,7 +248,11 @@ impl DPhpy {
                 }
             }
             RelOperator::ProjectSet(_)
+            | RelOperator::Unpivot(_)
+            | RelOperator::JsonTable(_)
+            | RelOperator::Qualify(_)
             | RelOperator::Aggregate(_)
+            | RelOperator::WindowAgg(_)
             | RelOperator::Sort(_)
             | RelOperator::Limit(_)
             | RelOperator::EvalScalar(_)
@@ -277,7 +283,7 @@ impl DPhpy {
                     Ok((new_s_expr, optimized))
                 }
             }
-            RelOperator::UnionAll(_) => {
+            RelOperator::UnionAll(_) | RelOperator::Except(_) | RelOperator::Intersect(_) => {
                 let new_s_expr = self.new_children(s_expr).await?;
                 self.join_relations.push(JoinRelation::new(
                     &new_s_expr,
@@ -474,9 +474,35 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
             .run(&s_expr)?;
     }
 
+    optimize_shared_ctes(opt_ctx).await?;
+
     Ok(s_expr)
 }
 
+// Optimize the bodies of the ctes shared by their consumers, which are bound only once
+// and stored in the metadata, see `Binder::bind_shared_cte`.
+async fn optimize_shared_ctes(opt_ctx: &mut OptimizerContext) -> Result<()> {
+    loop {
+        let next = {
+            let mut metadata = opt_ctx.metadata.write();
+            metadata
+                .shared_ctes_mut()
+                .iter_mut()
+                .enumerate()
+                .find(|(_, cte)| !cte.optimized)
+                .map(|(cte_id, cte)| {
+                    cte.optimized = true;
+                    (cte_id, cte.s_expr.clone())
+                })
+        };
+        let Some((cte_id, s_expr)) = next else {
+            return Ok(());
+        };
+        let s_expr = Box::pin(optimize_query(opt_ctx, s_expr)).await?;
+        opt_ctx.metadata.write().shared_ctes_mut()[cte_id].s_expr = s_expr;
+    }
+}
+
 // TODO(leiysky): reuse the optimization logic with `optimize_query`
 async fn get_optimized_memo(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -> Result<Memo> {
     if contains_local_table_scan(&s_expr, &opt_ctx.metadata) {
@@ -22,9 +22,12 @@ use crate::optimizer::rule::RuleID;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::SExpr;
 use crate::plans::Filter;
+use crate::plans::Qualify;
 use crate::plans::RelOp;
+use crate::plans::RelOperator;
 
-// Merge two adjacent `Filter`s into one
+// Merge two adjacent `Filter`s into one, a `Filter` on top of
+// a `Qualify` is merged into the `Qualify`
 pub struct RuleMergeFilter {
     id: RuleID,
     matchers: Vec<Matcher>,
@@ -39,13 +42,16 @@ impl RuleMergeFilter {
             //  Filter
             //  \
             //   *
-            matchers: vec![Matcher::MatchOp {
-                op_type: RelOp::Filter,
-                children: vec![Matcher::MatchOp {
+            matchers: [RelOp::Filter, RelOp::Qualify]
+                .into_iter()
+                .map(|op_type| Matcher::MatchOp {
                     op_type: RelOp::Filter,
-                    children: vec![Matcher::Leaf],
-                }],
-            }],
+                    children: vec![Matcher::MatchOp {
+                        op_type,
+                        children: vec![Matcher::Leaf],
+                    }],
+                })
+                .collect(),
         }
     }
 }
@@ -57,17 +63,24 @@ impl Rule for RuleMergeFilter {
 
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
         let up_filter: Filter = s_expr.plan().clone().try_into()?;
-        let down_filter: Filter = s_expr.child(0)?.plan().clone().try_into()?;
+        let down_plan = s_expr.child(0)?.plan();
+        let down_predicates = match down_plan {
+            RelOperator::Qualify(qualify) => qualify.predicates.clone(),
+            plan => Filter::try_from(plan.clone())?.predicates,
+        };
 
         let predicates = up_filter
             .predicates
             .into_iter()
-            .chain(down_filter.predicates)
+            .chain(down_predicates)
             .collect();
-        let merged = Filter { predicates };
+        let merged: RelOperator = match down_plan {
+            RelOperator::Qualify(_) => Qualify { predicates }.into(),
+            _ => Filter { predicates }.into(),
+        };
 
         let new_expr = SExpr::create_unary(
-            Arc::new(merged.into()),
+            Arc::new(merged),
             Arc::new(s_expr.child(0)?.child(0)?.clone()),
         );
         state.add_result(new_expr);
@@ -21,7 +21,9 @@ use crate::optimizer::rule::TransformResult;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
 use crate::plans::Filter;
+use crate::plans::Qualify;
 use crate::plans::RelOp;
+use crate::plans::RelOperator;
 use crate::plans::Window;
 
 /// Input:   Filter
@@ -46,7 +48,8 @@ use crate::plans::Window;
 ///               \
 ///                *
 ///
-/// note that only push down filter used in `Window.partition_by` columns
+/// note that only push down filter used in `Window.partition_by` columns,
+/// the same applies to `Qualify`.
 pub struct RulePushDownFilterWindow {
     id: RuleID,
     matchers: Vec<Matcher>,
@@ -56,13 +59,16 @@ impl RulePushDownFilterWindow {
     pub fn new() -> Self {
         Self {
             id: RuleID::PushDownFilterWindow,
-            matchers: vec![Matcher::MatchOp {
-                op_type: RelOp::Filter,
-                children: vec![Matcher::MatchOp {
-                    op_type: RelOp::Window,
-                    children: vec![Matcher::Leaf],
-                }],
-            }],
+            matchers: [RelOp::Filter, RelOp::Qualify]
+                .into_iter()
+                .map(|op_type| Matcher::MatchOp {
+                    op_type,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::Window,
+                        children: vec![Matcher::Leaf],
+                    }],
+                })
+                .collect(),
         }
     }
 }
@@ -77,7 +83,10 @@ impl Rule for RulePushDownFilterWindow {
         s_expr: &SExpr,
         state: &mut TransformResult,
     ) -> databend_common_exception::Result<()> {
-        let Filter { predicates } = s_expr.plan().clone().try_into()?;
+        let predicates = match s_expr.plan() {
+            RelOperator::Qualify(qualify) => qualify.predicates.clone(),
+            plan => Filter::try_from(plan.clone())?.predicates,
+        };
         let window_expr = s_expr.child(0)?;
         let window: Window = window_expr.plan().clone().try_into()?;
         let allowed = window.partition_by_columns()?;
@@ -109,11 +118,18 @@ impl Rule for RulePushDownFilterWindow {
                 )),
             )
         } else {
-            let remaining_filter = Filter {
-                predicates: remaining,
+            let remaining_filter: RelOperator = match s_expr.plan() {
+                RelOperator::Qualify(_) => Qualify {
+                    predicates: remaining,
+                }
+                .into(),
+                _ => Filter {
+                    predicates: remaining,
+                }
+                .into(),
             };
             let mut s_expr = SExpr::create_unary(
-                Arc::new(remaining_filter.into()),
+                Arc::new(remaining_filter),
                 Arc::new(SExpr::create_unary(
                     Arc::new(window.into()),
                     Arc::new(SExpr::create_unary(
@@ -39,13 +39,13 @@ use crate::plans::Window;
 use crate::plans::WindowFuncType;
 use crate::MetadataRef;
 
-/// Input:  Filter
+/// Input:  Filter or Qualify
 ///           \
 ///          Window
 ///             \
 ///              Sort
 ///
-/// Output: Filter
+/// Output: Filter or Qualify
 ///           \
 ///          Window
 ///             \
@@ -61,16 +61,19 @@ impl RulePushDownFilterWindowTopN {
         Self {
             id: RuleID::PushDownFilterWindowTopN,
             metadata,
-            matchers: vec![Matcher::MatchOp {
-                op_type: RelOp::Filter,
-                children: vec![Matcher::MatchOp {
-                    op_type: RelOp::Window,
+            matchers: [RelOp::Filter, RelOp::Qualify]
+                .into_iter()
+                .map(|op_type| Matcher::MatchOp {
+                    op_type,
                     children: vec![Matcher::MatchOp {
-                        op_type: RelOp::Sort,
-                        children: vec![Matcher::Leaf],
+                        op_type: RelOp::Window,
+                        children: vec![Matcher::MatchOp {
+                            op_type: RelOp::Sort,
+                            children: vec![Matcher::Leaf],
+                        }],
                     }],
-                }],
-            }],
+                })
+                .collect(),
         }
     }
 }
@@ -81,7 +84,10 @@ impl Rule for RulePushDownFilterWindowTopN {
     }
 
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
-        let filter: Filter = s_expr.plan().clone().try_into()?;
+        let predicates = match s_expr.plan() {
+            RelOperator::Qualify(qualify) => qualify.predicates.clone(),
+            plan => Filter::try_from(plan.clone())?.predicates,
+        };
         let window_expr = s_expr.child(0)?;
         let window: Window = window_expr.plan().clone().try_into()?;
         let sort_expr = window_expr.child(0)?;
@@ -91,8 +97,7 @@ impl Rule for RulePushDownFilterWindowTopN {
             return Ok(());
         }
 
-        let predicates = filter
-            .predicates
+        let predicates = predicates
             .into_iter()
             .filter_map(|predicate| extract_top_n(window.index, predicate))
             .collect::<Vec<_>>();
@@ -126,7 +126,10 @@ fn find_group_by_keys(
     group_by_keys: &mut HashMap<IndexType, Box<DataType>>,
 ) -> Result<()> {
     match child.plan() {
-        RelOperator::EvalScalar(_) | RelOperator::Filter(_) | RelOperator::Window(_) => {
+        RelOperator::EvalScalar(_)
+        | RelOperator::Filter(_)
+        | RelOperator::Qualify(_)
+        | RelOperator::Window(_) => {
             find_group_by_keys(child.child(0)?, group_by_keys)?;
         }
         RelOperator::Aggregate(agg) => {
@@ -140,8 +143,13 @@ fn find_group_by_keys(
         | RelOperator::Limit(_)
         | RelOperator::Exchange(_)
         | RelOperator::UnionAll(_)
+        | RelOperator::Except(_)
+        | RelOperator::Intersect(_)
+        | RelOperator::WindowAgg(_)
         | RelOperator::DummyTableScan(_)
         | RelOperator::ProjectSet(_)
+        | RelOperator::Unpivot(_)
+        | RelOperator::JsonTable(_)
         | RelOperator::ConstantTableScan(_)
         | RelOperator::ExpressionScan(_)
         | RelOperator::CacheScan(_)
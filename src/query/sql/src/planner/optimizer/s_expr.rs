@@ -266,6 +266,13 @@ impl SExpr {
                     });
                 }
             }
+            RelOperator::Qualify(op) => {
+                for predicate in &op.predicates {
+                    get_udf_names(predicate)?.iter().for_each(|udf| {
+                        udfs.insert(*udf);
+                    });
+                }
+            }
             RelOperator::Aggregate(op) => {
                 for group_items in &op.group_items {
                     get_udf_names(&group_items.scalar)?.iter().for_each(|udf| {
@@ -330,6 +337,13 @@ impl SExpr {
                     });
                 }
             }
+            RelOperator::WindowAgg(op) => {
+                for agg_func in &op.aggregate_functions {
+                    get_udf_names(&agg_func.scalar)?.iter().for_each(|udf| {
+                        udfs.insert(*udf);
+                    });
+                }
+            }
             RelOperator::Udf(udf) => {
                 for item in &udf.items {
                     get_udf_names(&item.scalar)?.iter().for_each(|udf| {
@@ -353,6 +367,10 @@ impl SExpr {
             }
             RelOperator::Limit(_)
             | RelOperator::UnionAll(_)
+            | RelOperator::Except(_)
+            | RelOperator::Intersect(_)
+            | RelOperator::Unpivot(_)
+            | RelOperator::JsonTable(_)
             | RelOperator::Sort(_)
             | RelOperator::DummyTableScan(_)
             | RelOperator::ConstantTableScan(_)
@@ -443,6 +461,14 @@ impl SExpr {
         self.children.iter().any(|child| child.has_merge_exchange())
     }
 
+    #[recursive::recursive]
+    pub fn has_aggregate(&self) -> bool {
+        if let RelOperator::Aggregate(_) | RelOperator::WindowAgg(_) = self.plan.as_ref() {
+            return true;
+        }
+        self.children.iter().any(|child| child.has_aggregate())
+    }
+
     pub fn derive_relational_prop(&self) -> Result<Arc<RelationalProperty>> {
         if let Some(rel_prop) = self.rel_prop.lock().unwrap().as_ref() {
             return Ok(rel_prop.clone());
@@ -461,6 +487,10 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::Limit(_)
         | RelOperator::Exchange(_)
         | RelOperator::UnionAll(_)
+        | RelOperator::Except(_)
+        | RelOperator::Intersect(_)
+        | RelOperator::Unpivot(_)
+        | RelOperator::JsonTable(_)
         | RelOperator::Sort(_)
         | RelOperator::DummyTableScan(_)
         | RelOperator::ConstantTableScan(_)
@@ -480,6 +510,7 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
             .iter()
             .any(|expr| find_subquery_in_expr(&expr.scalar)),
         RelOperator::Filter(op) => op.predicates.iter().any(find_subquery_in_expr),
+        RelOperator::Qualify(op) => op.predicates.iter().any(find_subquery_in_expr),
         RelOperator::Aggregate(op) => {
             op.group_items
                 .iter()
@@ -510,6 +541,10 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
             .items
             .iter()
             .any(|expr| find_subquery_in_expr(&expr.scalar)),
+        RelOperator::WindowAgg(op) => op
+            .aggregate_functions
+            .iter()
+            .any(|expr| find_subquery_in_expr(&expr.scalar)),
         RelOperator::MutationSource(_) => false,
     }
 }
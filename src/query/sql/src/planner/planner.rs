@@ -51,6 +51,7 @@ use crate::VariableNormalizer;
 
 const PROBE_INSERT_INITIAL_TOKENS: usize = 128;
 const PROBE_INSERT_MAX_TOKENS: usize = 128 * 8;
+const RETURNING_KEYWORD: &[u8] = b"returning";
 
 pub struct Planner {
     pub(crate) ctx: Arc<dyn TableContext>,
@@ -125,7 +126,12 @@ impl Planner {
         let first_token = tokenizer
             .peek()
             .and_then(|token| Some(token.as_ref().ok()?.kind));
-        let is_insert_stmt = matches!(first_token, Some(TokenKind::INSERT)) && {
+        // `INSERT ... RETURNING` needs the full statement to be parsed, the values can't be kept as raw string.
+        let has_returning = final_sql
+            .as_bytes()
+            .windows(RETURNING_KEYWORD.len())
+            .any(|w| w.eq_ignore_ascii_case(RETURNING_KEYWORD));
+        let is_insert_stmt = !has_returning && matches!(first_token, Some(TokenKind::INSERT)) && {
             let mut tokenizer = Tokenizer::new(&final_sql);
             tokenizer.next_chunk::<3>().is_ok_and(|first_three_tokens| {
                 matches!(first_token, Some(TokenKind::INSERT))
@@ -327,7 +333,7 @@ impl Planner {
 
 pub fn get_query_kind(stmt: &Statement) -> QueryKind {
     match stmt {
-        Statement::Query { .. } => QueryKind::Query,
+        Statement::Query { .. } | Statement::AssertExists { .. } => QueryKind::Query,
         Statement::StatementWithSettings { stmt, .. } => get_query_kind(stmt),
         Statement::CopyIntoTable(_) => QueryKind::CopyIntoTable,
         Statement::CopyIntoLocation(_) => QueryKind::CopyIntoLocation,
@@ -53,6 +53,8 @@ pub struct GroupingSets {
     pub sets: Vec<Vec<IndexType>>,
     /// See the comment in `GroupingSetsInfo`.
     pub dup_group_items: Vec<(IndexType, DataType)>,
+    /// See the comment in `GroupingSetsInfo`.
+    pub grouping_ids: Vec<(Vec<usize>, IndexType)>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -269,6 +271,12 @@ impl Operator for Aggregate {
         for agg in self.aggregate_functions.iter() {
             output_columns.insert(agg.index);
         }
+        // The `GROUPING_ID` columns are computed after the final aggregation.
+        if self.mode != AggregateMode::Partial {
+            if let Some(grouping_sets) = &self.grouping_sets {
+                output_columns.extend(grouping_sets.grouping_ids.iter().map(|(_, index)| *index));
+            }
+        }
 
         // Derive outer columns
         let outer_columns = input_prop
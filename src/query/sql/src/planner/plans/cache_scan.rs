@@ -30,6 +30,12 @@ use crate::plans::RelOp;
 #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum CacheSource {
     HashJoinBuild((usize, Vec<usize>)),
+    /// The blocks stored by `PhysicalPlan::Replicate` with the cache key.
+    Replicate((u64, Vec<usize>)),
+    /// The output of the shared CTE body in the metadata with the CTE id.
+    Cte((usize, Vec<usize>)),
+    /// The result of the remote Flight SQL query in the metadata with the import id.
+    FlightSql((usize, Vec<usize>)),
 }
 
 impl CacheSource {
@@ -39,6 +45,18 @@ impl CacheSource {
                 let column_indexes = column_indexes.iter().map(|idx| projection[*idx]).collect();
                 CacheSource::HashJoinBuild((*cache_index, column_indexes))
             }
+            CacheSource::Replicate((cache_key, column_indexes)) => {
+                let column_indexes = column_indexes.iter().map(|idx| projection[*idx]).collect();
+                CacheSource::Replicate((*cache_key, column_indexes))
+            }
+            CacheSource::Cte((cte_id, column_indexes)) => {
+                let column_indexes = column_indexes.iter().map(|idx| projection[*idx]).collect();
+                CacheSource::Cte((*cte_id, column_indexes))
+            }
+            CacheSource::FlightSql((import_id, column_indexes)) => {
+                let column_indexes = column_indexes.iter().map(|idx| projection[*idx]).collect();
+                CacheSource::FlightSql((*import_id, column_indexes))
+            }
         }
     }
 }
@@ -0,0 +1,182 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+
+use crate::binder::ColumnBinding;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::optimizer::Statistics;
+use crate::plans::BoundColumnRef;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
+
+/// `EXCEPT [ALL]`, outputs the rows of the left input which are not found in the right input,
+/// NULLs are treated as equal. With `ALL`, each right row removes at most one equal left row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Except {
+    // Columns of the left input, they are also the output columns of except.
+    pub left_outputs: Vec<ColumnBinding>,
+    // Columns of the right input, which have the same data types as `left_outputs`.
+    pub right_outputs: Vec<ColumnBinding>,
+    pub all: bool,
+}
+
+impl Except {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        used_columns.extend(self.left_outputs.iter().map(|column| column.index));
+        used_columns.extend(self.right_outputs.iter().map(|column| column.index));
+        Ok(used_columns)
+    }
+
+    // Hashing all the columns sends a left row to the node of the right rows which remove it.
+    fn hash_keys(outputs: &[ColumnBinding]) -> Distribution {
+        Distribution::Hash(
+            outputs
+                .iter()
+                .map(|column| {
+                    ScalarExpr::BoundColumnRef(BoundColumnRef {
+                        span: None,
+                        column: column.clone(),
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    pub fn derive_except_stats(&self, left_stat_info: Arc<StatInfo>) -> Result<Arc<StatInfo>> {
+        // At most all the left rows are output.
+        Ok(Arc::new(StatInfo {
+            cardinality: left_stat_info.cardinality,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: left_stat_info.statistics.column_stats.clone(),
+            },
+        }))
+    }
+}
+
+impl Operator for Except {
+    fn rel_op(&self) -> RelOp {
+        RelOp::Except
+    }
+
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let left_prop = rel_expr.derive_relational_prop_child(0)?;
+        let right_prop = rel_expr.derive_relational_prop_child(1)?;
+
+        // Derive output columns
+        let output_columns = self
+            .left_outputs
+            .iter()
+            .map(|column| column.index)
+            .collect();
+        // Derive outer columns
+        let outer_columns = left_prop
+            .outer_columns
+            .union(&right_prop.outer_columns)
+            .cloned()
+            .collect();
+
+        // Derive used columns
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(left_prop.used_columns.clone());
+        used_columns.extend(right_prop.used_columns.clone());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+            orderings: vec![],
+            partition_orderings: None,
+        }))
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        let left_prop = rel_expr.derive_physical_prop_child(0)?;
+        let right_prop = rel_expr.derive_physical_prop_child(1)?;
+
+        if left_prop.distribution == Distribution::Serial
+            || right_prop.distribution == Distribution::Serial
+        {
+            return Ok(PhysicalProperty {
+                distribution: Distribution::Serial,
+            });
+        }
+
+        // The output rows are the rows of the left side, which keep its distribution.
+        Ok(left_prop)
+    }
+
+    fn derive_stats(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        let left_stat_info = rel_expr.derive_cardinality_child(0)?;
+        self.derive_except_stats(left_stat_info)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        rel_expr: &RelExpr,
+        child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        let mut required = required.clone();
+        let left_physical_prop = rel_expr.derive_physical_prop_child(0)?;
+        let right_physical_prop = rel_expr.derive_physical_prop_child(1)?;
+
+        // The equal rows of both sides must be compared in the same node.
+        if left_physical_prop.distribution == Distribution::Serial
+            || right_physical_prop.distribution == Distribution::Serial
+        {
+            required.distribution = Distribution::Serial;
+        } else if child_index == 0 {
+            required.distribution = Self::hash_keys(&self.left_outputs);
+        } else {
+            required.distribution = Self::hash_keys(&self.right_outputs);
+        }
+
+        Ok(required)
+    }
+
+    fn compute_required_prop_children(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _required: &RequiredProperty,
+    ) -> Result<Vec<Vec<RequiredProperty>>> {
+        // (Hash, Hash)
+        Ok(vec![vec![
+            RequiredProperty {
+                distribution: Self::hash_keys(&self.left_outputs),
+            },
+            RequiredProperty {
+                distribution: Self::hash_keys(&self.right_outputs),
+            },
+        ]])
+    }
+}
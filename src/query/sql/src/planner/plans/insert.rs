@@ -23,8 +23,10 @@ use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::FromData;
+use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableInfo;
 use enum_as_inner::EnumAsInner;
 use serde::Deserialize;
@@ -34,6 +36,7 @@ use super::Plan;
 use crate::planner::format::FormatOptions;
 use crate::planner::format::MetadataIdHumanizer;
 use crate::plans::CopyIntoTablePlan;
+use crate::IndexType;
 use crate::INSERT_NAME;
 
 #[derive(Clone, Debug, EnumAsInner)]
@@ -64,6 +67,9 @@ pub struct Insert {
     // it should be provided as some `table_info`.
     // otherwise, the table being inserted will be resolved by using `catalog`.`database`.`table`
     pub table_info: Option<TableInfo>,
+    // The expressions of `RETURNING` clause, evaluated on the inserted rows in the order of the table schema.
+    pub returning: Vec<(RemoteExpr, IndexType)>,
+    pub returning_names: Vec<String>,
 }
 
 impl PartialEq for Insert {
@@ -100,6 +106,8 @@ impl Insert {
             // table_info only used create table as select.
             table_info: _,
             source,
+            returning: _,
+            returning_names,
         } = self;
 
         let table_name = format!("{}.{}.{}", catalog, database, table);
@@ -110,11 +118,17 @@ impl Insert {
             .collect::<Vec<_>>()
             .join(",");
 
-        let children = vec![
+        let mut children = vec![
             FormatTreeNode::new(format!("table: {table_name}")),
             FormatTreeNode::new(format!("inserted columns: [{inserted_columns}]")),
             FormatTreeNode::new(format!("overwrite: {overwrite}")),
         ];
+        if !returning_names.is_empty() {
+            children.push(FormatTreeNode::new(format!(
+                "returning: [{}]",
+                returning_names.join(", ")
+            )));
+        }
 
         let formatted_plan = format_insert_source("InsertPlan", source, options, children)?;
 
@@ -125,6 +139,18 @@ impl Insert {
     }
 
     pub fn schema(&self) -> DataSchemaRef {
+        if !self.returning.is_empty() {
+            return DataSchemaRefExt::create(
+                self.returning
+                    .iter()
+                    .zip(self.returning_names.iter())
+                    .map(|((expr, _), name)| {
+                        DataField::new(name, expr.as_expr(&BUILTIN_FUNCTIONS).data_type().clone())
+                    })
+                    .collect(),
+            );
+        }
+
         DataSchemaRefExt::create(vec![DataField::new(
             INSERT_NAME,
             DataType::Number(NumberDataType::UInt64),
@@ -227,6 +253,7 @@ impl std::fmt::Debug for Insert {
             .field("table", &self.table)
             .field("schema", &self.schema)
             .field("overwrite", &self.overwrite)
+            .field("returning_names", &self.returning_names)
             .finish()
     }
 }
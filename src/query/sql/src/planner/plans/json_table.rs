@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::ops::Deref;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// `JsonTable` converts JSON values to rows, each input row is expanded to one row per
+/// JSON value selected by `row_path` from `source_col`. The value at the path of each
+/// column is cast to the type of the column, it's NULL if the path is not found or the
+/// value can't be cast. An input row without any selected value is not in the output.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonTable {
+    /// The variant column to select the rows from.
+    pub source_col: IndexType,
+    pub row_path: String,
+    /// The output column, the JSON path relative to the row and the type of each column.
+    pub columns: Vec<(IndexType, String, DataType)>,
+}
+
+impl JsonTable {
+    pub fn derive_json_table_stats(&self, input_stat: &mut StatInfo) -> Result<Arc<StatInfo>> {
+        // The same as `ProjectSet`, assume that each row selects 3 values.
+        input_stat.statistics.precise_cardinality = None;
+        input_stat.cardinality *= 3.0;
+        Ok(Arc::new(input_stat.clone()))
+    }
+}
+
+impl Operator for JsonTable {
+    fn rel_op(&self) -> RelOp {
+        RelOp::JsonTable
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let child_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns
+        let mut output_columns = child_prop.output_columns.clone();
+        output_columns.extend(self.columns.iter().map(|(index, _, _)| *index));
+
+        // Derive used columns
+        let mut used_columns = child_prop.used_columns.clone();
+        used_columns.insert(self.source_col);
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns: child_prop.outer_columns.clone(),
+            used_columns,
+            orderings: vec![],
+            partition_orderings: None,
+        }))
+    }
+
+    fn derive_stats(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        let mut input_stat = rel_expr.derive_cardinality_child(0)?.deref().clone();
+        self.derive_json_table_stats(&mut input_stat)
+    }
+}
@@ -23,12 +23,15 @@ mod data_mask;
 mod ddl;
 mod dummy_table_scan;
 mod eval_scalar;
+mod except;
 mod exchange;
 mod expression_scan;
 mod filter;
 mod insert;
 mod insert_multi_table;
+mod intersect;
 mod join;
+mod json_table;
 mod kill;
 mod limit;
 mod mutation;
@@ -38,6 +41,7 @@ mod optimize;
 mod plan;
 mod presign;
 mod project_set;
+mod qualify;
 mod r_cte_scan;
 mod recluster;
 mod replace;
@@ -51,7 +55,9 @@ mod system;
 mod udaf;
 mod udf;
 mod union_all;
+mod unpivot;
 mod window;
+mod window_agg;
 
 pub use aggregate::*;
 pub use async_function::AsyncFunction;
@@ -64,12 +70,15 @@ pub use data_mask::*;
 pub use ddl::*;
 pub use dummy_table_scan::DummyTableScan;
 pub use eval_scalar::*;
+pub use except::Except;
 pub use exchange::*;
 pub use expression_scan::*;
 pub use filter::*;
 pub use insert::*;
 pub use insert_multi_table::*;
+pub use intersect::Intersect;
 pub use join::*;
+pub use json_table::JsonTable;
 pub use kill::KillPlan;
 pub use limit::*;
 pub use mutation::MatchedEvaluator;
@@ -84,6 +93,7 @@ pub use optimize::*;
 pub use plan::*;
 pub use presign::*;
 pub use project_set::*;
+pub use qualify::Qualify;
 pub use r_cte_scan::*;
 pub use recluster::*;
 pub use replace::Replace;
@@ -97,4 +107,6 @@ pub use system::*;
 pub use udaf::*;
 pub use udf::*;
 pub use union_all::UnionAll;
+pub use unpivot::Unpivot;
 pub use window::*;
+pub use window_agg::*;
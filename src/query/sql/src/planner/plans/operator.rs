@@ -32,19 +32,25 @@ use crate::plans::CacheScan;
 use crate::plans::ConstantTableScan;
 use crate::plans::DummyTableScan;
 use crate::plans::EvalScalar;
+use crate::plans::Except;
 use crate::plans::Exchange;
 use crate::plans::ExpressionScan;
 use crate::plans::Filter;
+use crate::plans::Intersect;
 use crate::plans::Join;
+use crate::plans::JsonTable;
 use crate::plans::Limit;
 use crate::plans::Mutation;
 use crate::plans::OptimizeCompactBlock;
 use crate::plans::ProjectSet;
+use crate::plans::Qualify;
 use crate::plans::Scan;
 use crate::plans::Sort;
 use crate::plans::Udf;
 use crate::plans::UnionAll;
+use crate::plans::Unpivot;
 use crate::plans::Window;
+use crate::plans::WindowAgg;
 
 pub trait Operator {
     /// Get relational operator kind
@@ -99,19 +105,25 @@ pub enum RelOp {
     Join,
     EvalScalar,
     Filter,
+    Qualify,
     Aggregate,
     Sort,
     Limit,
     Exchange,
     UnionAll,
+    Except,
+    Intersect,
     DummyTableScan,
     Window,
     ProjectSet,
+    Unpivot,
+    JsonTable,
     ConstantTableScan,
     ExpressionScan,
     CacheScan,
     Udf,
     Udaf,
+    WindowAgg,
     AsyncFunction,
     RecursiveCteScan,
     MergeInto,
@@ -136,18 +148,24 @@ pub enum RelOperator {
     Join(Join),
     EvalScalar(EvalScalar),
     Filter(Filter),
+    Qualify(Qualify),
     Aggregate(Aggregate),
     Sort(Sort),
     Limit(Limit),
     Exchange(Exchange),
     UnionAll(UnionAll),
+    Except(Except),
+    Intersect(Intersect),
     DummyTableScan(DummyTableScan),
     Window(Window),
     ProjectSet(ProjectSet),
+    Unpivot(Unpivot),
+    JsonTable(JsonTable),
     ConstantTableScan(ConstantTableScan),
     ExpressionScan(ExpressionScan),
     CacheScan(CacheScan),
     Udf(Udf),
+    WindowAgg(WindowAgg),
     RecursiveCteScan(RecursiveCteScan),
     AsyncFunction(AsyncFunction),
     Mutation(Mutation),
@@ -162,18 +180,24 @@ impl Operator for RelOperator {
             RelOperator::Join(rel_op) => rel_op.rel_op(),
             RelOperator::EvalScalar(rel_op) => rel_op.rel_op(),
             RelOperator::Filter(rel_op) => rel_op.rel_op(),
+            RelOperator::Qualify(rel_op) => rel_op.rel_op(),
             RelOperator::Aggregate(rel_op) => rel_op.rel_op(),
             RelOperator::Sort(rel_op) => rel_op.rel_op(),
             RelOperator::Limit(rel_op) => rel_op.rel_op(),
             RelOperator::Exchange(rel_op) => rel_op.rel_op(),
             RelOperator::UnionAll(rel_op) => rel_op.rel_op(),
+            RelOperator::Except(rel_op) => rel_op.rel_op(),
+            RelOperator::Intersect(rel_op) => rel_op.rel_op(),
             RelOperator::DummyTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::ProjectSet(rel_op) => rel_op.rel_op(),
+            RelOperator::Unpivot(rel_op) => rel_op.rel_op(),
+            RelOperator::JsonTable(rel_op) => rel_op.rel_op(),
             RelOperator::Window(rel_op) => rel_op.rel_op(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::ExpressionScan(rel_op) => rel_op.rel_op(),
             RelOperator::CacheScan(rel_op) => rel_op.rel_op(),
             RelOperator::Udf(rel_op) => rel_op.rel_op(),
+            RelOperator::WindowAgg(rel_op) => rel_op.rel_op(),
             RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
             RelOperator::AsyncFunction(rel_op) => rel_op.rel_op(),
             RelOperator::Mutation(rel_op) => rel_op.rel_op(),
@@ -188,18 +212,24 @@ impl Operator for RelOperator {
             RelOperator::Join(rel_op) => rel_op.arity(),
             RelOperator::EvalScalar(rel_op) => rel_op.arity(),
             RelOperator::Filter(rel_op) => rel_op.arity(),
+            RelOperator::Qualify(rel_op) => rel_op.arity(),
             RelOperator::Aggregate(rel_op) => rel_op.arity(),
             RelOperator::Sort(rel_op) => rel_op.arity(),
             RelOperator::Limit(rel_op) => rel_op.arity(),
             RelOperator::Exchange(rel_op) => rel_op.arity(),
             RelOperator::UnionAll(rel_op) => rel_op.arity(),
+            RelOperator::Except(rel_op) => rel_op.arity(),
+            RelOperator::Intersect(rel_op) => rel_op.arity(),
             RelOperator::DummyTableScan(rel_op) => rel_op.arity(),
             RelOperator::Window(rel_op) => rel_op.arity(),
             RelOperator::ProjectSet(rel_op) => rel_op.arity(),
+            RelOperator::Unpivot(rel_op) => rel_op.arity(),
+            RelOperator::JsonTable(rel_op) => rel_op.arity(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.arity(),
             RelOperator::ExpressionScan(rel_op) => rel_op.arity(),
             RelOperator::CacheScan(rel_op) => rel_op.arity(),
             RelOperator::Udf(rel_op) => rel_op.arity(),
+            RelOperator::WindowAgg(rel_op) => rel_op.arity(),
             RelOperator::RecursiveCteScan(rel_op) => rel_op.arity(),
             RelOperator::AsyncFunction(rel_op) => rel_op.arity(),
             RelOperator::Mutation(rel_op) => rel_op.arity(),
@@ -214,18 +244,24 @@ impl Operator for RelOperator {
             RelOperator::Join(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::EvalScalar(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Filter(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::Qualify(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Aggregate(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Sort(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Limit(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Exchange(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::UnionAll(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::Except(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::Intersect(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::Unpivot(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::JsonTable(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ExpressionScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::CacheScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::WindowAgg(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::AsyncFunction(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Mutation(rel_op) => rel_op.derive_relational_prop(rel_expr),
@@ -240,18 +276,24 @@ impl Operator for RelOperator {
             RelOperator::Join(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::EvalScalar(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Filter(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::Qualify(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Aggregate(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Sort(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Limit(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Exchange(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::UnionAll(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::Except(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::Intersect(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::Unpivot(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::JsonTable(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ExpressionScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::CacheScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::WindowAgg(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::AsyncFunction(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Mutation(rel_op) => rel_op.derive_physical_prop(rel_expr),
@@ -266,18 +308,24 @@ impl Operator for RelOperator {
             RelOperator::Join(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::EvalScalar(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Filter(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::Qualify(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Aggregate(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Sort(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Limit(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Exchange(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::UnionAll(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::Except(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::Intersect(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::Unpivot(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::JsonTable(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::ExpressionScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::CacheScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::WindowAgg(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::AsyncFunction(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Mutation(rel_op) => rel_op.derive_stats(rel_expr),
@@ -306,6 +354,9 @@ impl Operator for RelOperator {
             RelOperator::Filter(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::Qualify(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
             RelOperator::Aggregate(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
@@ -321,6 +372,12 @@ impl Operator for RelOperator {
             RelOperator::UnionAll(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::Except(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::Intersect(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
             RelOperator::DummyTableScan(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
@@ -330,6 +387,12 @@ impl Operator for RelOperator {
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::Unpivot(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::JsonTable(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
             RelOperator::ConstantTableScan(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
@@ -342,6 +405,9 @@ impl Operator for RelOperator {
             RelOperator::Udf(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::WindowAgg(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
             RelOperator::RecursiveCteScan(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
@@ -379,6 +445,9 @@ impl Operator for RelOperator {
             RelOperator::Filter(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
+            RelOperator::Qualify(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
             RelOperator::Aggregate(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
@@ -394,6 +463,12 @@ impl Operator for RelOperator {
             RelOperator::UnionAll(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
+            RelOperator::Except(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
+            RelOperator::Intersect(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
             RelOperator::DummyTableScan(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
@@ -403,6 +478,12 @@ impl Operator for RelOperator {
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
+            RelOperator::Unpivot(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
+            RelOperator::JsonTable(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
             RelOperator::ConstantTableScan(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
@@ -415,6 +496,9 @@ impl Operator for RelOperator {
             RelOperator::Udf(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
+            RelOperator::WindowAgg(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
             RelOperator::RecursiveCteScan(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
@@ -615,12 +699,112 @@ impl TryFrom<RelOperator> for Exchange {
     }
 }
 
+impl From<Qualify> for RelOperator {
+    fn from(v: Qualify) -> Self {
+        Self::Qualify(v)
+    }
+}
+
+impl TryFrom<RelOperator> for Qualify {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::Qualify(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(format!(
+                "Cannot downcast {:?} to Qualify",
+                value.rel_op()
+            )))
+        }
+    }
+}
+
+impl From<Unpivot> for RelOperator {
+    fn from(v: Unpivot) -> Self {
+        Self::Unpivot(v)
+    }
+}
+
+impl TryFrom<RelOperator> for Unpivot {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::Unpivot(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(format!(
+                "Cannot downcast {:?} to Unpivot",
+                value.rel_op()
+            )))
+        }
+    }
+}
+
+impl From<JsonTable> for RelOperator {
+    fn from(v: JsonTable) -> Self {
+        Self::JsonTable(v)
+    }
+}
+
+impl TryFrom<RelOperator> for JsonTable {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::JsonTable(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(format!(
+                "Cannot downcast {:?} to JsonTable",
+                value.rel_op()
+            )))
+        }
+    }
+}
+
 impl From<UnionAll> for RelOperator {
     fn from(v: UnionAll) -> Self {
         Self::UnionAll(v)
     }
 }
 
+impl From<Except> for RelOperator {
+    fn from(v: Except) -> Self {
+        Self::Except(v)
+    }
+}
+
+impl TryFrom<RelOperator> for Except {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::Except(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(format!(
+                "Cannot downcast {:?} to Except",
+                value.rel_op()
+            )))
+        }
+    }
+}
+
+impl From<Intersect> for RelOperator {
+    fn from(v: Intersect) -> Self {
+        Self::Intersect(v)
+    }
+}
+
+impl TryFrom<RelOperator> for Intersect {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::Intersect(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(format!(
+                "Cannot downcast {:?} to Intersect",
+                value.rel_op()
+            )))
+        }
+    }
+}
+
 impl TryFrom<RelOperator> for UnionAll {
     type Error = ErrorCode;
     fn try_from(value: RelOperator) -> Result<Self> {
@@ -818,3 +1002,23 @@ impl TryFrom<RelOperator> for MutationSource {
         }
     }
 }
+
+impl From<WindowAgg> for RelOperator {
+    fn from(v: WindowAgg) -> Self {
+        Self::WindowAgg(v)
+    }
+}
+
+impl TryFrom<RelOperator> for WindowAgg {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::WindowAgg(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(format!(
+                "Cannot downcast {:?} to WindowAgg",
+                value.rel_op()
+            )))
+        }
+    }
+}
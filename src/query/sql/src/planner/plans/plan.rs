@@ -445,6 +445,11 @@ pub enum RewriteKind {
 
     Call,
     ShowProcedures,
+
+    /// The query is run in the background, the plan returns its query id.
+    AsyncAggregate,
+    /// The query fails with the error message if it returns no rows.
+    AssertExists(String),
 }
 
 impl Plan {
@@ -479,6 +484,10 @@ impl Display for Plan {
 impl Plan {
     pub fn schema(&self) -> DataSchemaRef {
         match self {
+            Plan::Query {
+                rewrite_kind: Some(RewriteKind::AsyncAggregate),
+                ..
+            } => DataSchemaRefExt::create(vec![DataField::new("query_id", DataType::String)]),
             Plan::Query {
                 s_expr: _,
                 metadata: _,
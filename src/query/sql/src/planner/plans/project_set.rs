@@ -23,6 +23,7 @@ use crate::optimizer::StatInfo;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarItem;
+use crate::IndexType;
 
 /// `ProjectSet` is a plan that evaluate a series of
 /// set-returning functions, zip the result together,
@@ -30,6 +31,10 @@ use crate::plans::ScalarItem;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ProjectSet {
     pub srfs: Vec<ScalarItem>,
+    /// The output column of the 1-based index of the zipped result, set by `WITH ORDINALITY`.
+    pub ordinality: Option<IndexType>,
+    /// Set for a lateral scalar table function, whose array result is flattened by a `FlatMap`.
+    pub flat_map: bool,
 }
 
 impl ProjectSet {
@@ -58,6 +63,9 @@ impl Operator for ProjectSet {
         for srf in &self.srfs {
             output_columns.insert(srf.index);
         }
+        if let Some(ordinality) = self.ordinality {
+            output_columns.insert(ordinality);
+        }
 
         // Derive used columns
         let mut used_columns = child_prop.used_columns.clone();
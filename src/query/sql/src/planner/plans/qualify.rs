@@ -0,0 +1,54 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Filter;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
+
+/// `Qualify` filters the rows by the predicates of the `QUALIFY` clause, which are
+/// evaluated on the results of the window functions below it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Qualify {
+    pub predicates: Vec<ScalarExpr>,
+}
+
+impl Qualify {
+    fn as_filter(&self) -> Filter {
+        Filter {
+            predicates: self.predicates.clone(),
+        }
+    }
+}
+
+impl Operator for Qualify {
+    fn rel_op(&self) -> RelOp {
+        RelOp::Qualify
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        self.as_filter().derive_relational_prop(rel_expr)
+    }
+
+    fn derive_stats(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        self.as_filter().derive_stats(rel_expr)
+    }
+}
@@ -936,13 +936,16 @@ pub struct UDFScriptCode {
 pub enum UDFType {
     Server(String), // server_addr
     Script(UDFScriptCode),
+    // The location of an ONNX model in a stage, run by `PREDICT`.
+    Model(String),
 }
 
 impl UDFType {
     pub fn match_type(&self, is_script: bool) -> bool {
         match self {
             UDFType::Server(_) => !is_script,
-            UDFType::Script(_) => is_script,
+            // The models run in the query node like the scripts.
+            UDFType::Script(_) | UDFType::Model(_) => is_script,
         }
     }
 }
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// `Unpivot` converts columns to rows, each input row is expanded to one row per column
+/// in `columns_to_unpivot`. The name of the column is in `name_col` and its value is in
+/// `value_col`, the unpivoted columns are not in the output.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Unpivot {
+    /// The columns to unpivot, they have the same data type as `value_col`.
+    pub columns_to_unpivot: Vec<IndexType>,
+    /// The values of `name_col` for each of `columns_to_unpivot`.
+    pub names: Vec<String>,
+    pub name_col: IndexType,
+    pub value_col: IndexType,
+}
+
+impl Unpivot {
+    pub fn derive_unpivot_stats(&self, input_stat: &mut StatInfo) -> Result<Arc<StatInfo>> {
+        let num_columns = self.columns_to_unpivot.len();
+        input_stat.cardinality *= num_columns as f64;
+        input_stat.statistics.precise_cardinality = input_stat
+            .statistics
+            .precise_cardinality
+            .map(|cardinality| cardinality * num_columns as u64);
+        for column in &self.columns_to_unpivot {
+            input_stat.statistics.column_stats.remove(column);
+        }
+        Ok(Arc::new(input_stat.clone()))
+    }
+}
+
+impl Operator for Unpivot {
+    fn rel_op(&self) -> RelOp {
+        RelOp::Unpivot
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let child_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns
+        let mut output_columns = child_prop.output_columns.clone();
+        for column in &self.columns_to_unpivot {
+            output_columns.remove(column);
+        }
+        output_columns.insert(self.name_col);
+        output_columns.insert(self.value_col);
+
+        // Derive used columns
+        let mut used_columns = child_prop.used_columns.clone();
+        used_columns.extend(self.columns_to_unpivot.iter().cloned());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns: child_prop.outer_columns.clone(),
+            used_columns,
+            orderings: vec![],
+            partition_orderings: None,
+        }))
+    }
+
+    fn derive_stats(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        let mut input_stat = rel_expr.derive_cardinality_child(0)?.deref().clone();
+        self.derive_unpivot_stats(&mut input_stat)
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::plans::ScalarItem;
+use crate::IndexType;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum TimeWindowKind {
+    /// Fixed-size, non-overlapping windows aligned to the unix epoch.
+    Tumbling,
+    /// Windows closed after no rows arrive for `window_size`.
+    Session,
+}
+
+/// Aggregate the rows by the time windows of `time_column`, `GROUP BY TUMBLING WINDOW(..)`
+/// or `GROUP BY SESSION WINDOW(..)`. Outputs one row per window, with the bounds of the
+/// window in `window_start` and `window_end`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WindowAgg {
+    pub kind: TimeWindowKind,
+    // The timestamp column the windows are computed on.
+    pub time_column: IndexType,
+    // The window size of tumbling windows, or the gap of session windows, in microseconds.
+    pub window_size: i64,
+    pub window_start: IndexType,
+    pub window_end: IndexType,
+    pub aggregate_functions: Vec<ScalarItem>,
+}
+
+impl WindowAgg {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        used_columns.insert(self.time_column);
+        used_columns.insert(self.window_start);
+        used_columns.insert(self.window_end);
+        for item in self.aggregate_functions.iter() {
+            used_columns.insert(item.index);
+            used_columns.extend(item.scalar.used_columns());
+        }
+        Ok(used_columns)
+    }
+}
+
+impl Operator for WindowAgg {
+    fn rel_op(&self) -> RelOp {
+        RelOp::WindowAgg
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns
+        let mut output_columns = ColumnSet::new();
+        output_columns.insert(self.window_start);
+        output_columns.insert(self.window_end);
+        for item in self.aggregate_functions.iter() {
+            output_columns.insert(item.index);
+        }
+
+        // Derive outer columns
+        let outer_columns = input_prop
+            .outer_columns
+            .difference(&output_columns)
+            .cloned()
+            .collect();
+
+        // Derive used columns
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(input_prop.used_columns.clone());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+            orderings: vec![],
+            partition_orderings: None,
+        }))
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn derive_stats(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        // At most one window per input row.
+        rel_expr.derive_cardinality_child(0)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        _required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        // The rows of a window may come from any node.
+        Ok(RequiredProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn compute_required_prop_children(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _required: &RequiredProperty,
+    ) -> Result<Vec<Vec<RequiredProperty>>> {
+        Ok(vec![vec![RequiredProperty {
+            distribution: Distribution::Serial,
+        }]])
+    }
+}
@@ -197,6 +197,17 @@ impl VisitorMut<'_> for GroupingChecker<'_> {
             return Ok(());
         }
 
+        if let Some(grouping_sets) = &self.bind_context.aggregate_info.grouping_sets {
+            if grouping_sets
+                .grouping_ids
+                .iter()
+                .any(|(_, index)| *index == column.column.index)
+            {
+                // Be replaced by `AggregateRewriter`.
+                return Ok(());
+            }
+        }
+
         // If this is a group item, then it should have been replaced with `group_items_map`
         Err(ErrorCode::SemanticError(format!(
             "column \"{}\" must appear in the GROUP BY clause or be used in an aggregate function",
@@ -74,6 +74,7 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::types::F32;
+use databend_common_expression::types::F64;
 use databend_common_expression::ColumnIndex;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataField;
@@ -1809,6 +1810,24 @@ impl<'a> TypeChecker<'a> {
             params
         };
 
+        // Convert the percentile of approx_percentile_cont to params
+        let params = if func_name.eq_ignore_ascii_case("approx_percentile_cont")
+            && arguments.len() == 2
+            && params.is_empty()
+        {
+            let percentile: F64 = check_number(
+                None,
+                &FunctionContext::default(),
+                &arguments[1].as_expr()?,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let _ = arguments.pop();
+            let _ = arg_types.pop();
+            vec![Scalar::Number(NumberScalar::Float64(percentile))]
+        } else {
+            params
+        };
+
         // Rewrite `xxx(distinct)` to `xxx_distinct(...)`
         let (func_name, distinct) = if func_name.eq_ignore_ascii_case("count") && distinct {
             ("count_distinct", false)
@@ -2810,14 +2829,14 @@ impl<'a> TypeChecker<'a> {
             Self::rewrite_substring(&mut args);
         }
 
-        if func_name == "grouping" {
-            // `grouping` will be rewritten again after resolving grouping sets.
+        if func_name == "grouping" || func_name == "grouping_id" {
+            // `grouping` and `grouping_id` will be rewritten again after resolving grouping sets.
             return Ok(Box::new((
                 ScalarExpr::FunctionCall(FunctionCall {
                     span,
                     params: vec![],
                     arguments: args,
-                    func_name: "grouping".to_string(),
+                    func_name: func_name.to_string(),
                 }),
                 DataType::Number(NumberDataType::UInt32),
             )));
@@ -3358,6 +3377,7 @@ impl<'a> TypeChecker<'a> {
             Ascii::new("least"),
             Ascii::new("stream_has_data"),
             Ascii::new("getvariable"),
+            Ascii::new("predict"),
         ];
         FUNCTIONS
     }
@@ -3759,6 +3779,7 @@ impl<'a> TypeChecker<'a> {
                     "Variable name must be a constant string",
                 )))
             }
+            ("predict", args) => Some(self.resolve_predict(span, args)),
             _ => None,
         }
     }
@@ -4184,6 +4205,72 @@ impl<'a> TypeChecker<'a> {
         )))
     }
 
+    /// Resolve `PREDICT('@stage/model.onnx', arg1, ...)`, which runs the ONNX model in the
+    /// stage on the arguments cast to Float32, and returns the first value of its output.
+    fn resolve_predict(
+        &mut self,
+        span: Span,
+        args: &[&Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if args.len() < 2 {
+            return Err(ErrorCode::InvalidArgument(
+                "predict requires a model location and at least one argument",
+            )
+            .set_span(span));
+        }
+        let box (scalar, _) = self.resolve(args[0])?;
+        let location = match ConstantExpr::try_from(scalar) {
+            Ok(ConstantExpr {
+                value: Scalar::String(location),
+                ..
+            }) if location.starts_with('@') => location,
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "Model location of predict must be a constant string of a stage path like '@stage/model.onnx'",
+                )
+                .set_span(args[0].span()));
+            }
+        };
+
+        let mut arguments = Vec::with_capacity(args.len() - 1);
+        let mut arg_types = Vec::with_capacity(args.len() - 1);
+        for argument in args[1..].iter() {
+            let box (arg, ty) = self.resolve(argument)?;
+            let dest_type = if ty.is_nullable_or_null() {
+                DataType::Number(NumberDataType::Float32).wrap_nullable()
+            } else {
+                DataType::Number(NumberDataType::Float32)
+            };
+            if ty != dest_type {
+                arguments.push(wrap_cast(&arg, &dest_type));
+            } else {
+                arguments.push(arg);
+            }
+            arg_types.push(dest_type);
+        }
+
+        let arg_names = args.iter().map(|arg| format!("{arg}")).join(", ");
+        // The prediction of a row with a NULL argument is NULL.
+        let return_type = DataType::Number(NumberDataType::Float32).wrap_nullable();
+
+        self.bind_context.have_udf_script = true;
+        self.ctx.set_cacheable(false);
+        Ok(Box::new((
+            UDFCall {
+                span,
+                name: "predict".to_string(),
+                handler: "predict".to_string(),
+                display_name: format!("predict({})", arg_names),
+                arg_types,
+                return_type: Box::new(return_type.clone()),
+                udf_type: UDFType::Model(location),
+                arguments,
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
     fn resolve_udaf_script(
         &mut self,
         span: Span,
@@ -32,6 +32,7 @@ impl ViewRewriter {
             alias,
             temporal,
             with_options,
+            index_hint,
             pivot,
             unpivot,
             sample,
@@ -52,6 +53,7 @@ impl ViewRewriter {
                     alias: alias.clone(),
                     temporal: temporal.clone(),
                     with_options: with_options.clone(),
+                    index_hint: index_hint.clone(),
                     pivot: pivot.clone(),
                     unpivot: unpivot.clone(),
                     sample: sample.clone(),
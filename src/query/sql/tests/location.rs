@@ -332,6 +332,7 @@ async fn test_parse_uri_location() -> Result<()> {
                 StorageParams::Http(StorageHttpConfig {
                     endpoint_url: "https://example.com".to_string(),
                     paths: ["/tmp.csv"].iter().map(|v| v.to_string()).collect(),
+                    token: "".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -351,6 +352,7 @@ async fn test_parse_uri_location() -> Result<()> {
                         .iter()
                         .map(|v| v.to_string())
                         .collect(),
+                    token: "".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -376,6 +378,7 @@ async fn test_parse_uri_location() -> Result<()> {
                     .iter()
                     .map(|v| v.to_string())
                     .collect(),
+                    token: "".to_string(),
                 }),
                 "/".to_string(),
             ),
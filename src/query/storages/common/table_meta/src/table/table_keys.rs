@@ -59,6 +59,13 @@ pub const OPT_KEY_ENABLE_COPY_DEDUP_FULL_PATH: &str = "copy_dedup_full_path";
 pub const LINEAR_CLUSTER_TYPE: &str = "linear";
 pub const HILBERT_CLUSTER_TYPE: &str = "hilbert";
 
+// the following are used for rollup tables, whose rows pre-aggregate a raw table
+pub const OPT_KEY_ROLLUP_KEYS: &str = "rollup_keys";
+pub const OPT_KEY_ROLLUP_COUNT: &str = "rollup_count";
+
+// a boolean predicate over the table columns, only the rows it accepts are visible to readers
+pub const OPT_KEY_ROW_ACCESS_POLICY: &str = "row_access_policy";
+
 /// Table option keys that reserved for internal usage only
 /// - Users are not allowed to specified this option keys in DDL
 /// - Should not be shown in `show create table` statement
@@ -27,8 +27,11 @@ use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::DistributionLevel;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TimeNavigation;
 use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::AbortChecker;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -68,7 +71,7 @@ pub struct DeltaTable {
     meta: DeltaTableMeta,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct DeltaTableMeta {
     partition_columns: Vec<String>,
 }
@@ -157,24 +160,57 @@ impl DeltaTable {
         Ok((schema, meta))
     }
 
-    #[async_backtrace::framed]
-    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+    fn build(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
         let op = init_operator(sp)?;
         let opendal_store = Arc::new(OpendalStore::new(op));
 
-        let mut table = DeltaTableBuilder::from_uri(Url::from_directory_path("/").unwrap())
+        DeltaTableBuilder::from_uri(Url::from_directory_path("/").unwrap())
             .with_storage_backend(opendal_store, Url::from_directory_path("/").unwrap())
             .build()
             .map_err(|err| {
                 ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
-            })?;
+            })
+    }
 
+    #[async_backtrace::framed]
+    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::build(sp)?;
         table.load().await.map_err(|err| {
             ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
         })?;
         Ok(table)
     }
 
+    /// Replay the transaction log up to the version of the point, `AT (SNAPSHOT => '<version>')`,
+    /// or up to the latest version committed before the timestamp, `AT (TIMESTAMP => ...)`.
+    #[async_backtrace::framed]
+    pub async fn load_at(
+        sp: &StorageParams,
+        point: &NavigationPoint,
+    ) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::build(sp)?;
+        let loaded = match point {
+            NavigationPoint::SnapshotID(version) => {
+                let version = version.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!(
+                        "Delta table version must be an integer, but got '{version}'"
+                    ))
+                })?;
+                table.load_version(version).await
+            }
+            NavigationPoint::TimePoint(timestamp) => table.load_with_datetime(*timestamp).await,
+            NavigationPoint::StreamInfo(_) => {
+                return Err(ErrorCode::Unimplemented(
+                    "Delta table can not be read at a stream point",
+                ));
+            }
+        };
+        loaded.map_err(|err| {
+            ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
+        })?;
+        Ok(table)
+    }
+
     #[async_backtrace::framed]
     async fn table(&self) -> Result<&deltalake::table::DeltaTable> {
         self.table
@@ -412,6 +448,26 @@ impl Table for DeltaTable {
     fn support_prewhere(&self) -> bool {
         true
     }
+
+    #[async_backtrace::framed]
+    async fn navigate_to(
+        &self,
+        navigation: &TimeNavigation,
+        _abort_checker: AbortChecker,
+    ) -> Result<Arc<dyn Table>> {
+        let TimeNavigation::TimeTravel(point) = navigation else {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Changes of the table '{}', which uses the 'DELTA' engine, are not supported",
+                self.name()
+            )));
+        };
+        let table = Self::load_at(self.get_storage_params()?, point).await?;
+        Ok(Arc::new(Self {
+            info: self.info.clone(),
+            table: OnceCell::new_with(Some(table)),
+            meta: self.meta.clone(),
+        }))
+    }
 }
 
 pub fn get_partition_values(add: &Add, fields: &[TableField]) -> Result<Vec<Scalar>> {
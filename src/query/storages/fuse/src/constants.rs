@@ -34,6 +34,7 @@ pub const FUSE_TBL_LAST_SNAPSHOT_HINT_V2: &str = "last_snapshot_location_hint_v2
 pub const FUSE_TBL_VIRTUAL_BLOCK_PREFIX: &str = "_vb";
 pub const FUSE_TBL_AGG_INDEX_PREFIX: &str = "_i_a";
 pub const FUSE_TBL_INVERTED_INDEX_PREFIX: &str = "_i_i";
+pub const FUSE_TBL_WAL_PREFIX: &str = "_wal";
 
 pub const DEFAULT_ROW_PER_PAGE: usize = 131072;
 pub const DEFAULT_ROW_PER_PAGE_FOR_BLOCKING: usize = 2048;
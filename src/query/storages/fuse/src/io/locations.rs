@@ -33,6 +33,7 @@ use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
+use crate::constants::FUSE_TBL_WAL_PREFIX;
 use crate::index::filters::BlockFilter;
 use crate::index::InvertedIndexFile;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
@@ -63,6 +64,7 @@ pub struct TableMetaLocationGenerator {
     snapshot_location_prefix: String,
     agg_index_location_prefix: String,
     inverted_index_location_prefix: String,
+    wal_location_prefix: String,
 }
 
 impl TableMetaLocationGenerator {
@@ -75,6 +77,7 @@ impl TableMetaLocationGenerator {
         let agg_index_location_prefix = format!("{}/{}/", &prefix, FUSE_TBL_AGG_INDEX_PREFIX);
         let inverted_index_location_prefix =
             format!("{}/{}/", &prefix, FUSE_TBL_INVERTED_INDEX_PREFIX);
+        let wal_location_prefix = format!("{}/{}/", &prefix, FUSE_TBL_WAL_PREFIX);
         Self {
             prefix,
             block_location_prefix,
@@ -83,6 +86,7 @@ impl TableMetaLocationGenerator {
             snapshot_location_prefix,
             agg_index_location_prefix,
             inverted_index_location_prefix,
+            wal_location_prefix,
         }
     }
 
@@ -106,6 +110,10 @@ impl TableMetaLocationGenerator {
         &self.snapshot_location_prefix
     }
 
+    pub fn wal_location_prefix(&self) -> &str {
+        &self.wal_location_prefix
+    }
+
     pub fn gen_block_location(
         &self,
         table_meta_timestamps: TableMetaTimestamps,
@@ -209,25 +209,26 @@ impl SinkAnalyzeState {
         if data_block.num_rows() == 0 {
             return Ok(());
         }
+        // The columns are bucket_lower, bucket_upper, count and ndv.
         for row in 0..data_block.num_rows() {
-            let column = &data_block.columns()[1];
-            let value = column.value.index(row).clone().unwrap();
-            let number = value.as_number().unwrap();
-            let ndv = number.as_u_int64().unwrap();
-            let upper_bound =
-                Datum::from_scalar(data_block.columns()[2].value.index(row).unwrap().to_owned())
+            let lower_bound =
+                Datum::from_scalar(data_block.columns()[0].value.index(row).unwrap().to_owned())
                     .ok_or_else(|| {
                         ErrorCode::Internal("Don't support the type to generate histogram")
                     })?;
-            let lower_bound =
-                Datum::from_scalar(data_block.columns()[3].value.index(row).unwrap().to_owned())
+            let upper_bound =
+                Datum::from_scalar(data_block.columns()[1].value.index(row).unwrap().to_owned())
                     .ok_or_else(|| {
                         ErrorCode::Internal("Don't support the type to generate histogram")
                     })?;
-            let count_col = &data_block.columns()[4];
+            let count_col = &data_block.columns()[2];
             let val = count_col.value.index(row).clone().unwrap();
             let number = val.as_number().unwrap();
             let count = number.as_u_int64().unwrap();
+            let column = &data_block.columns()[3];
+            let value = column.value.index(row).clone().unwrap();
+            let number = value.as_number().unwrap();
+            let ndv = number.as_u_int64().unwrap();
             let bucket = HistogramBucket::new(lower_bound, upper_bound, *count as f64, *ndv as f64);
             self.histograms
                 .entry(col_id)
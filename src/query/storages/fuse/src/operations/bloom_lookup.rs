@@ -0,0 +1,117 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_table_meta::meta::Location;
+use log::info;
+
+use crate::io::SegmentsIO;
+use crate::pruning::BloomPruner;
+use crate::pruning::BloomPrunerCreator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// The segments of the snapshot which may contain rows with `column = value`.
+    ///
+    /// A segment is skipped if the bloom filters of all its blocks test negative for the
+    /// value. All the segments are returned if the column has no bloom index.
+    #[async_backtrace::framed]
+    pub async fn bloom_lookup_segments(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        column: &str,
+        value: &Scalar,
+    ) -> Result<Vec<Location>> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(vec![]);
+        };
+
+        let schema = self.schema_with_stream();
+        let field = schema.field_with_name(column)?;
+        let expr = check_function(
+            None,
+            "eq",
+            &[],
+            &[
+                Expr::ColumnRef {
+                    span: None,
+                    id: column.to_string(),
+                    data_type: DataType::from(field.data_type()),
+                    display_name: column.to_string(),
+                },
+                Expr::Constant {
+                    span: None,
+                    scalar: value.clone(),
+                    data_type: value.as_ref().infer_data_type(),
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )?;
+        let Some(bloom_pruner) = BloomPrunerCreator::create(
+            ctx.get_function_context()?,
+            &schema,
+            self.get_operator(),
+            Some(&expr),
+            self.bloom_index_cols(),
+            None,
+        )?
+        else {
+            return Ok(snapshot.segments.clone());
+        };
+
+        let mut segments = Vec::with_capacity(snapshot.segments.len());
+        for location in snapshot.segments.iter() {
+            let segment = SegmentsIO::read_compact_segment(
+                self.get_operator(),
+                location.clone(),
+                schema.clone(),
+                true,
+            )
+            .await?;
+            for block_meta in segment.block_metas()? {
+                let column_ids = block_meta.col_metas.keys().cloned().collect::<Vec<_>>();
+                if bloom_pruner
+                    .should_keep(
+                        &block_meta.bloom_filter_index_location,
+                        block_meta.bloom_filter_index_size,
+                        &block_meta.col_stats,
+                        column_ids,
+                        &block_meta,
+                    )
+                    .await
+                {
+                    segments.push(location.clone());
+                    break;
+                }
+            }
+        }
+
+        info!(
+            "bloom lookup of {} kept {} of {} segments",
+            column,
+            segments.len(),
+            snapshot.segments.len()
+        );
+        Ok(segments)
+    }
+}
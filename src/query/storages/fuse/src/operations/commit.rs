@@ -66,6 +66,7 @@ use crate::operations::common::TableMutationAggregator;
 use crate::operations::common::TransformSerializeSegment;
 use crate::operations::set_backoff;
 use crate::operations::SnapshotHintWriter;
+use crate::operations::WriteConflictRetry;
 use crate::statistics::merge_statistics;
 use crate::FuseTable;
 
@@ -111,6 +112,7 @@ impl FuseTable {
         });
 
         let snapshot_gen = AppendGenerator::new(ctx.clone(), overwrite);
+        let write_conflict_retry = WriteConflictRetry::try_create(ctx.as_ref())?;
         pipeline.add_sink(|input| {
             CommitSink::try_create(
                 self,
@@ -120,6 +122,7 @@ impl FuseTable {
                 snapshot_gen.clone(),
                 input,
                 None,
+                write_conflict_retry,
                 prev_snapshot_id,
                 deduplicated_label.clone(),
                 table_meta_timestamps,
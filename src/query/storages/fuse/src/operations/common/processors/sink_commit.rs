@@ -56,6 +56,7 @@ use crate::operations::AppendGenerator;
 use crate::operations::CommitMeta;
 use crate::operations::SnapshotGenerator;
 use crate::operations::TruncateGenerator;
+use crate::operations::WriteConflictRetry;
 use crate::FuseTable;
 enum State {
     None,
@@ -91,6 +92,7 @@ pub struct CommitSink<F: SnapshotGenerator> {
     purge: bool,
     retries: u64,
     max_retry_elapsed: Option<Duration>,
+    write_conflict_retry: WriteConflictRetry,
     backoff: ExponentialBackoff,
 
     new_segment_locs: Vec<Location>,
@@ -117,6 +119,7 @@ where F: SnapshotGenerator + Send + Sync + 'static
         snapshot_gen: F,
         input: Arc<InputPort>,
         max_retry_elapsed: Option<Duration>,
+        write_conflict_retry: WriteConflictRetry,
         prev_snapshot_id: Option<SnapshotId>,
         deduplicated_label: Option<String>,
         table_meta_timestamps: TableMetaTimestamps,
@@ -150,6 +153,7 @@ where F: SnapshotGenerator + Send + Sync + 'static
             backoff: ExponentialBackoff::default(),
             retries: 0,
             max_retry_elapsed,
+            write_conflict_retry,
             input,
             new_segment_locs: vec![],
             start_time: Instant::now(),
@@ -202,7 +206,11 @@ where F: SnapshotGenerator + Send + Sync + 'static
 
         self.new_segment_locs = meta.new_segment_locs;
 
-        self.backoff = set_backoff(None, None, self.max_retry_elapsed);
+        self.backoff = set_backoff(
+            Some(Duration::from_millis(self.write_conflict_retry.backoff_ms)),
+            None,
+            self.max_retry_elapsed,
+        );
 
         self.snapshot_gen
             .set_conflict_resolve_context(meta.conflict_resolve_context);
@@ -529,7 +537,12 @@ where F: SnapshotGenerator + Send + Sync + 'static
                     }
                     Err(e) if self.is_error_recoverable(&e) => {
                         let table_info = self.table.get_table_info();
-                        match self.backoff.next_backoff() {
+                        let backoff = if self.write_conflict_retry.exhausted(self.retries) {
+                            None
+                        } else {
+                            self.backoff.next_backoff()
+                        };
+                        match backoff {
                             Some(d) => {
                                 let name = table_info.name.clone();
                                 debug!(
@@ -15,6 +15,7 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod bloom_lookup;
 mod changes;
 mod commit;
 mod common;
@@ -26,6 +27,7 @@ mod merge_into;
 mod mutation;
 mod mutation_source;
 mod navigate;
+mod partition_change_detector;
 mod read;
 mod read_data;
 mod read_partitions;
@@ -46,6 +48,7 @@ pub use compact::CompactOptions;
 pub use merge_into::*;
 pub use mutation::*;
 pub use mutation_source::*;
+pub use partition_change_detector::PartitionChangeDetector;
 pub use read::need_reserve_block_info;
 pub use read::row_fetch_processor;
 pub use replace_into::*;
@@ -54,3 +57,4 @@ pub use util::acquire_task_permit;
 pub use util::column_parquet_metas;
 pub use util::read_block;
 pub use util::set_backoff;
+pub use util::WriteConflictRetry;
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_exception::Result;
+use futures::TryStreamExt;
+use opendal::EntryMode;
+
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseBlockPartInfo;
+use crate::FuseTable;
+
+/// Detects the block partitions of the base table that changed since the last refresh
+/// of an aggregating index.
+///
+/// The fingerprint of a block is the location of its aggregating index file. Blocks are
+/// immutable, so a block rewritten by a mutation gets a new location and a new fingerprint.
+/// The fingerprints of the index are the index files written so far, a block is changed
+/// if its fingerprint is not one of them and it's created after the last refresh.
+pub struct PartitionChangeDetector {
+    index_id: u64,
+    updated_on: Option<DateTime<Utc>>,
+    fingerprints: HashSet<String>,
+}
+
+impl PartitionChangeDetector {
+    #[async_backtrace::framed]
+    pub async fn try_create(
+        fuse_table: &FuseTable,
+        index_id: u64,
+        updated_on: Option<DateTime<Utc>>,
+    ) -> Result<Self> {
+        let prefix = format!(
+            "{}{}/",
+            fuse_table
+                .meta_location_generator()
+                .agg_index_location_prefix(),
+            index_id
+        );
+        let mut fingerprints = HashSet::new();
+        let mut lister = fuse_table.get_operator_ref().lister_with(&prefix).await?;
+        while let Some(entry) = lister.try_next().await? {
+            if entry.metadata().mode() == EntryMode::FILE {
+                fingerprints.insert(entry.path().to_string());
+            }
+        }
+
+        Ok(PartitionChangeDetector {
+            index_id,
+            updated_on,
+            fingerprints,
+        })
+    }
+
+    pub fn fingerprint(&self, part: &FuseBlockPartInfo) -> String {
+        TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+            &part.location,
+            self.index_id,
+        )
+    }
+
+    pub fn is_changed(&self, part: &PartInfoPtr) -> Result<bool> {
+        let part = FuseBlockPartInfo::from_part(part)?;
+        let refreshed = matches!(
+            (part.create_on, self.updated_on),
+            (Some(create_on), Some(updated_on)) if create_on <= updated_on
+        );
+        Ok(!refreshed && !self.fingerprints.contains(&self.fingerprint(part)))
+    }
+
+    /// Keep the changed partitions only, in their original order.
+    pub fn changed_partitions(&self, parts: Vec<PartInfoPtr>) -> Result<Vec<PartInfoPtr>> {
+        let mut changed = Vec::with_capacity(parts.len());
+        for part in parts {
+            if self.is_changed(&part)? {
+                changed.push(part);
+            }
+        }
+        Ok(changed)
+    }
+}
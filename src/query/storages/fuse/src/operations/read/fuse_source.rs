@@ -37,6 +37,7 @@ use crate::io::VirtualColumnReader;
 use crate::operations::read::block_partition_receiver_source::BlockPartitionReceiverSource;
 use crate::operations::read::block_partition_source::BlockPartitionSource;
 use crate::operations::read::native_data_transform_reader::ReadNativeDataTransform;
+use crate::operations::read::parquet_data_prefetch_source::PrefetchParquetDataSource;
 use crate::operations::read::parquet_data_transform_reader::ReadParquetDataTransform;
 use crate::operations::read::DeserializeDataTransform;
 use crate::operations::read::NativeDeserializeDataTransform;
@@ -169,7 +170,8 @@ pub fn build_fuse_parquet_source_pipeline(
     (max_threads, max_io_requests) =
         adjust_threads_and_request(false, max_threads, max_io_requests, plan);
 
-    match block_reader.support_blocking_api() {
+    // The read-ahead is done by spawning async reads, even if the storage has a blocking api.
+    match block_reader.support_blocking_api() && plan.prefetch_depth == 0 {
         true => {
             let partitions = dispatch_partitions(ctx.clone(), plan, max_threads);
             let partitions = StealablePartitions::new(partitions, ctx.clone());
@@ -203,11 +205,33 @@ pub fn build_fuse_parquet_source_pipeline(
             let partitions = dispatch_partitions(ctx.clone(), plan, max_io_requests);
             let partitions = StealablePartitions::new(partitions, ctx.clone());
 
-            match receiver {
+            match &receiver {
                 Some(rx) => {
                     let pipe = build_receiver_source(max_io_requests, ctx.clone(), rx.clone())?;
                     pipeline.add_pipe(pipe);
                 }
+                None if plan.prefetch_depth > 0 => {
+                    let mut source_builder = SourcePipeBuilder::create();
+                    for i in 0..max_io_requests {
+                        let output = OutputPort::create();
+                        source_builder.add_source(
+                            output.clone(),
+                            PrefetchParquetDataSource::create(
+                                i,
+                                partitions.clone(),
+                                plan.prefetch_depth,
+                                plan.table_index,
+                                ctx.clone(),
+                                table_schema.clone(),
+                                block_reader.clone(),
+                                index_reader.clone(),
+                                virtual_reader.clone(),
+                                output,
+                            )?,
+                        );
+                    }
+                    pipeline.add_pipe(source_builder.finalize());
+                }
                 None => {
                     let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
                     let pipe = build_block_source(
@@ -220,18 +244,21 @@ pub fn build_fuse_parquet_source_pipeline(
                 }
             }
 
-            pipeline.add_transform(|input, output| {
-                ReadParquetDataTransform::<false>::create(
-                    plan.table_index,
-                    ctx.clone(),
-                    table_schema.clone(),
-                    block_reader.clone(),
-                    index_reader.clone(),
-                    virtual_reader.clone(),
-                    input,
-                    output,
-                )
-            })?;
+            // The prefetch source has read the data already.
+            if receiver.is_some() || plan.prefetch_depth == 0 {
+                pipeline.add_transform(|input, output| {
+                    ReadParquetDataTransform::<false>::create(
+                        plan.table_index,
+                        ctx.clone(),
+                        table_schema.clone(),
+                        block_reader.clone(),
+                        index_reader.clone(),
+                        virtual_reader.clone(),
+                        input,
+                        output,
+                    )
+                })?;
+            }
 
             pipeline.try_resize(std::cmp::min(max_threads, max_io_requests))?;
 
@@ -18,6 +18,7 @@ mod native_data_source;
 mod native_data_source_deserializer;
 mod native_data_transform_reader;
 mod native_rows_fetcher;
+mod parquet_data_prefetch_source;
 mod parquet_data_source;
 mod parquet_data_source_deserializer;
 mod parquet_data_transform_reader;
@@ -28,7 +28,9 @@ use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::plan::TopK;
 use databend_common_catalog::plan::VirtualColumnField;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterStats;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::eval_function;
 use databend_common_expression::filter_helper::FilterHelpers;
@@ -59,6 +61,7 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::IndexType;
+use parking_lot::Mutex;
 use xorf::BinaryFuse16;
 
 use super::native_data_source::NativeDataSource;
@@ -213,6 +216,8 @@ pub struct NativeDeserializeDataTransform {
     prewhere_schema: DataSchema,
     prewhere_columns: Vec<usize>,
     prewhere_filter: Arc<Option<Expr>>,
+    /// The offset in the prewhere block of the column selecting the rows instead of `prewhere_filter`.
+    prewhere_row_selector: Option<usize>,
     filter_executor: Option<FilterExecutor>,
 
     // Structures for virtual columns:
@@ -222,7 +227,7 @@ pub struct NativeDeserializeDataTransform {
 
     // Structures for the bloom runtime filter:
     ctx: Arc<dyn TableContext>,
-    bloom_runtime_filter: Option<Vec<(FieldIndex, BinaryFuse16)>>,
+    bloom_runtime_filter: Option<Vec<(FieldIndex, BinaryFuse16, Arc<Mutex<RuntimeFilterStats>>)>>,
 
     // Structures for aggregating index:
     index_reader: Arc<Option<AggIndexReader>>,
@@ -340,6 +345,7 @@ impl NativeDeserializeDataTransform {
         let func_ctx = ctx.get_function_context()?;
         let prewhere_schema = src_schema.project(&prewhere_columns);
         let prewhere_filter = Self::build_prewhere_filter_expr(plan, &prewhere_schema)?;
+        let prewhere_row_selector = Self::build_prewhere_row_selector(plan, &prewhere_schema);
 
         let filter_executor = if let Some(expr) = prewhere_filter.as_ref() {
             Some(FilterExecutor::new(
@@ -379,6 +385,7 @@ impl NativeDeserializeDataTransform {
                 output_schema,
                 virtual_column_fields,
                 prewhere_filter,
+                prewhere_row_selector,
                 prewhere_virtual_column_fields,
                 filter_executor,
                 skipped_pages: 0,
@@ -406,6 +413,13 @@ impl NativeDeserializeDataTransform {
         ))
     }
 
+    fn build_prewhere_row_selector(plan: &DataSourcePlan, schema: &DataSchema) -> Option<usize> {
+        let prewhere = PushDownInfo::prewhere_of_push_downs(plan.push_downs.as_ref())?;
+        let table_schema = plan.source_info.schema();
+        let name = table_schema.field(prewhere.row_selector_column?).name();
+        schema.index_of(name).ok()
+    }
+
     fn add_output_block(&mut self, data_block: DataBlock) {
         let rows = data_block.num_rows();
         if rows == 0 {
@@ -818,7 +832,17 @@ impl NativeDeserializeDataTransform {
 
             let filter_executor = self.filter_executor.as_mut().unwrap();
 
-            let count = filter_executor.select(&prewhere_block)?;
+            let count = match self.prewhere_row_selector {
+                // The rows are already selected by the row selector column,
+                // so the prewhere filter is not evaluated.
+                Some(offset) => {
+                    let selector = prewhere_block
+                        .get_by_offset(offset)
+                        .to_column(prewhere_block.num_rows());
+                    filter_executor.from_bitmap(row_selector_to_bitmap(selector)?)
+                }
+                None => filter_executor.select(&prewhere_block)?,
+            };
 
             // If it's all filtered, we can skip the current pages.
             if count == 0 {
@@ -838,7 +862,7 @@ impl NativeDeserializeDataTransform {
     fn read_and_check_bloom_runtime_filter(&mut self) -> Result<bool> {
         if let Some(bloom_runtime_filter) = self.bloom_runtime_filter.as_ref() {
             let mut bitmaps = Vec::with_capacity(bloom_runtime_filter.len());
-            for (idx, filter) in bloom_runtime_filter.iter() {
+            for (idx, filter, stats) in bloom_runtime_filter.iter() {
                 let column = if let Some((_, column)) =
                     self.read_state.columns.iter().find(|(i, _)| i == idx)
                 {
@@ -858,6 +882,7 @@ impl NativeDeserializeDataTransform {
                 let probe_column = probe_block.get_last_column().clone();
                 update_bitmap_with_bloom_filter(probe_column, filter, &mut bitmap)?;
                 let unset_bits = bitmap.null_count();
+                stats.lock().filtered_rows += unset_bits as u64;
                 if unset_bits == bitmap.len() {
                     // skip current page.
                     return Ok(false);
@@ -935,15 +960,23 @@ impl NativeDeserializeDataTransform {
     fn try_init_bloom_runtime_filter(&mut self) {
         if self.bloom_runtime_filter.is_none() {
             let bloom_filters = self.ctx.get_bloom_runtime_filter_with_id(self.table_index);
+            let bloom_stats = self
+                .ctx
+                .get_bloom_runtime_filter_stats_with_id(self.table_index);
             let bloom_filters = bloom_filters
                 .into_iter()
                 .filter_map(|filter| {
                     let name = filter.0.as_str();
+                    let stats = bloom_stats
+                        .iter()
+                        .find(|(column, _)| column == name)
+                        .map(|(_, stats)| stats.clone())
+                        .unwrap_or_default();
                     // Some probe keys are not in the schema, they are derived from expressions.
                     self.src_schema
                         .index_of(name)
                         .ok()
-                        .map(|idx| (idx, filter.1.clone()))
+                        .map(|idx| (idx, filter.1.clone(), stats))
                 })
                 .collect::<Vec<_>>();
             if !bloom_filters.is_empty() {
@@ -1148,6 +1181,26 @@ impl Processor for NativeDeserializeDataTransform {
 /// Build a dummy filter executor to retain a selection.
 ///
 /// This method may be used by `update_topk_heap` and `read_and_check_bloom_runtime_filter`.
+/// Convert a boolean column to the selection bitmap, null values are not selected.
+fn row_selector_to_bitmap(column: Column) -> Result<MutableBitmap> {
+    let (column, validity) = match column {
+        Column::Nullable(column) => (column.column, Some(column.validity)),
+        column => (column, None),
+    };
+    let Column::Boolean(bitmap) = column else {
+        return Err(ErrorCode::Internal(format!(
+            "The prewhere row selector must be a boolean column, but got {}",
+            column.data_type()
+        )));
+    };
+    let bitmap = match validity {
+        Some(validity) => (&bitmap).bitand(&validity),
+        None => bitmap,
+    };
+    let rows = bitmap.len();
+    Ok(FilterHelpers::filter_to_bitmap(Value::Column(bitmap), rows))
+}
+
 fn new_dummy_filter_executor(func_ctx: FunctionContext) -> FilterExecutor {
     let dummy_expr = Expr::Constant {
         span: None,
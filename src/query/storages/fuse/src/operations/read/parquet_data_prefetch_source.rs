@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio::task::JoinHandle;
+use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::plan::StealablePartitions;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::TableSchema;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_sql::IndexType;
+use databend_storages_common_io::ReadSettings;
+
+use super::parquet_data_source::ParquetDataSource;
+use super::parquet_data_transform_reader::read_parquet_data;
+use crate::io::AggIndexReader;
+use crate::io::BlockReader;
+use crate::io::VirtualColumnReader;
+use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
+use crate::operations::read::runtime_filter_prunner::runtime_filter_pruner;
+
+/// Steal the partitions and read their data like `BlockPartitionSource` followed by
+/// `ReadParquetDataTransform`, but the reads of the next `prefetch_depth` partitions are
+/// spawned before the data of the current one is returned, so they overlap with its processing.
+pub struct PrefetchParquetDataSource {
+    id: usize,
+    partitions: StealablePartitions,
+    prefetch_depth: usize,
+    // The spawned reads, in the order of the partitions.
+    reading: VecDeque<(PartInfoPtr, JoinHandle<Result<ParquetDataSource>>)>,
+    no_more_partitions: bool,
+
+    func_ctx: FunctionContext,
+    block_reader: Arc<BlockReader>,
+    index_reader: Arc<Option<AggIndexReader>>,
+    virtual_reader: Arc<Option<VirtualColumnReader>>,
+    table_schema: Arc<TableSchema>,
+    table_index: IndexType,
+    context: Arc<dyn TableContext>,
+}
+
+impl PrefetchParquetDataSource {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        id: usize,
+        partitions: StealablePartitions,
+        prefetch_depth: usize,
+        table_index: IndexType,
+        ctx: Arc<dyn TableContext>,
+        table_schema: Arc<TableSchema>,
+        block_reader: Arc<BlockReader>,
+        index_reader: Arc<Option<AggIndexReader>>,
+        virtual_reader: Arc<Option<VirtualColumnReader>>,
+        output: Arc<OutputPort>,
+    ) -> Result<ProcessorPtr> {
+        let func_ctx = ctx.get_function_context()?;
+        AsyncSourcer::create(ctx.clone(), output, PrefetchParquetDataSource {
+            id,
+            partitions,
+            prefetch_depth,
+            reading: VecDeque::with_capacity(prefetch_depth + 1),
+            no_more_partitions: false,
+            func_ctx,
+            block_reader,
+            index_reader,
+            virtual_reader,
+            table_schema,
+            table_index,
+            context: ctx,
+        })
+    }
+
+    fn spawn_reads(&mut self) -> Result<()> {
+        let mut filters = self
+            .context
+            .get_inlist_runtime_filter_with_id(self.table_index);
+        filters.extend(
+            self.context
+                .get_min_max_runtime_filter_with_id(self.table_index),
+        );
+
+        while !self.no_more_partitions && self.reading.len() <= self.prefetch_depth {
+            let Some(parts) = self.partitions.steal(self.id, 1) else {
+                self.no_more_partitions = true;
+                break;
+            };
+            for part in parts {
+                if runtime_filter_pruner(
+                    self.table_schema.clone(),
+                    &part,
+                    &filters,
+                    &self.func_ctx,
+                )? {
+                    continue;
+                }
+
+                let handle = databend_common_base::runtime::spawn(read_parquet_data(
+                    part.clone(),
+                    self.block_reader.clone(),
+                    ReadSettings::from_ctx(&self.context)?,
+                    self.index_reader.clone(),
+                    self.virtual_reader.clone(),
+                ));
+                self.reading.push_back((part, handle));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for PrefetchParquetDataSource {
+    const NAME: &'static str = "PrefetchParquetDataSource";
+    // The data is carried by the meta of an empty block.
+    const SKIP_EMPTY_DATA_BLOCK: bool = false;
+
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        self.spawn_reads()?;
+
+        let Some((part, handle)) = self.reading.pop_front() else {
+            return Ok(None);
+        };
+        let source = handle
+            .await
+            .map_err(|e| ErrorCode::TokioError(format!("Prefetch block data failed: {}", e)))??;
+        Ok(Some(DataBlock::empty_with_meta(
+            DataSourceWithMeta::create(vec![part], vec![source]),
+        )))
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        // The query is finished early, e.g. by a limit, the reads ahead are not needed.
+        for (_, handle) in self.reading.drain(..) {
+            handle.abort();
+        }
+        Ok(())
+    }
+}
@@ -24,6 +24,7 @@ use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterReady;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterStats;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -43,6 +44,7 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::IndexType;
+use parking_lot::Mutex;
 use xorf::BinaryFuse16;
 
 use super::parquet_data_source::ParquetDataSource;
@@ -74,7 +76,7 @@ pub struct DeserializeDataTransform {
     virtual_reader: Arc<Option<VirtualColumnReader>>,
 
     base_block_ids: Option<Scalar>,
-    cached_runtime_filter: Option<Vec<(FieldIndex, BinaryFuse16)>>,
+    cached_runtime_filter: Option<Vec<(FieldIndex, BinaryFuse16, Arc<Mutex<RuntimeFilterStats>>)>>,
     // for merge_into target build.
     need_reserve_block_info: bool,
     need_wait_runtime_filter: bool,
@@ -141,17 +143,25 @@ impl DeserializeDataTransform {
         // Check if already cached runtime filters
         if self.cached_runtime_filter.is_none() {
             let bloom_filters = self.ctx.get_bloom_runtime_filter_with_id(self.table_index);
+            let bloom_stats = self
+                .ctx
+                .get_bloom_runtime_filter_stats_with_id(self.table_index);
             let bloom_filters = bloom_filters
                 .into_iter()
                 .filter_map(|filter| {
                     let name = filter.0.as_str();
+                    let stats = bloom_stats
+                        .iter()
+                        .find(|(column, _)| column == name)
+                        .map(|(_, stats)| stats.clone())
+                        .unwrap_or_default();
                     // Some probe keys are not in the schema, they are derived from expressions.
                     self.src_schema
                         .index_of(name)
                         .ok()
-                        .map(|idx| (idx, filter.1.clone()))
+                        .map(|idx| (idx, filter.1.clone(), stats))
                 })
-                .collect::<Vec<(FieldIndex, BinaryFuse16)>>();
+                .collect::<Vec<_>>();
             if bloom_filters.is_empty() {
                 return Ok(None);
             }
@@ -159,13 +169,14 @@ impl DeserializeDataTransform {
         }
 
         let mut bitmaps = vec![];
-        for (idx, filter) in self.cached_runtime_filter.as_ref().unwrap().iter() {
+        for (idx, filter, stats) in self.cached_runtime_filter.as_ref().unwrap().iter() {
             let mut bitmap = MutableBitmap::from_len_zeroed(data_block.num_rows());
             let probe_block_entry = data_block.get_by_offset(*idx);
             let probe_column = probe_block_entry
                 .value
                 .convert_to_full_column(&probe_block_entry.data_type, data_block.num_rows());
             update_bitmap_with_bloom_filter(probe_column, filter, &mut bitmap)?;
+            stats.lock().filtered_rows += bitmap.null_count() as u64;
             bitmaps.push(bitmap);
         }
         if !bitmaps.is_empty() {
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -228,53 +229,15 @@ impl AsyncTransform for ReadParquetDataTransform<false> {
                         let virtual_reader = self.virtual_reader.clone();
 
                         chunks.push(async move {
-                            databend_common_base::runtime::spawn(async move {
-                                let part = FuseBlockPartInfo::from_part(&part)?;
-
-                                if let Some(index_reader) = index_reader.as_ref() {
-                                    let loc =
-                                        TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                                            &part.location,
-                                            index_reader.index_id(),
-                                        );
-                                    if let Some(data) = index_reader
-                                        .read_parquet_data_by_merge_io(&settings, &loc)
-                                        .await
-                                    {
-                                        // Read from aggregating index.
-                                        return Ok::<_, ErrorCode>(ParquetDataSource::AggIndex(data));
-                                    }
-                                }
-
-                                // If virtual column file exists, read the data from the virtual columns directly.
-                                let virtual_source = if let Some(virtual_reader) = virtual_reader.as_ref() {
-                                    let virtual_block_meta = part.block_meta_index.as_ref().and_then(|b| b.virtual_block_meta.as_ref());
-                                    virtual_reader
-                                        .read_parquet_data_by_merge_io(&settings, &virtual_block_meta, part.nums_rows)
-                                        .await
-                                } else {
-                                    None
-                                };
-
-                                let ignore_column_ids = if let Some(virtual_source) = &virtual_source {
-                                    &virtual_source.ignore_column_ids
-                                } else {
-                                    &None
-                                };
-
-                                let source = block_reader
-                                    .read_columns_data_by_merge_io(
-                                        &settings,
-                                        &part.location,
-                                        &part.columns_meta,
-                                        ignore_column_ids,
-                                    )
-                                    .await?;
-
-                                Ok(ParquetDataSource::Normal((source, virtual_source)))
-                            })
-                                .await
-                                .unwrap()
+                            databend_common_base::runtime::spawn(read_parquet_data(
+                                part,
+                                block_reader,
+                                settings,
+                                index_reader,
+                                virtual_reader,
+                            ))
+                            .await
+                            .unwrap()
                         });
                     }
 
@@ -292,3 +255,58 @@ impl AsyncTransform for ReadParquetDataTransform<false> {
         ))
     }
 }
+
+/// Read the data of a block, from the aggregating index or the virtual columns if they exist.
+pub(super) async fn read_parquet_data(
+    part: PartInfoPtr,
+    block_reader: Arc<BlockReader>,
+    settings: ReadSettings,
+    index_reader: Arc<Option<AggIndexReader>>,
+    virtual_reader: Arc<Option<VirtualColumnReader>>,
+) -> Result<ParquetDataSource> {
+    let part = FuseBlockPartInfo::from_part(&part)?;
+
+    if let Some(index_reader) = index_reader.as_ref() {
+        let loc = TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+            &part.location,
+            index_reader.index_id(),
+        );
+        if let Some(data) = index_reader
+            .read_parquet_data_by_merge_io(&settings, &loc)
+            .await
+        {
+            // Read from aggregating index.
+            return Ok(ParquetDataSource::AggIndex(data));
+        }
+    }
+
+    // If virtual column file exists, read the data from the virtual columns directly.
+    let virtual_source = if let Some(virtual_reader) = virtual_reader.as_ref() {
+        let virtual_block_meta = part
+            .block_meta_index
+            .as_ref()
+            .and_then(|b| b.virtual_block_meta.as_ref());
+        virtual_reader
+            .read_parquet_data_by_merge_io(&settings, &virtual_block_meta, part.nums_rows)
+            .await
+    } else {
+        None
+    };
+
+    let ignore_column_ids = if let Some(virtual_source) = &virtual_source {
+        &virtual_source.ignore_column_ids
+    } else {
+        &None
+    };
+
+    let source = block_reader
+        .read_columns_data_by_merge_io(
+            &settings,
+            &part.location,
+            &part.columns_meta,
+            ignore_column_ids,
+        )
+        .await?;
+
+    Ok(ParquetDataSource::Normal((source, virtual_source)))
+}
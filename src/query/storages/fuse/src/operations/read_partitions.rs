@@ -378,10 +378,17 @@ impl FuseTable {
 
         prune_pipeline
             .add_transform(|input, output| ExtractSegmentTransform::create(input, output, true))?;
+        if let Some((probability, seed)) =
+            PushDownInfo::system_sample_of_push_downs(&pruner.push_down)
+        {
+            prune_pipeline.add_transform(|input, output| {
+                SampleBlockMetasTransform::create(input, output, probability, Some(seed))
+            })?;
+        }
         let sample_probability = table_sample(&pruner.push_down)?;
         if let Some(probability) = sample_probability {
             prune_pipeline.add_transform(|input, output| {
-                SampleBlockMetasTransform::create(input, output, probability)
+                SampleBlockMetasTransform::create(input, output, probability, None)
             })?;
         }
         let block_pruner = Arc::new(BlockPruner::create(pruner.pruning_ctx.clone())?);
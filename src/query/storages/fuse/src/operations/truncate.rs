@@ -27,6 +27,7 @@ use crate::operations::common::CommitMeta;
 use crate::operations::common::CommitSink;
 use crate::operations::common::ConflictResolveContext;
 use crate::operations::common::TruncateGenerator;
+use crate::operations::WriteConflictRetry;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -74,6 +75,7 @@ impl FuseTable {
 
         let snapshot_gen = TruncateGenerator::new(mode);
         let table_meta_timestamps = ctx.get_table_meta_timestamps(self, Some(prev_snapshot))?;
+        let write_conflict_retry = WriteConflictRetry::try_create(ctx.as_ref())?;
         pipeline.add_sink(|input| {
             CommitSink::try_create(
                 self,
@@ -83,6 +85,7 @@ impl FuseTable {
                 snapshot_gen.clone(),
                 input,
                 None,
+                write_conflict_retry,
                 prev_snapshot_id,
                 None,
                 table_meta_timestamps,
@@ -22,6 +22,7 @@ use databend_common_base::base::tokio::sync::OwnedSemaphorePermit;
 use databend_common_base::base::tokio::sync::Semaphore;
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ColumnId;
@@ -68,6 +69,29 @@ pub fn set_backoff(
         .build()
 }
 
+/// How a commit which conflicts with a concurrent transaction is retried.
+#[derive(Clone, Copy, Debug)]
+pub struct WriteConflictRetry {
+    /// The maximum number of retries, 0 to retry until the retry timeout.
+    pub retry_count: u32,
+    /// The delay before the first retry in milliseconds, doubled on each retry.
+    pub backoff_ms: u64,
+}
+
+impl WriteConflictRetry {
+    pub fn try_create(ctx: &dyn TableContext) -> Result<Self> {
+        let settings = ctx.get_settings();
+        Ok(WriteConflictRetry {
+            retry_count: settings.get_write_conflict_retry_count()?,
+            backoff_ms: settings.get_write_conflict_backoff_ms()?,
+        })
+    }
+
+    pub fn exhausted(&self, retries: u64) -> bool {
+        self.retry_count != 0 && retries >= self.retry_count as u64
+    }
+}
+
 pub fn column_parquet_metas(
     file_meta: &parquet::format::FileMetaData,
     schema: &TableSchemaRef,
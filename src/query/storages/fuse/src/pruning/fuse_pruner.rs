@@ -18,6 +18,7 @@ use std::sync::Arc;
 use databend_common_base::base::tokio::sync::Semaphore;
 use databend_common_base::runtime::Runtime;
 use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::plan::sample_block;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -379,9 +380,21 @@ impl FusePruner {
                         }
                     } else {
                         let sample_probability = table_sample(&push_down)?;
+                        let system_sample = PushDownInfo::system_sample_of_push_downs(&push_down);
                         for (location, info) in pruned_segments {
                             let mut block_metas =
                                 Self::extract_block_metas(&location.location.0, &info, true)?;
+                            if let Some((probability, seed)) = system_sample {
+                                block_metas = Arc::new(
+                                    block_metas
+                                        .iter()
+                                        .filter(|block| {
+                                            sample_block(&block.location.0, probability, seed)
+                                        })
+                                        .cloned()
+                                        .collect(),
+                                );
+                            }
                             if let Some(probability) = sample_probability {
                                 if block_metas.len() <= SMALL_DATASET_SAMPLE_THRESHOLD {
                                     // Deterministic sampling for small datasets
@@ -15,6 +15,7 @@
 use std::cmp::max;
 use std::sync::Arc;
 
+use databend_common_catalog::plan::sample_block;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_pipeline_core::processors::InputPort;
@@ -33,6 +34,8 @@ const SMALL_DATASET_SAMPLE_THRESHOLD: usize = 100;
 
 pub struct SampleBlockMetasTransform {
     probability: f64,
+    // The seed of `TABLESAMPLE SYSTEM`, which keeps or skips each block by the seed.
+    seed: Option<u64>,
 }
 
 impl SampleBlockMetasTransform {
@@ -40,10 +43,12 @@ impl SampleBlockMetasTransform {
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         probability: f64,
+        seed: Option<u64>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(
             BlockMetaAccumulatingTransformer::create(input, output, SampleBlockMetasTransform {
                 probability,
+                seed,
             }),
         ))
     }
@@ -66,7 +71,15 @@ impl SampleBlockMetasTransform {
         &self,
         block_metas: &Arc<Vec<Arc<BlockMeta>>>,
     ) -> Arc<Vec<Arc<BlockMeta>>> {
-        if block_metas.len() <= SMALL_DATASET_SAMPLE_THRESHOLD {
+        if let Some(seed) = self.seed {
+            Arc::new(
+                block_metas
+                    .iter()
+                    .filter(|block| sample_block(&block.location.0, self.probability, seed))
+                    .cloned()
+                    .collect(),
+            )
+        } else if block_metas.len() <= SMALL_DATASET_SAMPLE_THRESHOLD {
             // Deterministic sampling for small datasets
             // Ensure at least one block is sampled for small datasets
             let sample_size = max(
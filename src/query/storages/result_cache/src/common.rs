@@ -55,4 +55,7 @@ pub struct ResultCacheValue {
     pub partitions_shas: Vec<String>,
     /// The location of the result cache file.
     pub location: String,
+    /// The `(table id, column id)` of the table columns read by the query.
+    #[serde(default)]
+    pub columns_used: Vec<(u64, u32)>,
 }
@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::UpsertKV;
+
+use crate::common::gen_result_cache_prefix;
+use crate::common::ResultCacheValue;
+
+/// Remove the result cache entries which become stale after a schema change.
+pub struct ResultCacheInvalidator {
+    inner: Arc<MetaStore>,
+}
+
+impl ResultCacheInvalidator {
+    pub fn create(inner: Arc<MetaStore>) -> Self {
+        Self { inner }
+    }
+
+    /// Delete the cache entries of `tenant` whose query read the dropped column,
+    /// returns the number of the deleted entries.
+    #[async_backtrace::framed]
+    pub async fn invalidate_for_column_drop(
+        &self,
+        tenant: &str,
+        table_id: u64,
+        column_id: u32,
+    ) -> Result<usize> {
+        let prefix = gen_result_cache_prefix(tenant);
+        let entries = self.inner.prefix_list_kv(&prefix).await?;
+
+        let mut invalidated = 0;
+        for (key, value) in entries {
+            let value = serde_json::from_slice::<ResultCacheValue>(&value.data)?;
+            if value.columns_used.contains(&(table_id, column_id)) {
+                self.inner.upsert_kv(UpsertKV::delete(&key)).await?;
+                invalidated += 1;
+            }
+        }
+        Ok(invalidated)
+    }
+}
@@ -104,7 +104,13 @@ impl ResultCacheReader {
 
     #[async_backtrace::framed]
     async fn read_result_from_cache(&self, location: &str) -> Result<Vec<DataBlock>> {
-        let data = self.operator.read(location).await?;
+        Self::read_blocks(&self.operator, location).await
+    }
+
+    /// Read the blocks of the result cache file at `location`.
+    #[async_backtrace::framed]
+    pub async fn read_blocks(operator: &Operator, location: &str) -> Result<Vec<DataBlock>> {
+        let data = operator.read(location).await?;
         // TODO: improve this part by implement ChunkReader for opendal::Buffer.
         let chunk_reader = data.to_bytes();
         let reader = ParquetRecordBatchReader::try_new(chunk_reader, usize::MAX)?;
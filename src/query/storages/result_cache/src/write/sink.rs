@@ -39,6 +39,7 @@ pub struct WriteResultCacheSink {
     ctx: Arc<dyn TableContext>,
     sql: String,
     partitions_shas: Vec<String>,
+    columns_used: Vec<(u64, u32)>,
 
     meta_mgr: ResultCacheMetaManager,
     meta_key: String,
@@ -101,6 +102,7 @@ impl AsyncMpscSink for WriteResultCacheSink {
             query_time: now,
             ttl: ttl_sec,
             partitions_shas: self.partitions_shas.clone(),
+            columns_used: self.columns_used.clone(),
             result_size: self.cache_writer.current_bytes(),
             num_rows: self.cache_writer.num_rows(),
             location,
@@ -121,6 +123,7 @@ impl WriteResultCacheSink {
         schema: TableSchemaRef,
         inputs: Vec<Arc<InputPort>>,
         kv_store: Arc<MetaStore>,
+        columns_used: Vec<(u64, u32)>,
     ) -> Result<ProcessorPtr> {
         let settings = ctx.get_settings();
         let max_bytes = settings.get_query_result_cache_max_bytes()?;
@@ -143,6 +146,7 @@ impl WriteResultCacheSink {
                 ctx,
                 sql,
                 partitions_shas,
+                columns_used,
                 meta_mgr: ResultCacheMetaManager::create(kv_store, ttl),
                 meta_key,
                 cache_writer,
@@ -21,6 +21,7 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 
 use super::parquet_file::append_data_to_parquet_files;
+use super::partitioned_file::append_data_to_partitioned_files;
 use super::row_based_file::append_data_to_row_based_files;
 use crate::append::output::SumSummaryTransform;
 use crate::StageTable;
@@ -40,6 +41,20 @@ impl StageTable {
         let op = StageTable::get_op(&self.table_info.stage_info)?;
         let query_id = ctx.get_id();
         let group_id = AtomicUsize::new(0);
+        if !self
+            .table_info
+            .copy_into_location_options
+            .partition_by
+            .is_empty()
+        {
+            return append_data_to_partitioned_files(
+                pipeline,
+                ctx,
+                self.table_info.clone(),
+                op,
+                query_id,
+            );
+        }
         match fmt {
             FileFormatParams::Parquet(_) => append_data_to_parquet_files(
                 pipeline,
@@ -15,6 +15,7 @@
 mod do_append;
 mod output;
 mod parquet_file;
+mod partitioned_file;
 mod path;
 mod row_based_file;
 
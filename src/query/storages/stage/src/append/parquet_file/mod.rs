@@ -17,3 +17,4 @@ mod limit_file_size_processor;
 mod pipeline;
 mod writer_processor;
 pub(crate) use pipeline::append_data_to_parquet_files;
+pub(crate) use writer_processor::create_writer;
@@ -73,7 +73,7 @@ const MAX_BUFFER_SIZE: usize = 64 * 1024 * 1024;
 const MAX_ROW_GROUP_SIZE: usize = 1024 * 1024;
 const CREATE_BY_LEN: usize = 24; // "Databend 1.2.333-nightly".len();
 
-fn create_writer(
+pub(crate) fn create_writer(
     arrow_schema: Arc<Schema>,
     targe_file_size: Option<usize>,
 ) -> Result<ArrowWriter<Vec<u8>>> {
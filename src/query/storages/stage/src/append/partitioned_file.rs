@@ -0,0 +1,199 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use arrow_schema::Schema;
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_compress::CompressAlgorithm;
+use databend_common_compress::CompressCodec;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::ScalarRef;
+use databend_common_formats::FileFormatOptionsExt;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use opendal::Operator;
+
+use super::parquet_file::create_writer;
+use crate::append::output::DataSummary;
+use crate::append::path::unload_path;
+use crate::append::UnloadOutput;
+use crate::compression::get_compression_alg_copy;
+
+/// The directory of a partition whose value is NULL.
+const NULL_PARTITION: &str = "__NULL__";
+
+/// Write the rows of each partition into a file under `path/<column>=<value>/`.
+pub(crate) fn append_data_to_partitioned_files(
+    pipeline: &mut Pipeline,
+    ctx: Arc<dyn TableContext>,
+    table_info: StageTableInfo,
+    op: Operator,
+    query_id: String,
+) -> Result<()> {
+    let partition_by = table_info
+        .copy_into_location_options
+        .partition_by
+        .iter()
+        .map(|name| table_info.schema.index_of(name))
+        .collect::<Result<Vec<_>>>()?;
+    let compression = match &table_info.stage_info.file_format_params {
+        FileFormatParams::Parquet(_) => None,
+        params => get_compression_alg_copy(params.compression(), "")?,
+    };
+
+    pipeline.try_resize(1)?;
+    pipeline.add_async_accumulating_transformer(|| PartitionedFileWriter {
+        ctx: ctx.clone(),
+        table_info: table_info.clone(),
+        data_accessor: op.clone(),
+        query_id: query_id.clone(),
+        partition_by: partition_by.clone(),
+        compression,
+        partitions: BTreeMap::new(),
+    });
+    Ok(())
+}
+
+/// Buffers the blocks of every partition and writes them out once the input is finished,
+/// each partition goes to its own file.
+pub struct PartitionedFileWriter {
+    ctx: Arc<dyn TableContext>,
+    table_info: StageTableInfo,
+    data_accessor: Operator,
+    query_id: String,
+    partition_by: Vec<FieldIndex>,
+    compression: Option<CompressAlgorithm>,
+
+    // sub-directory of the partition => blocks of the partition
+    partitions: BTreeMap<String, Vec<DataBlock>>,
+}
+
+impl PartitionedFileWriter {
+    fn partition_dir(&self, columns: &[ScalarRef]) -> String {
+        self.partition_by
+            .iter()
+            .zip(columns)
+            .map(|(offset, value)| {
+                let name = self.table_info.schema.field(*offset).name();
+                match value {
+                    ScalarRef::Null => format!("{name}={NULL_PARTITION}/"),
+                    ScalarRef::String(value) => format!("{name}={value}/"),
+                    value => format!("{name}={value}/"),
+                }
+            })
+            .collect()
+    }
+
+    fn serialize(&self, blocks: Vec<DataBlock>) -> Result<(Vec<u8>, DataSummary)> {
+        let row_counts = blocks.iter().map(|block| block.num_rows()).sum::<usize>();
+        let mut output = match &self.table_info.stage_info.file_format_params {
+            FileFormatParams::Parquet(_) => {
+                let arrow_schema = Arc::new(Schema::from(self.table_info.schema.as_ref()));
+                let mut writer = create_writer(arrow_schema, None)?;
+                for block in blocks {
+                    writer.write(&block.to_record_batch(&self.table_info.schema)?)?;
+                }
+                writer.into_inner()?
+            }
+            params => {
+                let mut options_ext =
+                    FileFormatOptionsExt::create_from_settings(&self.ctx.get_settings(), false)?;
+                let mut output_format =
+                    options_ext.get_output_format(self.table_info.schema(), params.clone())?;
+                let mut output = output_format.serialize_prefix()?;
+                for block in &blocks {
+                    output.extend(output_format.serialize_block(block)?);
+                }
+                output.extend(output_format.finalize()?);
+                output
+            }
+        };
+        let input_bytes = output.len();
+        if let Some(compression) = self.compression {
+            output = CompressCodec::from(compression).compress_all(&output)?;
+        }
+        let output_bytes = output.len();
+        Ok((output, DataSummary {
+            row_counts,
+            input_bytes,
+            output_bytes,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncAccumulatingTransform for PartitionedFileWriter {
+    const NAME: &'static str = "PartitionedFileWriter";
+
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        let num_rows = data.num_rows();
+        let columns = self
+            .partition_by
+            .iter()
+            .map(|offset| data.get_by_offset(*offset).to_column(num_rows))
+            .collect::<Vec<_>>();
+
+        let mut rows: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+        for row in 0..num_rows {
+            let values = columns
+                .iter()
+                .map(|column| column.index(row).unwrap())
+                .collect::<Vec<_>>();
+            rows.entry(self.partition_dir(&values))
+                .or_default()
+                .push(row as u32);
+        }
+
+        for (dir, indices) in rows {
+            let block = data.take(&indices)?;
+            self.partitions.entry(dir).or_default().push(block);
+        }
+        Ok(None)
+    }
+
+    async fn on_finish(&mut self, output: bool) -> Result<Option<DataBlock>> {
+        let mut unload_output =
+            UnloadOutput::create(self.table_info.copy_into_location_options.detailed_output);
+        let path = &self.table_info.files_info.path;
+        let base = if path.is_empty() || path == "/" {
+            String::new()
+        } else if path.ends_with('/') {
+            path.clone()
+        } else {
+            format!("{path}/")
+        };
+
+        for (dir, blocks) in std::mem::take(&mut self.partitions) {
+            let (data, summary) = self.serialize(blocks)?;
+            let mut table_info = self.table_info.clone();
+            table_info.files_info.path = format!("{base}{dir}");
+            let path = unload_path(&table_info, &self.query_id, 0, 0, self.compression);
+            self.data_accessor.write(&path, data).await?;
+            unload_output.add_file(&path, summary);
+        }
+
+        let blocks = unload_output.to_block_partial();
+        if !output || blocks.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(DataBlock::concat(&blocks)?))
+    }
+}
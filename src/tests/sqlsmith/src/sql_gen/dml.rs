@@ -342,6 +342,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             alias: None,
             temporal: None,
             with_options: None,
+            index_hint: None,
             pivot: None,
             unpivot: None,
             sample: None,
@@ -545,6 +546,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             alias: None,
             temporal: None,
             with_options: None,
+            index_hint: None,
             pivot: None,
             unpivot: None,
             sample: None,
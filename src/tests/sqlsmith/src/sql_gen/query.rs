@@ -636,6 +636,7 @@ impl<R: Rng> SqlGenerator<'_, R> {
             // TODO
             temporal: None,
             with_options: None,
+            index_hint: None,
             // TODO
             pivot: None,
             // TODO
@@ -679,6 +680,7 @@ impl<R: Rng> SqlGenerator<'_, R> {
                         value: Literal::UInt64(self.rng.gen_range(0..=10)),
                     }],
                     named_params: vec![],
+                    with_ordinality: false,
                     alias: None,
                     sample: None,
                 }
@@ -752,6 +754,7 @@ impl<R: Rng> SqlGenerator<'_, R> {
                         vec![param1, param2, param3]
                     },
                     named_params: vec![],
+                    with_ordinality: false,
                     alias: None,
                     sample: None,
                 }
@@ -613,4 +613,61 @@ query II
 select * from t2 order by c1;
 ----
 1 2
-3 4
\ No newline at end of file
+3 4
+# insert first routes each row to exactly one table
+statement ok
+create or replace table s(id int);
+
+statement ok
+insert into s select number from numbers(100);
+
+statement ok
+insert into s values(NULL);
+
+statement ok
+create or replace table t_c(id int);
+
+statement ok
+create or replace table t_a(id int);
+
+statement ok
+create or replace table t_b(id int);
+
+statement ok
+INSERT FIRST
+    WHEN id < 30 THEN
+      INTO t_c
+    WHEN id < 70 THEN
+      INTO t_a
+    ELSE
+      INTO t_b
+SELECT * from s;
+
+query IIII
+select (select count(*) from t_c), (select min(id) from t_c), (select max(id) from t_c), (select count(*) from t_c where id >= 30);
+----
+30 0 29 0
+
+query IIII
+select (select count(*) from t_a), (select min(id) from t_a), (select max(id) from t_a), (select count(*) from t_a where id < 30 or id >= 70);
+----
+40 30 69 0
+
+query IIII
+select (select count(*) from t_b), (select min(id) from t_b), (select max(id) from t_b), (select count(*) from t_b where id is null);
+----
+31 70 99 1
+
+query II
+select count(*), count(distinct id) from (select id from t_c union all select id from t_a union all select id from t_b);
+----
+101 100
+
+statement ok
+drop table t_a;
+
+statement ok
+drop table t_b;
+
+statement ok
+drop table t_c;
@@ -29,20 +29,18 @@ Commit
         ├── branch 1: orders_placed.order_id (#0), 'ExpressHandling'
         ├── branch 2: orders_placed.order_id (#0), 'StandardHandling'
         ├── branch 3: orders_placed.order_id (#0), 'ReviewNeeded'
-        └── Filter
+        └── Scatter
             ├── branch 0: is_true(orders_placed.order_amount (#2) > CAST(1000 AS Float32 NULL))
-            ├── branch 1: is_true((NOT orders_placed.order_amount (#2) > CAST(1000 AS Float32 NULL) AND orders_placed.order_amount (#2) > CAST(500 AS Float32 NULL)))
-            ├── branch 2: is_true(((NOT orders_placed.order_amount (#2) > CAST(1000 AS Float32 NULL) AND NOT orders_placed.order_amount (#2) > CAST(500 AS Float32 NULL)) AND orders_placed.order_amount (#2) > CAST(100 AS Float32 NULL)))
-            ├── branch 3: is_true(((NOT orders_placed.order_amount (#2) > CAST(1000 AS Float32 NULL) AND NOT orders_placed.order_amount (#2) > CAST(500 AS Float32 NULL)) AND NOT orders_placed.order_amount (#2) > CAST(100 AS Float32 NULL)))
-            └── Duplicate
-                ├── Duplicate data to 4 branch
-                └── TableScan
-                    ├── table: default.default.orders_placed
-                    ├── output columns: [order_id (#0), order_amount (#2)]
-                    ├── read rows: 5
-                    ├── read size: < 1 KiB
-                    ├── partitions total: 1
-                    ├── partitions scanned: 1
-                    ├── pruning stats: [segments: <range pruning: 1 to 1>, blocks: <range pruning: 1 to 1>]
-                    ├── push downs: [filters: [], limit: NONE]
-                    └── estimated rows: 5.00
+            ├── branch 1: is_true(orders_placed.order_amount (#2) > CAST(500 AS Float32 NULL))
+            ├── branch 2: is_true(orders_placed.order_amount (#2) > CAST(100 AS Float32 NULL))
+            ├── branch 3: true
+            └── TableScan
+                ├── table: default.default.orders_placed
+                ├── output columns: [order_id (#0), order_amount (#2)]
+                ├── read rows: 5
+                ├── read size: < 1 KiB
+                ├── partitions total: 1
+                ├── partitions scanned: 1
+                ├── pruning stats: [segments: <range pruning: 1 to 1>, blocks: <range pruning: 1 to 1>]
+                ├── push downs: [filters: [], limit: NONE]
+                └── estimated rows: 5.00
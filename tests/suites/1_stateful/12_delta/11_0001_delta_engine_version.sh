@@ -0,0 +1,19 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(realpath "$CURDIR"/../../../data/delta/partitioned/)
+
+stmt "drop table if exists test_delta;"
+
+echo ">>>> create table test_delta engine = delta location = 'fs://\${ROOT}/';"
+echo "create table test_delta engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+# version 2 to 5 each insert one row, version 4 is a checkpoint.
+query "select c5 from test_delta at (snapshot => '2') order by c5;"
+query "select c5 from test_delta at (snapshot => '3') order by c5;"
+query "select c5 from test_delta at (snapshot => '5') order by c5;"
+query "select c1, p4 from test_delta at (snapshot => '4') where p4 > 20 order by c1;"
+query "select count() from test_delta at (snapshot => '1');"
+
+stmt "drop table test_delta;"